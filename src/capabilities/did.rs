@@ -18,7 +18,7 @@
 use crate::errors::{BadFormat, Errors, Outcome, PetitionFailure};
 use crate::services::client::ClientTrait;
 use crate::types::dids::{
-    DidDocument, DidType, JwkDid, VerificationMaterial, VerificationMethod, WebDid,
+    DidDocument, DidType, ExternalDid, JwkDid, VerificationMaterial, VerificationMethod, WebDid,
 };
 use crate::utils::{ResponseExt, StringOrArr, decode_url_safe_no_pad, http_client};
 use serde_json::Value;
@@ -33,6 +33,9 @@ pub enum Did {
     Jwk(JwkDid),
     /// Domain-name and internet infrastructure anchored identifier scheme (`did:web:`).
     Web(WebDid),
+    /// Any other method, resolved through the Universal Resolver fallback of a
+    /// [`DidResolver`](super::DidResolver).
+    External(ExternalDid),
 }
 
 impl Did {
@@ -45,7 +48,7 @@ impl Did {
     ///
     /// # Errors
     /// Returns an [`Errors::FormatError`] if the anatomy of a `did:web` path matrix is broken,
-    /// or [`Errors::FeatureNotImplError`] if the targeted sub-scheme is unsupported.
+    /// or [`Errors::FeatureNotImplError`] if the input is not a syntactically valid DID.
     pub fn parse(did: &str) -> Outcome<Did> {
         let did = did.split_once('#').map(|(did, _)| did).unwrap_or(did);
 
@@ -71,6 +74,8 @@ impl Did {
             let j = JwkDid::new(did, rest.to_owned());
 
             Ok(Did::Jwk(j))
+        } else if let Some(method) = Self::external_method(did) {
+            Ok(Did::External(ExternalDid::new(did, method)))
         } else {
             Err(Errors::not_impl(
                 format!("Did format {did} not supported"),
@@ -86,6 +91,7 @@ impl Did {
         match self {
             Did::Jwk(j) => j.id(),
             Did::Web(w) => w.id(),
+            Did::External(e) => e.id(),
         }
     }
 
@@ -94,19 +100,35 @@ impl Did {
         match self {
             Did::Jwk(_) => DidType::Jwk,
             Did::Web(_) => DidType::Web,
+            Did::External(_) => DidType::Other,
         }
     }
 
     // ===== RESOLUTION LIFECYCLE ==================================================================
 
     /// Executes the complete state resolution workflow, mapping the instance into a valid W3C [`DidDocument`].
+    ///
+    /// Only the natively supported methods resolve here; the others need a
+    /// [`DidResolver`](super::DidResolver) with a Universal Resolver fallback.
     pub async fn resolve(&self) -> Outcome<DidDocument> {
         match self {
             Did::Jwk(j) => Self::resolve_jwk(j),
             Did::Web(w) => Self::resolve_web(w).await,
+            Did::External(e) => Err(Errors::not_impl(
+                format!("Did method {} not supported", e.method()),
+                None,
+            )),
         }
     }
 
+    /// Extracts the method of a syntactically valid DID.
+    fn external_method(did: &str) -> Option<&str> {
+        let (method, specific_id) = did.strip_prefix("did:")?.split_once(':')?;
+        let valid_method = !method.is_empty()
+            && method.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit());
+        (valid_method && !specific_id.is_empty()).then_some(method)
+    }
+
     /// Parses internal data parameters to reconstruct a self-contained `did:jwk` Document locally.
     fn resolve_jwk(did: &JwkDid) -> Outcome<DidDocument> {
         let jwk_bytes = decode_url_safe_no_pad(did.jwk())?;
@@ -0,0 +1,58 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use super::{Did, UniversalResolver};
+use crate::config::traits::UniversalResolverConfigTrait;
+use crate::errors::{Errors, Outcome};
+use crate::types::dids::DidDocument;
+
+/// DID resolution entry point handed to the verification routines.
+///
+/// `did:jwk` and `did:web` are resolved natively; any other method goes to the
+/// [`UniversalResolver`] fallback when the configuration enables one. The default resolver has
+/// no fallback.
+#[derive(Clone, Default)]
+pub struct DidResolver {
+    fallback: Option<Arc<UniversalResolver>>,
+}
+
+impl DidResolver {
+    /// Builds the resolver, with the Universal Resolver fallback if `config` sets its URL.
+    pub fn new(config: &impl UniversalResolverConfigTrait) -> Self {
+        Self {
+            fallback: UniversalResolver::new(config).map(Arc::new),
+        }
+    }
+
+    /// Resolves `did` into its [`DidDocument`].
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FeatureNotImplError`] for methods without native support when no
+    /// fallback is configured.
+    pub async fn resolve(&self, did: &Did) -> Outcome<DidDocument> {
+        match (did, &self.fallback) {
+            (Did::External(e), Some(fallback)) => fallback.resolve(e.id()).await,
+            (Did::External(e), None) => Err(Errors::not_impl(
+                format!("Did method {} not supported", e.method()),
+                None,
+            )),
+            (did, _) => did.resolve().await,
+        }
+    }
+}
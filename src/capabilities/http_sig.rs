@@ -50,6 +50,30 @@ impl HttpSig {
         authorization: Option<&str>,
     ) -> Outcome<HeaderMap> {
         let alg = alg.unwrap_or(priv_key.alg());
+        let draft = Self::prepare(
+            key_source,
+            alg.clone(),
+            method,
+            url,
+            body_bytes,
+            authorization,
+        );
+        let signature_bytes = priv_key.sign_bytes(draft.signature_base(), alg)?;
+        draft.finish(&signature_bytes)
+    }
+
+    /// Computes the signature base of an outbound request without signing it.
+    ///
+    /// Lets keys held outside the process sign the base and hand the signature back to
+    /// [`HttpSigDraft::finish`].
+    pub fn prepare(
+        key_source: &KeySource,
+        alg: Alg,
+        method: &str,
+        url: &str,
+        body_bytes: &[u8],
+        authorization: Option<&str>,
+    ) -> HttpSigDraft {
         let key_id = key_source.thumbprint();
         let created = unix_now();
         let nonce = random_nonce_32();
@@ -68,36 +92,12 @@ impl HttpSig {
             authorization,
         );
 
-        let signature_bytes = priv_key.sign_bytes(signature_base.as_bytes(), alg)?;
-        let signature_b64 = URL_SAFE_NO_PAD.encode(&signature_bytes);
-
-        let mut headers = HeaderMap::new();
-        headers.insert(
-            "content-digest",
-            content_digest.parse().map_err(|e| {
-                Errors::parse("Failed to parse content-digest header", Some(Box::new(e)))
-            })?,
-        );
-        headers.insert(
-            "content-length",
-            content_length.to_string().parse().map_err(|e| {
-                Errors::parse("Failed to parse content-length header", Some(Box::new(e)))
-            })?,
-        );
-        headers.insert(
-            "signature-input",
-            format!("sig1={sig_params}").parse().map_err(|e| {
-                Errors::parse("Failed to parse signature-input header", Some(Box::new(e)))
-            })?,
-        );
-        headers.insert(
-            "signature",
-            format!("sig1=:{signature_b64}:").parse().map_err(|e| {
-                Errors::parse("Failed to parse signature header", Some(Box::new(e)))
-            })?,
-        );
-
-        Ok(headers)
+        HttpSigDraft {
+            content_digest,
+            content_length,
+            signature_base,
+            sig_params,
+        }
     }
 
     // ===== VERIFICATION — SERVER SIDE ============================================================
@@ -295,6 +295,55 @@ impl HttpSig {
     }
 }
 
+/// Outbound RFC 9421 signature awaiting the bytes signed over its base.
+pub struct HttpSigDraft {
+    content_digest: String,
+    content_length: usize,
+    signature_base: String,
+    sig_params: String,
+}
+
+impl HttpSigDraft {
+    /// Returns the bytes the key must sign.
+    pub fn signature_base(&self) -> &[u8] {
+        self.signature_base.as_bytes()
+    }
+
+    /// Builds the signature headers around `signature_bytes`.
+    pub fn finish(self, signature_bytes: &[u8]) -> Outcome<HeaderMap> {
+        let signature_b64 = URL_SAFE_NO_PAD.encode(signature_bytes);
+        let sig_params = self.sig_params;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "content-digest",
+            self.content_digest.parse().map_err(|e| {
+                Errors::parse("Failed to parse content-digest header", Some(Box::new(e)))
+            })?,
+        );
+        headers.insert(
+            "content-length",
+            self.content_length.to_string().parse().map_err(|e| {
+                Errors::parse("Failed to parse content-length header", Some(Box::new(e)))
+            })?,
+        );
+        headers.insert(
+            "signature-input",
+            format!("sig1={sig_params}").parse().map_err(|e| {
+                Errors::parse("Failed to parse signature-input header", Some(Box::new(e)))
+            })?,
+        );
+        headers.insert(
+            "signature",
+            format!("sig1=:{signature_b64}:").parse().map_err(|e| {
+                Errors::parse("Failed to parse signature header", Some(Box::new(e)))
+            })?,
+        );
+
+        Ok(headers)
+    }
+}
+
 // ===== MODULE LOCAL HELPER PROCEDURES ============================================================
 
 /// Pulls the dynamic host epoch runtime clock matrix.
@@ -0,0 +1,190 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes128Gcm, Aes256Gcm, Nonce};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use p256::ecdh::diffie_hellman;
+use p256::pkcs8::DecodePrivateKey;
+use p256::{PublicKey, SecretKey};
+use rand::rngs::OsRng;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use crate::errors::{BadFormat, Errors, Outcome};
+
+/// Key management algorithm accepted for encrypted authorization responses.
+pub const JWE_ALG: &str = "ECDH-ES";
+/// Content encryption algorithms accepted for encrypted authorization responses.
+pub const JWE_ENC_SUPPORTED: [&str; 2] = ["A128GCM", "A256GCM"];
+
+/// P-256 key receiving `ECDH-ES` encrypted JWEs (RFC 7516 / RFC 7518 §4.6).
+///
+/// Used for JARM: the public half travels in the `client_metadata` of an authorization
+/// request and the wallet encrypts its `direct_post.jwt` response to it. Keys are either
+/// generated per exchange or loaded once from a PKCS#8 PEM kept in the vault.
+#[derive(Clone)]
+pub struct EcdhEsKey {
+    sk: SecretKey,
+}
+
+impl EcdhEsKey {
+    /// Generates a fresh key.
+    pub fn generate() -> Self {
+        Self {
+            sk: SecretKey::random(&mut OsRng),
+        }
+    }
+
+    /// Restores a key from the private JWK produced by [`private_jwk`](Self::private_jwk).
+    pub fn from_private_jwk(jwk: &str) -> Outcome<Self> {
+        let sk = SecretKey::from_jwk_str(jwk)
+            .map_err(|e| Errors::parse("Invalid response encryption key", Some(Box::new(e))))?;
+        Ok(Self { sk })
+    }
+
+    /// Loads a long-lived key from its PKCS#8 PEM.
+    pub fn from_pkcs8_pem(pem: &str) -> Outcome<Self> {
+        let sk = SecretKey::from_pkcs8_pem(pem).map_err(|e| {
+            Errors::format(
+                BadFormat::Received,
+                "PEM is not a P-256 PKCS#8 key",
+                Some(Box::new(e)),
+            )
+        })?;
+        Ok(Self { sk })
+    }
+
+    /// Serializes the whole key, `d` included, as a JWK.
+    pub fn private_jwk(&self) -> String {
+        self.sk.to_jwk_string().to_string()
+    }
+
+    /// RFC 7638 thumbprint of the public key.
+    pub fn kid(&self) -> String {
+        let jwk = self.raw_public_jwk();
+        let thumbprint_input = json!({
+            "crv": jwk["crv"],
+            "kty": jwk["kty"],
+            "x": jwk["x"],
+            "y": jwk["y"],
+        });
+        URL_SAFE_NO_PAD.encode(Sha256::digest(thumbprint_input.to_string().as_bytes()))
+    }
+
+    /// Public JWK advertised to the wallet, identified by its [`kid`](Self::kid).
+    pub fn public_jwk(&self) -> Value {
+        let mut jwk = self.raw_public_jwk();
+        jwk["kid"] = Value::String(self.kid());
+        jwk["use"] = Value::String("enc".to_string());
+        jwk["alg"] = Value::String(JWE_ALG.to_string());
+        jwk
+    }
+
+    fn raw_public_jwk(&self) -> Value {
+        serde_json::to_value(self.sk.public_key().to_jwk()).unwrap_or_else(|_| json!({}))
+    }
+
+    /// Decrypts a compact JWE using direct key agreement and returns its plaintext.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`](crate::errors::Errors::FormatError) for malformed
+    /// JWEs or unsupported algorithms, and an
+    /// [`Errors::SecurityError`](crate::errors::Errors::SecurityError) when authentication fails.
+    pub fn decrypt(&self, jwe: &str) -> Outcome<Vec<u8>> {
+        let parts: Vec<&str> = jwe.trim().split('.').collect();
+        let [protected, encrypted_key, iv, ciphertext, tag] = parts[..] else {
+            return Err(malformed("JWE must have five parts"));
+        };
+        if !encrypted_key.is_empty() {
+            return Err(malformed("ECDH-ES JWEs carry no encrypted key"));
+        }
+
+        let header: Value = serde_json::from_slice(&b64(protected)?)
+            .map_err(|e| Errors::parse("Invalid JWE header", Some(Box::new(e))))?;
+        if header["alg"].as_str() != Some(JWE_ALG) {
+            return Err(malformed(format!("Unsupported JWE alg {}", header["alg"])));
+        }
+        if header["kid"].as_str().is_some_and(|kid| kid != self.kid()) {
+            return Err(Errors::security("JWE was encrypted to another key", None));
+        }
+        let enc = header["enc"].as_str().unwrap_or_default();
+        let key_len = match enc {
+            "A128GCM" => 16,
+            "A256GCM" => 32,
+            other => return Err(malformed(format!("Unsupported JWE enc '{other}'"))),
+        };
+
+        let epk = PublicKey::from_jwk_str(&header["epk"].to_string())
+            .map_err(|e| Errors::parse("Invalid JWE epk", Some(Box::new(e))))?;
+        let shared = diffie_hellman(self.sk.to_nonzero_scalar(), epk.as_affine());
+        let apu = optional_b64(&header["apu"])?;
+        let apv = optional_b64(&header["apv"])?;
+        let cek = concat_kdf(shared.raw_secret_bytes(), enc, &apu, &apv, key_len);
+
+        let iv = b64(iv)?;
+        if iv.len() != 12 {
+            return Err(malformed("JWE iv must be 96 bits"));
+        }
+        let mut msg = b64(ciphertext)?;
+        msg.extend(b64(tag)?);
+        let payload = Payload {
+            msg: &msg,
+            aad: protected.as_bytes(),
+        };
+        let nonce = Nonce::from_slice(&iv);
+
+        let plaintext = match key_len {
+            16 => Aes128Gcm::new_from_slice(&cek).map(|c| c.decrypt(nonce, payload)),
+            _ => Aes256Gcm::new_from_slice(&cek).map(|c| c.decrypt(nonce, payload)),
+        };
+        match plaintext {
+            Ok(Ok(plaintext)) => Ok(plaintext),
+            _ => Err(Errors::security("Unable to decrypt JWE", None)),
+        }
+    }
+}
+
+/// Concat KDF (NIST SP 800-56A) as profiled by RFC 7518 §4.6.2, single SHA-256 round.
+fn concat_kdf(z: &[u8], enc: &str, apu: &[u8], apv: &[u8], key_len: usize) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(1u32.to_be_bytes());
+    hasher.update(z);
+    for field in [enc.as_bytes(), apu, apv] {
+        hasher.update((field.len() as u32).to_be_bytes());
+        hasher.update(field);
+    }
+    hasher.update(((key_len * 8) as u32).to_be_bytes());
+    hasher.finalize()[..key_len].to_vec()
+}
+
+fn b64(part: &str) -> Outcome<Vec<u8>> {
+    URL_SAFE_NO_PAD
+        .decode(part)
+        .map_err(|e| Errors::parse("Invalid base64url in JWE", Some(Box::new(e))))
+}
+
+fn optional_b64(value: &Value) -> Outcome<Vec<u8>> {
+    match value.as_str() {
+        Some(s) => b64(s),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn malformed(reason: impl Into<String>) -> Errors {
+    Errors::format(BadFormat::Received, reason, None)
+}
@@ -15,7 +15,7 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use crate::capabilities::Did;
+use crate::capabilities::{Did, DidResolver};
 use crate::errors::{BadFormat, Errors, Outcome};
 use crate::types::dids::DidType;
 use crate::types::keys::PublicKey;
@@ -76,11 +76,13 @@ impl Kid {
 
     /// Triggers the downstream DID Document resolution pipeline to extract the target matching [`PublicKey`].
     ///
+    /// The DID is resolved through `resolver`.
+    ///
     /// # Errors
     /// Returns an [`Errors::FormatError`] if the designated fragment identifier fails to match
     /// any verification methods listed inside the recovered canonical structural data document.
-    pub async fn get_key(&self) -> Outcome<PublicKey> {
-        let did_doc = self.did.resolve().await?;
+    pub async fn get_key(&self, resolver: &DidResolver) -> Outcome<PublicKey> {
+        let did_doc = resolver.resolve(&self.did).await?;
 
         let vm = did_doc
             .verification_method
@@ -16,14 +16,20 @@
  */
 
 mod did;
+mod did_resolver;
 mod digest_sri;
 mod http_sig;
+mod jwe;
 mod kid;
 mod signer;
+mod universal_resolver;
 mod verifier;
 pub use did::*;
+pub use did_resolver::*;
 pub use digest_sri::*;
 pub use http_sig::*;
+pub use jwe::*;
 pub use kid::*;
 pub use signer::*;
+pub use universal_resolver::*;
 pub use verifier::*;
@@ -16,7 +16,7 @@
  */
 
 use crate::errors::Outcome;
-use crate::types::crypto::{Canon, Proof};
+use crate::types::crypto::Proof;
 use crate::types::jwt::{Jwt, JwtHeader};
 use crate::types::keys::{Alg, SigningCtx};
 use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
@@ -31,22 +31,31 @@ pub struct Signer;
 impl Signer {
     // ===== EMBEDDED DATA INTEGRITY PROOFS ========================================================
 
-    /// Generates an attached compliant W3C [`Proof`] entity calculated over canonicalized buffers.
+    /// Generates an attached compliant W3C [`Proof`] entity over `document` (without its `proof`).
     ///
-    /// The resulting structure encodes the computed cryptographic signature payload into a standard
-    /// Multibase Base58 string pattern prefixed with the structural literal 'z'.
-    pub fn sign_embed(sig_ctx: &SigningCtx, canonical: &Canon, alg: Alg) -> Outcome<Proof> {
+    /// The proof options are signed together with the document, as
+    /// [`Proof::data_integrity_input`] lays out. The resulting structure encodes the computed
+    /// cryptographic signature payload into a standard Multibase Base58 string pattern prefixed
+    /// with the structural literal 'z'.
+    pub async fn sign_embed(sig_ctx: &SigningCtx, document: &Value) -> Outcome<Proof> {
         let cryptosuite = sig_ctx.key().cryptosuite()?;
-        let sig_bytes = sig_ctx.key().sign_bytes(canonical.as_ref(), alg)?;
-        let proof_value = format!("z{}", bs58::encode(&sig_bytes).into_string());
+        let alg = Alg::from_cryptosuite(&cryptosuite);
         let verification_method = format!("{}#{}", sig_ctx.did().id(), sig_ctx.keys_frag());
 
-        Ok(Proof {
+        let mut proof = Proof {
             r#type: "DataIntegrityProof".to_string(),
-            cryptosuite,
+            cryptosuite: Some(cryptosuite),
             verification_method,
-            proof_value,
-        })
+            proof_value: String::new(),
+            created: None,
+            proof_purpose: None,
+            challenge: None,
+            domain: None,
+        };
+        let input = proof.data_integrity_input(document).await?;
+        let sig_bytes = sig_ctx.key().sign_bytes(&input, alg)?;
+        proof.proof_value = format!("z{}", bs58::encode(&sig_bytes).into_string());
+        Ok(proof)
     }
 
     // ===== ENVELOPED JSON WEB TOKENS =============================================================
@@ -59,8 +68,26 @@ impl Signer {
         value: &Value,
     ) -> Outcome<Jwt> {
         let kid = format!("{}#{}", sig_ctx.did().id(), sig_ctx.keys_frag());
+        let signing_input = Self::signing_input(kid, sig_ctx.key().alg(), typ, cty, value)?;
+        let sig_bytes = sig_ctx
+            .key()
+            .sign_bytes(signing_input.as_bytes(), sig_ctx.key().alg())?;
+        Self::assemble(&signing_input, &sig_bytes)
+    }
+
+    /// Builds the `header.payload` JWS signing input of a compact JWT.
+    ///
+    /// Lets keys held outside the process sign the input and hand the signature back to
+    /// [`Signer::assemble`].
+    pub fn signing_input(
+        kid: String,
+        alg: Alg,
+        typ: &str,
+        cty: &str,
+        value: &Value,
+    ) -> Outcome<String> {
         let header = JwtHeader {
-            alg: sig_ctx.key().alg(),
+            alg,
             typ: Some(typ.to_string()),
             cty: Some(cty.to_string()),
             kid,
@@ -73,11 +100,12 @@ impl Signer {
         let header_b64 = URL_SAFE_NO_PAD.encode(&header_bytes);
         let payload_b64 = URL_SAFE_NO_PAD.encode(&payload_bytes);
 
-        let signing_input = format!("{header_b64}.{payload_b64}");
-        let sig_bytes = sig_ctx
-            .key()
-            .sign_bytes(signing_input.as_bytes(), sig_ctx.key().alg())?;
-        let sig_b64 = URL_SAFE_NO_PAD.encode(&sig_bytes);
+        Ok(format!("{header_b64}.{payload_b64}"))
+    }
+
+    /// Appends `sig_bytes` to `signing_input`, producing the compact JWT.
+    pub fn assemble(signing_input: &str, sig_bytes: &[u8]) -> Outcome<Jwt> {
+        let sig_b64 = URL_SAFE_NO_PAD.encode(sig_bytes);
 
         let jwt = format!("{signing_input}.{sig_b64}");
         Jwt::parse(&jwt)
@@ -0,0 +1,236 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use crate::config::traits::UniversalResolverConfigTrait;
+use crate::errors::{BadFormat, Errors, Outcome, PetitionFailure};
+use crate::services::client::ClientTrait;
+use crate::types::dids::{DidDocument, DidService, VerificationMaterial, VerificationMethod};
+use crate::utils::{ResponseExt, StringOrArr, http_client};
+use reqwest::header::{ACCEPT, HeaderMap, HeaderValue};
+use serde_json::Value;
+use tracing::{debug, info};
+
+/// Accept header asking for the full resolution result rather than the bare document.
+const RESOLUTION_ACCEPT: &str = "application/ld+json;profile=\"https://w3id.org/did-resolution\"";
+
+/// Fallback resolver delegating DID methods without native support to a Universal Resolver.
+///
+/// Built from the configuration and handed to a [`DidResolver`](super::DidResolver), which
+/// sends the methods it cannot resolve natively to `GET {url}/1.0/identifiers/{did}`. Only
+/// verification methods carrying a `publicKeyJwk` or `publicKeyMultibase` and usable for
+/// signing are kept, so the returned document feeds the regular key extraction path.
+pub struct UniversalResolver {
+    url: String,
+    ttl: Duration,
+    cache: RwLock<HashMap<String, (Instant, DidDocument)>>,
+}
+
+impl UniversalResolver {
+    // ===== LIFECYCLE =============================================================================
+
+    /// Builds the fallback if the configuration enables it.
+    pub fn new(config: &impl UniversalResolverConfigTrait) -> Option<Self> {
+        let url = config.get_universal_resolver_url()?;
+        info!("Universal Resolver fallback enabled at {url}");
+        Some(UniversalResolver {
+            url: url.to_string(),
+            ttl: config.get_resolver_cache_ttl(),
+            cache: RwLock::new(HashMap::new()),
+        })
+    }
+
+    // ===== RESOLUTION ============================================================================
+
+    /// Resolves `did`, serving it from cache while it is fresh.
+    pub async fn resolve(&self, did: &str) -> Outcome<DidDocument> {
+        if let Some(doc) = self.cached(did) {
+            return Ok(doc);
+        }
+
+        let url = format!("{}/1.0/identifiers/{}", self.url, urlencoding::encode(did));
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static(RESOLUTION_ACCEPT));
+
+        let res = http_client().get(&url, Some(headers)).await?;
+        if !res.status().is_success() {
+            return Err(Errors::petition(
+                url,
+                "GET",
+                Some(res.status()),
+                PetitionFailure::HttpStatus(res.status()),
+                "Universal Resolver resolution failed",
+                None,
+            ));
+        }
+        let body: Value = res.parse_json().await?;
+        let doc = into_did_document(did, body)?;
+
+        let mut cache = self.cache.write().unwrap_or_else(|e| e.into_inner());
+        cache.retain(|_, (at, _)| at.elapsed() < self.ttl);
+        cache.insert(did.to_string(), (Instant::now(), doc.clone()));
+        Ok(doc)
+    }
+
+    fn cached(&self, did: &str) -> Option<DidDocument> {
+        let cache = self.cache.read().unwrap_or_else(|e| e.into_inner());
+        cache
+            .get(did)
+            .filter(|(at, _)| at.elapsed() < self.ttl)
+            .map(|(_, doc)| doc.clone())
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Maps a resolution result (or a bare document) into a [`DidDocument`].
+fn into_did_document(did: &str, body: Value) -> Outcome<DidDocument> {
+    let is_result =
+        body.get("didDocument").is_some() || body.get("didResolutionMetadata").is_some();
+    let mut doc = if is_result {
+        let doc = body["didDocument"].clone();
+        if doc.is_null() {
+            let error = body["didResolutionMetadata"]["error"]
+                .as_str()
+                .unwrap_or("no document returned");
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Universal Resolver could not resolve {did}: {error}"),
+                None,
+            ));
+        }
+        doc
+    } else {
+        body
+    };
+
+    if doc.get("id").and_then(Value::as_str) != Some(did) {
+        return Err(Errors::format(
+            BadFormat::Received,
+            format!("DID Document id mismatch: expected {did}"),
+            None,
+        ));
+    }
+
+    // Verification methods may be listed or embedded in any signing relationship. Key agreement
+    // keys are for encryption only, so those not referenced by a signing relationship are dropped.
+    let key_agreement: HashSet<String> = match doc.get_mut("keyAgreement").map(Value::take) {
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(|item| item.as_str().or_else(|| item.get("id")?.as_str()))
+            .map(|id| absolute_id(did, id))
+            .collect(),
+        _ => HashSet::new(),
+    };
+    let mut signing: HashSet<String> = HashSet::new();
+    let mut candidates: Vec<Value> = Vec::new();
+    for field in [
+        "verificationMethod",
+        "authentication",
+        "assertionMethod",
+        "capabilityInvocation",
+        "capabilityDelegation",
+    ] {
+        let Some(Value::Array(items)) = doc.get_mut(field).map(Value::take) else {
+            continue;
+        };
+        for item in items {
+            if field != "verificationMethod" {
+                let id = item.as_str().or_else(|| item.get("id")?.as_str());
+                signing.extend(id.map(|id| absolute_id(did, id)));
+            }
+            if item.is_object() {
+                candidates.push(item);
+            }
+        }
+    }
+
+    let mut verification_method: Vec<VerificationMethod> = Vec::new();
+    for vm in candidates {
+        let Some(vm) = into_verification_method(did, &vm) else {
+            debug!("Skipping unsupported verification method of {did}: {vm}");
+            continue;
+        };
+        if key_agreement.contains(&vm.id) && !signing.contains(&vm.id) {
+            debug!("Skipping key agreement method {} of {did}", vm.id);
+            continue;
+        }
+        if verification_method.iter().all(|known| known.id != vm.id) {
+            verification_method.push(vm);
+        }
+    }
+
+    let context = doc
+        .get("@context")
+        .cloned()
+        .and_then(|c| serde_json::from_value(c).ok())
+        .unwrap_or_else(|| StringOrArr::Arr(vec!["https://www.w3.org/ns/did/v1".to_string()]));
+    let service: Option<Vec<DidService>> = doc
+        .get("service")
+        .cloned()
+        .and_then(|s| serde_json::from_value(s).ok());
+
+    Ok(DidDocument {
+        context,
+        id: did.to_string(),
+        controller: None,
+        also_known_as: None,
+        service,
+        verification_method,
+        authentication: None,
+        assertion_method: None,
+        key_agreement: None,
+        capability_invocation: None,
+        capability_delegation: None,
+    })
+}
+
+/// Keeps the key material ymir can consume, whatever the declared method type.
+fn into_verification_method(did: &str, vm: &Value) -> Option<VerificationMethod> {
+    let id = absolute_id(did, vm.get("id")?.as_str()?);
+    let material = if let Some(jwk) = vm.get("publicKeyJwk") {
+        VerificationMaterial::JsonWebKey2020 {
+            public_key_jwk: jwk.clone(),
+        }
+    } else {
+        VerificationMaterial::Multikey {
+            public_key_multibase: vm.get("publicKeyMultibase")?.as_str()?.to_string(),
+        }
+    };
+    Some(VerificationMethod {
+        id,
+        controller: vm
+            .get("controller")
+            .and_then(Value::as_str)
+            .unwrap_or(did)
+            .to_string(),
+        material,
+        expires: None,
+        revoked: None,
+    })
+}
+
+/// Expands a relative `#fragment` reference against `did`.
+fn absolute_id(did: &str, id: &str) -> String {
+    match id.strip_prefix('#') {
+        Some(frag) => format!("{did}#{frag}"),
+        None => id.to_string(),
+    }
+}
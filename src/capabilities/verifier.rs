@@ -15,18 +15,22 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use super::Kid;
+use std::time::Duration;
+
+use super::{Did, DidResolver, Kid};
 use crate::errors::{BadFormat, Errors, Outcome};
-use crate::types::crypto::{Canon, Proof};
-use crate::types::jwt::Jwt;
-use crate::types::keys::Alg;
+use crate::types::crypto::Proof;
+use crate::types::jwt::{Jwt, KB_JWT_TYP, KbJwtClaims, SdJwt};
+use crate::types::keys::PublicKey;
+use crate::utils::{encode_url_safe_no_pad, is_active_with};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
 /// Centralized Cryptographic Verification Engine validating asset authenticity.
 ///
 /// Processes incoming data boundaries by resolving internal key material anchors
-/// and evaluating structural correctness of embedded proofs or enveloped network tokens.
+/// and evaluating structural correctness of embedded proofs or enveloped network tokens. Signer
+/// DIDs are resolved through the [`DidResolver`] each routine is handed.
 pub struct Verifier;
 
 impl Verifier {
@@ -37,7 +41,7 @@ impl Verifier {
     /// # Errors
     /// Returns an [`Errors::FormatError`] if the data object lacks valid structural proofs or
     /// if any single evaluated data cryptographic signature step encounters mathematical verification mismatches.
-    pub async fn verify_embed(value: &Value) -> Outcome<()> {
+    pub async fn verify_embed(resolver: &DidResolver, value: &Value) -> Outcome<()> {
         let mut value = value.clone();
         let proof_value = value
             .as_object_mut()
@@ -46,27 +50,51 @@ impl Verifier {
 
         let proofs: Vec<Proof> = serde_json::from_value(proof_value)?;
 
-        let canonical = Canon::try_from(&value)?;
         for proof in proofs {
-            Self::verify_single_proof(&canonical, &proof).await?;
+            let key = Kid::parse(&proof.verification_method)?
+                .get_key(resolver)
+                .await?;
+            proof.verify(&key, &value).await?;
         }
         Ok(())
     }
 
-    /// Isolated logical runner verifying an individual extracted W3C structural [`Proof`] instance.
-    async fn verify_single_proof(value: &Canon, proof: &Proof) -> Outcome<()> {
-        let kid = Kid::parse(&proof.verification_method)?;
-        let alg = Alg::from_cryptosuite(&proof.cryptosuite);
-        let key = kid.get_key().await?;
+    /// Verifies the single Data Integrity proof of a JSON-LD document (`ldp_vc`, `ldp_vp`).
+    ///
+    /// Returns the document without its proof, the proof and the [`Kid`] of its verification
+    /// method. Checking `proofPurpose`, `challenge` and `domain` is left to the caller.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`] when the document holds no single proof, and any
+    /// signature or cryptosuite error raised while checking it.
+    pub async fn verify_data_integrity(
+        resolver: &DidResolver,
+        document: &Value,
+    ) -> Outcome<(Value, Proof, Kid)> {
+        let mut unsecured = document.clone();
+        let proof_value = unsecured
+            .as_object_mut()
+            .and_then(|obj| obj.remove("proof"))
+            .ok_or_else(|| Errors::format(BadFormat::Received, "Missing proof", None))?;
+        let proof: Proof = match proof_value {
+            Value::Array(mut proofs) if proofs.len() == 1 => {
+                serde_json::from_value(proofs.remove(0))?
+            }
+            Value::Array(_) => {
+                return Err(Errors::format(
+                    BadFormat::Received,
+                    "Exactly one proof is expected",
+                    None,
+                ));
+            }
+            proof => serde_json::from_value(proof)?,
+        };
 
-        let b58 = proof.proof_value.strip_prefix('z').ok_or_else(|| {
-            Errors::parse("proofValue must start with 'z' (multibase base58btc)", None)
-        })?;
-        let sig = bs58::decode(b58)
-            .into_vec()
-            .map_err(|e| Errors::parse("base58 decode of proofValue failed", Some(Box::new(e))))?;
+        let kid = Kid::parse(&proof.verification_method)?;
+        let key = kid.get_key(resolver).await?;
+        proof.verify(&key, &unsecured).await?;
 
-        key.verify_bytes(value.as_ref(), &sig, &alg)
+        Ok((unsecured, proof, kid))
     }
 
     // ===== ENVELOPED JWT VALIDATION ==============================================================
@@ -79,29 +107,131 @@ impl Verifier {
     /// Returns an [`Errors::FormatError`] if verification bounds break or if the token's structural
     /// target `"aud"` vector claims fail to match the expected parameter constraint layout.
     pub async fn verify_enveloped<T: DeserializeOwned>(
+        resolver: &DidResolver,
         jwt: &Jwt,
         expected_aud: Option<&str>,
+    ) -> Outcome<(Kid, T)> {
+        Self::verify_enveloped_for(resolver, jwt, expected_aud.as_slice()).await
+    }
+
+    /// Same as [`Self::verify_enveloped`], accepting a token bound to any of `accepted_auds`.
+    ///
+    /// An empty slice skips the audience check.
+    pub async fn verify_enveloped_for<T: DeserializeOwned>(
+        resolver: &DidResolver,
+        jwt: &Jwt,
+        accepted_auds: &[&str],
     ) -> Outcome<(Kid, T)> {
         let kid = Kid::parse(&jwt.header().kid)?;
-        let key = kid.get_key().await?;
+        let key = kid.get_key(resolver).await?;
+        let payload = Self::verify_with_key(jwt, &key, accepted_auds)?;
+        Ok((kid, payload))
+    }
+
+    /// Verifies `jwt` against an already known `key`, whatever its header `kid` claims.
+    fn verify_with_key<T: DeserializeOwned>(
+        jwt: &Jwt,
+        key: &PublicKey,
+        accepted_auds: &[&str],
+    ) -> Outcome<T> {
         key.verify_bytes(jwt.signing_input(), jwt.signature(), &jwt.header().alg)?;
 
         let value_payload: Value = jwt.unsafe_claims()?;
-        if let Some(expected) = expected_aud {
+        if !accepted_auds.is_empty() {
             let matches = match &value_payload["aud"] {
-                Value::String(s) => s == expected,
-                Value::Array(arr) => arr.iter().any(|v| v.as_str() == Some(expected)),
+                Value::String(s) => accepted_auds.contains(&s.as_str()),
+                Value::Array(arr) => arr
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .any(|aud| accepted_auds.contains(&aud)),
                 _ => false,
             };
             if !matches {
                 return Err(Errors::format(
                     BadFormat::Received,
-                    format!("audience mismatch: expected '{expected}'"),
+                    format!("audience mismatch: expected one of {accepted_auds:?}"),
                     None,
                 ));
             }
         }
-        let payload: T = serde_json::from_value(value_payload)?;
-        Ok((kid, payload))
+        Ok(serde_json::from_value(value_payload)?)
+    }
+
+    // ===== SELECTIVE DISCLOSURE JWT VALIDATION ===================================================
+
+    /// Verifies an SD-JWT VC presentation together with its mandatory Key Binding JWT.
+    ///
+    /// The issuer signature is checked first, then every disclosure is resolved against the
+    /// `_sd` digests. The KB-JWT must be signed by the holder key bound in `cnf`, be issued no
+    /// later than `leeway` from now and carry one of the accepted `aud`s, the expected `nonce`
+    /// and an `sd_hash` over the presented SD-JWT. Returns the issuer [`Kid`], the holder
+    /// [`Did`] and the payload with all disclosed claims in place.
+    ///
+    /// A key bound by value in `cnf.jwk` stands for the holder `did:jwk` of that key.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`] on any signature, disclosure or binding mismatch, and an
+    /// [`Errors::SecurityError`] when the KB-JWT nonce or `sd_hash` does not match.
+    pub async fn verify_sd_jwt(
+        resolver: &DidResolver,
+        sd_jwt: &SdJwt,
+        accepted_auds: &[&str],
+        expected_nonce: &str,
+        leeway: Duration,
+    ) -> Outcome<(Kid, Did, Value)> {
+        let (issuer_kid, _) = Self::verify_enveloped::<Value>(resolver, sd_jwt.jwt(), None).await?;
+        let claims = sd_jwt.disclosed_claims()?;
+
+        let kb_jwt = sd_jwt.kb_jwt().ok_or_else(|| {
+            Errors::format(BadFormat::Received, "Key Binding JWT is missing", None)
+        })?;
+        if kb_jwt.header().typ.as_deref() != Some(KB_JWT_TYP) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Key Binding JWT typ must be '{KB_JWT_TYP}'"),
+                None,
+            ));
+        }
+
+        // The bound key itself must have signed, not just any key of the holder DID.
+        let (holder, holder_key) = Self::holder_binding(resolver, &claims["cnf"]).await?;
+        let kb_claims: KbJwtClaims = Self::verify_with_key(kb_jwt, &holder_key, accepted_auds)
+            .map_err(|e| {
+                Errors::format(
+                    BadFormat::Received,
+                    "Key Binding JWT is not signed by the bound holder key",
+                    Some(Box::new(e)),
+                )
+            })?;
+
+        if kb_claims.nonce != expected_nonce {
+            return Err(Errors::security("KB-JWT nonce mismatch", None));
+        }
+        if kb_claims.sd_hash != sd_jwt.sd_hash() {
+            return Err(Errors::security("KB-JWT sd_hash mismatch", None));
+        }
+        is_active_with(kb_claims.iat, leeway)?;
+
+        Ok((issuer_kid, holder, claims))
+    }
+
+    /// Resolves the holder DID and key bound in an SD-JWT `cnf` claim.
+    async fn holder_binding(resolver: &DidResolver, cnf: &Value) -> Outcome<(Did, PublicKey)> {
+        if let Some(kid) = cnf["kid"].as_str() {
+            let kid = Kid::parse(kid)?;
+            let key = kid.get_key(resolver).await?;
+            return Ok((kid.did().to_owned(), key));
+        }
+        if cnf["jwk"].is_object() {
+            let key = PublicKey::parse_from_jwk(&cnf["jwk"])?;
+            let jwk = serde_json::to_vec(&key.public_jwk())?;
+            let did = Did::parse(&format!("did:jwk:{}", encode_url_safe_no_pad(jwk)))?;
+            return Ok((did, key));
+        }
+        Err(Errors::format(
+            BadFormat::Received,
+            "SD-JWT has neither a cnf.kid nor a cnf.jwk holder binding",
+            None,
+        ))
     }
 }
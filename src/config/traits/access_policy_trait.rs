@@ -0,0 +1,55 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use crate::config::types::{AccessPolicy, AccessPolicyConfig};
+
+/// Shared behavior for configurations carrying the GNAP access policies.
+pub trait AccessPolicyConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root access policy configuration model.
+    fn access_policy_config(&self) -> &AccessPolicyConfig;
+
+    // ===== ACCESS POLICIES =======================================================================
+
+    /// Returns the access granted to GNAP tokens per route group.
+    fn get_access_policies(&self) -> &[AccessPolicy] {
+        &self.access_policy_config().policies
+    }
+
+    /// Returns the policy of the route group `path` belongs to, the most specific one winning.
+    fn get_access_policy_for(&self, path: &str) -> Option<&AccessPolicy> {
+        self.get_access_policies()
+            .iter()
+            .filter(|policy| policy.covers(path))
+            .max_by_key(|policy| policy.group.len())
+    }
+
+    /// Returns how long issued access tokens stay valid, `None` when they never expire.
+    fn get_access_token_ttl(&self) -> Option<Duration> {
+        self.access_policy_config()
+            .token_ttl_secs
+            .map(Duration::from_secs)
+    }
+
+    /// Checks whether bearer tokens may be issued and used without a proof of possession.
+    fn allows_bearer_tokens(&self) -> bool {
+        self.access_policy_config().allow_bearer_tokens
+    }
+}
@@ -0,0 +1,70 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use crate::config::types::ArchiveConfig;
+
+/// Shared behavior for configurations carrying the archival settings.
+pub trait ArchiveConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root archive configuration model.
+    fn archive_config(&self) -> &ArchiveConfig;
+
+    // ===== ARCHIVE SETTINGS ======================================================================
+
+    /// Returns the base URL of the object storage, without trailing slash.
+    fn get_archive_endpoint(&self) -> &str {
+        self.archive_config().endpoint.trim_end_matches('/')
+    }
+
+    /// Returns the bucket receiving the bundles.
+    fn get_archive_bucket(&self) -> &str {
+        &self.archive_config().bucket
+    }
+
+    /// Returns the signing region of the object storage.
+    fn get_archive_region(&self) -> &str {
+        &self.archive_config().region
+    }
+
+    /// Returns the key prefix of every bundle, without surrounding slashes.
+    fn get_archive_prefix(&self) -> &str {
+        self.archive_config().prefix.trim_matches('/')
+    }
+
+    /// Returns the vault path of the object storage credentials.
+    fn get_archive_secrets_path(&self) -> &str {
+        &self.archive_config().secrets_path
+    }
+
+    /// Returns how long a finished flow stays in the database before being archived.
+    fn get_archive_retention(&self) -> Duration {
+        Duration::from_secs(self.archive_config().retention_days * 24 * 3600)
+    }
+
+    /// Returns the delay between two archival rounds.
+    fn get_archive_interval(&self) -> Duration {
+        Duration::from_secs(self.archive_config().interval_secs.max(60))
+    }
+
+    /// Returns the maximum number of records per bundle.
+    fn get_archive_batch_size(&self) -> u64 {
+        self.archive_config().batch_size.max(1)
+    }
+}
@@ -0,0 +1,50 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use crate::config::types::{ClaimMapping, ClaimSourceConfig, ClaimSourcesConfig};
+use crate::types::vcs::VcType;
+
+/// Shared behavior for configurations carrying credential claim sources.
+pub trait ClaimSourcesConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root claim sources configuration model.
+    fn claim_sources_config(&self) -> &ClaimSourcesConfig;
+
+    // ===== SOURCES & MAPPINGS ====================================================================
+
+    /// Lists the configured claim sources.
+    fn get_claim_sources(&self) -> &[ClaimSourceConfig] {
+        &self.claim_sources_config().sources
+    }
+
+    /// Lists the subject fields of `vc_type` filled from a claim source, in declaration order.
+    fn get_claim_mappings(&self, vc_type: &VcType) -> Vec<&ClaimMapping> {
+        self.claim_sources_config()
+            .mappings
+            .iter()
+            .filter(|mapping| &mapping.vc_type == vc_type)
+            .collect()
+    }
+
+    /// Returns how long a fetched record is reused.
+    fn get_claim_cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.claim_sources_config().cache_ttl_secs)
+    }
+}
@@ -0,0 +1,58 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use crate::config::types::CleanupConfig;
+use crate::types::cleanup::CleanupTable;
+
+/// Shared behavior for configurations carrying the cleanup settings.
+pub trait CleanupConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root cleanup configuration model.
+    fn cleanup_config(&self) -> &CleanupConfig;
+
+    // ===== CLEANUP SETTINGS ======================================================================
+
+    /// Returns how long finished records of `table` are kept, or `None` to keep them forever.
+    fn get_retention(&self, table: CleanupTable) -> Option<Duration> {
+        let config = self.cleanup_config();
+        let days = match table {
+            CleanupTable::RecvGrants => config.recv_grants_days,
+            CleanupTable::SentGrants => config.sent_grants_days,
+            CleanupTable::Issuances => config.issuances_days,
+            CleanupTable::Verifications => config.verifications_days,
+        };
+        days.map(|days| Duration::from_secs(days * 24 * 3600))
+    }
+
+    /// Returns the delay between two cleanup rounds.
+    fn get_cleanup_interval(&self) -> Duration {
+        Duration::from_secs(self.cleanup_config().interval_secs.max(60))
+    }
+
+    /// Returns how long a grant may stay unfinished before cleanup closes it.
+    fn get_grant_ttl(&self) -> Duration {
+        Duration::from_secs(self.cleanup_config().grant_ttl_secs)
+    }
+
+    /// Returns the maximum number of records deleted at once.
+    fn get_cleanup_batch_size(&self) -> u64 {
+        self.cleanup_config().batch_size.max(1)
+    }
+}
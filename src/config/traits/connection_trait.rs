@@ -15,7 +15,12 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::net::IpAddr;
+
+use axum::http::HeaderMap;
+
 use crate::config::types::ConnectionConfig;
+use crate::utils::forwarded_base_url;
 
 /// Shared behavior for structural configurations managing deployment flags and operational flags.
 pub trait ConnectionConfigTrait {
@@ -41,4 +46,34 @@ pub trait ConnectionConfigTrait {
     fn has_tls_proxy(&self) -> bool {
         self.connection().has_tls_proxy
     }
+
+    /// Evaluates whether proxy-supplied forwarding headers may be trusted to build public URIs.
+    fn trust_forwarded_headers(&self) -> bool {
+        self.connection().trust_forwarded_headers
+    }
+
+    /// Evaluates whether `peer` is a proxy whose forwarding headers may be trusted.
+    ///
+    /// An unknown peer is only trusted when no explicit proxy list is configured.
+    fn trusts_forwarding_peer(&self, peer: Option<IpAddr>) -> bool {
+        let proxies = &self.connection().trusted_proxies;
+        self.trust_forwarded_headers()
+            && (proxies.is_empty() || peer.is_some_and(|ip| proxies.contains(&ip)))
+    }
+
+    /// Recovers the public base URL announced by `peer`, ignored unless the peer is trusted.
+    fn forwarded_base_from(&self, headers: &HeaderMap, peer: Option<IpAddr>) -> Option<String> {
+        match self.trusts_forwarding_peer(peer) {
+            true => forwarded_base_url(headers),
+            false => None,
+        }
+    }
+
+    /// Recovers the simulated clock skew for generated tokens, always disabled in production.
+    fn simulated_clock_skew(&self) -> Option<i64> {
+        match self.is_prod() {
+            true => None,
+            false => self.connection().simulated_clock_skew,
+        }
+    }
 }
@@ -17,6 +17,9 @@
 
 use crate::config::types::{DatabaseConfig, DbType};
 use crate::types::secrets::DbSecrets;
+use log::LevelFilter;
+use sea_orm::ConnectOptions;
+use std::time::Duration;
 
 /// Shared behavior for component configurations provisioning structural database connections.
 pub trait DatabaseConfigTrait {
@@ -30,19 +33,75 @@ pub trait DatabaseConfigTrait {
     /// Assembles the complete canonical connection string injected into data mapping layers (e.g., Sea-ORM).
     ///
     /// Automatically isolates volatile properties like passwords using runtime decoupled [`DbSecrets`].
+    ///
+    /// Embedded engines ignore the credentials, see [`Self::get_embedded_db_url`].
     fn get_full_db_url(&self, db_secrets: &DbSecrets) -> String {
+        if let Some(url) = self.get_embedded_db_url() {
+            return url;
+        }
+        let db_config = self.db();
+        format!(
+            "{}://{}:{}@{}:{}/{}",
+            db_config.db_type,
+            db_secrets.user,
+            db_secrets.password,
+            db_config.url,
+            db_config.port,
+            db_secrets.name
+        )
+    }
+
+    /// Returns the connection string of the embedded SQLite engines, which need no credentials.
+    ///
+    /// `url` is taken as the database file path (created when missing) unless it already is a
+    /// `sqlite:` connection string. [`DbType::Memory`] opens a private in-memory database that
+    /// lives as long as the process, for the callers asking for a connection anyway:
+    /// [`build_repos`](crate::services::repo::build_repos) keeps its rows in plain maps.
+    fn get_embedded_db_url(&self) -> Option<String> {
         let db_config = self.db();
         match db_config.db_type {
-            DbType::Memory => ":memory:".to_string(),
-            _ => format!(
-                "{}://{}:{}@{}:{}/{}",
-                db_config.db_type,
-                db_secrets.user,
-                db_secrets.password,
-                db_config.url,
-                db_config.port,
-                db_secrets.name
-            ),
+            DbType::Sqlite if db_config.url.starts_with("sqlite:") => Some(db_config.url.clone()),
+            DbType::Sqlite => Some(format!("sqlite://{}?mode=rwc", db_config.url)),
+            DbType::Memory => Some("sqlite::memory:".to_string()),
+            _ => None,
+        }
+    }
+
+    // ===== POOL TUNING ===========================================================================
+
+    /// Wraps `url` in the pool, timeout and logging settings of the configuration.
+    ///
+    /// Unset values keep the driver defaults. With a slow query threshold, statements are
+    /// logged at `debug` and only the slow ones at `warn`. The statement timeout is a Postgres session
+    /// setting, so the embedded SQLite engines ignore it.
+    fn get_connect_options(&self, url: String) -> ConnectOptions {
+        let db_config = self.db();
+        let mut options = ConnectOptions::new(url);
+        if let Some(max) = db_config.max_connections {
+            options.max_connections(max);
+        }
+        if let Some(min) = db_config.min_connections {
+            options.min_connections(min);
+        }
+        if let Some(secs) = db_config.acquire_timeout_secs {
+            options.acquire_timeout(Duration::from_secs(secs));
+        }
+        if let Some(secs) = db_config.idle_timeout_secs {
+            options.idle_timeout(Duration::from_secs(secs));
+        }
+        if let Some(ms) = db_config.statement_timeout_ms {
+            options.map_sqlx_postgres_opts(move |pg| {
+                pg.options([("statement_timeout", format!("{}ms", ms))])
+            });
+        }
+        if let Some(ms) = db_config.slow_query_ms {
+            options
+                .sqlx_logging_level(LevelFilter::Debug)
+                .sqlx_slow_statements_logging_settings(
+                    LevelFilter::Warn,
+                    Duration::from_millis(ms),
+                );
         }
+        options
     }
 }
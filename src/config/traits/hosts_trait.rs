@@ -17,6 +17,7 @@
 
 use crate::config::types::{CommonHostsConfig, HostConfig, HostType};
 use crate::errors::Errors;
+use crate::types::http::current_forwarded_base;
 
 /// Shared behavior for component matrices managing multi-transport endpoints.
 pub trait HostsConfigTrait {
@@ -47,6 +48,22 @@ pub trait HostsConfigTrait {
         self.get_helper(host_type).get_host()
     }
 
+    /// Resolves the externally visible base URL used in every generated URI.
+    ///
+    /// Precedence is the configured canonical `public_url`, then the base URL a trusted proxy
+    /// announced for the current request (see `forwarded_base_middleware`), and finally the
+    /// configured host.
+    fn get_external_host(&self, host_type: HostType) -> String {
+        let host = self.get_helper(host_type);
+        if host.public_url.is_some() {
+            return host.get_host();
+        }
+        match host_type {
+            HostType::Http => current_forwarded_base().unwrap_or_else(|| host.get_host()),
+            _ => host.get_host(),
+        }
+    }
+
     /// Computes the internal boundary URI matching specialized network parameters.
     fn get_internal_host(&self, host_type: HostType) -> String {
         self.get_helper(host_type).get_internal_host()
@@ -96,7 +113,12 @@ pub trait SingleHostTrait {
     // ===== METRIC COMPUTATION ENGINE =============================================================
 
     /// Resolves the complete outward facing web service address layout.
+    ///
+    /// A configured canonical `public_url` always wins over the protocol/url/port triple.
     fn get_host(&self) -> String {
+        if let Some(public_url) = self.host().public_url.as_ref() {
+            return public_url.trim_end_matches('/').to_string();
+        }
         match self.host().port.as_ref() {
             Some(port) => format!("{}://{}:{}", self.host().protocol, self.host().url, port),
             None => format!("{}://{}", self.host().protocol, self.host().url),
@@ -0,0 +1,58 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use crate::config::types::KeyRotationConfig;
+use crate::types::keys::KeyGenSpec;
+
+/// Shared behavior for configurations carrying the signing key rotation settings.
+pub trait KeyRotationConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root key rotation configuration model.
+    fn key_rotation_config(&self) -> &KeyRotationConfig;
+
+    // ===== ROTATION SETTINGS =====================================================================
+
+    /// Returns the key pair generated on every rotation.
+    fn get_rotation_key_spec(&self) -> &KeyGenSpec {
+        &self.key_rotation_config().key
+    }
+
+    /// Returns the age at which the scheduler rotates the signing key, if it does.
+    fn get_max_key_age(&self) -> Option<Duration> {
+        self.key_rotation_config()
+            .max_age_days
+            .map(|days| Duration::from_secs(days * 24 * 3600))
+    }
+
+    /// Returns how long the previous key stays published after a rotation.
+    fn get_rotation_grace_period(&self) -> Duration {
+        Duration::from_secs(self.key_rotation_config().grace_period_secs)
+    }
+
+    /// Returns the delay between two scheduler rounds.
+    fn get_rotation_check_interval(&self) -> Duration {
+        Duration::from_secs(self.key_rotation_config().check_interval_secs.max(60))
+    }
+
+    /// Returns the vault path the rotation state is kept at.
+    fn get_rotation_state_path(&self) -> &str {
+        &self.key_rotation_config().state_path
+    }
+}
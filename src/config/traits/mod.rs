@@ -15,18 +15,46 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod access_policy_trait;
 mod api_trait;
+mod archive_trait;
+mod claim_sources_trait;
+mod cleanup_trait;
 mod connection_trait;
 mod db_trait;
 mod did_trait;
 mod hosts_trait;
+mod key_rotation_trait;
+mod mtls_trait;
+mod proxy_trait;
+mod quota_trait;
+mod revocation_feed_trait;
+mod standby_trait;
+mod templates_trait;
+mod trust_anchor_trait;
+mod universal_resolver_trait;
+mod vault_trait;
 mod verify_req_trait;
 mod wallet_trait;
 
+pub use access_policy_trait::AccessPolicyConfigTrait;
 pub use api_trait::ApiConfigTrait;
+pub use archive_trait::ArchiveConfigTrait;
+pub use claim_sources_trait::ClaimSourcesConfigTrait;
+pub use cleanup_trait::CleanupConfigTrait;
 pub use connection_trait::ConnectionConfigTrait;
 pub use db_trait::DatabaseConfigTrait;
 pub use did_trait::DidConfigTrait;
 pub use hosts_trait::{HostsConfigTrait, SingleHostTrait};
+pub use key_rotation_trait::KeyRotationConfigTrait;
+pub use mtls_trait::MtlsConfigTrait;
+pub use proxy_trait::ProxyConfigTrait;
+pub use quota_trait::QuotaConfigTrait;
+pub use revocation_feed_trait::RevocationFeedConfigTrait;
+pub use standby_trait::StandbyConfigTrait;
+pub use templates_trait::TemplatesConfigTrait;
+pub use trust_anchor_trait::TrustAnchorConfigTrait;
+pub use universal_resolver_trait::UniversalResolverConfigTrait;
+pub use vault_trait::VaultConfigTrait;
 pub use verify_req_trait::VerifyReqConfigTrait;
 pub use wallet_trait::WalletConfigTrait;
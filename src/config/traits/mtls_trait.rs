@@ -0,0 +1,33 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::config::types::{MtlsConfig, MtlsDestination};
+
+/// Shared behavior for configurations carrying outbound mutual TLS settings.
+pub trait MtlsConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root mTLS configuration model.
+    fn mtls_config(&self) -> &MtlsConfig;
+
+    // ===== DESTINATION SETTINGS ==================================================================
+
+    /// Lists the destinations reached with their own TLS settings.
+    fn get_mtls_destinations(&self) -> &[MtlsDestination] {
+        &self.mtls_config().destinations
+    }
+}
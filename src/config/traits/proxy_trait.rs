@@ -0,0 +1,38 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::config::types::ProxyConfig;
+
+/// Shared behavior for configurations carrying an outbound proxy.
+pub trait ProxyConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root proxy configuration model.
+    fn proxy_config(&self) -> &ProxyConfig;
+
+    // ===== PROXY SETTINGS ========================================================================
+
+    /// Recovers the proxy URL outbound requests go through, if any.
+    fn get_proxy_url(&self) -> Option<&str> {
+        self.proxy_config().url.as_deref()
+    }
+
+    /// Lists the hosts reached without the proxy.
+    fn get_no_proxy(&self) -> &[String] {
+        &self.proxy_config().no_proxy
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::config::types::{QuotaConfig, QuotaLimit};
+use crate::types::quota::QuotaKind;
+
+/// Shared behavior for configurations carrying per-participant quotas.
+pub trait QuotaConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root quota configuration model.
+    fn quota_config(&self) -> &QuotaConfig;
+
+    // ===== LIMITS ================================================================================
+
+    /// Returns the limit applied to `kind`, or `None` when the operation is unmetered.
+    fn get_quota(&self, kind: QuotaKind) -> Option<QuotaLimit> {
+        let config = self.quota_config();
+        match kind {
+            QuotaKind::Issuance => config.issuance,
+            QuotaKind::Verification => config.verification,
+        }
+        .filter(|limit| limit.window_secs > 0)
+    }
+}
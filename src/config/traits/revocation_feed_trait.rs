@@ -0,0 +1,50 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use crate::config::types::{RevocationFeedConfig, RevocationFeedSource};
+
+/// Shared behavior for configurations carrying the revocation feed settings.
+pub trait RevocationFeedConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root revocation feed configuration model.
+    fn revocation_feed_config(&self) -> &RevocationFeedConfig;
+
+    // ===== FEED SETTINGS =========================================================================
+
+    /// Returns the authority feed to follow, if any.
+    fn get_feed_source(&self) -> Option<&RevocationFeedSource> {
+        self.revocation_feed_config().source.as_ref()
+    }
+
+    /// Returns the delay between two polls of the feed.
+    fn get_poll_interval(&self) -> Duration {
+        Duration::from_secs(self.revocation_feed_config().poll_interval_secs)
+    }
+
+    /// Returns the maximum number of events per page.
+    fn get_feed_page_size(&self) -> u64 {
+        self.revocation_feed_config().page_size.max(1)
+    }
+
+    /// Returns the vault path the subscriber cursor is kept at.
+    fn get_feed_cursor_path(&self) -> &str {
+        &self.revocation_feed_config().cursor_path
+    }
+}
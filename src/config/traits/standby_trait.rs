@@ -0,0 +1,46 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use crate::config::types::StandbyConfig;
+use crate::types::standby::InstanceRole;
+
+/// Shared behavior for configurations carrying the active/standby settings.
+pub trait StandbyConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root standby configuration model.
+    fn standby_config(&self) -> &StandbyConfig;
+
+    // ===== STANDBY SETTINGS ======================================================================
+
+    /// Returns the role taken at startup.
+    fn get_startup_role(&self) -> InstanceRole {
+        self.standby_config().role
+    }
+
+    /// Returns the identifier of this instance.
+    fn get_instance_id(&self) -> &str {
+        &self.standby_config().instance_id
+    }
+
+    /// Returns the delay between two state handoffs.
+    fn get_handoff_interval(&self) -> Duration {
+        Duration::from_secs(self.standby_config().handoff_interval_secs.max(1))
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::config::types::TemplatesConfig;
+use crate::types::vcs::VcType;
+use crate::types::vcs::vc_specs::template::CredentialTemplate;
+
+/// Shared behavior for configurations carrying credential subject templates.
+pub trait TemplatesConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root template configuration model.
+    fn templates_config(&self) -> &TemplatesConfig;
+
+    // ===== TEMPLATE LOOKUP =======================================================================
+
+    /// Finds the configured template bound to a credential type, if any.
+    fn get_template(&self, vc_type: &VcType) -> Option<&CredentialTemplate> {
+        self.templates_config()
+            .templates
+            .iter()
+            .find(|template| &template.vc_type == vc_type)
+    }
+}
@@ -0,0 +1,40 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use crate::config::types::{TrustAnchorConfig, TrustRegistry};
+
+/// Shared behavior for configurations carrying external trust anchors.
+pub trait TrustAnchorConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root trust anchor configuration model.
+    fn trust_anchor_config(&self) -> &TrustAnchorConfig;
+
+    // ===== SYNC SETTINGS =========================================================================
+
+    /// Lists the external registries to pull trusted issuers from.
+    fn get_registries(&self) -> &[TrustRegistry] {
+        &self.trust_anchor_config().registries
+    }
+
+    /// Returns the delay between two sync rounds.
+    fn get_sync_interval(&self) -> Duration {
+        Duration::from_secs(self.trust_anchor_config().sync_interval_secs)
+    }
+}
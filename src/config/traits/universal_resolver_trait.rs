@@ -0,0 +1,44 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use crate::config::types::UniversalResolverConfig;
+
+/// Shared behavior for configurations carrying the Universal Resolver fallback settings.
+pub trait UniversalResolverConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root Universal Resolver configuration model.
+    fn universal_resolver_config(&self) -> &UniversalResolverConfig;
+
+    // ===== RESOLVER SETTINGS =====================================================================
+
+    /// Returns the resolver base URL without trailing slash, if the fallback is enabled.
+    fn get_universal_resolver_url(&self) -> Option<&str> {
+        self.universal_resolver_config()
+            .url
+            .as_deref()
+            .map(|url| url.trim_end_matches('/'))
+            .filter(|url| !url.is_empty())
+    }
+
+    /// Returns how long a resolved document is served from cache.
+    fn get_resolver_cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.universal_resolver_config().cache_ttl_secs)
+    }
+}
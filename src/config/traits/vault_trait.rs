@@ -0,0 +1,48 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::config::types::{VaultAuth, VaultConfig, VaultRetries};
+use crate::types::keys::KeyGenSpec;
+
+/// Shared behavior for configurations carrying the HashiCorp Vault client settings.
+pub trait VaultConfigTrait {
+    // ===== EXTRACTION ANCHORS ====================================================================
+
+    /// Returns a backing reference to the root vault configuration model.
+    fn vault_config(&self) -> &VaultConfig;
+
+    // ===== AUTHENTICATION ========================================================================
+
+    /// Returns the method the node logs into Vault with.
+    fn get_vault_auth(&self) -> &VaultAuth {
+        &self.vault_config().auth
+    }
+
+    // ===== CONNECTION ============================================================================
+
+    /// Returns the retry policy of the calls hitting an unavailable Vault.
+    fn get_vault_retries(&self) -> &VaultRetries {
+        &self.vault_config().retries
+    }
+
+    // ===== KEY BOOTSTRAP =========================================================================
+
+    /// Returns the key pair to generate on first run, if the node bootstraps its own keys.
+    fn get_key_bootstrap(&self) -> Option<&KeyGenSpec> {
+        self.vault_config().bootstrap.as_ref()
+    }
+}
@@ -15,8 +15,15 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use crate::config::types::VerifyReqConfig;
+use std::time::Duration;
+
+use crate::config::types::{StatusCheckMode, VerifyReqConfig};
 use crate::types::vcs::VcType;
+use crate::types::verification::input_descriptor::DescriptorConstraint;
+use crate::types::verification::{
+    AudienceTemplate, QueryLanguage, RedactionProfile, ResponseEncryption, ResponseMode, VpFormat,
+    VpPolicy,
+};
 
 /// Shared behavior for evaluation contexts demanding data space verification checks.
 pub trait VerifyReqConfigTrait {
@@ -37,8 +44,117 @@ pub trait VerifyReqConfigTrait {
         self.verify_req_config().auto_approve_cert
     }
 
+    /// Returns the enforcement level for credential status list checks.
+    fn get_status_check(&self) -> StatusCheckMode {
+        self.verify_req_config().status_check
+    }
+
     /// Recovers the precise array of requested Verifiable Credential taxonomy schemas.
     fn get_requested_vcs(&self) -> &[VcType] {
         &self.verify_req_config().vcs_requested
     }
+
+    /// Returns the credential policy, requiring every requested type when none is configured.
+    fn get_policy(&self) -> VpPolicy {
+        self.verify_req_config()
+            .policy
+            .clone()
+            .unwrap_or_else(|| VpPolicy::all_of(self.get_requested_vcs()))
+    }
+
+    /// Returns the Presentation Exchange constraints configured per credential type.
+    fn get_constraints(&self) -> &[DescriptorConstraint] {
+        &self.verify_req_config().constraints
+    }
+
+    /// Returns whether authorization requests carry a Presentation Exchange definition or a DCQL query.
+    fn get_query_language(&self) -> QueryLanguage {
+        self.verify_req_config().query_language
+    }
+
+    /// Returns the presentation formats accepted in Presentation Exchange responses.
+    fn get_vp_formats(&self) -> &[VpFormat] {
+        &self.verify_req_config().vp_formats
+    }
+
+    /// Returns whether `direct_post` authorization responses are encrypted.
+    fn get_response_encryption(&self) -> ResponseEncryption {
+        self.verify_req_config().response_encryption
+    }
+
+    /// Returns the vault path of the static response encryption key, if one is configured.
+    fn get_response_key_path(&self) -> Option<&str> {
+        self.verify_req_config().response_key_path.as_deref()
+    }
+
+    /// Returns how long a verification session waits for the wallet.
+    fn get_session_ttl(&self) -> Duration {
+        Duration::from_secs(self.verify_req_config().session_ttl_secs)
+    }
+
+    /// Returns the clock drift tolerated when checking token and credential validity times.
+    fn get_clock_leeway(&self) -> Duration {
+        Duration::from_secs(self.verify_req_config().clock_leeway_secs)
+    }
+
+    /// Returns the webhooks notified of every verification outcome.
+    fn get_webhooks(&self) -> &[String] {
+        &self.verify_req_config().webhooks
+    }
+
+    /// Returns the redaction profile applied to verified claims before they are stored.
+    fn get_redaction_profile(&self) -> Option<&RedactionProfile> {
+        self.verify_req_config().redaction.as_ref()
+    }
+
+    /// Returns the audiences accepted on top of the session one.
+    fn get_accepted_audiences(&self) -> &[AudienceTemplate] {
+        &self.verify_req_config().accepted_audiences
+    }
+
+    /// Resolves the credential types demanded for a resource/action pair.
+    ///
+    /// Falls back to the global requested set when no profile covers the pair.
+    fn get_requirements_for(&self, resource: Option<&str>, action: Option<&str>) -> &[VcType] {
+        resource
+            .and_then(|resource| {
+                self.verify_req_config()
+                    .requirements
+                    .iter()
+                    .find(|profile| profile.matches(resource, action))
+            })
+            .map(|profile| profile.vcs_requested.as_slice())
+            .unwrap_or_else(|| self.get_requested_vcs())
+    }
+
+    /// Resolves the credential policy for a resource/action pair.
+    ///
+    /// A matching profile requires all of its types; otherwise the global policy applies.
+    fn get_policy_for(&self, resource: Option<&str>, action: Option<&str>) -> VpPolicy {
+        resource
+            .and_then(|resource| {
+                self.verify_req_config()
+                    .requirements
+                    .iter()
+                    .find(|profile| profile.matches(resource, action))
+            })
+            .map(|profile| VpPolicy::all_of(&profile.vcs_requested))
+            .unwrap_or_else(|| self.get_policy())
+    }
+
+    /// Resolves the OpenID4VP response mode for a resource/action pair.
+    ///
+    /// Falls back to the global response mode when no profile covers the pair or the
+    /// matching profile does not set one.
+    fn get_response_mode_for(&self, resource: Option<&str>, action: Option<&str>) -> ResponseMode {
+        resource
+            .and_then(|resource| {
+                self.verify_req_config()
+                    .requirements
+                    .iter()
+                    .find(|profile| profile.matches(resource, action))
+            })
+            .and_then(|profile| profile.response_mode)
+            .unwrap_or(self.verify_req_config().response_mode)
+    }
 }
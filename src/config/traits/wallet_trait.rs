@@ -16,8 +16,8 @@
  */
 
 use crate::config::traits::HostsConfigTrait;
-use crate::config::types::{HostType, WalletConfig};
-use crate::types::wallet::WalletInstance;
+use crate::config::types::{HostType, WalletConfig, WalletProvider};
+use crate::types::wallet::{CredentialSelection, WalletInstance};
 
 /// Shared behavior for component managers overseeing user wallet instance states.
 pub trait WalletConfigTrait {
@@ -37,4 +37,14 @@ pub trait WalletConfigTrait {
     fn get_wallet(&self) -> &WalletInstance {
         &self.wallet_config().wallet
     }
+
+    /// Returns the backend selected to serve the wallet operations.
+    fn get_wallet_provider(&self) -> WalletProvider {
+        self.wallet_config().provider
+    }
+
+    /// Returns the rule picking among several credentials answering the same descriptor.
+    fn get_credential_selection(&self) -> &CredentialSelection {
+        &self.wallet_config().selection
+    }
 }
@@ -0,0 +1,68 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::AccessPolicyConfigTrait;
+use crate::types::gnap::grant_request::access::AccessType;
+use crate::types::gnap::grant_request::interact::InteractAction;
+
+/// Access GNAP tokens are granted and how they must be presented.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct AccessPolicyConfig {
+    /// Access granted per route group. Any requested access is granted if empty.
+    #[serde(default)]
+    pub policies: Vec<AccessPolicy>,
+    /// Seconds issued GNAP access tokens stay valid. Tokens never expire if unset.
+    #[serde(default)]
+    pub token_ttl_secs: Option<u64>,
+    /// Whether clients may ask for bearer tokens, used without an `httpsig` proof.
+    #[serde(default)]
+    pub allow_bearer_tokens: bool,
+}
+
+/// Access a group of routes grants to GNAP access tokens.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AccessPolicy {
+    /// Path prefix of the routes in the group, e.g. `/api/v1/catalog`.
+    pub group: String,
+    /// Access type a token must be requested with to reach the group.
+    pub r#type: AccessType,
+    /// Actions granted over the group. An empty list grants every requested action.
+    #[serde(default)]
+    pub actions: Vec<InteractAction>,
+}
+
+impl AccessPolicy {
+    /// Checks whether `path` belongs to the route group.
+    pub fn covers(&self, path: &str) -> bool {
+        let group = self.group.trim_end_matches('/');
+        path.strip_prefix(group)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+
+    /// Checks whether the group grants `action`.
+    pub fn allows(&self, action: &InteractAction) -> bool {
+        self.actions.is_empty() || self.actions.contains(action)
+    }
+}
+
+impl AccessPolicyConfigTrait for AccessPolicyConfig {
+    fn access_policy_config(&self) -> &AccessPolicyConfig {
+        self
+    }
+}
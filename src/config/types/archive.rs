@@ -0,0 +1,74 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::ArchiveConfigTrait;
+
+/// Export of finished flows to an S3-compatible object storage.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ArchiveConfig {
+    /// Base URL of the object storage, e.g. `https://s3.eu-west-1.amazonaws.com`.
+    pub endpoint: String,
+    /// Bucket receiving the bundles, addressed path-style.
+    pub bucket: String,
+    /// Signing region of the object storage.
+    #[serde(default = "default_region")]
+    pub region: String,
+    /// Key prefix of every bundle.
+    #[serde(default = "default_prefix")]
+    pub prefix: String,
+    /// Vault path of the access key pair.
+    pub secrets_path: String,
+    /// Days a finished flow stays in the database before being archived.
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u64,
+    /// Seconds between two archival rounds.
+    #[serde(default = "default_archive_interval")]
+    pub interval_secs: u64,
+    /// Maximum number of records per bundle.
+    #[serde(default = "default_batch_size")]
+    pub batch_size: u64,
+}
+
+impl ArchiveConfigTrait for ArchiveConfig {
+    fn archive_config(&self) -> &ArchiveConfig {
+        self
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn default_region() -> String {
+    "us-east-1".to_string()
+}
+
+fn default_prefix() -> String {
+    "ymir".to_string()
+}
+
+fn default_retention_days() -> u64 {
+    30
+}
+
+fn default_archive_interval() -> u64 {
+    3600
+}
+
+fn default_batch_size() -> u64 {
+    500
+}
@@ -0,0 +1,118 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::traits::ClaimSourcesConfigTrait;
+use crate::types::vcs::VcType;
+
+/// Authoritative systems credential subject fields are looked up in at issuance.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ClaimSourcesConfig {
+    /// Systems mappings can refer to by name.
+    #[serde(default)]
+    pub sources: Vec<ClaimSourceConfig>,
+    /// Subject fields filled from a source, per credential type.
+    #[serde(default)]
+    pub mappings: Vec<ClaimMapping>,
+    /// Seconds a record fetched for a participant is reused before being fetched again.
+    #[serde(default = "default_cache_ttl")]
+    pub cache_ttl_secs: u64,
+}
+
+/// Single external system holding participant records.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ClaimSourceConfig {
+    /// Name mappings refer to the source by.
+    pub name: String,
+    #[serde(flatten)]
+    pub kind: ClaimSourceKind,
+    /// Vault path of the source credentials, if it requires any.
+    #[serde(default)]
+    pub secrets_path: Option<String>,
+}
+
+/// Protocols claim sources are reached with.
+///
+/// Queries may carry `{{participant.*}}` placeholders, escaped for the protocol before use.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClaimSourceKind {
+    /// JSON API answering `GET url` with the participant record.
+    Rest { url: String },
+    /// Directory searched under `base_dn` with `filter`, which must match a single entry.
+    ///
+    /// Only available with the `ldap` feature.
+    Ldap {
+        url: String,
+        base_dn: String,
+        filter: String,
+        /// DN bound as before searching, with the `password` of the source secrets.
+        #[serde(default)]
+        bind_dn: Option<String>,
+    },
+}
+
+/// Subject field of a credential type filled from a claim source.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ClaimMapping {
+    pub vc_type: VcType,
+    /// Dotted path of the subject field, e.g. `gx:legalAddress.gx:countryCode`.
+    pub field: String,
+    /// Name of the source the value comes from.
+    pub source: String,
+    /// Dotted path of the value in a REST record, or attribute name in an LDAP entry.
+    pub attribute: String,
+    #[serde(default)]
+    pub on_failure: ClaimFailurePolicy,
+}
+
+/// What issuance does when a mapped value cannot be obtained.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaimFailurePolicy {
+    /// Refuse to build the subject.
+    #[default]
+    Fail,
+    /// Leave the field as the template rendered it.
+    Keep,
+    /// Fill the field with a fixed value.
+    Default(Value),
+}
+
+impl Default for ClaimSourcesConfig {
+    fn default() -> Self {
+        Self {
+            sources: Vec::new(),
+            mappings: Vec::new(),
+            cache_ttl_secs: default_cache_ttl(),
+        }
+    }
+}
+
+impl ClaimSourcesConfigTrait for ClaimSourcesConfig {
+    fn claim_sources_config(&self) -> &ClaimSourcesConfig {
+        self
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn default_cache_ttl() -> u64 {
+    300
+}
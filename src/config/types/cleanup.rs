@@ -0,0 +1,68 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::CleanupConfigTrait;
+
+/// Pruning of finished protocol state that is not worth archiving.
+///
+/// Every table keeps its records forever unless a retention is set for it.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct CleanupConfig {
+    /// Seconds between two cleanup rounds.
+    #[serde(default = "default_cleanup_interval")]
+    pub interval_secs: u64,
+    /// Maximum number of records deleted at once.
+    #[serde(default = "default_batch_size")]
+    pub batch_size: u64,
+    /// Seconds a grant may stay unfinished before it is closed and left to age out.
+    #[serde(default = "default_grant_ttl")]
+    pub grant_ttl_secs: u64,
+    /// Days finished received grants and their interactions are kept.
+    #[serde(default)]
+    pub recv_grants_days: Option<u64>,
+    /// Days finished sent grants and their interactions are kept.
+    #[serde(default)]
+    pub sent_grants_days: Option<u64>,
+    /// Days finished issuance sessions are kept.
+    #[serde(default)]
+    pub issuances_days: Option<u64>,
+    /// Days finished verification sessions are kept.
+    #[serde(default)]
+    pub verifications_days: Option<u64>,
+}
+
+impl CleanupConfigTrait for CleanupConfig {
+    fn cleanup_config(&self) -> &CleanupConfig {
+        self
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn default_cleanup_interval() -> u64 {
+    3600
+}
+
+fn default_batch_size() -> u64 {
+    500
+}
+
+fn default_grant_ttl() -> u64 {
+    24 * 3600
+}
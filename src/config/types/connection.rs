@@ -15,6 +15,8 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::net::IpAddr;
+
 use crate::config::traits::ConnectionConfigTrait;
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +29,15 @@ pub struct ConnectionConfig {
     pub is_vault_real: bool,
     /// Flag checking if communication nodes are routed via reverse proxy TLS terminators.
     pub has_tls_proxy: bool,
+    /// Flag allowing `Forwarded`/`X-Forwarded-*` headers to drive external URL detection.
+    #[serde(default)]
+    pub trust_forwarded_headers: bool,
+    /// Peers allowed to send those headers; empty trusts every peer once the flag is set.
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpAddr>,
+    /// Offset in seconds applied to generated token timestamps for interop testing. Non-production only.
+    #[serde(default)]
+    pub simulated_clock_skew: Option<i64>,
 }
 
 impl ConnectionConfigTrait for ConnectionConfig {
@@ -29,8 +29,27 @@ pub struct DatabaseConfig {
     pub db_type: DbType,
     /// Targeted database location domain or network IP.
     pub url: String,
-    /// Ingress connection port vector.
+    /// Ingress connection port vector, unused by the embedded SQLite engines.
+    #[serde(default)]
     pub port: String,
+    /// Upper bound of pooled connections, the driver default when unset.
+    #[serde(default)]
+    pub max_connections: Option<u32>,
+    /// Connections kept open even when idle.
+    #[serde(default)]
+    pub min_connections: Option<u32>,
+    /// Seconds a caller waits for a free pooled connection before failing.
+    #[serde(default)]
+    pub acquire_timeout_secs: Option<u64>,
+    /// Seconds an idle connection stays in the pool before being closed.
+    #[serde(default)]
+    pub idle_timeout_secs: Option<u64>,
+    /// Milliseconds after which Postgres cancels a running statement.
+    #[serde(default)]
+    pub statement_timeout_ms: Option<u64>,
+    /// Milliseconds after which a statement is logged as slow.
+    #[serde(default)]
+    pub slow_query_ms: Option<u64>,
 }
 
 impl DatabaseConfigTrait for DatabaseConfig {
@@ -84,6 +84,16 @@ pub struct DidWebConfig {
     pub path: Option<String>,
     /// Custom network port exposure parameter if overriding default TLS hooks.
     pub port: Option<String>,
+    /// Also publishes the `did:jwk` of the same key and signed statements binding both DIDs.
+    #[serde(default)]
+    pub dual_stack: bool,
+}
+
+impl DidConfig {
+    /// Whether the `did:web` identity is paired with a `did:jwk` of the same key.
+    pub fn is_dual_stack(&self) -> bool {
+        matches!(self, DidConfig::Web { web_config } if web_config.dual_stack)
+    }
 }
 
 impl DidConfigTrait for DidConfig {
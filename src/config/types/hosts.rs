@@ -30,6 +30,9 @@ pub struct HostConfig {
     pub port: Option<String>,
     /// Private cluster deployment container transport mapping boundary.
     pub internal_port: Option<String>,
+    /// Canonical public base URL (scheme, host and path prefix) overriding every generated URI.
+    #[serde(default)]
+    pub public_url: Option<String>,
 }
 
 impl SingleHostTrait for HostConfig {
@@ -0,0 +1,74 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::KeyRotationConfigTrait;
+use crate::types::keys::KeyGenSpec;
+
+/// Rollover of the agent signing key.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct KeyRotationConfig {
+    /// Key pair generated on every rotation.
+    #[serde(default)]
+    pub key: KeyGenSpec,
+    /// Days a signing key is used before the scheduler rotates it. Absent disables
+    /// scheduled rotation, leaving only the admin API.
+    #[serde(default)]
+    pub max_age_days: Option<u64>,
+    /// Seconds the previous key stays published for verification after a rotation.
+    #[serde(default = "default_grace_period")]
+    pub grace_period_secs: u64,
+    /// Seconds between two scheduler rounds.
+    #[serde(default = "default_check_interval")]
+    pub check_interval_secs: u64,
+    /// Vault path of the rotation state.
+    #[serde(default = "default_state_path")]
+    pub state_path: String,
+}
+
+impl Default for KeyRotationConfig {
+    fn default() -> Self {
+        Self {
+            key: KeyGenSpec::default(),
+            max_age_days: None,
+            grace_period_secs: default_grace_period(),
+            check_interval_secs: default_check_interval(),
+            state_path: default_state_path(),
+        }
+    }
+}
+
+impl KeyRotationConfigTrait for KeyRotationConfig {
+    fn key_rotation_config(&self) -> &KeyRotationConfig {
+        self
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn default_grace_period() -> u64 {
+    7 * 24 * 3600
+}
+
+fn default_check_interval() -> u64 {
+    3600
+}
+
+fn default_state_path() -> String {
+    "crypto/rotation".to_string()
+}
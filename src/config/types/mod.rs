@@ -15,18 +15,46 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod access_policy;
 mod api;
+mod archive;
+mod claim_sources;
+mod cleanup;
 mod connection;
 mod db;
 mod dids;
 mod hosts;
+mod key_rotation;
+mod mtls;
+mod proxy;
+mod quota;
+mod revocation_feed;
+mod standby;
+mod templates;
+mod trust_anchor;
+mod universal_resolver;
+mod vault;
 mod verify_req;
 mod wallet;
 
+pub use access_policy::*;
 pub use api::*;
+pub use archive::*;
+pub use claim_sources::*;
+pub use cleanup::*;
 pub use connection::*;
 pub use db::*;
 pub use dids::*;
 pub use hosts::*;
+pub use key_rotation::*;
+pub use mtls::*;
+pub use proxy::*;
+pub use quota::*;
+pub use revocation_feed::*;
+pub use standby::*;
+pub use templates::*;
+pub use trust_anchor::*;
+pub use universal_resolver::*;
+pub use vault::*;
 pub use verify_req::*;
 pub use wallet::*;
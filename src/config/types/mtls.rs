@@ -0,0 +1,58 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::MtlsConfigTrait;
+
+/// Outbound mutual TLS settings, one entry per destination requiring client authentication.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct MtlsConfig {
+    /// Destinations reached with their own TLS settings; any other one uses the default client.
+    #[serde(default)]
+    pub destinations: Vec<MtlsDestination>,
+}
+
+/// TLS settings used to reach a single wallet, authority or peer.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MtlsDestination {
+    /// Origin the settings apply to, e.g. `https://wallet.example.org:8443`.
+    pub origin: String,
+    /// Present the client certificate and key stored in the vault under
+    /// `VAULT_APP_CLIENT_CERT` and `VAULT_APP_CLIENT_KEY`.
+    #[serde(default = "default_client_identity")]
+    pub client_identity: bool,
+    /// PEM bundle of extra CA certificates trusted for this destination, on top of the
+    /// system roots.
+    #[serde(default)]
+    pub ca_bundle: Option<String>,
+    /// Accept any server certificate. Development only: ignored in production.
+    #[serde(default)]
+    pub insecure: bool,
+}
+
+impl MtlsConfigTrait for MtlsConfig {
+    fn mtls_config(&self) -> &MtlsConfig {
+        self
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn default_client_identity() -> bool {
+    true
+}
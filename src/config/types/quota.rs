@@ -0,0 +1,46 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::QuotaConfigTrait;
+
+/// Per-participant allowances protecting the authority from runaway agents.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct QuotaConfig {
+    /// Credentials a participant may be issued per window. Unlimited when unset.
+    #[serde(default)]
+    pub issuance: Option<QuotaLimit>,
+    /// Verification attempts a participant may make per window. Unlimited when unset.
+    #[serde(default)]
+    pub verification: Option<QuotaLimit>,
+}
+
+/// Maximum number of operations allowed within a fixed time window.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuotaLimit {
+    /// Operations allowed per window.
+    pub max: u32,
+    /// Window length in seconds; windows are aligned to the Unix epoch.
+    pub window_secs: u64,
+}
+
+impl QuotaConfigTrait for QuotaConfig {
+    fn quota_config(&self) -> &QuotaConfig {
+        self
+    }
+}
@@ -0,0 +1,77 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::RevocationFeedConfigTrait;
+
+/// Revocation feed published by the authority and followed by participant agents.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct RevocationFeedConfig {
+    /// Authority feed to follow. Empty on the authority itself and on agents not subscribing.
+    #[serde(default)]
+    pub source: Option<RevocationFeedSource>,
+    /// Seconds between two polls of the feed.
+    #[serde(default = "default_poll_interval")]
+    pub poll_interval_secs: u64,
+    /// Maximum number of events served or requested per page.
+    #[serde(default = "default_page_size")]
+    pub page_size: u64,
+    /// Vault path the subscriber cursor is kept at, so a restart resumes where it stopped.
+    #[serde(default = "default_cursor_path")]
+    pub cursor_path: String,
+}
+
+/// Remote feed endpoint and the DID expected to sign it.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct RevocationFeedSource {
+    /// Endpoint serving the signed feed pages.
+    pub url: String,
+    /// DID of the authority; pages signed by anyone else are rejected.
+    pub authority_did: String,
+}
+
+impl Default for RevocationFeedConfig {
+    fn default() -> Self {
+        Self {
+            source: None,
+            poll_interval_secs: default_poll_interval(),
+            page_size: default_page_size(),
+            cursor_path: default_cursor_path(),
+        }
+    }
+}
+
+impl RevocationFeedConfigTrait for RevocationFeedConfig {
+    fn revocation_feed_config(&self) -> &RevocationFeedConfig {
+        self
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn default_poll_interval() -> u64 {
+    60
+}
+
+fn default_page_size() -> u64 {
+    100
+}
+
+fn default_cursor_path() -> String {
+    "revocation/feed-cursor".to_string()
+}
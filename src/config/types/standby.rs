@@ -0,0 +1,61 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::StandbyConfigTrait;
+use crate::types::standby::InstanceRole;
+
+/// Active/standby pairing of agents sharing the same database.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct StandbyConfig {
+    /// Role taken at startup. A standby only becomes active through a promotion.
+    #[serde(default)]
+    pub role: InstanceRole,
+    /// Identifier recorded on the leader lease. Random per process if unset.
+    #[serde(default = "default_instance_id")]
+    pub instance_id: String,
+    /// Seconds between two state handoffs.
+    #[serde(default = "default_handoff_interval")]
+    pub handoff_interval_secs: u64,
+}
+
+impl Default for StandbyConfig {
+    fn default() -> Self {
+        Self {
+            role: InstanceRole::default(),
+            instance_id: default_instance_id(),
+            handoff_interval_secs: default_handoff_interval(),
+        }
+    }
+}
+
+impl StandbyConfigTrait for StandbyConfig {
+    fn standby_config(&self) -> &StandbyConfig {
+        self
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn default_instance_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+fn default_handoff_interval() -> u64 {
+    5
+}
@@ -0,0 +1,35 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::TemplatesConfigTrait;
+use crate::types::vcs::vc_specs::template::CredentialTemplate;
+
+/// Deployment catalogue of `credentialSubject` templates keyed by credential type.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct TemplatesConfig {
+    /// Templates loaded at startup. Database-stored templates take precedence over these.
+    #[serde(default)]
+    pub templates: Vec<CredentialTemplate>,
+}
+
+impl TemplatesConfigTrait for TemplatesConfig {
+    fn templates_config(&self) -> &TemplatesConfig {
+        self
+    }
+}
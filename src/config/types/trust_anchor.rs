@@ -0,0 +1,86 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::TrustAnchorConfigTrait;
+use crate::types::vcs::VcType;
+
+/// External trust anchors the local trusted issuer registry is kept in sync with.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct TrustAnchorConfig {
+    /// Registries pulled on every sync round.
+    #[serde(default)]
+    pub registries: Vec<TrustRegistry>,
+    /// Seconds between two sync rounds.
+    #[serde(default = "default_sync_interval")]
+    pub sync_interval_secs: u64,
+}
+
+/// Single external registry endpoint.
+///
+/// Only `https` endpoints are synced.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct TrustRegistry {
+    /// Endpoint listing the trusted issuers.
+    pub url: String,
+    /// DID of the operator signing the list. Required by the [`RegistryFormat::Json`] format.
+    #[serde(default)]
+    pub signer: Option<String>,
+    /// Wire format served by the endpoint.
+    #[serde(default)]
+    pub format: RegistryFormat,
+    /// Credential type every issuer of this registry is trusted for. Empty trusts them for all.
+    ///
+    /// When set, entries listed for another type are dropped.
+    #[serde(default)]
+    pub vc_type: Option<VcType>,
+}
+
+/// Wire formats understood by the trust anchor sync.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistryFormat {
+    /// Compact JWS signed by the registry `signer` whose payload is a JSON list of DIDs or
+    /// `{ "did", "vc_type" }` objects, optionally wrapped in an `issuers` or `items` field
+    /// (e.g. a Gaia-X registry export).
+    #[default]
+    Json,
+    /// EBSI Trusted Issuers Registry API, following its paginated `links.next`.
+    Ebsi,
+}
+
+impl Default for TrustAnchorConfig {
+    fn default() -> Self {
+        Self {
+            registries: Vec::new(),
+            sync_interval_secs: default_sync_interval(),
+        }
+    }
+}
+
+impl TrustAnchorConfigTrait for TrustAnchorConfig {
+    fn trust_anchor_config(&self) -> &TrustAnchorConfig {
+        self
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn default_sync_interval() -> u64 {
+    3600
+}
@@ -0,0 +1,52 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::UniversalResolverConfigTrait;
+
+/// Universal Resolver instance used for DID methods without native support.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct UniversalResolverConfig {
+    /// Base URL of the resolver (e.g. `https://dev.uniresolver.io`). Fallback is off when unset.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Seconds a resolved document is served from cache.
+    #[serde(default = "default_cache_ttl")]
+    pub cache_ttl_secs: u64,
+}
+
+impl Default for UniversalResolverConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            cache_ttl_secs: default_cache_ttl(),
+        }
+    }
+}
+
+impl UniversalResolverConfigTrait for UniversalResolverConfig {
+    fn universal_resolver_config(&self) -> &UniversalResolverConfig {
+        self
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn default_cache_ttl() -> u64 {
+    3600
+}
@@ -0,0 +1,126 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::traits::VaultConfigTrait;
+use crate::types::keys::KeyGenSpec;
+
+/// HashiCorp Vault client settings.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct VaultConfig {
+    /// How the node authenticates against Vault.
+    #[serde(default)]
+    pub auth: VaultAuth,
+    /// Key pair generated on first run when no agent key is stored yet.
+    #[serde(default)]
+    pub bootstrap: Option<KeyGenSpec>,
+    /// Retry policy of the calls hitting an unavailable Vault.
+    #[serde(default)]
+    pub retries: VaultRetries,
+}
+
+/// Retry policy of the Vault calls failing transiently.
+///
+/// Calls are retried while Vault is unreachable, sealed or overloaded, and right after a new
+/// login when it rejects the token.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct VaultRetries {
+    /// Attempts made per call, the first one included.
+    #[serde(default = "default_attempts")]
+    pub attempts: u32,
+    /// Milliseconds before the first retry, doubled on every further one.
+    #[serde(default = "default_backoff_ms")]
+    pub backoff_ms: u64,
+    /// Longest delay between two attempts, in seconds.
+    #[serde(default = "default_max_backoff_secs")]
+    pub max_backoff_secs: u64,
+}
+
+impl Default for VaultRetries {
+    fn default() -> Self {
+        Self {
+            attempts: default_attempts(),
+            backoff_ms: default_backoff_ms(),
+            max_backoff_secs: default_max_backoff_secs(),
+        }
+    }
+}
+
+/// Vault authentication methods.
+///
+/// Tokens obtained through a login method are renewed before their lease runs out, logging in
+/// again once they can no longer be renewed.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum VaultAuth {
+    /// Static token read from `VAULT_TOKEN`.
+    #[default]
+    Token,
+    /// AppRole login with a role id and the secret id held in an environment variable.
+    AppRole {
+        #[serde(default = "default_approle_mount")]
+        mount: String,
+        role_id: String,
+        #[serde(default = "default_secret_id_env")]
+        secret_id_env: String,
+    },
+    /// Kubernetes login with the service account token mounted in the pod.
+    Kubernetes {
+        #[serde(default = "default_kubernetes_mount")]
+        mount: String,
+        role: String,
+        #[serde(default = "default_jwt_path")]
+        jwt_path: String,
+    },
+}
+
+impl VaultConfigTrait for VaultConfig {
+    fn vault_config(&self) -> &VaultConfig {
+        self
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn default_approle_mount() -> String {
+    "approle".to_string()
+}
+
+fn default_secret_id_env() -> String {
+    "VAULT_SECRET_ID".to_string()
+}
+
+fn default_kubernetes_mount() -> String {
+    "kubernetes".to_string()
+}
+
+fn default_jwt_path() -> String {
+    "/var/run/secrets/kubernetes.io/serviceaccount/token".to_string()
+}
+
+fn default_attempts() -> u32 {
+    5
+}
+
+fn default_backoff_ms() -> u64 {
+    500
+}
+
+fn default_max_backoff_secs() -> u64 {
+    30
+}
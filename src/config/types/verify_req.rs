@@ -20,6 +20,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::config::traits::VerifyReqConfigTrait;
 use crate::types::vcs::VcType;
+use crate::types::verification::input_descriptor::DescriptorConstraint;
+use crate::types::verification::{
+    AudienceTemplate, QueryLanguage, RedactionProfile, ResponseEncryption, ResponseMode, VpFormat,
+    VpPolicy,
+};
+use crate::utils::DEFAULT_CLOCK_SKEW_LEEWAY;
 
 /// Verification compliance matrix specifying required credential parameters and trust anchors.
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -31,6 +37,98 @@ pub struct VerifyReqConfig {
     /// Collection of required target credential formats mapped via string parsing.
     #[serde(deserialize_with = "deserialize_vc_type_vec")]
     pub vcs_requested: Vec<VcType>,
+    /// AND/OR combination of credential types replacing the all-of semantics of `vcs_requested`.
+    #[serde(default)]
+    pub policy: Option<VpPolicy>,
+    /// Per-resource requirement profiles overriding the global credential set.
+    #[serde(default)]
+    pub requirements: Vec<RequirementProfile>,
+    /// How strictly `credentialStatus` entries of presented credentials are enforced.
+    #[serde(default)]
+    pub status_check: StatusCheckMode,
+    /// Response mode used by profiles that do not set their own.
+    #[serde(default)]
+    pub response_mode: ResponseMode,
+    /// Presentation Exchange constraints applied to the descriptor of each requested type.
+    #[serde(default)]
+    pub constraints: Vec<DescriptorConstraint>,
+    /// Query language used to describe the requested credentials in authorization requests.
+    #[serde(default)]
+    pub query_language: QueryLanguage,
+    /// Presentation formats requested through Presentation Exchange definitions.
+    #[serde(default = "VpFormat::defaults")]
+    pub vp_formats: Vec<VpFormat>,
+    /// Whether `direct_post` responses must be encrypted to an ephemeral verifier key (JARM).
+    #[serde(default)]
+    pub response_encryption: ResponseEncryption,
+    /// Vault path of a static P-256 response encryption key; ephemeral keys are used if unset.
+    #[serde(default)]
+    pub response_key_path: Option<String>,
+    /// Seconds a verification session waits for the wallet before expiring.
+    #[serde(default = "default_session_ttl")]
+    pub session_ttl_secs: u64,
+    /// Claims redaction applied before verifications are persisted. Raw tokens are kept if unset.
+    #[serde(default)]
+    pub redaction: Option<RedactionProfile>,
+    /// Seconds of clock drift tolerated on `nbf`/`iat`/`exp` and VC validity windows.
+    #[serde(default = "default_clock_leeway")]
+    pub clock_leeway_secs: u64,
+    /// Webhook URLs notified with a signed event whenever a verification completes or fails.
+    #[serde(default)]
+    pub webhooks: Vec<String>,
+    /// Further audiences presentations may be bound to, e.g. the internal URL of the agent.
+    ///
+    /// The session audience follows the hosts config, `public_url` included.
+    #[serde(default)]
+    pub accepted_audiences: Vec<AudienceTemplate>,
+}
+
+/// Enforcement level applied when checking presented credentials against their status lists.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusCheckMode {
+    /// Revoked or suspended credentials are rejected, unreachable or invalid lists are logged.
+    #[default]
+    BestEffort,
+    /// Any status list that cannot be fetched or validated rejects the credential.
+    Mandatory,
+}
+
+/// Credential requirement profile bound to a protected resource and a subset of its actions.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct RequirementProfile {
+    /// Resource identifier the profile applies to.
+    pub resource: String,
+    /// Actions covered by the profile. An empty list covers every action.
+    #[serde(default)]
+    pub actions: Vec<String>,
+    /// Credential types a client must present to be granted access.
+    #[serde(deserialize_with = "deserialize_vc_type_vec")]
+    pub vcs_requested: Vec<VcType>,
+    /// Response mode overriding the global one for this profile.
+    #[serde(default)]
+    pub response_mode: Option<ResponseMode>,
+}
+
+impl RequirementProfile {
+    /// Checks whether the profile covers the given resource and optional action.
+    pub fn matches(&self, resource: &str, action: Option<&str>) -> bool {
+        if self.resource != resource {
+            return false;
+        }
+        match action {
+            Some(action) => self.actions.is_empty() || self.actions.iter().any(|a| a == action),
+            None => true,
+        }
+    }
+}
+
+fn default_session_ttl() -> u64 {
+    600
+}
+
+fn default_clock_leeway() -> u64 {
+    DEFAULT_CLOCK_SKEW_LEEWAY.as_secs()
 }
 
 impl VerifyReqConfigTrait for VerifyReqConfig {
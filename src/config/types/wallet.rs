@@ -19,7 +19,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::config::traits::WalletConfigTrait;
 use crate::config::types::CommonHostsConfig;
-use crate::types::wallet::WalletInstance;
+use crate::types::wallet::{CredentialSelection, WalletInstance};
 
 /// Technical exposure matrix defining wallet instance characteristics and gateway endpoints.
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -27,10 +27,31 @@ pub struct WalletConfig {
     /// Active runtime state profile tracker. Leverages internal `Default` implementations.
     #[serde(default)]
     pub wallet: WalletInstance,
+    /// Backend serving the wallet operations, picked once at startup.
+    #[serde(default)]
+    pub provider: WalletProvider,
+    /// Rule picking among several credentials answering the same input descriptor.
+    #[serde(default)]
+    pub selection: CredentialSelection,
     /// Multi-transport routing descriptors dedicated to wallet network integrations.
     pub api: CommonHostsConfig,
 }
 
+/// Wallet backends selectable at runtime.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WalletProvider {
+    /// walt.id wallet API.
+    #[serde(rename = "waltid")]
+    WaltId,
+    /// In-process wallet keeping its DIDs, keys and credentials in the local database.
+    #[serde(rename = "native")]
+    Native,
+    /// Fafnir-compatible wallet API reachable at the configured `api` hosts.
+    #[default]
+    #[serde(rename = "custom-url")]
+    CustomUrl,
+}
+
 impl WalletConfigTrait for WalletConfig {
     fn wallet_config(&self) -> &WalletConfig {
         self
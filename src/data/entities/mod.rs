@@ -18,4 +18,7 @@
 pub mod received;
 pub mod sent;
 pub mod shared;
+pub mod string_list;
 pub mod wallet;
+
+pub use string_list::StringList;
@@ -0,0 +1,102 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::keys::DbKeySource;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Labeled access token issued for a grant asking for several tokens (RFC 9635 §2.1.2).
+///
+/// The single token of a grant stays on the participant it was issued to; each token of a
+/// multiple token request gets its own row, with the access stored under [`Model::access_id`].
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "recv_access_token")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // TOKEN VALUE
+    pub grant_id: String,       // REQUEST
+    pub participant_id: String, // REQUEST
+    pub label: String,          // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub token_key: Option<DbKeySource>, // REQUEST
+    pub expires_at: Option<DateTime<Utc>>, // RESPONSE
+    pub issued_at: DateTime<Utc>, // DEFAULT
+}
+
+impl Model {
+    /// Id of the resource request holding the access granted to this token.
+    pub fn access_id(&self) -> String {
+        access_id(&self.grant_id, &self.label)
+    }
+
+    /// Tells whether the token has passed its expiry.
+    pub fn expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+    }
+}
+
+/// Id of the resource request holding the access of the token labeled `label` in grant
+/// `grant_id`.
+pub fn access_id(grant_id: &str, label: &str) -> String {
+    format!("{grant_id}#{label}")
+}
+
+#[derive(Clone, Debug)]
+pub struct Plan {
+    pub value: String,
+    pub grant_id: String,
+    pub participant_id: String,
+    pub label: String,
+    pub token_key: Option<DbKeySource>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.value),
+            grant_id: ActiveValue::Set(self.grant_id),
+            participant_id: ActiveValue::Set(self.participant_id),
+            label: ActiveValue::Set(self.label),
+            token_key: ActiveValue::Set(self.token_key),
+            expires_at: ActiveValue::Set(self.expires_at),
+            issued_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            grant_id: ActiveValue::Set(self.grant_id),
+            participant_id: ActiveValue::Set(self.participant_id),
+            label: ActiveValue::Set(self.label),
+            token_key: ActiveValue::Set(self.token_key),
+            expires_at: ActiveValue::Set(self.expires_at),
+            issued_at: ActiveValue::Set(self.issued_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,60 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::verification::EvidenceBundle;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Signed evidence sealed once a verification finishes. Rows are written once and never updated.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "recv_evidence_bundle")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // BUNDLE JTI
+    pub verification_id: String, // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub bundle: EvidenceBundle, // PACKAGING
+    /// Compact JWS over `bundle`, signed by the verifier.
+    pub jwt: String, // PACKAGING
+    pub sealed_at: DateTime<Utc>, // DEFAULT
+}
+
+#[derive(Clone, Debug)]
+pub struct Plan {
+    pub bundle: EvidenceBundle,
+    pub jwt: String,
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.bundle.jti.clone()),
+            verification_id: ActiveValue::Set(self.bundle.verification_id.clone()),
+            bundle: ActiveValue::Set(self.bundle),
+            jwt: ActiveValue::Set(self.jwt),
+            sealed_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
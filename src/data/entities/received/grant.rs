@@ -16,6 +16,7 @@
  */
 
 use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::extensions::empty_extensions;
 use crate::types::gnap::GrantStatus;
 use crate::types::gnap::grant_request::GrantKind;
 use crate::types::vcs::VcTypeConfig;
@@ -37,6 +38,7 @@ pub struct Model {
     pub status: GrantStatus,             // DEFAULT
     pub created_at: DateTime<Utc>,       // DEFAULT
     pub ended_at: Option<DateTime<Utc>>, // COMPLETION
+    pub extensions: serde_json::Value,   // INTEGRATOR
 }
 
 #[derive(Clone, Debug)]
@@ -58,6 +60,7 @@ impl IntoOverwriteActive<ActiveModel> for Plan {
             status: ActiveValue::Set(GrantStatus::Pending),
             created_at: ActiveValue::Set(Utc::now()),
             ended_at: ActiveValue::Set(None),
+            extensions: ActiveValue::Set(empty_extensions()),
         }
     }
 }
@@ -73,6 +76,7 @@ impl IntoOverwriteActive<ActiveModel> for Model {
             status: ActiveValue::Set(self.status),
             created_at: ActiveValue::Set(self.created_at),
             ended_at: ActiveValue::Set(self.ended_at),
+            extensions: ActiveValue::Set(self.extensions),
         }
     }
 }
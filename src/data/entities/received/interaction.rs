@@ -15,17 +15,17 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::errors::{Errors, Outcome};
 use crate::services::repo::postgres::IntoOverwriteActive;
 use crate::types::gnap::grant_request::interact::{FinishMethod, HashMethod, InteractStart};
 use crate::types::keys::DbKeySource;
-use base64::Engine;
-use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use axum::http::HeaderMap;
+use chrono::{DateTime, Duration, Utc};
 use rand::Rng;
 use rand::distributions::Alphanumeric;
 use sea_orm::ActiveValue;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256, Sha384, Sha512};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "recv_interactions")]
@@ -44,6 +44,7 @@ pub struct Model {
     pub continue_id: String,        // RESPONSE
     pub continue_token: String,     // RESPONSE
     pub continue_wait: Option<i64>, // RESPONSE
+    pub last_polled_at: Option<DateTime<Utc>>,
     pub as_nonce: String,           // RANDOM
     pub interact_ref: String,       // RANDOM
     pub hash: String,               // RANDOM
@@ -57,16 +58,34 @@ pub struct Plan {
     pub callback_uri: String,            // REQUEST
     pub key_source: DbKeySource,         // REQUEST
     pub client_nonce: String,            // REQUEST
-    pub hash_method: Option<HashMethod>, // REQUEST
+    pub finish: FinishHash,              // RANDOM
     pub hints: Option<String>,           // REQUEST
-    pub grant_endpoint: String,          // REQUEST
     pub continue_endpoint: String,       // RESPONSE
     pub continue_token: String,          // RESPONSE
     pub continue_wait: Option<i64>,      // RESPONSE
 }
 
-impl IntoOverwriteActive<ActiveModel> for Plan {
-    fn into_active(self) -> ActiveModel {
+/// AS nonce, interaction reference and finish hash the client checks once redirected back.
+#[derive(Clone, Debug)]
+pub struct FinishHash {
+    pub method: HashMethod,
+    pub as_nonce: String,
+    pub interact_ref: String,
+    pub hash: String,
+}
+
+impl FinishHash {
+    /// Draws the AS nonce and interaction reference, hashing them with the method the client
+    /// `requested`, `sha-256` when it did not ask for one.
+    ///
+    /// # Errors
+    /// Returns a format error when the client requested an unsupported method.
+    pub fn draw(
+        requested: Option<HashMethod>,
+        client_nonce: &str,
+        grant_endpoint: &str,
+    ) -> Outcome<Self> {
+        let method = HashMethod::negotiate(requested)?;
         let as_nonce: String = rand::thread_rng()
             .sample_iter(&Alphanumeric)
             .take(36)
@@ -77,42 +96,68 @@ impl IntoOverwriteActive<ActiveModel> for Plan {
             .take(16)
             .map(char::from)
             .collect();
+        let hash =
+            method.interaction_hash(client_nonce, &as_nonce, &interact_ref, grant_endpoint)?;
+        Ok(Self {
+            method,
+            as_nonce,
+            interact_ref,
+            hash,
+        })
+    }
+}
+
+impl Model {
+    /// Checks that a `method` call to the continuation URI is signed with the client key.
+    ///
+    /// Continue tokens are always bound to the key the grant was requested with.
+    pub fn check_proof(&self, headers: &HeaderMap, method: &str, body: &[u8]) -> Outcome<()> {
+        self.key_source
+            .verify_request(headers, method, &self.continue_endpoint, body)
+    }
+
+    /// Checks that the client waited the advertised `continue_wait` since its last poll.
+    ///
+    /// # Errors
+    /// Returns a rate limit error carrying the seconds left to wait.
+    pub fn check_poll(&self, now: DateTime<Utc>) -> Outcome<()> {
+        let (Some(last), Some(wait)) = (self.last_polled_at, self.continue_wait) else {
+            return Ok(());
+        };
+        let left = (last + Duration::seconds(wait) - now).num_seconds();
+        if left > 0 {
+            return Err(Errors::rate_limit(
+                format!(
+                    "Continuation {} polled before its wait elapsed",
+                    self.continue_id
+                ),
+                None,
+            )
+            .with_retry_after(left as u64));
+        }
+        Ok(())
+    }
+
+    /// Returns a fresh continuation access token.
+    pub fn new_continue_token() -> String {
+        rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(32)
+            .map(char::from)
+            .collect()
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
         let continue_id: String = rand::thread_rng()
             .sample_iter(&Alphanumeric)
             .take(12)
             .map(char::from)
             .collect();
 
-        let hash_method = self.hash_method.unwrap_or(HashMethod::Sha256);
-
-        let hash_input = format!(
-            "{}\n{}\n{}\n{}",
-            self.client_nonce, as_nonce, interact_ref, self.grant_endpoint
-        );
-
-        let hash_result = match &hash_method {
-            HashMethod::Sha256 => {
-                let mut h = Sha256::new();
-                h.update(hash_input.as_bytes());
-                h.finalize().to_vec()
-            }
-            HashMethod::Sha384 => {
-                let mut h = Sha384::new();
-                h.update(hash_input.as_bytes());
-                h.finalize().to_vec()
-            }
-            HashMethod::Sha512 => {
-                let mut h = Sha512::new();
-                h.update(hash_input.as_bytes());
-                h.finalize().to_vec()
-            }
-            _ => unreachable!(),
-        };
-
         let cont_endpoint = format!("{}/{}", self.continue_endpoint, continue_id);
 
-        let hash = URL_SAFE_NO_PAD.encode(hash_result);
-
         ActiveModel {
             id: ActiveValue::Set(self.id),
             start: ActiveValue::Set(self.start),
@@ -120,15 +165,16 @@ impl IntoOverwriteActive<ActiveModel> for Plan {
             callback_uri: ActiveValue::Set(self.callback_uri),
             key_source: ActiveValue::Set(self.key_source),
             client_nonce: ActiveValue::Set(self.client_nonce),
-            hash_method: ActiveValue::Set(hash_method),
+            hash_method: ActiveValue::Set(self.finish.method),
             hints: ActiveValue::Set(self.hints),
             continue_endpoint: ActiveValue::Set(cont_endpoint),
             continue_id: ActiveValue::Set(continue_id),
             continue_token: ActiveValue::Set(self.continue_token),
             continue_wait: ActiveValue::Set(self.continue_wait),
-            as_nonce: ActiveValue::Set(as_nonce),
-            interact_ref: ActiveValue::Set(interact_ref),
-            hash: ActiveValue::Set(hash),
+            last_polled_at: ActiveValue::Set(None),
+            as_nonce: ActiveValue::Set(self.finish.as_nonce),
+            interact_ref: ActiveValue::Set(self.finish.interact_ref),
+            hash: ActiveValue::Set(self.finish.hash),
         }
     }
 }
@@ -148,6 +194,7 @@ impl IntoOverwriteActive<ActiveModel> for Model {
             continue_id: ActiveValue::Set(self.continue_id),
             continue_token: ActiveValue::Set(self.continue_token),
             continue_wait: ActiveValue::Set(self.continue_wait),
+            last_polled_at: ActiveValue::Set(self.last_polled_at),
             as_nonce: ActiveValue::Set(self.as_nonce),
             interact_ref: ActiveValue::Set(self.interact_ref),
             hash: ActiveValue::Set(self.hash),
@@ -15,6 +15,10 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+pub mod access_token;
+pub mod evidence_bundle;
 pub mod grant;
 pub mod interaction;
+pub mod presentation_definition;
 pub mod verification;
+pub mod verification_audit;
@@ -0,0 +1,93 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::errors::Outcome;
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::vcs::VPDef;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Presentation definition published by reference, keyed by its content address.
+///
+/// The definition itself is never rewritten; a newer definition for the same profile only
+/// marks it as superseded.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "recv_presentation_definition")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // CONTENT HASH
+    pub profile: String, // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub definition: Value, // REQUEST
+    pub created_at: DateTime<Utc>, // DEFAULT
+    pub superseded_by: Option<String>, // SUPERSESSION
+    pub superseded_at: Option<DateTime<Utc>>, // SUPERSESSION
+}
+
+#[derive(Clone, Debug)]
+pub struct Plan {
+    /// Content addressed definition, see [`VPDef::content_addressed`].
+    pub definition: VPDef,
+    /// Requested credential types the definition was generated for.
+    pub profile: String,
+}
+
+impl Model {
+    pub fn definition(&self) -> Outcome<VPDef> {
+        Ok(serde_json::from_value(self.definition.clone())?)
+    }
+
+    pub fn is_superseded(&self) -> bool {
+        self.superseded_by.is_some()
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.definition.id.clone()),
+            profile: ActiveValue::Set(self.profile),
+            definition: ActiveValue::Set(
+                serde_json::to_value(&self.definition).unwrap_or_default(),
+            ),
+            created_at: ActiveValue::Set(Utc::now()),
+            superseded_by: ActiveValue::Set(None),
+            superseded_at: ActiveValue::Set(None),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            profile: ActiveValue::Set(self.profile),
+            definition: ActiveValue::Set(self.definition),
+            created_at: ActiveValue::Set(self.created_at),
+            superseded_by: ActiveValue::Set(self.superseded_by),
+            superseded_at: ActiveValue::Set(self.superseded_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -15,15 +15,21 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::data::entities::StringList;
+use crate::errors::{Errors, Outcome};
 use crate::services::repo::postgres::IntoOverwriteActive;
 use crate::types::vcs::VcType;
-use crate::types::verification::VerificationStatus;
-use chrono::{DateTime, Utc};
+use crate::types::verification::{
+    AudienceTemplate, PresentedCredential, QueryLanguage, RedactedCredential, RedactionProfile,
+    ResponseMode, VerificationStatus,
+};
+use chrono::{DateTime, Duration, Utc};
 use rand::Rng;
 use rand::distributions::Alphanumeric;
 use sea_orm::ActiveValue;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "recv_verification")]
@@ -34,21 +40,48 @@ pub struct Model {
     pub nonce: String,              // RANDOM
     #[sea_orm(column_type = "JsonBinary")]
     pub vc_type: Vec<VcType>,       // REQUEST
+    pub purpose: Option<String>,    // REQUEST
     pub audience: String,           // SEMI-RANDOM
+    pub response_mode: ResponseMode, // REQUEST
+    pub query_language: QueryLanguage, // REQUEST
     pub holder: Option<String>,     // RESPONSE
     pub vpt: Option<String>,        // RESPONSE
-    pub vcs: Vec<String>,           // RESPONSE
+    #[sea_orm(column_type = "JsonBinary")]
+    pub vcs: StringList,            // RESPONSE
     pub status: VerificationStatus, // DEFAULT
     pub created_at: DateTime<Utc>,  // DEFAULT
+    pub expires_at: DateTime<Utc>,  // DEFAULT
     pub ended_at: Option<DateTime<Utc>>, // RESPONSE
+    pub receipt: Option<String>,    // RESPONSE
+    /// Private JWK of the ephemeral response encryption key, or kid of the static one.
+    #[serde(skip_serializing, default)]
+    pub response_key: Option<String>, // RANDOM
+    /// Redacted subjects kept instead of the raw credentials when a profile applies.
+    #[sea_orm(column_type = "JsonBinary")]
+    pub claims: Vec<RedactedCredential>, // DEFAULT
+    pub redaction_profile: Option<String>, // RESPONSE
+    pub webhook_url: Option<String>,       // REQUEST
+    /// Content addressed presentation definition the session was offered.
+    pub pd_id: Option<String>, // PUBLICATION
+    /// Bumped on every update so concurrent writers of the same session are detected.
+    #[serde(default)]
+    pub version: i32, // DEFAULT
                                     // pub requirements: Value, TODO
 }
 
 #[derive(Clone, Debug)]
 pub struct Plan {
     pub id: String,           // REQUEST
+    /// Session audience with a `{state}` placeholder, filled once the state is drawn.
     pub audience: String,     // SEMI-RANDOM
     pub vc_type: Vec<VcType>, // REQUEST
+    pub purpose: Option<String>, // REQUEST
+    pub response_mode: ResponseMode, // REQUEST
+    pub query_language: QueryLanguage, // REQUEST
+    pub response_key: Option<String>, // RANDOM
+    pub ttl_secs: i64,                // REQUEST
+    /// Webhook notified of this verification's outcome, on top of the global ones.
+    pub webhook_url: Option<String>, // REQUEST
 }
 
 impl IntoOverwriteActive<ActiveModel> for Plan {
@@ -63,19 +96,30 @@ impl IntoOverwriteActive<ActiveModel> for Plan {
             .take(12)
             .map(char::from)
             .collect();
-        let audience = format!("{}/{}", self.audience, &state);
+        let audience = AudienceTemplate::bind_state(&self.audience, &state);
         ActiveModel {
             id: ActiveValue::Set(self.id),
             state: ActiveValue::Set(state),
             nonce: ActiveValue::Set(nonce),
             vc_type: ActiveValue::Set(self.vc_type),
+            purpose: ActiveValue::Set(self.purpose),
             audience: ActiveValue::Set(audience),
+            response_mode: ActiveValue::Set(self.response_mode),
+            query_language: ActiveValue::Set(self.query_language),
             holder: ActiveValue::Set(None),
             vpt: ActiveValue::Set(None),
-            vcs: ActiveValue::Set(Vec::new()),
+            vcs: ActiveValue::Set(StringList::default()),
             status: ActiveValue::Set(VerificationStatus::Pending),
             created_at: ActiveValue::Set(Utc::now()),
+            expires_at: ActiveValue::Set(Utc::now() + Duration::seconds(self.ttl_secs)),
             ended_at: ActiveValue::Set(None),
+            receipt: ActiveValue::Set(None),
+            response_key: ActiveValue::Set(self.response_key),
+            claims: ActiveValue::Set(Vec::new()),
+            redaction_profile: ActiveValue::Set(None),
+            webhook_url: ActiveValue::Set(self.webhook_url),
+            pd_id: ActiveValue::Set(None),
+            version: ActiveValue::Set(0),
         }
     }
 }
@@ -87,14 +131,83 @@ impl IntoOverwriteActive<ActiveModel> for Model {
             state: ActiveValue::Set(self.state),
             nonce: ActiveValue::Set(self.nonce),
             vc_type: ActiveValue::Set(self.vc_type),
+            purpose: ActiveValue::Set(self.purpose),
             audience: ActiveValue::Set(self.audience),
+            response_mode: ActiveValue::Set(self.response_mode),
+            query_language: ActiveValue::Set(self.query_language),
             holder: ActiveValue::Set(self.holder),
             vpt: ActiveValue::Set(self.vpt),
             vcs: ActiveValue::Set(self.vcs),
             status: ActiveValue::Set(self.status),
             created_at: ActiveValue::Set(self.created_at),
+            expires_at: ActiveValue::Set(self.expires_at),
             ended_at: ActiveValue::Set(self.ended_at),
+            receipt: ActiveValue::Set(self.receipt),
+            response_key: ActiveValue::Set(self.response_key),
+            claims: ActiveValue::Set(self.claims),
+            redaction_profile: ActiveValue::Set(self.redaction_profile),
+            webhook_url: ActiveValue::Set(self.webhook_url),
+            pd_id: ActiveValue::Set(self.pd_id),
+            version: ActiveValue::Set(self.version),
+        }
+    }
+}
+
+impl Model {
+    /// Whether a session still open, pending or presented, has outlived its TTL.
+    pub fn is_expired(&self) -> bool {
+        matches!(
+            self.status,
+            VerificationStatus::Pending | VerificationStatus::Presented
+        ) && Utc::now() > self.expires_at
+    }
+
+    /// Moves the session to `next`, closing it when `next` is final.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the state machine forbids the transition.
+    pub fn transition(&mut self, next: VerificationStatus) -> Outcome<()> {
+        if !self.status.can_transition_to(&next) {
+            return Err(Errors::forbidden(
+                format!("Verification cannot move from {:?} to {:?}", self.status, next),
+                None,
+            ));
+        }
+        if next.is_terminal() {
+            self.ended_at = Some(Utc::now());
+            self.response_key = None;
+        }
+        self.status = next;
+        Ok(())
+    }
+
+    /// Replaces the raw presentation and credentials with their redacted subjects.
+    ///
+    /// Credentials that cannot be decoded (only possible on failed sessions) are dropped.
+    pub fn redact(&mut self, profile: &RedactionProfile) {
+        self.claims = self
+            .vcs
+            .iter()
+            .filter_map(|vc| profile.redact_vc(vc).ok())
+            .collect();
+        self.redaction_profile = Some(profile.name.clone());
+        self.vpt = None;
+        self.vcs = StringList::default();
+    }
+
+    /// Subjects of the verified credentials, read from the redacted copy when one was stored.
+    pub fn credential_subjects(&self) -> Outcome<Vec<Value>> {
+        if self.redaction_profile.is_some() {
+            return Ok(self
+                .claims
+                .iter()
+                .map(|c| c.credential_subject.clone())
+                .collect());
         }
+        self.vcs
+            .iter()
+            .map(|vc| Ok(PresentedCredential::decode(vc)?.credential_subject))
+            .collect()
     }
 }
 
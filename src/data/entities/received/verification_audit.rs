@@ -0,0 +1,96 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::verification::{
+    AuditCheck, AuditedRequest, PresentedCredential, VerificationStatus,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Evidence kept for every wallet response, independently of the redaction profiles.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "recv_verification_audit")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // DEFAULT
+    pub verification_id: String, // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub request: AuditedRequest, // REQUEST
+    pub vp_token: Option<String>, // RESPONSE
+    pub presentation_submission: Option<String>, // RESPONSE
+    #[sea_orm(column_type = "JsonBinary")]
+    pub claims: Vec<PresentedCredential>, // RESPONSE
+    #[sea_orm(column_type = "JsonBinary")]
+    pub checks: Vec<AuditCheck>, // RESPONSE
+    pub outcome: VerificationStatus, // RESPONSE
+    pub failure_reason: Option<String>, // RESPONSE
+    pub recorded_at: DateTime<Utc>, // DEFAULT
+}
+
+#[derive(Clone, Debug)]
+pub struct Plan {
+    pub verification_id: String,
+    pub request: AuditedRequest,
+    pub vp_token: Option<String>,
+    pub presentation_submission: Option<String>,
+    pub claims: Vec<PresentedCredential>,
+    pub checks: Vec<AuditCheck>,
+    pub outcome: VerificationStatus,
+    pub failure_reason: Option<String>,
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
+            verification_id: ActiveValue::Set(self.verification_id),
+            request: ActiveValue::Set(self.request),
+            vp_token: ActiveValue::Set(self.vp_token),
+            presentation_submission: ActiveValue::Set(self.presentation_submission),
+            claims: ActiveValue::Set(self.claims),
+            checks: ActiveValue::Set(self.checks),
+            outcome: ActiveValue::Set(self.outcome),
+            failure_reason: ActiveValue::Set(self.failure_reason),
+            recorded_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            verification_id: ActiveValue::Set(self.verification_id),
+            request: ActiveValue::Set(self.request),
+            vp_token: ActiveValue::Set(self.vp_token),
+            presentation_submission: ActiveValue::Set(self.presentation_submission),
+            claims: ActiveValue::Set(self.claims),
+            checks: ActiveValue::Set(self.checks),
+            outcome: ActiveValue::Set(self.outcome),
+            failure_reason: ActiveValue::Set(self.failure_reason),
+            recorded_at: ActiveValue::Set(self.recorded_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
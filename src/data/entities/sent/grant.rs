@@ -15,9 +15,13 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::errors::{Errors, Outcome};
 use crate::services::repo::postgres::IntoOverwriteActive;
-use crate::types::gnap::GrantStatus;
+use crate::types::extensions::empty_extensions;
+use crate::types::gnap::access_token::{AccessTokens, StoredToken};
 use crate::types::gnap::grant_request::GrantKind;
+use crate::types::gnap::grant_response::SubjectId;
+use crate::types::gnap::{GrantStatus, RequestLifecycle};
 use crate::types::vcs::VcTypeConfig;
 use chrono::{DateTime, Utc};
 use sea_orm::ActiveValue;
@@ -34,7 +38,13 @@ pub struct Model {
     pub grant_endpoint: String,
     pub kind: GrantKind, // Type of request, (token or vc)
     pub status: GrantStatus,
+    pub lifecycle: RequestLifecycle, // Continuation step the request has reached
     pub token: Option<String>,
+    pub token_expires_at: Option<DateTime<Utc>>,
+    pub manage_uri: Option<String>, // Token management URI to rotate the token
+    pub manage_token: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub labeled_tokens: Option<Vec<StoredToken>>, // Tokens of a multiple token request
     #[sea_orm(column_type = "JsonBinary")]
     pub vc_type_config: Option<Vec<VcTypeConfig>>,
     pub vc_uri: Option<String>,
@@ -42,6 +52,9 @@ pub struct Model {
     pub auto: bool, // If active, redeeming credentials or presented them is automatic
     pub created_at: DateTime<Utc>,
     pub ended_at: Option<DateTime<Utc>>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub sub_ids: Option<Vec<SubjectId>>, // Who the authority authenticated
+    pub extensions: serde_json::Value, // Integrator-defined data
 }
 
 #[derive(Clone, Debug)]
@@ -65,12 +78,19 @@ impl IntoOverwriteActive<ActiveModel> for Plan {
             kind: ActiveValue::Set(self.kind),
             auto: ActiveValue::Set(self.auto.unwrap_or(false)),
             status: ActiveValue::Set(GrantStatus::Processing),
+            lifecycle: ActiveValue::Set(RequestLifecycle::Created),
             token: ActiveValue::Set(None),
+            token_expires_at: ActiveValue::Set(None),
+            manage_uri: ActiveValue::Set(None),
+            manage_token: ActiveValue::Set(None),
+            labeled_tokens: ActiveValue::Set(None),
             vc_type_config: ActiveValue::Set(self.vc_type_config),
             vc_uri: ActiveValue::Set(None),
             as_assigned_id: ActiveValue::Set(None),
             created_at: ActiveValue::Set(Utc::now()),
             ended_at: ActiveValue::Set(None),
+            sub_ids: ActiveValue::Set(None),
+            extensions: ActiveValue::Set(empty_extensions()),
         }
     }
 }
@@ -85,14 +105,112 @@ impl IntoOverwriteActive<ActiveModel> for Model {
             kind: ActiveValue::Set(self.kind),
             auto: ActiveValue::Set(self.auto),
             status: ActiveValue::Set(self.status),
+            lifecycle: ActiveValue::Set(self.lifecycle),
             token: ActiveValue::Set(self.token),
+            token_expires_at: ActiveValue::Set(self.token_expires_at),
+            manage_uri: ActiveValue::Set(self.manage_uri),
+            manage_token: ActiveValue::Set(self.manage_token),
+            labeled_tokens: ActiveValue::Set(self.labeled_tokens),
             vc_type_config: ActiveValue::Set(self.vc_type_config),
             vc_uri: ActiveValue::Set(self.vc_uri),
             as_assigned_id: ActiveValue::Set(self.as_assigned_id),
             created_at: ActiveValue::Set(self.created_at),
             ended_at: ActiveValue::Set(self.ended_at),
+            sub_ids: ActiveValue::Set(self.sub_ids),
+            extensions: ActiveValue::Set(self.extensions),
+        }
+    }
+}
+
+impl Model {
+    /// Moves the request to `next`, closing it when `next` is final.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the step arrives out of order.
+    pub fn transition(&mut self, next: RequestLifecycle) -> Outcome<()> {
+        if !self.lifecycle.can_transition_to(&next) {
+            return Err(Errors::forbidden(
+                format!(
+                    "Grant request {} cannot move from {:?} to {:?}",
+                    self.id, self.lifecycle, next
+                ),
+                None,
+            ));
+        }
+        if next.is_terminal() {
+            self.ended_at = Some(Utc::now());
+        }
+        self.lifecycle = next;
+        Ok(())
+    }
+
+    /// Stores the access tokens issued by the authority at `issued_at`.
+    ///
+    /// A single token fills the token columns; the tokens of a multiple token request are
+    /// kept by label.
+    pub fn store_tokens(&mut self, tokens: AccessTokens, issued_at: DateTime<Utc>) {
+        match tokens {
+            AccessTokens::Single(token) => self.put_token(None, StoredToken::new(token, issued_at)),
+            AccessTokens::Multiple(tokens) => {
+                self.labeled_tokens = Some(
+                    tokens
+                        .into_iter()
+                        .map(|token| StoredToken::new(token, issued_at))
+                        .collect(),
+                );
+            }
         }
     }
+
+    /// Returns the token stored under `label`, or the single token when `label` is `None`.
+    pub fn stored_token(&self, label: Option<&str>) -> Option<StoredToken> {
+        match label {
+            None => self.token.clone().map(|value| StoredToken {
+                label: None,
+                value,
+                expires_at: self.token_expires_at,
+                manage_uri: self.manage_uri.clone(),
+                manage_token: self.manage_token.clone(),
+            }),
+            Some(label) => self
+                .labeled_tokens
+                .as_ref()?
+                .iter()
+                .find(|token| token.label.as_deref() == Some(label))
+                .cloned(),
+        }
+    }
+
+    /// Replaces the token stored under `label`, or the single token when `label` is `None`.
+    pub fn put_token(&mut self, label: Option<&str>, mut token: StoredToken) {
+        let Some(label) = label else {
+            self.token = Some(token.value);
+            self.token_expires_at = token.expires_at;
+            self.manage_uri = token.manage_uri;
+            self.manage_token = token.manage_token;
+            return;
+        };
+        token.label = Some(label.to_string());
+        let tokens = self.labeled_tokens.get_or_insert_with(Vec::new);
+        tokens.retain(|stored| stored.label.as_deref() != Some(label));
+        tokens.push(token);
+    }
+
+    /// Tells whether the grant still holds any access token.
+    pub fn holds_token(&self) -> bool {
+        self.token.is_some() || self.labeled_tokens.as_ref().is_some_and(|t| !t.is_empty())
+    }
+
+    /// Drops every access token of the grant, telling whether there was any.
+    pub fn clear_tokens(&mut self) -> bool {
+        let held = self.holds_token();
+        self.token = None;
+        self.token_expires_at = None;
+        self.manage_uri = None;
+        self.manage_token = None;
+        self.labeled_tokens = None;
+        held
+    }
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
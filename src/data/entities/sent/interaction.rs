@@ -15,6 +15,7 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::errors::{Errors, Outcome};
 use crate::services::repo::postgres::IntoOverwriteActive;
 use crate::types::gnap::grant_request::interact::{FinishMethod, HashMethod, InteractStart};
 use rand::Rng;
@@ -102,7 +103,62 @@ impl IntoOverwriteActive<ActiveModel> for Model {
     }
 }
 
+impl Model {
+    /// Records the finish callback of the interaction.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the AS never returned the continuation
+    /// details (the grant was not answered yet) or a callback was already recorded, and an
+    /// [`Errors::SecurityError`] when `hash` is not the finish hash of this interaction
+    /// (RFC 9635 §4.2.3) computed over `grant_endpoint`.
+    pub fn record_callback(
+        &mut self,
+        interact_ref: String,
+        hash: String,
+        grant_endpoint: &str,
+    ) -> Outcome<()> {
+        if self.continue_token.is_none() {
+            return Err(Errors::forbidden(
+                format!("Interaction {} has not been started", self.id),
+                None,
+            ));
+        }
+        if self.interact_ref.is_some() {
+            return Err(Errors::forbidden(
+                format!("Interaction {} already received its callback", self.id),
+                None,
+            ));
+        }
+        let Some(as_nonce) = self.as_nonce.as_deref() else {
+            return Err(Errors::security(
+                format!("Interaction {} has no AS nonce to check the hash", self.id),
+                None,
+            ));
+        };
+        let expected = self.hash_method.interaction_hash(
+            &self.client_nonce,
+            as_nonce,
+            &interact_ref,
+            grant_endpoint,
+        )?;
+        if !constant_time_eq(expected.as_bytes(), hash.as_bytes()) {
+            return Err(Errors::security(
+                format!("Finish hash of interaction {} does not match", self.id),
+                None,
+            ));
+        }
+        self.interact_ref = Some(interact_ref);
+        self.hash = Some(hash);
+        Ok(())
+    }
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
 
 impl ActiveModelBehavior for ActiveModel {}
+
+/// Compares two byte strings without leaking where they differ through timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
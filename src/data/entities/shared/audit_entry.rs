@@ -0,0 +1,112 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::audit::{
+    AuditOperation, audit_diff, audit_snapshot, current_audit_actor, redact_snapshot,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "audit_entries")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // DEFAULT
+    /// Token digest or participant the write was made on behalf of, when known.
+    pub actor: Option<String>, // CONTEXT
+    /// Table of the audited row.
+    pub entity: String, // REQUEST
+    pub entity_id: String,         // REQUEST
+    pub operation: AuditOperation, // REQUEST
+    pub before: Option<Json>,      // REQUEST
+    pub after: Option<Json>,       // REQUEST
+    /// Changed fields as `{field: {before, after}}`.
+    pub diff: Json, // DEFAULT
+    pub created_at: DateTime<Utc>, // DEFAULT
+}
+
+#[derive(Clone, Debug)]
+pub struct Plan {
+    pub actor: Option<String>,
+    pub entity: String,
+    pub entity_id: String,
+    pub operation: AuditOperation,
+    /// Unmasked snapshots; redacted fields are masked when the entry is built.
+    pub before: Option<Json>,
+    pub after: Option<Json>,
+}
+
+impl Plan {
+    /// Describes a write to `entity_id` of `entity`, made by the actor of the current task.
+    pub fn new<M: Serialize>(
+        entity: &str,
+        entity_id: &str,
+        operation: AuditOperation,
+        before: Option<&M>,
+        after: Option<&M>,
+    ) -> Self {
+        Self {
+            actor: current_audit_actor(),
+            entity: entity.to_string(),
+            entity_id: entity_id.to_string(),
+            operation,
+            before: before.and_then(audit_snapshot),
+            after: after.and_then(audit_snapshot),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        let diff = audit_diff(self.before.as_ref(), self.after.as_ref());
+        ActiveModel {
+            id: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
+            actor: ActiveValue::Set(self.actor),
+            entity: ActiveValue::Set(self.entity),
+            entity_id: ActiveValue::Set(self.entity_id),
+            operation: ActiveValue::Set(self.operation),
+            before: ActiveValue::Set(self.before.map(redact_snapshot)),
+            after: ActiveValue::Set(self.after.map(redact_snapshot)),
+            diff: ActiveValue::Set(diff),
+            created_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            actor: ActiveValue::Set(self.actor),
+            entity: ActiveValue::Set(self.entity),
+            entity_id: ActiveValue::Set(self.entity_id),
+            operation: ActiveValue::Set(self.operation),
+            before: ActiveValue::Set(self.before),
+            after: ActiveValue::Set(self.after),
+            diff: ActiveValue::Set(self.diff),
+            created_at: ActiveValue::Set(self.created_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
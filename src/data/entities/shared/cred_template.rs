@@ -0,0 +1,74 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::vcs::VcType;
+use crate::types::vcs::vc_specs::template::CredentialTemplate;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "cred_templates")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub vc_type: String, // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub subject: serde_json::Value, // REQUEST
+    pub updated_at: DateTime<Utc>,  // DEFAULT
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Plan {
+    pub vc_type: VcType,
+    pub subject: serde_json::Value,
+}
+
+impl Model {
+    pub fn to_template(&self) -> CredentialTemplate {
+        let vc_type =
+            VcType::from_str(&self.vc_type).unwrap_or(VcType::Other(self.vc_type.clone()));
+        CredentialTemplate::new(vc_type, self.subject.clone())
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            vc_type: ActiveValue::Set(self.vc_type.to_string()),
+            subject: ActiveValue::Set(self.subject),
+            updated_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            vc_type: ActiveValue::Set(self.vc_type),
+            subject: ActiveValue::Set(self.subject),
+            updated_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
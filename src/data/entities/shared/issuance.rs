@@ -16,8 +16,11 @@
  */
 
 use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::extensions::empty_extensions;
+use crate::types::issuance::{DEFAULT_NONCE_EXPIRES_IN, NotificationEvent};
 use crate::types::vcs::{BuildCtx, VcTypeConfig};
 use crate::utils::create_opaque_token;
+use chrono::{DateTime, Duration, Utc};
 use sea_orm::ActiveValue;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -34,11 +37,21 @@ pub struct Model {
     pub token: String,
     pub token_expiration: i64,
     pub nonce: String,
+    pub nonce_expires_at: DateTime<Utc>,
+    pub nonce_consumed: bool,
     pub aud: String,
     pub issuer_did: String,
     pub credential_id: String,
     pub credential: Option<String>,
     pub build_ctx: BuildCtx,
+    pub notification_id: String,
+    pub notification_event: Option<NotificationEvent>,
+    pub notification_description: Option<String>,
+    pub notified_at: Option<DateTime<Utc>>,
+    pub extensions: serde_json::Value,
+    /// Bumped on every update so concurrent writers of the same issuance are detected.
+    #[serde(default)]
+    pub version: i32,
 }
 
 #[derive(Clone, Debug)]
@@ -65,11 +78,21 @@ impl IntoOverwriteActive<ActiveModel> for Plan {
             token: ActiveValue::Set(token),
             token_expiration: ActiveValue::Set(600),
             nonce: ActiveValue::Set(nonce),
+            nonce_expires_at: ActiveValue::Set(
+                Utc::now() + Duration::seconds(DEFAULT_NONCE_EXPIRES_IN as i64),
+            ),
+            nonce_consumed: ActiveValue::Set(false),
             aud: ActiveValue::Set(self.aud),
             issuer_did: ActiveValue::Set(self.issuer_did),
             credential_id: ActiveValue::Set(credential_id),
             credential: ActiveValue::Set(None),
             build_ctx: ActiveValue::Set(self.build_ctx),
+            notification_id: ActiveValue::Set(create_opaque_token()),
+            notification_event: ActiveValue::Set(None),
+            notification_description: ActiveValue::Set(None),
+            notified_at: ActiveValue::Set(None),
+            extensions: ActiveValue::Set(empty_extensions()),
+            version: ActiveValue::Set(0),
         }
     }
 }
@@ -84,15 +107,32 @@ impl IntoOverwriteActive<ActiveModel> for Model {
             token: ActiveValue::Set(self.token),
             token_expiration: ActiveValue::Set(self.token_expiration),
             nonce: ActiveValue::Set(self.nonce),
+            nonce_expires_at: ActiveValue::Set(self.nonce_expires_at),
+            nonce_consumed: ActiveValue::Set(self.nonce_consumed),
             aud: ActiveValue::Set(self.aud),
             issuer_did: ActiveValue::Set(self.issuer_did),
             credential_id: ActiveValue::Set(self.credential_id),
             credential: ActiveValue::Set(self.credential),
             build_ctx: ActiveValue::Set(self.build_ctx),
+            notification_id: ActiveValue::Set(self.notification_id),
+            notification_event: ActiveValue::Set(self.notification_event),
+            notification_description: ActiveValue::Set(self.notification_description),
+            notified_at: ActiveValue::Set(self.notified_at),
+            extensions: ActiveValue::Set(self.extensions),
+            version: ActiveValue::Set(self.version),
         }
     }
 }
 
+impl Model {
+    /// Replaces the current `c_nonce` with a fresh one, restarting its lifetime.
+    pub fn rotate_nonce(&mut self) {
+        self.nonce = create_opaque_token();
+        self.nonce_expires_at = Utc::now() + Duration::seconds(DEFAULT_NONCE_EXPIRES_IN as i64);
+        self.nonce_consumed = false;
+    }
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
 
@@ -15,6 +15,16 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+pub mod audit_entry;
+pub mod cred_template;
 pub mod issuance;
 pub mod participant;
+pub mod quota_counter;
+pub mod replay_entry;
 pub mod resource_req;
+pub mod revocation_event;
+pub mod rp_subscription;
+pub mod shared_state;
+pub mod status_entry;
+pub mod status_list;
+pub mod trusted_issuer;
@@ -15,8 +15,14 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::errors::{Errors, Outcome};
 use crate::services::repo::postgres::IntoOverwriteActive;
-use crate::types::participants::ParticipantType;
+use crate::types::extensions::empty_extensions;
+use crate::types::gnap::access_token::AccessToken;
+use crate::types::keys::DbKeySource;
+use crate::types::participants::{BusinessAttributes, ParticipantStatus, ParticipantType};
+use crate::utils::Clock;
+use axum::http::HeaderMap;
 use chrono::{DateTime, Utc};
 use sea_orm::entity::prelude::*;
 use sea_orm::{ActiveValue, DeriveEntityModel};
@@ -27,14 +33,30 @@ use serde::{Deserialize, Serialize};
 pub struct Model {
     #[sea_orm(primary_key)]
     pub participant_id: String, // REQUEST
-    pub participant_nick: String,          // REQUEST
-    pub participant_type: ParticipantType, // REQUEST
-    pub base_url: String,                  // REQUEST
-    pub token: Option<String>,             // REQUEST
-    pub saved_at: DateTime<Utc>,           // DEFAULT
-    pub last_interaction: DateTime<Utc>,   // DEFAULT
-    pub extra_fields: serde_json::Value,   // REQUEST
-    pub is_me: bool,                       // REQUEST
+    pub participant_nick: String,                 // REQUEST
+    pub participant_type: ParticipantType,        // REQUEST
+    pub base_url: String,                         // REQUEST
+    pub token: Option<String>,                    // REQUEST
+    pub token_expires_at: Option<DateTime<Utc>>,  // REQUEST
+    pub saved_at: DateTime<Utc>,                  // DEFAULT
+    pub last_interaction: DateTime<Utc>,          // DEFAULT
+    pub extra_fields: serde_json::Value,          // REQUEST
+    pub is_me: bool,                              // REQUEST
+    pub legal_name: Option<String>,               // VERIFIED
+    pub country_code: Option<String>,             // VERIFIED
+    pub registration_number: Option<String>,      // VERIFIED
+    pub registration_number_type: Option<String>, // VERIFIED
+    pub extensions: serde_json::Value,            // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub token_key: Option<DbKeySource>, // REQUEST
+    #[sea_orm(unique)]
+    pub instance_id: Option<String>, // REGISTRATION
+    #[sea_orm(column_type = "JsonBinary")]
+    pub client_key: Option<DbKeySource>, // REGISTRATION
+    #[serde(default)]
+    pub status: ParticipantStatus, // LIFECYCLE
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>, // LIFECYCLE
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -44,8 +66,141 @@ pub struct Plan {
     pub participant_type: ParticipantType,
     pub base_url: String,
     pub token: Option<String>,
+    pub token_expires_at: Option<DateTime<Utc>>,
+    pub token_key: Option<DbKeySource>,
     pub extra_fields: Option<serde_json::Value>,
     pub is_me: bool,
+    pub extensions: Option<serde_json::Value>,
+    #[serde(default)]
+    pub status: Option<ParticipantStatus>,
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl Model {
+    /// Overwrites the business columns with the attributes present in `attrs`.
+    pub fn apply_business(&mut self, attrs: BusinessAttributes) {
+        let current = BusinessAttributes {
+            legal_name: self.legal_name.take(),
+            country_code: self.country_code.take(),
+            registration_number: self.registration_number.take(),
+            registration_number_type: self.registration_number_type.take(),
+        };
+        let merged = attrs.merge(current);
+        self.legal_name = merged.legal_name;
+        self.country_code = merged.country_code;
+        self.registration_number = merged.registration_number;
+        self.registration_number_type = merged.registration_number_type;
+    }
+
+    /// Returns the lifecycle state of the mate, counting a lapsed membership as expired.
+    pub fn effective_status(&self) -> ParticipantStatus {
+        let lapsed = self.expires_at.is_some_and(|at| at <= Clock::now());
+        match self.status {
+            ParticipantStatus::Invited | ParticipantStatus::Active if lapsed => {
+                ParticipantStatus::Expired
+            }
+            status => status,
+        }
+    }
+
+    /// Moves the mate to lifecycle state `to`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ConflictError`] when the current state cannot move to `to`, and a
+    /// validation error when activating a mate whose membership has already lapsed.
+    pub fn transition(&mut self, to: ParticipantStatus) -> Outcome<()> {
+        let from = self.effective_status();
+        if !from.can_transition_to(to) {
+            return Err(Errors::conflict(
+                format!(
+                    "Participant {} cannot move from {from} to {to}",
+                    self.participant_id
+                ),
+                None,
+            ));
+        }
+        if to == ParticipantStatus::Active && self.expires_at.is_some_and(|at| at <= Clock::now()) {
+            return Err(Errors::validation(
+                format!(
+                    "Membership of {} has lapsed; extend it before activating",
+                    self.participant_id
+                ),
+                None,
+            ));
+        }
+        self.status = to;
+        Ok(())
+    }
+
+    /// Checks that the mate may use its access token.
+    ///
+    /// # Errors
+    /// Returns an unauthorized error when the membership has expired and a forbidden error
+    /// when the mate is suspended or has not been activated yet.
+    pub fn check_standing(&self) -> Outcome<()> {
+        match self.effective_status() {
+            ParticipantStatus::Active => Ok(()),
+            ParticipantStatus::Expired => Err(Errors::unauthorized(
+                format!("Membership of {} has expired", self.participant_id),
+                None,
+            )),
+            status => Err(Errors::forbidden(
+                format!("Participant {} is {status}", self.participant_id),
+                None,
+            )),
+        }
+    }
+
+    /// Bounds a token expiry by the membership expiry, so no token outlives the mate.
+    pub fn cap_token_expiry(&self, at: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+        match (at, self.expires_at) {
+            (Some(at), Some(limit)) => Some(at.min(limit)),
+            (at, limit) => at.or(limit),
+        }
+    }
+
+    /// Stores an access token issued at `issued_at` to a client proving possession of `key`.
+    ///
+    /// Unless the token was issued with the bearer flag, its later use must carry an
+    /// `httpsig` proof made with `key`.
+    pub fn bind_token(&mut self, token: &AccessToken, key: &DbKeySource, issued_at: DateTime<Utc>) {
+        self.token = Some(token.value.clone());
+        self.token_expires_at = self.cap_token_expiry(token.expires_at(issued_at));
+        self.token_key = (!token.is_bearer()).then(|| key.clone());
+    }
+
+    /// Checks the proof of possession of a request made with the access token.
+    ///
+    /// # Errors
+    /// Returns a security error when the token is key-bound and `headers` carry no valid
+    /// `httpsig` signature of the request made with the bound key.
+    pub fn check_token_proof(
+        &self,
+        headers: &HeaderMap,
+        method: &str,
+        url: &str,
+        body: &[u8],
+    ) -> Outcome<()> {
+        match &self.token_key {
+            Some(key) => key.verify_request(headers, method, url, body),
+            None => Ok(()),
+        }
+    }
+
+    /// Tells whether the access token has passed its expiry.
+    pub fn token_expired(&self) -> bool {
+        self.token_expires_at.is_some_and(|at| at <= Clock::now())
+    }
+
+    pub fn business(&self) -> BusinessAttributes {
+        BusinessAttributes {
+            legal_name: self.legal_name.clone(),
+            country_code: self.country_code.clone(),
+            registration_number: self.registration_number.clone(),
+            registration_number_type: self.registration_number_type.clone(),
+        }
+    }
 }
 
 impl IntoOverwriteActive<ActiveModel> for Plan {
@@ -56,10 +211,21 @@ impl IntoOverwriteActive<ActiveModel> for Plan {
             participant_type: ActiveValue::Set(self.participant_type),
             base_url: ActiveValue::Set(self.base_url),
             token: ActiveValue::Set(self.token),
+            token_expires_at: ActiveValue::Set(self.token_expires_at),
+            token_key: ActiveValue::Set(self.token_key),
+            instance_id: ActiveValue::Set(None),
+            client_key: ActiveValue::Set(None),
             saved_at: ActiveValue::Set(Utc::now()),
             last_interaction: ActiveValue::Set(Utc::now()),
             extra_fields: ActiveValue::Set(self.extra_fields.unwrap_or(serde_json::json!({}))),
             is_me: ActiveValue::Set(self.is_me),
+            legal_name: ActiveValue::Set(None),
+            country_code: ActiveValue::Set(None),
+            registration_number: ActiveValue::Set(None),
+            registration_number_type: ActiveValue::Set(None),
+            extensions: ActiveValue::Set(self.extensions.unwrap_or_else(empty_extensions)),
+            status: ActiveValue::Set(self.status.unwrap_or_default()),
+            expires_at: ActiveValue::Set(self.expires_at),
         }
     }
 }
@@ -72,10 +238,21 @@ impl IntoOverwriteActive<ActiveModel> for Model {
             participant_type: ActiveValue::Set(self.participant_type),
             base_url: ActiveValue::Set(self.base_url),
             token: ActiveValue::Set(self.token),
+            token_expires_at: ActiveValue::Set(self.token_expires_at),
+            token_key: ActiveValue::Set(self.token_key),
+            instance_id: ActiveValue::Set(self.instance_id),
+            client_key: ActiveValue::Set(self.client_key),
             saved_at: ActiveValue::Set(self.saved_at),
             last_interaction: ActiveValue::Set(Utc::now()),
             extra_fields: ActiveValue::Set(self.extra_fields),
             is_me: ActiveValue::Set(self.is_me),
+            legal_name: ActiveValue::Set(self.legal_name),
+            country_code: ActiveValue::Set(self.country_code),
+            registration_number: ActiveValue::Set(self.registration_number),
+            registration_number_type: ActiveValue::Set(self.registration_number_type),
+            extensions: ActiveValue::Set(self.extensions),
+            status: ActiveValue::Set(self.status),
+            expires_at: ActiveValue::Set(self.expires_at),
         }
     }
 }
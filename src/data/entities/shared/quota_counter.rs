@@ -0,0 +1,89 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::quota::QuotaKind;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "quota_counters")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,                  // DEFAULT
+    pub participant_id: String,      // REQUEST
+    pub kind: QuotaKind,             // REQUEST
+    pub window_start: DateTime<Utc>, // REQUEST
+    pub count: i32,                  // DEFAULT
+    pub created_at: DateTime<Utc>,   // DEFAULT
+    pub updated_at: DateTime<Utc>,   // DEFAULT
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Plan {
+    pub participant_id: String,
+    pub kind: QuotaKind,
+    pub window_start: DateTime<Utc>,
+}
+
+impl Plan {
+    /// Deterministic id, so concurrent increments of the same window hit the same row.
+    pub fn counter_id(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            self.kind,
+            self.window_start.timestamp(),
+            self.participant_id
+        )
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        let now = Utc::now();
+        ActiveModel {
+            id: ActiveValue::Set(self.counter_id()),
+            participant_id: ActiveValue::Set(self.participant_id),
+            kind: ActiveValue::Set(self.kind),
+            window_start: ActiveValue::Set(self.window_start),
+            count: ActiveValue::Set(1),
+            created_at: ActiveValue::Set(now),
+            updated_at: ActiveValue::Set(now),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            participant_id: ActiveValue::Set(self.participant_id),
+            kind: ActiveValue::Set(self.kind),
+            window_start: ActiveValue::Set(self.window_start),
+            count: ActiveValue::Set(self.count),
+            created_at: ActiveValue::Set(self.created_at),
+            updated_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,77 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::verification::ReplayKind;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "replay_entries")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // DEFAULT
+    pub kind: ReplayKind,          // REQUEST
+    pub value: String,             // REQUEST
+    pub expires_at: DateTime<Utc>, // REQUEST
+    pub created_at: DateTime<Utc>, // DEFAULT
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Plan {
+    pub kind: ReplayKind,
+    pub value: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Plan {
+    /// Deterministic id, so a second submission of the same value hits the same row.
+    pub fn entry_id(&self) -> String {
+        format!("{}:{}", self.kind, self.value)
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.entry_id()),
+            kind: ActiveValue::Set(self.kind),
+            value: ActiveValue::Set(self.value),
+            expires_at: ActiveValue::Set(self.expires_at),
+            created_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            kind: ActiveValue::Set(self.kind),
+            value: ActiveValue::Set(self.value),
+            expires_at: ActiveValue::Set(self.expires_at),
+            created_at: ActiveValue::Set(self.created_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
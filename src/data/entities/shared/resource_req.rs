@@ -15,6 +15,7 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::data::entities::StringList;
 use crate::services::repo::postgres::IntoOverwriteActive;
 use crate::types::gnap::grant_request::access::{AccessTokenFlag, AccessType};
 use crate::types::gnap::grant_request::interact::InteractAction;
@@ -30,10 +31,13 @@ pub struct Model {
     pub r#type: AccessType,                  // REQUEST
     #[sea_orm(column_type = "JsonBinary")]
     pub actions: Vec<InteractAction>,        // REQUEST
-    pub locations: Option<Vec<String>>,      // REQUEST
-    pub datatypes: Option<Vec<String>>,      // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub locations: Option<StringList>,       // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub datatypes: Option<StringList>,       // REQUEST
     pub identifier: Option<String>,          // REQUEST
-    pub privileges: Option<Vec<String>>,     // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub privileges: Option<StringList>,      // REQUEST
     pub label: Option<String>,               // REQUEST
     #[sea_orm(column_type = "JsonBinary")]
     pub flags: Option<Vec<AccessTokenFlag>>, // REQUEST
@@ -0,0 +1,68 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "revocation_events")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // DEFAULT
+    pub seq: i64,                  // DEFAULT (database sequence, feed cursor)
+    pub subject: String,           // REQUEST
+    pub reason: Option<String>,    // REQUEST
+    pub revoked_at: DateTime<Utc>, // DEFAULT
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Plan {
+    pub subject: String,
+    pub reason: Option<String>,
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
+            seq: ActiveValue::NotSet,
+            subject: ActiveValue::Set(self.subject),
+            reason: ActiveValue::Set(self.reason),
+            revoked_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            seq: ActiveValue::Set(self.seq),
+            subject: ActiveValue::Set(self.subject),
+            reason: ActiveValue::Set(self.reason),
+            revoked_at: ActiveValue::Set(self.revoked_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
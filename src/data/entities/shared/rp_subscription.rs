@@ -0,0 +1,117 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::StringList;
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::vcs::VcType;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "rp_subscriptions")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // RANDOM
+    pub callback_url: String,           // REQUEST
+    pub participant_id: Option<String>, // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub vc_type: Option<VcType>,        // REQUEST
+    pub purpose: Option<String>,        // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub claims: StringList,             // REQUEST
+    pub active: bool,                   // DEFAULT
+    pub created_at: DateTime<Utc>,      // DEFAULT
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Plan {
+    pub callback_url: String,
+    pub participant_id: Option<String>,
+    pub vc_type: Option<VcType>,
+    pub purpose: Option<String>,
+    #[serde(default)]
+    pub claims: Vec<String>,
+}
+
+impl Model {
+    /// Checks whether a finished verification falls inside this subscription filter.
+    ///
+    /// Every filter left empty acts as a wildcard.
+    pub fn matches(
+        &self,
+        holder: Option<&str>,
+        vc_types: &[VcType],
+        purpose: Option<&str>,
+    ) -> bool {
+        if !self.active {
+            return false;
+        }
+        if let Some(participant_id) = &self.participant_id {
+            if holder != Some(participant_id.as_str()) {
+                return false;
+            }
+        }
+        if let Some(vc_type) = &self.vc_type {
+            if !vc_types.contains(vc_type) {
+                return false;
+            }
+        }
+        if let Some(sub_purpose) = &self.purpose {
+            if purpose != Some(sub_purpose.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
+            callback_url: ActiveValue::Set(self.callback_url),
+            participant_id: ActiveValue::Set(self.participant_id),
+            vc_type: ActiveValue::Set(self.vc_type),
+            purpose: ActiveValue::Set(self.purpose),
+            claims: ActiveValue::Set(self.claims.into()),
+            active: ActiveValue::Set(true),
+            created_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            callback_url: ActiveValue::Set(self.callback_url),
+            participant_id: ActiveValue::Set(self.participant_id),
+            vc_type: ActiveValue::Set(self.vc_type),
+            purpose: ActiveValue::Set(self.purpose),
+            claims: ActiveValue::Set(self.claims),
+            active: ActiveValue::Set(self.active),
+            created_at: ActiveValue::Set(self.created_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,72 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "shared_state")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub key: String, // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub value: Value, // REQUEST
+    pub epoch: i64,                // REQUEST (fencing token of the writer)
+    pub instance_id: String,       // REQUEST
+    pub updated_at: DateTime<Utc>, // DEFAULT
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Plan {
+    pub key: String,
+    pub value: Value,
+    pub epoch: i64,
+    pub instance_id: String,
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            key: ActiveValue::Set(self.key),
+            value: ActiveValue::Set(self.value),
+            epoch: ActiveValue::Set(self.epoch),
+            instance_id: ActiveValue::Set(self.instance_id),
+            updated_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            key: ActiveValue::Set(self.key),
+            value: ActiveValue::Set(self.value),
+            epoch: ActiveValue::Set(self.epoch),
+            instance_id: ActiveValue::Set(self.instance_id),
+            updated_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
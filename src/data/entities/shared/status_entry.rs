@@ -0,0 +1,81 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::issuance::StatusPurpose;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "status_entries")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,                // RANDOM
+    pub credential_id: String,     // REQUEST
+    pub list_id: String,           // REQUEST
+    pub index: i32,                // REQUEST
+    pub purpose: StatusPurpose,    // REQUEST
+    pub flagged: bool,             // DEFAULT
+    pub created_at: DateTime<Utc>, // DEFAULT
+    pub updated_at: DateTime<Utc>, // DEFAULT
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Plan {
+    pub credential_id: String,
+    pub list_id: String,
+    pub index: i32,
+    pub purpose: StatusPurpose,
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        let now = Utc::now();
+        ActiveModel {
+            id: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
+            credential_id: ActiveValue::Set(self.credential_id),
+            list_id: ActiveValue::Set(self.list_id),
+            index: ActiveValue::Set(self.index),
+            purpose: ActiveValue::Set(self.purpose),
+            flagged: ActiveValue::Set(false),
+            created_at: ActiveValue::Set(now),
+            updated_at: ActiveValue::Set(now),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            credential_id: ActiveValue::Set(self.credential_id),
+            list_id: ActiveValue::Set(self.list_id),
+            index: ActiveValue::Set(self.index),
+            purpose: ActiveValue::Set(self.purpose),
+            flagged: ActiveValue::Set(self.flagged),
+            created_at: ActiveValue::Set(self.created_at),
+            updated_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
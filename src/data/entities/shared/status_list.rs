@@ -0,0 +1,133 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::issuance::{Bitstring, StatusPurpose};
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "status_lists")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,                 // RANDOM
+    pub purpose: StatusPurpose,     // REQUEST
+    pub size: i32,                  // REQUEST
+    pub next_index: i32,            // DEFAULT
+    pub bits: Vec<u8>,              // DEFAULT
+    pub allocated: Option<Vec<u8>>, // DEFAULT
+    pub created_at: DateTime<Utc>,  // DEFAULT
+    pub updated_at: DateTime<Utc>,  // DEFAULT
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Plan {
+    pub purpose: StatusPurpose,
+    pub size: i32,
+}
+
+impl Model {
+    pub fn bitstring(&self) -> Bitstring {
+        Bitstring::from_bytes(self.bits.clone())
+    }
+    pub fn is_full(&self) -> bool {
+        self.next_index >= self.size
+    }
+
+    /// Returns the positions already handed out. Lists created before positions were drawn
+    /// at random have no map and were filled sequentially.
+    pub fn allocation(&self) -> Bitstring {
+        if let Some(bytes) = &self.allocated {
+            return Bitstring::from_bytes(bytes.clone());
+        }
+        let mut map = Bitstring::new(self.size);
+        for index in 0..self.next_index.min(self.size) {
+            let _ = map.set(index, true);
+        }
+        map
+    }
+
+    /// Reserves a uniformly random free position, so indexes do not leak issuance order.
+    pub fn claim_random_index(&mut self) -> Outcome<i32> {
+        if self.is_full() {
+            return Err(Errors::conflict(
+                format!("Status list {} is full", self.id),
+                None,
+            ));
+        }
+
+        let mut map = self.allocation();
+        let mut skip = rand::thread_rng().gen_range(0..self.size - self.next_index);
+        for index in 0..self.size {
+            if map.get(index)? {
+                continue;
+            }
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            map.set(index, true)?;
+            self.next_index += 1;
+            self.allocated = Some(map.into_bytes());
+            return Ok(index);
+        }
+        Err(Errors::crazy(
+            format!("Status list {} has no free position left", self.id),
+            None,
+        ))
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        let now = Utc::now();
+        ActiveModel {
+            id: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
+            purpose: ActiveValue::Set(self.purpose),
+            size: ActiveValue::Set(self.size),
+            next_index: ActiveValue::Set(0),
+            bits: ActiveValue::Set(Bitstring::new(self.size).into_bytes()),
+            allocated: ActiveValue::Set(Some(Bitstring::new(self.size).into_bytes())),
+            created_at: ActiveValue::Set(now),
+            updated_at: ActiveValue::Set(now),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            purpose: ActiveValue::Set(self.purpose),
+            size: ActiveValue::Set(self.size),
+            next_index: ActiveValue::Set(self.next_index),
+            bits: ActiveValue::Set(self.bits),
+            allocated: ActiveValue::Set(self.allocated),
+            created_at: ActiveValue::Set(self.created_at),
+            updated_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
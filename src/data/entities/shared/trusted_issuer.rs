@@ -0,0 +1,86 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::vcs::VcType;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "trusted_issuers")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // RANDOM
+    pub issuer_did: String,        // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub vc_type: Option<VcType>,   // REQUEST
+    pub name: Option<String>,      // REQUEST
+    pub source: Option<String>,    // REQUEST
+    pub created_at: DateTime<Utc>, // DEFAULT
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Plan {
+    pub issuer_did: String,
+    pub vc_type: Option<VcType>,
+    pub name: Option<String>,
+    /// External registry the entry was synced from. Manual registrations leave it empty.
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+impl Model {
+    /// Checks whether this registration allows its issuer to issue `vc_type`.
+    ///
+    /// A registration without a type trusts the issuer for every credential type.
+    pub fn covers(&self, vc_type: &VcType) -> bool {
+        self.vc_type.as_ref().is_none_or(|t| t == vc_type)
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
+            issuer_did: ActiveValue::Set(self.issuer_did),
+            vc_type: ActiveValue::Set(self.vc_type),
+            name: ActiveValue::Set(self.name),
+            source: ActiveValue::Set(self.source),
+            created_at: ActiveValue::Set(Utc::now()),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            issuer_did: ActiveValue::Set(self.issuer_did),
+            vc_type: ActiveValue::Set(self.vc_type),
+            name: ActiveValue::Set(self.name),
+            source: ActiveValue::Set(self.source),
+            created_at: ActiveValue::Set(self.created_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -17,4 +17,5 @@
 
 pub mod did;
 pub mod key;
+pub mod pending_presentation;
 pub mod vc;
@@ -0,0 +1,100 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::errors::Outcome;
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::types::wallet::{ConsentStatus, PresentationCandidate};
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Presentation request held back until the holder approves or rejects it.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "pending_presentations")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // RANDOM
+    pub request_uri: String,      // REQUEST
+    pub verifier: Option<String>, // REQUEST
+    #[sea_orm(column_type = "JsonBinary")]
+    pub candidates: Value, // REQUEST
+    pub status: ConsentStatus,    // DECISION
+    #[sea_orm(column_type = "JsonBinary")]
+    pub selected: Option<Value>, // DECISION
+    pub error: Option<String>,    // DECISION
+    pub created_at: DateTime<Utc>, // DEFAULT
+    pub decided_at: Option<DateTime<Utc>>, // DECISION
+}
+
+#[derive(Clone, Debug)]
+pub struct Plan {
+    pub request_uri: String,
+    pub verifier: Option<String>,
+    pub candidates: Vec<PresentationCandidate>,
+}
+
+impl Model {
+    pub fn candidates(&self) -> Outcome<Vec<PresentationCandidate>> {
+        Ok(serde_json::from_value(self.candidates.clone())?)
+    }
+
+    /// Closes the request with `status`, remembering the credentials presented if any.
+    pub fn decide(&mut self, status: ConsentStatus, selected: Option<&[String]>) {
+        self.status = status;
+        self.selected = selected.map(|ids| Value::from(ids.to_vec()));
+        self.decided_at = Some(Utc::now());
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Plan {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(uuid::Uuid::new_v4().to_string()),
+            request_uri: ActiveValue::Set(self.request_uri),
+            verifier: ActiveValue::Set(self.verifier),
+            candidates: ActiveValue::Set(serde_json::to_value(&self.candidates).unwrap_or_default()),
+            status: ActiveValue::Set(ConsentStatus::Pending),
+            selected: ActiveValue::Set(None),
+            error: ActiveValue::Set(None),
+            created_at: ActiveValue::Set(Utc::now()),
+            decided_at: ActiveValue::Set(None),
+        }
+    }
+}
+
+impl IntoOverwriteActive<ActiveModel> for Model {
+    fn into_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            request_uri: ActiveValue::Set(self.request_uri),
+            verifier: ActiveValue::Set(self.verifier),
+            candidates: ActiveValue::Set(self.candidates),
+            status: ActiveValue::Set(self.status),
+            selected: ActiveValue::Set(self.selected),
+            error: ActiveValue::Set(self.error),
+            created_at: ActiveValue::Set(self.created_at),
+            decided_at: ActiveValue::Set(self.decided_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -15,7 +15,23 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod runner;
+
+pub mod portable;
 pub mod received;
 pub mod sent;
 pub mod shared;
 pub mod wallet;
+pub use runner::{
+    MigrateCommand, MigrationReport, MigrationSource, MigrationState, migrate_down, migrate_up,
+    migration_status, run_migrate_command,
+};
+
+/// Sources of every bundled migration, for checksum verification.
+pub fn get_migration_sources() -> Vec<MigrationSource> {
+    let mut sources = shared::get_shared_sources();
+    sources.extend(received::get_recv_sources());
+    sources.extend(sent::get_sent_sources());
+    sources.extend(wallet::get_wallet_sources());
+    sources
+}
@@ -0,0 +1,145 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Stand-ins for the baseline migrations that create `TEXT[]` columns.
+//!
+//! Each one keeps the name of the baseline it replaces and runs it untouched on Postgres, where
+//! `m20261016_120018_verification_vcs_json` and `m20261016_120019_string_list_json` later turn
+//! the arrays into JSON. SQLite has no array type, so there the same table is created with the
+//! lists as JSON from the start. Register these instead of the baselines to support both.
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DbBackend;
+
+use super::received::m20260622_120012_verification::{self as verification, RecvVerification};
+use super::shared::m20260622_120001_resource_req::{self as resource_req, ResourcesReqs};
+
+/// [`resource_req`] with JSON string lists outside Postgres.
+pub struct ResourceReq;
+
+impl MigrationName for ResourceReq {
+    fn name(&self) -> &str {
+        "m20260622_120001_resource_req"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for ResourceReq {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DbBackend::Postgres {
+            return resource_req::Migration.up(manager).await;
+        }
+        manager
+            .create_table(
+                Table::create()
+                    .table(ResourcesReqs::Table)
+                    .col(
+                        ColumnDef::new(ResourcesReqs::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ResourcesReqs::Type)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ResourcesReqs::Actions)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ResourcesReqs::Locations).json_binary())
+                    .col(ColumnDef::new(ResourcesReqs::Datatypes).json_binary())
+                    .col(ColumnDef::new(ResourcesReqs::Identifier).string())
+                    .col(ColumnDef::new(ResourcesReqs::Privileges).json_binary())
+                    .col(ColumnDef::new(ResourcesReqs::Label).string())
+                    .col(ColumnDef::new(ResourcesReqs::Flags).json_binary())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        resource_req::Migration.down(manager).await
+    }
+}
+
+/// [`verification`] with a JSON `vcs` list outside Postgres.
+pub struct RecvVerificationTable;
+
+impl MigrationName for RecvVerificationTable {
+    fn name(&self) -> &str {
+        "m20260622_120012_verification"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for RecvVerificationTable {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DbBackend::Postgres {
+            return verification::Migration.up(manager).await;
+        }
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecvVerification::Table)
+                    .col(
+                        ColumnDef::new(RecvVerification::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RecvVerification::State).string().not_null())
+                    .col(ColumnDef::new(RecvVerification::Nonce).string().not_null())
+                    .col(
+                        ColumnDef::new(RecvVerification::VcType)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecvVerification::Audience)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RecvVerification::Holder).string())
+                    .col(ColumnDef::new(RecvVerification::Vpt).string())
+                    .col(
+                        ColumnDef::new(RecvVerification::Vcs)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecvVerification::Status)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecvVerification::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RecvVerification::EndedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        verification::Migration.down(manager).await
+    }
+}
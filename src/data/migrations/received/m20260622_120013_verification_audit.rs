@@ -0,0 +1,107 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecvVerificationAudit::Table)
+                    .col(
+                        ColumnDef::new(RecvVerificationAudit::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(RecvVerificationAudit::VerificationId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecvVerificationAudit::Request)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RecvVerificationAudit::VpToken).text())
+                    .col(ColumnDef::new(RecvVerificationAudit::PresentationSubmission).text())
+                    .col(
+                        ColumnDef::new(RecvVerificationAudit::Claims)
+                            .json_binary()
+                            .not_null()
+                            .default("[]"),
+                    )
+                    .col(
+                        ColumnDef::new(RecvVerificationAudit::Checks)
+                            .json_binary()
+                            .not_null()
+                            .default("[]"),
+                    )
+                    .col(
+                        ColumnDef::new(RecvVerificationAudit::Outcome)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RecvVerificationAudit::FailureReason).text())
+                    .col(
+                        ColumnDef::new(RecvVerificationAudit::RecordedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_recv_verification_audit_verification_id")
+                    .table(RecvVerificationAudit::Table)
+                    .col(RecvVerificationAudit::VerificationId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RecvVerificationAudit::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum RecvVerificationAudit {
+    #[iden = "recv_verification_audit"]
+    Table,
+    Id,
+    VerificationId,
+    Request,
+    VpToken,
+    PresentationSubmission,
+    Claims,
+    Checks,
+    Outcome,
+    FailureReason,
+    RecordedAt,
+}
@@ -0,0 +1,58 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DbBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE: &str = "recv_verification";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Only Postgres ever had the `TEXT[]` column; SQLite created it as JSON already.
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+        db.execute_unprepared(&format!(
+            r#"ALTER TABLE "{TABLE}" ALTER COLUMN "vcs" TYPE jsonb USING to_jsonb("vcs")"#
+        ))
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        // A `USING` clause cannot hold the subquery that unpacks the JSON array, so the
+        // column is rebuilt instead.
+        let db = manager.get_connection();
+        db.execute_unprepared(&format!(
+            r#"ALTER TABLE "{TABLE}" ADD COLUMN "vcs_array" TEXT[];
+            UPDATE "{TABLE}" SET "vcs_array" = ARRAY(SELECT jsonb_array_elements_text("vcs"));
+            ALTER TABLE "{TABLE}" DROP COLUMN "vcs";
+            ALTER TABLE "{TABLE}" RENAME COLUMN "vcs_array" TO "vcs";
+            ALTER TABLE "{TABLE}" ALTER COLUMN "vcs" SET NOT NULL"#
+        ))
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecvAccessToken::Table)
+                    .col(
+                        ColumnDef::new(RecvAccessToken::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RecvAccessToken::GrantId).string().not_null())
+                    .col(
+                        ColumnDef::new(RecvAccessToken::ParticipantId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RecvAccessToken::Label).string().not_null())
+                    .col(ColumnDef::new(RecvAccessToken::TokenKey).json_binary())
+                    .col(ColumnDef::new(RecvAccessToken::ExpiresAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(RecvAccessToken::IssuedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_recv_access_token_grant_label")
+                    .table(RecvAccessToken::Table)
+                    .col(RecvAccessToken::GrantId)
+                    .col(RecvAccessToken::Label)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RecvAccessToken::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum RecvAccessToken {
+    #[iden = "recv_access_token"]
+    Table,
+    Id,
+    GrantId,
+    ParticipantId,
+    Label,
+    TokenKey,
+    ExpiresAt,
+    IssuedAt,
+}
@@ -0,0 +1,119 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Default of the new NOT NULL `expires_at`: sessions opened before the column existed count
+/// as expired. SQLite only accepts a constant default when adding a column.
+const EPOCH: &str = "1970-01-01 00:00:00+00:00";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite takes a single column per `ALTER TABLE`.
+        for column in [
+            ColumnDef::new(RecvVerification::Purpose)
+                .string()
+                .to_owned(),
+            ColumnDef::new(RecvVerification::ResponseMode)
+                .string_len(16)
+                .not_null()
+                .default("direct_post")
+                .to_owned(),
+            ColumnDef::new(RecvVerification::Receipt).text().to_owned(),
+            ColumnDef::new(RecvVerification::ResponseKey)
+                .text()
+                .to_owned(),
+            ColumnDef::new(RecvVerification::ExpiresAt)
+                .timestamp_with_time_zone()
+                .not_null()
+                .default(EPOCH)
+                .to_owned(),
+            ColumnDef::new(RecvVerification::Claims)
+                .json_binary()
+                .not_null()
+                .default("[]")
+                .to_owned(),
+            ColumnDef::new(RecvVerification::RedactionProfile)
+                .string()
+                .to_owned(),
+            ColumnDef::new(RecvVerification::WebhookUrl)
+                .string()
+                .to_owned(),
+            ColumnDef::new(RecvVerification::PdId).string().to_owned(),
+            ColumnDef::new(RecvVerification::Version)
+                .integer()
+                .not_null()
+                .default(0)
+                .to_owned(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(RecvVerification::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            RecvVerification::Purpose,
+            RecvVerification::ResponseMode,
+            RecvVerification::Receipt,
+            RecvVerification::ResponseKey,
+            RecvVerification::ExpiresAt,
+            RecvVerification::Claims,
+            RecvVerification::RedactionProfile,
+            RecvVerification::WebhookUrl,
+            RecvVerification::PdId,
+            RecvVerification::Version,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(RecvVerification::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum RecvVerification {
+    #[iden = "recv_verification"]
+    Table,
+    Purpose,
+    ResponseMode,
+    Receipt,
+    ResponseKey,
+    ExpiresAt,
+    Claims,
+    RedactionProfile,
+    WebhookUrl,
+    PdId,
+    Version,
+}
@@ -15,17 +15,90 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use super::MigrationSource;
 use sea_orm_migration::MigrationTrait;
 
 pub mod m20260622_120010_grant;
 pub mod m20260622_120011_interaction;
 pub mod m20260622_120012_verification;
+pub mod m20260622_120013_verification_audit;
+pub mod m20260622_120014_presentation_definition;
+pub mod m20260622_120015_evidence_bundle;
+pub mod m20261016_120016_verification_query_language;
+pub mod m20261016_120018_verification_vcs_json;
+pub mod m20261016_120023_grant_extensions;
+pub mod m20261016_120025_recv_access_token;
+pub mod m20261016_120028_interaction_last_polled_at;
+pub mod m20261016_120029_verification_columns;
 
 /// All received-side migrations, executed together.
 pub fn get_recv_migrations() -> Vec<Box<dyn MigrationTrait>> {
     vec![
         Box::new(m20260622_120010_grant::Migration),
         Box::new(m20260622_120011_interaction::Migration),
-        Box::new(m20260622_120012_verification::Migration),
+        Box::new(super::portable::RecvVerificationTable),
+        Box::new(m20260622_120013_verification_audit::Migration),
+        Box::new(m20260622_120014_presentation_definition::Migration),
+        Box::new(m20260622_120015_evidence_bundle::Migration),
+        Box::new(m20261016_120016_verification_query_language::Migration),
+        Box::new(m20261016_120018_verification_vcs_json::Migration),
+        Box::new(m20261016_120023_grant_extensions::Migration),
+        Box::new(m20261016_120025_recv_access_token::Migration),
+        Box::new(m20261016_120028_interaction_last_polled_at::Migration),
+        Box::new(m20261016_120029_verification_columns::Migration),
+    ]
+}
+
+/// Sources of the received-side migrations, checksummed to detect drift.
+pub fn get_recv_sources() -> Vec<MigrationSource> {
+    vec![
+        MigrationSource::new(
+            "m20260622_120010_grant",
+            include_str!("m20260622_120010_grant.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120011_interaction",
+            include_str!("m20260622_120011_interaction.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120012_verification",
+            include_str!("m20260622_120012_verification.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120013_verification_audit",
+            include_str!("m20260622_120013_verification_audit.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120014_presentation_definition",
+            include_str!("m20260622_120014_presentation_definition.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120015_evidence_bundle",
+            include_str!("m20260622_120015_evidence_bundle.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120016_verification_query_language",
+            include_str!("m20261016_120016_verification_query_language.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120018_verification_vcs_json",
+            include_str!("m20261016_120018_verification_vcs_json.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120023_grant_extensions",
+            include_str!("m20261016_120023_grant_extensions.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120025_recv_access_token",
+            include_str!("m20261016_120025_recv_access_token.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120028_interaction_last_polled_at",
+            include_str!("m20261016_120028_interaction_last_polled_at.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120029_verification_columns",
+            include_str!("m20261016_120029_verification_columns.rs"),
+        ),
     ]
 }
@@ -0,0 +1,465 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::errors::{Errors, Outcome};
+use crate::utils::encode_url_safe_no_pad;
+use chrono::Utc;
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::{MigrationStatus, MigratorTrait};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tracing::info;
+
+/// Source of a migration, checksummed so edits made after it was applied are detected.
+#[derive(Clone, Debug)]
+pub struct MigrationSource {
+    pub name: &'static str,
+    pub source: &'static str,
+}
+
+impl MigrationSource {
+    pub fn new(name: &'static str, source: &'static str) -> Self {
+        Self { name, source }
+    }
+
+    /// Digest of the code alone, so reformatting, doc edits or the license header are not
+    /// reported as drift.
+    pub fn checksum(&self) -> String {
+        let normalized: String = strip_comments(self.source).split_whitespace().collect();
+        encode_url_safe_no_pad(Sha256::digest(normalized.as_bytes()))
+    }
+}
+
+/// Entry point of the `migrate` command a consumer binary exposes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MigrateCommand {
+    /// Applies every pending migration.
+    Up,
+    /// Rolls back the last `steps` applied migrations.
+    Down { steps: u32 },
+    /// Lists every migration with its state, changing nothing.
+    Status,
+    /// Records the current checksum of every applied migration that has none yet.
+    ///
+    /// Run once, after checking the schema by hand, on databases migrated before checksums were
+    /// tracked.
+    Baseline,
+}
+
+impl MigrateCommand {
+    /// Parses the arguments following `migrate`: `up`, `down [steps]`, `status` or `baseline`.
+    ///
+    /// No argument at all means `up`.
+    pub fn from_args<I, S>(args: I) -> Outcome<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let args: Vec<S> = args.into_iter().collect();
+        let args: Vec<&str> = args.iter().map(AsRef::as_ref).collect();
+        match args.as_slice() {
+            [] | ["up"] => Ok(MigrateCommand::Up),
+            ["status"] => Ok(MigrateCommand::Status),
+            ["baseline"] => Ok(MigrateCommand::Baseline),
+            ["down"] => Ok(MigrateCommand::Down { steps: 1 }),
+            ["down", steps] => {
+                let steps = u32::from_str(steps).map_err(|e| {
+                    Errors::parse(
+                        format!("Invalid migration steps: {}", steps),
+                        Some(Box::new(e)),
+                    )
+                })?;
+                Ok(MigrateCommand::Down { steps })
+            }
+            _ => Err(Errors::parse(
+                format!("Unknown migrate command: {}", args.join(" ")),
+                None,
+            )),
+        }
+    }
+}
+
+/// State of one migration against the connected database.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationState {
+    /// Not applied yet.
+    Pending,
+    /// Applied, and its source still matches the recorded checksum.
+    Applied,
+    /// Applied, but its source changed since: the schema may differ from other environments.
+    Drifted,
+    /// Applied without a checksum to compare against, or without a known source.
+    Unverified,
+}
+
+impl Display for MigrationState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            MigrationState::Pending => "pending",
+            MigrationState::Applied => "applied",
+            MigrationState::Drifted => "drifted",
+            MigrationState::Unverified => "unverified",
+        };
+        f.pad(s)
+    }
+}
+
+/// One line of `migrate status`.
+#[derive(Clone, Debug, Serialize)]
+pub struct MigrationReport {
+    pub name: String,
+    pub state: MigrationState,
+    /// Checksum of the source compiled into this binary.
+    pub checksum: Option<String>,
+    /// Checksum recorded when the migration was applied.
+    pub recorded: Option<String>,
+}
+
+impl Display for MigrationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:<12} {}", self.state, self.name)
+    }
+}
+
+/// Runs `command` with the migrator `M`, then reports the state of every migration.
+///
+/// `sources` are looked up by migration name; use
+/// [`get_migration_sources`](super::get_migration_sources) for the bundled ones.
+pub async fn run_migrate_command<M: MigratorTrait>(
+    db: &DatabaseConnection,
+    command: MigrateCommand,
+    sources: &[MigrationSource],
+) -> Outcome<Vec<MigrationReport>> {
+    match command {
+        MigrateCommand::Up => migrate_up::<M>(db, sources).await?,
+        MigrateCommand::Down { steps } => migrate_down::<M>(db, steps).await?,
+        MigrateCommand::Status => {}
+        MigrateCommand::Baseline => baseline::<M>(db, sources).await?,
+    }
+    migration_status::<M>(db, sources).await
+}
+
+/// Applies every pending migration and records the checksum of each one it applied.
+///
+/// Migrations applied before checksums were tracked stay `unverified`: their source may have
+/// changed since, so only [`baseline`] vouches for them.
+///
+/// # Errors
+/// Returns an [`Errors::ConflictError`] without touching the schema when an applied migration
+/// drifted from its recorded checksum.
+pub async fn migrate_up<M: MigratorTrait>(
+    db: &DatabaseConnection,
+    sources: &[MigrationSource],
+) -> Outcome<()> {
+    let before = migration_status::<M>(db, sources).await?;
+    let drifted: Vec<&str> = before
+        .iter()
+        .filter(|report| report.state == MigrationState::Drifted)
+        .map(|report| report.name.as_str())
+        .collect();
+    if !drifted.is_empty() {
+        return Err(Errors::conflict(
+            format!("Applied migrations changed since: {}", drifted.join(", ")),
+            None,
+        ));
+    }
+
+    M::up(db, None)
+        .await
+        .map_err(|e| Errors::db("Unable to apply migrations", Some(Box::new(e))))?;
+
+    let pending: Vec<&str> = before
+        .iter()
+        .filter(|report| report.state == MigrationState::Pending)
+        .map(|report| report.name.as_str())
+        .collect();
+    for report in migration_status::<M>(db, sources).await? {
+        if report.state == MigrationState::Pending || !pending.contains(&report.name.as_str()) {
+            continue;
+        }
+        if let Some(checksum) = report.checksum {
+            record_checksum(db, &report.name, &checksum).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Records the current checksum of every `unverified` migration, trusting the schema as it is.
+pub async fn baseline<M: MigratorTrait>(
+    db: &DatabaseConnection,
+    sources: &[MigrationSource],
+) -> Outcome<()> {
+    let mut baselined = 0;
+    for report in migration_status::<M>(db, sources).await? {
+        if report.state != MigrationState::Unverified {
+            continue;
+        }
+        if let Some(checksum) = report.checksum {
+            record_checksum(db, &report.name, &checksum).await?;
+            baselined += 1;
+        }
+    }
+    info!("Baselined {} migration(s)", baselined);
+    Ok(())
+}
+
+/// Rolls back the last `steps` applied migrations, forgetting their checksums.
+pub async fn migrate_down<M: MigratorTrait>(db: &DatabaseConnection, steps: u32) -> Outcome<()> {
+    M::down(db, Some(steps))
+        .await
+        .map_err(|e| Errors::db("Unable to roll back migrations", Some(Box::new(e))))?;
+
+    let pending: Vec<String> = M::get_pending_migrations(db)
+        .await
+        .map_err(|e| Errors::db("Unable to list pending migrations", Some(Box::new(e))))?
+        .iter()
+        .map(|migration| migration.name().to_string())
+        .collect();
+    forget_checksums(db, pending).await?;
+    info!("Rolled back {} migration(s)", steps);
+    Ok(())
+}
+
+/// Lists every migration of `M` in order, comparing applied ones against their checksums.
+pub async fn migration_status<M: MigratorTrait>(
+    db: &DatabaseConnection,
+    sources: &[MigrationSource],
+) -> Outcome<Vec<MigrationReport>> {
+    let migrations = M::get_migration_with_status(db)
+        .await
+        .map_err(|e| Errors::db("Unable to read migration status", Some(Box::new(e))))?;
+    let recorded = recorded_checksums(db).await?;
+
+    Ok(migrations
+        .iter()
+        .map(|migration| {
+            let name = migration.name().to_string();
+            let checksum = sources
+                .iter()
+                .find(|source| source.name == name)
+                .map(MigrationSource::checksum);
+            let recorded = recorded.get(&name).cloned();
+            let state = match (migration.status(), &checksum, &recorded) {
+                (MigrationStatus::Pending, _, _) => MigrationState::Pending,
+                (MigrationStatus::Applied, Some(current), Some(stored)) if current == stored => {
+                    MigrationState::Applied
+                }
+                (MigrationStatus::Applied, Some(_), Some(_)) => MigrationState::Drifted,
+                (MigrationStatus::Applied, _, _) => MigrationState::Unverified,
+            };
+            MigrationReport {
+                name,
+                state,
+                checksum,
+                recorded,
+            }
+        })
+        .collect())
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Drops line and block comments from Rust source, leaving string and char literals untouched.
+fn strip_comments(source: &str) -> String {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::with_capacity(source.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match (chars[i], chars.get(i + 1).copied()) {
+            ('/', Some('/')) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            ('/', Some('*')) => {
+                let mut depth = 0;
+                while i < chars.len() {
+                    match (chars[i], chars.get(i + 1).copied()) {
+                        ('/', Some('*')) => {
+                            depth += 1;
+                            i += 2;
+                        }
+                        ('*', Some('/')) => {
+                            depth -= 1;
+                            i += 2;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => i += 1,
+                    }
+                }
+                out.push(' ');
+            }
+            ('r', Some('"' | '#')) if raw_string_hashes(&chars[i + 1..]).is_some() => {
+                let hashes = raw_string_hashes(&chars[i + 1..]).unwrap_or_default();
+                let start = i;
+                i += hashes + 2;
+                while i < chars.len() {
+                    if chars[i] == '"' && chars[i + 1..].iter().take(hashes).all(|c| *c == '#') {
+                        i += hashes + 1;
+                        break;
+                    }
+                    i += 1;
+                }
+                out.extend(&chars[start..i.min(chars.len())]);
+            }
+            ('"', _) => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                i += 1;
+                out.extend(&chars[start..i.min(chars.len())]);
+            }
+            ('\'', Some('\\')) => {
+                let start = i;
+                i += 2;
+                while i < chars.len() && chars[i] != '\'' {
+                    i += 1;
+                }
+                i += 1;
+                out.extend(&chars[start..i.min(chars.len())]);
+            }
+            ('\'', Some(_)) if chars.get(i + 2) == Some(&'\'') => {
+                out.extend(&chars[i..i + 3]);
+                i += 3;
+            }
+            (c, _) => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Number of `#` opening a raw string literal right after its `r`, if one starts there.
+fn raw_string_hashes(chars: &[char]) -> Option<usize> {
+    let hashes = chars.iter().take_while(|c| **c == '#').count();
+    match chars.get(hashes) {
+        Some('"') => Some(hashes),
+        _ => None,
+    }
+}
+
+// ===== CHECKSUM TABLE ============================================================================
+
+#[derive(Iden)]
+enum MigrationChecksum {
+    #[iden = "ymir_migration_checksum"]
+    Table,
+    Version,
+    Checksum,
+    RecordedAt,
+}
+
+async fn ensure_checksum_table(db: &DatabaseConnection) -> Outcome<()> {
+    SchemaManager::new(db)
+        .create_table(
+            Table::create()
+                .table(MigrationChecksum::Table)
+                .if_not_exists()
+                .col(
+                    ColumnDef::new(MigrationChecksum::Version)
+                        .string()
+                        .not_null()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(MigrationChecksum::Checksum)
+                        .string()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(MigrationChecksum::RecordedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .to_owned(),
+        )
+        .await
+        .map_err(|e| {
+            Errors::db(
+                "Unable to create migration checksum table",
+                Some(Box::new(e)),
+            )
+        })
+}
+
+async fn recorded_checksums(db: &DatabaseConnection) -> Outcome<HashMap<String, String>> {
+    ensure_checksum_table(db).await?;
+    let query = Query::select()
+        .columns([MigrationChecksum::Version, MigrationChecksum::Checksum])
+        .from(MigrationChecksum::Table)
+        .to_owned();
+    let rows = db
+        .query_all(db.get_database_backend().build(&query))
+        .await
+        .map_err(|e| Errors::db("Unable to read migration checksums", Some(Box::new(e))))?;
+
+    rows.iter()
+        .map(|row| {
+            let version: String = row.try_get("", "version")?;
+            let checksum: String = row.try_get("", "checksum")?;
+            Ok((version, checksum))
+        })
+        .collect::<Result<_, DbErr>>()
+        .map_err(|e| Errors::db("Unable to read migration checksums", Some(Box::new(e))))
+}
+
+async fn record_checksum(db: &DatabaseConnection, name: &str, checksum: &str) -> Outcome<()> {
+    let query = Query::insert()
+        .into_table(MigrationChecksum::Table)
+        .columns([
+            MigrationChecksum::Version,
+            MigrationChecksum::Checksum,
+            MigrationChecksum::RecordedAt,
+        ])
+        .values([name.into(), checksum.into(), Utc::now().into()])
+        .map_err(|e| Errors::db("Unable to build migration checksum", Some(Box::new(e))))?
+        .on_conflict(
+            OnConflict::column(MigrationChecksum::Version)
+                .update_columns([MigrationChecksum::Checksum, MigrationChecksum::RecordedAt])
+                .to_owned(),
+        )
+        .to_owned();
+    db.execute(db.get_database_backend().build(&query))
+        .await
+        .map_err(|e| Errors::db("Unable to record migration checksum", Some(Box::new(e))))?;
+    Ok(())
+}
+
+async fn forget_checksums(db: &DatabaseConnection, names: Vec<String>) -> Outcome<()> {
+    if names.is_empty() {
+        return Ok(());
+    }
+    let query = Query::delete()
+        .from_table(MigrationChecksum::Table)
+        .and_where(Expr::col(MigrationChecksum::Version).is_in(names))
+        .to_owned();
+    db.execute(db.get_database_backend().build(&query))
+        .await
+        .map_err(|e| Errors::db("Unable to forget migration checksums", Some(Box::new(e))))?;
+    Ok(())
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite takes a single column per `ALTER TABLE`.
+        for column in [
+            ColumnDef::new(SentGrants::Lifecycle)
+                .string_len(32)
+                .not_null()
+                .default("Created")
+                .to_owned(),
+            ColumnDef::new(SentGrants::TokenExpiresAt)
+                .timestamp_with_time_zone()
+                .to_owned(),
+            ColumnDef::new(SentGrants::ManageUri).string().to_owned(),
+            ColumnDef::new(SentGrants::ManageToken).string().to_owned(),
+            ColumnDef::new(SentGrants::LabeledTokens)
+                .json_binary()
+                .to_owned(),
+            ColumnDef::new(SentGrants::SubIds).json_binary().to_owned(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SentGrants::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            SentGrants::Lifecycle,
+            SentGrants::TokenExpiresAt,
+            SentGrants::ManageUri,
+            SentGrants::ManageToken,
+            SentGrants::LabeledTokens,
+            SentGrants::SubIds,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SentGrants::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum SentGrants {
+    #[iden = "sent_grants"]
+    Table,
+    Lifecycle,
+    TokenExpiresAt,
+    ManageUri,
+    ManageToken,
+    LabeledTokens,
+    SubIds,
+}
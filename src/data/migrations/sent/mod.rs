@@ -15,11 +15,14 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use super::MigrationSource;
 use sea_orm_migration::MigrationTrait;
 
 pub mod m20260622_120020_grant;
 pub mod m20260622_120021_interaction;
 pub mod m20260622_120022_verification;
+pub mod m20261016_120024_grant_extensions;
+pub mod m20261016_120030_grant_columns;
 
 /// All sent-side migrations, executed together.
 pub fn get_sent_migrations() -> Vec<Box<dyn MigrationTrait>> {
@@ -27,5 +30,33 @@ pub fn get_sent_migrations() -> Vec<Box<dyn MigrationTrait>> {
         Box::new(m20260622_120020_grant::Migration),
         Box::new(m20260622_120021_interaction::Migration),
         Box::new(m20260622_120022_verification::Migration),
+        Box::new(m20261016_120024_grant_extensions::Migration),
+        Box::new(m20261016_120030_grant_columns::Migration),
+    ]
+}
+
+/// Sources of the sent-side migrations, checksummed to detect drift.
+pub fn get_sent_sources() -> Vec<MigrationSource> {
+    vec![
+        MigrationSource::new(
+            "m20260622_120020_grant",
+            include_str!("m20260622_120020_grant.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120021_interaction",
+            include_str!("m20260622_120021_interaction.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120022_verification",
+            include_str!("m20260622_120022_verification.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120024_grant_extensions",
+            include_str!("m20261016_120024_grant_extensions.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120030_grant_columns",
+            include_str!("m20261016_120030_grant_columns.rs"),
+        ),
     ]
 }
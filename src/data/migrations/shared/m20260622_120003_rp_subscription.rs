@@ -0,0 +1,93 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DbBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RpSubscriptions::Table)
+                    .col(
+                        ColumnDef::new(RpSubscriptions::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(RpSubscriptions::CallbackUrl)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RpSubscriptions::ParticipantId).string())
+                    .col(ColumnDef::new(RpSubscriptions::VcType).json_binary())
+                    .col(ColumnDef::new(RpSubscriptions::Purpose).string())
+                    .col(string_list(manager, RpSubscriptions::Claims).not_null())
+                    .col(
+                        ColumnDef::new(RpSubscriptions::Active)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(RpSubscriptions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RpSubscriptions::Table).to_owned())
+            .await
+    }
+}
+
+/// Native string array on Postgres, converted to JSON by `m20261016_120019_string_list_json`.
+///
+/// SQLite has no array type, so there the list is JSON from the start.
+fn string_list(manager: &SchemaManager, column: RpSubscriptions) -> ColumnDef {
+    let mut def = ColumnDef::new(column);
+    match manager.get_database_backend() {
+        DbBackend::Postgres => def.array(ColumnType::String(StringLen::None)),
+        _ => def.json_binary(),
+    };
+    def
+}
+
+#[derive(Iden)]
+pub enum RpSubscriptions {
+    #[iden = "rp_subscriptions"]
+    Table,
+    Id,
+    CallbackUrl,
+    ParticipantId,
+    VcType,
+    Purpose,
+    Claims,
+    Active,
+    CreatedAt,
+}
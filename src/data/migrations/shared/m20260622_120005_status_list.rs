@@ -0,0 +1,147 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StatusLists::Table)
+                    .col(
+                        ColumnDef::new(StatusLists::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(StatusLists::Purpose)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StatusLists::Size).integer().not_null())
+                    .col(
+                        ColumnDef::new(StatusLists::NextIndex)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(StatusLists::Bits).binary().not_null())
+                    .col(
+                        ColumnDef::new(StatusLists::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StatusLists::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(StatusEntries::Table)
+                    .col(
+                        ColumnDef::new(StatusEntries::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(StatusEntries::CredentialId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StatusEntries::ListId).string().not_null())
+                    .col(ColumnDef::new(StatusEntries::Index).integer().not_null())
+                    .col(
+                        ColumnDef::new(StatusEntries::Purpose)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StatusEntries::Flagged)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(StatusEntries::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StatusEntries::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(StatusEntries::ListId)
+                            .col(StatusEntries::Index),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StatusEntries::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(StatusLists::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum StatusLists {
+    #[iden = "status_lists"]
+    Table,
+    Id,
+    Purpose,
+    Size,
+    NextIndex,
+    Bits,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+pub enum StatusEntries {
+    #[iden = "status_entries"]
+    Table,
+    Id,
+    CredentialId,
+    ListId,
+    Index,
+    Purpose,
+    Flagged,
+    CreatedAt,
+    UpdatedAt,
+}
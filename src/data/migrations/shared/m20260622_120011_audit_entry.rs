@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditEntries::Table)
+                    .col(
+                        ColumnDef::new(AuditEntries::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AuditEntries::Actor).string().null())
+                    .col(ColumnDef::new(AuditEntries::Entity).string().not_null())
+                    .col(ColumnDef::new(AuditEntries::EntityId).string().not_null())
+                    .col(
+                        ColumnDef::new(AuditEntries::Operation)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuditEntries::Before).json_binary().null())
+                    .col(ColumnDef::new(AuditEntries::After).json_binary().null())
+                    .col(ColumnDef::new(AuditEntries::Diff).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(AuditEntries::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_entries_entity")
+                    .table(AuditEntries::Table)
+                    .col(AuditEntries::Entity)
+                    .col(AuditEntries::EntityId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditEntries::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum AuditEntries {
+    #[iden = "audit_entries"]
+    Table,
+    Id,
+    Actor,
+    Entity,
+    EntityId,
+    Operation,
+    Before,
+    After,
+    Diff,
+    CreatedAt,
+}
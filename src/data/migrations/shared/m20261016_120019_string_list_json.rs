@@ -0,0 +1,74 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DbBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// `(table, column, nullable)` of every string list that Postgres stored as `TEXT[]`.
+const COLUMNS: [(&str, &str, bool); 4] = [
+    ("resources_reqs", "locations", true),
+    ("resources_reqs", "datatypes", true),
+    ("resources_reqs", "privileges", true),
+    ("rp_subscriptions", "claims", false),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Only Postgres ever had `TEXT[]` columns; SQLite created them as JSON already.
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+        for (table, column, _) in COLUMNS {
+            db.execute_unprepared(&format!(
+                r#"ALTER TABLE "{table}" ALTER COLUMN "{column}" TYPE jsonb USING to_jsonb("{column}")"#
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        // A `USING` clause cannot hold the subquery that unpacks the JSON array, so each
+        // column is rebuilt instead.
+        let db = manager.get_connection();
+        for (table, column, nullable) in COLUMNS {
+            db.execute_unprepared(&format!(
+                r#"ALTER TABLE "{table}" ADD COLUMN "{column}_array" TEXT[];
+                UPDATE "{table}" SET "{column}_array" = ARRAY(SELECT jsonb_array_elements_text("{column}"))
+                    WHERE "{column}" IS NOT NULL;
+                ALTER TABLE "{table}" DROP COLUMN "{column}";
+                ALTER TABLE "{table}" RENAME COLUMN "{column}_array" TO "{column}""#
+            ))
+            .await?;
+            if !nullable {
+                db.execute_unprepared(&format!(
+                    r#"ALTER TABLE "{table}" ALTER COLUMN "{column}" SET NOT NULL"#
+                ))
+                .await?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const INSTANCE_ID_INDEX: &str = "idx_participants_instance_id";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite takes a single column per `ALTER TABLE`.
+        for column in [
+            ColumnDef::new(Participants::TokenExpiresAt)
+                .timestamp_with_time_zone()
+                .to_owned(),
+            ColumnDef::new(Participants::TokenKey)
+                .json_binary()
+                .to_owned(),
+            ColumnDef::new(Participants::InstanceId).string().to_owned(),
+            ColumnDef::new(Participants::ClientKey)
+                .json_binary()
+                .to_owned(),
+            ColumnDef::new(Participants::Status)
+                .string_len(16)
+                .not_null()
+                .default("Active")
+                .to_owned(),
+            ColumnDef::new(Participants::ExpiresAt)
+                .timestamp_with_time_zone()
+                .to_owned(),
+            ColumnDef::new(Participants::LegalName).string().to_owned(),
+            ColumnDef::new(Participants::CountryCode)
+                .string_len(8)
+                .to_owned(),
+            ColumnDef::new(Participants::RegistrationNumber)
+                .string()
+                .to_owned(),
+            ColumnDef::new(Participants::RegistrationNumberType)
+                .string_len(64)
+                .to_owned(),
+            ColumnDef::new(Participants::Extensions)
+                .json_binary()
+                .not_null()
+                .default("{}")
+                .to_owned(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Participants::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // SQLite cannot add a UNIQUE column in place, so the constraint is an index.
+        manager
+            .create_index(
+                Index::create()
+                    .name(INSTANCE_ID_INDEX)
+                    .table(Participants::Table)
+                    .col(Participants::InstanceId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(INSTANCE_ID_INDEX)
+                    .table(Participants::Table)
+                    .to_owned(),
+            )
+            .await?;
+        for column in [
+            Participants::TokenExpiresAt,
+            Participants::TokenKey,
+            Participants::InstanceId,
+            Participants::ClientKey,
+            Participants::Status,
+            Participants::ExpiresAt,
+            Participants::LegalName,
+            Participants::CountryCode,
+            Participants::RegistrationNumber,
+            Participants::RegistrationNumberType,
+            Participants::Extensions,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Participants::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Participants {
+    #[iden = "participants"]
+    Table,
+    TokenExpiresAt,
+    TokenKey,
+    InstanceId,
+    ClientKey,
+    Status,
+    ExpiresAt,
+    LegalName,
+    CountryCode,
+    RegistrationNumber,
+    RegistrationNumberType,
+    Extensions,
+}
@@ -0,0 +1,115 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Default of the new NOT NULL timestamp: sessions opened before the column existed get a nonce
+/// that is already expired. SQLite only accepts a constant default when adding a column.
+const EPOCH: &str = "1970-01-01 00:00:00+00:00";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite takes a single column per `ALTER TABLE`.
+        for column in [
+            ColumnDef::new(Issuance::NonceExpiresAt)
+                .timestamp_with_time_zone()
+                .not_null()
+                .default(EPOCH)
+                .to_owned(),
+            ColumnDef::new(Issuance::NonceConsumed)
+                .boolean()
+                .not_null()
+                .default(false)
+                .to_owned(),
+            ColumnDef::new(Issuance::NotificationId)
+                .string()
+                .not_null()
+                .default("")
+                .to_owned(),
+            ColumnDef::new(Issuance::NotificationEvent)
+                .string_len(32)
+                .to_owned(),
+            ColumnDef::new(Issuance::NotificationDescription)
+                .string()
+                .to_owned(),
+            ColumnDef::new(Issuance::NotifiedAt)
+                .timestamp_with_time_zone()
+                .to_owned(),
+            ColumnDef::new(Issuance::Extensions)
+                .json_binary()
+                .not_null()
+                .default("{}")
+                .to_owned(),
+            ColumnDef::new(Issuance::Version)
+                .integer()
+                .not_null()
+                .default(0)
+                .to_owned(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Issuance::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            Issuance::NonceExpiresAt,
+            Issuance::NonceConsumed,
+            Issuance::NotificationId,
+            Issuance::NotificationEvent,
+            Issuance::NotificationDescription,
+            Issuance::NotifiedAt,
+            Issuance::Extensions,
+            Issuance::Version,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Issuance::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Issuance {
+    #[iden = "issuance"]
+    Table,
+    NonceExpiresAt,
+    NonceConsumed,
+    NotificationId,
+    NotificationEvent,
+    NotificationDescription,
+    NotifiedAt,
+    Extensions,
+    Version,
+}
@@ -18,8 +18,106 @@
 pub mod m20260622_120000_participant;
 pub mod m20260622_120001_resource_req;
 pub mod m20260622_120002_issuance;
+pub mod m20260622_120003_rp_subscription;
+pub mod m20260622_120004_cred_template;
+pub mod m20260622_120005_status_list;
+pub mod m20260622_120006_trusted_issuer;
+pub mod m20260622_120007_quota_counter;
+pub mod m20260622_120008_replay_entry;
+pub mod m20260622_120009_revocation_event;
+pub mod m20260622_120010_shared_state;
+pub mod m20260622_120011_audit_entry;
+pub mod m20261016_120017_status_list_allocation;
+pub mod m20261016_120019_string_list_json;
+pub mod m20261016_120026_participant_columns;
+pub mod m20261016_120027_issuance_columns;
+
+use super::MigrationSource;
 
 // Short aliases — consumers pick the ones they need.
 pub use m20260622_120000_participant as participant;
 pub use m20260622_120001_resource_req as resource_req;
 pub use m20260622_120002_issuance as issuance;
+pub use m20260622_120003_rp_subscription as rp_subscription;
+pub use m20260622_120004_cred_template as cred_template;
+pub use m20260622_120005_status_list as status_list;
+pub use m20260622_120006_trusted_issuer as trusted_issuer;
+pub use m20260622_120007_quota_counter as quota_counter;
+pub use m20260622_120008_replay_entry as replay_entry;
+pub use m20260622_120009_revocation_event as revocation_event;
+pub use m20260622_120010_shared_state as shared_state;
+pub use m20260622_120011_audit_entry as audit_entry;
+pub use m20261016_120017_status_list_allocation as status_list_allocation;
+pub use m20261016_120019_string_list_json as string_list_json;
+pub use m20261016_120026_participant_columns as participant_columns;
+pub use m20261016_120027_issuance_columns as issuance_columns;
+
+/// Sources of the shared migrations, checksummed to detect drift.
+pub fn get_shared_sources() -> Vec<MigrationSource> {
+    vec![
+        MigrationSource::new(
+            "m20260622_120000_participant",
+            include_str!("m20260622_120000_participant.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120001_resource_req",
+            include_str!("m20260622_120001_resource_req.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120002_issuance",
+            include_str!("m20260622_120002_issuance.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120003_rp_subscription",
+            include_str!("m20260622_120003_rp_subscription.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120004_cred_template",
+            include_str!("m20260622_120004_cred_template.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120005_status_list",
+            include_str!("m20260622_120005_status_list.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120006_trusted_issuer",
+            include_str!("m20260622_120006_trusted_issuer.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120007_quota_counter",
+            include_str!("m20260622_120007_quota_counter.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120008_replay_entry",
+            include_str!("m20260622_120008_replay_entry.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120009_revocation_event",
+            include_str!("m20260622_120009_revocation_event.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120010_shared_state",
+            include_str!("m20260622_120010_shared_state.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120011_audit_entry",
+            include_str!("m20260622_120011_audit_entry.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120017_status_list_allocation",
+            include_str!("m20261016_120017_status_list_allocation.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120019_string_list_json",
+            include_str!("m20261016_120019_string_list_json.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120026_participant_columns",
+            include_str!("m20261016_120026_participant_columns.rs"),
+        ),
+        MigrationSource::new(
+            "m20261016_120027_issuance_columns",
+            include_str!("m20261016_120027_issuance_columns.rs"),
+        ),
+    ]
+}
@@ -0,0 +1,85 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PendingPresentations::Table)
+                    .col(
+                        ColumnDef::new(PendingPresentations::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PendingPresentations::RequestUri)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PendingPresentations::Verifier).string())
+                    .col(
+                        ColumnDef::new(PendingPresentations::Candidates)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PendingPresentations::Status)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PendingPresentations::Selected).json_binary())
+                    .col(ColumnDef::new(PendingPresentations::Error).text())
+                    .col(
+                        ColumnDef::new(PendingPresentations::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PendingPresentations::DecidedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PendingPresentations::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum PendingPresentations {
+    #[iden = "pending_presentations"]
+    Table,
+    Id,
+    RequestUri,
+    Verifier,
+    Candidates,
+    Status,
+    Selected,
+    Error,
+    CreatedAt,
+    DecidedAt,
+}
@@ -15,11 +15,13 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use super::MigrationSource;
 use sea_orm_migration::MigrationTrait;
 
 pub mod m20260622_120030_did;
 pub mod m20260622_120031_key;
 pub mod m20260622_120032_vc;
+pub mod m20260622_120033_pending_presentation;
 
 /// All wallet migrations, executed together.
 pub fn get_wallet_migrations() -> Vec<Box<dyn MigrationTrait>> {
@@ -27,5 +29,28 @@ pub fn get_wallet_migrations() -> Vec<Box<dyn MigrationTrait>> {
         Box::new(m20260622_120030_did::Migration),
         Box::new(m20260622_120031_key::Migration),
         Box::new(m20260622_120032_vc::Migration),
+        Box::new(m20260622_120033_pending_presentation::Migration),
+    ]
+}
+
+/// Sources of the wallet migrations, checksummed to detect drift.
+pub fn get_wallet_sources() -> Vec<MigrationSource> {
+    vec![
+        MigrationSource::new(
+            "m20260622_120030_did",
+            include_str!("m20260622_120030_did.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120031_key",
+            include_str!("m20260622_120031_key.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120032_vc",
+            include_str!("m20260622_120032_vc.rs"),
+        ),
+        MigrationSource::new(
+            "m20260622_120033_pending_presentation",
+            include_str!("m20260622_120033_pending_presentation.rs"),
+        ),
     ]
 }
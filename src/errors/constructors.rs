@@ -36,6 +36,8 @@ impl Errors {
             PetitionFailure::BodyRead => (StatusCode::BAD_GATEWAY, 1600),
             PetitionFailure::Serialization => (StatusCode::INTERNAL_SERVER_ERROR, 1400),
             PetitionFailure::Concurrency => (StatusCode::SERVICE_UNAVAILABLE, 1500),
+            PetitionFailure::Timeout => (StatusCode::GATEWAY_TIMEOUT, 1700),
+            PetitionFailure::CircuitOpen => (StatusCode::SERVICE_UNAVAILABLE, 1800),
         };
 
         Errors::PetitionError {
@@ -185,6 +187,7 @@ impl Errors {
             MissingAction::Key => 3130,
             MissingAction::Did => 3140,
             MissingAction::Onboarding => 3150,
+            MissingAction::TxCode => 3160,
             _ => 3100,
         };
         Errors::MissingActionError {
@@ -286,6 +289,37 @@ impl Errors {
         }
     }
 
+    /// Exhausted rate or quota allowance builder.
+    pub fn rate_limit(reason: impl Into<String>, source: Option<AnyError>) -> Self {
+        Errors::RateLimitError {
+            info: ErrorInfo {
+                message: "Rate Limit Error".to_string(),
+                error_code: 4500,
+                status_code: StatusCode::TOO_MANY_REQUESTS,
+                details: None,
+            },
+            retry_after: None,
+            reason: reason.into(),
+            source,
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Lost optimistic concurrency race builder.
+    pub fn conflict(reason: impl Into<String>, source: Option<AnyError>) -> Self {
+        Errors::ConflictError {
+            info: ErrorInfo {
+                message: "Conflict Error".to_string(),
+                error_code: 4600,
+                status_code: StatusCode::CONFLICT,
+                details: None,
+            },
+            reason: reason.into(),
+            source,
+            backtrace: Backtrace::capture(),
+        }
+    }
+
     /// Standard internal database mapping tracker.
     pub fn db(reason: impl Into<String>, source: Option<AnyError>) -> Self {
         Errors::DatabaseError {
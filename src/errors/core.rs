@@ -134,6 +134,22 @@ pub enum Errors {
         source: Option<AnyError>,
         backtrace: Backtrace,
     },
+    /// Client exhausted a configured rate or quota allowance.
+    RateLimitError {
+        info: ErrorInfo,
+        /// Seconds the client should wait before trying again, sent as `Retry-After`.
+        retry_after: Option<u64>,
+        reason: String,
+        source: Option<AnyError>,
+        backtrace: Backtrace,
+    },
+    /// Write lost a compare-and-swap race against a concurrent update of the same row.
+    ConflictError {
+        info: ErrorInfo,
+        reason: String,
+        source: Option<AnyError>,
+        backtrace: Backtrace,
+    },
     /// Internal engine database operational error originating from the Sea-ORM layer.
     DatabaseError {
         info: ErrorInfo,
@@ -199,6 +215,8 @@ impl Display for Errors {
             Errors::UnauthorizedError { info, .. } => write!(f, "{}\n", info.message),
             Errors::ForbiddenError { info, .. } => write!(f, "{}\n", info.message),
             Errors::SecurityError { info, .. } => write!(f, "{}\n", info.message),
+            Errors::RateLimitError { info, .. } => write!(f, "{}\n", info.message),
+            Errors::ConflictError { info, .. } => write!(f, "{}\n", info.message),
             Errors::DatabaseError { info, .. } => write!(f, "{}\n", info.message),
             Errors::FeatureNotImplError { info, .. } => write!(f, "{}\n", info.message),
             Errors::EnvVarError { info, .. } => write!(f, "{}\n", info.message),
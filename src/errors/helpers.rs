@@ -18,7 +18,7 @@
 use axum::http::StatusCode;
 use tracing::error;
 
-use super::{ErrorInfo, Errors, HttpContext};
+use super::{ErrorInfo, Errors, HttpContext, PetitionFailure};
 
 impl Errors {
     /// Factory builder initializing unified HTTP tracing context frames.
@@ -51,6 +51,8 @@ impl Errors {
             Errors::UnauthorizedError { info, .. } => info.details = details,
             Errors::ForbiddenError { info, .. } => info.details = details,
             Errors::SecurityError { info, .. } => info.details = details,
+            Errors::RateLimitError { info, .. } => info.details = details,
+            Errors::ConflictError { info, .. } => info.details = details,
             Errors::DatabaseError { info, .. } => info.details = details,
             Errors::FeatureNotImplError { info, .. } => info.details = details,
             Errors::EnvVarError { info, .. } => info.details = details,
@@ -62,6 +64,24 @@ impl Errors {
         self
     }
 
+    /// Tells a rate limited client how many seconds to wait before trying again.
+    ///
+    /// Only applies to [`Errors::RateLimitError`]; other errors are returned unchanged.
+    pub fn with_retry_after(mut self, secs: u64) -> Self {
+        if let Errors::RateLimitError { retry_after, .. } = &mut self {
+            *retry_after = Some(secs);
+        }
+        self
+    }
+
+    /// Returns the seconds a rate limited client should wait before trying again.
+    pub fn retry_after(&self) -> Option<u64> {
+        match self {
+            Errors::RateLimitError { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
     /// Reflective extraction yielding access to the shared core metadata [`ErrorInfo`].
     pub fn info(&self) -> &ErrorInfo {
         match self {
@@ -78,6 +98,8 @@ impl Errors {
             Errors::UnauthorizedError { info, .. } => info,
             Errors::ForbiddenError { info, .. } => info,
             Errors::SecurityError { info, .. } => info,
+            Errors::RateLimitError { info, .. } => info,
+            Errors::ConflictError { info, .. } => info,
             Errors::DatabaseError { info, .. } => info,
             Errors::FeatureNotImplError { info, .. } => info,
             Errors::EnvVarError { info, .. } => info,
@@ -161,6 +183,8 @@ impl Errors {
             | Errors::UnauthorizedError { reason, source, backtrace, .. }
             | Errors::ForbiddenError { reason, source, backtrace, .. }
             | Errors::SecurityError { reason, source, backtrace, .. }
+            | Errors::RateLimitError { reason, source, backtrace, .. }
+            | Errors::ConflictError { reason, source, backtrace, .. }
             | Errors::DatabaseError { reason, source, backtrace, .. }
             | Errors::FeatureNotImplError { reason, source, backtrace, .. }
             | Errors::EnvVarError { reason, source, backtrace, .. }
@@ -195,6 +219,8 @@ impl Errors {
             | Errors::UnauthorizedError { reason, .. }
             | Errors::ForbiddenError { reason, .. }
             | Errors::SecurityError { reason, .. }
+            | Errors::RateLimitError { reason, .. }
+            | Errors::ConflictError { reason, .. }
             | Errors::DatabaseError { reason, .. }
             | Errors::FeatureNotImplError { reason, .. }
             | Errors::EnvVarError { reason, .. }
@@ -205,6 +231,22 @@ impl Errors {
         }
     }
 
+    /// Whether the remote side may succeed if the call is sent again.
+    ///
+    /// Holds for remote server errors, dropped connections and calls past their deadline.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Errors::PetitionError {
+                failure: PetitionFailure::Network | PetitionFailure::Timeout,
+                ..
+            } => true,
+            Errors::PetitionError { ctx, .. } | Errors::WalletError { ctx, .. } => ctx
+                .http_code
+                .is_some_and(|code| code.is_server_error()),
+            _ => false,
+        }
+    }
+
     /// Emits a structured log dump matching standard tracking envelopes to the active system logger.
     pub fn log(&self) {
         error!(
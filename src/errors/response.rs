@@ -16,6 +16,7 @@
  */
 
 use axum::Json;
+use axum::http::header::RETRY_AFTER;
 use axum::response::{IntoResponse, Response};
 
 use super::Errors;
@@ -39,6 +40,9 @@ impl IntoResponse for Errors {
         let status = info.status_code;
 
         // Marshals response structures directly into standard Axum tuples.
-        (status, Json(info)).into_response()
+        match self.retry_after() {
+            Some(secs) => (status, [(RETRY_AFTER, secs.to_string())], Json(info)).into_response(),
+            None => (status, Json(info)).into_response(),
+        }
     }
 }
@@ -63,6 +63,10 @@ pub enum PetitionFailure {
     Serialization,
     /// Multi-threaded internal rate-limiter or synchronization backpressure semaphore blockades.
     Concurrency,
+    /// The operation did not complete within its configured deadline and was cancelled.
+    Timeout,
+    /// The circuit breaker of the destination host is open and the call was not sent.
+    CircuitOpen,
 }
 
 impl Display for PetitionFailure {
@@ -74,6 +78,8 @@ impl Display for PetitionFailure {
             PetitionFailure::BodyRead => write!(f, "Failed to read response body"),
             PetitionFailure::Serialization => write!(f, "Serialization failed"),
             PetitionFailure::Concurrency => write!(f, "Concurrency limit reached"),
+            PetitionFailure::Timeout => write!(f, "Operation timed out"),
+            PetitionFailure::CircuitOpen => write!(f, "Circuit breaker open"),
         }
     }
 }
@@ -93,6 +99,8 @@ pub enum MissingAction {
     Onboarding,
     /// Requested Verifiable Credentials properties absent from storage.
     Credentials,
+    /// Out-of-band Transaction Code demanded by a credential offer.
+    TxCode,
     /// Unclassified prerequisite structural element target mismatch.
     Unknown,
 }
@@ -106,6 +114,7 @@ impl Display for MissingAction {
             MissingAction::Did => "DID",
             MissingAction::Onboarding => "Onboarding",
             MissingAction::Credentials => "Credentials",
+            MissingAction::TxCode => "TxCode",
             _ => "Unknown",
         };
         write!(f, "{}", s)
@@ -0,0 +1,91 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use axum::routing::get;
+use axum::{Json, Router};
+
+use crate::data::entities::shared::audit_entry::Model;
+use crate::errors::AppResult;
+use crate::modules::AuditModuleTrait;
+use crate::types::audit::{AuditFilter, token_actor, with_audit_actor};
+use crate::types::pagination::{Page, PageRequest};
+use crate::utils::{extract_bearer_token, extract_gnap_token};
+
+/// HTTP API Gateway Router exposing the audit log of state-changing operations.
+///
+/// Snapshots are redacted but still describe participants and sessions, so the router is
+/// administrative only and must be mounted behind the consumer's own access control.
+pub struct AuditRouter {
+    module: Arc<dyn AuditModuleTrait>,
+}
+
+impl AuditRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the audit module.
+    pub fn new(module: Arc<dyn AuditModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET /audit`               - Lists a page of entries, filtered by `entity`,
+    ///   `entity_id`, `actor` or `operation` and windowed by `limit`, `offset`, `sort` and
+    ///   `order`. Newest first by default.
+    /// * `GET /audit/{entity}/{id}` - Lists every write recorded for one row, oldest first.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/audit", get(Self::list))
+            .route("/audit/{entity}/{id}", get(Self::history))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn list(
+        State(module): State<Arc<dyn AuditModuleTrait>>,
+        Query(filter): Query<AuditFilter>,
+        Query(page): Query<PageRequest>,
+    ) -> AppResult<Json<Page<Model>>> {
+        Ok(Json(module.list_audit_entries(&filter, &page).await?))
+    }
+
+    async fn history(
+        State(module): State<Arc<dyn AuditModuleTrait>>,
+        Path((entity, id)): Path<(String, String)>,
+    ) -> AppResult<Json<Vec<Model>>> {
+        Ok(Json(module.entity_history(&entity, &id).await?))
+    }
+}
+
+/// Middleware naming the caller as the actor of the audited writes its request makes.
+///
+/// The actor is a digest of the GNAP or Bearer token, never the token itself; requests
+/// without one are recorded without an actor. Mount it with [`axum::middleware::from_fn`].
+pub async fn audit_actor_middleware(request: Request, next: Next) -> Response {
+    let token = extract_gnap_token(request.headers())
+        .or_else(|_| extract_bearer_token(request.headers()))
+        .ok();
+    match token {
+        Some(token) => with_audit_actor(token_actor(&token), next.run(request)).await,
+        None => next.run(request).await,
+    }
+}
@@ -0,0 +1,56 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::routing::get;
+use axum::{Json, Router};
+
+use crate::services::client::CircuitBreaker;
+use crate::types::http::CircuitStatus;
+
+/// HTTP API Gateway Router exposing the outbound circuit breaker state for diagnostics.
+///
+/// Administrative only: it must be mounted behind the consumer's own access control.
+pub struct CircuitBreakerRouter {
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl CircuitBreakerRouter {
+    /// Instantiates a new HTTP network boundary instance over the client's circuit breaker.
+    pub fn new(breaker: Arc<CircuitBreaker>) -> Self {
+        Self { breaker }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET /diagnostics/circuits` - Lists the hosts with recent failures and the state of
+    ///   their circuit (`closed`, `open` or `half_open`).
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/diagnostics/circuits", get(Self::list))
+            .with_state(self.breaker.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn list(State(breaker): State<Arc<CircuitBreaker>>) -> Json<Vec<CircuitStatus>> {
+        Json(breaker.statuses())
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::routing::put;
+use axum::{Json, Router};
+
+use crate::data::entities::shared::participant;
+use crate::errors::AppResult;
+use crate::modules::ClientRegistryModuleTrait;
+use crate::types::gnap::grant_request::client::ClientKey;
+
+/// HTTP API Gateway Router managing the GNAP client instances pre-registered by mates.
+///
+/// Administrative only: it must be mounted behind the consumer's own access control.
+pub struct ClientRegistryRouter {
+    module: Arc<dyn ClientRegistryModuleTrait>,
+}
+
+impl ClientRegistryRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the client registry module.
+    pub fn new(module: Arc<dyn ClientRegistryModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `PUT /participants/{id}/client`    - Registers the mate as a client instance with a key.
+    /// * `DELETE /participants/{id}/client` - Drops the client instance registration.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route(
+                "/participants/{id}/client",
+                put(Self::register).delete(Self::unregister),
+            )
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn register(
+        State(module): State<Arc<dyn ClientRegistryModuleTrait>>,
+        Path(id): Path<String>,
+        Json(key): Json<ClientKey>,
+    ) -> AppResult<Json<participant::Model>> {
+        Ok(Json(module.register_client(&id, &key).await?))
+    }
+
+    async fn unregister(
+        State(module): State<Arc<dyn ClientRegistryModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<participant::Model>> {
+        Ok(Json(module.unregister_client(&id).await?))
+    }
+}
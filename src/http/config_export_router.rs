@@ -0,0 +1,58 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::routing::get;
+use axum::{Json, Router};
+
+use crate::errors::AppResult;
+use crate::modules::ConfigExportModuleTrait;
+use crate::types::config_snapshot::ConfigSnapshot;
+
+/// HTTP API Gateway Router exporting the effective configuration.
+///
+/// Must be mounted behind the consumer's own access control.
+pub struct ConfigExportRouter {
+    module: Arc<dyn ConfigExportModuleTrait>,
+}
+
+impl ConfigExportRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the config export module.
+    pub fn new(module: Arc<dyn ConfigExportModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET /config/export` - Returns the configuration snapshot with secrets as vault references.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/config/export", get(Self::export))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn export(
+        State(module): State<Arc<dyn ConfigExportModuleTrait>>,
+    ) -> AppResult<Json<ConfigSnapshot>> {
+        Ok(Json(module.export_config()?))
+    }
+}
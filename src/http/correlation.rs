@@ -0,0 +1,46 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use axum::extract::Request;
+use axum::http::HeaderValue;
+use axum::middleware::Next;
+use axum::response::Response;
+use tracing::{Instrument, info_span};
+
+use crate::types::http::{CORRELATION_HEADER, accept_correlation_id, with_correlation_id};
+
+/// Middleware carrying the correlation id of a multi-hop flow through this agent.
+///
+/// The `x-correlation-id` of the incoming request is kept (or a new one started), sent on
+/// every outbound call the handler makes, attached to its log lines and echoed on the
+/// response. Mount it with [`axum::middleware::from_fn`].
+pub async fn correlation_id_middleware(request: Request, next: Next) -> Response {
+    let received = request
+        .headers()
+        .get(CORRELATION_HEADER)
+        .and_then(|value| value.to_str().ok());
+    let id = accept_correlation_id(received);
+
+    let span = info_span!("request", correlation_id = %id);
+    let mut response = with_correlation_id(id.clone(), next.run(request))
+        .instrument(span)
+        .await;
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(CORRELATION_HEADER, value);
+    }
+    response
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::routing::post;
+use axum::{Json, Router};
+
+use crate::errors::AppResult;
+use crate::modules::CredentialIssuanceModuleTrait;
+use crate::types::issuance::{CredentialRequest, GiveVC};
+use crate::utils::extract_bearer_token;
+
+/// HTTP API Gateway Router exposing the OpenID4VCI Credential Endpoint.
+pub struct CredentialIssuanceRouter {
+    module: Arc<dyn CredentialIssuanceModuleTrait>,
+}
+
+impl CredentialIssuanceRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the credential issuance module.
+    pub fn new(module: Arc<dyn CredentialIssuanceModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the credential issuance routing tree.
+    ///
+    /// Mount it under the `{api_path}` advertised in the issuer metadata.
+    ///
+    /// # Exposed Map
+    /// * `POST /credential` - Issues the requested credential (Bearer access token).
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/credential", post(Self::issue))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn issue(
+        State(module): State<Arc<dyn CredentialIssuanceModuleTrait>>,
+        headers: HeaderMap,
+        Json(cred_req): Json<CredentialRequest>,
+    ) -> AppResult<Json<GiveVC>> {
+        let token = extract_bearer_token(&headers)?;
+        let response = module.issue(cred_req, &token).await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,89 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, State};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::data::entities::shared::participant;
+use crate::errors::AppResult;
+use crate::modules::DidEquivalenceModuleTrait;
+use crate::types::dids::DidEquivalenceProof;
+use crate::utils::extract_payload;
+
+/// HTTP API Gateway Router exposing the agent's DID equivalence statements.
+pub struct DidEquivalenceRouter {
+    module: Arc<dyn DidEquivalenceModuleTrait>,
+}
+
+impl DidEquivalenceRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the DID equivalence module.
+    pub fn new(module: Arc<dyn DidEquivalenceModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `POST /did-equivalence/verify`            - Checks a peer's proof and returns the aliases it binds.
+    /// * `GET  /did-equivalence/participants/{id}` - Retrieves a participant, the agent under any of its DIDs.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/did-equivalence/verify", post(Self::verify_equivalence))
+            .route(
+                "/did-equivalence/participants/{id}",
+                get(Self::get_participant),
+            )
+            .with_state(self.module.clone())
+    }
+
+    /// Mounts the public discovery route, next to `/.well-known/did.json`.
+    ///
+    /// # Exposed Map
+    /// * `GET /.well-known/did-equivalence` - Publishes the agent's DIDs and signed statements.
+    pub fn well_known(&self) -> Router {
+        Router::new()
+            .route("/.well-known/did-equivalence", get(Self::get_equivalence))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn get_equivalence(
+        State(module): State<Arc<dyn DidEquivalenceModuleTrait>>,
+    ) -> AppResult<Json<DidEquivalenceProof>> {
+        Ok(Json(module.get_equivalence().await?))
+    }
+
+    async fn verify_equivalence(
+        State(module): State<Arc<dyn DidEquivalenceModuleTrait>>,
+        payload: Result<Json<DidEquivalenceProof>, JsonRejection>,
+    ) -> AppResult<Json<Vec<String>>> {
+        let proof = extract_payload(payload)?;
+        Ok(Json(module.verify_equivalence(&proof).await?))
+    }
+
+    async fn get_participant(
+        State(module): State<Arc<dyn DidEquivalenceModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<participant::Model>> {
+        Ok(Json(module.get_participant(&id).await?))
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::data::entities::received::evidence_bundle;
+use crate::errors::AppResult;
+use crate::modules::EvidenceModuleTrait;
+use crate::types::verification::EvidenceBundle;
+
+/// HTTP API Gateway Router exposing sealed verification evidence.
+pub struct EvidenceRouter {
+    module: Arc<dyn EvidenceModuleTrait>,
+}
+
+impl EvidenceRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the evidence module.
+    pub fn new(module: Arc<dyn EvidenceModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the evidence routing tree.
+    ///
+    /// Mount it under `{api_path}/verifier`, behind admin authentication: bundles carry the
+    /// raw audit of the exchange.
+    ///
+    /// # Exposed Map
+    /// * `POST /verifications/{id}/evidence` - Seals (or returns) the evidence of a verification.
+    /// * `GET  /verifications/{id}/evidence` - Retrieves previously sealed evidence.
+    /// * `POST /evidence/verify`             - Validates a bundle JWT sent as the raw body.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route(
+                "/verifications/{id}/evidence",
+                get(Self::get_evidence).post(Self::package_evidence),
+            )
+            .route("/evidence/verify", post(Self::verify_evidence))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn package_evidence(
+        State(module): State<Arc<dyn EvidenceModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<evidence_bundle::Model>> {
+        Ok(Json(module.package_evidence(&id).await?))
+    }
+
+    async fn get_evidence(
+        State(module): State<Arc<dyn EvidenceModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<evidence_bundle::Model>> {
+        Ok(Json(module.get_evidence(&id).await?))
+    }
+
+    async fn verify_evidence(
+        State(module): State<Arc<dyn EvidenceModuleTrait>>,
+        body: String,
+    ) -> AppResult<Json<EvidenceBundle>> {
+        Ok(Json(module.verify_evidence(body.trim()).await?))
+    }
+}
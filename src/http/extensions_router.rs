@@ -0,0 +1,313 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{DefaultBodyLimit, Path, State};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::Value;
+
+use crate::errors::AppResult;
+use crate::modules::ExtensionsModuleTrait;
+use crate::types::extensions::{ExtensionRecord, ExtensionTarget, MAX_EXTENSIONS_BYTES};
+use crate::utils::extract_payload;
+
+/// HTTP API Gateway Router administering integrator-defined `extensions` data.
+///
+/// Must be mounted behind the consumer's own access control.
+pub struct ExtensionsRouter {
+    module: Arc<dyn ExtensionsModuleTrait>,
+}
+
+impl ExtensionsRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the extensions module.
+    pub fn new(module: Arc<dyn ExtensionsModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// Bodies larger than [`MAX_EXTENSIONS_BYTES`] are rejected before being parsed.
+    ///
+    /// # Exposed Map
+    /// * `GET   /participants/{id}/extensions`      - Retrieves the extensions of a participant.
+    /// * `PUT   /participants/{id}/extensions`      - Replaces them.
+    /// * `PATCH /participants/{id}/extensions`      - Applies a JSON merge patch to them.
+    /// * `POST  /participants/extensions/search`    - Lists ids and extensions of matching participants.
+    /// * `GET   /issuances/{id}/extensions`         - Retrieves the extensions of an issuance session.
+    /// * `PUT   /issuances/{id}/extensions`         - Replaces them.
+    /// * `PATCH /issuances/{id}/extensions`         - Applies a JSON merge patch to them.
+    /// * `POST  /issuances/extensions/search`       - Lists ids and extensions of matching sessions.
+    /// * `GET   /grants/sent/{id}/extensions`       - Retrieves the extensions of a sent grant.
+    /// * `PUT   /grants/sent/{id}/extensions`       - Replaces them.
+    /// * `PATCH /grants/sent/{id}/extensions`       - Applies a JSON merge patch to them.
+    /// * `POST  /grants/sent/extensions/search`     - Lists ids and extensions of matching sent grants.
+    /// * `GET   /grants/received/{id}/extensions`   - Retrieves the extensions of a received grant.
+    /// * `PUT   /grants/received/{id}/extensions`   - Replaces them.
+    /// * `PATCH /grants/received/{id}/extensions`   - Applies a JSON merge patch to them.
+    /// * `POST  /grants/received/extensions/search` - Lists ids and extensions of matching received grants.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route(
+                "/participants/{id}/extensions",
+                get(Self::get_participant)
+                    .put(Self::set_participant)
+                    .patch(Self::patch_participant),
+            )
+            .route(
+                "/participants/extensions/search",
+                post(Self::search_participants),
+            )
+            .route(
+                "/issuances/{id}/extensions",
+                get(Self::get_issuance)
+                    .put(Self::set_issuance)
+                    .patch(Self::patch_issuance),
+            )
+            .route("/issuances/extensions/search", post(Self::search_issuances))
+            .route(
+                "/grants/sent/{id}/extensions",
+                get(Self::get_sent_grant)
+                    .put(Self::set_sent_grant)
+                    .patch(Self::patch_sent_grant),
+            )
+            .route(
+                "/grants/sent/extensions/search",
+                post(Self::search_sent_grants),
+            )
+            .route(
+                "/grants/received/{id}/extensions",
+                get(Self::get_received_grant)
+                    .put(Self::set_received_grant)
+                    .patch(Self::patch_received_grant),
+            )
+            .route(
+                "/grants/received/extensions/search",
+                post(Self::search_received_grants),
+            )
+            .layer(DefaultBodyLimit::max(MAX_EXTENSIONS_BYTES))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn get_participant(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<Value>> {
+        let extensions = module
+            .get_extensions(ExtensionTarget::Participant, &id)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn set_participant(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Value>> {
+        let extensions = extract_payload(payload)?;
+        let extensions = module
+            .set_extensions(ExtensionTarget::Participant, &id, extensions)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn patch_participant(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Value>> {
+        let patch = extract_payload(payload)?;
+        let extensions = module
+            .patch_extensions(ExtensionTarget::Participant, &id, patch)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn search_participants(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Vec<ExtensionRecord>>> {
+        let filter = extract_payload(payload)?;
+        let records = module
+            .find_participants_by_extensions(filter)
+            .await?
+            .into_iter()
+            .map(|mate| ExtensionRecord {
+                id: mate.participant_id,
+                extensions: mate.extensions,
+            })
+            .collect();
+        Ok(Json(records))
+    }
+
+    async fn get_issuance(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<Value>> {
+        let extensions = module
+            .get_extensions(ExtensionTarget::Issuance, &id)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn set_issuance(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Value>> {
+        let extensions = extract_payload(payload)?;
+        let extensions = module
+            .set_extensions(ExtensionTarget::Issuance, &id, extensions)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn patch_issuance(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Value>> {
+        let patch = extract_payload(payload)?;
+        let extensions = module
+            .patch_extensions(ExtensionTarget::Issuance, &id, patch)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn search_issuances(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Vec<ExtensionRecord>>> {
+        let filter = extract_payload(payload)?;
+        let records = module
+            .find_issuances_by_extensions(filter)
+            .await?
+            .into_iter()
+            .map(|issuance| ExtensionRecord {
+                id: issuance.id,
+                extensions: issuance.extensions,
+            })
+            .collect();
+        Ok(Json(records))
+    }
+
+    async fn get_sent_grant(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<Value>> {
+        let extensions = module
+            .get_extensions(ExtensionTarget::SentGrant, &id)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn set_sent_grant(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Value>> {
+        let extensions = extract_payload(payload)?;
+        let extensions = module
+            .set_extensions(ExtensionTarget::SentGrant, &id, extensions)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn patch_sent_grant(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Value>> {
+        let patch = extract_payload(payload)?;
+        let extensions = module
+            .patch_extensions(ExtensionTarget::SentGrant, &id, patch)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn search_sent_grants(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Vec<ExtensionRecord>>> {
+        let filter = extract_payload(payload)?;
+        let records = module
+            .find_sent_grants_by_extensions(filter)
+            .await?
+            .into_iter()
+            .map(|grant| ExtensionRecord {
+                id: grant.id,
+                extensions: grant.extensions,
+            })
+            .collect();
+        Ok(Json(records))
+    }
+
+    async fn get_received_grant(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<Value>> {
+        let extensions = module
+            .get_extensions(ExtensionTarget::ReceivedGrant, &id)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn set_received_grant(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Value>> {
+        let extensions = extract_payload(payload)?;
+        let extensions = module
+            .set_extensions(ExtensionTarget::ReceivedGrant, &id, extensions)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn patch_received_grant(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Value>> {
+        let patch = extract_payload(payload)?;
+        let extensions = module
+            .patch_extensions(ExtensionTarget::ReceivedGrant, &id, patch)
+            .await?;
+        Ok(Json(extensions))
+    }
+
+    async fn search_received_grants(
+        State(module): State<Arc<dyn ExtensionsModuleTrait>>,
+        payload: Result<Json<Value>, JsonRejection>,
+    ) -> AppResult<Json<Vec<ExtensionRecord>>> {
+        let filter = extract_payload(payload)?;
+        let records = module
+            .find_received_grants_by_extensions(filter)
+            .await?
+            .into_iter()
+            .map(|grant| ExtensionRecord {
+                id: grant.id,
+                extensions: grant.extensions,
+            })
+            .collect();
+        Ok(Json(records))
+    }
+}
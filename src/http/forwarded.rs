@@ -0,0 +1,147 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::extract::{ConnectInfo, Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::config::traits::ConnectionConfigTrait;
+use crate::config::types::ConnectionConfig;
+use crate::types::http::with_forwarded_base;
+
+/// Middleware letting a trusted reverse proxy decide the public base URL of generated URIs.
+///
+/// The `Forwarded`/`X-Forwarded-*` headers are only read when `trust_forwarded_headers` is
+/// set and the peer is one of the `trusted_proxies` (any peer when the list is empty); the
+/// peer address comes from `into_make_service_with_connect_info`. Mount it with
+/// [`axum::middleware::from_fn_with_state`].
+pub async fn forwarded_base_middleware(
+    State(connection): State<Arc<ConnectionConfig>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip());
+    match connection.forwarded_base_from(request.headers(), peer) {
+        Some(base) => with_forwarded_base(base, next.run(request)).await,
+        None => next.run(request).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use axum::http::{HeaderMap, HeaderValue};
+
+    use crate::config::traits::{ConnectionConfigTrait, HostsConfigTrait};
+    use crate::config::types::{CommonHostsConfig, ConnectionConfig, HostConfig, HostType};
+    use crate::types::http::with_forwarded_base;
+
+    fn connection(trust: bool, proxies: &[&str]) -> ConnectionConfig {
+        ConnectionConfig {
+            is_prod: true,
+            is_vault_real: true,
+            has_tls_proxy: true,
+            trust_forwarded_headers: trust,
+            trusted_proxies: proxies.iter().map(|p| p.parse().unwrap()).collect(),
+            simulated_clock_skew: None,
+        }
+    }
+
+    fn forwarded_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-proto", HeaderValue::from_static("https"));
+        headers.insert(
+            "x-forwarded-host",
+            HeaderValue::from_static("proxy.example"),
+        );
+        headers.insert("x-forwarded-prefix", HeaderValue::from_static("/agent"));
+        headers
+    }
+
+    fn peer(ip: &str) -> Option<IpAddr> {
+        Some(ip.parse().unwrap())
+    }
+
+    #[test]
+    fn headers_from_untrusted_peers_are_ignored() {
+        let headers = forwarded_headers();
+
+        let disabled = connection(false, &[]);
+        assert_eq!(
+            disabled.forwarded_base_from(&headers, peer("10.0.0.1")),
+            None
+        );
+
+        let pinned = connection(true, &["10.0.0.1"]);
+        assert_eq!(
+            pinned.forwarded_base_from(&headers, peer("203.0.113.9")),
+            None
+        );
+        assert_eq!(pinned.forwarded_base_from(&headers, None), None);
+        assert_eq!(
+            pinned
+                .forwarded_base_from(&headers, peer("10.0.0.1"))
+                .as_deref(),
+            Some("https://proxy.example/agent")
+        );
+    }
+
+    #[tokio::test]
+    async fn public_url_wins_over_the_forwarded_base() {
+        let mut http = HostConfig {
+            protocol: "http".to_string(),
+            url: "127.0.0.1".to_string(),
+            port: Some("1500".to_string()),
+            internal_port: None,
+            public_url: None,
+        };
+        let hosts = CommonHostsConfig {
+            http: http.clone(),
+            grpc: None,
+            graphql: None,
+        };
+        assert_eq!(
+            hosts.get_external_host(HostType::Http),
+            hosts.get_host(HostType::Http)
+        );
+
+        let forwarded = with_forwarded_base("https://proxy.example/agent", async {
+            hosts.get_external_host(HostType::Http)
+        })
+        .await;
+        assert_eq!(forwarded, "https://proxy.example/agent");
+
+        http.public_url = Some("https://public.example".to_string());
+        let hosts = CommonHostsConfig {
+            http,
+            grpc: None,
+            graphql: None,
+        };
+        let canonical = with_forwarded_base("https://proxy.example/agent", async {
+            hosts.get_external_host(HostType::Http)
+        })
+        .await;
+        assert_eq!(canonical, hosts.get_host(HostType::Http));
+    }
+}
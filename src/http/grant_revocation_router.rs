@@ -0,0 +1,114 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{delete, post};
+use axum::{Json, Router};
+
+use crate::data::entities::received::grant;
+use crate::errors::AppResult;
+use crate::modules::GrantRevocationModuleTrait;
+use crate::types::gnap::access_token::RotatedToken;
+use crate::utils::extract_gnap_token;
+
+/// HTTP API Gateway Router revoking received GNAP grants and managing the tokens they issued.
+///
+/// The public surface authenticates callers with the continue or access token being given up,
+/// signed with the client key unless it is a bearer token; the administrative one must be
+/// mounted behind the consumer's own access control.
+pub struct GrantRevocationRouter {
+    module: Arc<dyn GrantRevocationModuleTrait>,
+}
+
+impl GrantRevocationRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the grant revocation module.
+    pub fn new(module: Arc<dyn GrantRevocationModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the public routing tree.
+    ///
+    /// # Exposed Map
+    /// * `DELETE /continue/{cont_id}` - Revokes the in-progress grant owning the continue token.
+    /// * `POST /token`                - Rotates the access token sent as GNAP authorization.
+    /// * `DELETE /token`              - Revokes the access token sent as GNAP authorization.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/continue/{cont_id}", delete(Self::revoke_continuation))
+            .route(
+                "/token",
+                post(Self::rotate_token).delete(Self::revoke_token),
+            )
+            .with_state(self.module.clone())
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `DELETE /grants/{id}` - Revokes a received grant and the token it issued.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/grants/{id}", delete(Self::revoke_grant))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn revoke_continuation(
+        State(module): State<Arc<dyn GrantRevocationModuleTrait>>,
+        Path(cont_id): Path<String>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> AppResult<StatusCode> {
+        let token = extract_gnap_token(&headers)?;
+        module
+            .revoke_by_continuation(&cont_id, &token, &headers, &body)
+            .await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    async fn rotate_token(
+        State(module): State<Arc<dyn GrantRevocationModuleTrait>>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> AppResult<Json<RotatedToken>> {
+        let token = extract_gnap_token(&headers)?;
+        let access_token = module.rotate_access_token(&token, &headers, &body).await?;
+        Ok(Json(RotatedToken { access_token }))
+    }
+
+    async fn revoke_token(
+        State(module): State<Arc<dyn GrantRevocationModuleTrait>>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> AppResult<StatusCode> {
+        let token = extract_gnap_token(&headers)?;
+        module.revoke_access_token(&token, &headers, &body).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    async fn revoke_grant(
+        State(module): State<Arc<dyn GrantRevocationModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<grant::Model>> {
+        Ok(Json(module.revoke_grant(&id).await?))
+    }
+}
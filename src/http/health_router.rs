@@ -15,20 +15,35 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::sync::Arc;
+
 use axum::Router;
+use axum::extract::State;
+use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::get;
 
+use crate::services::vault::VaultService;
+
 /// HTTP API Gateway Router governing infrastructure diagnostic probes.
 ///
 /// Provisions standard stateless endpoints utilized by network proxies, load balancers,
 /// and container orchestrators (such as Kubernetes pods) to evaluate host operational availability.
-pub struct HealthRouter;
+pub struct HealthRouter {
+    vault: Option<Arc<VaultService>>,
+}
 
 impl HealthRouter {
     /// Instantiates a new stateless network health diagnostic boundary layer.
     pub fn new() -> Self {
-        Self {}
+        Self { vault: None }
+    }
+
+    /// Makes the readiness probe fail while `vault` cannot serve secrets, so traffic is held
+    /// back during an outage instead of failing request by request.
+    pub fn with_vault(mut self, vault: Arc<VaultService>) -> Self {
+        self.vault = Some(vault);
+        self
     }
 
     /// Composes and registers standard diagnostic routes into a unified sub-routing architecture branch.
@@ -43,11 +58,25 @@ impl HealthRouter {
             .route("/health", get(Self::get_ok))
             .route("/healthz", get(Self::get_ok))
             .route("/liveness", get(Self::get_ok))
-            .route("/readiness", get(Self::get_ok))
+            .route("/readiness", get(Self::get_ready))
+            .with_state(self.vault)
     }
 
     /// Stateless Axum endpoint handler returning an immutable string indicator to validate thread execution.
     async fn get_ok() -> impl IntoResponse {
         "OK".into_response()
     }
+
+    /// Readiness handler additionally probing the secrets backend, when one was given.
+    async fn get_ready(State(vault): State<Option<Arc<VaultService>>>) -> impl IntoResponse {
+        let Some(vault) = vault else {
+            return "OK".into_response();
+        };
+        let health = vault.health().await;
+        if health.is_ready() {
+            "OK".into_response()
+        } else {
+            (StatusCode::SERVICE_UNAVAILABLE, format!("Vault {health}")).into_response()
+        }
+    }
 }
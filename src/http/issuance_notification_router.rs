@@ -0,0 +1,64 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::{Json, Router};
+
+use crate::errors::AppResult;
+use crate::modules::IssuanceNotificationModuleTrait;
+use crate::types::issuance::NotificationRequest;
+use crate::utils::extract_bearer_token;
+
+/// HTTP API Gateway Router exposing the OpenID4VCI Notification Endpoint.
+pub struct IssuanceNotificationRouter {
+    module: Arc<dyn IssuanceNotificationModuleTrait>,
+}
+
+impl IssuanceNotificationRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the notification module.
+    pub fn new(module: Arc<dyn IssuanceNotificationModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the notification routing tree.
+    ///
+    /// Mount it under the `{api_path}` advertised in the issuer metadata.
+    ///
+    /// # Exposed Map
+    /// * `POST /notification` - Records a wallet-reported credential event (Bearer access token).
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/notification", post(Self::notify))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn notify(
+        State(module): State<Arc<dyn IssuanceNotificationModuleTrait>>,
+        headers: HeaderMap,
+        Json(notification): Json<NotificationRequest>,
+    ) -> AppResult<StatusCode> {
+        let token = extract_bearer_token(&headers)?;
+        module.notify(notification, &token).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+}
@@ -0,0 +1,66 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::errors::AppResult;
+use crate::modules::KeyIntegrityModuleTrait;
+use crate::types::wallet::KeyIntegrityReport;
+
+/// HTTP API Gateway Router exposing signing key drift checks.
+///
+/// Must be mounted behind the consumer's own access control.
+pub struct KeyIntegrityRouter {
+    module: Arc<dyn KeyIntegrityModuleTrait>,
+}
+
+impl KeyIntegrityRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the key integrity module.
+    pub fn new(module: Arc<dyn KeyIntegrityModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET  /keys/integrity` - Compares the Vault key with the wallet and published DID documents.
+    /// * `POST /keys/repair`    - Rebinds the Vault key as the default key of the active DID.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/keys/integrity", get(Self::check))
+            .route("/keys/repair", post(Self::repair))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn check(
+        State(module): State<Arc<dyn KeyIntegrityModuleTrait>>,
+    ) -> AppResult<Json<KeyIntegrityReport>> {
+        Ok(Json(module.check_key_integrity().await?))
+    }
+
+    async fn repair(
+        State(module): State<Arc<dyn KeyIntegrityModuleTrait>>,
+    ) -> AppResult<Json<KeyIntegrityReport>> {
+        Ok(Json(module.repair_key_binding().await?))
+    }
+}
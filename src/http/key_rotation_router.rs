@@ -0,0 +1,74 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::errors::AppResult;
+use crate::modules::KeyRotationModuleTrait;
+use crate::types::wallet::{KeyRotationReport, KeyRotationState};
+
+/// HTTP API Gateway Router exposing the signing key rotation.
+///
+/// Must be mounted behind the consumer's own access control.
+pub struct KeyRotationRouter {
+    module: Arc<dyn KeyRotationModuleTrait>,
+}
+
+impl KeyRotationRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the key rotation module.
+    pub fn new(module: Arc<dyn KeyRotationModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET  /keys/rotation` - Shows the active key, the keys being retired and past events.
+    /// * `POST /keys/rotate`   - Rolls the signing key of the active DID over to a new key.
+    /// * `POST /keys/retire`   - Unpublishes the previous keys whose grace period is over.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/keys/rotation", get(Self::state))
+            .route("/keys/rotate", post(Self::rotate))
+            .route("/keys/retire", post(Self::retire))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn state(
+        State(module): State<Arc<dyn KeyRotationModuleTrait>>,
+    ) -> AppResult<Json<KeyRotationState>> {
+        Ok(Json(module.rotation_state().await?))
+    }
+
+    async fn rotate(
+        State(module): State<Arc<dyn KeyRotationModuleTrait>>,
+    ) -> AppResult<Json<KeyRotationReport>> {
+        Ok(Json(module.rotate_signing_key().await?))
+    }
+
+    async fn retire(
+        State(module): State<Arc<dyn KeyRotationModuleTrait>>,
+    ) -> AppResult<Json<Vec<String>>> {
+        Ok(Json(module.retire_expired_keys().await?))
+    }
+}
@@ -0,0 +1,91 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::extract::rejection::JsonRejection;
+use axum::routing::get;
+use axum::{Json, Router};
+
+use crate::errors::AppResult;
+use crate::modules::LogFilterModuleTrait;
+use crate::types::logging::LogFilter;
+use crate::utils::extract_payload;
+
+/// HTTP API Gateway Router adjusting the tracing filter at runtime.
+///
+/// Must be mounted behind the consumer's own access control.
+pub struct LogFilterRouter {
+    module: Arc<dyn LogFilterModuleTrait>,
+}
+
+impl LogFilterRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the log filter module.
+    pub fn new(module: Arc<dyn LogFilterModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET    /logging/filter` - Returns the directives currently in effect.
+    /// * `PUT    /logging/filter` - Replaces the filter.
+    /// * `PATCH  /logging/filter` - Adds directives on top of the current filter.
+    /// * `DELETE /logging/filter` - Restores the startup filter.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route(
+                "/logging/filter",
+                get(Self::get_filter)
+                    .put(Self::set_filter)
+                    .patch(Self::extend_filter)
+                    .delete(Self::reset_filter),
+            )
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn get_filter(
+        State(module): State<Arc<dyn LogFilterModuleTrait>>,
+    ) -> AppResult<Json<LogFilter>> {
+        Ok(Json(module.get_log_filter()?))
+    }
+
+    async fn set_filter(
+        State(module): State<Arc<dyn LogFilterModuleTrait>>,
+        payload: Result<Json<LogFilter>, JsonRejection>,
+    ) -> AppResult<Json<LogFilter>> {
+        let filter = extract_payload(payload)?;
+        Ok(Json(module.set_log_filter(filter)?))
+    }
+
+    async fn extend_filter(
+        State(module): State<Arc<dyn LogFilterModuleTrait>>,
+        payload: Result<Json<LogFilter>, JsonRejection>,
+    ) -> AppResult<Json<LogFilter>> {
+        let filter = extract_payload(payload)?;
+        Ok(Json(module.extend_log_filter(filter)?))
+    }
+
+    async fn reset_filter(
+        State(module): State<Arc<dyn LogFilterModuleTrait>>,
+    ) -> AppResult<Json<LogFilter>> {
+        Ok(Json(module.reset_log_filter()?))
+    }
+}
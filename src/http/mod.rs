@@ -15,10 +15,70 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod audit_router;
+mod circuit_breaker_router;
+mod client_registry_router;
+mod config_export_router;
+mod correlation;
+mod credential_issuance_router;
+mod did_equivalence_router;
+mod evidence_router;
+mod extensions_router;
+mod forwarded;
+mod grant_revocation_router;
 mod health_router;
+mod issuance_notification_router;
+mod key_integrity_router;
+mod key_rotation_router;
+mod log_filter_router;
 mod openapi_router;
+mod participant_lifecycle_router;
+mod participant_search_router;
+mod participant_transfer_router;
+mod presentation_consent_router;
+mod receipt_router;
+mod requirements_router;
+mod revocation_feed_router;
+mod revocation_impact_router;
+mod standby_router;
+mod status_list_router;
+mod trust_anchor_router;
+mod trusted_issuer_router;
+mod verification_audit_router;
+mod vp_callback_router;
+mod vp_response_router;
 mod wallet_router;
 
+pub use audit_router::{AuditRouter, audit_actor_middleware};
+pub use circuit_breaker_router::CircuitBreakerRouter;
+pub use client_registry_router::ClientRegistryRouter;
+pub use config_export_router::ConfigExportRouter;
+pub use correlation::correlation_id_middleware;
+pub use credential_issuance_router::CredentialIssuanceRouter;
+pub use did_equivalence_router::DidEquivalenceRouter;
+pub use evidence_router::EvidenceRouter;
+pub use extensions_router::ExtensionsRouter;
+pub use forwarded::forwarded_base_middleware;
+pub use grant_revocation_router::GrantRevocationRouter;
 pub use health_router::HealthRouter;
+pub use issuance_notification_router::IssuanceNotificationRouter;
+pub use key_integrity_router::KeyIntegrityRouter;
+pub use key_rotation_router::KeyRotationRouter;
+pub use log_filter_router::LogFilterRouter;
 pub use openapi_router::OpenapiRouter;
+pub use participant_lifecycle_router::ParticipantLifecycleRouter;
+pub use participant_search_router::ParticipantSearchRouter;
+pub use participant_transfer_router::ParticipantTransferRouter;
+pub use presentation_consent_router::PresentationConsentRouter;
+pub use receipt_router::ReceiptRouter;
+pub use requirements_router::RequirementsRouter;
+pub use revocation_feed_router::RevocationFeedRouter;
+pub use revocation_impact_router::RevocationImpactRouter;
+pub use standby_router::{StandbyRouter, standby_write_guard};
+pub use status_list_router::StatusListRouter;
+pub use trust_anchor_router::TrustAnchorRouter;
+pub use trusted_issuer_router::TrustedIssuerRouter;
+pub use verification_audit_router::VerificationAuditRouter;
+pub use vp_callback_router::VpCallbackRouter;
+pub use vp_response_router::VpResponseRouter;
 pub use wallet_router::WalletRouter;
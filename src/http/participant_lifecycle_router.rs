@@ -0,0 +1,80 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::routing::{get, post, put};
+use axum::{Json, Router};
+
+use crate::data::entities::shared::participant;
+use crate::errors::AppResult;
+use crate::modules::ParticipantLifecycleModuleTrait;
+use crate::types::participants::{ParticipantStatus, StatusChange};
+
+/// HTTP API Gateway Router moving business mates through their lifecycle.
+///
+/// Administrative only: it must be mounted behind the consumer's own access control.
+pub struct ParticipantLifecycleRouter {
+    module: Arc<dyn ParticipantLifecycleModuleTrait>,
+}
+
+impl ParticipantLifecycleRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the lifecycle module.
+    pub fn new(module: Arc<dyn ParticipantLifecycleModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `PUT /participants/{id}/status`        - Moves the mate to another lifecycle state,
+    ///   optionally replacing its membership expiry.
+    /// * `GET /participants/status/{status}`    - Lists the mates stored in a lifecycle state.
+    /// * `POST /participants/status/expire`     - Expires every mate whose membership lapsed and
+    ///   answers how many were expired.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/participants/{id}/status", put(Self::change_status))
+            .route("/participants/status/{status}", get(Self::list_by_status))
+            .route("/participants/status/expire", post(Self::expire_lapsed))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn change_status(
+        State(module): State<Arc<dyn ParticipantLifecycleModuleTrait>>,
+        Path(id): Path<String>,
+        Json(change): Json<StatusChange>,
+    ) -> AppResult<Json<participant::Model>> {
+        Ok(Json(module.change_status(&id, change).await?))
+    }
+
+    async fn list_by_status(
+        State(module): State<Arc<dyn ParticipantLifecycleModuleTrait>>,
+        Path(status): Path<ParticipantStatus>,
+    ) -> AppResult<Json<Vec<participant::Model>>> {
+        Ok(Json(module.list_by_status(status).await?))
+    }
+
+    async fn expire_lapsed(
+        State(module): State<Arc<dyn ParticipantLifecycleModuleTrait>>,
+    ) -> AppResult<Json<usize>> {
+        Ok(Json(module.expire_lapsed().await?))
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use crate::data::entities::shared::participant;
+use crate::errors::AppResult;
+use crate::modules::ParticipantSearchModuleTrait;
+use crate::types::pagination::{Page, PageRequest};
+
+/// Query parameters accepted by the search endpoint.
+#[derive(Deserialize)]
+struct SearchQuery {
+    /// Text to look for in the id, nick and base URL; empty matches every participant.
+    #[serde(default)]
+    q: String,
+}
+
+/// HTTP API Gateway Router searching the known participants.
+///
+/// Administrative only: it must be mounted behind the consumer's own access control.
+pub struct ParticipantSearchRouter {
+    module: Arc<dyn ParticipantSearchModuleTrait>,
+}
+
+impl ParticipantSearchRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the search module.
+    pub fn new(module: Arc<dyn ParticipantSearchModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET /participants/search` - Lists a page of the participants matching `q`, windowed
+    ///   by `limit`, `offset`, `sort` and `order`. Sorted by nick by default.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/participants/search", get(Self::search))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn search(
+        State(module): State<Arc<dyn ParticipantSearchModuleTrait>>,
+        Query(query): Query<SearchQuery>,
+        Query(page): Query<PageRequest>,
+    ) -> AppResult<Json<Page<participant::Model>>> {
+        Ok(Json(module.search_participants(&query.q, &page).await?))
+    }
+}
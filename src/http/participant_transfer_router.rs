@@ -0,0 +1,87 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use crate::data::entities::shared::participant;
+use crate::errors::AppResult;
+use crate::modules::ParticipantTransferModuleTrait;
+use crate::types::participants::{TransferFormat, parse_participants, render_participants};
+
+/// Query parameters accepted by the export endpoint.
+#[derive(Deserialize)]
+struct ExportQuery {
+    /// Encoding of the exported list, `json` unless told otherwise.
+    #[serde(default)]
+    format: TransferFormat,
+}
+
+/// HTTP API Gateway Router importing and exporting participants in bulk.
+///
+/// Administrative only: it must be mounted behind the consumer's own access control.
+pub struct ParticipantTransferRouter {
+    module: Arc<dyn ParticipantTransferModuleTrait>,
+}
+
+impl ParticipantTransferRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the transfer module.
+    pub fn new(module: Arc<dyn ParticipantTransferModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `POST /participants/import` - Upserts a participant list sent as `text/csv` or JSON.
+    /// * `GET  /participants/export` - Returns every other participant, as JSON or, with
+    ///   `?format=csv`, as CSV.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/participants/import", post(Self::import))
+            .route("/participants/export", get(Self::export))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn import(
+        State(module): State<Arc<dyn ParticipantTransferModuleTrait>>,
+        headers: HeaderMap,
+        body: String,
+    ) -> AppResult<Json<Vec<participant::Model>>> {
+        let content_type = headers.get(CONTENT_TYPE).and_then(|v| v.to_str().ok());
+        let records = parse_participants(&body, TransferFormat::from_content_type(content_type))?;
+        Ok(Json(module.import_participants(records).await?))
+    }
+
+    async fn export(
+        State(module): State<Arc<dyn ParticipantTransferModuleTrait>>,
+        Query(query): Query<ExportQuery>,
+    ) -> AppResult {
+        let records = module.export_participants().await?;
+        let body = render_participants(&records, query.format)?;
+        Ok(([(CONTENT_TYPE, query.format.content_type())], body).into_response())
+    }
+}
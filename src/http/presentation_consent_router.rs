@@ -0,0 +1,172 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, Query, State};
+use axum::http::header::CACHE_CONTROL;
+use axum::response::IntoResponse;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use chrono::Utc;
+use futures_util::Stream;
+use futures_util::stream;
+use serde::Deserialize;
+use tokio::time::sleep;
+
+use crate::data::entities::wallet::pending_presentation::Model;
+use crate::errors::AppResult;
+use crate::modules::PresentationConsentModuleTrait;
+use crate::types::wallet::{ConsentStatus, OidcUri};
+use crate::utils::extract_payload;
+
+/// How long the event stream waits for new requests before checking again.
+const EVENT_WAIT: Duration = Duration::from_secs(30);
+
+/// Filter parameters of `GET /presentations`.
+#[derive(Deserialize)]
+struct ListQuery {
+    status: Option<ConsentStatus>,
+}
+
+/// Decision payload of `POST /presentations/{id}/approve`.
+#[derive(Deserialize, Default)]
+struct ApproveReq {
+    /// Credentials to present; the first candidate of every descriptor when omitted.
+    #[serde(default)]
+    credentials: Option<Vec<String>>,
+}
+
+/// HTTP API Gateway Router exposing the holder consent over outgoing presentations.
+///
+/// Must be mounted behind the consumer's own access control.
+pub struct PresentationConsentRouter {
+    module: Arc<dyn PresentationConsentModuleTrait>,
+}
+
+impl PresentationConsentRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the consent module.
+    pub fn new(module: Arc<dyn PresentationConsentModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `POST /presentations`              - Holds an OID4VP request until it is decided.
+    /// * `GET  /presentations`              - Lists requests, optionally filtered by `status`.
+    /// * `GET  /presentations/events`       - Streams every request received from now on as SSE.
+    /// * `GET  /presentations/{id}`         - Fetches a request and its candidate credentials.
+    /// * `POST /presentations/{id}/approve` - Presents the chosen credentials.
+    /// * `POST /presentations/{id}/reject`  - Closes the request without presenting.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route(
+                "/presentations",
+                get(Self::list_presentations).post(Self::hold_presentation),
+            )
+            .route("/presentations/events", get(Self::presentation_events))
+            .route("/presentations/{id}", get(Self::get_presentation))
+            .route(
+                "/presentations/{id}/approve",
+                post(Self::approve_presentation),
+            )
+            .route(
+                "/presentations/{id}/reject",
+                post(Self::reject_presentation),
+            )
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn hold_presentation(
+        State(module): State<Arc<dyn PresentationConsentModuleTrait>>,
+        payload: Result<Json<OidcUri>, JsonRejection>,
+    ) -> AppResult<Json<Model>> {
+        let payload = extract_payload(payload)?;
+        Ok(Json(module.hold_presentation(&payload.uri).await?))
+    }
+
+    async fn list_presentations(
+        State(module): State<Arc<dyn PresentationConsentModuleTrait>>,
+        Query(query): Query<ListQuery>,
+    ) -> AppResult {
+        let models = module.presentations(query.status).await?;
+        Ok(([(CACHE_CONTROL, "no-store")], Json(models)).into_response())
+    }
+
+    /// Emits a `presentation` event carrying each request as soon as it is held.
+    async fn presentation_events(
+        State(module): State<Arc<dyn PresentationConsentModuleTrait>>,
+    ) -> Sse<impl Stream<Item = Result<Event, axum::Error>>> {
+        let state = (module, Utc::now(), VecDeque::<Model>::new());
+        let events = stream::unfold(state, |(module, mut since, mut queue)| async move {
+            loop {
+                if let Some(model) = queue.pop_front() {
+                    let event = Event::default()
+                        .event("presentation")
+                        .id(&model.id)
+                        .json_data(&model);
+                    return Some((event, (module, since, queue)));
+                }
+                match module.wait_for_presentations(since, EVENT_WAIT).await {
+                    Ok(models) => {
+                        if let Some(last) = models.last() {
+                            since = last.created_at;
+                        }
+                        queue.extend(models);
+                    }
+                    Err(e) => {
+                        e.log();
+                        sleep(module.get_event_poll_interval()).await;
+                    }
+                }
+            }
+        });
+        Sse::new(events).keep_alive(KeepAlive::default())
+    }
+
+    async fn get_presentation(
+        State(module): State<Arc<dyn PresentationConsentModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<Model>> {
+        Ok(Json(module.presentation(&id).await?))
+    }
+
+    async fn approve_presentation(
+        State(module): State<Arc<dyn PresentationConsentModuleTrait>>,
+        Path(id): Path<String>,
+        payload: Option<Json<ApproveReq>>,
+    ) -> AppResult<Json<Model>> {
+        let req = payload.map(|Json(req)| req).unwrap_or_default();
+        Ok(Json(
+            module.approve_presentation(&id, req.credentials).await?,
+        ))
+    }
+
+    async fn reject_presentation(
+        State(module): State<Arc<dyn PresentationConsentModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<Model>> {
+        Ok(Json(module.reject_presentation(&id).await?))
+    }
+}
@@ -0,0 +1,83 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::errors::AppResult;
+use crate::modules::ReceiptModuleTrait;
+use crate::types::verification::VerificationReceipt;
+
+/// HTTP API Gateway Router exposing verification receipts.
+pub struct ReceiptRouter {
+    module: Arc<dyn ReceiptModuleTrait>,
+}
+
+impl ReceiptRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the receipt module.
+    pub fn new(module: Arc<dyn ReceiptModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the receipt routing tree.
+    ///
+    /// Mount it under `{api_path}/verifier`.
+    ///
+    /// # Exposed Map
+    /// * `POST /verifications/{id}/receipt` - Issues (or returns) the receipt of a verification.
+    /// * `GET  /verifications/{id}/receipt` - Retrieves a previously issued receipt.
+    /// * `POST /receipts/verify`            - Validates a receipt JWT sent as the raw body.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route(
+                "/verifications/{id}/receipt",
+                get(Self::get_receipt).post(Self::issue_receipt),
+            )
+            .route("/receipts/verify", post(Self::verify_receipt))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn issue_receipt(
+        State(module): State<Arc<dyn ReceiptModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult {
+        let jwt = module.issue_receipt(&id).await?;
+        Ok(([(CONTENT_TYPE, "application/jwt")], jwt).into_response())
+    }
+
+    async fn get_receipt(
+        State(module): State<Arc<dyn ReceiptModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult {
+        let jwt = module.get_receipt(&id).await?;
+        Ok(([(CONTENT_TYPE, "application/jwt")], jwt).into_response())
+    }
+
+    async fn verify_receipt(
+        State(module): State<Arc<dyn ReceiptModuleTrait>>,
+        body: String,
+    ) -> AppResult<Json<VerificationReceipt>> {
+        Ok(Json(module.verify_receipt(body.trim()).await?))
+    }
+}
@@ -0,0 +1,70 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use crate::errors::AppResult;
+use crate::modules::RequirementsModuleTrait;
+use crate::types::verification::TokenRequirements;
+
+/// Query parameters accepted by the requirements discovery endpoint.
+#[derive(Deserialize)]
+struct RequirementsQuery {
+    /// Protected resource identifier the client intends to access.
+    resource: Option<String>,
+    /// Action the client intends to perform over the resource.
+    action: Option<String>,
+}
+
+/// HTTP API Gateway Router exposing access requirement discovery.
+///
+/// Allows clients to learn which Verifiable Credentials they need before starting a GNAP
+/// grant, avoiding round-trips that are doomed to fail at the presentation step.
+pub struct RequirementsRouter {
+    module: Arc<dyn RequirementsModuleTrait>,
+}
+
+impl RequirementsRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the requirements module.
+    pub fn new(module: Arc<dyn RequirementsModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the discovery routing tree bound to its shared module state context.
+    ///
+    /// # Exposed Map
+    /// * `GET /requirements?resource=...&action=...` - Lists the credentials required for access.
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/requirements", get(Self::get_requirements))
+            .with_state(self.module)
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn get_requirements(
+        State(module): State<Arc<dyn RequirementsModuleTrait>>,
+        Query(query): Query<RequirementsQuery>,
+    ) -> AppResult<Json<TokenRequirements>> {
+        Ok(Json(module.get_requirements(query.resource, query.action)))
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use crate::data::entities::shared::revocation_event;
+use crate::errors::AppResult;
+use crate::modules::RevocationFeedModuleTrait;
+
+/// Query parameters accepted by the revocation feed endpoint.
+#[derive(Deserialize)]
+struct FeedQuery {
+    /// Sequence number of the last event already ingested.
+    #[serde(default)]
+    cursor: i64,
+    /// Maximum number of events to return, capped by the configured page size.
+    limit: Option<u64>,
+}
+
+/// Body accepted when revoking a participant.
+#[derive(Deserialize, Default)]
+struct RevokeBody {
+    reason: Option<String>,
+}
+
+/// HTTP API Gateway Router governing the authority's revocation feed.
+///
+/// Split into a public surface, polled by participant agents, and an administrative one that
+/// must be mounted behind the consumer's own access control.
+pub struct RevocationFeedRouter {
+    module: Arc<dyn RevocationFeedModuleTrait>,
+}
+
+impl RevocationFeedRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the revocation feed module.
+    pub fn new(module: Arc<dyn RevocationFeedModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the public routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET /revocations?cursor=&limit=` - Serves the signed feed page following `cursor`.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/revocations", get(Self::get_feed))
+            .with_state(self.module.clone())
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `POST /participants/{id}/revoke` - Revokes the participant and publishes it on the feed.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/participants/{id}/revoke", post(Self::revoke_participant))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn get_feed(
+        State(module): State<Arc<dyn RevocationFeedModuleTrait>>,
+        Query(query): Query<FeedQuery>,
+    ) -> AppResult {
+        let jwt = module.get_feed(query.cursor, query.limit).await?;
+        Ok(([(CONTENT_TYPE, "application/jwt")], jwt).into_response())
+    }
+
+    async fn revoke_participant(
+        State(module): State<Arc<dyn RevocationFeedModuleTrait>>,
+        Path(id): Path<String>,
+        body: Option<Json<RevokeBody>>,
+    ) -> AppResult<Json<Vec<revocation_event::Model>>> {
+        let Json(body) = body.unwrap_or_default();
+        Ok(Json(module.revoke_participant(&id, body.reason).await?))
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::routing::get;
+use axum::{Json, Router};
+
+use crate::errors::AppResult;
+use crate::modules::RevocationImpactModuleTrait;
+use crate::types::revocation::RevocationImpact;
+
+/// HTTP API Gateway Router exposing dry-run revocation impact reports.
+///
+/// Administrative only: mount it behind the consumer's own access control, next to the
+/// endpoints that actually revoke.
+pub struct RevocationImpactRouter {
+    module: Arc<dyn RevocationImpactModuleTrait>,
+}
+
+impl RevocationImpactRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the revocation impact module.
+    pub fn new(module: Arc<dyn RevocationImpactModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET /participants/{id}/revocation-impact` - Reports what revoking the participant affects.
+    /// * `GET /credentials/{id}/revocation-impact`  - Reports what revoking the credential affects.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route(
+                "/participants/{id}/revocation-impact",
+                get(Self::participant_impact),
+            )
+            .route(
+                "/credentials/{id}/revocation-impact",
+                get(Self::credential_impact),
+            )
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn participant_impact(
+        State(module): State<Arc<dyn RevocationImpactModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<RevocationImpact>> {
+        Ok(Json(module.participant_impact(&id).await?))
+    }
+
+    async fn credential_impact(
+        State(module): State<Arc<dyn RevocationImpactModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<RevocationImpact>> {
+        Ok(Json(module.credential_impact(&id).await?))
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::errors::AppResult;
+use crate::modules::StandbyModuleTrait;
+use crate::services::standby::StandbyTrait;
+use crate::types::standby::StandbyStatus;
+
+/// HTTP API Gateway Router steering the active/standby pair.
+///
+/// Administrative only: it must be mounted behind the consumer's own access control.
+pub struct StandbyRouter {
+    module: Arc<dyn StandbyModuleTrait>,
+}
+
+impl StandbyRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the standby module.
+    pub fn new(module: Arc<dyn StandbyModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET  /standby`         - Reports the role of this instance and the current leader.
+    /// * `POST /standby/promote` - Promotes this instance to active.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/standby", get(Self::get_status))
+            .route("/standby/promote", post(Self::promote))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn get_status(
+        State(module): State<Arc<dyn StandbyModuleTrait>>,
+    ) -> AppResult<Json<StandbyStatus>> {
+        Ok(Json(module.status().await?))
+    }
+
+    async fn promote(
+        State(module): State<Arc<dyn StandbyModuleTrait>>,
+    ) -> AppResult<Json<StandbyStatus>> {
+        Ok(Json(module.promote().await?))
+    }
+}
+
+/// Middleware turning away the mutating requests reaching an instance on standby.
+///
+/// Safe methods (`GET`, `HEAD`, `OPTIONS`, `TRACE`) are always served. Mount it over the
+/// dataspace-facing routers with [`axum::middleware::from_fn_with_state`] and the standby
+/// service; the [`StandbyRouter`] must stay outside it so the standby can be promoted.
+pub async fn standby_write_guard(
+    State(standby): State<Arc<dyn StandbyTrait>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if request.method().is_safe() {
+        return next.run(request).await;
+    }
+    match standby.ensure_active().await {
+        Ok(()) => next.run(request).await,
+        Err(e) => e.into_response(),
+    }
+}
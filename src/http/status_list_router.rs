@@ -0,0 +1,119 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::data::entities::shared::status_entry;
+use crate::errors::AppResult;
+use crate::modules::StatusListModuleTrait;
+use crate::types::issuance::StatusPurpose;
+
+/// HTTP API Gateway Router governing credential status lists.
+///
+/// Split into a public surface, dereferenced by verifiers checking a credential, and an
+/// administrative one that must be mounted behind the consumer's own access control.
+pub struct StatusListRouter {
+    module: Arc<dyn StatusListModuleTrait>,
+}
+
+impl StatusListRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the status list module.
+    pub fn new(module: Arc<dyn StatusListModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the public status list routing tree.
+    ///
+    /// Mount it under `{api_path}/issuer` so URLs match the `statusListCredential` entries.
+    ///
+    /// # Exposed Map
+    /// * `GET /status-lists/{id}` - Serves the signed `BitstringStatusListCredential`.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/status-lists/{id}", get(Self::get_status_list))
+            .with_state(self.module.clone())
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET  /credentials/{id}/status`    - Lists the status entries of an issued credential.
+    /// * `POST /credentials/{id}/revoke`    - Permanently revokes the credential.
+    /// * `POST /credentials/{id}/suspend`   - Suspends the credential.
+    /// * `POST /credentials/{id}/reinstate` - Lifts a suspension.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/credentials/{id}/status", get(Self::get_credential_status))
+            .route("/credentials/{id}/revoke", post(Self::revoke))
+            .route("/credentials/{id}/suspend", post(Self::suspend))
+            .route("/credentials/{id}/reinstate", post(Self::reinstate))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn get_status_list(
+        State(module): State<Arc<dyn StatusListModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult {
+        let jwt = module.get_status_list(&id).await?;
+        Ok(([(CONTENT_TYPE, "application/vc+jwt")], jwt).into_response())
+    }
+
+    async fn get_credential_status(
+        State(module): State<Arc<dyn StatusListModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<Vec<status_entry::Model>>> {
+        Ok(Json(module.get_credential_status(&id).await?))
+    }
+
+    async fn revoke(
+        State(module): State<Arc<dyn StatusListModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<status_entry::Model>> {
+        let entry = module
+            .update_status(&id, StatusPurpose::Revocation, true)
+            .await?;
+        Ok(Json(entry))
+    }
+
+    async fn suspend(
+        State(module): State<Arc<dyn StatusListModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<status_entry::Model>> {
+        let entry = module
+            .update_status(&id, StatusPurpose::Suspension, true)
+            .await?;
+        Ok(Json(entry))
+    }
+
+    async fn reinstate(
+        State(module): State<Arc<dyn StatusListModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<status_entry::Model>> {
+        let entry = module
+            .update_status(&id, StatusPurpose::Suspension, false)
+            .await?;
+        Ok(Json(entry))
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::errors::AppResult;
+use crate::modules::TrustAnchorModuleTrait;
+use crate::types::verification::TrustSyncStatus;
+
+/// HTTP API Gateway Router exposing the external trust anchor sync.
+///
+/// Must be mounted behind the consumer's own access control.
+pub struct TrustAnchorRouter {
+    module: Arc<dyn TrustAnchorModuleTrait>,
+}
+
+impl TrustAnchorRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the trust anchor module.
+    pub fn new(module: Arc<dyn TrustAnchorModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET  /trust-anchors/status` - Latest sync outcome of every registry.
+    /// * `POST /trust-anchors/sync`   - Runs a sync round right away.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/trust-anchors/status", get(Self::status))
+            .route("/trust-anchors/sync", post(Self::sync))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn status(
+        State(module): State<Arc<dyn TrustAnchorModuleTrait>>,
+    ) -> AppResult<Json<Vec<TrustSyncStatus>>> {
+        Ok(Json(module.sync_status().await))
+    }
+
+    async fn sync(
+        State(module): State<Arc<dyn TrustAnchorModuleTrait>>,
+    ) -> AppResult<Json<Vec<TrustSyncStatus>>> {
+        module.sync_all().await;
+        Ok(Json(module.sync_status().await))
+    }
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+
+use crate::data::entities::shared::trusted_issuer::{Model, Plan};
+use crate::errors::AppResult;
+use crate::modules::TrustedIssuerModuleTrait;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::verification::TrustedIssuerFilter;
+use crate::utils::extract_payload;
+
+/// HTTP API Gateway Router administering the trusted issuer registry.
+///
+/// Must be mounted behind the consumer's own access control.
+pub struct TrustedIssuerRouter {
+    module: Arc<dyn TrustedIssuerModuleTrait>,
+}
+
+impl TrustedIssuerRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the trusted issuer module.
+    pub fn new(module: Arc<dyn TrustedIssuerModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET    /trusted-issuers`      - Lists a page of registrations, filtered by `issuer_did`
+    ///   or `source` and windowed by `limit`, `offset`, `sort` and `order`.
+    /// * `POST   /trusted-issuers`      - Registers an issuer DID, optionally for one `vc_type`.
+    /// * `GET    /trusted-issuers/{id}` - Retrieves a registration.
+    /// * `DELETE /trusted-issuers/{id}` - Removes a registration.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/trusted-issuers", get(Self::list).post(Self::register))
+            .route("/trusted-issuers/{id}", get(Self::get_one).delete(Self::remove))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn list(
+        State(module): State<Arc<dyn TrustedIssuerModuleTrait>>,
+        Query(filter): Query<TrustedIssuerFilter>,
+        Query(page): Query<PageRequest>,
+    ) -> AppResult<Json<Page<Model>>> {
+        Ok(Json(module.list_trusted_issuers(&filter, &page).await?))
+    }
+
+    async fn register(
+        State(module): State<Arc<dyn TrustedIssuerModuleTrait>>,
+        payload: Result<Json<Plan>, JsonRejection>,
+    ) -> AppResult<(StatusCode, Json<Model>)> {
+        let plan = extract_payload(payload)?;
+        let model = module.register_trusted_issuer(plan).await?;
+        Ok((StatusCode::CREATED, Json(model)))
+    }
+
+    async fn get_one(
+        State(module): State<Arc<dyn TrustedIssuerModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<Model>> {
+        Ok(Json(module.get_trusted_issuer(&id).await?))
+    }
+
+    async fn remove(
+        State(module): State<Arc<dyn TrustedIssuerModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<StatusCode> {
+        module.remove_trusted_issuer(&id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+}
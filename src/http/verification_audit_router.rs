@@ -0,0 +1,73 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+
+use crate::data::entities::received::{verification, verification_audit};
+use crate::errors::AppResult;
+use crate::modules::VerificationModuleTrait;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::verification::VerificationFilter;
+
+/// HTTP API Gateway Router exposing the verifier sessions and their audit trail.
+///
+/// Entries carry raw presentations and undisclosed claims, so the router is administrative
+/// only and must be mounted behind the consumer's own access control.
+pub struct VerificationAuditRouter {
+    module: Arc<dyn VerificationModuleTrait>,
+}
+
+impl VerificationAuditRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the verification module.
+    pub fn new(module: Arc<dyn VerificationModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the administrative routing tree.
+    ///
+    /// # Exposed Map
+    /// * `GET /verifications`            - Lists a page of sessions, filtered by `status` or
+    ///   `holder` and windowed by `limit`, `offset`, `sort` and `order`.
+    /// * `GET /verifications/{id}/audit` - Lists the evidence recorded for the verification.
+    pub fn admin_router(&self) -> Router {
+        Router::new()
+            .route("/verifications", get(Self::list_verifications))
+            .route("/verifications/{id}/audit", get(Self::get_audit_trail))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn list_verifications(
+        State(module): State<Arc<dyn VerificationModuleTrait>>,
+        Query(filter): Query<VerificationFilter>,
+        Query(page): Query<PageRequest>,
+    ) -> AppResult<Json<Page<verification::Model>>> {
+        Ok(Json(module.list_verifications(&filter, &page).await?))
+    }
+
+    async fn get_audit_trail(
+        State(module): State<Arc<dyn VerificationModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<Vec<verification_audit::Model>>> {
+        Ok(Json(module.audit_trail(&id).await?))
+    }
+}
@@ -0,0 +1,181 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Form, Path, Query, State};
+use axum::http::header::{CACHE_CONTROL, REFERRER_POLICY};
+use axum::response::{Html, IntoResponse};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{AppResult, BadFormat, Errors};
+use crate::modules::VerificationModuleTrait;
+use crate::types::verification::VerificationStatus;
+
+/// Relay page for `response_mode=fragment`.
+///
+/// Fragments never reach the server, so the page strips the fragment from the address bar
+/// and re-submits its parameters as a form POST to the same URL.
+const FRAGMENT_RELAY_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>Completing verification</title></head>
+<body>
+<form id="relay" method="post"></form>
+<noscript>JavaScript is required to complete the verification.</noscript>
+<script>
+const params = new URLSearchParams(window.location.hash.substring(1));
+history.replaceState(null, "", window.location.pathname);
+const form = document.getElementById("relay");
+for (const [name, value] of params) {
+  const input = document.createElement("input");
+  input.type = "hidden";
+  input.name = name;
+  input.value = value;
+  form.appendChild(input);
+}
+form.submit();
+</script>
+</body>
+</html>
+"#;
+
+/// Authorization response parameters returned by a same-device wallet.
+#[derive(Deserialize)]
+struct CallbackParams {
+    vp_token: Option<String>,
+    presentation_submission: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+impl CallbackParams {
+    fn is_empty(&self) -> bool {
+        self.vp_token.is_none() && self.state.is_none() && self.error.is_none()
+    }
+}
+
+/// Outcome shown to the browser once the exchange is completed.
+#[derive(Serialize)]
+struct CallbackOutcome {
+    id: String,
+    status: VerificationStatus,
+}
+
+/// HTTP API Gateway Router receiving same-device wallet responses.
+///
+/// Backs the `fragment` response mode, where the wallet redirects the user agent to the
+/// verifier instead of posting the `vp_token` directly. A `vp_token` sent in the query string
+/// is refused, as it has already been exposed to every log on the way.
+pub struct VpCallbackRouter {
+    module: Arc<dyn VerificationModuleTrait>,
+}
+
+impl VpCallbackRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the verification module.
+    pub fn new(module: Arc<dyn VerificationModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the callback routing tree.
+    ///
+    /// Mount it under `{api_path}/verifier` so it matches the `redirect_uri` of the plans.
+    ///
+    /// # Exposed Map
+    /// * `GET  /callback/{state}` - Serves the fragment relay page, or records a wallet error.
+    /// * `POST /callback/{state}` - Receives the parameters relayed from a `fragment` response.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route(
+                "/callback/{state}",
+                get(Self::handle_query).post(Self::handle_relay),
+            )
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn handle_query(
+        State(module): State<Arc<dyn VerificationModuleTrait>>,
+        Path(state): Path<String>,
+        Query(params): Query<CallbackParams>,
+    ) -> AppResult {
+        if params.is_empty() {
+            let headers = [
+                (CACHE_CONTROL, "no-store"),
+                (REFERRER_POLICY, "no-referrer"),
+            ];
+            return Ok((headers, Html(FRAGMENT_RELAY_PAGE)).into_response());
+        }
+        if params.vp_token.is_some() {
+            return Err(Errors::security(
+                "vp_token must not be sent in the query string",
+                None,
+            ));
+        }
+        Self::complete(module, &state, params).await
+    }
+
+    async fn handle_relay(
+        State(module): State<Arc<dyn VerificationModuleTrait>>,
+        Path(state): Path<String>,
+        Form(params): Form<CallbackParams>,
+    ) -> AppResult {
+        Self::complete(module, &state, params).await
+    }
+
+    async fn complete(
+        module: Arc<dyn VerificationModuleTrait>,
+        state: &str,
+        params: CallbackParams,
+    ) -> AppResult {
+        if params.state.as_deref() != Some(state) {
+            return Err(Errors::security(
+                "Callback state does not match the exchange",
+                None,
+            ));
+        }
+
+        let model = match (params.vp_token, params.error) {
+            (_, Some(error)) => {
+                let reason = params.error_description.unwrap_or(error);
+                module.fail_verification(state, &reason).await?
+            }
+            (Some(vp_token), None) => {
+                let submission = params.presentation_submission.as_deref();
+                module
+                    .complete_verification(state, &vp_token, submission)
+                    .await?
+            }
+            (None, None) => {
+                return Err(Errors::format(
+                    BadFormat::Received,
+                    "Callback carries neither vp_token nor error",
+                    None,
+                ));
+            }
+        };
+
+        let outcome = CallbackOutcome {
+            id: model.id,
+            status: model.status,
+        };
+        Ok(([(CACHE_CONTROL, "no-store")], Json(outcome)).into_response())
+    }
+}
@@ -0,0 +1,177 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::{Form, Path, Query, State};
+use axum::http::HeaderValue;
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE, LINK};
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::AppResult;
+use crate::modules::VerificationModuleTrait;
+use crate::types::verification::{AuthorizationResponse, VerificationSession, VerificationStatus};
+use crate::utils::{QrFormat, render_qr};
+
+/// Longest a status request is held open waiting for the session to change.
+const MAX_LONG_POLL_SECS: u64 = 30;
+
+/// Outcome returned to the wallet once the exchange is completed.
+#[derive(Serialize)]
+struct ResponseOutcome {
+    id: String,
+    status: VerificationStatus,
+}
+
+/// Query parameters of the session status endpoint.
+#[derive(Deserialize)]
+struct StatusQuery {
+    /// Seconds to hold the request open until the status changes. Answers at once if unset.
+    wait: Option<u64>,
+    /// Status the client last saw; defaults to `pending`.
+    since: Option<VerificationStatus>,
+}
+
+/// Query parameters of the QR code endpoint.
+#[derive(Deserialize)]
+struct QrQuery {
+    #[serde(default)]
+    format: QrFormat,
+}
+
+/// HTTP API Gateway Router receiving cross-device wallet responses and serving session status.
+///
+/// Backs the `direct_post` response mode, plain or encrypted (`direct_post.jwt`). A desktop
+/// relying party shows the QR code of a session and long-polls its status while the holder
+/// scans it with a mobile wallet.
+pub struct VpResponseRouter {
+    module: Arc<dyn VerificationModuleTrait>,
+}
+
+impl VpResponseRouter {
+    /// Instantiates a new HTTP network boundary instance wrapping the verification module.
+    pub fn new(module: Arc<dyn VerificationModuleTrait>) -> Self {
+        Self { module }
+    }
+
+    /// Composes the response routing tree.
+    ///
+    /// Mount it under `{api_path}/verifier` so it matches the `response_uri` of the plans.
+    ///
+    /// # Exposed Map
+    /// * `POST /verify/{state}`                 - Receives a `vp_token`, an error or a JARM `response`.
+    /// * `GET  /verifications/{id}/status?wait` - Polls, or long-polls, the state of a session.
+    /// * `GET  /verifications/{id}/qr?format`   - Renders the wallet request URI as an SVG or PNG QR code.
+    /// * `GET  /request/{id}`                   - Serves the request object referenced by the QR code.
+    /// * `GET  /pd/{id}`                        - Serves a content addressed presentation definition.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/verify/{state}", post(Self::handle_response))
+            .route("/pd/{id}", get(Self::presentation_definition))
+            .route("/request/{id}", get(Self::request_object))
+            .route("/verifications/{id}/status", get(Self::session_status))
+            .route("/verifications/{id}/qr", get(Self::session_qr))
+            .with_state(self.module.clone())
+    }
+
+    // ===== HTTP HANDLER INNER LOGIC REPRESENTATIONS ==============================================
+
+    async fn handle_response(
+        State(module): State<Arc<dyn VerificationModuleTrait>>,
+        Path(state): Path<String>,
+        Form(response): Form<AuthorizationResponse>,
+    ) -> AppResult {
+        let model = module.complete_direct_post(&state, response).await?;
+        let outcome = ResponseOutcome {
+            id: model.id,
+            status: model.status,
+        };
+        Ok(([(CACHE_CONTROL, "no-store")], Json(outcome)).into_response())
+    }
+
+    /// Definitions never change under their id, so wallets may cache them for good. A
+    /// superseded one links to its successor.
+    async fn presentation_definition(
+        State(module): State<Arc<dyn VerificationModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult {
+        let (definition, superseded_by) = module.presentation_definition(&id).await?;
+        let mut response = (
+            [(CACHE_CONTROL, "public, max-age=31536000, immutable")],
+            Json(definition),
+        )
+            .into_response();
+        if let Some(successor) = superseded_by.and_then(|id| {
+            HeaderValue::from_str(&format!("<{id}>; rel=\"successor-version\"")).ok()
+        }) {
+            response.headers_mut().insert(LINK, successor);
+        }
+        Ok(response)
+    }
+
+    async fn request_object(
+        State(module): State<Arc<dyn VerificationModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult {
+        let request = module.request_object(&id).await?;
+        Ok((
+            [
+                (CONTENT_TYPE, "application/oauth-authz-req+jwt"),
+                (CACHE_CONTROL, "no-store"),
+            ],
+            request,
+        )
+            .into_response())
+    }
+
+    async fn session_status(
+        State(module): State<Arc<dyn VerificationModuleTrait>>,
+        Path(id): Path<String>,
+        Query(query): Query<StatusQuery>,
+    ) -> AppResult {
+        let session: VerificationSession = match query.wait {
+            Some(wait) => {
+                let since = query.since.unwrap_or(VerificationStatus::Pending);
+                let timeout = Duration::from_secs(wait.min(MAX_LONG_POLL_SECS));
+                module.wait_for_session(&id, since, timeout).await?
+            }
+            None => module.session_status(&id).await?,
+        };
+        Ok(([(CACHE_CONTROL, "no-store")], Json(session)).into_response())
+    }
+
+    async fn session_qr(
+        State(module): State<Arc<dyn VerificationModuleTrait>>,
+        Path(id): Path<String>,
+        Query(query): Query<QrQuery>,
+    ) -> AppResult {
+        let uri = module.verification_uri(&id).await?;
+        let image = render_qr(&uri, query.format)?;
+        Ok((
+            [
+                (CONTENT_TYPE, query.format.content_type()),
+                (CACHE_CONTROL, "no-store"),
+            ],
+            image,
+        )
+            .into_response())
+    }
+}
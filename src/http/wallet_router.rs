@@ -17,20 +17,28 @@
 
 use std::sync::Arc;
 
+use crate::data::entities::sent::grant;
 use crate::data::entities::wallet::vc::Model;
 use crate::data::entities::wallet::{did, key};
 use crate::errors::AppResult;
-use crate::modules::WalletModuleTrait;
+use crate::modules::{
+    TenantWallet, VcRequesterModuleTrait, WalletModuleTrait, spawn_credential_request,
+};
+use crate::services::wallet::WalletRegistry;
 use crate::types::dids::{DidBuilder, DidDocument, DidService};
-use crate::types::wallet::{DidSearch, OidcUri, WalletInfo};
+use crate::types::gnap::CallbackBody;
+use crate::types::vcs::VcType;
+use crate::types::wallet::{
+    DidSearch, KeyBackup, KeyImportSummary, OidcUri, OnboardingStatus, VcSearch, WalletInfo,
+};
 use crate::utils::extract_payload;
 use axum::extract::rejection::JsonRejection;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::{delete, get, post};
 use axum::{Json, Router};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Internal operational payload to register and pair raw asymmetric private keys.
 #[derive(Deserialize)]
@@ -55,18 +63,70 @@ struct RegisterDidReq {
     service: Option<Vec<DidService>>,
 }
 
+/// Payload asking for an encrypted backup of the wallet keys.
+#[derive(Deserialize)]
+struct ExportKeysReq {
+    /// Passphrase the backup is encrypted under; it is needed again to restore it.
+    passphrase: String,
+}
+
+/// Payload restoring a key backup into the wallet.
+#[derive(Deserialize)]
+struct ImportKeysReq {
+    /// Backup as returned by `POST /keys/export`.
+    backup: KeyBackup,
+    /// Passphrase the backup was encrypted under.
+    passphrase: String,
+}
+
+/// Payload asking a remote authority to issue us a credential.
+#[derive(Deserialize)]
+struct RequestCredentialReq {
+    /// Base URL of the authority; the grant path is appended to it.
+    authority: String,
+    /// Type of the credential requested.
+    vc_type: VcType,
+}
+
+/// Tenant wallet listed by `GET /tenants`.
+#[derive(Serialize, Clone)]
+struct TenantEntry {
+    id: String,
+    aliases: Vec<String>,
+}
+
 /// HTTP API Gateway Router governing the Wallet Module ecosystem.
 ///
 /// Exposes administrative endpoints for key and DID lifecycle tracking, Verifiable Credentials inventories,
 /// and standard out-of-band execution entry points for dynamic OID4VCI / OID4VP protocol exchanges.
 pub struct WalletRouter {
     holder: Arc<dyn WalletModuleTrait>,
+    tenants: WalletRegistry,
+    requester: Option<Arc<dyn VcRequesterModuleTrait>>,
 }
 
 impl WalletRouter {
     /// Instantiates a new HTTP network boundary instance wrapping the target functional business module.
     pub fn new(holder: Arc<dyn WalletModuleTrait>) -> Self {
-        Self { holder }
+        Self {
+            holder,
+            tenants: WalletRegistry::new(),
+            requester: None,
+        }
+    }
+
+    /// Serves every wallet of `tenants` under `/tenants/{id}`, and under its alias if it has one,
+    /// with the same routes as the default wallet.
+    pub fn with_tenants(mut self, tenants: WalletRegistry) -> Self {
+        self.tenants = tenants;
+        self
+    }
+
+    /// Exposes `/request-credential`, running credential requests to remote authorities
+    /// through `requester`.
+    pub fn with_vc_requester(mut self, requester: Arc<dyn VcRequesterModuleTrait>) -> Self {
+        self.requester = Some(requester);
+        self
     }
 
     /// Composes and provisions the foundational operational API routing tree bound to its shared module state context.
@@ -74,22 +134,75 @@ impl WalletRouter {
     /// # Exposed Map
     /// * `GET  /is-linked`      - Asserts linking execution parameters.
     /// * `POST /link`           - Enforces external ecosystem directory linkages.
+    /// * `GET/POST /onboarding` - Reports onboarding progress or resumes the missing steps.
     /// * `POST /key`            - Imports raw asymmetric cryptographic key material.
     /// * `DELETE /key/{id}`     - Purges custom key references.
+    /// * `POST /keys/export`    - Exports the keys and DIDs as a passphrase-encrypted backup.
+    /// * `POST /keys/import`    - Restores a backup, skipping the keys and DIDs already stored.
     /// * `GET/POST /did`        - Fetches primary identity string or spawns custom local DIDs.
     /// * `DELETE /did/{id}`     - Drops target DID structural mappings.
     /// * `DELETE /credential/{id}` - Un-links and purges specific credential records.
     /// * `GET  /info`           - Resolves runtime telemetry indicators.
     /// * `GET  /vcs`            - Collects full relational credential arrays.
+    /// * `GET  /credentials`    - Lists the stored credentials.
+    /// * `GET/DELETE /credentials/{id}` - Fetches or purges a single stored credential.
+    /// * `GET  /credentials/search` - Filters credentials by type, issuer, expiry and subject text.
     /// * `POST /oidc4vci`       - Dispatches inbound OpenID4VCI credential offers.
     /// * `POST /oidc4vp`        - Resolves outbound presentation request validation targets.
+    /// * `POST /request-credential` - Asks a remote authority for a credential, returning the flow.
+    /// * `GET  /request-credential/{id}` - Tracks a credential request flow.
+    /// * `POST /request-credential/{id}/callback` - Receives the authority's finish callback.
+    /// * `GET  /tenants`        - Lists the tenant wallets and their aliases.
+    /// * `/tenants/{id}/...`    - Any of the routes above, run against a tenant wallet.
     pub fn router(self) -> Router {
+        let mut tenants = Router::new();
+        let mut entries: Vec<TenantEntry> = Vec::new();
+        for (id, wallet) in self.tenants.iter() {
+            let scoped = Self::new(Arc::new(TenantWallet::new(wallet.clone()))).router();
+            tenants = tenants.nest(&format!("/tenants/{id}"), scoped);
+            entries.push(TenantEntry {
+                id: id.to_string(),
+                aliases: Vec::new(),
+            });
+        }
+        for (alias, id) in self.tenants.aliases() {
+            if let Ok(wallet) = self.tenants.get(id) {
+                let scoped = Self::new(Arc::new(TenantWallet::new(wallet))).router();
+                tenants = tenants.nest(&format!("/tenants/{alias}"), scoped);
+            }
+            if let Some(entry) = entries.iter_mut().find(|e| e.id == id) {
+                entry.aliases.push(alias.to_string());
+            }
+        }
+        tenants = tenants.route("/tenants", get(move || async move { Json(entries) }));
+
+        let requests = match self.requester {
+            Some(requester) => Router::new()
+                .route("/request-credential", post(Self::request_credential))
+                .route(
+                    "/request-credential/{id}",
+                    get(Self::get_credential_request),
+                )
+                .route(
+                    "/request-credential/{id}/callback",
+                    post(Self::credential_request_callback),
+                )
+                .with_state(requester),
+            None => Router::new(),
+        };
+
         Router::new()
             .route("/is-linked", get(Self::is_linked))
             .route("/link", post(Self::link))
+            .route(
+                "/onboarding",
+                get(Self::onboarding_status).post(Self::onboard),
+            )
             .route("/key", post(Self::register_key))
             .route("/keys", get(Self::get_wallet_keys))
             .route("/key/{id}", delete(Self::delete_key))
+            .route("/keys/export", post(Self::export_keys))
+            .route("/keys/import", post(Self::import_keys))
             .route("/did", get(Self::get_wallet_did).post(Self::register_did))
             .route("/did/{id}", delete(Self::delete_did))
             .route("/did/{id}/default", post(Self::set_default_did))
@@ -104,9 +217,17 @@ impl WalletRouter {
             .route("/credential/{id}", delete(Self::delete_credential))
             .route("/info", get(Self::get_wallet_info))
             .route("/vcs", get(Self::get_wallet_credentials))
+            .route("/credentials", get(Self::get_wallet_credentials))
+            .route(
+                "/credentials/{id}",
+                get(Self::get_wallet_credential).delete(Self::delete_credential),
+            )
+            .route("/credentials/search", get(Self::search_credentials))
             .route("/oid4vci", post(Self::process_oidc4vci))
             .route("/oid4vp", post(Self::process_oidc4vp))
             .with_state(self.holder)
+            .merge(tenants)
+            .merge(requests)
     }
 
     /// Mounts an isolated routing context specifically configured to answer public `did:web` resolution challenges.
@@ -125,6 +246,18 @@ impl WalletRouter {
         holder.link().await
     }
 
+    async fn onboarding_status(
+        State(holder): State<Arc<dyn WalletModuleTrait>>,
+    ) -> AppResult<Json<OnboardingStatus>> {
+        Ok(Json(holder.onboarding_status().await?))
+    }
+
+    async fn onboard(
+        State(holder): State<Arc<dyn WalletModuleTrait>>,
+    ) -> AppResult<Json<OnboardingStatus>> {
+        Ok(Json(holder.onboard().await?))
+    }
+
     async fn is_linked(State(holder): State<Arc<dyn WalletModuleTrait>>) -> AppResult {
         Ok(match holder.is_linked().await {
             true => StatusCode::OK.into_response(),
@@ -141,6 +274,22 @@ impl WalletRouter {
         Ok(StatusCode::CREATED)
     }
 
+    async fn export_keys(
+        State(holder): State<Arc<dyn WalletModuleTrait>>,
+        payload: Result<Json<ExportKeysReq>, JsonRejection>,
+    ) -> AppResult<Json<KeyBackup>> {
+        let req = extract_payload(payload)?;
+        Ok(Json(holder.export_keys(&req.passphrase).await?))
+    }
+
+    async fn import_keys(
+        State(holder): State<Arc<dyn WalletModuleTrait>>,
+        payload: Result<Json<ImportKeysReq>, JsonRejection>,
+    ) -> AppResult<Json<KeyImportSummary>> {
+        let req = extract_payload(payload)?;
+        Ok(Json(holder.import_keys(req.backup, &req.passphrase).await?))
+    }
+
     async fn register_did(
         State(holder): State<Arc<dyn WalletModuleTrait>>,
         payload: Result<Json<RegisterDidReq>, JsonRejection>,
@@ -245,6 +394,51 @@ impl WalletRouter {
         Ok(Json(holder.get_wallet_credentials().await?))
     }
 
+    async fn get_wallet_credential(
+        State(holder): State<Arc<dyn WalletModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<Model>> {
+        Ok(Json(holder.get_wallet_credential(&id).await?))
+    }
+
+    async fn search_credentials(
+        State(holder): State<Arc<dyn WalletModuleTrait>>,
+        Query(search): Query<VcSearch>,
+    ) -> AppResult<Json<Vec<Model>>> {
+        Ok(Json(holder.search_credentials(search).await?))
+    }
+
+    async fn request_credential(
+        State(requester): State<Arc<dyn VcRequesterModuleTrait>>,
+        payload: Result<Json<RequestCredentialReq>, JsonRejection>,
+    ) -> AppResult<(StatusCode, Json<grant::Model>)> {
+        let req = extract_payload(payload)?;
+        let model = requester
+            .start_credential_request(&req.authority, req.vc_type)
+            .await?;
+        if !model.lifecycle.is_terminal() {
+            spawn_credential_request(requester, model.id.clone());
+        }
+        Ok((StatusCode::ACCEPTED, Json(model)))
+    }
+
+    async fn get_credential_request(
+        State(requester): State<Arc<dyn VcRequesterModuleTrait>>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<grant::Model>> {
+        Ok(Json(requester.credential_request(&id).await?))
+    }
+
+    async fn credential_request_callback(
+        State(requester): State<Arc<dyn VcRequesterModuleTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<CallbackBody>, JsonRejection>,
+    ) -> AppResult<StatusCode> {
+        let body = extract_payload(payload)?;
+        requester.record_credential_callback(&id, body).await?;
+        Ok(StatusCode::OK)
+    }
+
     async fn get_wallet_keys(
         State(holder): State<Arc<dyn WalletModuleTrait>>,
     ) -> AppResult<Json<Vec<key::Model>>> {
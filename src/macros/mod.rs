@@ -122,4 +122,54 @@ macro_rules! impl_seaorm_via_str {
             }
         }
     };
-}
\ No newline at end of file
+}
+/// Declarative macro attaching the [`CrudRepoTrait`](crate::services::repo::traits::CrudRepoTrait)
+/// contract to in-memory repositories.
+///
+/// Delegates every operation to the
+/// [`BasicMemoryRepo`](crate::services::repo::memory::BasicMemoryRepo) mixin, which cannot
+/// provide a blanket implementation of its own without overlapping the Postgres one.
+///
+/// # Examples
+/// ```ignore
+/// impl_memory_crud!(KeyMemoryRepo, key::Model, key::Model);
+/// ```
+#[macro_export]
+macro_rules! impl_memory_crud {
+    ($repo:ty, $model:ty, $plan:ty) => {
+        #[async_trait::async_trait]
+        impl $crate::services::repo::traits::CrudRepoTrait<$model, $plan> for $repo {
+            async fn get_all(
+                &self,
+                limit: Option<u64>,
+                offset: Option<u64>,
+            ) -> $crate::errors::Outcome<Vec<$model>> {
+                $crate::services::repo::memory::BasicMemoryRepo::basic_get_all(self, limit, offset)
+                    .await
+            }
+
+            async fn get_page(
+                &self,
+                request: &$crate::types::pagination::PageRequest,
+            ) -> $crate::errors::Outcome<$crate::types::pagination::Page<$model>> {
+                $crate::services::repo::memory::BasicMemoryRepo::basic_get_page(self, request).await
+            }
+
+            async fn get_by_id(&self, id: &str) -> $crate::errors::Outcome<$model> {
+                $crate::services::repo::memory::BasicMemoryRepo::basic_get_by_id(self, id).await
+            }
+
+            async fn create(&self, plan: $plan) -> $crate::errors::Outcome<$model> {
+                $crate::services::repo::memory::BasicMemoryRepo::basic_create(self, plan).await
+            }
+
+            async fn update(&self, model: $model) -> $crate::errors::Outcome<$model> {
+                $crate::services::repo::memory::BasicMemoryRepo::basic_update(self, model).await
+            }
+
+            async fn delete(&self, id: &str) -> $crate::errors::Outcome<()> {
+                $crate::services::repo::memory::BasicMemoryRepo::basic_delete(self, id).await
+            }
+        }
+    };
+}
@@ -0,0 +1,259 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::config::traits::AccessPolicyConfigTrait;
+use crate::data::entities::shared::resource_req;
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::services::repo::traits::received::{RecvAccessTokenRepoTrait, RecvGrantRepoTrait};
+use crate::services::repo::traits::shared::{ParticipantRepoTrait, ResourceReqRepoTrait};
+use crate::types::gnap::grant_request::access::{AccessTokenFlag, AccessTokenRequest};
+use crate::types::gnap::grant_request::interact::InteractAction;
+use crate::types::keys::DbKeySource;
+use crate::utils::Clock;
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+
+/// Business Orchestration Module mapping GNAP access requests onto the configured route groups.
+///
+/// The access a client asks for is narrowed to what the [access policies] grant and stored as
+/// the token requirements of the grant; a token presented later only reaches the route groups
+/// and actions it was granted, with a proof of possession of the key it is bound to unless
+/// [bearer tokens] are allowed.
+///
+/// [access policies]: AccessPolicyConfigTrait::get_access_policies
+/// [bearer tokens]: AccessPolicyConfigTrait::allows_bearer_tokens
+#[async_trait]
+pub trait AccessPolicyModuleTrait: AccessPolicyConfigTrait + Send + Sync + 'static {
+    /// Returns the repository persisting the access granted to each grant.
+    fn resource_reqs(&self) -> Arc<dyn ResourceReqRepoTrait>;
+
+    /// Returns the repository persisting grants received from participants.
+    fn recv_grants(&self) -> Arc<dyn RecvGrantRepoTrait>;
+
+    /// Returns the repository persisting the labeled tokens of multiple token grants.
+    fn recv_access_tokens(&self) -> Arc<dyn RecvAccessTokenRepoTrait>;
+
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Resolves the access granted for `request` and stores it under `id`: the grant id, or
+    /// the [`access_id`](crate::data::entities::received::access_token::access_id) of a
+    /// labeled token.
+    ///
+    /// Requested locations name route groups; without them every group of the requested
+    /// type is considered. Requested actions are kept only if one of those groups allows them.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when no route group grants the requested access
+    /// or a bearer token is requested while they are not allowed.
+    async fn grant_access(
+        &self,
+        id: &str,
+        request: &AccessTokenRequest,
+    ) -> Outcome<resource_req::Model> {
+        let bearer = request
+            .flags
+            .as_ref()
+            .is_some_and(|flags| flags.contains(&AccessTokenFlag::Bearer));
+        if bearer && !self.allows_bearer_tokens() {
+            return Err(Errors::forbidden(
+                "Bearer access tokens are not issued",
+                None,
+            ));
+        }
+
+        let access = &request.access;
+        let mut granted = resource_req::Model {
+            id: id.to_string(),
+            r#type: access.r#type.clone(),
+            actions: access.actions.clone().unwrap_or_default(),
+            locations: access.locations.clone().map(Into::into),
+            datatypes: access.datatypes.clone().map(Into::into),
+            identifier: access.identifier.clone(),
+            privileges: access.privileges.clone().map(Into::into),
+            label: request.label.clone(),
+            flags: request.flags.clone(),
+        };
+
+        if !self.get_access_policies().is_empty() {
+            let groups: Vec<_> = self
+                .get_access_policies()
+                .iter()
+                .filter(|policy| policy.r#type == access.r#type)
+                .filter(|policy| {
+                    access
+                        .locations
+                        .as_ref()
+                        .is_none_or(|locations| locations.contains(&policy.group))
+                })
+                .collect();
+            granted
+                .actions
+                .retain(|action| groups.iter().any(|policy| policy.allows(action)));
+
+            if groups.is_empty() || (access.actions.is_some() && granted.actions.is_empty()) {
+                return Err(Errors::forbidden(
+                    format!(
+                        "No route group grants the {} access requested",
+                        access.r#type
+                    ),
+                    None,
+                ));
+            }
+            granted.locations = Some(groups.iter().map(|policy| policy.group.clone()).collect());
+        }
+
+        self.resource_reqs().create(granted).await
+    }
+
+    /// Checks that the access granted with `token` covers `action` over the route at `url`.
+    ///
+    /// The token must be bound to a key and come with an `httpsig` proof of the request made
+    /// with it; tokens without a bound key are only accepted while bearer tokens are allowed.
+    /// Both the single token held by a mate and the labeled tokens of a grant are accepted.
+    ///
+    /// # Errors
+    /// Returns an unauthorized error when `token` is unknown, expired or an unbound token no
+    /// longer accepted, a security error when the request proof does not hold and an
+    /// [`Errors::ForbiddenError`] when the granted access does not reach the route or the action.
+    async fn authorize(
+        &self,
+        token: &str,
+        action: &InteractAction,
+        method: &str,
+        url: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Outcome<resource_req::Model> {
+        let (token_key, expires_at, access_id) = self.resolve_token(token).await?;
+        if expires_at.is_some_and(|at| at <= Clock::now()) {
+            return Err(Errors::unauthorized("Access token has expired", None));
+        }
+        match &token_key {
+            Some(key) => key.verify_request(headers, method, url, body)?,
+            None if !self.allows_bearer_tokens() => {
+                return Err(Errors::unauthorized(
+                    "Access token is not bound to a key",
+                    None,
+                ));
+            }
+            None => {}
+        }
+
+        let granted = self.resource_reqs().get_by_id(&access_id).await?;
+
+        let path = Url::parse(url)
+            .map(|url| url.path().to_string())
+            .map_err(|e| {
+                Errors::format(
+                    BadFormat::Received,
+                    "Invalid request url",
+                    Some(Box::new(e)),
+                )
+            })?;
+        self.check_access(&granted, action, &path)?;
+        Ok(granted)
+    }
+
+    /// Finds the key `token` is bound to, its expiry and the id its access is stored under,
+    /// looking at the mates' single tokens first and then at the labeled ones.
+    ///
+    /// # Errors
+    /// Returns an unauthorized error when no mate or grant holds `token`, and an
+    /// [`Errors::ForbiddenError`] when the mate no longer stands or no grant issued the token.
+    async fn resolve_token(
+        &self,
+        token: &str,
+    ) -> Outcome<(Option<DbKeySource>, Option<DateTime<Utc>>, String)> {
+        match self.participants().get_by_token(token).await {
+            Ok(mate) => {
+                let grant = self
+                    .recv_grants()
+                    .filter_by_participant(&mate.participant_nick)
+                    .await?
+                    .into_iter()
+                    .find(|grant| grant.token.as_deref() == Some(token))
+                    .ok_or_else(|| {
+                        Errors::forbidden(
+                            format!("No grant of {} issued the token", mate.participant_nick),
+                            None,
+                        )
+                    })?;
+                return Ok((mate.token_key, mate.token_expires_at, grant.id));
+            }
+            Err(Errors::MissingResourceError { .. }) => {}
+            Err(e) => return Err(e),
+        }
+
+        let labeled = match self.recv_access_tokens().get_by_id(token).await {
+            Ok(labeled) => labeled,
+            Err(Errors::MissingResourceError { .. }) => {
+                return Err(Errors::unauthorized("Access token is not valid", None));
+            }
+            Err(e) => return Err(e),
+        };
+        self.participants()
+            .get_by_id(&labeled.participant_id)
+            .await?
+            .check_standing()?;
+        let access_id = labeled.access_id();
+        Ok((labeled.token_key, labeled.expires_at, access_id))
+    }
+
+    /// Checks the access stored in `granted` against `action` over the route at `path`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the route is outside every granted group or
+    /// the action was not granted.
+    fn check_access(
+        &self,
+        granted: &resource_req::Model,
+        action: &InteractAction,
+        path: &str,
+    ) -> Outcome<()> {
+        if let Some(policy) = self.get_access_policy_for(path) {
+            let in_group = policy.r#type == granted.r#type
+                && granted
+                    .locations
+                    .as_ref()
+                    .is_none_or(|groups| groups.contains(&policy.group));
+            if !in_group || !policy.allows(action) {
+                return Err(Errors::forbidden(
+                    format!("Token of grant {} does not reach {path}", granted.id),
+                    None,
+                ));
+            }
+        } else if !self.get_access_policies().is_empty() {
+            return Err(Errors::forbidden(
+                format!("No route group covers {path}"),
+                None,
+            ));
+        }
+
+        if !granted.actions.is_empty() && !granted.actions.contains(action) {
+            return Err(Errors::forbidden(
+                format!("Action {action} was not granted to grant {}", granted.id),
+                None,
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::config::traits::ArchiveConfigTrait;
+use crate::errors::Outcome;
+use crate::modules::standby::on_standby;
+use crate::services::HasArchive;
+use crate::services::repo::traits::received::{
+    RecvGrantRepoTrait, RecvInteractionRepoTrait, RecvVerificationRepoTrait,
+};
+use crate::services::repo::traits::shared::IssuanceRepoTrait;
+use crate::services::standby::StandbyTrait;
+use crate::types::archive::{ArchiveBundle, ArchiveKind, ArchiveReport, ArchivedGrant};
+
+/// Business Orchestration Module moving finished flows out of the operational database.
+///
+/// Records are exported in bundles to the archive store and only deleted once their bundle
+/// is stored, so a failure in between exports them again on the next round rather than
+/// losing them.
+#[async_trait]
+pub trait ArchiveModuleTrait: HasArchive + ArchiveConfigTrait + Send + Sync + 'static {
+    /// Returns the repository of the verification sessions.
+    fn verifications(&self) -> Arc<dyn RecvVerificationRepoTrait>;
+
+    /// Returns the repository of the issuance sessions.
+    fn issuances(&self) -> Arc<dyn IssuanceRepoTrait>;
+
+    /// Returns the repository of the received GNAP grants.
+    fn grants(&self) -> Arc<dyn RecvGrantRepoTrait>;
+
+    /// Returns the repository of the received GNAP interactions.
+    fn interactions(&self) -> Arc<dyn RecvInteractionRepoTrait>;
+
+    /// Returns the role keeper of an active/standby pair, if the instance takes part in one.
+    ///
+    /// The scheduled job pauses while it reports standby.
+    fn standby_guard(&self) -> Option<Arc<dyn StandbyTrait>> {
+        None
+    }
+
+    /// Flows finished before this instant are due for archival.
+    fn archive_cutoff(&self) -> DateTime<Utc> {
+        let retention = chrono::Duration::from_std(self.get_archive_retention())
+            .unwrap_or(chrono::Duration::MAX);
+        Utc::now()
+            .checked_sub_signed(retention)
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+    }
+
+    /// Archives the verification sessions that are over.
+    async fn archive_verifications(&self) -> Outcome<ArchiveReport> {
+        let cutoff = self.archive_cutoff();
+        let limit = self.get_archive_batch_size();
+        let mut report = ArchiveReport::new(ArchiveKind::Verifications);
+        loop {
+            let batch = self.verifications().get_archivable(cutoff, limit).await?;
+            if batch.is_empty() {
+                break;
+            }
+            let ids: Vec<String> = batch.iter().map(|m| m.id.clone()).collect();
+            let bundle = ArchiveBundle::new(ArchiveKind::Verifications, &batch)?;
+            report.objects.push(self.store_bundle(&bundle).await?);
+
+            let pruned = self.verifications().delete_many(&ids).await?;
+            report.archived += pruned;
+            if pruned == 0 || (batch.len() as u64) < limit {
+                break;
+            }
+        }
+        Ok(report)
+    }
+
+    /// Archives the issuance sessions that are over.
+    async fn archive_issuances(&self) -> Outcome<ArchiveReport> {
+        let cutoff = self.archive_cutoff();
+        let limit = self.get_archive_batch_size();
+        let mut report = ArchiveReport::new(ArchiveKind::Issuances);
+        loop {
+            let batch = self.issuances().get_archivable(cutoff, limit).await?;
+            if batch.is_empty() {
+                break;
+            }
+            let ids: Vec<String> = batch.iter().map(|m| m.id.clone()).collect();
+            let bundle = ArchiveBundle::new(ArchiveKind::Issuances, &batch)?;
+            report.objects.push(self.store_bundle(&bundle).await?);
+
+            let pruned = self.issuances().delete_many(&ids).await?;
+            report.archived += pruned;
+            if pruned == 0 || (batch.len() as u64) < limit {
+                break;
+            }
+        }
+        Ok(report)
+    }
+
+    /// Archives the finished GNAP grants together with their interactions.
+    async fn archive_interactions(&self) -> Outcome<ArchiveReport> {
+        let cutoff = self.archive_cutoff();
+        let limit = self.get_archive_batch_size();
+        let mut report = ArchiveReport::new(ArchiveKind::Interactions);
+        loop {
+            let batch = self.grants().get_archivable(cutoff, limit).await?;
+            if batch.is_empty() {
+                break;
+            }
+            let count = batch.len() as u64;
+            let ids: Vec<String> = batch.iter().map(|m| m.id.clone()).collect();
+            let mut interactions = self.interactions().filter_by_ids(&ids).await?;
+            let records: Vec<ArchivedGrant> = batch
+                .into_iter()
+                .map(|grant| {
+                    let interaction = interactions
+                        .iter()
+                        .position(|i| i.id == grant.id)
+                        .map(|idx| interactions.swap_remove(idx));
+                    ArchivedGrant { grant, interaction }
+                })
+                .collect();
+            let bundle = ArchiveBundle::new(ArchiveKind::Interactions, &records)?;
+            report.objects.push(self.store_bundle(&bundle).await?);
+
+            self.interactions().delete_many(&ids).await?;
+            let pruned = self.grants().delete_many(&ids).await?;
+            report.archived += pruned;
+            if pruned == 0 || count < limit {
+                break;
+            }
+        }
+        Ok(report)
+    }
+
+    /// Compresses and uploads `bundle`, returning its object key.
+    async fn store_bundle(&self, bundle: &ArchiveBundle) -> Outcome<String> {
+        let key = bundle.object_key(self.get_archive_prefix());
+        self.archive().put_object(&key, bundle.compress()?).await?;
+        Ok(key)
+    }
+
+    /// Runs an archival round over every family, one failing family not blocking the others.
+    async fn archive_all(&self) -> Vec<ArchiveReport> {
+        let results = [
+            (
+                ArchiveKind::Verifications,
+                self.archive_verifications().await,
+            ),
+            (ArchiveKind::Issuances, self.archive_issuances().await),
+            (ArchiveKind::Interactions, self.archive_interactions().await),
+        ];
+        let mut reports = Vec::new();
+        for (kind, result) in results {
+            match result {
+                Ok(report) => {
+                    if report.archived > 0 {
+                        info!(
+                            "Archived {} {kind} in {} bundle(s)",
+                            report.archived,
+                            report.objects.len()
+                        );
+                    }
+                    reports.push(report);
+                }
+                Err(e) => warn!("Archival of {kind} failed: {e}"),
+            }
+        }
+        reports
+    }
+}
+
+/// Spawns the periodic archival job, running a first round immediately.
+pub fn spawn_archive_job(module: Arc<dyn ArchiveModuleTrait>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(module.get_archive_interval());
+        loop {
+            ticker.tick().await;
+            if on_standby(module.standby_guard()).await {
+                continue;
+            }
+            module.archive_all().await;
+        }
+    })
+}
@@ -0,0 +1,53 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::data::entities::shared::audit_entry::Model;
+use crate::errors::Outcome;
+use crate::services::repo::traits::shared::AuditEntryRepoTrait;
+use crate::types::audit::AuditFilter;
+use crate::types::pagination::{Page, PageRequest, SortOrder};
+use async_trait::async_trait;
+
+/// Business Orchestration Module reading the audit log for governance reviews.
+///
+/// Entries are written by the audited repositories themselves, on every create, update and
+/// delete, so this module only queries them.
+#[async_trait]
+pub trait AuditModuleTrait: Send + Sync + 'static {
+    /// Returns the repository persisting audit entries.
+    fn audit_entries(&self) -> Arc<dyn AuditEntryRepoTrait>;
+
+    /// Lists one page of the entries matching `filter`, newest first unless sorted otherwise.
+    async fn list_audit_entries(
+        &self,
+        filter: &AuditFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<Model>> {
+        let request = match request.sort {
+            Some(_) => request.clone(),
+            None => request.clone().sorted_by("created_at", SortOrder::Desc),
+        };
+        self.audit_entries().search(filter, &request).await
+    }
+
+    /// Returns every write recorded for one row of `entity`, oldest first.
+    async fn entity_history(&self, entity: &str, entity_id: &str) -> Outcome<Vec<Model>> {
+        self.audit_entries().history(entity, entity_id).await
+    }
+}
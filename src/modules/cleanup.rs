@@ -0,0 +1,246 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::config::traits::CleanupConfigTrait;
+use crate::errors::Outcome;
+use crate::modules::standby::on_standby;
+use crate::services::repo::traits::received::{
+    RecvGrantRepoTrait, RecvInteractionRepoTrait, RecvVerificationRepoTrait,
+};
+use crate::services::repo::traits::sent::{SentGrantRepoTrait, SentInteractionRepoTrait};
+use crate::services::repo::traits::shared::IssuanceRepoTrait;
+use crate::services::standby::StandbyTrait;
+use crate::types::cleanup::{CleanupReport, CleanupTable};
+
+/// Business Orchestration Module pruning protocol state nobody will look at again.
+///
+/// Unlike [`ArchiveModuleTrait`](crate::modules::ArchiveModuleTrait) it keeps no copy: finished
+/// records older than their table's retention are deleted outright, and sessions left
+/// unfinished past their deadline are closed first so that they age out too.
+#[async_trait]
+pub trait CleanupModuleTrait: CleanupConfigTrait + Send + Sync + 'static {
+    /// Returns the repository of the received GNAP grants.
+    fn recv_grants(&self) -> Arc<dyn RecvGrantRepoTrait>;
+
+    /// Returns the repository of the received GNAP interactions.
+    fn recv_interactions(&self) -> Arc<dyn RecvInteractionRepoTrait>;
+
+    /// Returns the repository of the GNAP grants sent to authorities.
+    fn sent_grants(&self) -> Arc<dyn SentGrantRepoTrait>;
+
+    /// Returns the repository of the interactions of those grants.
+    fn sent_interactions(&self) -> Arc<dyn SentInteractionRepoTrait>;
+
+    /// Returns the repository of the issuance sessions.
+    fn issuances(&self) -> Arc<dyn IssuanceRepoTrait>;
+
+    /// Returns the repository of the verification sessions.
+    fn verifications(&self) -> Arc<dyn RecvVerificationRepoTrait>;
+
+    /// Returns the role keeper of an active/standby pair, if the instance takes part in one.
+    ///
+    /// The scheduled job pauses while it reports standby.
+    fn standby_guard(&self) -> Option<Arc<dyn StandbyTrait>> {
+        None
+    }
+
+    /// Records of `table` finished before this instant are due for removal, `None` when the
+    /// table is kept forever.
+    fn cleanup_cutoff(&self, table: CleanupTable) -> Option<DateTime<Utc>> {
+        let retention =
+            chrono::Duration::from_std(self.get_retention(table)?).unwrap_or(chrono::Duration::MAX);
+        Some(
+            Utc::now()
+                .checked_sub_signed(retention)
+                .unwrap_or(DateTime::<Utc>::MIN_UTC),
+        )
+    }
+
+    /// Grants created before this instant and still unfinished are abandoned.
+    fn stale_grant_cutoff(&self) -> DateTime<Utc> {
+        let ttl = chrono::Duration::from_std(self.get_grant_ttl()).unwrap_or(chrono::Duration::MAX);
+        Utc::now()
+            .checked_sub_signed(ttl)
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+    }
+
+    /// Rejects the abandoned received grants, then deletes the finished ones together with
+    /// their interactions.
+    async fn cleanup_recv_grants(&self) -> Outcome<CleanupReport> {
+        let mut report = CleanupReport::new(CleanupTable::RecvGrants);
+        report.expired = self
+            .recv_grants()
+            .expire_stale(self.stale_grant_cutoff())
+            .await?;
+        let Some(cutoff) = self.cleanup_cutoff(CleanupTable::RecvGrants) else {
+            return Ok(report);
+        };
+        let limit = self.get_cleanup_batch_size();
+        report.removed = prune(
+            limit,
+            || async move {
+                let batch = self.recv_grants().get_archivable(cutoff, limit).await?;
+                Ok(batch.into_iter().map(|m| m.id).collect())
+            },
+            |ids| async move {
+                self.recv_interactions().delete_many(&ids).await?;
+                self.recv_grants().delete_many(&ids).await
+            },
+        )
+        .await?;
+        Ok(report)
+    }
+
+    /// Denies the abandoned sent grants, then deletes the finished ones together with their
+    /// interactions.
+    async fn cleanup_sent_grants(&self) -> Outcome<CleanupReport> {
+        let mut report = CleanupReport::new(CleanupTable::SentGrants);
+        report.expired = self
+            .sent_grants()
+            .expire_stale(self.stale_grant_cutoff())
+            .await?;
+        let Some(cutoff) = self.cleanup_cutoff(CleanupTable::SentGrants) else {
+            return Ok(report);
+        };
+        let limit = self.get_cleanup_batch_size();
+        report.removed = prune(
+            limit,
+            || async move {
+                let batch = self.sent_grants().get_archivable(cutoff, limit).await?;
+                Ok(batch.into_iter().map(|m| m.id).collect())
+            },
+            |ids| async move {
+                self.sent_interactions().delete_many(&ids).await?;
+                self.sent_grants().delete_many(&ids).await
+            },
+        )
+        .await?;
+        Ok(report)
+    }
+
+    /// Deletes the issuance sessions that are over.
+    async fn cleanup_issuances(&self) -> Outcome<CleanupReport> {
+        let mut report = CleanupReport::new(CleanupTable::Issuances);
+        let Some(cutoff) = self.cleanup_cutoff(CleanupTable::Issuances) else {
+            return Ok(report);
+        };
+        let limit = self.get_cleanup_batch_size();
+        report.removed = prune(
+            limit,
+            || async move {
+                let batch = self.issuances().get_archivable(cutoff, limit).await?;
+                Ok(batch.into_iter().map(|m| m.id).collect())
+            },
+            |ids| async move { self.issuances().delete_many(&ids).await },
+        )
+        .await?;
+        Ok(report)
+    }
+
+    /// Expires the verification sessions past their TTL, then deletes the finished ones.
+    async fn cleanup_verifications(&self) -> Outcome<CleanupReport> {
+        let mut report = CleanupReport::new(CleanupTable::Verifications);
+        report.expired = self.verifications().expire_stale(Utc::now()).await?;
+        let Some(cutoff) = self.cleanup_cutoff(CleanupTable::Verifications) else {
+            return Ok(report);
+        };
+        let limit = self.get_cleanup_batch_size();
+        report.removed = prune(
+            limit,
+            || async move {
+                let batch = self.verifications().get_archivable(cutoff, limit).await?;
+                Ok(batch.into_iter().map(|m| m.id).collect())
+            },
+            |ids| async move { self.verifications().delete_many(&ids).await },
+        )
+        .await?;
+        Ok(report)
+    }
+
+    /// Runs a cleanup round over every table, one failing table not blocking the others.
+    async fn cleanup_all(&self) -> Vec<CleanupReport> {
+        let mut reports = Vec::new();
+        for table in CleanupTable::ALL {
+            let result = match table {
+                CleanupTable::RecvGrants => self.cleanup_recv_grants().await,
+                CleanupTable::SentGrants => self.cleanup_sent_grants().await,
+                CleanupTable::Issuances => self.cleanup_issuances().await,
+                CleanupTable::Verifications => self.cleanup_verifications().await,
+            };
+            match result {
+                Ok(report) => {
+                    if report.expired > 0 || report.removed > 0 {
+                        info!(
+                            "Cleaned up {table}: {} expired, {} removed",
+                            report.expired, report.removed
+                        );
+                    }
+                    reports.push(report);
+                }
+                Err(e) => warn!("Cleanup of {table} failed: {e}"),
+            }
+        }
+        reports
+    }
+}
+
+/// Spawns the periodic cleanup job, running a first round immediately.
+pub fn spawn_cleanup_job(module: Arc<dyn CleanupModuleTrait>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(module.get_cleanup_interval());
+        loop {
+            ticker.tick().await;
+            if on_standby(module.standby_guard()).await {
+                continue;
+            }
+            module.cleanup_all().await;
+        }
+    })
+}
+
+/// Deletes the batches returned by `fetch` until a short or unproductive one, returning how
+/// many records were removed.
+async fn prune<F, FetchFut, D, DeleteFut>(limit: u64, fetch: F, delete: D) -> Outcome<u64>
+where
+    F: Fn() -> FetchFut,
+    FetchFut: Future<Output = Outcome<Vec<String>>>,
+    D: Fn(Vec<String>) -> DeleteFut,
+    DeleteFut: Future<Output = Outcome<u64>>,
+{
+    let mut removed = 0;
+    loop {
+        let ids = fetch().await?;
+        if ids.is_empty() {
+            break;
+        }
+        let count = ids.len() as u64;
+        let pruned = delete(ids).await?;
+        removed += pruned;
+        if pruned == 0 || count < limit {
+            break;
+        }
+    }
+    Ok(removed)
+}
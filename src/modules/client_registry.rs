@@ -0,0 +1,108 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::data::entities::shared::participant;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::traits::shared::ParticipantRepoTrait;
+use crate::types::gnap::grant_request::client::{ClientInstance, ClientKey};
+use crate::types::keys::DbKeySource;
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use tracing::info;
+use uuid::Uuid;
+
+/// Business Orchestration Module keeping the GNAP client instances pre-registered by mates.
+///
+/// A registered mate is given an `instance_id` to send as the `client` of its grant requests
+/// instead of its whole key (RFC 9635 §2.3.1); its requests are still checked against the key
+/// it registered.
+#[async_trait]
+pub trait ClientRegistryModuleTrait: Send + Sync + 'static {
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Registers mate `id` as a client instance proving possession of `key`.
+    ///
+    /// Every registration assigns a fresh instance id, retiring the previous one.
+    ///
+    /// # Errors
+    /// Returns a not implemented error when `key` is not proofed with `httpsig`.
+    async fn register_client(&self, id: &str, key: &ClientKey) -> Outcome<participant::Model> {
+        let key = key.to_db()?;
+        let mut mate = self.participants().get_by_id(id).await?;
+        mate.instance_id = Some(Uuid::new_v4().to_string());
+        mate.client_key = Some(key);
+        let mate = self.participants().update(mate).await?;
+        info!("Participant {id} registered as a client instance");
+        Ok(mate)
+    }
+
+    /// Drops the client instance registration of mate `id`.
+    async fn unregister_client(&self, id: &str) -> Outcome<participant::Model> {
+        let mut mate = self.participants().get_by_id(id).await?;
+        mate.instance_id = None;
+        mate.client_key = None;
+        let mate = self.participants().update(mate).await?;
+        info!("Client instance registration of participant {id} dropped");
+        Ok(mate)
+    }
+
+    /// Resolves the key of the `client` of a grant request and checks the request was signed
+    /// with it.
+    ///
+    /// Returns the key along with the registered mate when `client` is an instance reference.
+    ///
+    /// # Errors
+    /// Returns an unauthorized error when the instance id is unknown, a not implemented error
+    /// when a key sent in full is not proofed with `httpsig`, and a security error when the
+    /// request proof does not hold.
+    async fn resolve_client(
+        &self,
+        client: &ClientInstance,
+        headers: &HeaderMap,
+        method: &str,
+        url: &str,
+        body: &[u8],
+    ) -> Outcome<(DbKeySource, Option<participant::Model>)> {
+        let (key, mate) = match client {
+            ClientInstance::Full(client) => (client.key.to_db()?, None),
+            ClientInstance::Reference(instance_id) => {
+                let mate = match self.participants().get_by_instance_id(instance_id).await {
+                    Ok(mate) => mate,
+                    Err(Errors::MissingResourceError { .. }) => {
+                        return Err(Errors::unauthorized(
+                            format!("Client instance {instance_id} is not registered"),
+                            None,
+                        ));
+                    }
+                    Err(e) => return Err(e),
+                };
+                let Some(key) = mate.client_key.clone() else {
+                    return Err(Errors::unauthorized(
+                        format!("Client instance {instance_id} has no registered key"),
+                        None,
+                    ));
+                };
+                (key, Some(mate))
+            }
+        };
+        key.verify_request(headers, method, url, body)?;
+        Ok((key, mate))
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::errors::Outcome;
+use crate::types::config_snapshot::ConfigSnapshot;
+use serde_json::Value;
+use tracing::info;
+
+/// Business Orchestration Module exporting the effective agent configuration.
+///
+/// Implementors only provide the serialized configuration (every config type derives
+/// `Serialize`); redaction, vault references and fingerprinting are shared. A CLI command
+/// can print [`export_config`](Self::export_config) the same way the HTTP endpoint serves it.
+pub trait ConfigExportModuleTrait: Send + Sync + 'static {
+    /// Returns the fully resolved configuration the agent is running with.
+    fn effective_config(&self) -> Outcome<Value>;
+
+    /// Builds a diffable snapshot of the effective configuration with secrets replaced by
+    /// vault references.
+    fn export_config(&self) -> Outcome<ConfigSnapshot> {
+        let snapshot = ConfigSnapshot::new(self.effective_config()?);
+        info!("Exported config snapshot {}", snapshot.fingerprint);
+        Ok(snapshot)
+    }
+}
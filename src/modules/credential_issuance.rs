@@ -0,0 +1,134 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::config::traits::TemplatesConfigTrait;
+use crate::data::entities::shared::participant;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::traits::shared::{
+    CredTemplateRepoTrait, IssuanceRepoTrait, ParticipantRepoTrait,
+};
+use crate::services::{HasClaimEnricher, HasIssuer};
+use crate::types::issuance::{CredentialRequest, GiveVC, VcBody};
+use crate::types::vcs::vc_specs::template::{CredentialTemplate, TemplateVars};
+use crate::types::vcs::{VcFormat, VcType, W3cDataModelVersion};
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// Business Orchestration Module backing the OpenID4VCI Credential Endpoint.
+///
+/// Turns an accepted credential request into a signed credential. The subject is rendered
+/// from the template of the credential type, or taken from the claims gathered while the
+/// issuance session was planned, and enriched from the configured claim sources when the
+/// holder is a known participant.
+#[async_trait]
+pub trait CredentialIssuanceModuleTrait:
+    HasIssuer + HasClaimEnricher + TemplatesConfigTrait + Send + Sync + 'static
+{
+    /// Returns the repository persisting issuance sessions.
+    fn issuances(&self) -> Arc<dyn IssuanceRepoTrait>;
+
+    /// Returns the repository holding the runtime-managed credential subject templates.
+    fn cred_templates(&self) -> Arc<dyn CredTemplateRepoTrait>;
+
+    /// Returns the repository holding the participants credentials are issued to.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Issues the credential requested with the access token `token`.
+    ///
+    /// The signed credential is stored on the issuance session before it is returned, along
+    /// with the `notification_id` the wallet reports its lifecycle events under.
+    ///
+    /// # Errors
+    /// Fails when the request does not validate against the session, when the requested
+    /// format cannot be issued, when the template uses an undefined variable or when a claim
+    /// source marked `fail` cannot be read.
+    async fn issue(&self, cred_req: CredentialRequest, token: &str) -> Outcome<GiveVC> {
+        let mut issuance = self.issuances().get_by_token(token).await?;
+        let (holder_did, vc_config) = self
+            .issuer()
+            .validate_cred_req(&mut issuance, cred_req, token)
+            .await?;
+        // The proof nonce is spent even if the credential cannot be built afterwards.
+        let mut issuance = self.issuances().update(issuance).await?;
+        if *vc_config.format() != VcFormat::JwtVcJson {
+            return Err(Errors::not_impl(
+                format!(
+                    "Issuing '{}' credentials is not supported",
+                    vc_config.format()
+                ),
+                None,
+            ));
+        }
+
+        let participant = self.holder(&holder_did).await?;
+        let mut subject = match self.template(vc_config.vc_type()).await? {
+            Some(template) => {
+                let mut vars = TemplateVars::new(&holder_did).build_ctx(&issuance.build_ctx);
+                if let Some(participant) = &participant {
+                    vars = vars.participant(participant);
+                }
+                template.render(&vars)?
+            }
+            None => match &issuance.build_ctx.claims {
+                Value::Object(claims) => Value::Object(claims.clone()),
+                _ => Value::Object(Default::default()),
+            },
+        };
+        if let Some(participant) = &participant {
+            self.claim_enricher()
+                .enrich(vc_config.vc_type(), participant, &mut subject)
+                .await?;
+        }
+        let Value::Object(claims) = subject else {
+            return Err(Errors::crazy("Credential subject is not an object", None));
+        };
+        let claims = self
+            .issuer()
+            .credential_builder(vc_config.vc_type(), W3cDataModelVersion::default())
+            .await
+            .id(&issuance.credential_id)
+            .subject(holder_did, claims)
+            .build()?;
+        let signed = self.issuer().sign_claims(&claims).await?;
+
+        issuance.credential = Some(signed.clone());
+        let issuance = self.issuances().update(issuance).await?;
+        Ok(GiveVC::synchronous(vec![VcBody::jwt(signed)])
+            .with_notification(issuance.notification_id))
+    }
+
+    /// Resolves the subject template of `vc_type`, stored ones taking precedence over the
+    /// configured ones.
+    async fn template(&self, vc_type: &VcType) -> Outcome<Option<CredentialTemplate>> {
+        match self.cred_templates().get_by_vc_type(vc_type).await {
+            Ok(model) => Ok(Some(model.to_template())),
+            Err(Errors::MissingResourceError { .. }) => Ok(self.get_template(vc_type).cloned()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Looks up the participant registered under `holder_did`, if any.
+    async fn holder(&self, holder_did: &str) -> Outcome<Option<participant::Model>> {
+        match self.participants().get_by_id(holder_did).await {
+            Ok(participant) => Ok(Some(participant)),
+            Err(Errors::MissingResourceError { .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
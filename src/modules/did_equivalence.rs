@@ -0,0 +1,81 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::data::entities::shared::participant;
+use crate::errors::{Errors, Outcome};
+use crate::services::HasDidEquivalence;
+use crate::services::repo::traits::shared::ParticipantRepoTrait;
+use crate::types::dids::DidEquivalenceProof;
+use async_trait::async_trait;
+
+/// Business Orchestration Module for dual-stack identities.
+///
+/// Publishes the signed cross-references binding the agent's DIDs, checks the ones published
+/// by peers, and resolves the agent's own participant record under any of its identifiers so
+/// existing records keep working while peers migrate from one DID method to another.
+#[async_trait]
+pub trait DidEquivalenceModuleTrait: HasDidEquivalence + Send + Sync + 'static {
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Returns the agent's DIDs together with the statements binding them.
+    async fn get_equivalence(&self) -> Outcome<DidEquivalenceProof> {
+        self.did_equivalence().build_proof().await
+    }
+
+    /// Checks a proof published by a peer and returns the aliases it establishes.
+    ///
+    /// Every alias must be bound in both directions: `did` vouching for the alias and the
+    /// alias vouching for `did`. A `did:jwk` alias must also encode a key of `did`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::SecurityError`] when a statement is missing for any alias, or a
+    /// `did:jwk` alias holds a key `did` does not publish.
+    async fn verify_equivalence(&self, proof: &DidEquivalenceProof) -> Outcome<Vec<String>> {
+        let mut bindings = HashSet::new();
+        for token in &proof.statements {
+            let statement = self.did_equivalence().verify_statement(token).await?;
+            bindings.insert((statement.iss, statement.sub));
+        }
+
+        for alias in &proof.also_known_as {
+            let forward = bindings.contains(&(proof.did.clone(), alias.clone()));
+            let backward = bindings.contains(&(alias.clone(), proof.did.clone()));
+            if !(forward && backward) {
+                return Err(Errors::security(
+                    format!("{alias} is not cross-referenced with {}", proof.did),
+                    None,
+                ));
+            }
+            self.did_equivalence()
+                .verify_alias_key(&proof.did, alias)
+                .await?;
+        }
+        Ok(proof.also_known_as.clone())
+    }
+
+    /// Returns the participant named `id`, accepting any of the agent's own DIDs for "me".
+    async fn get_participant(&self, id: &str) -> Outcome<participant::Model> {
+        if self.did_equivalence().is_self(id).await {
+            return self.participants().get_me().await;
+        }
+        self.participants().get_by_id(id).await
+    }
+}
@@ -0,0 +1,174 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use crate::data::entities::received::{evidence_bundle, verification};
+use crate::errors::{Errors, Outcome};
+use crate::services::HasEvidence;
+use crate::services::repo::traits::received::{
+    RecvEvidenceBundleRepoTrait, RecvPresentationDefinitionRepoTrait,
+    RecvVerificationAuditRepoTrait, RecvVerificationRepoTrait,
+};
+use crate::types::verification::{
+    EvidenceBundle, EvidenceDecision, EvidencePolicy, vp_token_digest,
+};
+use crate::utils::Clock;
+use async_trait::async_trait;
+use tracing::info;
+
+/// Business Orchestration Module packaging verification evidence for disputes.
+///
+/// Once a verification reaches a final state, its audit trail, the DID documents of the holder
+/// and issuers, the status lists of the presented credentials and the policy profile are
+/// assembled into a bundle signed by the verifier. Each verification gets a single bundle,
+/// which is never rewritten afterwards.
+#[async_trait]
+pub trait EvidenceModuleTrait: HasEvidence + Send + Sync + 'static {
+    /// Returns the repository persisting received verifications.
+    fn verifications(&self) -> Arc<dyn RecvVerificationRepoTrait>;
+
+    /// Returns the repository keeping the evidence of every wallet response.
+    fn audits(&self) -> Arc<dyn RecvVerificationAuditRepoTrait>;
+
+    /// Returns the repository of the presentation definitions served by reference.
+    fn definitions(&self) -> Arc<dyn RecvPresentationDefinitionRepoTrait>;
+
+    /// Returns the repository of sealed evidence bundles.
+    fn bundles(&self) -> Arc<dyn RecvEvidenceBundleRepoTrait>;
+
+    /// Returns the bundle of `verification_id`, packaging and sealing it on first call.
+    ///
+    /// Packaging right when the verification finishes captures the DIDs and status lists as
+    /// the verifier saw them; a later call captures them as they stand then.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] while the verification is still in progress.
+    async fn package_evidence(&self, verification_id: &str) -> Outcome<evidence_bundle::Model> {
+        if let Some(sealed) = self.bundles().get_by_verification(verification_id).await? {
+            return Ok(sealed);
+        }
+        let model = self.verifications().get_by_id(verification_id).await?;
+        if !model.status.is_terminal() {
+            return Err(Errors::forbidden(
+                "Evidence can only be packaged once the verification has finished",
+                None,
+            ));
+        }
+
+        info!("Packaging evidence for verification {verification_id}");
+        let bundle = self.assemble_bundle(&model).await?;
+        let jwt = self.evidence().sign_bundle(&bundle).await?;
+        let plan = evidence_bundle::Plan { bundle, jwt };
+        match self.bundles().seal(plan).await {
+            Ok(sealed) => Ok(sealed),
+            // A concurrent call sealed it first; the stored bundle wins.
+            Err(e) => match self.bundles().get_by_verification(verification_id).await? {
+                Some(sealed) => Ok(sealed),
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Returns the bundle previously sealed for `verification_id`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::MissingResourceError`] when no bundle has been sealed yet.
+    async fn get_evidence(&self, verification_id: &str) -> Outcome<evidence_bundle::Model> {
+        self.bundles()
+            .get_by_verification(verification_id)
+            .await?
+            .ok_or_else(|| {
+                Errors::missing_resource(
+                    verification_id,
+                    "No evidence sealed for this verification",
+                    None,
+                )
+            })
+    }
+
+    /// Checks a bundle JWT, e.g. one handed to an arbitrator, and returns its payload.
+    async fn verify_evidence(&self, token: &str) -> Outcome<EvidenceBundle> {
+        self.evidence().verify_bundle(token).await
+    }
+
+    /// Collects the inputs of the decision on `model` into an unsigned bundle.
+    async fn assemble_bundle(&self, model: &verification::Model) -> Outcome<EvidenceBundle> {
+        let audits = self.audits().get_by_verification(&model.id).await?;
+
+        let presentation_definition = match &model.pd_id {
+            Some(id) => self.definitions().find(id).await?.map(|pd| pd.definition),
+            None => None,
+        };
+
+        let mut dids = BTreeSet::new();
+        dids.extend(model.holder.clone());
+        let mut status_lists = BTreeSet::new();
+        for claim in audits.iter().flat_map(|audit| &audit.claims) {
+            if !claim.issuer.is_empty() {
+                dids.insert(claim.issuer.clone());
+            }
+            let url = claim
+                .credential_status
+                .as_ref()
+                .and_then(|status| status.status_list_credential.clone());
+            status_lists.extend(url);
+        }
+
+        let mut did_documents = Vec::new();
+        for did in &dids {
+            did_documents.push(self.evidence().snapshot_did(did).await);
+        }
+        let mut status_list_snapshots = Vec::new();
+        for url in &status_lists {
+            status_list_snapshots.push(self.evidence().snapshot_status_list(url).await);
+        }
+
+        let decisions = audits
+            .into_iter()
+            .map(|audit| EvidenceDecision {
+                recorded_at: audit.recorded_at.timestamp(),
+                vp_token_digest: audit.vp_token.as_deref().map(vp_token_digest),
+                presentation_submission: audit.presentation_submission,
+                checks: audit.checks,
+                outcome: audit.outcome,
+                failure_reason: audit.failure_reason,
+            })
+            .collect();
+
+        Ok(EvidenceBundle {
+            iss: self.evidence().issuer().await,
+            jti: uuid::Uuid::new_v4().to_string(),
+            iat: Clock::issued_now().timestamp(),
+            verification_id: model.id.clone(),
+            holder: model.holder.clone(),
+            status: model.status.clone(),
+            ended_at: model.ended_at.map(|t| t.timestamp()),
+            policy: EvidencePolicy {
+                vc_types: model.vc_type.clone(),
+                purpose: model.purpose.clone(),
+                presentation_definition_id: model.pd_id.clone(),
+                presentation_definition,
+                redaction_profile: model.redaction_profile.clone(),
+            },
+            decisions,
+            did_documents,
+            status_lists: status_list_snapshots,
+        })
+    }
+}
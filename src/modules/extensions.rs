@@ -0,0 +1,146 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::data::entities::shared::{issuance, participant};
+use crate::data::entities::{received, sent};
+use crate::errors::Outcome;
+use crate::services::repo::traits::received::RecvGrantRepoTrait;
+use crate::services::repo::traits::sent::SentGrantRepoTrait;
+use crate::services::repo::traits::shared::{IssuanceRepoTrait, ParticipantRepoTrait};
+use crate::types::extensions::{ExtensionTarget, merge_extensions, validate_extensions};
+use async_trait::async_trait;
+use serde_json::Value;
+use tracing::info;
+
+/// Business Orchestration Module for integrator-defined `extensions` data.
+///
+/// Lets deployments attach their own key-value data (ERP ids, cost centers, ...) to
+/// participants, issuance sessions and the credential requests sent or received as grants,
+/// without adding columns. Every write is checked
+/// against the limits of [`crate::types::extensions`].
+#[async_trait]
+pub trait ExtensionsModuleTrait: Send + Sync + 'static {
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Returns the repository persisting issuance sessions.
+    fn issuances(&self) -> Arc<dyn IssuanceRepoTrait>;
+
+    /// Returns the repository persisting grants requested from participants.
+    fn sent_grants(&self) -> Arc<dyn SentGrantRepoTrait>;
+
+    /// Returns the repository persisting grants received from participants.
+    fn recv_grants(&self) -> Arc<dyn RecvGrantRepoTrait>;
+
+    /// Retrieves the `extensions` object of a record.
+    async fn get_extensions(&self, target: ExtensionTarget, id: &str) -> Outcome<Value> {
+        match target {
+            ExtensionTarget::Participant => Ok(self.participants().get_by_id(id).await?.extensions),
+            ExtensionTarget::Issuance => Ok(self.issuances().get_by_id(id).await?.extensions),
+            ExtensionTarget::SentGrant => Ok(self.sent_grants().get_by_id(id).await?.extensions),
+            ExtensionTarget::ReceivedGrant => {
+                Ok(self.recv_grants().get_by_id(id).await?.extensions)
+            }
+        }
+    }
+
+    /// Replaces the `extensions` object of a record.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`](crate::errors::Errors::FormatError) when `extensions`
+    /// is not an object or exceeds the size limits.
+    async fn set_extensions(
+        &self,
+        target: ExtensionTarget,
+        id: &str,
+        extensions: Value,
+    ) -> Outcome<Value> {
+        validate_extensions(&extensions)?;
+        info!("Updating extensions of {target} {id}");
+        match target {
+            ExtensionTarget::Participant => Ok(self
+                .participants()
+                .update_extensions(id, extensions)
+                .await?
+                .extensions),
+            ExtensionTarget::Issuance => Ok(self
+                .issuances()
+                .update_extensions(id, extensions)
+                .await?
+                .extensions),
+            ExtensionTarget::SentGrant => Ok(self
+                .sent_grants()
+                .update_extensions(id, extensions)
+                .await?
+                .extensions),
+            ExtensionTarget::ReceivedGrant => Ok(self
+                .recv_grants()
+                .update_extensions(id, extensions)
+                .await?
+                .extensions),
+        }
+    }
+
+    /// Applies a JSON merge patch to the `extensions` object of a record.
+    ///
+    /// Keys set to `null` in the patch are removed; the limits apply to the merged result.
+    async fn patch_extensions(
+        &self,
+        target: ExtensionTarget,
+        id: &str,
+        patch: Value,
+    ) -> Outcome<Value> {
+        let mut extensions = self.get_extensions(target, id).await?;
+        merge_extensions(&mut extensions, patch);
+        self.set_extensions(target, id, extensions).await
+    }
+
+    /// Lists participants whose `extensions` contain every key/value pair of `filter`.
+    async fn find_participants_by_extensions(
+        &self,
+        filter: Value,
+    ) -> Outcome<Vec<participant::Model>> {
+        validate_extensions(&filter)?;
+        self.participants().filter_by_extensions(&filter).await
+    }
+
+    /// Lists issuance sessions whose `extensions` contain every key/value pair of `filter`.
+    async fn find_issuances_by_extensions(&self, filter: Value) -> Outcome<Vec<issuance::Model>> {
+        validate_extensions(&filter)?;
+        self.issuances().filter_by_extensions(&filter).await
+    }
+
+    /// Lists sent grants whose `extensions` contain every key/value pair of `filter`.
+    async fn find_sent_grants_by_extensions(
+        &self,
+        filter: Value,
+    ) -> Outcome<Vec<sent::grant::Model>> {
+        validate_extensions(&filter)?;
+        self.sent_grants().filter_by_extensions(&filter).await
+    }
+
+    /// Lists received grants whose `extensions` contain every key/value pair of `filter`.
+    async fn find_received_grants_by_extensions(
+        &self,
+        filter: Value,
+    ) -> Outcome<Vec<received::grant::Model>> {
+        validate_extensions(&filter)?;
+        self.recv_grants().filter_by_extensions(&filter).await
+    }
+}
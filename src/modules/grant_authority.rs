@@ -0,0 +1,405 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::config::traits::AccessPolicyConfigTrait;
+use crate::data::entities::received::{access_token, interaction};
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::modules::AccessPolicyModuleTrait;
+use crate::services::repo::traits::received::RecvInteractionRepoTrait;
+use crate::types::gnap::GrantStatus;
+use crate::types::gnap::access_token::{AccessToken, AccessTokens};
+use crate::types::gnap::grant_request::access::{AccessTokenRequest, AccessTokenRequests};
+use crate::utils::{Clock, create_opaque_token};
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use tracing::info;
+
+/// Business Orchestration Module serving the authority side of received GNAP grants once
+/// the interaction is over.
+///
+/// Issued access tokens are bound to the key the client requested the grant with, unless it
+/// asked for a bearer token, and every continuation call must be signed with that key. The
+/// consumer's grant and continuation handlers build their responses from what it returns.
+#[async_trait]
+pub trait GrantAuthorityModuleTrait: AccessPolicyModuleTrait + Send + Sync + 'static {
+    /// Returns the repository persisting the interactions of received grants.
+    fn recv_interactions(&self) -> Arc<dyn RecvInteractionRepoTrait>;
+
+    /// Returns the token management URI handed out with issued access tokens, so clients can
+    /// rotate and revoke them through [`GrantRevocationRouter`](crate::http::GrantRevocationRouter).
+    ///
+    /// Tokens are issued without a management URI if `None`.
+    fn token_manage_uri(&self) -> Option<String> {
+        None
+    }
+
+    /// Checks a `POST` to the continuation URI of the grant continued at `cont_id`.
+    ///
+    /// The call must present the current continue `token` and be signed with the client key.
+    /// The token is then rotated, and the returned interaction carries the new one for the
+    /// continuation response.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when `token` is not the current continue token,
+    /// a rate limit error when the client polls before `continue_wait` elapsed, and a security
+    /// error when the request proof does not hold.
+    async fn continue_grant(
+        &self,
+        cont_id: &str,
+        token: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Outcome<interaction::Model> {
+        let interaction = self.recv_interactions().get_by_cont_id(cont_id).await?;
+        if interaction.continue_token != token {
+            return Err(Errors::forbidden(
+                format!("Continue token for {cont_id} is not valid"),
+                None,
+            ));
+        }
+        interaction.check_proof(headers, "POST", body)?;
+        self.recv_interactions()
+            .rotate_continue_token(cont_id, token)
+            .await
+    }
+
+    /// Issues the access tokens requested by grant `id`, approved for the mate `participant_id`.
+    ///
+    /// A single token request is answered with [`issue_access_token`](Self::issue_access_token);
+    /// each token of a multiple token request is issued with
+    /// [`issue_labeled_token`](Self::issue_labeled_token), in request order.
+    ///
+    /// # Errors
+    /// Returns a format error when a token of a multiple token request is unlabeled or a label
+    /// is repeated.
+    async fn issue_access_tokens(
+        &self,
+        id: &str,
+        participant_id: &str,
+        requests: &AccessTokenRequests,
+    ) -> Outcome<AccessTokens> {
+        requests.check_labels()?;
+        let requests = match requests {
+            AccessTokenRequests::Single(request) => {
+                return Ok(AccessTokens::Single(
+                    self.issue_access_token(id, participant_id, request).await?,
+                ));
+            }
+            AccessTokenRequests::Multiple(requests) => requests,
+        };
+
+        let mut tokens = Vec::with_capacity(requests.len());
+        for request in requests {
+            tokens.push(
+                self.issue_labeled_token(id, participant_id, request)
+                    .await?,
+            );
+        }
+        let mut grant = self.recv_grants().get_by_id(id).await?;
+        grant.status = GrantStatus::Approved;
+        self.recv_grants().update(grant).await?;
+        info!(
+            "{} labeled access tokens issued for grant {id}",
+            tokens.len()
+        );
+        Ok(AccessTokens::Multiple(tokens))
+    }
+
+    /// Issues the access token of grant `id`, approved for the mate `participant_id`.
+    ///
+    /// The access is narrowed by [`grant_access`](AccessPolicyModuleTrait::grant_access) and
+    /// the token is bound to the key the grant was requested with, so
+    /// [`authorize`](AccessPolicyModuleTrait::authorize) asks for its `httpsig` proof. It
+    /// expires after [`get_access_token_ttl`](AccessPolicyConfigTrait::get_access_token_ttl) and
+    /// can be managed at [`token_manage_uri`](Self::token_manage_uri) when those are set.
+    async fn issue_access_token(
+        &self,
+        id: &str,
+        participant_id: &str,
+        request: &AccessTokenRequest,
+    ) -> Outcome<AccessToken> {
+        let interaction = self.recv_interactions().get_by_id(id).await?;
+        let mut grant = self.recv_grants().get_by_id(id).await?;
+        let mut mate = self.participants().get_by_id(participant_id).await?;
+        mate.check_standing()?;
+
+        let granted = self.grant_access(id, request).await?;
+        let mut token = AccessToken::new(create_opaque_token(), granted);
+        if let Some(ttl) = self.get_access_token_ttl() {
+            token = token.with_expiry(ttl);
+        }
+        if let Some(uri) = self.token_manage_uri() {
+            let value = token.value.clone();
+            token = token.with_manage(uri, value);
+        }
+        mate.bind_token(&token, &interaction.key_source, Clock::now());
+        self.participants().update(mate).await?;
+
+        grant.token = Some(token.value.clone());
+        grant.status = GrantStatus::Approved;
+        self.recv_grants().update(grant).await?;
+        info!("Access token issued for grant {id}");
+        Ok(token)
+    }
+
+    /// Issues the token labeled in `request` for grant `id`, approved for the mate
+    /// `participant_id`.
+    ///
+    /// The token gets its own row, and its access is stored under the
+    /// [`access_id`](access_token::access_id) of the label, so every label of the grant is
+    /// authorized on its own. Access, key binding and expiry follow
+    /// [`issue_access_token`](Self::issue_access_token), capped by the mate membership; no
+    /// management URI is handed out, the tokens end with the grant.
+    async fn issue_labeled_token(
+        &self,
+        id: &str,
+        participant_id: &str,
+        request: &AccessTokenRequest,
+    ) -> Outcome<AccessToken> {
+        let label = request.label.clone().ok_or_else(|| {
+            Errors::format(
+                BadFormat::Received,
+                "Every access token of a multiple token request needs a label",
+                None,
+            )
+        })?;
+        let interaction = self.recv_interactions().get_by_id(id).await?;
+        let mate = self.participants().get_by_id(participant_id).await?;
+        mate.check_standing()?;
+
+        let granted = self
+            .grant_access(&access_token::access_id(id, &label), request)
+            .await?;
+        let mut token = AccessToken::new(create_opaque_token(), granted);
+        if let Some(ttl) = self.get_access_token_ttl() {
+            token = token.with_expiry(ttl);
+        }
+        self.recv_access_tokens()
+            .create(access_token::Plan {
+                value: token.value.clone(),
+                grant_id: id.to_string(),
+                participant_id: participant_id.to_string(),
+                label,
+                token_key: (!token.is_bearer()).then(|| interaction.key_source.clone()),
+                expires_at: mate.cap_token_expiry(token.expires_at(Clock::now())),
+            })
+            .await?;
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::http::HeaderMap;
+    use serde_json::json;
+
+    use super::GrantAuthorityModuleTrait;
+    use crate::config::traits::AccessPolicyConfigTrait;
+    use crate::config::types::AccessPolicyConfig;
+    use crate::data::entities::received::{grant, interaction};
+    use crate::data::entities::shared::participant;
+    use crate::modules::AccessPolicyModuleTrait;
+    use crate::services::repo::Repos;
+    use crate::services::repo::traits::received::{
+        RecvAccessTokenRepoTrait, RecvGrantRepoTrait, RecvInteractionRepoTrait,
+    };
+    use crate::services::repo::traits::shared::{ParticipantRepoTrait, ResourceReqRepoTrait};
+    use crate::types::gnap::access_token::AccessTokens;
+    use crate::types::gnap::grant_request::GrantKind;
+    use crate::types::gnap::grant_request::access::{AccessTokenFlag, AccessTokenRequests};
+    use crate::types::gnap::grant_request::interact::{
+        FinishMethod, InteractAction, InteractStart,
+    };
+    use crate::types::keys::DbKeySource;
+    use crate::types::participants::{ParticipantStatus, ParticipantType};
+
+    struct Authority {
+        repos: Repos,
+        config: AccessPolicyConfig,
+    }
+
+    impl AccessPolicyConfigTrait for Authority {
+        fn access_policy_config(&self) -> &AccessPolicyConfig {
+            &self.config
+        }
+    }
+
+    impl AccessPolicyModuleTrait for Authority {
+        fn resource_reqs(&self) -> Arc<dyn ResourceReqRepoTrait> {
+            self.repos.resource_reqs.clone()
+        }
+
+        fn recv_grants(&self) -> Arc<dyn RecvGrantRepoTrait> {
+            self.repos.recv_grants.clone()
+        }
+
+        fn recv_access_tokens(&self) -> Arc<dyn RecvAccessTokenRepoTrait> {
+            self.repos.recv_access_tokens.clone()
+        }
+
+        fn participants(&self) -> Arc<dyn ParticipantRepoTrait> {
+            self.repos.participants.clone()
+        }
+    }
+
+    impl GrantAuthorityModuleTrait for Authority {
+        fn recv_interactions(&self) -> Arc<dyn RecvInteractionRepoTrait> {
+            self.repos.recv_interactions.clone()
+        }
+    }
+
+    async fn authority_with_grant(id: &str) -> Authority {
+        let authority = Authority {
+            repos: Repos::memory(),
+            config: AccessPolicyConfig {
+                policies: vec![],
+                token_ttl_secs: Some(3600),
+                allow_bearer_tokens: true,
+            },
+        };
+        authority
+            .participants()
+            .create(participant::Plan {
+                participant_id: "did:web:mate".to_string(),
+                participant_nick: "mate".to_string(),
+                participant_type: ParticipantType::Agent,
+                base_url: "https://mate.example".to_string(),
+                token: None,
+                token_expires_at: None,
+                token_key: None,
+                extra_fields: None,
+                is_me: false,
+                extensions: None,
+                status: Some(ParticipantStatus::Active),
+                expires_at: None,
+            })
+            .await
+            .unwrap();
+        authority
+            .recv_grants()
+            .create(grant::Plan {
+                id: id.to_string(),
+                participant_nick: "mate".to_string(),
+                vc_type_config: None,
+                kind: GrantKind::AccessToken,
+            })
+            .await
+            .unwrap();
+        authority
+            .recv_interactions()
+            .create(interaction::Plan {
+                id: id.to_string(),
+                start: vec![InteractStart::Oid4VP],
+                method: FinishMethod::Redirect,
+                callback_uri: "https://mate.example/callback".to_string(),
+                key_source: DbKeySource::PublicKey(json!({ "kty": "OKP" })),
+                client_nonce: "nonce".to_string(),
+                finish: interaction::FinishHash::draw(None, "nonce", "https://me.example/grant")
+                    .unwrap(),
+                hints: None,
+                continue_endpoint: "https://me.example/continue".to_string(),
+                continue_token: "continue".to_string(),
+                continue_wait: None,
+            })
+            .await
+            .unwrap();
+        authority
+    }
+
+    #[tokio::test]
+    async fn labeled_tokens_are_issued_and_authorized_per_label() {
+        let authority = authority_with_grant("grant-1").await;
+        let AccessTokenRequests::Multiple(mut requests) = AccessTokenRequests::labeled(vec![
+            ("talk".to_string(), vec![InteractAction::Talk]),
+            ("vc-exchange".to_string(), vec![InteractAction::RequestVc]),
+        ]) else {
+            unreachable!()
+        };
+        for request in &mut requests {
+            request.flags = Some(vec![AccessTokenFlag::Bearer]);
+        }
+
+        let issued = authority
+            .issue_access_tokens(
+                "grant-1",
+                "did:web:mate",
+                &AccessTokenRequests::Multiple(requests),
+            )
+            .await
+            .unwrap();
+        let AccessTokens::Multiple(tokens) = issued else {
+            panic!("a multiple token request must get the access_token array");
+        };
+        let labels: Vec<_> = tokens.iter().map(|t| t.label.as_deref()).collect();
+        assert_eq!(labels, [Some("talk"), Some("vc-exchange")]);
+        assert_ne!(tokens[0].value, tokens[1].value);
+        assert_eq!(
+            authority
+                .recv_access_tokens()
+                .filter_by_grant("grant-1")
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let url = "https://me.example/api/resource";
+        let (headers, body) = (HeaderMap::new(), b"" as &[u8]);
+        let talk = authority
+            .authorize(
+                &tokens[0].value,
+                &InteractAction::Talk,
+                "GET",
+                url,
+                &headers,
+                body,
+            )
+            .await
+            .unwrap();
+        assert_eq!(talk.label.as_deref(), Some("talk"));
+        let vc = authority
+            .authorize(
+                &tokens[1].value,
+                &InteractAction::RequestVc,
+                "GET",
+                url,
+                &headers,
+                body,
+            )
+            .await
+            .unwrap();
+        assert_eq!(vc.label.as_deref(), Some("vc-exchange"));
+        assert!(
+            authority
+                .authorize(
+                    &tokens[0].value,
+                    &InteractAction::RequestVc,
+                    "GET",
+                    url,
+                    &headers,
+                    body
+                )
+                .await
+                .is_err(),
+            "the talk token must not reach the vc-exchange access"
+        );
+    }
+}
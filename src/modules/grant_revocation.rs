@@ -0,0 +1,213 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::data::entities::received::grant;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::traits::received::{
+    RecvAccessTokenRepoTrait, RecvGrantRepoTrait, RecvInteractionRepoTrait,
+};
+use crate::services::repo::traits::shared::{ParticipantRepoTrait, ResourceReqRepoTrait};
+use crate::types::gnap::GrantStatus;
+use crate::types::gnap::access_token::AccessToken;
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use chrono::Utc;
+use tracing::info;
+
+/// Business Orchestration Module revoking received GNAP grants (RFC 9635 §5.4 and §6).
+///
+/// A client ends an in-progress grant with a `DELETE` on its continuation URI, rotates an
+/// issued access token with a `POST` on its management URI and gives it up with a `DELETE`
+/// there; an administrator can revoke any grant by id. Either way the interaction and resource request of the grant are dropped and
+/// the token stored for the mate stops being accepted, as do the labeled tokens of the grant.
+#[async_trait]
+pub trait GrantRevocationModuleTrait: Send + Sync + 'static {
+    /// Returns the repository persisting grants received from participants.
+    fn recv_grants(&self) -> Arc<dyn RecvGrantRepoTrait>;
+
+    /// Returns the repository persisting the interactions of those grants.
+    fn recv_interactions(&self) -> Arc<dyn RecvInteractionRepoTrait>;
+
+    /// Returns the repository persisting the labeled tokens of multiple token grants.
+    fn recv_access_tokens(&self) -> Arc<dyn RecvAccessTokenRepoTrait>;
+
+    /// Returns the repository persisting the access requested by those grants.
+    fn resource_reqs(&self) -> Arc<dyn ResourceReqRepoTrait>;
+
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Returns the token management URI handed out with the issued access tokens.
+    fn token_manage_uri(&self) -> String;
+
+    /// Revokes grant `id`, whatever step it reached.
+    ///
+    /// # Errors
+    /// Returns a missing resource error when the grant is unknown.
+    async fn revoke_grant(&self, id: &str) -> Outcome<grant::Model> {
+        let mut grant = self.recv_grants().get_by_id(id).await?;
+        if let Some(token) = grant.token.take() {
+            self.invalidate_mate_token(&token).await?;
+        }
+        if grant.status != GrantStatus::Finalized {
+            grant.status = GrantStatus::Rejected;
+        }
+        if grant.ended_at.is_none() {
+            grant.ended_at = Some(Utc::now());
+        }
+
+        for labeled in self.recv_access_tokens().filter_by_grant(id).await? {
+            self.resource_reqs().delete(&labeled.access_id()).await?;
+        }
+        self.recv_access_tokens().delete_by_grant(id).await?;
+        self.recv_interactions().delete(id).await?;
+        self.resource_reqs().delete(id).await?;
+        let grant = self.recv_grants().update(grant).await?;
+        info!("Grant {id} revoked");
+        Ok(grant)
+    }
+
+    /// Revokes the grant continued at `cont_id`, presented with its continue `token`.
+    ///
+    /// The `DELETE` call must be signed with the key the grant was requested with.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when `token` is not the current continue token,
+    /// and a security error when the request proof does not hold.
+    async fn revoke_by_continuation(
+        &self,
+        cont_id: &str,
+        token: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Outcome<()> {
+        let interaction = self.recv_interactions().get_by_cont_id(cont_id).await?;
+        if interaction.continue_token != token {
+            return Err(Errors::forbidden(
+                format!("Continue token for {cont_id} is not valid"),
+                None,
+            ));
+        }
+        interaction.check_proof(headers, "DELETE", body)?;
+        self.revoke_grant(&interaction.id).await?;
+        Ok(())
+    }
+
+    /// Revokes an issued access token on behalf of the mate holding it.
+    ///
+    /// Key-bound tokens must come with a `DELETE` call to the
+    /// [management URI](Self::token_manage_uri) signed with the bound key.
+    ///
+    /// # Errors
+    /// Returns a missing resource error when no mate holds `token`, an unauthorized error
+    /// when it already expired, and a security error when the request proof does not hold.
+    async fn revoke_access_token(
+        &self,
+        token: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Outcome<()> {
+        let mut mate = self.participants().get_by_token(token).await?;
+        mate.check_token_proof(headers, "DELETE", &self.token_manage_uri(), body)?;
+        for mut grant in self
+            .recv_grants()
+            .filter_by_participant(&mate.participant_nick)
+            .await?
+        {
+            if grant.token.as_deref() == Some(token) {
+                grant.token = None;
+                self.recv_grants().update(grant).await?;
+            }
+        }
+        mate.token = None;
+        mate.token_expires_at = None;
+        mate.token_key = None;
+        self.participants().update(mate).await?;
+        info!("Access token revoked");
+        Ok(())
+    }
+
+    /// Rotates an issued access token on behalf of the mate holding it (RFC 9635 §6.1).
+    ///
+    /// Key-bound tokens must come with a `POST` call to the
+    /// [management URI](Self::token_manage_uri) signed with the bound key. The new value keeps
+    /// the access, expiry and key binding of the old one, which stops being accepted.
+    ///
+    /// # Errors
+    /// Returns a missing resource error when no mate holds `token`, an unauthorized error
+    /// when it expired and a security error when the request proof does not hold.
+    async fn rotate_access_token(
+        &self,
+        token: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Outcome<AccessToken> {
+        let mate = self.participants().get_by_token(token).await?;
+        mate.check_token_proof(headers, "POST", &self.token_manage_uri(), body)?;
+        let mut grant = self
+            .recv_grants()
+            .filter_by_participant(&mate.participant_nick)
+            .await?
+            .into_iter()
+            .find(|grant| grant.token.as_deref() == Some(token))
+            .ok_or_else(|| {
+                Errors::missing_resource(
+                    &mate.participant_id,
+                    "No grant issued this access token",
+                    None,
+                )
+            })?;
+        let access = self.resource_reqs().get_by_id(&grant.id).await?;
+
+        let mate = self
+            .participants()
+            .rotate_token(token, mate.token_expires_at)
+            .await?;
+        let value = mate.token.clone().unwrap_or_default();
+        grant.token = Some(value.clone());
+        self.recv_grants().update(grant).await?;
+
+        let mut rotated =
+            AccessToken::new(value.clone(), access).with_manage(self.token_manage_uri(), value);
+        if let Some(ttl) = mate
+            .token_expires_at
+            .and_then(|at| (at - Utc::now()).to_std().ok())
+        {
+            rotated = rotated.with_expiry(ttl);
+        }
+        info!("Access token rotated");
+        Ok(rotated)
+    }
+
+    /// Clears `token` from the mate it was issued to, if still stored.
+    async fn invalidate_mate_token(&self, token: &str) -> Outcome<()> {
+        let mut mate = match self.participants().get_by_token(token).await {
+            Ok(mate) => mate,
+            Err(Errors::MissingResourceError { .. } | Errors::UnauthorizedError { .. }) => {
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+        mate.token = None;
+        mate.token_expires_at = None;
+        mate.token_key = None;
+        self.participants().update(mate).await?;
+        Ok(())
+    }
+}
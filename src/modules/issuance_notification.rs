@@ -0,0 +1,49 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::errors::Outcome;
+use crate::services::HasIssuer;
+use crate::services::repo::traits::shared::IssuanceRepoTrait;
+use crate::types::issuance::NotificationRequest;
+use async_trait::async_trait;
+
+/// Business Orchestration Module backing the OpenID4VCI Notification Endpoint.
+///
+/// Stores the credential lifecycle event a wallet reports after issuance, which tells
+/// acknowledged credentials apart from ones still in flight.
+#[async_trait]
+pub trait IssuanceNotificationModuleTrait: HasIssuer + Send + Sync + 'static {
+    /// Returns the repository persisting issuance sessions.
+    fn issuances(&self) -> Arc<dyn IssuanceRepoTrait>;
+
+    /// Records `notification` on the issuance session that handed out its `notification_id`.
+    ///
+    /// # Errors
+    /// Returns a forbidden error when `token` is not the access token of that session.
+    async fn notify(&self, notification: NotificationRequest, token: &str) -> Outcome<()> {
+        let mut issuance = self
+            .issuances()
+            .get_by_notification_id(&notification.notification_id)
+            .await?;
+        self.issuer()
+            .process_notification(&mut issuance, notification, token)?;
+        self.issuances().update(issuance).await?;
+        Ok(())
+    }
+}
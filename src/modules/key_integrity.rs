@@ -0,0 +1,179 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::capabilities::Did;
+use crate::data::entities::wallet::key;
+use crate::errors::{Errors, Outcome};
+use crate::services::vault::VaultTrait;
+use crate::services::{HasVault, HasWallet};
+use crate::types::dids::DidDocument;
+use crate::types::keys::{PrivateKey, PublicKey};
+use crate::types::secrets::PemHelper;
+use crate::types::wallet::{DidSearch, KeyDrift, KeyIntegrityReport};
+use async_trait::async_trait;
+use tracing::{error, info};
+
+/// Business Orchestration Module guarding against signing key drift.
+///
+/// After a Vault restore or a manual key swap the key in Vault may no longer be the one the
+/// wallet and the published DID document advertise, which otherwise only shows up later as
+/// signature failures at the counterparties.
+#[async_trait]
+pub trait KeyIntegrityModuleTrait: HasWallet + HasVault + Send + Sync + 'static {
+    /// Compares the JWK thumbprints of the Vault key, the wallet DID document and the
+    /// resolved (published) DID document for the active identity.
+    async fn check_key_integrity(&self) -> Outcome<KeyIntegrityReport> {
+        let (did, did_doc, key_ref) = {
+            let identity = self.wallet().get_identity();
+            let lock = identity.read().await;
+            (
+                lock.did().clone(),
+                lock.did_doc().clone(),
+                lock.key_ref().clone(),
+            )
+        };
+        let vm_id = format!("{}#{}", did.id(), key_ref.fragment());
+        let mut drifts = Vec::new();
+
+        let vault_thumbprint = match self
+            .vault()
+            .read::<PemHelper>(None, key_ref.internal())
+            .await
+        {
+            Ok(pem) => match PrivateKey::try_from(pem) {
+                Ok(key) => Some(key.public_key().jwk_thumbprint()),
+                Err(e) => {
+                    drifts.push(KeyDrift::VaultKeyUnavailable(e.to_string()));
+                    None
+                }
+            },
+            Err(e) => {
+                drifts.push(KeyDrift::VaultKeyUnavailable(e.to_string()));
+                None
+            }
+        };
+
+        let wallet_thumbprint = vm_thumbprint(&did_doc, &vm_id);
+        match (&vault_thumbprint, &wallet_thumbprint) {
+            (_, None) => drifts.push(KeyDrift::MissingFromWallet),
+            (Some(vault), Some(wallet)) if vault != wallet => drifts.push(KeyDrift::WalletMismatch),
+            _ => {}
+        }
+
+        let published_thumbprint = match did.resolve().await {
+            Ok(published) => {
+                let thumbprint = vm_thumbprint(&published, &vm_id);
+                match (&vault_thumbprint, &thumbprint) {
+                    (_, None) => drifts.push(KeyDrift::MissingFromPublished),
+                    (Some(vault), Some(published)) if vault != published => {
+                        drifts.push(KeyDrift::PublishedMismatch)
+                    }
+                    _ => {}
+                }
+                thumbprint
+            }
+            Err(e) => {
+                drifts.push(KeyDrift::PublishedUnresolvable(e.to_string()));
+                None
+            }
+        };
+
+        Ok(KeyIntegrityReport {
+            did: did.id().to_string(),
+            verification_method: vm_id,
+            vault_thumbprint,
+            wallet_thumbprint,
+            published_thumbprint,
+            drifts,
+        })
+    }
+
+    /// Runs [`check_key_integrity`](Self::check_key_integrity) and loudly reports any drift.
+    ///
+    /// Meant to be called once the wallet is linked during startup. It never fails the boot:
+    /// the report is returned so the caller decides whether to stop.
+    async fn verify_keys_on_startup(&self) -> Outcome<KeyIntegrityReport> {
+        let report = self.check_key_integrity().await?;
+        if report.is_healthy() {
+            info!("Signing key {} matches Vault", report.verification_method);
+            return Ok(report);
+        }
+
+        error!("=================================================================================");
+        error!(
+            "SIGNING KEY DRIFT DETECTED for {}",
+            report.verification_method
+        );
+        for drift in &report.drifts {
+            error!("  - {drift}");
+        }
+        error!("Signatures issued now will fail verification at counterparties.");
+        error!("Restore the matching key in Vault, or rebind the Vault key through the key");
+        error!("repair endpoint and republish the DID document.");
+        error!("=================================================================================");
+        Ok(report)
+    }
+
+    /// Registers the current Vault key in the wallet and makes it the default key of the
+    /// active DID, then checks the bindings again.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] for `did:jwk` identities, whose identifier is
+    /// derived from the key itself and therefore cannot be rebound.
+    async fn repair_key_binding(&self) -> Outcome<KeyIntegrityReport> {
+        let (did, key_ref) = {
+            let identity = self.wallet().get_identity();
+            let lock = identity.read().await;
+            (lock.did().clone(), lock.key_ref().clone())
+        };
+        if matches!(did, Did::Jwk(_)) {
+            return Err(Errors::forbidden(
+                "did:jwk identities are bound to their key; register a new DID instead",
+                None,
+            ));
+        }
+
+        let pem: PemHelper = self.vault().read(None, key_ref.internal()).await?;
+        let model = self
+            .wallet()
+            .register_key(key::Plan {
+                id: format!("crypto/keys/{}", uuid::Uuid::new_v4()),
+                alias: "vault-recovered".to_string(),
+                pem: pem.pem().to_string(),
+            })
+            .await?;
+
+        let search = DidSearch::Did(did.id().to_string());
+        self.wallet()
+            .add_key_to_did(search.clone(), model.id.clone())
+            .await?;
+        self.wallet().set_default_key(search, model.id).await?;
+        info!("Vault key rebound to {}", did.id());
+
+        self.check_key_integrity().await
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn vm_thumbprint(doc: &DidDocument, vm_id: &str) -> Option<String> {
+    doc.verification_method
+        .iter()
+        .find(|vm| vm.id == vm_id)
+        .and_then(|vm| PublicKey::parse_from_vm(vm).ok())
+        .map(|key| key.jwk_thumbprint())
+}
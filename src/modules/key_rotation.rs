@@ -0,0 +1,271 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::capabilities::Did;
+use crate::config::traits::KeyRotationConfigTrait;
+use crate::data::entities::shared::audit_entry;
+use crate::data::entities::wallet::key;
+use crate::errors::{Errors, Outcome};
+use crate::modules::standby::on_standby;
+use crate::services::repo::traits::shared::AuditEntryRepoTrait;
+use crate::services::standby::StandbyTrait;
+use crate::services::vault::VaultTrait;
+use crate::services::{HasVault, HasWallet};
+use crate::types::audit::AuditOperation;
+use crate::types::keys::PrivateKey;
+use crate::types::wallet::{
+    DidSearch, KeyRef, KeyRotationEvent, KeyRotationReport, KeyRotationState, RetiringKey,
+    RotationStep,
+};
+use crate::utils::Clock;
+use async_trait::async_trait;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Business Orchestration Module rolling the agent signing key over without downtime.
+///
+/// The new key is published in the DID document before it starts signing, and the previous
+/// one stays published for a grace period so signatures already handed out keep verifying.
+/// Every step is recorded in the rotation state kept in the vault as soon as it is done, and
+/// in the audit log when one is wired in.
+#[async_trait]
+pub trait KeyRotationModuleTrait:
+    HasWallet + HasVault + KeyRotationConfigTrait + Send + Sync + 'static
+{
+    /// Returns the audit log rotation steps are written to, if enabled.
+    fn audit_log(&self) -> Option<Arc<dyn AuditEntryRepoTrait>> {
+        None
+    }
+
+    /// Returns the role keeper of an active/standby pair, if the instance takes part in one.
+    ///
+    /// The scheduled job pauses while it reports standby.
+    fn standby_guard(&self) -> Option<Arc<dyn StandbyTrait>> {
+        None
+    }
+
+    /// Returns the rotation state, empty before the first rotation.
+    ///
+    /// # Errors
+    /// Returns the vault error when the state exists but cannot be read.
+    async fn rotation_state(&self) -> Outcome<KeyRotationState> {
+        match self
+            .vault()
+            .read(None, self.get_rotation_state_path())
+            .await
+        {
+            Ok(state) => Ok(state),
+            Err(Errors::MissingResourceError { .. }) => Ok(KeyRotationState::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Records `step` in `state` and persists it, so an interrupted rotation leaves a trail.
+    ///
+    /// The audit log entry is best effort: failing to write it is logged, not returned.
+    async fn record_step(
+        &self,
+        state: &mut KeyRotationState,
+        did: &str,
+        key_id: &str,
+        step: RotationStep,
+    ) -> Outcome<()> {
+        info!("Key rotation of {did}: {step:?} for key {key_id}");
+        let operation = match step {
+            RotationStep::Generated => AuditOperation::Create,
+            RotationStep::Retired => AuditOperation::Delete,
+            _ => AuditOperation::Update,
+        };
+        let event = KeyRotationEvent {
+            key_id: key_id.to_string(),
+            did: did.to_string(),
+            at: Clock::now(),
+            step,
+        };
+        if let Some(audit_log) = self.audit_log() {
+            let plan =
+                audit_entry::Plan::new("key_rotation", key_id, operation, None, Some(&event));
+            if let Err(e) = audit_log.create(plan).await {
+                e.log();
+            }
+        }
+        state.record(event);
+        self.vault()
+            .write(None, self.get_rotation_state_path(), &*state)
+            .await
+    }
+
+    /// Generates a new signing key, publishes it and makes it the default key of the active
+    /// DID, scheduling the previous key for retirement.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] for `did:jwk` identities, whose identifier is
+    /// derived from the key itself and therefore cannot be rotated.
+    async fn rotate_signing_key(&self) -> Outcome<KeyRotationReport> {
+        let (did, previous) = active_key(self).await;
+        if matches!(did, Did::Jwk(_)) {
+            return Err(Errors::forbidden(
+                "did:jwk identities are bound to their key; register a new DID instead",
+                None,
+            ));
+        }
+        let mut state = self.rotation_state().await?;
+
+        let result: Outcome<KeyRotationReport> = async {
+            let key = PrivateKey::generate(self.get_rotation_key_spec())?;
+            let model = self
+                .wallet()
+                .register_key(key::Plan {
+                    id: format!("crypto/keys/{}", uuid::Uuid::new_v4()),
+                    alias: format!("rotated-{}", Clock::now().format("%Y%m%d")),
+                    pem: key.to_pkcs8_pem()?,
+                })
+                .await?;
+            self.record_step(&mut state, did.id(), &model.id, RotationStep::Generated)
+                .await?;
+
+            let search = DidSearch::Did(did.id().to_string());
+            self.wallet()
+                .add_key_to_did(search.clone(), model.id.clone())
+                .await?;
+            self.record_step(&mut state, did.id(), &model.id, RotationStep::Published)
+                .await?;
+
+            self.wallet()
+                .set_default_key(search, model.id.clone())
+                .await?;
+            let grace =
+                chrono::Duration::from_std(self.get_rotation_grace_period()).unwrap_or_default();
+            let retire_at = Clock::now() + grace;
+            state.active = Some(model.id.clone());
+            state.retiring.push(RetiringKey {
+                key_id: previous.internal().to_string(),
+                did: did.id().to_string(),
+                retire_at,
+            });
+            let step = RotationStep::Activated {
+                previous: previous.internal().to_string(),
+            };
+            self.record_step(&mut state, did.id(), &model.id, step)
+                .await?;
+            Ok(KeyRotationReport {
+                did: did.id().to_string(),
+                previous_key: previous.internal().to_string(),
+                active_key: model.id,
+                retire_at,
+            })
+        }
+        .await;
+
+        if let Err(e) = &result {
+            let step = RotationStep::Failed {
+                reason: e.to_string(),
+            };
+            self.record_step(&mut state, did.id(), previous.internal(), step)
+                .await?;
+        }
+        result
+    }
+
+    /// Removes from their DID document the previous keys whose grace period is over,
+    /// returning their ids. Keys failing to retire are retried on the next call.
+    async fn retire_expired_keys(&self) -> Outcome<Vec<String>> {
+        let mut state = self.rotation_state().await?;
+        let expired = state.expired(Clock::now());
+        if expired.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut retired = Vec::new();
+        for key in expired {
+            let result: Outcome<()> = async {
+                let search = DidSearch::Did(key.did.clone());
+                self.wallet()
+                    .remove_key_from_did(search, key.key_id.clone())
+                    .await?;
+                self.wallet().delete_key(&key.key_id).await
+            }
+            .await;
+
+            let step = match result {
+                Ok(()) => {
+                    state.forget(&key.key_id);
+                    retired.push(key.key_id.clone());
+                    RotationStep::Retired
+                }
+                Err(e) => RotationStep::Failed {
+                    reason: e.to_string(),
+                },
+            };
+            self.record_step(&mut state, &key.did, &key.key_id, step)
+                .await?;
+        }
+        Ok(retired)
+    }
+
+    /// Tells whether the active signing key is older than the configured maximum age.
+    async fn rotation_due(&self) -> Outcome<bool> {
+        let Some(max_age) = self.get_max_key_age() else {
+            return Ok(false);
+        };
+        let (did, key_ref) = active_key(self).await;
+        if matches!(did, Did::Jwk(_)) {
+            return Ok(false);
+        }
+        let key = self.wallet().retrieve_key(key_ref.internal()).await?;
+        let max_age = chrono::Duration::from_std(max_age).unwrap_or(chrono::Duration::MAX);
+        Ok(key.created_at + max_age <= Clock::now())
+    }
+}
+
+/// Spawns the scheduler rotating the signing key once it is due and retiring previous keys
+/// at the end of their grace period.
+pub fn spawn_key_rotation(module: Arc<dyn KeyRotationModuleTrait>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(module.get_rotation_check_interval());
+        loop {
+            ticker.tick().await;
+            if on_standby(module.standby_guard()).await {
+                continue;
+            }
+            match module.rotation_due().await {
+                Ok(true) => match module.rotate_signing_key().await {
+                    Ok(report) => info!(
+                        "Signing key of {} rotated to {}",
+                        report.did, report.active_key
+                    ),
+                    Err(e) => warn!("Scheduled key rotation failed: {e}"),
+                },
+                Ok(false) => {}
+                Err(e) => warn!("Unable to check the signing key age: {e}"),
+            }
+            if let Err(e) = module.retire_expired_keys().await {
+                warn!("Unable to retire previous signing keys: {e}");
+            }
+        }
+    })
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+async fn active_key<M: HasWallet + ?Sized>(module: &M) -> (Did, KeyRef) {
+    let identity = module.wallet().get_identity();
+    let lock = identity.read().await;
+    (lock.did().clone(), lock.key_ref().clone())
+}
@@ -0,0 +1,57 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::errors::Outcome;
+use crate::services::HasLogFilter;
+use crate::types::logging::LogFilter;
+use tracing::warn;
+
+/// Business Orchestration Module for runtime logging configuration.
+///
+/// Every change is logged at `warn` so it remains visible whatever filter is in effect.
+pub trait LogFilterModuleTrait: HasLogFilter + Send + Sync + 'static {
+    /// Returns the directives currently in effect.
+    fn get_log_filter(&self) -> Outcome<LogFilter> {
+        let directives = self.log_filter().current()?;
+        Ok(LogFilter { directives })
+    }
+
+    /// Replaces the active filter.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`](crate::errors::Errors::FormatError) when the
+    /// directives cannot be parsed; the previous filter stays active.
+    fn set_log_filter(&self, filter: LogFilter) -> Outcome<LogFilter> {
+        let directives = self.log_filter().set(&filter.directives)?;
+        warn!("Log filter replaced: {directives}");
+        Ok(LogFilter { directives })
+    }
+
+    /// Adds directives on top of the active filter (e.g. `ymir::services::verifier=debug`).
+    fn extend_log_filter(&self, filter: LogFilter) -> Outcome<LogFilter> {
+        let directives = self.log_filter().extend(&filter.directives)?;
+        warn!("Log filter extended: {directives}");
+        Ok(LogFilter { directives })
+    }
+
+    /// Restores the filter the agent was started with.
+    fn reset_log_filter(&self) -> Outcome<LogFilter> {
+        let directives = self.log_filter().reset()?;
+        warn!("Log filter reset: {directives}");
+        Ok(LogFilter { directives })
+    }
+}
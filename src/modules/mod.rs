@@ -15,6 +15,72 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod access_policy;
+mod archive;
+mod audit;
+mod cleanup;
+mod client_registry;
+mod config_export;
+mod credential_issuance;
+mod did_equivalence;
+mod evidence;
+mod extensions;
+mod grant_authority;
+mod grant_revocation;
+mod issuance_notification;
+mod key_integrity;
+mod key_rotation;
+mod log_filter;
+mod participant_lifecycle;
+mod participant_search;
+mod participant_transfer;
+mod presentation_consent;
+mod quota;
+mod receipt;
+mod requirements;
+mod revocation_feed;
+mod revocation_impact;
+mod standby;
+mod status_list;
+mod trust_anchor;
+mod trusted_issuer;
+mod vc_requester;
+mod verification;
 mod wallet;
 
-pub use wallet::WalletModuleTrait;
+pub use access_policy::AccessPolicyModuleTrait;
+pub use archive::{ArchiveModuleTrait, spawn_archive_job};
+pub use audit::AuditModuleTrait;
+pub use cleanup::{CleanupModuleTrait, spawn_cleanup_job};
+pub use client_registry::ClientRegistryModuleTrait;
+pub use config_export::ConfigExportModuleTrait;
+pub use credential_issuance::CredentialIssuanceModuleTrait;
+pub use did_equivalence::DidEquivalenceModuleTrait;
+pub use evidence::EvidenceModuleTrait;
+pub use extensions::ExtensionsModuleTrait;
+pub use grant_authority::GrantAuthorityModuleTrait;
+pub use grant_revocation::GrantRevocationModuleTrait;
+pub use issuance_notification::IssuanceNotificationModuleTrait;
+pub use key_integrity::KeyIntegrityModuleTrait;
+pub use key_rotation::{KeyRotationModuleTrait, spawn_key_rotation};
+pub use log_filter::LogFilterModuleTrait;
+pub use participant_lifecycle::ParticipantLifecycleModuleTrait;
+pub use participant_search::ParticipantSearchModuleTrait;
+pub use participant_transfer::ParticipantTransferModuleTrait;
+pub use presentation_consent::PresentationConsentModuleTrait;
+pub use quota::QuotaModuleTrait;
+pub use receipt::ReceiptModuleTrait;
+pub use requirements::RequirementsModuleTrait;
+pub use revocation_feed::{
+    RevocationFeedModuleTrait, RevocationSubscriberModuleTrait, spawn_revocation_subscriber,
+};
+pub use revocation_impact::RevocationImpactModuleTrait;
+pub use standby::{StandbyModuleTrait, spawn_standby_handoff};
+pub use status_list::StatusListModuleTrait;
+pub use trust_anchor::{TrustAnchorModuleTrait, spawn_trust_anchor_sync};
+pub use trusted_issuer::TrustedIssuerModuleTrait;
+pub use vc_requester::{
+    VcRequesterModuleTrait, spawn_credential_refresh, spawn_credential_request,
+};
+pub use verification::{VerificationModuleTrait, spawn_verification_sweeper};
+pub use wallet::{TenantWallet, WalletModuleTrait};
@@ -0,0 +1,80 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::data::entities::shared::participant;
+use crate::errors::Outcome;
+use crate::services::repo::traits::shared::ParticipantRepoTrait;
+use crate::types::participants::{ParticipantStatus, StatusChange};
+use async_trait::async_trait;
+use tracing::info;
+
+/// Business Orchestration Module driving business mates through their lifecycle.
+///
+/// Mates move between `Invited`, `Active`, `Suspended` and `Expired`; only active mates get
+/// their access tokens accepted, so a suspension takes effect on their very next request.
+#[async_trait]
+pub trait ParticipantLifecycleModuleTrait: Send + Sync + 'static {
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Applies `change` to mate `id`, replacing its membership expiry first when given.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ConflictError`](crate::errors::Errors::ConflictError) when the
+    /// mate cannot move to the requested state, and a validation error when activating a mate
+    /// whose membership has lapsed.
+    async fn change_status(&self, id: &str, change: StatusChange) -> Outcome<participant::Model> {
+        let mut mate = self.participants().get_by_id(id).await?;
+        if change.expires_at.is_some() {
+            mate.expires_at = change.expires_at;
+        }
+        let from = mate.effective_status();
+        mate.transition(change.status)?;
+        let mate = self.participants().update(mate).await?;
+        info!("Participant {id} moved from {from} to {}", change.status);
+        Ok(mate)
+    }
+
+    /// Lists the mates whose stored lifecycle state is `status`.
+    async fn list_by_status(&self, status: ParticipantStatus) -> Outcome<Vec<participant::Model>> {
+        self.participants().filter_by_status(status).await
+    }
+
+    /// Stores `Expired` on every invited or active mate whose membership has lapsed.
+    ///
+    /// Tokens of lapsed mates are refused whether or not they were swept; sweeping keeps the
+    /// stored state, and the audit log, in line with it. Returns the number of mates expired.
+    async fn expire_lapsed(&self) -> Outcome<usize> {
+        let mut expired = 0;
+        for status in [ParticipantStatus::Invited, ParticipantStatus::Active] {
+            for mut mate in self.participants().filter_by_status(status).await? {
+                if mate.effective_status() != ParticipantStatus::Expired {
+                    continue;
+                }
+                mate.transition(ParticipantStatus::Expired)?;
+                self.participants().update(mate).await?;
+                expired += 1;
+            }
+        }
+        if expired > 0 {
+            info!("Expired {expired} participants with a lapsed membership");
+        }
+        Ok(expired)
+    }
+}
@@ -0,0 +1,47 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::data::entities::shared::participant;
+use crate::errors::Outcome;
+use crate::services::repo::traits::shared::ParticipantRepoTrait;
+use crate::types::pagination::{Page, PageRequest, SortOrder};
+use async_trait::async_trait;
+
+/// Business Orchestration Module letting UIs look partners up without listing them all.
+#[async_trait]
+pub trait ParticipantSearchModuleTrait: Send + Sync + 'static {
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Lists one page of the participants whose id, nick or base URL contain `query`, sorted
+    /// by nick unless sorted otherwise.
+    async fn search_participants(
+        &self,
+        query: &str,
+        request: &PageRequest,
+    ) -> Outcome<Page<participant::Model>> {
+        let request = match request.sort {
+            Some(_) => request.clone(),
+            None => request
+                .clone()
+                .sorted_by("participant_nick", SortOrder::Asc),
+        };
+        self.participants().search(query.trim(), &request).await
+    }
+}
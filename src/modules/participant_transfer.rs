@@ -0,0 +1,97 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::data::entities::shared::participant;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::traits::shared::ParticipantRepoTrait;
+use crate::types::participants::ParticipantRecord;
+use async_trait::async_trait;
+use tracing::info;
+
+/// Business Orchestration Module importing and exporting participants in bulk.
+///
+/// Meant for operators migrating an existing dataspace: the participant list of the old
+/// deployment is exported as CSV or JSON and imported here in one statement.
+#[async_trait]
+pub trait ParticipantTransferModuleTrait: Send + Sync + 'static {
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Upserts every record, overwriting the slug, type and base URL of known participants, and
+    /// their extensions when the record carries some.
+    ///
+    /// The list is checked as a whole before anything is written.
+    ///
+    /// # Errors
+    /// Returns a format error when a record is incomplete or its base URL is invalid, and an
+    /// [`Errors::ConflictError`] when an id is repeated or names this host.
+    async fn import_participants(
+        &self,
+        records: Vec<ParticipantRecord>,
+    ) -> Outcome<Vec<participant::Model>> {
+        let mut ids = HashSet::new();
+        for record in &records {
+            record.validate()?;
+            if !ids.insert(record.id.as_str()) {
+                return Err(Errors::conflict(
+                    format!("Participant {} is listed more than once", record.id),
+                    None,
+                ));
+            }
+        }
+        let ids: Vec<String> = ids.into_iter().map(str::to_string).collect();
+        let existing = self.participants().get_batch(&ids).await?;
+        if let Some(me) = existing.iter().find(|m| m.is_me) {
+            return Err(Errors::conflict(
+                format!(
+                    "Participant {} is this host and cannot be imported",
+                    me.participant_id
+                ),
+                None,
+            ));
+        }
+
+        let plans = records
+            .into_iter()
+            .map(|mut record| {
+                if record.extensions.is_none() {
+                    record.extensions = existing
+                        .iter()
+                        .find(|m| m.participant_id == record.id)
+                        .map(|m| m.extensions.clone());
+                }
+                record.into_plan()
+            })
+            .collect();
+        let imported = self.participants().import_batch(plans).await?;
+        info!("Imported {} participants", imported.len());
+        Ok(imported)
+    }
+
+    /// Lists every participant other than this host as portable records.
+    async fn export_participants(&self) -> Outcome<Vec<ParticipantRecord>> {
+        let mates = self.participants().get_all(None, None).await?;
+        Ok(mates
+            .iter()
+            .filter(|m| !m.is_me)
+            .map(ParticipantRecord::from)
+            .collect())
+    }
+}
@@ -0,0 +1,187 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::data::entities::wallet::pending_presentation::{Model, Plan};
+use crate::errors::{Errors, Outcome};
+use crate::services::HasWallet;
+use crate::services::repo::traits::wallet::PendingPresentationRepoTrait;
+use crate::types::wallet::{ConsentStatus, PresentationCandidate};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use tokio::time::{Instant, sleep_until};
+use tracing::{info, warn};
+
+/// Business Orchestration Module holding presentations back for the holder's consent.
+///
+/// Instead of answering an OID4VP request with the first matching credentials, the request
+/// is stored with every candidate credential until an administrator approves it, possibly
+/// picking other credentials, or rejects it.
+#[async_trait]
+pub trait PresentationConsentModuleTrait: HasWallet + Send + Sync + 'static {
+    /// Returns the repository persisting the requests awaiting consent.
+    fn pending_presentations(&self) -> Arc<dyn PendingPresentationRepoTrait>;
+
+    /// Returns how often the requests are re-read on behalf of an event stream subscriber.
+    fn get_event_poll_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    /// Stores the OID4VP request at `uri` together with the credentials able to answer it.
+    ///
+    /// # Errors
+    /// Returns a missing resource error when no credential answers any descriptor.
+    async fn hold_presentation(&self, uri: &str) -> Outcome<Model> {
+        let candidates = self.wallet().presentation_candidates(uri).await?;
+        if candidates.iter().all(|c| c.credentials.is_empty()) {
+            return Err(Errors::missing_resource(
+                uri,
+                "No stored credentials answer the presentation request",
+                None,
+            ));
+        }
+        let verifier = Url::parse(uri).ok().and_then(|url| {
+            url.query_pairs()
+                .find(|(k, _)| k == "client_id")
+                .map(|(_, v)| v.into_owned())
+        });
+        let model = self
+            .pending_presentations()
+            .create(Plan {
+                request_uri: uri.to_string(),
+                verifier,
+                candidates,
+            })
+            .await?;
+        info!("Presentation {} is waiting for consent", model.id);
+        Ok(model)
+    }
+
+    /// Presents the credentials `selected`, or the first candidate of every descriptor when
+    /// none are given, and closes the request.
+    ///
+    /// A presentation the wallet fails to deliver is closed as `Failed` with the reason.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the request was already decided, including
+    /// by a concurrent call, or a selected credential is not one of its candidates.
+    async fn approve_presentation(
+        &self,
+        id: &str,
+        selected: Option<Vec<String>>,
+    ) -> Outcome<Model> {
+        let mut model = self.pending(id).await?;
+        let candidates = model.candidates()?;
+        let selected = match selected {
+            Some(selected) => {
+                let allowed: HashSet<&String> =
+                    candidates.iter().flat_map(|c| &c.credentials).collect();
+                if let Some(unknown) = selected.iter().find(|vc| !allowed.contains(vc)) {
+                    return Err(Errors::forbidden(
+                        format!("Credential {unknown} is not a candidate of presentation {id}"),
+                        None,
+                    ));
+                }
+                selected
+            }
+            None => PresentationCandidate::default_selection(&candidates),
+        };
+
+        // Claims the request first, so a concurrent decision cannot present it a second time.
+        model.status = ConsentStatus::Presenting;
+        let mut model = self
+            .pending_presentations()
+            .advance(model, ConsentStatus::Pending)
+            .await?;
+        match self
+            .wallet()
+            .present_credentials(&model.request_uri, selected.clone())
+            .await
+        {
+            Ok(()) => {
+                info!("Presentation {id} approved and presented");
+                model.decide(ConsentStatus::Presented, Some(&selected));
+            }
+            Err(e) => {
+                warn!("Presentation {id} approved but failed: {e}");
+                model.decide(ConsentStatus::Failed, Some(&selected));
+                model.error = Some(e.reason().to_string());
+            }
+        }
+        self.pending_presentations()
+            .advance(model, ConsentStatus::Presenting)
+            .await
+    }
+
+    /// Closes the request without presenting anything.
+    async fn reject_presentation(&self, id: &str) -> Outcome<Model> {
+        let mut model = self.pending(id).await?;
+        info!("Presentation {id} rejected");
+        model.decide(ConsentStatus::Rejected, None);
+        self.pending_presentations()
+            .advance(model, ConsentStatus::Pending)
+            .await
+    }
+
+    /// Returns request `id`, which must still be waiting for a decision.
+    async fn pending(&self, id: &str) -> Outcome<Model> {
+        let model = self.pending_presentations().get_by_id(id).await?;
+        if model.status != ConsentStatus::Pending {
+            return Err(Errors::forbidden(
+                format!("Presentation {id} was already {:?}", model.status),
+                None,
+            ));
+        }
+        Ok(model)
+    }
+
+    /// Returns request `id` whatever its state.
+    async fn presentation(&self, id: &str) -> Outcome<Model> {
+        self.pending_presentations().get_by_id(id).await
+    }
+
+    /// Lists the requests in `status`, or every request when `None`.
+    async fn presentations(&self, status: Option<ConsentStatus>) -> Outcome<Vec<Model>> {
+        match status {
+            Some(status) => self.pending_presentations().filter_by_status(status).await,
+            None => self.pending_presentations().get_all(None, None).await,
+        }
+    }
+
+    /// Waits for requests received after `since`, returning as soon as there is one or once
+    /// `timeout` elapses.
+    ///
+    /// Feeds the event stream an administrator console follows incoming requests with.
+    async fn wait_for_presentations(
+        &self,
+        since: DateTime<Utc>,
+        timeout: Duration,
+    ) -> Outcome<Vec<Model>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let models = self.pending_presentations().created_after(since).await?;
+            if !models.is_empty() || Instant::now() >= deadline {
+                return Ok(models);
+            }
+            sleep_until(deadline.min(Instant::now() + self.get_event_poll_interval())).await;
+        }
+    }
+}
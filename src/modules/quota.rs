@@ -0,0 +1,152 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::config::traits::QuotaConfigTrait;
+use crate::config::types::QuotaLimit;
+use crate::data::entities::shared::quota_counter::Plan;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::traits::shared::QuotaCounterRepoTrait;
+use crate::types::quota::{QuotaKind, QuotaUsage};
+use crate::utils::Clock;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use tracing::{info, warn};
+
+/// Business Orchestration Module metering issuances and verifications per participant.
+///
+/// Counters live in fixed windows aligned to the Unix epoch and are persisted, so allowances
+/// survive restarts. The issuer consumes the issuance quota of a participant before building
+/// its plan once wired through [`IssuerService::with_quota`], and the verification module
+/// consumes the verification quota on every wallet response through its
+/// [`quota_meter`](crate::modules::VerificationModuleTrait::quota_meter); operations without a
+/// configured limit are never metered.
+///
+/// [`IssuerService::with_quota`]: crate::services::issuer::oid4vci_1_0::IssuerService::with_quota
+#[async_trait]
+pub trait QuotaModuleTrait: QuotaConfigTrait + Send + Sync + 'static {
+    /// Returns the repository persisting quota counters.
+    fn quota_counters(&self) -> Arc<dyn QuotaCounterRepoTrait>;
+
+    /// Counts one `kind` operation for `participant_id` against its current window.
+    ///
+    /// Rejected attempts still count, so a participant hammering the authority stays blocked
+    /// until the window rolls over.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::RateLimitError`](crate::errors::Errors::RateLimitError) once the
+    /// window allowance is exhausted.
+    async fn consume_quota(
+        &self,
+        participant_id: &str,
+        kind: QuotaKind,
+    ) -> Outcome<Option<QuotaUsage>> {
+        let Some(limit) = self.get_quota(kind) else {
+            return Ok(None);
+        };
+
+        let window_start = window_start(Clock::now(), &limit);
+        let counter = self
+            .quota_counters()
+            .increment(Plan {
+                participant_id: participant_id.to_string(),
+                kind,
+                window_start,
+            })
+            .await?;
+        let usage = usage(participant_id, kind, window_start, counter.count, &limit);
+
+        if usage.is_exceeded() {
+            warn!(
+                "Participant {participant_id} exceeded its {kind} quota ({}/{})",
+                usage.used, usage.limit
+            );
+            return Err(Errors::rate_limit(
+                format!(
+                    "{kind} quota exhausted until {}",
+                    usage.window_end.to_rfc3339()
+                ),
+                None,
+            ));
+        }
+        Ok(Some(usage))
+    }
+
+    /// Reports the consumption of every configured quota of a participant in its current window.
+    async fn get_quota_usage(&self, participant_id: &str) -> Outcome<Vec<QuotaUsage>> {
+        let now = Clock::now();
+        let mut usages = Vec::new();
+        for kind in [QuotaKind::Issuance, QuotaKind::Verification] {
+            let Some(limit) = self.get_quota(kind) else {
+                continue;
+            };
+            let window_start = window_start(now, &limit);
+            let used = self
+                .quota_counters()
+                .get_by_participant(participant_id, window_start)
+                .await?
+                .into_iter()
+                .find(|c| c.kind == kind && c.window_start == window_start)
+                .map_or(0, |c| c.count);
+            usages.push(usage(participant_id, kind, window_start, used, &limit));
+        }
+        Ok(usages)
+    }
+
+    /// Drops the counters of windows that can no longer be consumed.
+    async fn purge_expired_counters(&self) -> Outcome<u64> {
+        let longest = [QuotaKind::Issuance, QuotaKind::Verification]
+            .into_iter()
+            .filter_map(|kind| self.get_quota(kind))
+            .map(|limit| limit.window_secs)
+            .max()
+            .unwrap_or(0);
+        let cutoff = Clock::now() - Duration::seconds(longest as i64);
+        let purged = self.quota_counters().purge_before(cutoff).await?;
+        if purged > 0 {
+            info!("Purged {purged} expired quota counters");
+        }
+        Ok(purged)
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Start of the epoch-aligned window `now` falls into.
+fn window_start(now: DateTime<Utc>, limit: &QuotaLimit) -> DateTime<Utc> {
+    let window = limit.window_secs as i64;
+    let start = now.timestamp() - now.timestamp().rem_euclid(window);
+    Utc.timestamp_opt(start, 0).single().unwrap_or(now)
+}
+
+fn usage(
+    participant_id: &str,
+    kind: QuotaKind,
+    window_start: DateTime<Utc>,
+    used: i32,
+    limit: &QuotaLimit,
+) -> QuotaUsage {
+    QuotaUsage {
+        participant_id: participant_id.to_string(),
+        kind,
+        window_start,
+        window_end: window_start + Duration::seconds(limit.window_secs as i64),
+        used,
+        limit: limit.max,
+    }
+}
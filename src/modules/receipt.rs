@@ -0,0 +1,70 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::data::entities::received::verification;
+use crate::errors::{Errors, Outcome};
+use crate::services::HasReceipt;
+use crate::services::repo::traits::received::RecvVerificationRepoTrait;
+use crate::types::verification::VerificationReceipt;
+use async_trait::async_trait;
+
+/// Business Orchestration Module for verification receipts.
+///
+/// Issues one signed receipt per successful verification, keeps it next to the verification
+/// so the holder can fetch it again later, and validates receipts shown by third parties.
+#[async_trait]
+pub trait ReceiptModuleTrait: HasReceipt + Send + Sync + 'static {
+    /// Returns the repository persisting received verifications.
+    fn verifications(&self) -> Arc<dyn RecvVerificationRepoTrait>;
+
+    /// Returns the receipt of `verification_id`, signing and storing it on first call.
+    async fn issue_receipt(&self, verification_id: &str) -> Outcome<String> {
+        let mut model: verification::Model =
+            self.verifications().get_by_id(verification_id).await?;
+        if let Some(receipt) = &model.receipt {
+            return Ok(receipt.clone());
+        }
+
+        let receipt = self.receipt().build_receipt(&model).await?;
+        let jwt = self.receipt().sign_receipt(&receipt).await?;
+        model.receipt = Some(jwt.clone());
+        self.verifications().update(model).await?;
+        Ok(jwt)
+    }
+
+    /// Returns the previously issued receipt of `verification_id`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::MissingResourceError`] when no receipt has been issued yet.
+    async fn get_receipt(&self, verification_id: &str) -> Outcome<String> {
+        let model: verification::Model = self.verifications().get_by_id(verification_id).await?;
+        model.receipt.ok_or_else(|| {
+            Errors::missing_resource(
+                verification_id,
+                "No receipt issued for this verification",
+                None,
+            )
+        })
+    }
+
+    /// Checks a receipt JWT presented by a third party and returns its payload.
+    async fn verify_receipt(&self, token: &str) -> Outcome<VerificationReceipt> {
+        self.receipt().verify_receipt(token).await
+    }
+}
@@ -0,0 +1,52 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::config::traits::VerifyReqConfigTrait;
+use crate::types::vcs::{VPDef, W3cDataModelVersion};
+use crate::types::verification::TokenRequirements;
+
+/// Business Orchestration Module for access requirement discovery.
+///
+/// Publishes which Verifiable Credentials a client is expected to present for a given
+/// resource and action, resolved from the verification requirement profiles.
+pub trait RequirementsModuleTrait: VerifyReqConfigTrait + Send + Sync + 'static {
+    /// Resolves the requirements for an optional resource/action pair.
+    ///
+    /// Without a matching profile the global credential policy is returned.
+    fn get_requirements(
+        &self,
+        resource: Option<String>,
+        action: Option<String>,
+    ) -> TokenRequirements {
+        let policy = self.get_policy_for(resource.as_deref(), action.as_deref());
+        let vc_types = policy.vc_types();
+        let presentation_definition = VPDef::with_constraints(
+            "requirements",
+            &vc_types,
+            W3cDataModelVersion::default(),
+            self.get_constraints(),
+        )
+        .with_policy(&policy);
+
+        TokenRequirements {
+            resource,
+            action,
+            vc_types,
+            presentation_definition,
+        }
+    }
+}
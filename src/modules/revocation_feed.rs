@@ -0,0 +1,266 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::config::traits::RevocationFeedConfigTrait;
+use crate::data::entities::shared::revocation_event;
+use crate::errors::Outcome;
+use crate::modules::standby::on_standby;
+use crate::services::HasRevocationFeed;
+use crate::services::repo::RepoTransaction;
+use crate::services::repo::traits::received::{RecvGrantRepoTrait, RecvVerificationRepoTrait};
+use crate::services::repo::traits::sent::SentGrantRepoTrait;
+use crate::services::repo::traits::shared::{ParticipantRepoTrait, RevocationEventRepoTrait};
+use crate::services::standby::StandbyTrait;
+use crate::types::gnap::GrantStatus;
+use async_trait::async_trait;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Business Orchestration Module publishing the authority's revocation feed.
+///
+/// Every revocation is appended to a ledger served as signed, cursor-based pages, so peer
+/// agents learn about it on their next poll instead of on their next failed interaction.
+#[async_trait]
+pub trait RevocationFeedModuleTrait:
+    HasRevocationFeed + RevocationFeedConfigTrait + Send + Sync + 'static
+{
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Returns the repository persisting the published revocations.
+    fn revocation_events(&self) -> Arc<dyn RevocationEventRepoTrait>;
+
+    /// Revokes the participant `participant_id` and the identities nested under it.
+    ///
+    /// The participant's token stops being accepted and one feed event is published per
+    /// revoked DID.
+    ///
+    /// # Errors
+    /// Returns a missing resource error when the participant is unknown.
+    async fn revoke_participant(
+        &self,
+        participant_id: &str,
+        reason: Option<String>,
+    ) -> Outcome<Vec<revocation_event::Model>> {
+        let mut participant = self.participants().get_by_id(participant_id).await?;
+        let dependents = self.participants().get_dependents(participant_id).await?;
+
+        let mut subjects = vec![participant.participant_id.clone()];
+        subjects.extend(dependents.into_iter().map(|p| p.participant_id));
+
+        if participant.token.take().is_some() {
+            self.participants().update(participant).await?;
+        }
+
+        let mut events = Vec::with_capacity(subjects.len());
+        for subject in subjects {
+            let event = self
+                .revocation_events()
+                .create(revocation_event::Plan {
+                    subject,
+                    reason: reason.clone(),
+                })
+                .await?;
+            events.push(event);
+        }
+        info!(
+            "Participant {participant_id} revoked, {} feed events published",
+            events.len()
+        );
+        Ok(events)
+    }
+
+    /// Returns the signed feed page following `cursor`.
+    ///
+    /// `limit` is capped to the configured page size.
+    async fn get_feed(&self, cursor: i64, limit: Option<u64>) -> Outcome<String> {
+        let page_size = self.get_feed_page_size();
+        let limit = limit.unwrap_or(page_size).clamp(1, page_size);
+
+        let mut events = self.revocation_events().since(cursor, limit + 1).await?;
+        let has_more = events.len() as u64 > limit;
+        events.truncate(limit as usize);
+
+        let page = self
+            .revocation_feed()
+            .build_page(
+                cursor,
+                events.into_iter().map(Into::into).collect(),
+                has_more,
+            )
+            .await?;
+        self.revocation_feed().sign_page(&page).await
+    }
+}
+
+/// Business Orchestration Module following the authority's revocation feed.
+///
+/// Every revoked DID loses what this agent cached for it: the bearer token it was handed,
+/// the tokens exchanged with it over GNAP and the verifications it passed.
+#[async_trait]
+pub trait RevocationSubscriberModuleTrait:
+    HasRevocationFeed + RevocationFeedConfigTrait + Send + Sync + 'static
+{
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Returns the repository persisting grants received from participants.
+    fn recv_grants(&self) -> Arc<dyn RecvGrantRepoTrait>;
+
+    /// Returns the repository persisting grants requested from participants.
+    fn sent_grants(&self) -> Arc<dyn SentGrantRepoTrait>;
+
+    /// Returns the repository persisting verification sessions.
+    fn verifications(&self) -> Arc<dyn RecvVerificationRepoTrait>;
+
+    /// Returns the role keeper of an active/standby pair, if the instance takes part in one.
+    ///
+    /// The scheduled job pauses while it reports standby.
+    fn standby_guard(&self) -> Option<Arc<dyn StandbyTrait>> {
+        None
+    }
+
+    /// Opens the unit of work [`invalidate_subject`](Self::invalidate_subject) writes through.
+    ///
+    /// Without one every update commits on its own, and a failure midway leaves the subject
+    /// partly invalidated until the page is retried.
+    async fn begin_transaction(&self) -> Outcome<Option<RepoTransaction>> {
+        Ok(None)
+    }
+
+    /// Drops every token and verification cached for `did`, returning how many were touched.
+    async fn invalidate_subject(&self, did: &str) -> Outcome<u64> {
+        let Some(txn) = self.begin_transaction().await? else {
+            return invalidate_subject(
+                &*self.participants(),
+                &*self.recv_grants(),
+                &*self.sent_grants(),
+                &*self.verifications(),
+                did,
+            )
+            .await;
+        };
+        let repos = txn.repos();
+        let invalidated = invalidate_subject(
+            &*repos.participants,
+            &*repos.recv_grants,
+            &*repos.sent_grants,
+            &*repos.recv_verifications,
+            did,
+        )
+        .await?;
+        txn.commit().await?;
+        Ok(invalidated)
+    }
+
+    /// Ingests every event published since the last poll, returning how many were applied.
+    ///
+    /// The cursor only moves past a page once all its events have been applied, so a failure
+    /// midway makes the next poll retry the page.
+    async fn poll_feed(&self) -> Outcome<usize> {
+        let Some(source) = self.get_feed_source() else {
+            return Ok(0);
+        };
+
+        let mut ingested = 0;
+        loop {
+            let cursor = self.revocation_feed().cursor().await?;
+            let page = self
+                .revocation_feed()
+                .fetch_page(source, cursor, self.get_feed_page_size())
+                .await?;
+
+            for event in &page.events {
+                let invalidated = self.invalidate_subject(&event.subject).await?;
+                info!(
+                    "Revocation of {} ingested, {invalidated} records invalidated",
+                    event.subject
+                );
+            }
+            ingested += page.events.len();
+            self.revocation_feed().set_cursor(page.next_cursor).await?;
+
+            if !page.has_more || page.next_cursor == cursor {
+                break;
+            }
+        }
+        Ok(ingested)
+    }
+}
+
+/// Spawns the feed subscriber, polling the authority immediately and then periodically.
+pub fn spawn_revocation_subscriber(
+    module: Arc<dyn RevocationSubscriberModuleTrait>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(module.get_poll_interval());
+        loop {
+            ticker.tick().await;
+            if on_standby(module.standby_guard()).await {
+                continue;
+            }
+            if let Err(e) = module.poll_feed().await {
+                warn!("Unable to poll the revocation feed: {e}");
+            }
+        }
+    })
+}
+
+async fn invalidate_subject(
+    participants: &dyn ParticipantRepoTrait,
+    recv_grants: &dyn RecvGrantRepoTrait,
+    sent_grants: &dyn SentGrantRepoTrait,
+    verifications: &dyn RecvVerificationRepoTrait,
+    did: &str,
+) -> Outcome<u64> {
+    let mut invalidated = 0;
+
+    for mut participant in participants.get_batch(&[did.to_string()]).await? {
+        for mut grant in recv_grants
+            .filter_by_participant(&participant.participant_nick)
+            .await?
+        {
+            let in_flight = matches!(grant.status, GrantStatus::Processing | GrantStatus::Pending);
+            if grant.token.is_none() && !in_flight {
+                continue;
+            }
+            grant.token = None;
+            if in_flight {
+                grant.status = GrantStatus::Rejected;
+            }
+            recv_grants.update(grant).await?;
+            invalidated += 1;
+        }
+
+        if participant.token.take().is_some() {
+            participants.update(participant).await?;
+            invalidated += 1;
+        }
+    }
+
+    for mut grant in sent_grants.filter_by_participant(did).await? {
+        if grant.clear_tokens() {
+            sent_grants.update(grant).await?;
+            invalidated += 1;
+        }
+    }
+
+    invalidated += verifications.revoke_holder(did).await?;
+    Ok(invalidated)
+}
@@ -0,0 +1,185 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::data::entities::shared::issuance;
+use crate::errors::Outcome;
+use crate::services::repo::traits::received::RecvGrantRepoTrait;
+use crate::services::repo::traits::sent::SentGrantRepoTrait;
+use crate::services::repo::traits::shared::{
+    IssuanceRepoTrait, ParticipantRepoTrait, RpSubscriptionRepoTrait, StatusEntryRepoTrait,
+};
+use crate::types::gnap::{GrantStatus, RequestLifecycle};
+use crate::types::revocation::{ImpactItem, ImpactKind, RevocationImpact};
+use async_trait::async_trait;
+
+/// Business Orchestration Module reporting the blast radius of a revocation.
+///
+/// Every report is a dry run: it reads the ledgers a revocation would touch and changes
+/// nothing, so operators can review what breaks before revoking for real.
+#[async_trait]
+pub trait RevocationImpactModuleTrait: Send + Sync + 'static {
+    /// Returns the repository persisting participants.
+    fn participants(&self) -> Arc<dyn ParticipantRepoTrait>;
+
+    /// Returns the repository persisting grants received from participants.
+    fn recv_grants(&self) -> Arc<dyn RecvGrantRepoTrait>;
+
+    /// Returns the repository persisting grants requested from participants.
+    fn sent_grants(&self) -> Arc<dyn SentGrantRepoTrait>;
+
+    /// Returns the repository persisting issuance sessions.
+    fn issuances(&self) -> Arc<dyn IssuanceRepoTrait>;
+
+    /// Returns the repository persisting relying-party subscriptions.
+    fn rp_subscriptions(&self) -> Arc<dyn RpSubscriptionRepoTrait>;
+
+    /// Returns the repository mapping credentials to list positions.
+    fn status_entries(&self) -> Arc<dyn StatusEntryRepoTrait>;
+
+    /// Reports what revoking the participant `participant_id` would affect.
+    ///
+    /// # Errors
+    /// Returns a missing resource error when the participant is unknown.
+    async fn participant_impact(&self, participant_id: &str) -> Outcome<RevocationImpact> {
+        let participant = self.participants().get_by_id(participant_id).await?;
+        let nick = participant.participant_nick.as_str();
+        let mut impact = RevocationImpact::new(participant_id);
+
+        if participant.token.is_some() {
+            impact.active_tokens.push(ImpactItem::new(
+                ImpactKind::ParticipantToken,
+                participant_id,
+                None,
+            ));
+        }
+
+        for grant in self.recv_grants().filter_by_participant(nick).await? {
+            let detail = Some(format!("{:?}", grant.status));
+            match grant.status {
+                GrantStatus::Processing | GrantStatus::Pending => impact
+                    .in_flight_flows
+                    .push(ImpactItem::new(ImpactKind::ReceivedGrant, grant.id, detail)),
+                GrantStatus::Approved | GrantStatus::Finalized if grant.token.is_some() => impact
+                    .active_tokens
+                    .push(ImpactItem::new(ImpactKind::ReceivedGrant, grant.id, detail)),
+                _ => {}
+            }
+        }
+
+        for grant in self
+            .sent_grants()
+            .filter_by_participant(participant_id)
+            .await?
+        {
+            let detail = Some(format!("{:?}", grant.lifecycle));
+            if !grant.lifecycle.is_terminal() {
+                impact.in_flight_flows.push(ImpactItem::new(
+                    ImpactKind::SentGrant,
+                    grant.id,
+                    detail,
+                ));
+            } else if grant.lifecycle == RequestLifecycle::TokenReceived && grant.holds_token() {
+                impact
+                    .active_tokens
+                    .push(ImpactItem::new(ImpactKind::SentGrant, grant.id, detail));
+            }
+        }
+
+        for session in self.issuances().filter_by_subject(nick).await? {
+            if let Some(item) = pending_issuance(&session) {
+                impact.in_flight_flows.push(item);
+            }
+        }
+
+        for dependent in self.participants().get_dependents(participant_id).await? {
+            impact.sub_identities.push(ImpactItem::new(
+                ImpactKind::SubIdentity,
+                dependent.participant_id,
+                Some(dependent.participant_nick),
+            ));
+        }
+
+        for sub in self
+            .rp_subscriptions()
+            .filter_by_participant(participant_id)
+            .await?
+        {
+            if sub.active {
+                impact.scheduled_jobs.push(ImpactItem::new(
+                    ImpactKind::RpSubscription,
+                    sub.id,
+                    Some(sub.callback_url),
+                ));
+            }
+        }
+
+        Ok(impact)
+    }
+
+    /// Reports what revoking the issued credential `credential_id` would affect.
+    async fn credential_impact(&self, credential_id: &str) -> Outcome<RevocationImpact> {
+        let mut impact = RevocationImpact::new(credential_id);
+
+        for session in self.issuances().filter_by_credential(credential_id).await? {
+            if session.credential.is_none() {
+                impact.active_tokens.push(ImpactItem::new(
+                    ImpactKind::Issuance,
+                    session.id.clone(),
+                    Some("access token can still redeem the credential".to_string()),
+                ));
+            }
+            if let Some(item) = pending_issuance(&session) {
+                impact.in_flight_flows.push(item);
+            }
+        }
+
+        for entry in self
+            .status_entries()
+            .get_all_by_credential(credential_id)
+            .await?
+        {
+            if !entry.flagged {
+                impact.status_entries.push(ImpactItem::new(
+                    ImpactKind::StatusEntry,
+                    entry.id,
+                    Some(format!(
+                        "{} {}#{}",
+                        entry.purpose, entry.list_id, entry.index
+                    )),
+                ));
+            }
+        }
+
+        Ok(impact)
+    }
+}
+
+/// Issuance session still waiting for the wallet to fetch or acknowledge its credential.
+fn pending_issuance(session: &issuance::Model) -> Option<ImpactItem> {
+    let detail = match (&session.credential, &session.notification_event) {
+        (None, _) => "credential not issued yet",
+        (Some(_), None) => "credential not acknowledged by the wallet",
+        (Some(_), Some(_)) => return None,
+    };
+    Some(ImpactItem::new(
+        ImpactKind::Issuance,
+        session.id.clone(),
+        Some(detail.to_string()),
+    ))
+}
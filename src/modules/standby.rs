@@ -0,0 +1,212 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::config::traits::StandbyConfigTrait;
+use crate::data::entities::shared::shared_state;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::traits::shared::SharedStateRepoTrait;
+use crate::services::standby::StandbyTrait;
+use crate::services::{HasIdentity, HasRevocationFeed, HasStandby, HasWallet};
+use crate::types::standby::{
+    InstanceRole, LEADER_STATE_KEY, LeaderLease, SNAPSHOT_STATE_KEY, StandbyStatus, StateSnapshot,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Serialize;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Business Orchestration Module running an instance as part of an active/standby pair.
+///
+/// The active instance keeps publishing its in-memory state to the shared database, which
+/// the standby applies as it goes. Promoting the standby bumps the leader epoch: the former
+/// active finds itself fenced off on its next round and steps down to standby.
+///
+/// A standby instance makes no writes of its own: the
+/// [`standby_write_guard`](crate::http::standby_write_guard) middleware turns away its
+/// mutating requests and the scheduled jobs wired with a `standby_guard` pause until it is
+/// promoted.
+#[async_trait]
+pub trait StandbyModuleTrait:
+    HasStandby
+    + HasWallet
+    + HasIdentity
+    + HasRevocationFeed
+    + StandbyConfigTrait
+    + Send
+    + Sync
+    + 'static
+{
+    /// Returns the repository shared by both instances.
+    fn shared_state(&self) -> Arc<dyn SharedStateRepoTrait>;
+
+    /// Returns the lease of the last promoted instance, if any.
+    async fn leader(&self) -> Outcome<Option<LeaderLease>> {
+        match self.shared_state().find(LEADER_STATE_KEY).await? {
+            Some(model) => Ok(Some(serde_json::from_value(model.value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Publishes the state of the active instance, stepping down if it was fenced off.
+    ///
+    /// A restarted leader recognises its own lease and resumes its epoch.
+    async fn publish_state(&self) -> Outcome<()> {
+        let (role, mut epoch) = self.standby().role().await;
+        if role != InstanceRole::Active {
+            return Ok(());
+        }
+        if let Some(leader) = self.leader().await? {
+            if leader.epoch > epoch && leader.instance_id != self.standby().instance_id() {
+                warn!(
+                    "Instance {} was promoted at epoch {}, stepping down",
+                    leader.instance_id, leader.epoch
+                );
+                self.standby()
+                    .set_role(InstanceRole::Standby, leader.epoch)
+                    .await;
+                return Ok(());
+            }
+            if leader.epoch > epoch {
+                epoch = leader.epoch;
+                self.standby().set_role(InstanceRole::Active, epoch).await;
+            }
+        }
+
+        let snapshot = StateSnapshot {
+            identity: self.wallet().retrieve_default_did().await.ok(),
+            revocation_cursor: self.revocation_feed().cursor().await?,
+            wallet_session: self.wallet().export_session().await,
+            taken_at: Utc::now(),
+        };
+        let plan = state_plan(SNAPSHOT_STATE_KEY, &snapshot, epoch, self.get_instance_id())?;
+        if !self.shared_state().publish(plan).await? {
+            warn!("State snapshot was fenced off, stepping down");
+            self.standby().set_role(InstanceRole::Standby, epoch).await;
+            return Ok(());
+        }
+        self.standby().record_sync(snapshot.taken_at).await;
+        Ok(())
+    }
+
+    /// Applies the latest snapshot published by the active instance.
+    ///
+    /// Returns `false` when nothing was published yet.
+    async fn sync_state(&self) -> Outcome<bool> {
+        let Some(model) = self.shared_state().find(SNAPSHOT_STATE_KEY).await? else {
+            return Ok(false);
+        };
+        let snapshot: StateSnapshot = serde_json::from_value(model.value)?;
+        if let Some(did) = &snapshot.identity {
+            self.identity().save_identity(did).await?;
+        }
+        if let Some(session) = snapshot.wallet_session {
+            self.wallet().resume_session(session).await?;
+        }
+        self.revocation_feed()
+            .set_cursor(snapshot.revocation_cursor)
+            .await?;
+        self.standby().record_sync(snapshot.taken_at).await;
+        Ok(true)
+    }
+
+    /// Promotes this instance to active, after a last catch-up with the published state.
+    ///
+    /// Promoting the active instance is a no-op.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when another instance was promoted concurrently.
+    async fn promote(&self) -> Outcome<StandbyStatus> {
+        let (role, _) = self.standby().role().await;
+        if role == InstanceRole::Active {
+            return self.status().await;
+        }
+
+        self.sync_state().await?;
+        let epoch = self.leader().await?.map_or(0, |lease| lease.epoch) + 1;
+        let lease = LeaderLease {
+            instance_id: self.get_instance_id().to_string(),
+            epoch,
+            promoted_at: Utc::now(),
+        };
+        let plan = state_plan(LEADER_STATE_KEY, &lease, epoch, self.get_instance_id())?;
+        if !self.shared_state().claim(plan).await? {
+            return Err(Errors::forbidden(
+                "Another instance was promoted concurrently",
+                None,
+            ));
+        }
+        self.standby().set_role(InstanceRole::Active, epoch).await;
+        info!("Instance {} promoted at epoch {epoch}", lease.instance_id);
+        self.status().await
+    }
+
+    /// Returns the role of this instance and the current leader.
+    async fn status(&self) -> Outcome<StandbyStatus> {
+        let (role, epoch) = self.standby().role().await;
+        Ok(StandbyStatus {
+            instance_id: self.get_instance_id().to_string(),
+            role,
+            epoch,
+            leader: self.leader().await?.map(|lease| lease.instance_id),
+            last_sync_at: self.standby().last_sync().await,
+        })
+    }
+}
+
+/// Spawns the periodic handoff: the active instance publishes, the standby applies.
+pub fn spawn_standby_handoff(module: Arc<dyn StandbyModuleTrait>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(module.get_handoff_interval());
+        loop {
+            ticker.tick().await;
+            let result = match module.standby().role().await {
+                (InstanceRole::Active, _) => module.publish_state().await,
+                (InstanceRole::Standby, _) => module.sync_state().await.map(|_| ()),
+            };
+            if let Err(e) = result {
+                warn!("Standby state handoff failed: {e}");
+            }
+        }
+    })
+}
+
+/// Tells whether the scheduled job holding `guard` must pause, this instance being on standby.
+pub(crate) async fn on_standby(guard: Option<Arc<dyn StandbyTrait>>) -> bool {
+    match guard {
+        Some(standby) => standby.role().await.0 == InstanceRole::Standby,
+        None => false,
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn state_plan<T: Serialize>(
+    key: &str,
+    value: &T,
+    epoch: i64,
+    instance_id: &str,
+) -> Outcome<shared_state::Plan> {
+    Ok(shared_state::Plan {
+        key: key.to_string(),
+        value: serde_json::to_value(value)?,
+        epoch,
+        instance_id: instance_id.to_string(),
+    })
+}
@@ -0,0 +1,108 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::data::entities::shared::{status_entry, status_list};
+use crate::errors::{Errors, Outcome};
+use crate::services::HasStatusList;
+use crate::services::repo::traits::shared::{StatusEntryRepoTrait, StatusListRepoTrait};
+use crate::types::issuance::StatusPurpose;
+use crate::types::vcs::doc::VCStatus;
+use async_trait::async_trait;
+
+/// Business Orchestration Module for credential status (Bitstring Status List).
+///
+/// Allocates a list position for every issued credential, publishes the signed lists and
+/// flips bits when an administrator revokes, suspends or reinstates a credential.
+#[async_trait]
+pub trait StatusListModuleTrait: HasStatusList + Send + Sync + 'static {
+    /// Returns the repository persisting status lists.
+    fn status_lists(&self) -> Arc<dyn StatusListRepoTrait>;
+
+    /// Returns the repository mapping credentials to list positions.
+    fn status_entries(&self) -> Arc<dyn StatusEntryRepoTrait>;
+
+    // ===== ISSUANCE ==============================================================================
+
+    /// Reserves a list position for `credential_id` and returns the `credentialStatus` entry
+    /// to embed in the credential before signing it.
+    async fn allocate_status(
+        &self,
+        credential_id: &str,
+        purpose: StatusPurpose,
+    ) -> Outcome<VCStatus> {
+        let (list, index) = self.status_lists().allocate(purpose).await?;
+        self.status_entries()
+            .create(status_entry::Plan {
+                credential_id: credential_id.to_string(),
+                list_id: list.id.clone(),
+                index,
+                purpose,
+            })
+            .await?;
+        Ok(self.status_list().build_entry(&list, index))
+    }
+
+    // ===== PUBLICATION ===========================================================================
+
+    /// Returns the signed `BitstringStatusListCredential` for `list_id`.
+    async fn get_status_list(&self, list_id: &str) -> Outcome<String> {
+        let list: status_list::Model = self.status_lists().get_by_id(list_id).await?;
+        self.status_list().sign_status_list(&list).await
+    }
+
+    // ===== ADMINISTRATION ========================================================================
+
+    /// Sets (`flagged = true`) or clears the status bit of an issued credential.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when trying to clear a revocation, which is final.
+    async fn update_status(
+        &self,
+        credential_id: &str,
+        purpose: StatusPurpose,
+        flagged: bool,
+    ) -> Outcome<status_entry::Model> {
+        let mut entry = self
+            .status_entries()
+            .get_by_credential(credential_id, purpose)
+            .await?;
+        if purpose == StatusPurpose::Revocation && entry.flagged && !flagged {
+            return Err(Errors::forbidden(
+                "A revoked credential cannot be reinstated",
+                None,
+            ));
+        }
+
+        self.status_lists()
+            .set_bit(&entry.list_id, entry.index, flagged)
+            .await?;
+        entry.flagged = flagged;
+        self.status_entries().update(entry).await
+    }
+
+    /// Returns every status entry allocated to a credential.
+    async fn get_credential_status(
+        &self,
+        credential_id: &str,
+    ) -> Outcome<Vec<status_entry::Model>> {
+        self.status_entries()
+            .get_all_by_credential(credential_id)
+            .await
+    }
+}
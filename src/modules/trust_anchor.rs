@@ -0,0 +1,176 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::config::traits::TrustAnchorConfigTrait;
+use crate::config::types::TrustRegistry;
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::modules::standby::on_standby;
+use crate::services::HasTrustAnchor;
+use crate::services::repo::RepoTransaction;
+use crate::services::repo::traits::shared::TrustedIssuerRepoTrait;
+use crate::services::standby::StandbyTrait;
+use crate::types::verification::TrustSyncStatus;
+use crate::utils::Clock;
+use async_trait::async_trait;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Business Orchestration Module syncing the trusted issuer registry with external anchors.
+///
+/// Every configured registry owns the registrations tagged with its URL: a sync round adds
+/// the issuers it newly lists and drops the ones it no longer does, leaving manual
+/// registrations untouched. A registry suddenly listing nobody is treated as a failed round
+/// rather than a request to drop every issuer it had listed.
+#[async_trait]
+pub trait TrustAnchorModuleTrait:
+    HasTrustAnchor + TrustAnchorConfigTrait + Send + Sync + 'static
+{
+    /// Returns the repository persisting issuer registrations.
+    fn trusted_issuers(&self) -> Arc<dyn TrustedIssuerRepoTrait>;
+
+    /// Returns the role keeper of an active/standby pair, if the instance takes part in one.
+    ///
+    /// The scheduled job pauses while it reports standby.
+    fn standby_guard(&self) -> Option<Arc<dyn StandbyTrait>> {
+        None
+    }
+
+    /// Opens the unit of work a sync round writes its registrations through.
+    ///
+    /// Without one every registration and its audit entry commit on their own, and a round
+    /// failing midway leaves the registry partly applied.
+    async fn begin_transaction(&self) -> Outcome<Option<RepoTransaction>> {
+        Ok(None)
+    }
+
+    /// Merges the issuers listed by `registry` into the local store.
+    async fn sync_registry(&self, registry: &TrustRegistry) -> Outcome<TrustSyncStatus> {
+        let previous = self
+            .trust_anchor()
+            .sync_status()
+            .await
+            .into_iter()
+            .find(|status| status.registry == registry.url);
+        let mut status = TrustSyncStatus {
+            registry: registry.url.clone(),
+            last_attempt_at: Clock::now(),
+            last_success_at: previous.as_ref().and_then(|s| s.last_success_at),
+            issuers: previous.as_ref().map_or(0, |s| s.issuers),
+            added: 0,
+            removed: 0,
+            last_error: None,
+        };
+
+        let result: Outcome<()> = async {
+            let remote = self.trust_anchor().fetch_issuers(registry).await?;
+            let txn = self.begin_transaction().await?;
+            let issuers = match &txn {
+                Some(txn) => txn.repos().trusted_issuers.clone(),
+                None => self.trusted_issuers(),
+            };
+            let local = issuers.get_by_source(&registry.url).await?;
+            if remote.is_empty() && !local.is_empty() {
+                return Err(Errors::format(
+                    BadFormat::Received,
+                    "Trust registry listed no issuers, keeping the previous sync",
+                    None,
+                ));
+            }
+
+            let remote_keys: HashSet<_> = remote
+                .iter()
+                .map(|p| (p.issuer_did.clone(), p.vc_type.clone()))
+                .collect();
+            let local_keys: HashSet<_> = local
+                .iter()
+                .map(|m| (m.issuer_did.clone(), m.vc_type.clone()))
+                .collect();
+
+            for model in local
+                .into_iter()
+                .filter(|m| !remote_keys.contains(&(m.issuer_did.clone(), m.vc_type.clone())))
+            {
+                issuers.delete(&model.id).await?;
+                status.removed += 1;
+            }
+            let mut seen = HashSet::new();
+            for plan in remote {
+                let key = (plan.issuer_did.clone(), plan.vc_type.clone());
+                if local_keys.contains(&key) || !seen.insert(key) {
+                    continue;
+                }
+                issuers.create(plan).await?;
+                status.added += 1;
+            }
+            drop(issuers);
+            if let Some(txn) = txn {
+                txn.commit().await?;
+            }
+
+            status.issuers = remote_keys.len();
+            status.last_success_at = Some(status.last_attempt_at);
+            Ok(())
+        }
+        .await;
+
+        if let Err(e) = &result {
+            status.last_error = Some(e.to_string());
+        }
+        self.trust_anchor().record_status(status.clone()).await;
+        result.map(|()| status)
+    }
+
+    /// Syncs every configured registry, one failing registry not blocking the others.
+    async fn sync_all(&self) -> Vec<TrustSyncStatus> {
+        let mut statuses = Vec::new();
+        for registry in self.get_registries() {
+            match self.sync_registry(registry).await {
+                Ok(status) => {
+                    info!(
+                        "Trust registry {} synced: +{} -{}",
+                        registry.url, status.added, status.removed
+                    );
+                    statuses.push(status);
+                }
+                Err(e) => warn!("Trust registry {} sync failed: {e}", registry.url),
+            }
+        }
+        statuses
+    }
+
+    /// Returns the latest sync outcome of every registry.
+    async fn sync_status(&self) -> Vec<TrustSyncStatus> {
+        self.trust_anchor().sync_status().await
+    }
+}
+
+/// Spawns the periodic sync job, running a first round immediately.
+pub fn spawn_trust_anchor_sync(module: Arc<dyn TrustAnchorModuleTrait>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(module.get_sync_interval());
+        loop {
+            ticker.tick().await;
+            if on_standby(module.standby_guard()).await {
+                continue;
+            }
+            module.sync_all().await;
+        }
+    })
+}
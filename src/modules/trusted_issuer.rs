@@ -0,0 +1,120 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use crate::data::entities::received::verification;
+use crate::data::entities::shared::trusted_issuer::{Model, Plan};
+use crate::errors::{Errors, Outcome};
+use crate::services::HasVerifier;
+use crate::services::repo::traits::shared::TrustedIssuerRepoTrait;
+use crate::types::jwt::{Jwt, VCJwtClaims};
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::verification::{TrustedIssuerFilter, VerificationStatus};
+use async_trait::async_trait;
+use tracing::info;
+
+/// Business Orchestration Module for the trusted issuer registry.
+///
+/// Keeps the list of issuer DIDs allowed to issue each credential type and rejects
+/// presentations carrying credentials from anyone else.
+#[async_trait]
+pub trait TrustedIssuerModuleTrait: HasVerifier + Send + Sync + 'static {
+    /// Returns the repository persisting issuer registrations.
+    fn trusted_issuers(&self) -> Arc<dyn TrustedIssuerRepoTrait>;
+
+    // ===== ADMINISTRATION ========================================================================
+
+    /// Lists one page of the issuer registrations matching `filter`.
+    async fn list_trusted_issuers(
+        &self,
+        filter: &TrustedIssuerFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<Model>> {
+        self.trusted_issuers().search(filter, request).await
+    }
+
+    /// Retrieves a single issuer registration.
+    async fn get_trusted_issuer(&self, id: &str) -> Outcome<Model> {
+        self.trusted_issuers().get_by_id(id).await
+    }
+
+    /// Registers an issuer DID, optionally scoped to a single credential type.
+    async fn register_trusted_issuer(&self, plan: Plan) -> Outcome<Model> {
+        info!("Registering trusted issuer {}", plan.issuer_did);
+        self.trusted_issuers().create(plan).await
+    }
+
+    /// Removes an issuer registration.
+    async fn remove_trusted_issuer(&self, id: &str) -> Outcome<()> {
+        self.trusted_issuers().delete(id).await
+    }
+
+    // ===== ENFORCEMENT ===========================================================================
+
+    /// Checks that every credential was issued by an issuer registered for its type.
+    ///
+    /// Credentials without a specialized type are only accepted from issuers registered
+    /// without a type restriction.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::SecurityError`] on the first credential from an untrusted issuer.
+    async fn check_trusted_issuers(&self, vcs: &[String]) -> Outcome<()> {
+        for vc in vcs {
+            let claims: VCJwtClaims = Jwt::parse(vc)?.unsafe_claims()?;
+            let doc = claims.vc_doc();
+            let issuer = doc.issuer_did();
+            let registrations = self.trusted_issuers().get_by_issuer(issuer).await?;
+
+            let trusted = match doc.specialized_type() {
+                Some(vc_type) => registrations.iter().any(|r| r.covers(&vc_type)),
+                None => registrations.iter().any(|r| r.vc_type.is_none()),
+            };
+            if !trusted {
+                return Err(Errors::security(
+                    format!("Issuer {issuer} is not trusted for credential {}", doc.id()),
+                    None,
+                ));
+            }
+        }
+        info!("Every VC issuer is trusted");
+        Ok(())
+    }
+
+    /// Runs the full presentation verification and then enforces the registry.
+    ///
+    /// The verification moves to `Verified` only once both passed, and to `Failed` otherwise.
+    async fn verify_trusted(
+        &self,
+        verification_model: &mut verification::Model,
+        vp_token: &str,
+        presentation_submission: Option<&str>,
+    ) -> Outcome<()> {
+        let mut result = self
+            .verifier()
+            .verify_all(verification_model, vp_token, presentation_submission)
+            .await;
+        if result.is_ok() {
+            result = self.check_trusted_issuers(&verification_model.vcs).await;
+        }
+        verification_model.transition(match result {
+            Ok(()) => VerificationStatus::Verified,
+            Err(_) => VerificationStatus::Failed,
+        })?;
+        result
+    }
+}
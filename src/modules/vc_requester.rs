@@ -0,0 +1,755 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::data::entities::sent::{grant, interaction};
+use crate::data::entities::wallet::vc;
+use crate::errors::{Errors, Outcome};
+use crate::modules::standby::on_standby;
+use crate::services::repo::RepoTransaction;
+use crate::services::repo::traits::sent::{SentGrantRepoTrait, SentInteractionRepoTrait};
+use crate::services::repo::traits::shared::TrustedIssuerRepoTrait;
+use crate::services::standby::StandbyTrait;
+use crate::services::{HasVcRequester, HasWallet};
+use crate::types::gnap::access_token::StoredToken;
+use crate::types::gnap::grant_request::access::AccessTokenRequests;
+use crate::types::gnap::grant_request::client::Client;
+use crate::types::gnap::grant_request::interact::{
+    FinishMethod, HashMethod, InteractAction, InteractStart,
+};
+use crate::types::gnap::grant_request::{GrantKind, GrantRequest};
+use crate::types::gnap::grant_response::{Continuation, GrantResponse, GrantResponseKind};
+use crate::types::gnap::{CallbackBody, GrantStatus, RequestLifecycle};
+use crate::types::vcs::{VcFormat, VcType, VcTypeConfig};
+use crate::types::wallet::{CredentialRefreshEvent, RefreshOutcome};
+use crate::utils::Clock;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, sleep};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Business Orchestration Module asking remote authorities to issue credentials to us.
+///
+/// A request runs the whole holder side of the exchange: the signed GNAP grant request, the
+/// OID4VP presentation the authority asks for, the finish callback, the continuation call and
+/// finally the OID4VCI redemption into the wallet. The sent grant and interaction ids are the
+/// flow id handed back to the caller.
+#[async_trait]
+pub trait VcRequesterModuleTrait: HasWallet + HasVcRequester + Send + Sync + 'static {
+    /// Returns the repository persisting the grants sent to authorities.
+    fn sent_grants(&self) -> Arc<dyn SentGrantRepoTrait>;
+
+    /// Returns the repository persisting the interactions of those grants.
+    fn sent_interactions(&self) -> Arc<dyn SentInteractionRepoTrait>;
+
+    /// Returns the public URI the authority pushes the finish callback of flow `id` to.
+    fn callback_uri(&self, id: &str) -> String;
+
+    /// Returns the role keeper of an active/standby pair, if the instance takes part in one.
+    ///
+    /// The scheduled job pauses while it reports standby.
+    fn standby_guard(&self) -> Option<Arc<dyn StandbyTrait>> {
+        None
+    }
+
+    /// Returns the registry the issuers of received credentials are checked against.
+    ///
+    /// Without one, the offer is only checked against what was requested.
+    fn issuer_allow_list(&self) -> Option<Arc<dyn TrustedIssuerRepoTrait>> {
+        None
+    }
+
+    /// Opens the unit of work a flow's grant and interaction are written through together.
+    ///
+    /// Without one they are stored one after the other, and a crash in between leaves a
+    /// grant without its interaction.
+    async fn begin_transaction(&self) -> Outcome<Option<RepoTransaction>> {
+        Ok(None)
+    }
+
+    /// Returns the path of the grant endpoint, appended to the authority base URL.
+    fn get_grant_path(&self) -> String {
+        "/api/v1/gnap/grant".to_string()
+    }
+
+    /// Returns the method the authority must use for the interaction finish hash.
+    fn get_hash_method(&self) -> HashMethod {
+        HashMethod::Sha256
+    }
+
+    /// Returns how often a flow waiting on the authority is looked at again.
+    fn get_poll_interval(&self) -> Duration {
+        Duration::from_secs(5)
+    }
+
+    /// Returns how long a flow may take before it is given up.
+    fn get_flow_timeout(&self) -> Duration {
+        Duration::from_secs(10 * 60)
+    }
+
+    /// Returns how long before `validUntil` a stored credential is renewed.
+    fn get_refresh_window(&self) -> Duration {
+        Duration::from_secs(7 * 24 * 60 * 60)
+    }
+
+    /// Returns how often stored credentials are checked for renewal.
+    fn get_refresh_interval(&self) -> Duration {
+        Duration::from_secs(60 * 60)
+    }
+
+    /// Returns the URLs notified of every renewal outcome.
+    fn get_refresh_webhooks(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Returns how long before its expiry an access token is rotated.
+    fn get_token_refresh_margin(&self) -> Duration {
+        Duration::from_secs(60)
+    }
+
+    // ===== FLOW STEPS ============================================================================
+
+    /// Sends the grant request for a `vc_type` credential to the authority at `authority_url`.
+    ///
+    /// The returned grant is the flow handle; the remaining steps are run by
+    /// [`drive_credential_request`](Self::drive_credential_request).
+    async fn start_credential_request(
+        &self,
+        authority_url: &str,
+        vc_type: VcType,
+    ) -> Outcome<grant::Model> {
+        let vc_type_config = vec![VcTypeConfig::new(vc_type, VcFormat::JwtVcJson)];
+        let requested = vc_type_config.clone();
+        self.send_grant_request(
+            authority_url,
+            GrantKind::CredentialRequest,
+            Some(vc_type_config),
+            move |client, interaction| GrantRequest::new_vc(client, requested, interaction),
+        )
+        .await
+    }
+
+    /// Sends a grant request for access tokens to the authority at `authority_url`.
+    ///
+    /// One token is asked for per `(label, actions)` pair, later picked by its label with
+    /// [`access_token`](Self::access_token).
+    ///
+    /// # Errors
+    /// Returns a format error, before anything is stored or sent, when a label is repeated.
+    async fn start_token_request(
+        &self,
+        authority_url: &str,
+        tokens: Vec<(String, Vec<InteractAction>)>,
+    ) -> Outcome<grant::Model> {
+        let access_token = AccessTokenRequests::labeled(tokens);
+        access_token.check_labels()?;
+        self.send_grant_request(
+            authority_url,
+            GrantKind::AccessToken,
+            None,
+            move |client, interaction| GrantRequest::new_tokens(client, access_token, interaction),
+        )
+        .await
+    }
+
+    /// Stores a new flow towards the authority at `authority_url` and sends the grant request
+    /// `build` makes for it.
+    async fn send_grant_request<F>(
+        &self,
+        authority_url: &str,
+        kind: GrantKind,
+        vc_type_config: Option<Vec<VcTypeConfig>>,
+        build: F,
+    ) -> Outcome<grant::Model>
+    where
+        F: FnOnce(Client, &interaction::Model) -> GrantRequest + Send,
+    {
+        let id = Uuid::new_v4().to_string();
+        let authority = authority_url.trim_end_matches('/');
+        let grant_endpoint = format!("{authority}{}", self.get_grant_path());
+
+        let interaction = interaction::Plan {
+            id: id.clone(),
+            start: vec![InteractStart::Oid4VP],
+            method: FinishMethod::Push,
+            callback_uri: self.callback_uri(&id),
+            hash_method: Some(self.get_hash_method()),
+            hints: None,
+        };
+        let grant = grant::Plan {
+            id: id.clone(),
+            participant_id: authority.to_string(),
+            participant_nick: authority.to_string(),
+            vc_type_config,
+            grant_endpoint: grant_endpoint.clone(),
+            kind,
+            auto: Some(true),
+        };
+        let (grant, interaction) = match self.begin_transaction().await? {
+            Some(txn) => {
+                let repos = txn.repos();
+                let flow = create_flow(
+                    &*repos.sent_grants,
+                    &*repos.sent_interactions,
+                    grant,
+                    interaction,
+                )
+                .await?;
+                txn.commit().await?;
+                flow
+            }
+            None => {
+                create_flow(
+                    &*self.sent_grants(),
+                    &*self.sent_interactions(),
+                    grant,
+                    interaction,
+                )
+                .await?
+            }
+        };
+
+        info!("Requesting {} from {authority} in flow {id}", grant.kind);
+        let client = self.vc_requester().client().await?;
+        let request = build(client, &interaction);
+        let response = self
+            .vc_requester()
+            .request_grant(&grant_endpoint, &request)
+            .await?;
+        self.apply_grant_response(grant, interaction, response)
+            .await
+    }
+
+    /// Records the finish callback pushed by the authority for flow `id`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the flow is not waiting for its callback.
+    async fn record_credential_callback(
+        &self,
+        id: &str,
+        body: CallbackBody,
+    ) -> Outcome<grant::Model> {
+        let mut grant = self.sent_grants().get_by_id(id).await?;
+        match body {
+            CallbackBody::Approved(approved) => {
+                let mut interaction = self.sent_interactions().get_by_id(id).await?;
+                interaction.record_callback(
+                    approved.interact_ref,
+                    approved.hash,
+                    &grant.grant_endpoint,
+                )?;
+                let grant = self
+                    .sent_grants()
+                    .advance(grant, RequestLifecycle::CallbackReceived)
+                    .await?;
+                self.sent_interactions().update(interaction).await?;
+                Ok(grant)
+            }
+            CallbackBody::Rejected(rejected) => {
+                warn!("Credential request {id} rejected: {}", rejected.rejected);
+                grant.status = GrantStatus::Rejected;
+                self.sent_grants()
+                    .advance(grant, RequestLifecycle::Denied)
+                    .await
+            }
+        }
+    }
+
+    /// Calls the continuation URI of flow `id` and applies the authority's answer.
+    ///
+    /// The interaction reference is only sent on the first call after the callback.
+    async fn continue_credential_request(&self, id: &str) -> Outcome<grant::Model> {
+        let grant = self.sent_grants().get_by_id(id).await?;
+        let interaction = self.sent_interactions().get_by_id(id).await?;
+        let (Some(uri), Some(token)) = (
+            interaction.continue_endpoint.clone(),
+            interaction.continue_token.clone(),
+        ) else {
+            return Err(Errors::forbidden(
+                format!("Credential request {id} cannot be continued"),
+                None,
+            ));
+        };
+        let interact_ref = match grant.lifecycle {
+            RequestLifecycle::CallbackReceived => interaction.interact_ref.clone(),
+            _ => None,
+        };
+
+        let response = self
+            .vc_requester()
+            .continue_grant(&uri, &token, interact_ref.as_deref())
+            .await?;
+        let grant = self
+            .sent_grants()
+            .advance(grant, RequestLifecycle::Continued)
+            .await?;
+        self.apply_grant_response(grant, interaction, response)
+            .await
+    }
+
+    /// Rotates an access token of flow `id` through its token management URI.
+    ///
+    /// `label` picks one of the tokens of a multiple token request; `None` is the single token.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the authority issued no rotatable token.
+    async fn refresh_access_token(&self, id: &str, label: Option<&str>) -> Outcome<grant::Model> {
+        let mut grant = self.sent_grants().get_by_id(id).await?;
+        let stored = grant.stored_token(label);
+        let Some((uri, token)) = stored.and_then(|s| s.manage_uri.zip(s.manage_token)) else {
+            return Err(Errors::forbidden(
+                format!("Access token of {id} cannot be rotated"),
+                None,
+            ));
+        };
+        let issued_at = Clock::now();
+        let rotated = self.vc_requester().rotate_token(&uri, &token).await?;
+        grant.put_token(label, StoredToken::new(rotated, issued_at));
+        self.sent_grants().update(grant).await
+    }
+
+    /// Returns an access token of flow `id`, rotating it first when it is about to expire.
+    ///
+    /// `label` picks one of the tokens of a multiple token request; `None` is the single token.
+    async fn access_token(&self, id: &str, label: Option<&str>) -> Outcome<String> {
+        let margin =
+            chrono::Duration::from_std(self.get_token_refresh_margin()).unwrap_or_default();
+        let grant = self.sent_grants().get_by_id(id).await?;
+        let mut stored = grant.stored_token(label);
+        if stored.as_ref().is_some_and(|s| s.expiring(margin)) {
+            stored = self
+                .refresh_access_token(id, label)
+                .await?
+                .stored_token(label);
+        }
+        stored.map(|s| s.value).ok_or_else(|| {
+            Errors::missing_resource(id, format!("Grant {id} holds no such access token"), None)
+        })
+    }
+
+    /// Continues flow `id`, backing off as long as the authority asks when polled too fast.
+    async fn poll_continuation(&self, id: &str) -> Outcome<()> {
+        match self.continue_credential_request(id).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                let Some(secs) = e.retry_after() else {
+                    return Err(e);
+                };
+                info!("Credential request {id} polled too fast, waiting {secs}s");
+                sleep(Duration::from_secs(secs)).await;
+                Ok(())
+            }
+        }
+    }
+
+    /// Checks the credential offer at `uri`, answering `grant`, and redeems it.
+    ///
+    /// The issuer metadata must match the offer and the offer may only carry configurations
+    /// the grant asked for. Every credential received must then be signed by an issuer on the
+    /// [allow list](Self::issuer_allow_list) before it is stored.
+    async fn redeem_credential_offer(&self, grant: &grant::Model, uri: &str) -> Outcome<()> {
+        let offer = self.wallet().resolve_oid4vci_offer(uri).await?;
+        offer.check_requested(grant.vc_type_config.as_deref().unwrap_or_default())?;
+        let registry = self.issuer_allow_list();
+        self.wallet()
+            .redeem_oid4vci_offer(uri, offer, None, registry.as_deref())
+            .await
+    }
+
+    /// Stores the outcome of a grant or continuation call and moves the flow on.
+    ///
+    /// The authority rotates the continue token on every call, so the one it returns is
+    /// persisted before anything else can fail; a response without continuation leaves
+    /// nothing to continue with. An approved credential response is vetted and redeemed
+    /// into the wallet right away.
+    async fn apply_grant_response(
+        &self,
+        mut grant: grant::Model,
+        mut interaction: interaction::Model,
+        response: GrantResponse,
+    ) -> Outcome<grant::Model> {
+        let waiting = match grant.lifecycle {
+            RequestLifecycle::Created => RequestLifecycle::InteractionStarted,
+            _ => RequestLifecycle::Continued,
+        };
+        store_continuation(&mut interaction, response.continuation());
+        let mut interaction = self.sent_interactions().update(interaction).await?;
+
+        let next = match response {
+            GrantResponse::Pending(pending) => {
+                interaction.as_nonce = pending.interact.finish;
+                interaction.oidc_vp_uri = pending.interact.oid4vp;
+                grant.as_assigned_id = pending.instance_id.or(grant.as_assigned_id);
+                grant.status = GrantStatus::Pending;
+                waiting
+            }
+            GrantResponse::Processing(processing) => {
+                grant.as_assigned_id = processing.instance_id.or(grant.as_assigned_id);
+                grant.status = GrantStatus::Processing;
+                waiting
+            }
+            GrantResponse::Approved(approved) => {
+                grant.as_assigned_id = approved.instance_id.or(grant.as_assigned_id);
+                if let Some(subject) = &approved.subject {
+                    grant.sub_ids = Some(subject.ids());
+                }
+                match approved.kind {
+                    GrantResponseKind::CredentialResponse {
+                        credential_response,
+                    } => {
+                        self.redeem_credential_offer(&grant, &credential_response.credential_uri)
+                            .await?;
+                        grant.vc_uri = Some(credential_response.credential_uri);
+                        grant.status = GrantStatus::Finalized;
+                    }
+                    GrantResponseKind::AccessToken { access_token } => {
+                        grant.store_tokens(access_token, Clock::now());
+                        grant.status = GrantStatus::Approved;
+                    }
+                }
+                RequestLifecycle::TokenReceived
+            }
+            GrantResponse::Error(error) => {
+                warn!("Credential request {} refused: {:?}", grant.id, error.error);
+                grant.status = GrantStatus::Rejected;
+                RequestLifecycle::Denied
+            }
+        };
+
+        let Some(txn) = self.begin_transaction().await? else {
+            return save_flow(
+                &*self.sent_grants(),
+                &*self.sent_interactions(),
+                grant,
+                interaction,
+                next,
+            )
+            .await;
+        };
+        let repos = txn.repos();
+        let grant = save_flow(
+            &*repos.sent_grants,
+            &*repos.sent_interactions,
+            grant,
+            interaction,
+            next,
+        )
+        .await?;
+        txn.commit().await?;
+        Ok(grant)
+    }
+
+    /// Runs flow `id` until the credential lands in the wallet or the flow ends otherwise.
+    ///
+    /// The presentation the authority asks for is answered once; the flow then waits for
+    /// the finish callback and keeps calling the continuation URI while the authority is
+    /// still processing. Flows past [`get_flow_timeout`](Self::get_flow_timeout) are denied.
+    async fn drive_credential_request(&self, id: &str) -> Outcome<grant::Model> {
+        let deadline = Instant::now() + self.get_flow_timeout();
+        let mut presented = false;
+        loop {
+            let mut grant = self.sent_grants().get_by_id(id).await?;
+            if grant.lifecycle.is_terminal() {
+                return Ok(grant);
+            }
+            if Instant::now() >= deadline {
+                warn!("Credential request {id} timed out");
+                grant.status = GrantStatus::Rejected;
+                return self
+                    .sent_grants()
+                    .advance(grant, RequestLifecycle::Denied)
+                    .await;
+            }
+
+            match grant.lifecycle {
+                RequestLifecycle::InteractionStarted if !presented => {
+                    let interaction = self.sent_interactions().get_by_id(id).await?;
+                    if let Some(uri) = &interaction.oidc_vp_uri {
+                        self.wallet().process_oid4vp(uri).await?;
+                    }
+                    presented = true;
+                }
+                RequestLifecycle::CallbackReceived => {
+                    self.poll_continuation(id).await?;
+                }
+                RequestLifecycle::Continued => {
+                    let interaction = self.sent_interactions().get_by_id(id).await?;
+                    let wait = interaction
+                        .continue_wait
+                        .map(|secs| Duration::from_secs(secs.max(0) as u64))
+                        .unwrap_or(self.get_poll_interval());
+                    sleep(wait).await;
+                    self.poll_continuation(id).await?;
+                }
+                _ => sleep(self.get_poll_interval()).await,
+            }
+        }
+    }
+
+    /// Gives up flow `id`, leaving it denied.
+    async fn abandon_credential_request(&self, id: &str) -> Outcome<grant::Model> {
+        let mut grant = self.sent_grants().get_by_id(id).await?;
+        if grant.lifecycle.is_terminal() {
+            return Ok(grant);
+        }
+        grant.status = GrantStatus::Rejected;
+        self.sent_grants()
+            .advance(grant, RequestLifecycle::Denied)
+            .await
+    }
+
+    /// Returns the current state of flow `id`.
+    async fn credential_request(&self, id: &str) -> Outcome<grant::Model> {
+        self.sent_grants().get_by_id(id).await
+    }
+
+    // ===== CREDENTIAL REFRESH ====================================================================
+
+    /// Lists the stored credentials expiring within the [refresh window](Self::get_refresh_window).
+    ///
+    /// A credential is left out once another one of its type outlives the window, so a
+    /// renewal whose old credential could not be removed is not requested twice.
+    async fn expiring_credentials(&self) -> Outcome<Vec<vc::Model>> {
+        let window =
+            chrono::Duration::from_std(self.get_refresh_window()).unwrap_or(chrono::Duration::MAX);
+        let threshold = Clock::now()
+            .checked_add_signed(window)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        let vcs = self.wallet().retrieve_all_vcs().await?;
+
+        let expiring = vcs
+            .iter()
+            .filter(|vc| vc.valid_until.is_some_and(|until| until <= threshold))
+            .filter(|vc| {
+                !vcs.iter().any(|other| {
+                    other.vc_type == vc.vc_type
+                        && other.valid_until.is_none_or(|until| until > threshold)
+                })
+            })
+            .cloned()
+            .collect();
+        Ok(expiring)
+    }
+
+    /// Returns the authority `vc` came from: the one of the latest finalized credential
+    /// request for its type.
+    async fn credential_authority(&self, vc: &vc::Model) -> Outcome<Option<String>> {
+        let grants = self
+            .sent_grants()
+            .filter_by_type(GrantKind::CredentialRequest)
+            .await?;
+        Ok(grants
+            .into_iter()
+            .filter(|grant| grant.status == GrantStatus::Finalized)
+            .filter(|grant| {
+                grant
+                    .vc_type_config
+                    .iter()
+                    .flatten()
+                    .any(|config| config.vc_type() == &vc.vc_type)
+            })
+            .max_by_key(|grant| grant.created_at)
+            .map(|grant| grant.participant_id))
+    }
+
+    /// Requests a fresh copy of `vc` from its authority and replaces it in the wallet.
+    ///
+    /// The old credential is only deleted once the new one is stored. Either way the
+    /// outcome is pushed to the [refresh webhooks](Self::get_refresh_webhooks).
+    async fn refresh_credential(&self, vc: &vc::Model) -> Outcome<CredentialRefreshEvent> {
+        let authority = self.credential_authority(vc).await?;
+        let started_at = Clock::now();
+        let mut flow_id = None;
+
+        let result: Outcome<(String, Vec<String>)> = async {
+            let authority = authority.as_deref().ok_or_else(|| {
+                Errors::missing_resource(
+                    &vc.id,
+                    format!(
+                        "No credential request for {} was ever finalized",
+                        vc.vc_type
+                    ),
+                    None,
+                )
+            })?;
+            let grant = self
+                .start_credential_request(authority, vc.vc_type.clone())
+                .await?;
+            flow_id = Some(grant.id.clone());
+            let grant = match self.drive_credential_request(&grant.id).await {
+                Ok(grant) => grant,
+                Err(e) => {
+                    self.abandon_credential_request(&grant.id).await?;
+                    return Err(e);
+                }
+            };
+            if grant.status != GrantStatus::Finalized {
+                return Err(Errors::forbidden(
+                    format!(
+                        "Credential request {} ended as {:?}",
+                        grant.id, grant.status
+                    ),
+                    None,
+                ));
+            }
+
+            let new_vc_ids: Vec<String> = self
+                .wallet()
+                .retrieve_all_vcs()
+                .await?
+                .into_iter()
+                .filter(|new| {
+                    new.vc_type == vc.vc_type && new.id != vc.id && new.added_on >= started_at
+                })
+                .map(|new| new.id)
+                .collect();
+            if new_vc_ids.is_empty() {
+                return Err(Errors::missing_resource(
+                    &grant.id,
+                    "Credential request finalized without storing a credential",
+                    None,
+                ));
+            }
+            self.wallet().delete_vc(&vc.id).await?;
+            Ok((grant.id, new_vc_ids))
+        }
+        .await;
+
+        let outcome = match result {
+            Ok((flow_id, new_vc_ids)) => {
+                info!("Credential {} renewed as {:?}", vc.id, new_vc_ids);
+                RefreshOutcome::Renewed {
+                    flow_id,
+                    new_vc_ids,
+                }
+            }
+            Err(e) => {
+                warn!("Credential {} could not be renewed: {e}", vc.id);
+                RefreshOutcome::Failed {
+                    flow_id,
+                    reason: e.to_string(),
+                }
+            }
+        };
+        let event = CredentialRefreshEvent {
+            vc_id: vc.id.clone(),
+            vc_type: vc.vc_type.clone(),
+            authority,
+            valid_until: vc.valid_until,
+            at: Clock::now(),
+            outcome,
+        };
+        self.publish_refresh(&event);
+        Ok(event)
+    }
+
+    /// Renews every expiring credential, one failing renewal not blocking the others.
+    async fn refresh_expiring(&self) -> Outcome<Vec<CredentialRefreshEvent>> {
+        let mut events = Vec::new();
+        for vc in self.expiring_credentials().await? {
+            events.push(self.refresh_credential(&vc).await?);
+        }
+        Ok(events)
+    }
+
+    /// Pushes a renewal outcome to the refresh webhooks in the background.
+    fn publish_refresh(&self, event: &CredentialRefreshEvent) {
+        let webhooks = self.get_refresh_webhooks();
+        if webhooks.is_empty() {
+            return;
+        }
+
+        let requester = self.vc_requester();
+        let event = event.clone();
+        tokio::spawn(async move {
+            if let Err(e) = requester.notify_refresh(&webhooks, &event).await {
+                e.log();
+            }
+        });
+    }
+}
+
+/// Spawns the background task running flow `id` to completion.
+///
+/// A flow whose step fails is abandoned so it does not stay pending forever.
+pub fn spawn_credential_request(
+    module: Arc<dyn VcRequesterModuleTrait>,
+    id: String,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        match module.drive_credential_request(&id).await {
+            Ok(grant) => info!("Credential request {id} ended as {:?}", grant.status),
+            Err(e) => {
+                warn!("Credential request {id} failed: {e}");
+                if let Err(e) = module.abandon_credential_request(&id).await {
+                    e.log();
+                }
+            }
+        }
+    })
+}
+
+/// Spawns the periodic credential renewal job, running a first round immediately.
+pub fn spawn_credential_refresh(module: Arc<dyn VcRequesterModuleTrait>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(module.get_refresh_interval());
+        loop {
+            ticker.tick().await;
+            if on_standby(module.standby_guard()).await {
+                continue;
+            }
+            if let Err(e) = module.refresh_expiring().await {
+                e.log();
+            }
+        }
+    })
+}
+
+/// Stores the grant and interaction opening a flow.
+async fn create_flow(
+    grants: &dyn SentGrantRepoTrait,
+    interactions: &dyn SentInteractionRepoTrait,
+    grant: grant::Plan,
+    interaction: interaction::Plan,
+) -> Outcome<(grant::Model, interaction::Model)> {
+    let interaction = interactions.create(interaction).await?;
+    let grant = grants.create(grant).await?;
+    Ok((grant, interaction))
+}
+
+/// Stores the interaction of a flow and moves its grant to the `next` lifecycle step.
+async fn save_flow(
+    grants: &dyn SentGrantRepoTrait,
+    interactions: &dyn SentInteractionRepoTrait,
+    grant: grant::Model,
+    interaction: interaction::Model,
+    next: RequestLifecycle,
+) -> Outcome<grant::Model> {
+    interactions.update(interaction).await?;
+    grants.advance(grant, next).await
+}
+
+/// Keeps the continuation details of the last grant response, dropping the superseded ones.
+fn store_continuation(model: &mut interaction::Model, continuation: Option<&Continuation>) {
+    model.continue_endpoint = continuation.map(|c| c.uri.clone());
+    model.continue_token = continuation.map(|c| c.access_token.value.clone());
+    model.continue_wait = continuation.and_then(|c| c.wait).map(|wait| wait as i64);
+}
@@ -0,0 +1,721 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::config::traits::VerifyReqConfigTrait;
+use crate::data::entities::received::verification::Model;
+use crate::data::entities::received::{presentation_definition, verification_audit};
+use crate::data::entities::shared::replay_entry;
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::modules::standby::on_standby;
+use crate::modules::{
+    EvidenceModuleTrait, QuotaModuleTrait, RequirementsModuleTrait, TrustedIssuerModuleTrait,
+};
+use crate::services::repo::RepoTransaction;
+use crate::services::repo::traits::received::{
+    RecvPresentationDefinitionRepoTrait, RecvVerificationAuditRepoTrait, RecvVerificationRepoTrait,
+};
+use crate::services::repo::traits::shared::{ReplayEntryRepoTrait, RpSubscriptionRepoTrait};
+use crate::services::standby::StandbyTrait;
+use crate::services::{HasRelyingParty, HasVerifier};
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::quota::QuotaKind;
+use crate::types::vcs::{VPDef, VcType};
+use crate::types::verification::{
+    AuditCheck, AuditedRequest, AuthorizationResponse, PresentedCredential, ReplayKind,
+    VerificationFilter, VerificationSession, VerificationStatus, presentation_jtis,
+    vp_token_digest,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, sleep_until};
+use tracing::{info, warn};
+
+/// Business Orchestration Module for verifier-side presentation exchanges.
+///
+/// Resolves the exchange a wallet response belongs to, runs the presentation checks and
+/// persists the outcome, whatever response mode carried the `vp_token`. Outcomes are pushed
+/// as signed events to the configured webhooks.
+///
+/// Every wallet response also leaves an audit entry with the raw `vp_token`, the decoded
+/// claims and the result of each check, kept apart from the redacted verification for the
+/// [audit retention](Self::get_audit_retention) period. When an
+/// evidence packager is wired in, finished verifications are sealed into a signed bundle too.
+#[async_trait]
+pub trait VerificationModuleTrait: HasVerifier + HasRelyingParty + Send + Sync + 'static {
+    /// Returns the repository persisting received verifications.
+    fn verifications(&self) -> Arc<dyn RecvVerificationRepoTrait>;
+
+    /// Returns the repository keeping the evidence of every wallet response.
+    fn audits(&self) -> Arc<dyn RecvVerificationAuditRepoTrait>;
+
+    /// Returns the repository remembering consumed nonces, jtis and tokens.
+    fn replays(&self) -> Arc<dyn ReplayEntryRepoTrait>;
+
+    /// Returns the repository of the presentation definitions served by reference.
+    fn definitions(&self) -> Arc<dyn RecvPresentationDefinitionRepoTrait>;
+
+    /// Returns the role keeper of an active/standby pair, if the instance takes part in one.
+    ///
+    /// The scheduled job pauses while it reports standby.
+    fn standby_guard(&self) -> Option<Arc<dyn StandbyTrait>> {
+        None
+    }
+
+    /// Returns how long a consumed value keeps being rejected.
+    ///
+    /// Must outlive the validity of the presentations accepted, or they could be replayed
+    /// once their entry is purged.
+    fn get_replay_ttl(&self) -> Duration {
+        Duration::from_secs(24 * 60 * 60)
+    }
+
+    /// Returns the webhooks notified of every verification outcome.
+    ///
+    /// Usually backed by `VerifyReqConfigTrait::get_webhooks`.
+    fn get_webhooks(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Returns the repository of relying-party subscriptions, if decisions are delivered.
+    fn rp_subscriptions(&self) -> Option<Arc<dyn RpSubscriptionRepoTrait>> {
+        None
+    }
+
+    /// Opens the unit of work a verified presentation's `jti`s and outcome are written through.
+    ///
+    /// Without one they are stored one after the other, and a crash in between leaves the
+    /// `jti`s consumed by a session still `Presented`.
+    async fn begin_transaction(&self) -> Outcome<Option<RepoTransaction>> {
+        Ok(None)
+    }
+
+    /// Returns the module sealing the evidence of finished verifications, if enabled.
+    fn evidence_packager(&self) -> Option<Arc<dyn EvidenceModuleTrait>> {
+        None
+    }
+
+    /// Returns the trusted issuer registry enforced on verified presentations, if enabled.
+    fn trusted_issuer_registry(&self) -> Option<Arc<dyn TrustedIssuerModuleTrait>> {
+        None
+    }
+
+    /// Returns the requirement profiles choosing the response mode of new sessions, if any.
+    fn requirements(&self) -> Option<Arc<dyn RequirementsModuleTrait>> {
+        None
+    }
+
+    /// Returns the module metering verifications per participant, if quotas are enforced.
+    fn quota_meter(&self) -> Option<Arc<dyn QuotaModuleTrait>> {
+        None
+    }
+
+    /// Resolves the participant the session `model` was opened for, usually the one behind
+    /// the grant sharing its id. Sessions without one consume no quota.
+    async fn session_participant(&self, _model: &Model) -> Outcome<Option<String>> {
+        Ok(None)
+    }
+
+    /// Returns how long audit entries, and the raw `vp_token` they hold, are kept. Defaults
+    /// to 90 days; `None` keeps them forever.
+    fn get_audit_retention(&self) -> Option<Duration> {
+        Some(Duration::from_secs(90 * 24 * 3600))
+    }
+
+    /// Returns how often the sweeper expires abandoned sessions.
+    fn get_sweep_interval(&self) -> Duration {
+        Duration::from_secs(60)
+    }
+
+    /// Returns how often a long-polling client has the session re-read on its behalf.
+    fn get_long_poll_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    // ===== SESSIONS ==============================================================================
+
+    /// Opens the verification session `id` for an optional resource/action pair.
+    ///
+    /// The wallet answers in the response mode of the requirement profile covering the pair,
+    /// or in the configured one when no profile does.
+    ///
+    /// # Errors
+    /// Fails when the plan cannot be built, e.g. because no credential type is requested.
+    async fn open_session(
+        &self,
+        id: &str,
+        resource: Option<&str>,
+        action: Option<&str>,
+    ) -> Outcome<Model> {
+        let plan = match self.requirements() {
+            Some(requirements) => {
+                let response_mode = requirements.get_response_mode_for(resource, action);
+                self.verifier().build_vp_plan_with_mode(id, response_mode)?
+            }
+            None => self.verifier().build_vp_plan(id)?,
+        };
+        self.verifications().create(plan).await
+    }
+
+    // ===== WALLET RESPONSES ======================================================================
+
+    /// Resolves the session bound to `state`, which must still be waiting for the wallet.
+    ///
+    /// A session found past its TTL is moved to `Expired` on the spot.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the session is expired or already answered.
+    async fn pending_by_state(&self, state: &str) -> Outcome<Model> {
+        let mut model = self.verifications().get_by_state(state).await?;
+        if model.is_expired() {
+            model.transition(VerificationStatus::Expired)?;
+            self.verifications().update(model).await?;
+            return Err(Errors::forbidden("Verification has expired", None));
+        }
+        if model.status != VerificationStatus::Pending {
+            return Err(Errors::forbidden(
+                "Verification has already been completed",
+                None,
+            ));
+        }
+        Ok(model)
+    }
+
+    /// Completes the exchange bound to `state` with the `vp_token` returned by the wallet.
+    ///
+    /// Presentation Exchange responses must also carry their `presentation_submission`.
+    ///
+    /// The verification is stored whatever the outcome; the verification error, if any, is
+    /// returned afterwards.
+    ///
+    /// The session is stored as `Presented` before the checks run, so pollers see progress.
+    /// Verified claims go through the configured redaction profile before the final write.
+    ///
+    /// The verification quota of the [session participant](Self::session_participant) is
+    /// consumed before anything else, so rejected attempts count too.
+    ///
+    /// The `vp_token` and the exchange nonce are consumed first, so a token re-submitted
+    /// while the session is still pending (or against another session) is turned away.
+    /// Presentation `jti`s are only consumed once the presentation and its issuers are
+    /// verified, in the same unit of work as the outcome, and one already consumed fails the
+    /// session. The session reaches `Verified` or `Failed` only after every check ran.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the exchange is expired or already completed,
+    /// an [`Errors::SecurityError`] when the presentation is a replay and a rate limit error
+    /// once the participant exhausted its verification quota.
+    async fn complete_verification(
+        &self,
+        state: &str,
+        vp_token: &str,
+        presentation_submission: Option<&str>,
+    ) -> Outcome<Model> {
+        let mut model = self.pending_by_state(state).await?;
+        let mut checks = Vec::new();
+        if let Some(quotas) = self.quota_meter() {
+            if let Some(participant) = self.session_participant(&model).await? {
+                if let Err(e) = quotas
+                    .consume_quota(&participant, QuotaKind::Verification)
+                    .await
+                {
+                    checks.push(AuditCheck::failed("quota", e.to_string()));
+                    let plan = audit_plan(&model, Some(vp_token), presentation_submission, checks);
+                    self.record_audit(plan).await;
+                    return Err(e);
+                }
+            }
+        }
+        if !self
+            .consume_replay(ReplayKind::VpToken, &vp_token_digest(vp_token))
+            .await?
+        {
+            warn!("Rejected replayed vp_token for verification {}", model.id);
+            let reason = "vp_token has already been presented";
+            checks.push(AuditCheck::failed("vp_token_replay", reason));
+            let plan = audit_plan(&model, Some(vp_token), presentation_submission, checks);
+            self.record_audit(plan).await;
+            return Err(Errors::security(reason, None));
+        }
+        checks.push(AuditCheck::passed("vp_token_replay"));
+        if !self
+            .consume_replay(ReplayKind::VpNonce, &model.nonce)
+            .await?
+        {
+            let reason = "Verification has already been answered";
+            checks.push(AuditCheck::failed("nonce_replay", reason));
+            let plan = audit_plan(&model, Some(vp_token), presentation_submission, checks);
+            self.record_audit(plan).await;
+            return Err(Errors::forbidden(reason, None));
+        }
+        checks.push(AuditCheck::passed("nonce_replay"));
+        model.transition(VerificationStatus::Presented)?;
+        let mut model = self.verifications().update(model).await?;
+
+        info!("Completing verification {}", model.id);
+        let mut result = self
+            .verifier()
+            .verify_all(&mut model, vp_token, presentation_submission)
+            .await;
+        checks.push(AuditCheck::from_outcome("presentation", &result));
+        if let (Ok(()), Some(registry)) = (&result, self.trusted_issuer_registry()) {
+            result = registry.check_trusted_issuers(&model.vcs).await;
+            checks.push(AuditCheck::from_outcome("trusted_issuer", &result));
+        }
+        let claims = model
+            .vcs
+            .iter()
+            .filter_map(|vc| PresentedCredential::decode(vc).ok())
+            .collect();
+        self.verifier().redact(&mut model);
+
+        let txn = self.begin_transaction().await?;
+        let model = {
+            let (replays, verifications) = match &txn {
+                Some(txn) => (
+                    txn.repos().replay_entries.clone(),
+                    txn.repos().recv_verifications.clone(),
+                ),
+                None => (self.replays(), self.verifications()),
+            };
+            if result.is_ok() {
+                let ttl = self.get_replay_ttl();
+                if let Some(jti) = consume_jtis(&*replays, vp_token, ttl).await? {
+                    warn!(
+                        "Rejected replayed presentation {jti} for verification {}",
+                        model.id
+                    );
+                    let reason = format!("Presentation {jti} has already been presented");
+                    checks.push(AuditCheck::failed("jti_replay", &reason));
+                    result = Err(Errors::security(reason, None));
+                } else {
+                    checks.push(AuditCheck::passed("jti_replay"));
+                }
+            }
+            model.transition(match result {
+                Ok(()) => VerificationStatus::Verified,
+                Err(_) => VerificationStatus::Failed,
+            })?;
+            verifications.update(model).await?
+        };
+        if let Some(txn) = txn {
+            txn.commit().await?;
+        }
+        self.publish_outcome(&model);
+
+        let mut plan = audit_plan(&model, Some(vp_token), presentation_submission, checks);
+        plan.claims = claims;
+        self.record_audit(plan).await;
+        self.seal_evidence(&model);
+        result.map(|()| model)
+    }
+
+    /// Records `value` as consumed for the replay TTL, returning `false` if it already was.
+    ///
+    /// Credential `jti`s are deliberately not consumed: holders re-present the same
+    /// credential across exchanges, and each presentation is bound by its own nonce and `jti`.
+    async fn consume_replay(&self, kind: ReplayKind, value: &str) -> Outcome<bool> {
+        let plan = replay_entry::Plan {
+            kind,
+            value: value.to_string(),
+            expires_at: Utc::now() + self.get_replay_ttl(),
+        };
+        self.replays().consume(plan).await
+    }
+
+    /// Completes the exchange bound to `state` with a `direct_post` or `direct_post.jwt` response.
+    ///
+    /// Encrypted responses are decrypted with the key published for the exchange; plain ones
+    /// are accepted unless the exchange requires encryption.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::SecurityError`] when a required encryption is missing or the
+    /// decrypted `state` does not match the exchange.
+    async fn complete_direct_post(
+        &self,
+        state: &str,
+        response: AuthorizationResponse,
+    ) -> Outcome<Model> {
+        let model = self.verifications().get_by_state(state).await?;
+        let response = match response.response {
+            Some(jwe) => self.verifier().decrypt_response(&model, &jwe)?,
+            None if self.verifier().accepts_plain_response(&model) => response,
+            None => {
+                return Err(Errors::security(
+                    "Verification requires an encrypted response",
+                    None,
+                ));
+            }
+        };
+        if response.state.as_deref().is_some_and(|s| s != state) {
+            return Err(Errors::security(
+                "Response state does not match the exchange",
+                None,
+            ));
+        }
+
+        match (response.vp_token, response.error) {
+            (_, Some(error)) => {
+                let reason = response.error_description.unwrap_or(error);
+                self.fail_verification(state, &reason).await
+            }
+            (Some(vp_token), None) => {
+                let submission = response.presentation_submission.as_deref();
+                self.complete_verification(state, &vp_token, submission)
+                    .await
+            }
+            (None, None) => Err(Errors::format(
+                BadFormat::Received,
+                "Response carries neither vp_token nor error",
+                None,
+            )),
+        }
+    }
+
+    /// Marks the exchange bound to `state` as failed after the wallet returned an error.
+    async fn fail_verification(&self, state: &str, error: &str) -> Outcome<Model> {
+        let mut model = self.pending_by_state(state).await?;
+
+        info!("Wallet rejected verification {}: {error}", model.id);
+        model.transition(VerificationStatus::Failed)?;
+        let model = self.verifications().update(model).await?;
+        self.publish_outcome(&model);
+
+        let checks = vec![AuditCheck::failed("wallet_response", error)];
+        self.record_audit(audit_plan(&model, None, None, checks))
+            .await;
+        self.seal_evidence(&model);
+        Ok(model)
+    }
+
+    /// Stores an audit entry, logging instead of failing the exchange it documents.
+    async fn record_audit(&self, plan: verification_audit::Plan) {
+        if let Err(e) = self.audits().create(plan).await {
+            e.log();
+        }
+    }
+
+    /// Seals the evidence of a finished verification in the background, once its audit is stored.
+    fn seal_evidence(&self, model: &Model) {
+        let Some(packager) = self.evidence_packager() else {
+            return;
+        };
+        let id = model.id.clone();
+        tokio::spawn(async move {
+            if let Err(e) = packager.package_evidence(&id).await {
+                e.log();
+            }
+        });
+    }
+
+    /// Lists one page of the verification sessions matching `filter`.
+    async fn list_verifications(
+        &self,
+        filter: &VerificationFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<Model>> {
+        self.verifications().search(filter, request).await
+    }
+
+    /// Returns the evidence recorded for the verification `id`, oldest first.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::MissingResourceError`] when the verification does not exist.
+    async fn audit_trail(&self, id: &str) -> Outcome<Vec<verification_audit::Model>> {
+        self.verifications().get_by_id(id).await?;
+        self.audits().get_by_verification(id).await
+    }
+
+    /// Pushes the outcome of a finished verification to the global webhooks and its own, and
+    /// a signed decision to every relying-party subscription matching it.
+    ///
+    /// Delivery runs in the background so the wallet response is not held up by slow
+    /// downstream components.
+    fn publish_outcome(&self, model: &Model) {
+        let mut webhooks = self.get_webhooks();
+        webhooks.extend(model.webhook_url.clone());
+        let subscriptions = self.rp_subscriptions();
+        if webhooks.is_empty() && subscriptions.is_none() {
+            return;
+        }
+
+        let relying_party = self.relying_party();
+        let model = model.clone();
+        tokio::spawn(async move {
+            if !webhooks.is_empty() {
+                if let Err(e) = relying_party.notify_webhooks(&webhooks, &model).await {
+                    e.log();
+                }
+            }
+
+            let Some(subscriptions) = subscriptions else {
+                return;
+            };
+            let matching = subscriptions
+                .get_matching(
+                    model.holder.as_deref(),
+                    &model.vc_type,
+                    model.purpose.as_deref(),
+                )
+                .await;
+            let result = match matching {
+                Ok(matching) if matching.is_empty() => Ok(()),
+                Ok(matching) => relying_party.notify(&matching, &model).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                e.log();
+            }
+        });
+    }
+
+    // ===== SESSION LIFECYCLE =====================================================================
+
+    /// Returns the current state of a session, expiring it first if its TTL has passed.
+    async fn session_status(&self, id: &str) -> Outcome<VerificationSession> {
+        let mut model = self.verifications().get_by_id(id).await?;
+        if model.is_expired() {
+            model.transition(VerificationStatus::Expired)?;
+            model = self.verifications().update(model).await?;
+        }
+        Ok(VerificationSession::from(&model))
+    }
+
+    /// Registers the webhook notified of the outcome of a session still waiting for the wallet.
+    ///
+    /// Replaces any webhook set on the plan. Not exposed over HTTP: the URL is called by the
+    /// verifier, so only the integrator creating the session may choose it.
+    async fn register_webhook(&self, id: &str, webhook_url: &str) -> Outcome<Model> {
+        let model = self.verifications().get_by_id(id).await?;
+        let mut model = self.pending_by_state(&model.state).await?;
+        model.webhook_url = Some(webhook_url.to_string());
+        self.verifications().update(model).await
+    }
+
+    /// Returns the `openid4vp://` request of a session still waiting for the wallet.
+    ///
+    /// Lets a desktop relying party render the request as a QR code for a mobile wallet. The
+    /// request is passed by reference, so the nonce and state only reach the wallet fetching
+    /// the [request object](Self::request_object).
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the session is expired or already answered.
+    async fn verification_uri(&self, id: &str) -> Outcome<String> {
+        let model = self.verifications().get_by_id(id).await?;
+        let model = self.pending_by_state(&model.state).await?;
+        Ok(self.verifier().generate_request_uri(&model))
+    }
+
+    /// Returns the request object the wallet fetches from the `request_uri` of a session
+    /// still waiting for it.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the session is expired or already answered.
+    async fn request_object(&self, id: &str) -> Outcome<String> {
+        let model = self.verifications().get_by_id(id).await?;
+        let model = self.pending_by_state(&model.state).await?;
+        let model = self.publish_definition(model).await?;
+        self.verifier().generate_request_object(&model)
+    }
+
+    // ===== PRESENTATION DEFINITIONS ==============================================================
+
+    /// Publishes the presentation definition of `model` and binds the session to it.
+    ///
+    /// Definitions are stored once under their content address, and the newest one published
+    /// for a set of requested types supersedes the others. A session already bound keeps its
+    /// definition; a response to it fails once that definition is superseded.
+    async fn publish_definition(&self, mut model: Model) -> Outcome<Model> {
+        if model.pd_id.is_some() {
+            return Ok(model);
+        }
+        let definition = self.verifier().generate_vpd(&model)?;
+        let profile = definition_profile(&model.vc_type);
+        match self.definitions().find(&definition.id).await? {
+            None => {
+                info!("Publishing presentation definition {}", definition.id);
+                let plan = presentation_definition::Plan {
+                    definition: definition.clone(),
+                    profile: profile.clone(),
+                };
+                self.definitions().create(plan).await?;
+            }
+            Some(mut published) if published.is_superseded() => {
+                info!("Reinstating presentation definition {}", definition.id);
+                published.superseded_by = None;
+                published.superseded_at = None;
+                self.definitions().update(published).await?;
+            }
+            Some(_) => {}
+        }
+        let superseded = self
+            .definitions()
+            .supersede(&profile, &definition.id)
+            .await?;
+        if superseded > 0 {
+            info!(
+                "Presentation definition {} superseded {superseded} previous definitions",
+                definition.id
+            );
+        }
+
+        model.pd_id = Some(definition.id);
+        self.verifications().update(model).await
+    }
+
+    /// Returns the definition published under `id`, with the id of its successor if superseded.
+    ///
+    /// Superseded definitions keep being served, as their content never changes.
+    async fn presentation_definition(&self, id: &str) -> Outcome<(VPDef, Option<String>)> {
+        let published = self.definitions().find(id).await?.ok_or_else(|| {
+            Errors::missing_resource(id, "Presentation definition not found", None)
+        })?;
+        Ok((published.definition()?, published.superseded_by))
+    }
+
+    /// Long-polls a session until its status differs from `since` or `timeout` elapses.
+    ///
+    /// Returns the latest state either way, so browsers can loop on it without hammering the
+    /// verifier while the holder scans the QR code.
+    async fn wait_for_session(
+        &self,
+        id: &str,
+        since: VerificationStatus,
+        timeout: Duration,
+    ) -> Outcome<VerificationSession> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let session = self.session_status(id).await?;
+            if session.status != since || Instant::now() >= deadline {
+                return Ok(session);
+            }
+            sleep_until(deadline.min(Instant::now() + self.get_long_poll_interval())).await;
+        }
+    }
+
+    /// Moves every pending or presented session past its TTL to `Expired`, returning how many were closed.
+    async fn expire_stale_sessions(&self) -> Outcome<u64> {
+        let expired = self.verifications().expire_stale(Utc::now()).await?;
+        if expired > 0 {
+            info!("Expired {expired} abandoned verification sessions");
+        }
+        Ok(expired)
+    }
+
+    /// Drops replay entries past their TTL, returning how many were purged.
+    async fn purge_replay_entries(&self) -> Outcome<u64> {
+        let purged = self.replays().purge_expired(Utc::now()).await?;
+        if purged > 0 {
+            info!("Purged {purged} expired replay entries");
+        }
+        Ok(purged)
+    }
+
+    /// Drops the audit entries past the retention period, returning how many were purged.
+    async fn purge_audit_trail(&self) -> Outcome<u64> {
+        let Some(retention) = self.get_audit_retention() else {
+            return Ok(0);
+        };
+        let purged = self.audits().purge_before(Utc::now() - retention).await?;
+        if purged > 0 {
+            info!("Purged {purged} verification audit entries");
+        }
+        Ok(purged)
+    }
+}
+
+/// Spawns the periodic job expiring abandoned sessions and purging stale replay and audit entries.
+pub fn spawn_verification_sweeper(module: Arc<dyn VerificationModuleTrait>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(module.get_sweep_interval());
+        loop {
+            ticker.tick().await;
+            if on_standby(module.standby_guard()).await {
+                continue;
+            }
+            if let Err(e) = module.expire_stale_sessions().await {
+                warn!("Unable to expire verification sessions: {e}");
+            }
+            if let Err(e) = module.purge_replay_entries().await {
+                warn!("Unable to purge replay entries: {e}");
+            }
+            if let Err(e) = module.purge_audit_trail().await {
+                warn!("Unable to purge verification audit entries: {e}");
+            }
+        }
+    })
+}
+
+/// Key grouping the definitions generated for the same requested types, whatever their order.
+fn definition_profile(vc_types: &[VcType]) -> String {
+    let mut types: Vec<String> = vc_types.iter().map(ToString::to_string).collect();
+    types.sort();
+    types.dedup();
+    types.join(",")
+}
+
+/// Consumes the `jti` of every presentation of a verified `vp_token` for `ttl`, returning the
+/// first one that was already consumed. The `jti`s consumed before it are released again, so
+/// a rejected presentation leaves none behind.
+async fn consume_jtis(
+    replays: &dyn ReplayEntryRepoTrait,
+    vp_token: &str,
+    ttl: Duration,
+) -> Outcome<Option<String>> {
+    let mut consumed = Vec::new();
+    for jti in presentation_jtis(vp_token) {
+        let plan = replay_entry::Plan {
+            kind: ReplayKind::VpJti,
+            value: jti.clone(),
+            expires_at: Utc::now() + ttl,
+        };
+        let entry_id = plan.entry_id();
+        if !replays.consume(plan).await? {
+            for entry_id in consumed {
+                replays.delete(&entry_id).await?;
+            }
+            return Ok(Some(jti));
+        }
+        consumed.push(entry_id);
+    }
+    Ok(None)
+}
+
+/// Builds the audit entry of a wallet response, its first failed check giving the reason.
+fn audit_plan(
+    model: &Model,
+    vp_token: Option<&str>,
+    presentation_submission: Option<&str>,
+    checks: Vec<AuditCheck>,
+) -> verification_audit::Plan {
+    let failure_reason = checks
+        .iter()
+        .find(|check| !check.passed)
+        .and_then(|check| check.detail.clone());
+    verification_audit::Plan {
+        verification_id: model.id.clone(),
+        request: AuditedRequest::from(model),
+        vp_token: vp_token.map(str::to_string),
+        presentation_submission: presentation_submission.map(str::to_string),
+        claims: Vec::new(),
+        checks,
+        outcome: model.status.clone(),
+        failure_reason,
+    }
+}
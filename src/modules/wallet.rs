@@ -15,12 +15,21 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::sync::Arc;
+
 use crate::data::entities::wallet::{did, key, vc};
-use crate::errors::Outcome;
+use crate::errors::{Errors, Outcome};
+use crate::modules::{KeyIntegrityModuleTrait, PresentationConsentModuleTrait};
 use crate::services::HasWallet;
+use crate::services::repo::traits::shared::TrustedIssuerRepoTrait;
+use crate::services::wallet::WalletTrait;
 use crate::types::dids::{DidBuilder, DidDocument, DidService};
-use crate::types::wallet::{DidSearch, OidcUri, WalletInfo};
+use crate::types::verification::DcqlQuery;
+use crate::types::wallet::{
+    DidSearch, KeyBackup, KeyImportSummary, OidcUri, OnboardingStatus, VcSearch, WalletInfo,
+};
 use async_trait::async_trait;
+use tracing::warn;
 
 /// Business Orchestration Module for the SSI Decentralized Wallet.
 ///
@@ -30,11 +39,40 @@ use async_trait::async_trait;
 /// Automatically implements default structural routing to the underlying [`WalletTrait`] implementation.
 #[async_trait]
 pub trait WalletModuleTrait: HasWallet + Send + Sync + 'static {
+    /// Returns the registry received credentials are checked against before being stored.
+    ///
+    /// Without one, any issuer is accepted.
+    fn issuer_allow_list(&self) -> Option<Arc<dyn TrustedIssuerRepoTrait>> {
+        None
+    }
+
+    /// Returns the module OID4VP requests are held in until the holder consents to them.
+    ///
+    /// Without one, requests are answered at once with the first matching credentials.
+    fn presentation_consent(&self) -> Option<Arc<dyn PresentationConsentModuleTrait>> {
+        None
+    }
+
+    /// Returns the module checking the signing key against Vault once the wallet is linked.
+    ///
+    /// Without one, key drift goes unnoticed until counterparties reject signatures.
+    fn key_integrity(&self) -> Option<Arc<dyn KeyIntegrityModuleTrait>> {
+        None
+    }
+
     // ===== LIFECYCLE & LINKING ===================================================================
 
     /// Triggers an out-of-band linkage routine to anchor the wallet inside an ecosystem data space.
+    ///
+    /// The signing key is then checked for drift, which is reported without failing the link.
     async fn link(&self) -> Outcome<()> {
-        self.wallet().link().await
+        self.wallet().link().await?;
+        if let Some(key_integrity) = self.key_integrity() {
+            if let Err(e) = key_integrity.verify_keys_on_startup().await {
+                warn!("Unable to check the signing key integrity: {e}");
+            }
+        }
+        Ok(())
     }
 
     /// Asserts whether the wallet has been successfully linked and possesses an active identity context.
@@ -42,6 +80,16 @@ pub trait WalletModuleTrait: HasWallet + Send + Sync + 'static {
         self.wallet().get_did().await.is_ok()
     }
 
+    /// Reports which onboarding steps the wallet has gone through.
+    async fn onboarding_status(&self) -> Outcome<OnboardingStatus> {
+        self.wallet().onboarding_status().await
+    }
+
+    /// Resumes an interrupted onboarding, running only the steps still missing.
+    async fn onboard(&self) -> Outcome<OnboardingStatus> {
+        self.wallet().onboard().await
+    }
+
     /// Resolves and returns the fully compliant local Decentralized Identifier (DID) Document.
     async fn get_did_doc(&self) -> Outcome<DidDocument> {
         self.wallet().get_did_doc().await
@@ -129,16 +177,105 @@ pub trait WalletModuleTrait: HasWallet + Send + Sync + 'static {
         self.wallet().delete_vc(id).await
     }
 
+    // ===== BACKUP & MIGRATION ====================================================================
+
+    /// Produces a passphrase-encrypted backup of every private key and DID of the wallet.
+    async fn export_keys(&self, passphrase: &str) -> Outcome<KeyBackup> {
+        self.wallet().export_keys(passphrase).await
+    }
+
+    /// Restores a backup produced by [`WalletModuleTrait::export_keys`], possibly elsewhere.
+    async fn import_keys(&self, backup: KeyBackup, passphrase: &str) -> Outcome<KeyImportSummary> {
+        self.wallet().import_keys(backup, passphrase).await
+    }
+
     // ===== PROTOCOL INBOUND INTERACTIONS =========================================================
 
     /// Processes an inbound OpenID4VCI credential offer URI to claim and store a Verifiable Credential.
+    ///
+    /// Offers protected by a Transaction Code fail with a missing action error describing the
+    /// expected code until the payload carries it. With an
+    /// [allow list](Self::issuer_allow_list), credentials not signed by an issuer registered
+    /// for their type are refused.
     async fn process_oidc4vci(&self, payload: OidcUri) -> Outcome<()> {
-        self.wallet().process_oid4vci(&payload.uri).await
+        let tx_code = payload.tx_code.as_deref();
+        let Some(registry) = self.issuer_allow_list() else {
+            return self.wallet().process_oid4vci(&payload.uri, tx_code).await;
+        };
+        let offer = self.wallet().resolve_oid4vci_offer(&payload.uri).await?;
+        self.wallet()
+            .redeem_oid4vci_offer(&payload.uri, offer, tx_code, Some(registry.as_ref()))
+            .await
     }
 
     /// Processes an inbound OpenID4VP verifiable presentation request challenge to submit an evaluation response.
+    ///
+    /// With a [consent module](Self::presentation_consent), the request is only held for
+    /// approval and nothing is presented yet.
     async fn process_oidc4vp(&self, payload: OidcUri) -> Outcome<()> {
-        self.wallet().process_oid4vp(&payload.uri).await
+        let Some(consent) = self.presentation_consent() else {
+            return self.wallet().process_oid4vp(&payload.uri).await;
+        };
+        consent.hold_presentation(&payload.uri).await?;
+        Ok(())
+    }
+
+    /// Selects the stored Verifiable Credentials answering each credential query of a DCQL request.
+    ///
+    /// Results are keyed by credential query id, matching the layout of a DCQL `vp_token`.
+    /// Queries that are not `multiple` keep their first match only. A query without matches
+    /// fails the whole selection unless every credential set it belongs to is optional.
+    async fn match_dcql(&self, query: &DcqlQuery) -> Outcome<Vec<(String, Vec<vc::Model>)>> {
+        let vcs = self.wallet().retrieve_all_vcs().await?;
+
+        let mut selected = Vec::with_capacity(query.credentials.len());
+        for credential in &query.credentials {
+            let mut matches: Vec<vc::Model> = vcs
+                .iter()
+                .filter(|vc| credential.matches(&vc.parsed_document))
+                .cloned()
+                .collect();
+            if !credential.multiple {
+                matches.truncate(1);
+            }
+
+            let required = query.credential_sets.as_ref().is_none_or(|sets| {
+                sets.iter().any(|set| {
+                    set.required
+                        && set
+                            .options
+                            .iter()
+                            .all(|option| option.contains(&credential.id))
+                })
+            });
+            if matches.is_empty() && required {
+                return Err(Errors::missing_resource(
+                    &credential.id,
+                    format!("No stored credential satisfies query '{}'", credential.id),
+                    None,
+                ));
+            }
+            selected.push((credential.id.clone(), matches));
+        }
+
+        let unsatisfied = query.credential_sets.iter().flatten().find(|set| {
+            set.required
+                && !set.options.iter().any(|option| {
+                    option.iter().all(|id| {
+                        selected
+                            .iter()
+                            .any(|(selected_id, matches)| selected_id == id && !matches.is_empty())
+                    })
+                })
+        });
+        if let Some(set) = unsatisfied {
+            return Err(Errors::missing_resource(
+                set.options.concat().join(","),
+                "No option of a required credential set can be satisfied",
+                None,
+            ));
+        }
+        Ok(selected)
     }
 
     // ===== AUDITING & INVENTORY ==================================================================
@@ -158,8 +295,42 @@ pub trait WalletModuleTrait: HasWallet + Send + Sync + 'static {
         self.wallet().retrieve_all_vcs().await
     }
 
+    /// Retrieves a single stored Verifiable Credential by its identifier.
+    async fn get_wallet_credential(&self, id: &str) -> Outcome<vc::Model> {
+        self.wallet().retrieve_vc(id).await
+    }
+
+    /// Searches the stored Verifiable Credentials by type, issuer, expiry window and subject text.
+    ///
+    /// Filtering runs over the locally decoded credential index, so it behaves the same
+    /// regardless of the wallet backend.
+    async fn search_credentials(&self, search: VcSearch) -> Outcome<Vec<vc::Model>> {
+        let vcs = self.wallet().retrieve_all_vcs().await?;
+        Ok(vcs.into_iter().filter(|vc| search.matches(vc)).collect())
+    }
+
     /// Retrieves all asymmetric keypairs stored in this wallet.
     async fn get_wallet_keys(&self) -> Outcome<Vec<key::Model>> {
         self.wallet().retrieve_all_keys().await
     }
 }
+
+/// Wallet module bound to one tenant wallet of a
+/// [`WalletRegistry`](crate::services::wallet::WalletRegistry).
+pub struct TenantWallet {
+    wallet: Arc<dyn WalletTrait>,
+}
+
+impl TenantWallet {
+    pub fn new(wallet: Arc<dyn WalletTrait>) -> Self {
+        Self { wallet }
+    }
+}
+
+impl HasWallet for TenantWallet {
+    fn wallet(&self) -> Arc<dyn WalletTrait> {
+        self.wallet.clone()
+    }
+}
+
+impl WalletModuleTrait for TenantWallet {}
@@ -0,0 +1,162 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+use aws_credential_types::Credentials;
+use aws_sigv4::http_request::{
+    PayloadChecksumKind, PercentEncodingMode, SignableBody, SignableRequest, SigningParams,
+    SigningSettings, UriPathNormalizationMode, sign,
+};
+use aws_sigv4::sign::v4;
+use aws_smithy_runtime_api::client::identity::Identity;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use tracing::info;
+
+use super::ArchiveTrait;
+use crate::config::traits::ArchiveConfigTrait;
+use crate::config::types::ArchiveConfig;
+use crate::errors::{AnyError, BadFormat, Errors, Outcome, PetitionFailure};
+use crate::services::client::ClientTrait;
+use crate::services::vault::{VaultService, VaultTrait};
+use crate::types::http::HttpBody;
+use crate::types::secrets::ObjectStoreSecrets;
+use crate::utils::{Clock, http_client};
+
+/// Archive store writing to an S3-compatible bucket.
+///
+/// Requests are path-style and signed with AWS Signature Version 4. The access key pair is
+/// read from the vault on every upload so a rotation is picked up without restarting.
+pub struct S3ArchiveService {
+    config: ArchiveConfig,
+    vault: Arc<VaultService>,
+}
+
+impl S3ArchiveService {
+    pub fn new(config: ArchiveConfig, vault: Arc<VaultService>) -> Self {
+        Self { config, vault }
+    }
+}
+
+#[async_trait]
+impl ArchiveTrait for S3ArchiveService {
+    async fn put_object(&self, key: &str, body: Vec<u8>) -> Outcome<()> {
+        let secrets: ObjectStoreSecrets = self
+            .vault
+            .read(None, self.config.get_archive_secrets_path())
+            .await?;
+
+        let path = format!(
+            "/{}/{}",
+            self.config.get_archive_bucket(),
+            key.split('/')
+                .map(|segment| urlencoding::encode(segment).into_owned())
+                .collect::<Vec<_>>()
+                .join("/")
+        );
+        let url = format!("{}{}", self.config.get_archive_endpoint(), path);
+        let headers = self.signed_headers(&secrets, &url, &body)?;
+
+        let res = http_client()
+            .put(&url, Some(headers), HttpBody::Bytes(body))
+            .await?;
+        if !res.status().is_success() {
+            let status = res.status();
+            return Err(Errors::petition(
+                &url,
+                "PUT",
+                Some(status),
+                PetitionFailure::HttpStatus(status),
+                "Object storage rejected the archive bundle",
+                None,
+            ));
+        }
+        info!("Archive bundle stored at {url}");
+        Ok(())
+    }
+}
+
+impl S3ArchiveService {
+    /// Builds the SigV4-signed headers of a `PUT` on `url`, whose path is already URI-encoded.
+    fn signed_headers(
+        &self,
+        secrets: &ObjectStoreSecrets,
+        url: &str,
+        body: &[u8],
+    ) -> Outcome<HeaderMap> {
+        let identity: Identity = Credentials::new(
+            &secrets.access_key_id,
+            &secrets.secret_access_key,
+            None,
+            None,
+            "vault",
+        )
+        .into();
+        let mut settings = SigningSettings::default();
+        settings.payload_checksum_kind = PayloadChecksumKind::XAmzSha256;
+        settings.percent_encoding_mode = PercentEncodingMode::Single;
+        settings.uri_path_normalization_mode = UriPathNormalizationMode::Disabled;
+        let params: SigningParams = v4::SigningParams::builder()
+            .identity(&identity)
+            .region(self.config.get_archive_region())
+            .name("s3")
+            .time(SystemTime::from(Clock::now()))
+            .settings(settings)
+            .build()
+            .map_err(|e| signing_error(Box::new(e)))?
+            .into();
+
+        let request =
+            SignableRequest::new("PUT", url, std::iter::empty(), SignableBody::Bytes(body))
+                .map_err(|e| signing_error(Box::new(e)))?;
+        let (instructions, _) = sign(request, &params)
+            .map_err(|e| signing_error(Box::new(e)))?
+            .into_parts();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/gzip"));
+        for (name, value) in instructions.headers() {
+            let name =
+                HeaderName::from_bytes(name.as_bytes()).map_err(|e| signing_error(Box::new(e)))?;
+            headers.insert(name, header_value(value)?);
+        }
+        Ok(headers)
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn signing_error(source: AnyError) -> Errors {
+    Errors::format(
+        BadFormat::Sent,
+        "Unable to sign the object storage request",
+        Some(source),
+    )
+}
+
+fn header_value(value: &str) -> Outcome<HeaderValue> {
+    HeaderValue::from_str(value).map_err(|e| {
+        Errors::format(
+            BadFormat::Sent,
+            "Invalid object storage header",
+            Some(Box::new(e)),
+        )
+    })
+}
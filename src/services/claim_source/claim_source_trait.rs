@@ -0,0 +1,35 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::participant;
+use crate::errors::Outcome;
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// External system holding authoritative participant records.
+///
+/// Implemented for REST APIs and LDAP directories; deployments may plug their own through
+/// [`ClaimEnricherService::with_source`](super::ClaimEnricherService::with_source).
+#[async_trait]
+pub trait ClaimSourceTrait: Send + Sync + 'static {
+    /// Fetches the record of `participant` as a JSON object.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::MissingResourceError`](crate::errors::Errors) when the source
+    /// holds no record for the participant.
+    async fn fetch(&self, participant: &participant::Model) -> Outcome<Value>;
+}
@@ -0,0 +1,219 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use super::{ClaimEnricherTrait, ClaimSourceTrait, RestClaimSource};
+use crate::config::traits::ClaimSourcesConfigTrait;
+use crate::config::types::{ClaimFailurePolicy, ClaimSourceKind, ClaimSourcesConfig};
+use crate::data::entities::shared::participant;
+use crate::errors::{Errors, Outcome};
+use crate::services::vault::VaultService;
+use crate::types::vcs::VcType;
+
+type CacheKey = (String, String);
+
+/// Enricher dispatching the configured mappings to their claim sources.
+///
+/// Records are cached per source and participant for the configured TTL; failed lookups
+/// are not cached, so a recovering source is picked up on the next issuance.
+pub struct ClaimEnricherService {
+    config: ClaimSourcesConfig,
+    sources: HashMap<String, Arc<dyn ClaimSourceTrait>>,
+    cache: RwLock<HashMap<CacheKey, (Instant, Value)>>,
+}
+
+impl ClaimEnricherService {
+    /// Instantiates the configured sources.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FeatureNotImplError`] when an LDAP source is configured in a
+    /// build without the `ldap` feature.
+    pub fn new(config: ClaimSourcesConfig, vault: Arc<VaultService>) -> Outcome<Self> {
+        let mut sources: HashMap<String, Arc<dyn ClaimSourceTrait>> = HashMap::new();
+        for source in config.get_claim_sources() {
+            let secrets_path = source.secrets_path.clone();
+            let built: Arc<dyn ClaimSourceTrait> = match &source.kind {
+                ClaimSourceKind::Rest { url } => Arc::new(RestClaimSource::new(
+                    url.clone(),
+                    secrets_path,
+                    vault.clone(),
+                )),
+                #[cfg(feature = "ldap")]
+                ClaimSourceKind::Ldap {
+                    url,
+                    base_dn,
+                    filter,
+                    bind_dn,
+                } => Arc::new(super::LdapClaimSource::new(
+                    url.clone(),
+                    base_dn.clone(),
+                    filter.clone(),
+                    bind_dn.clone(),
+                    secrets_path,
+                    vault.clone(),
+                )),
+                #[cfg(not(feature = "ldap"))]
+                ClaimSourceKind::Ldap { .. } => {
+                    return Err(Errors::not_impl(
+                        format!(
+                            "Claim source '{}' needs a build with the ldap feature",
+                            source.name
+                        ),
+                        None,
+                    ));
+                }
+            };
+            sources.insert(source.name.clone(), built);
+        }
+
+        Ok(Self {
+            config,
+            sources,
+            cache: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Registers a custom source under `name`, replacing a configured one with the same name.
+    pub fn with_source(
+        mut self,
+        name: impl Into<String>,
+        source: Arc<dyn ClaimSourceTrait>,
+    ) -> Self {
+        self.sources.insert(name.into(), source);
+        self
+    }
+
+    /// Drops every cached record, e.g. after the authoritative system was corrected.
+    pub async fn clear_cache(&self) {
+        self.cache.write().await.clear();
+    }
+
+    async fn record(&self, source: &str, participant: &participant::Model) -> Outcome<Value> {
+        let key = (source.to_string(), participant.participant_id.clone());
+        if let Some((fetched_at, record)) = self.cache.read().await.get(&key) {
+            if fetched_at.elapsed() < self.config.get_claim_cache_ttl() {
+                return Ok(record.clone());
+            }
+        }
+
+        let record = self
+            .sources
+            .get(source)
+            .ok_or_else(|| {
+                Errors::missing_resource(source, "Claim source is not configured", None)
+            })?
+            .fetch(participant)
+            .await?;
+        self.cache
+            .write()
+            .await
+            .insert(key, (Instant::now(), record.clone()));
+        Ok(record)
+    }
+
+    async fn lookup(
+        &self,
+        source: &str,
+        attribute: &str,
+        participant: &participant::Model,
+    ) -> Outcome<Value> {
+        let record = self.record(source, participant).await?;
+        record
+            .pointer(&format!("/{}", attribute.replace('.', "/")))
+            .filter(|value| !value.is_null())
+            .cloned()
+            .ok_or_else(|| {
+                Errors::missing_resource(
+                    &participant.participant_id,
+                    format!("Claim source '{source}' has no '{attribute}' for the participant"),
+                    None,
+                )
+            })
+    }
+}
+
+#[async_trait]
+impl ClaimEnricherTrait for ClaimEnricherService {
+    async fn enrich(
+        &self,
+        vc_type: &VcType,
+        participant: &participant::Model,
+        subject: &mut Value,
+    ) -> Outcome<()> {
+        let mappings = self.config.get_claim_mappings(vc_type);
+        if mappings.is_empty() {
+            return Ok(());
+        }
+        info!(
+            "Enriching {vc_type} subject of {} from claim sources",
+            participant.participant_id
+        );
+
+        for mapping in mappings {
+            let value = match self
+                .lookup(&mapping.source, &mapping.attribute, participant)
+                .await
+            {
+                Ok(value) => value,
+                Err(e) => match &mapping.on_failure {
+                    ClaimFailurePolicy::Fail => return Err(e),
+                    ClaimFailurePolicy::Keep => {
+                        warn!("Keeping templated {}: {e}", mapping.field);
+                        continue;
+                    }
+                    ClaimFailurePolicy::Default(value) => {
+                        warn!("Defaulting {}: {e}", mapping.field);
+                        value.clone()
+                    }
+                },
+            };
+            set_field(subject, &mapping.field, value);
+        }
+        Ok(())
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Writes `value` at the dotted `path` of `subject`, creating intermediate objects.
+fn set_field(subject: &mut Value, path: &str, value: Value) {
+    let mut current = subject;
+    let mut segments = path.split('.').peekable();
+    while let Some(segment) = segments.next() {
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
+        }
+        let Value::Object(map) = current else {
+            unreachable!();
+        };
+        if segments.peek().is_none() {
+            map.insert(segment.to_string(), value);
+            return;
+        }
+        current = map
+            .entry(segment)
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+}
@@ -0,0 +1,40 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::participant;
+use crate::errors::Outcome;
+use crate::types::vcs::VcType;
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// Credential subject enrichment from the configured claim sources.
+#[async_trait]
+pub trait ClaimEnricherTrait: Send + Sync + 'static {
+    /// Fills the fields of `subject` mapped for `vc_type` with the records of `participant`.
+    ///
+    /// Meant to run on a rendered template, so looked up values replace whatever the
+    /// template put in their place.
+    ///
+    /// # Errors
+    /// Fails when a value cannot be obtained for a mapping whose policy is `fail`.
+    async fn enrich(
+        &self,
+        vc_type: &VcType,
+        participant: &participant::Model,
+        subject: &mut Value,
+    ) -> Outcome<()>;
+}
@@ -0,0 +1,136 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ldap3::{LdapConnAsync, LdapError, Scope, SearchEntry, ldap_escape};
+use serde_json::{Map, Value};
+use tracing::info;
+
+use super::ClaimSourceTrait;
+use crate::data::entities::shared::participant;
+use crate::errors::{Errors, Outcome, PetitionFailure};
+use crate::services::vault::{VaultService, VaultTrait};
+use crate::types::secrets::ClaimSourceSecrets;
+use crate::types::vcs::vc_specs::template::TemplateVars;
+
+/// Claim source searching an LDAP directory for the participant entry.
+///
+/// Placeholders of the filter are escaped per RFC 4515. Single-valued attributes become
+/// strings and multi-valued ones arrays of strings.
+pub struct LdapClaimSource {
+    url: String,
+    base_dn: String,
+    filter: String,
+    bind_dn: Option<String>,
+    secrets_path: Option<String>,
+    vault: Arc<VaultService>,
+}
+
+impl LdapClaimSource {
+    pub fn new(
+        url: String,
+        base_dn: String,
+        filter: String,
+        bind_dn: Option<String>,
+        secrets_path: Option<String>,
+        vault: Arc<VaultService>,
+    ) -> Self {
+        Self {
+            url,
+            base_dn,
+            filter,
+            bind_dn,
+            secrets_path,
+            vault,
+        }
+    }
+
+    async fn bind_password(&self) -> Outcome<String> {
+        let path = self.secrets_path.as_deref().ok_or_else(|| {
+            Errors::missing_resource(&self.url, "LDAP bind requires a secrets_path", None)
+        })?;
+        let secrets: ClaimSourceSecrets = self.vault.read(None, path).await?;
+        secrets
+            .password
+            .ok_or_else(|| Errors::missing_resource(path, "LDAP secrets carry no password", None))
+    }
+
+    fn failed(&self, reason: &str, e: LdapError) -> Errors {
+        Errors::petition(
+            &self.url,
+            "LDAP",
+            None,
+            PetitionFailure::Network,
+            reason,
+            Some(Box::new(e)),
+        )
+    }
+}
+
+#[async_trait]
+impl ClaimSourceTrait for LdapClaimSource {
+    async fn fetch(&self, participant: &participant::Model) -> Outcome<Value> {
+        let filter = TemplateVars::default()
+            .participant(participant)
+            .escaped(|s| ldap_escape(s).into_owned())
+            .render(&self.filter)?;
+        info!("Searching {} for {filter}", self.url);
+
+        let (conn, mut ldap) = LdapConnAsync::new(&self.url)
+            .await
+            .map_err(|e| self.failed("Unable to reach LDAP directory", e))?;
+        ldap3::drive!(conn);
+
+        if let Some(bind_dn) = &self.bind_dn {
+            let password = self.bind_password().await?;
+            ldap.simple_bind(bind_dn, &password)
+                .await
+                .and_then(|res| res.success())
+                .map_err(|e| self.failed("LDAP bind failed", e))?;
+        }
+        let (entries, _) = ldap
+            .search(&self.base_dn, Scope::Subtree, &filter, vec!["*"])
+            .await
+            .and_then(|res| res.success())
+            .map_err(|e| self.failed("LDAP search failed", e))?;
+        let _ = ldap.unbind().await;
+
+        let mut entries = entries.into_iter();
+        let (Some(entry), None) = (entries.next(), entries.next()) else {
+            return Err(Errors::missing_resource(
+                &participant.participant_id,
+                format!("LDAP filter {filter} does not match a single entry"),
+                None,
+            ));
+        };
+
+        let record: Map<String, Value> = SearchEntry::construct(entry)
+            .attrs
+            .into_iter()
+            .map(|(name, mut values)| {
+                let value = match values.len() {
+                    1 => Value::String(values.remove(0)),
+                    _ => Value::from(values),
+                };
+                (name, value)
+            })
+            .collect();
+        Ok(Value::Object(record))
+    }
+}
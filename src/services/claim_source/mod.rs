@@ -0,0 +1,30 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod claim_source_trait;
+mod enricher;
+mod enricher_trait;
+#[cfg(feature = "ldap")]
+mod ldap;
+mod rest;
+
+pub use claim_source_trait::ClaimSourceTrait;
+pub use enricher::ClaimEnricherService;
+pub use enricher_trait::ClaimEnricherTrait;
+#[cfg(feature = "ldap")]
+pub use ldap::LdapClaimSource;
+pub use rest::RestClaimSource;
@@ -0,0 +1,107 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::http::header::AUTHORIZATION;
+use axum::http::{HeaderMap, HeaderValue};
+use serde_json::Value;
+use tracing::info;
+
+use super::ClaimSourceTrait;
+use crate::data::entities::shared::participant;
+use crate::errors::{BadFormat, Errors, Outcome, PetitionFailure};
+use crate::services::client::ClientTrait;
+use crate::services::vault::{VaultService, VaultTrait};
+use crate::types::secrets::ClaimSourceSecrets;
+use crate::types::vcs::vc_specs::template::TemplateVars;
+use crate::utils::{ResponseExt, http_client};
+
+/// Claim source answering `GET {url}` with a JSON record.
+///
+/// Placeholders of the URL are percent-encoded; a `404` means the participant is unknown.
+pub struct RestClaimSource {
+    url: String,
+    secrets_path: Option<String>,
+    vault: Arc<VaultService>,
+}
+
+impl RestClaimSource {
+    pub fn new(url: String, secrets_path: Option<String>, vault: Arc<VaultService>) -> Self {
+        Self {
+            url,
+            secrets_path,
+            vault,
+        }
+    }
+
+    async fn headers(&self) -> Outcome<Option<HeaderMap>> {
+        let Some(path) = &self.secrets_path else {
+            return Ok(None);
+        };
+        let secrets: ClaimSourceSecrets = self.vault.read(None, path).await?;
+        let Some(token) = secrets.token else {
+            return Ok(None);
+        };
+
+        let value = HeaderValue::from_str(&format!("Bearer {token}")).map_err(|e| {
+            Errors::format(
+                BadFormat::Sent,
+                "Claim source token is not a valid header",
+                Some(Box::new(e)),
+            )
+        })?;
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, value);
+        Ok(Some(headers))
+    }
+}
+
+#[async_trait]
+impl ClaimSourceTrait for RestClaimSource {
+    async fn fetch(&self, participant: &participant::Model) -> Outcome<Value> {
+        let url = TemplateVars::default()
+            .participant(participant)
+            .escaped(|s| urlencoding::encode(s).into_owned())
+            .render(&self.url)?;
+        info!(
+            "Fetching claims of {} from {url}",
+            participant.participant_id
+        );
+
+        let res = http_client().get(&url, self.headers().await?).await?;
+        if res.status().as_u16() == 404 {
+            return Err(Errors::missing_resource(
+                &participant.participant_id,
+                format!("{url} holds no record for the participant"),
+                None,
+            ));
+        }
+        if !res.status().is_success() {
+            return Err(Errors::petition(
+                &url,
+                "GET",
+                Some(res.status()),
+                PetitionFailure::HttpStatus(res.status()),
+                "Claim source lookup failed",
+                None,
+            ));
+        }
+        res.parse_json().await
+    }
+}
@@ -0,0 +1,176 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tracing::{info, warn};
+
+use crate::types::http::{CircuitState, CircuitStatus};
+
+/// Circuit breaker keyed by destination `host:port`.
+///
+/// A host failing `failure_threshold` calls in a row gets its circuit opened: calls to it
+/// fail fast for `cooldown`, then a single probe is let through. A successful probe closes
+/// the circuit again, a failed one reopens it for another cooldown.
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    hosts: Mutex<HashMap<String, HostCircuit>>,
+}
+
+#[derive(Default)]
+struct HostCircuit {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probing: bool,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Decides whether a call to `host` may go out now.
+    ///
+    /// Returns the time left before the next probe when the circuit is open, or while the
+    /// probe of a half-open circuit is still in flight. The returned permit must be settled
+    /// with the outcome of the call.
+    pub fn admit(&self, host: &str) -> Result<CircuitPermit<'_>, Duration> {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        let mut permit = CircuitPermit {
+            breaker: self,
+            host: host.to_string(),
+            probe: false,
+        };
+        let Some(circuit) = hosts.get_mut(host) else {
+            return Ok(permit);
+        };
+        let Some(opened_at) = circuit.opened_at else {
+            return Ok(permit);
+        };
+        let elapsed = opened_at.elapsed();
+        if elapsed < self.cooldown {
+            return Err(self.cooldown - elapsed);
+        }
+        if circuit.probing {
+            return Err(Duration::ZERO);
+        }
+        circuit.probing = true;
+        permit.probe = true;
+        Ok(permit)
+    }
+
+    /// Frees the probe slot of `host` without judging the circuit.
+    fn release_probe(&self, host: &str) {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(circuit) = hosts.get_mut(host) {
+            circuit.probing = false;
+        }
+    }
+
+    /// Records a call to `host` that got an answer, closing its circuit.
+    pub fn record_success(&self, host: &str) {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(circuit) = hosts.remove(host) {
+            if circuit.opened_at.is_some() {
+                info!("Circuit towards {host} closed");
+            }
+        }
+    }
+
+    /// Records a failed call to `host`, opening its circuit past the threshold.
+    pub fn record_failure(&self, host: &str) {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        let circuit = hosts.entry(host.to_string()).or_default();
+        circuit.consecutive_failures += 1;
+        let reopen = circuit.probing;
+        circuit.probing = false;
+        if reopen || circuit.consecutive_failures >= self.failure_threshold {
+            if circuit.opened_at.is_none() || reopen {
+                warn!(
+                    "Circuit towards {host} opened after {} consecutive failures",
+                    circuit.consecutive_failures
+                );
+            }
+            circuit.opened_at = Some(Instant::now());
+        }
+    }
+
+    /// Lists the hosts with recent failures along with the state of their circuit.
+    pub fn statuses(&self) -> Vec<CircuitStatus> {
+        let hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        let mut statuses: Vec<_> = hosts
+            .iter()
+            .map(|(host, circuit)| {
+                let left = circuit
+                    .opened_at
+                    .map(|at| self.cooldown.checked_sub(at.elapsed()));
+                let (state, retry_in) = match left {
+                    None => (CircuitState::Closed, None),
+                    Some(Some(left)) => (CircuitState::Open, Some(left)),
+                    Some(None) => (CircuitState::HalfOpen, None),
+                };
+                CircuitStatus {
+                    host: host.clone(),
+                    state,
+                    consecutive_failures: circuit.consecutive_failures,
+                    retry_in_secs: retry_in.map(|left| left.as_secs()),
+                }
+            })
+            .collect();
+        statuses.sort_by(|a, b| a.host.cmp(&b.host));
+        statuses
+    }
+}
+
+/// Admission of one call through a [`CircuitBreaker`].
+///
+/// Dropping it unsettled, as when the calling future is cancelled mid-probe, frees the probe
+/// slot so the next call probes instead of the circuit staying half-open forever.
+pub struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    host: String,
+    probe: bool,
+}
+
+impl CircuitPermit<'_> {
+    /// Settles the call as answered, closing the circuit.
+    pub fn succeed(mut self) {
+        self.probe = false;
+        self.breaker.record_success(&self.host);
+    }
+
+    /// Settles the call as failed, counting it towards opening the circuit.
+    pub fn fail(mut self) {
+        self.probe = false;
+        self.breaker.record_failure(&self.host);
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if self.probe {
+            self.breaker.release_probe(&self.host);
+        }
+    }
+}
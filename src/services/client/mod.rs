@@ -15,8 +15,12 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod breaker;
 mod client_trait;
+mod mtls;
 mod rod_client;
 
+pub use breaker::{CircuitBreaker, CircuitPermit};
 pub use client_trait::ClientTrait;
+pub use mtls::DestinationTls;
 pub use rod_client::ClientService;
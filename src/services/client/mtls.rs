@@ -0,0 +1,106 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use reqwest::redirect::Policy;
+use reqwest::{Certificate, ClientBuilder, Identity};
+use tracing::warn;
+
+use crate::config::types::MtlsDestination;
+use crate::errors::{Errors, Outcome};
+use crate::services::vault::{VaultService, VaultTrait};
+use crate::types::secrets::StringHelper;
+use crate::utils::read;
+
+/// TLS material used to reach one destination.
+#[derive(Clone, Default)]
+pub struct DestinationTls {
+    /// Client certificate and key presented during the handshake.
+    pub identity: Option<Identity>,
+    /// CA certificates trusted on top of the system roots.
+    pub roots: Vec<Certificate>,
+    /// Skips server certificate validation.
+    pub insecure: bool,
+}
+
+impl DestinationTls {
+    /// Loads the material described by `destination`, reading the client identity from the
+    /// vault and the CA bundle from disk.
+    ///
+    /// The insecure flag is dropped, with a warning, when `is_prod` is set.
+    ///
+    /// # Errors
+    /// Returns a vault error when `VAULT_APP_CLIENT_CERT`/`VAULT_APP_CLIENT_KEY` are not set
+    /// or the client certificate or key is not stored, a read error
+    /// when the CA bundle is missing and a security error when any of them is not valid PEM.
+    pub async fn load(
+        vault: &VaultService,
+        destination: &MtlsDestination,
+        is_prod: bool,
+    ) -> Outcome<Self> {
+        let identity = match destination.client_identity {
+            true => {
+                let cert_path = std::env::var("VAULT_APP_CLIENT_CERT").map_err(|e| {
+                    Errors::vault("VAULT_APP_CLIENT_CERT env var not set", Some(Box::new(e)))
+                })?;
+                let key_path = std::env::var("VAULT_APP_CLIENT_KEY").map_err(|e| {
+                    Errors::vault("VAULT_APP_CLIENT_KEY env var not set", Some(Box::new(e)))
+                })?;
+                let cert: StringHelper = vault.read(None, &cert_path).await?;
+                let key: StringHelper = vault.read(None, &key_path).await?;
+                let pem = format!("{}\n{}", key.data(), cert.data());
+                let identity = Identity::from_pem(pem.as_bytes()).map_err(|e| {
+                    Errors::security("Invalid mTLS client identity", Some(Box::new(e)))
+                })?;
+                Some(identity)
+            }
+            false => None,
+        };
+
+        let roots = match &destination.ca_bundle {
+            Some(path) => Certificate::from_pem_bundle(read(path)?.as_bytes()).map_err(|e| {
+                Errors::security(format!("Invalid CA bundle {path}"), Some(Box::new(e)))
+            })?,
+            None => Vec::new(),
+        };
+
+        if destination.insecure && is_prod {
+            warn!(
+                "Ignoring insecure TLS for {} in production",
+                destination.origin
+            );
+        }
+
+        Ok(Self {
+            identity,
+            roots,
+            insecure: destination.insecure && !is_prod,
+        })
+    }
+
+    /// Applies the material to a client under construction.
+    ///
+    /// A client presenting an identity follows no redirects, so the destination cannot send
+    /// it, authenticated, somewhere else.
+    pub(crate) fn apply(self, mut builder: ClientBuilder) -> ClientBuilder {
+        if let Some(identity) = self.identity {
+            builder = builder.identity(identity).redirect(Policy::none());
+        }
+        builder
+            .tls_certs_merge(self.roots)
+            .tls_danger_accept_invalid_certs(self.insecure)
+    }
+}
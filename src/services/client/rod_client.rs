@@ -15,27 +15,47 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
-use axum::http::HeaderMap;
-use reqwest::{Client, RequestBuilder, Response};
+use axum::http::{HeaderMap, HeaderValue};
+use reqwest::{Client, ClientBuilder, NoProxy, Proxy, RequestBuilder, Response, Url};
 use tokio::sync::Semaphore;
-use tracing::info;
+use tracing::{debug, info};
+use uuid::Uuid;
+use crate::config::traits::{ConnectionConfigTrait, MtlsConfigTrait, ProxyConfigTrait};
 use crate::errors::{Errors, Outcome, PetitionFailure};
-use crate::services::client::ClientTrait;
-use crate::types::http::HttpBody;
+use crate::services::client::{CircuitBreaker, ClientTrait, DestinationTls};
+use crate::services::vault::VaultService;
+use crate::types::http::{CORRELATION_HEADER, HttpBody, current_correlation_id};
 
 /// Rate-limited HTTP Client Service with exponential backoff retries.
 ///
 /// Wraps a standard `reqwest::Client` inside a Tokio `Semaphore` safety shell to strictly
 /// govern outbound concurrency. Evaluates `5xx` statuses and network errors to auto-execute
 /// backoff loops without cascading crashes to identity protocols.
+///
+/// Destinations requiring client authentication get their own client, carrying the mTLS
+/// material registered with [`with_destination_tls`](Self::with_destination_tls). Every
+/// client goes through the proxy set with [`with_proxy`](Self::with_proxy), if any. With a
+/// [circuit breaker](Self::with_circuit_breaker), calls to a host that keeps failing fail
+/// fast instead of spending their retries.
+///
+/// Every call carries an `x-correlation-id` header, taken from the current task when the
+/// work runs [under one](crate::types::http::with_correlation_id), and is logged at debug
+/// level with its status and duration.
 pub struct ClientService {
     client: Client,
+    destinations: HashMap<String, Client>,
+    tls: HashMap<String, DestinationTls>,
+    proxy: Option<Proxy>,
+    breaker: Option<Arc<CircuitBreaker>>,
     limiter: Arc<Semaphore>,
     max_retries: u32,
+    concurrency_limit: usize,
+    timeout: Duration,
 }
 
 impl Default for ClientService {
@@ -46,23 +66,124 @@ impl Default for ClientService {
 
 impl ClientService {
     pub fn new(concurrency_limit: usize, timeout_secs: u64, max_retries: u32) -> Self {
+        let timeout = Duration::from_secs(timeout_secs);
         let client = Client::builder()
-            .timeout(Duration::from_secs(timeout_secs))
+            .timeout(timeout)
             .pool_max_idle_per_host(concurrency_limit)
             .build()
             .expect("Failed to build request client");
 
         Self {
             client,
+            destinations: HashMap::new(),
+            tls: HashMap::new(),
+            proxy: None,
+            breaker: None,
             limiter: Arc::new(Semaphore::new(concurrency_limit)),
             max_retries,
+            concurrency_limit,
+            timeout,
+        }
+    }
+
+    /// Reaches every URL under `origin` with a dedicated client carrying `tls`.
+    ///
+    /// # Errors
+    /// Returns a validation error when `origin` is not a URL and a security error when the
+    /// TLS material is rejected.
+    pub fn with_destination_tls(mut self, origin: &str, tls: DestinationTls) -> Outcome<Self> {
+        let key = origin_key(origin)?;
+        let client = self.destination_client(&key, tls.clone())?;
+        self.destinations.insert(key.clone(), client);
+        self.tls.insert(key, tls);
+        Ok(self)
+    }
+
+    /// Sends every request through the proxy of `config`, except those to hosts on its
+    /// `no_proxy` list. A config without proxy URL leaves the clients untouched.
+    ///
+    /// `http`, `https` and `socks5` proxy URLs are accepted, with credentials in the URL.
+    ///
+    /// # Errors
+    /// Returns a validation error when the proxy URL is invalid.
+    pub fn with_proxy(mut self, config: &impl ProxyConfigTrait) -> Outcome<Self> {
+        let Some(url) = config.get_proxy_url() else {
+            return Ok(self);
+        };
+        let proxy = Proxy::all(url)
+            .map_err(|e| Errors::validation("Invalid proxy URL", Some(Box::new(e))))?
+            .no_proxy(NoProxy::from_string(&config.get_no_proxy().join(",")));
+        self.proxy = Some(proxy);
+
+        self.client = self
+            .builder()
+            .build()
+            .map_err(|e| Errors::validation("Unable to build proxied client", Some(Box::new(e))))?;
+        for (key, tls) in &self.tls {
+            let client = self.destination_client(key, tls.clone())?;
+            self.destinations.insert(key.clone(), client);
         }
+        info!("Routing outbound requests through the configured proxy");
+        Ok(self)
+    }
+
+    /// Registers every mTLS destination of `config`, reading the client identity from `vault`.
+    ///
+    /// Insecure destinations are only honoured outside production.
+    pub async fn with_mtls<C>(mut self, config: &C, vault: &VaultService) -> Outcome<Self>
+    where
+        C: MtlsConfigTrait + ConnectionConfigTrait + Sync,
+    {
+        for destination in config.get_mtls_destinations() {
+            let tls = DestinationTls::load(vault, destination, config.is_prod()).await?;
+            self = self.with_destination_tls(&destination.origin, tls)?;
+            info!("Using mTLS towards {}", destination.origin);
+        }
+        Ok(self)
+    }
+
+    /// Opens the circuit of a host after `failure_threshold` consecutive network errors or
+    /// `5xx` answers, failing calls to it fast for `cooldown` before probing it again.
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, cooldown: Duration) -> Self {
+        self.breaker = Some(Arc::new(CircuitBreaker::new(failure_threshold, cooldown)));
+        self
+    }
+
+    /// Returns the circuit breaker, for diagnostics, when one is set.
+    pub fn circuit_breaker(&self) -> Option<Arc<CircuitBreaker>> {
+        self.breaker.clone()
     }
 
     // -----------------------------------------------------------------------
     // INTERNALS
     // -----------------------------------------------------------------------
 
+    fn builder(&self) -> ClientBuilder {
+        let builder = Client::builder()
+            .timeout(self.timeout)
+            .pool_max_idle_per_host(self.concurrency_limit);
+        match &self.proxy {
+            Some(proxy) => builder.proxy(proxy.clone()),
+            None => builder,
+        }
+    }
+
+    fn destination_client(&self, origin: &str, tls: DestinationTls) -> Outcome<Client> {
+        tls.apply(self.builder()).build().map_err(|e| {
+            Errors::security(
+                format!("Unable to build mTLS client for {origin}"),
+                Some(Box::new(e)),
+            )
+        })
+    }
+
+    fn client_for(&self, url: &str) -> &Client {
+        origin_key(url)
+            .ok()
+            .and_then(|key| self.destinations.get(&key))
+            .unwrap_or(&self.client)
+    }
+
     async fn dispatch(
         &self,
         method: reqwest::Method,
@@ -132,25 +253,83 @@ impl ClientService {
         headers: Option<HeaderMap>,
         body: HttpBody,
     ) -> Outcome<Response> {
-        info!("Sending {} to {}", method, url);
-        let mut req = self.client.request(method.clone(), url);
+        let mut req = self.client_for(url).request(method.clone(), url);
 
-        if let Some(h) = headers {
-            req = req.headers(h);
-        }
+        let mut headers = headers.unwrap_or_default();
+        let correlation_id = match headers.get(CORRELATION_HEADER) {
+            Some(id) => id.to_str().unwrap_or_default().to_string(),
+            None => {
+                let id = current_correlation_id().unwrap_or_else(|| Uuid::new_v4().to_string());
+                if let Ok(value) = HeaderValue::from_str(&id) {
+                    headers.insert(CORRELATION_HEADER, value);
+                }
+                id
+            }
+        };
+        req = req.headers(headers);
 
         req = self.apply_body(req, body)?;
 
-        let response = req.send().await.map_err(|e| {
-            Errors::petition(
-                url,
-                method.as_str(),
-                e.status().map(|s| s),
-                PetitionFailure::Network,
-                "Error sending petition",
-                Some(Box::new(e)),
-            )
-        })?;
+        let authority = Url::parse(url).ok().and_then(|url| {
+            let host = url.host_str()?;
+            let port = url.port_or_known_default()?;
+            Some(format!("{host}:{port}"))
+        });
+        let permit = match self.breaker.as_ref().zip(authority) {
+            Some((breaker, authority)) => match breaker.admit(&authority) {
+                Ok(permit) => Some(permit),
+                Err(retry_in) => {
+                    return Err(Errors::petition(
+                        url,
+                        method.as_str(),
+                        None,
+                        PetitionFailure::CircuitOpen,
+                        format!(
+                            "Circuit towards {authority} is open, retry in {}s",
+                            retry_in.as_secs()
+                        ),
+                        None,
+                    ));
+                }
+            },
+            None => None,
+        };
+
+        let logged_url = without_query(url);
+        let started = Instant::now();
+        let response = match req.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                debug!(
+                    "{method} {logged_url} failed after {}ms [{correlation_id}]: {e}",
+                    started.elapsed().as_millis()
+                );
+                if let Some(permit) = permit {
+                    permit.fail();
+                }
+                return Err(Errors::petition(
+                    url,
+                    method.as_str(),
+                    e.status().map(|s| s),
+                    PetitionFailure::Network,
+                    "Error sending petition",
+                    Some(Box::new(e)),
+                ));
+            }
+        };
+
+        debug!(
+            "{method} {logged_url} -> {} in {}ms [{correlation_id}]",
+            response.status(),
+            started.elapsed().as_millis()
+        );
+
+        if let Some(permit) = permit {
+            match response.status().is_server_error() {
+                true => permit.fail(),
+                false => permit.succeed(),
+            }
+        }
 
         if response.status().is_server_error() {
             let status = response.status();
@@ -225,3 +404,24 @@ impl ClientTrait for ClientService {
             .await
     }
 }
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Reduces a URL to the `scheme://host:port` origin destinations are registered under.
+fn origin_key(url: &str) -> Outcome<String> {
+    Url::parse(url)
+        .map(|url| url.origin().ascii_serialization())
+        .map_err(|e| Errors::validation(format!("Invalid destination {url}"), Some(Box::new(e))))
+}
+
+/// Strips the query and fragment of a URL before logging it, as they may carry tokens or codes.
+fn without_query(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(mut parsed) => {
+            parsed.set_query(None);
+            parsed.set_fragment(None);
+            parsed.to_string()
+        }
+        Err(_) => url.split(['?', '#']).next().unwrap_or_default().to_string(),
+    }
+}
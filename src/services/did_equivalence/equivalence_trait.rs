@@ -0,0 +1,47 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::errors::Outcome;
+use crate::types::dids::{DidEquivalence, DidEquivalenceProof};
+use async_trait::async_trait;
+
+/// DID equivalence service.
+///
+/// Binds the DIDs the agent publishes for the same key (e.g. a `did:web` and its `did:jwk`)
+/// through signed cross-references, and checks the ones published by peers.
+#[async_trait]
+pub trait DidEquivalenceTrait: Send + Sync + 'static {
+    /// Signs one statement per direction between the primary DID and each alias.
+    async fn build_proof(&self) -> Outcome<DidEquivalenceProof>;
+
+    /// Verifies an equivalence statement JWT and returns its payload.
+    ///
+    /// The signature must come from the DID named in `iss`.
+    async fn verify_statement(&self, token: &str) -> Outcome<DidEquivalence>;
+
+    /// Checks that a `did:jwk` alias encodes a key published in the document of `did`.
+    ///
+    /// Aliases of any other method are accepted as is, their statements being the only proof.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::SecurityError`](crate::errors::Errors) when no verification
+    /// method of `did` holds the alias key.
+    async fn verify_alias_key(&self, did: &str, alias: &str) -> Outcome<()>;
+
+    /// Whether `id` is the primary DID of the agent or one of its aliases.
+    async fn is_self(&self, id: &str) -> bool;
+}
@@ -0,0 +1,193 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::DidEquivalenceTrait;
+use crate::capabilities::{Did, DidResolver, Verifier};
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::services::signer::{SignerTrait, VaultKeySigner};
+use crate::services::vault::VaultService;
+use crate::types::dids::{
+    DidEquivalence, DidEquivalenceProof, EQUIVALENCE_TYP, VerificationMaterial, VerificationMethod,
+};
+use crate::types::jwt::Jwt;
+use crate::types::wallet::{Identity, KeyRef};
+use crate::utils::Clock;
+
+/// Verification method fragment of every `did:jwk` document.
+const JWK_FRAGMENT: &str = "0";
+
+/// Equivalence publisher signing with the node's active identity.
+pub struct DidEquivalenceService {
+    identity: Arc<RwLock<Identity>>,
+    signer: Arc<dyn SignerTrait>,
+    resolver: DidResolver,
+}
+
+impl DidEquivalenceService {
+    pub fn new(vault: Arc<VaultService>, identity: Arc<RwLock<Identity>>) -> Self {
+        Self {
+            identity,
+            signer: Arc::new(VaultKeySigner::new(vault)),
+            resolver: DidResolver::default(),
+        }
+    }
+
+    /// Signs through `signer`, e.g. a Vault Transit or KMS signer keeping the private keys
+    /// out of the process, instead of reading them from the vault.
+    pub fn with_signer(mut self, signer: Arc<dyn SignerTrait>) -> Self {
+        self.signer = signer;
+        self
+    }
+
+    /// Resolves signer DIDs through `resolver`, e.g. one with a Universal Resolver fallback.
+    pub fn with_did_resolver(mut self, resolver: DidResolver) -> Self {
+        self.resolver = resolver;
+        self
+    }
+}
+
+#[async_trait]
+impl DidEquivalenceTrait for DidEquivalenceService {
+    async fn build_proof(&self) -> Outcome<DidEquivalenceProof> {
+        let (did, key_ref, aliases) = {
+            let lock = self.identity.read().await;
+            (
+                lock.did().clone(),
+                lock.key_ref().clone(),
+                lock.aliases().to_vec(),
+            )
+        };
+
+        let mut proof = DidEquivalenceProof {
+            did: did.id().to_string(),
+            also_known_as: aliases.iter().map(|a| a.id().to_string()).collect(),
+            statements: Vec::new(),
+        };
+        if aliases.is_empty() {
+            return Ok(proof);
+        }
+
+        info!("Signing equivalence statements for {}", did.id());
+        for alias in &aliases {
+            let alias_frag = match alias {
+                Did::Jwk(_) => JWK_FRAGMENT,
+                Did::Web(_) | Did::External(_) => key_ref.fragment(),
+            };
+            let alias_ref = KeyRef::new(key_ref.internal(), alias_frag);
+            let forward = sign(self.signer.as_ref(), (&did, &key_ref), alias).await?;
+            let backward = sign(self.signer.as_ref(), (alias, &alias_ref), &did).await?;
+            proof.statements.extend([forward, backward]);
+        }
+        Ok(proof)
+    }
+
+    async fn verify_statement(&self, token: &str) -> Outcome<DidEquivalence> {
+        let jwt = Jwt::parse(token)?;
+        if jwt.header().typ.as_deref() != Some(EQUIVALENCE_TYP) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Equivalence statement typ must be '{EQUIVALENCE_TYP}'"),
+                None,
+            ));
+        }
+
+        let (kid, statement) =
+            Verifier::verify_enveloped::<DidEquivalence>(&self.resolver, &jwt, None).await?;
+        if kid.did().id() != statement.iss {
+            return Err(Errors::security(
+                "Equivalence statement iss & kid do not match",
+                None,
+            ));
+        }
+        Ok(statement)
+    }
+
+    async fn verify_alias_key(&self, did: &str, alias: &str) -> Outcome<()> {
+        let alias = Did::parse(alias)?;
+        if !matches!(alias, Did::Jwk(_)) {
+            return Ok(());
+        }
+        let alias_doc = self.resolver.resolve(&alias).await?;
+        let did_doc = self.resolver.resolve(&Did::parse(did)?).await?;
+
+        let shared = alias_doc
+            .verification_method
+            .iter()
+            .filter_map(public_jwk)
+            .any(|key| {
+                did_doc
+                    .verification_method
+                    .iter()
+                    .filter_map(public_jwk)
+                    .any(|candidate| same_key(key, candidate))
+            });
+        if !shared {
+            return Err(Errors::security(
+                format!("{} does not encode a key of {did}", alias.id()),
+                None,
+            ));
+        }
+        Ok(())
+    }
+
+    async fn is_self(&self, id: &str) -> bool {
+        self.identity.read().await.is_self(id)
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Returns the JWK of a verification method, if it publishes one.
+fn public_jwk(vm: &VerificationMethod) -> Option<&Value> {
+    match &vm.material {
+        VerificationMaterial::JsonWebKey { public_key_jwk }
+        | VerificationMaterial::JsonWebKey2020 { public_key_jwk } => Some(public_key_jwk),
+        VerificationMaterial::Multikey { .. } => None,
+    }
+}
+
+/// Whether two JWKs hold the same public key, ignoring `kid`, `alg` and other metadata.
+fn same_key(a: &Value, b: &Value) -> bool {
+    a.get("kty").is_some()
+        && ["kty", "crv", "x", "y", "n", "e"]
+            .iter()
+            .all(|member| a.get(member) == b.get(member))
+}
+
+/// Signs, as `identity`, the statement that `sub` shares its key.
+async fn sign(signer: &dyn SignerTrait, identity: (&Did, &KeyRef), sub: &Did) -> Outcome<String> {
+    let (did, key_ref) = identity;
+
+    let statement = DidEquivalence {
+        iss: did.id().to_string(),
+        sub: sub.id().to_string(),
+        iat: Clock::issued_now().timestamp(),
+    };
+    let claims = serde_json::to_value(&statement)?;
+
+    let jwt = signer
+        .sign_enveloped(did, key_ref, EQUIVALENCE_TYP, "json", &claims)
+        .await?;
+    Ok(jwt.as_str().to_string())
+}
@@ -0,0 +1,46 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::errors::Outcome;
+use crate::types::verification::{DidEvidence, EvidenceBundle, StatusListEvidence};
+use async_trait::async_trait;
+
+/// Verification evidence service.
+///
+/// Captures the external inputs of a verification (DID documents, status lists) as they stand
+/// and signs the resulting bundle, so it can be checked long after those inputs have changed.
+#[async_trait]
+pub trait EvidenceTrait: Send + Sync + 'static {
+    /// Returns the DID bundles are signed under.
+    async fn issuer(&self) -> String;
+
+    /// Resolves `did` now. Resolution failures are recorded in the snapshot, not returned.
+    async fn snapshot_did(&self, did: &str) -> DidEvidence;
+
+    /// Downloads the status list credential served at `url`, keeping it verbatim.
+    ///
+    /// Download failures are recorded in the snapshot, not returned.
+    async fn snapshot_status_list(&self, url: &str) -> StatusListEvidence;
+
+    /// Signs a bundle as a compact JWT using the active identity.
+    async fn sign_bundle(&self, bundle: &EvidenceBundle) -> Outcome<String>;
+
+    /// Verifies a bundle JWT and returns its payload.
+    ///
+    /// The signature must come from the DID named in `iss`.
+    async fn verify_bundle(&self, token: &str) -> Outcome<EvidenceBundle>;
+}
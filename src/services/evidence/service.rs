@@ -0,0 +1,172 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use super::EvidenceTrait;
+use crate::capabilities::{Did, DidResolver, Verifier};
+use crate::errors::{BadFormat, Errors, Outcome, PetitionFailure};
+use crate::services::client::ClientTrait;
+use crate::services::signer::{SignerTrait, VaultKeySigner};
+use crate::services::vault::VaultService;
+use crate::types::jwt::Jwt;
+use crate::types::verification::{DidEvidence, EVIDENCE_TYP, EvidenceBundle, StatusListEvidence};
+use crate::types::wallet::Identity;
+use crate::utils::{Clock, ResponseExt, encode_url_safe_no_pad, http_client};
+
+/// Evidence packager signing with the node's active identity.
+pub struct EvidenceService {
+    identity: Arc<RwLock<Identity>>,
+    signer: Arc<dyn SignerTrait>,
+    resolver: DidResolver,
+}
+
+impl EvidenceService {
+    pub fn new(vault: Arc<VaultService>, identity: Arc<RwLock<Identity>>) -> Self {
+        Self {
+            identity,
+            signer: Arc::new(VaultKeySigner::new(vault)),
+            resolver: DidResolver::default(),
+        }
+    }
+
+    /// Signs through `signer`, e.g. a Vault Transit or KMS signer keeping the private keys
+    /// out of the process, instead of reading them from the vault.
+    pub fn with_signer(mut self, signer: Arc<dyn SignerTrait>) -> Self {
+        self.signer = signer;
+        self
+    }
+
+    /// Resolves signer DIDs through `resolver`, e.g. one with a Universal Resolver fallback.
+    pub fn with_did_resolver(mut self, resolver: DidResolver) -> Self {
+        self.resolver = resolver;
+        self
+    }
+}
+
+#[async_trait]
+impl EvidenceTrait for EvidenceService {
+    async fn issuer(&self) -> String {
+        self.identity.read().await.did().id().to_string()
+    }
+
+    async fn snapshot_did(&self, did: &str) -> DidEvidence {
+        let resolved_at = Clock::now().timestamp();
+        let result = async { self.resolver.resolve(&Did::parse(did)?).await }.await;
+        if let Err(e) = &result {
+            warn!("Unable to resolve {did} for evidence: {e}");
+        }
+        let (document, error) = match result {
+            Ok(document) => (Some(document), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        DidEvidence {
+            did: did.to_string(),
+            resolved_at,
+            document,
+            error,
+        }
+    }
+
+    async fn snapshot_status_list(&self, url: &str) -> StatusListEvidence {
+        info!("Capturing status list {url} as evidence");
+        let retrieved_at = Clock::now().timestamp();
+
+        let result: Outcome<String> = async {
+            let res = http_client().get(url, None).await?;
+            if !res.status().is_success() {
+                return Err(Errors::petition(
+                    url,
+                    "GET",
+                    Some(res.status()),
+                    PetitionFailure::HttpStatus(res.status()),
+                    "Status list retrieval failed",
+                    None,
+                ));
+            }
+            res.parse_text().await
+        }
+        .await;
+
+        match result {
+            Ok(credential) => StatusListEvidence {
+                url: url.to_string(),
+                retrieved_at,
+                digest: Some(encode_url_safe_no_pad(Sha256::digest(
+                    credential.as_bytes(),
+                ))),
+                credential: Some(credential),
+                error: None,
+            },
+            Err(e) => {
+                warn!("Unable to capture status list {url}: {e}");
+                StatusListEvidence {
+                    url: url.to_string(),
+                    retrieved_at,
+                    credential: None,
+                    digest: None,
+                    error: Some(e.to_string()),
+                }
+            }
+        }
+    }
+
+    async fn sign_bundle(&self, bundle: &EvidenceBundle) -> Outcome<String> {
+        info!(
+            "Signing evidence bundle for verification {}",
+            bundle.verification_id
+        );
+
+        let (did, key_ref) = {
+            let lock = self.identity.read().await;
+            (lock.did().clone(), lock.key_ref().clone())
+        };
+        let claims = serde_json::to_value(bundle)?;
+
+        let jwt = self
+            .signer
+            .sign_enveloped(&did, &key_ref, EVIDENCE_TYP, "json", &claims)
+            .await?;
+        Ok(jwt.as_str().to_string())
+    }
+
+    async fn verify_bundle(&self, token: &str) -> Outcome<EvidenceBundle> {
+        let jwt = Jwt::parse(token)?;
+        if jwt.header().typ.as_deref() != Some(EVIDENCE_TYP) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Evidence bundle typ must be '{EVIDENCE_TYP}'"),
+                None,
+            ));
+        }
+
+        let (kid, bundle) =
+            Verifier::verify_enveloped::<EvidenceBundle>(&self.resolver, &jwt, None).await?;
+        if kid.did().id() != bundle.iss {
+            return Err(Errors::security(
+                "Evidence bundle iss & kid do not match",
+                None,
+            ));
+        }
+        Ok(bundle)
+    }
+}
@@ -15,8 +15,20 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::services::archive::ArchiveTrait;
+use crate::services::claim_source::ClaimEnricherTrait;
+use crate::services::did_equivalence::DidEquivalenceTrait;
+use crate::services::evidence::EvidenceTrait;
 use crate::services::issuer::IssuerTrait;
+use crate::services::log_filter::LogFilterTrait;
+use crate::services::receipt::ReceiptTrait;
+use crate::services::relying_party::RelyingPartyTrait;
+use crate::services::revocation_feed::RevocationFeedTrait;
+use crate::services::standby::StandbyTrait;
+use crate::services::status_list::StatusListTrait;
+use crate::services::trust_anchor::TrustAnchorTrait;
 use crate::services::vault::VaultService;
+use crate::services::vc_requester::VcRequesterTrait;
 use crate::services::verifier::VerifierTrait;
 use crate::services::wallet::WalletTrait;
 use std::sync::Arc;
@@ -67,3 +79,104 @@ pub trait HasIdentity {
     /// Returns a reference-counted pointer to the live Identity manager trait object.
     fn identity(&self) -> Arc<dyn IdentityTrait>;
 }
+
+/// Capability provider for the Relying-Party decision delivery service.
+///
+/// Lets verification modules fan out signed allow/deny decisions to subscribed
+/// relying applications once a presentation exchange reaches a final state.
+pub trait HasRelyingParty {
+    /// Returns a reference-counted pointer to the active Relying-Party service trait object.
+    fn relying_party(&self) -> Arc<dyn RelyingPartyTrait>;
+}
+
+/// Capability provider for the Bitstring Status List publishing service.
+///
+/// Lets issuance modules embed `credentialStatus` entries and serve signed status lists
+/// without binding to a concrete signing backend.
+pub trait HasStatusList {
+    /// Returns a reference-counted pointer to the active Status List service trait object.
+    fn status_list(&self) -> Arc<dyn StatusListTrait>;
+}
+
+/// Capability provider for the verification receipt service.
+///
+/// Lets verification modules hand holders a signed proof of a successful verification and
+/// check receipts presented back to them.
+pub trait HasReceipt {
+    /// Returns a reference-counted pointer to the active Receipt service trait object.
+    fn receipt(&self) -> Arc<dyn ReceiptTrait>;
+}
+
+/// Capability provider for the verification evidence service.
+///
+/// Lets verification modules seal the inputs of a decision into a signed bundle that outlives
+/// the DIDs and status lists it was taken from.
+pub trait HasEvidence {
+    /// Returns a reference-counted pointer to the active Evidence service trait object.
+    fn evidence(&self) -> Arc<dyn EvidenceTrait>;
+}
+
+/// Capability provider for the credential subject enricher.
+///
+/// Lets issuing modules fill configured subject fields from authoritative claim sources.
+pub trait HasClaimEnricher {
+    /// Returns a reference-counted pointer to the active Claim Enricher service trait object.
+    fn claim_enricher(&self) -> Arc<dyn ClaimEnricherTrait>;
+}
+
+/// Capability provider for the DID equivalence service.
+///
+/// Lets modules publish the signed bindings between the agent's DIDs and recognise the agent
+/// under any of them.
+pub trait HasDidEquivalence {
+    /// Returns a reference-counted pointer to the active DID Equivalence service trait object.
+    fn did_equivalence(&self) -> Arc<dyn DidEquivalenceTrait>;
+}
+
+/// Capability provider for the external trust anchor client.
+///
+/// Lets the trusted issuer registry be fed from remote registries instead of manual curation.
+pub trait HasTrustAnchor {
+    /// Returns a reference-counted pointer to the active Trust Anchor service trait object.
+    fn trust_anchor(&self) -> Arc<dyn TrustAnchorTrait>;
+}
+
+/// Capability provider for the revocation feed service.
+///
+/// Lets the authority publish signed revocation pages and participant agents follow them.
+pub trait HasRevocationFeed {
+    /// Returns a reference-counted pointer to the active Revocation Feed service trait object.
+    fn revocation_feed(&self) -> Arc<dyn RevocationFeedTrait>;
+}
+
+/// Capability provider for the active/standby role keeper.
+///
+/// Lets the standby module hand state over and promote the instance without re-onboarding.
+pub trait HasStandby {
+    /// Returns a reference-counted pointer to the active Standby service trait object.
+    fn standby(&self) -> Arc<dyn StandbyTrait>;
+}
+
+/// Capability provider for the runtime log filter controller.
+///
+/// Lets operators raise the verbosity of a single module while a flow is being diagnosed.
+pub trait HasLogFilter {
+    /// Returns a reference-counted pointer to the active Log Filter service trait object.
+    fn log_filter(&self) -> Arc<dyn LogFilterTrait>;
+}
+
+/// Capability provider for the long-term archive store.
+///
+/// Lets the archive module export finished flows before pruning them from the database.
+pub trait HasArchive {
+    /// Returns a reference-counted pointer to the active Archive service trait object.
+    fn archive(&self) -> Arc<dyn ArchiveTrait>;
+}
+
+/// Capability provider for the outbound GNAP credential requester.
+///
+/// Lets the holder ask remote authorities for credentials with signed grant requests.
+pub trait HasVcRequester {
+    /// Returns a reference-counted pointer to the active VC Requester service trait object.
+    fn vc_requester(&self) -> Arc<dyn VcRequesterTrait>;
+}
@@ -20,11 +20,12 @@ use crate::errors::Outcome;
 use crate::types::gnap::grant_request::GrantRequestKind;
 use crate::types::gnap::grant_request::client::Client;
 use crate::types::issuance::{
-    AuthServerMetadata, CredentialRequest, IssuerMetadata, IssuingToken, VcCredOffer,
-    VcTransmissionOffer,
+    AuthServerMetadata, CredentialRequest, IssuerMetadata, IssuingToken, NotificationRequest,
+    VcCredOffer, VcTransmissionOffer,
 };
-use crate::types::jwt::VCJwtClaims;
-use crate::types::vcs::{VcType, VcTypeConfig};
+use crate::types::jwt::{SdJwtVcClaims, VCJwtClaims};
+use crate::types::vcs::{CredentialBuilder, VcType, VcTypeConfig, W3cDataModelVersion};
+use crate::utils::{Missing, Present};
 use async_trait::async_trait;
 
 /// OpenID4VCI Verifiable Credential Issuer service specification.
@@ -64,16 +65,50 @@ pub trait IssuerTrait: Send + Sync + 'static {
     // ===== SECURITY VALIDATION & SIGNING =========================================================
 
     /// Formulates a valid access [`IssuingToken`] package containing session lifetimes.
-    fn get_token(&self, model: &issuance::Model) -> IssuingToken;
+    ///
+    /// Rotates the session `c_nonce` so every token response carries a fresh one; the
+    /// caller is responsible for persisting the mutated [`issuance::Model`].
+    fn get_token(&self, model: &mut issuance::Model) -> IssuingToken;
 
     /// Validates the client's payload request token against the session state and asserts the Proof of Possession (PoP).
+    ///
+    /// The proof nonce must match an unexpired, unconsumed `c_nonce`. On success the nonce is
+    /// marked as consumed so the same DidPossession proof cannot be replayed; the caller must
+    /// persist the mutated [`issuance::Model`].
     async fn validate_cred_req(
         &self,
-        issuance: &issuance::Model,
+        issuance: &mut issuance::Model,
         cred_req: CredentialRequest,
         token: &str,
     ) -> Outcome<(String, VcTypeConfig)>;
 
+    /// Records a wallet-reported credential lifecycle event on the issuance session.
+    ///
+    /// Asserts the bearer token and `notification_id` belong to the session before storing the
+    /// event; the caller must persist the mutated [`issuance::Model`].
+    fn process_notification(
+        &self,
+        issuance: &mut issuance::Model,
+        notification: NotificationRequest,
+        token: &str,
+    ) -> Outcome<()>;
+
+    /// Starts a credential issued by the identity signing `vc_type`; only the subject is left to set.
+    async fn credential_builder(
+        &self,
+        vc_type: &VcType,
+        model: W3cDataModelVersion,
+    ) -> CredentialBuilder<Present, Missing>;
+
     /// Digitally signs the structured credential claims using asymmetric keys pulled securely from the Vault.
+    ///
+    /// Claims should come from [`Self::credential_builder`]; an `iss` naming anyone but the
+    /// identity configured for the credential type (the agent's by default) is rejected.
     async fn sign_claims(&self, claims: &VCJwtClaims) -> Outcome<String>;
+
+    /// Signs a `dc+sd-jwt` credential, turning every subject claim into a salted disclosure.
+    ///
+    /// Returns the compact SD-JWT (`<jwt>~<disclosure>~...~`) so the disclosures travel with
+    /// the credential.
+    async fn sign_sd_jwt(&self, claims: &SdJwtVcClaims) -> Outcome<String>;
 }
@@ -15,21 +15,70 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::capabilities::Did;
 use crate::config::traits::HostsConfigTrait;
 use crate::config::types::CommonHostsConfig;
+use crate::types::vcs::VcType;
+use crate::types::wallet::KeyRef;
+use crate::utils::DEFAULT_CLOCK_SKEW_LEEWAY;
+
+/// Dedicated DID and vault key signing the credentials of a single `VcType`.
+#[derive(Debug, Clone)]
+pub struct IssuerIdentity {
+    did: Did,
+    key_ref: KeyRef,
+}
+
+impl IssuerIdentity {
+    pub fn new(did: Did, key_ref: KeyRef) -> Self {
+        Self { did, key_ref }
+    }
+    pub fn did(&self) -> &Did {
+        &self.did
+    }
+    pub fn key_ref(&self) -> &KeyRef {
+        &self.key_ref
+    }
+}
 
 pub struct IssuerConfig {
     hosts: CommonHostsConfig,
     api_path: String,
+    clock_leeway: Duration,
+    identities: HashMap<VcType, IssuerIdentity>,
 }
 
 impl IssuerConfig {
     pub fn new(hosts: CommonHostsConfig, api_path: String) -> IssuerConfig {
-        IssuerConfig { hosts, api_path }
+        IssuerConfig {
+            hosts,
+            api_path,
+            clock_leeway: DEFAULT_CLOCK_SKEW_LEEWAY,
+            identities: HashMap::new(),
+        }
+    }
+    /// Tolerates `clock_leeway` of drift on the `iat` of holder proofs.
+    pub fn with_clock_leeway(mut self, clock_leeway: Duration) -> Self {
+        self.clock_leeway = clock_leeway;
+        self
+    }
+    /// Signs `vc_type` credentials with `identity` instead of the agent identity.
+    pub fn with_issuer_identity(mut self, vc_type: VcType, identity: IssuerIdentity) -> Self {
+        self.identities.insert(vc_type, identity);
+        self
     }
     pub fn get_api_path(&self) -> &str {
         &self.api_path
     }
+    pub fn get_clock_leeway(&self) -> Duration {
+        self.clock_leeway
+    }
+    pub fn get_issuer_identity(&self, vc_type: &VcType) -> Option<&IssuerIdentity> {
+        self.identities.get(vc_type)
+    }
 }
 
 impl HostsConfigTrait for IssuerConfig {
@@ -18,5 +18,5 @@
 mod config;
 mod service;
 
-pub use config::IssuerConfig;
+pub use config::{IssuerConfig, IssuerIdentity};
 pub use service::IssuerService;
@@ -15,33 +15,38 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::str::FromStr;
 use std::sync::{Arc};
 use tokio::sync::{RwLock};
 
 use async_trait::async_trait;
+use chrono::Utc;
 use tracing::info;
 use urlencoding;
 
 use super::super::IssuerTrait;
 use super::IssuerConfig;
-use crate::capabilities::{Kid, Signer, Verifier};
+use crate::capabilities::{Did, DidResolver, Kid, Verifier};
 use crate::config::traits::HostsConfigTrait;
 use crate::config::types::HostType;
 use crate::data::entities::shared::issuance;
 use crate::errors::{BadFormat, Errors, Outcome};
-use crate::services::vault::{VaultService, VaultTrait};
+use crate::modules::QuotaModuleTrait;
+use crate::services::signer::{SignerTrait, VaultKeySigner};
+use crate::services::vault::VaultService;
 use crate::types::gnap::grant_request::GrantRequestKind;
 use crate::types::gnap::grant_request::client::{Client, KeyMaterial};
 use crate::types::issuance::{
     AuthServerMetadata, CredReqProof, CredentialRequest, DidPossession, IssuerMetadata,
-    IssuingToken, VcCredOffer, VcTransmissionOffer,
+    IssuingToken, NotificationRequest, VcCredOffer, VcTransmissionOffer,
 };
-use crate::types::jwt::{Jwt, VCJwtClaims};
-use crate::types::keys::{PrivateKey, SigningCtx};
-use crate::types::secrets::PemHelper;
-use crate::types::vcs::{BuildCtx, VcType, VcTypeConfig};
-use crate::types::wallet::Identity;
-use crate::utils::is_active;
+use crate::types::jwt::{Jwt, SD_JWT_VC_TYP, SdJwt, SdJwtVcClaims, VCJwtClaims, conceal};
+use crate::types::quota::QuotaKind;
+use crate::types::vcs::{
+    BuildCtx, CredentialBuilder, VcIssuer, VcType, VcTypeConfig, W3cDataModelVersion,
+};
+use crate::types::wallet::{Identity, KeyRef};
+use crate::utils::{Missing, Present, is_active_with};
 
 /// Core Implementation of the OpenID4VCI (v1.0) Credential Issuer Service.
 ///
@@ -51,17 +56,40 @@ use crate::utils::is_active;
 pub struct IssuerService {
     config: IssuerConfig,
     identity: Arc<RwLock<Identity>>,
-    vault: Arc<VaultService>,
+    signer: Arc<dyn SignerTrait>,
+    quota: Option<Arc<dyn QuotaModuleTrait>>,
+    resolver: DidResolver,
 }
 
 impl IssuerService {
     pub fn new(config: IssuerConfig, vault: Arc<VaultService>, identity: Arc<RwLock<Identity>>) -> Self {
         Self {
             config,
-            vault,
             identity,
+            signer: Arc::new(VaultKeySigner::new(vault)),
+            quota: None,
+            resolver: DidResolver::default(),
         }
     }
+
+    /// Signs credentials through `signer`, e.g. a Vault Transit or KMS signer keeping the
+    /// private keys out of the process, instead of reading them from the vault.
+    pub fn with_signer(mut self, signer: Arc<dyn SignerTrait>) -> Self {
+        self.signer = signer;
+        self
+    }
+
+    /// Meters issuances through `quota`: each plan consumes one issuance of its participant.
+    pub fn with_quota(mut self, quota: Arc<dyn QuotaModuleTrait>) -> Self {
+        self.quota = Some(quota);
+        self
+    }
+
+    /// Resolves holder DIDs through `resolver`, e.g. one with a Universal Resolver fallback.
+    pub fn with_did_resolver(mut self, resolver: DidResolver) -> Self {
+        self.resolver = resolver;
+        self
+    }
 }
 
 #[async_trait]
@@ -91,6 +119,11 @@ impl IssuerTrait for IssuerService {
                 None,
             )
         })?;
+        if let Some(quota) = &self.quota {
+            quota
+                .consume_quota(participant_nick, QuotaKind::Issuance)
+                .await?;
+        }
 
         let vc_configs: Vec<VcTypeConfig> = vc_req
             .credential_configurations
@@ -103,7 +136,7 @@ impl IssuerTrait for IssuerService {
             KeyMaterial::Jwk { .. } => None,
             KeyMaterial::Cert { cert } => Some(cert),
         };
-        let aud = self.config.get_host(HostType::Http);
+        let aud = self.config.get_external_host(HostType::Http);
 
         let build_ctx = BuildCtx::base(participant_nick, cert);
 
@@ -126,7 +159,7 @@ impl IssuerTrait for IssuerService {
         info!("Retrieving credential offer data");
 
         VcCredOffer::pre_authorized(
-            self.config.get_host(HostType::Http),
+            self.config.get_external_host(HostType::Http),
             &model.pre_auth_code,
             &model.vc_type_config,
         )
@@ -136,7 +169,7 @@ impl IssuerTrait for IssuerService {
         let api_path = self.config.get_api_path();
         let host = format!(
             "{}{}/issuer",
-            self.config.get_host(HostType::Http),
+            self.config.get_external_host(HostType::Http),
             api_path,
         );
 
@@ -169,7 +202,13 @@ impl IssuerTrait for IssuerService {
 
     fn get_issuer_metadata(&self, vcs: &[VcType]) -> IssuerMetadata {
         let (host, api_path) = self.metadata_hosts();
-        IssuerMetadata::new(&host, &api_path, vcs)
+        let mut metadata = IssuerMetadata::new(&host, &api_path, vcs);
+        for (config, cred_config) in metadata.credential_configurations_supported.iter_mut() {
+            if let Some(identity) = self.config.get_issuer_identity(config.vc_type()) {
+                cred_config.issuer_did = Some(identity.did().id().to_string());
+            }
+        }
+        metadata
     }
 
     fn get_oauth_server_data(&self) -> AuthServerMetadata {
@@ -177,8 +216,9 @@ impl IssuerTrait for IssuerService {
         AuthServerMetadata::new(&host, &api_path)
     }
 
-    fn get_token(&self, model: &issuance::Model) -> IssuingToken {
+    fn get_token(&self, model: &mut issuance::Model) -> IssuingToken {
         info!("Giving token");
+        model.rotate_nonce();
         IssuingToken::new(
             &model.token,
             Some(model.nonce.clone()),
@@ -187,7 +227,7 @@ impl IssuerTrait for IssuerService {
     }
     async fn validate_cred_req(
         &self,
-        issuance: &issuance::Model,
+        issuance: &mut issuance::Model,
         cred_req: CredentialRequest,
         token: &str,
     ) -> Outcome<(String, VcTypeConfig)> {
@@ -227,43 +267,124 @@ impl IssuerTrait for IssuerService {
         };
 
         let (kid, claims) =
-            Verifier::verify_enveloped::<DidPossession>(&jwt, Some(&issuance.aud)).await?;
+            Verifier::verify_enveloped::<DidPossession>(&self.resolver, &jwt, Some(&issuance.aud))
+                .await?;
 
+        validate_c_nonce(issuance)?;
         validate_did_possession(&claims, &kid, &issuance.nonce)?;
-        is_active(claims.iat)?;
+        is_active_with(claims.iat, self.config.get_clock_leeway())?;
+        issuance.nonce_consumed = true;
         Ok((kid.did().id().to_string(), vc_config))
     }
 
-    async fn sign_claims(&self, claims: &VCJwtClaims) -> Outcome<String> {
-        info!("Issuing credential");
+    fn process_notification(
+        &self,
+        issuance: &mut issuance::Model,
+        notification: NotificationRequest,
+        token: &str,
+    ) -> Outcome<()> {
+        info!("Processing credential notification");
 
-        let lock = self.identity.read().await;
-        let did = lock.did();
-        let key_ref = lock.key_ref();
+        if issuance.token != token {
+            return Err(Errors::forbidden("token does not match", None));
+        }
+        if issuance.notification_id != notification.notification_id {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "notification_id does not match",
+                None,
+            ));
+        }
+
+        issuance.notification_event = Some(notification.event);
+        issuance.notification_description = notification.event_description;
+        issuance.notified_at = Some(Utc::now());
+        Ok(())
+    }
+
+    async fn credential_builder(
+        &self,
+        vc_type: &VcType,
+        model: W3cDataModelVersion,
+    ) -> CredentialBuilder<Present, Missing> {
+        let iss = match self.config.get_issuer_identity(vc_type) {
+            Some(identity) => identity.did().id().to_string(),
+            None => self.identity.read().await.did().id().to_string(),
+        };
+        CredentialBuilder::new(vc_type, model).issuer(VcIssuer::new(iss, None::<String>))
+    }
 
-        let pem_helper: PemHelper = self.vault.read(None, key_ref.internal()).await?;
-        let key = PrivateKey::try_from(pem_helper)?;
+    async fn sign_claims(&self, claims: &VCJwtClaims) -> Outcome<String> {
+        info!("Issuing credential");
 
-        let sig_ctx = SigningCtx::new(did.clone(), key, key_ref.fragment().to_string());
+        let vc_type = claims.vc_doc().specialized_type();
+        let (did, key_ref) = self.signing_identity(vc_type.as_ref()).await;
+        if claims.iss().is_some_and(|iss| iss != did.id()) {
+            return Err(Errors::forbidden(
+                "Credential iss does not name the issuing identity",
+                None,
+            ));
+        }
         let claims = serde_json::to_value(claims)?;
 
-        let vc_jwt = Signer::sign_enveloped(&sig_ctx, "vc+ld+json+jwt", "vc+ld+json", &claims)?;
+        let vc_jwt = self
+            .signer
+            .sign_enveloped(&did, &key_ref, "vc+ld+json+jwt", "vc+ld+json", &claims)
+            .await?;
         Ok(vc_jwt.as_str().to_string())
     }
+
+    async fn sign_sd_jwt(&self, claims: &SdJwtVcClaims) -> Outcome<String> {
+        info!("Issuing SD-JWT credential");
+
+        let mut claims = claims.clone();
+        let disclosures = conceal(&mut claims.claims, &[])?;
+
+        let vc_type = VcType::from_str(&claims.vct).ok();
+        let (did, key_ref) = self.signing_identity(vc_type.as_ref()).await;
+        let claims = serde_json::to_value(&claims)?;
+
+        let jwt = self
+            .signer
+            .sign_enveloped(&did, &key_ref, SD_JWT_VC_TYP, "json", &claims)
+            .await?;
+        Ok(SdJwt::new(jwt, disclosures).issuance_string())
+    }
 }
 
 // ===== Internal helpers ======================================================
 
 impl IssuerService {
     fn metadata_hosts(&self) -> (String, String) {
-        let host = self.config.get_host(HostType::Http);
+        let host = self.config.get_external_host(HostType::Http);
         let api_path = format!("{}/issuer", self.config.get_api_path());
         (host, api_path)
     }
+
+    /// Picks the identity signing `vc_type` credentials, falling back to the agent identity.
+    async fn signing_identity(&self, vc_type: Option<&VcType>) -> (Did, KeyRef) {
+        let lock = self.identity.read().await;
+        let (did, key_ref) = match vc_type.and_then(|t| self.config.get_issuer_identity(t)) {
+            Some(identity) => (identity.did(), identity.key_ref()),
+            None => (lock.did(), lock.key_ref()),
+        };
+        (did.clone(), key_ref.clone())
+    }
 }
 
 // ===== Free helpers ==========================================================
 
+fn validate_c_nonce(issuance: &issuance::Model) -> Outcome<()> {
+    info!("Validating c_nonce");
+    if issuance.nonce_consumed {
+        return Err(Errors::security("c_nonce has already been used", None));
+    }
+    if Utc::now() > issuance.nonce_expires_at {
+        return Err(Errors::security("c_nonce has expired", None));
+    }
+    Ok(())
+}
+
 fn validate_did_possession(claims: &DidPossession, kid: &Kid, nonce: &str) -> Outcome<()> {
     info!("Validating did possession");
     if let Some(iss) = &claims.iss {
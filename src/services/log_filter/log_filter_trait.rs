@@ -0,0 +1,36 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::errors::Outcome;
+
+/// Runtime control over the tracing filter.
+///
+/// Directives use the `RUST_LOG` syntax (e.g. `info,ymir::services::verifier=debug`), so a
+/// single module can be made verbose without restarting the agent.
+pub trait LogFilterTrait: Send + Sync + 'static {
+    /// Returns the directives currently in effect.
+    fn current(&self) -> Outcome<String>;
+
+    /// Replaces the active filter with `directives`.
+    fn set(&self, directives: &str) -> Outcome<String>;
+
+    /// Adds `directives` on top of the active filter; a target already present gets the new level.
+    fn extend(&self, directives: &str) -> Outcome<String>;
+
+    /// Restores the filter the agent was started with.
+    fn reset(&self) -> Outcome<String>;
+}
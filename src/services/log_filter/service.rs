@@ -0,0 +1,95 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use tracing::Subscriber;
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::reload::{self, Handle};
+
+use super::LogFilterTrait;
+use crate::errors::{BadFormat, Errors, Outcome};
+
+/// Log filter controller backed by a [`tracing_subscriber::reload`] handle.
+///
+/// The consumer installs the layer returned by [`layer`](Self::layer) in its subscriber and
+/// keeps the service to adjust the filter afterwards.
+pub struct LogFilterService<S> {
+    handle: Handle<EnvFilter, S>,
+    initial: String,
+}
+
+impl<S> LogFilterService<S>
+where
+    S: Subscriber + 'static,
+{
+    pub fn new(handle: Handle<EnvFilter, S>, initial: impl Into<String>) -> Self {
+        Self {
+            handle,
+            initial: initial.into(),
+        }
+    }
+
+    /// Builds a reloadable filter layer from `RUST_LOG`, falling back to `default_directives`.
+    pub fn layer(default_directives: &str) -> (reload::Layer<EnvFilter, S>, Self) {
+        let initial = std::env::var(EnvFilter::DEFAULT_ENV)
+            .ok()
+            .filter(|d| EnvFilter::try_new(d).is_ok())
+            .unwrap_or_else(|| default_directives.to_string());
+        let filter = EnvFilter::try_new(&initial).unwrap_or_else(|_| EnvFilter::new("info"));
+        let (layer, handle) = reload::Layer::new(filter);
+        (layer, Self::new(handle, initial))
+    }
+
+    fn apply(&self, directives: &str) -> Outcome<String> {
+        let filter = EnvFilter::try_new(directives).map_err(|e| {
+            Errors::format(
+                BadFormat::Received,
+                format!("Invalid log filter '{directives}'"),
+                Some(Box::new(e)),
+            )
+        })?;
+        self.handle.reload(filter).map_err(|e| {
+            Errors::crazy("Log subscriber is no longer available", Some(Box::new(e)))
+        })?;
+        self.current()
+    }
+}
+
+impl<S> LogFilterTrait for LogFilterService<S>
+where
+    S: Subscriber + Send + Sync + 'static,
+{
+    fn current(&self) -> Outcome<String> {
+        self.handle
+            .with_current(|f| f.to_string())
+            .map_err(|e| Errors::crazy("Log subscriber is no longer available", Some(Box::new(e))))
+    }
+
+    fn set(&self, directives: &str) -> Outcome<String> {
+        self.apply(directives)
+    }
+
+    fn extend(&self, directives: &str) -> Outcome<String> {
+        // Later directives for the same target replace earlier ones when parsed.
+        let current = self.current()?;
+        self.apply(&format!("{current},{directives}"))
+    }
+
+    fn reset(&self) -> Outcome<String> {
+        let initial = self.initial.clone();
+        self.apply(&initial)
+    }
+}
@@ -15,11 +15,24 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+pub mod archive;
+pub mod claim_source;
 pub mod client;
+pub mod did_equivalence;
+pub mod evidence;
 mod has_service_trait;
 pub mod issuer;
+pub mod log_filter;
+pub mod receipt;
+pub mod relying_party;
 pub mod repo;
+pub mod revocation_feed;
+pub mod signer;
+pub mod standby;
+pub mod status_list;
+pub mod trust_anchor;
 pub mod vault;
+pub mod vc_requester;
 pub mod verifier;
 pub mod wallet;
 pub mod identity;
@@ -0,0 +1,45 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::verification;
+use crate::errors::Outcome;
+use crate::types::verification::VerificationReceipt;
+use async_trait::async_trait;
+
+/// Verification receipt service.
+///
+/// Produces signed, portable receipts for successful verifications and checks receipts
+/// presented back by holders or third parties.
+#[async_trait]
+pub trait ReceiptTrait: Send + Sync + 'static {
+    /// Builds the receipt payload for a finished verification.
+    ///
+    /// Only verifications in the `Verified` state can be receipted.
+    async fn build_receipt(
+        &self,
+        verification_model: &verification::Model,
+    ) -> Outcome<VerificationReceipt>;
+
+    /// Signs a receipt as a compact JWT using the active identity.
+    async fn sign_receipt(&self, receipt: &VerificationReceipt) -> Outcome<String>;
+
+    /// Verifies a receipt JWT and returns its payload.
+    ///
+    /// Both `iss` and the signing key must belong to the local agent DID, so receipts issued
+    /// by any other party are rejected.
+    async fn verify_receipt(&self, token: &str) -> Outcome<VerificationReceipt>;
+}
@@ -0,0 +1,148 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::ReceiptTrait;
+use crate::capabilities::{DidResolver, Verifier};
+use crate::data::entities::received::verification;
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::services::signer::{SignerTrait, VaultKeySigner};
+use crate::services::vault::VaultService;
+use crate::types::jwt::Jwt;
+use crate::types::verification::{
+    RECEIPT_TYP, ReceiptPolicy, VerificationReceipt, VerificationStatus,
+};
+use crate::types::wallet::Identity;
+use crate::utils::{Clock, encode_url_safe_no_pad};
+
+/// Receipt issuer signing with the node's active identity.
+pub struct ReceiptService {
+    identity: Arc<RwLock<Identity>>,
+    signer: Arc<dyn SignerTrait>,
+    resolver: DidResolver,
+}
+
+impl ReceiptService {
+    pub fn new(vault: Arc<VaultService>, identity: Arc<RwLock<Identity>>) -> Self {
+        Self {
+            identity,
+            signer: Arc::new(VaultKeySigner::new(vault)),
+            resolver: DidResolver::default(),
+        }
+    }
+
+    /// Signs through `signer`, e.g. a Vault Transit or KMS signer keeping the private keys
+    /// out of the process, instead of reading them from the vault.
+    pub fn with_signer(mut self, signer: Arc<dyn SignerTrait>) -> Self {
+        self.signer = signer;
+        self
+    }
+
+    /// Resolves signer DIDs through `resolver`, e.g. one with a Universal Resolver fallback.
+    pub fn with_did_resolver(mut self, resolver: DidResolver) -> Self {
+        self.resolver = resolver;
+        self
+    }
+}
+
+#[async_trait]
+impl ReceiptTrait for ReceiptService {
+    async fn build_receipt(&self, model: &verification::Model) -> Outcome<VerificationReceipt> {
+        if model.status != VerificationStatus::Verified {
+            return Err(Errors::forbidden(
+                "Only successful verifications can be receipted",
+                None,
+            ));
+        }
+        let verified_at = model
+            .ended_at
+            .ok_or_else(|| Errors::crazy("Verified verification without an end timestamp", None))?;
+
+        let iss = self.identity.read().await.did().id().to_string();
+
+        Ok(VerificationReceipt {
+            iss,
+            sub: model.holder.clone(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            iat: Clock::issued_now().timestamp(),
+            verification_id: model.id.clone(),
+            verified_at: verified_at.timestamp(),
+            claims_digest: claims_digest(model)?,
+            policy: ReceiptPolicy {
+                vc_types: model.vc_type.clone(),
+                purpose: model.purpose.clone(),
+            },
+        })
+    }
+
+    async fn sign_receipt(&self, receipt: &VerificationReceipt) -> Outcome<String> {
+        info!(
+            "Signing receipt for verification {}",
+            receipt.verification_id
+        );
+
+        let (did, key_ref) = {
+            let lock = self.identity.read().await;
+            (lock.did().clone(), lock.key_ref().clone())
+        };
+        let claims = serde_json::to_value(receipt)?;
+
+        let jwt = self
+            .signer
+            .sign_enveloped(&did, &key_ref, RECEIPT_TYP, "json", &claims)
+            .await?;
+        Ok(jwt.as_str().to_string())
+    }
+
+    async fn verify_receipt(&self, token: &str) -> Outcome<VerificationReceipt> {
+        let jwt = Jwt::parse(token)?;
+        if jwt.header().typ.as_deref() != Some(RECEIPT_TYP) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Receipt typ must be '{RECEIPT_TYP}'"),
+                None,
+            ));
+        }
+
+        let (kid, receipt) =
+            Verifier::verify_enveloped::<VerificationReceipt>(&self.resolver, &jwt, None).await?;
+        let identity = self.identity.read().await;
+        if receipt.iss != kid.did().id() || !identity.is_self(&receipt.iss) {
+            return Err(Errors::security(
+                "Receipt was not issued by this agent",
+                None,
+            ));
+        }
+        Ok(receipt)
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Digests the credential subjects of every verified credential, in presentation order.
+fn claims_digest(model: &verification::Model) -> Outcome<String> {
+    let subjects = model.credential_subjects()?;
+
+    let bytes = serde_json::to_vec(&subjects)?;
+    Ok(encode_url_safe_no_pad(Sha256::digest(bytes)))
+}
@@ -0,0 +1,72 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::verification;
+use crate::data::entities::shared::rp_subscription;
+use crate::errors::Outcome;
+use crate::types::verification::{VerificationDecision, VerificationEvent};
+use async_trait::async_trait;
+
+/// Relying-Party decision delivery service.
+///
+/// Turns finished verifications into compact, signed allow/deny decisions and
+/// pushes them to every relying application whose subscription filter matches,
+/// so those applications can authorize without parsing presentations themselves.
+#[async_trait]
+pub trait RelyingPartyTrait: Send + Sync + 'static {
+    /// Builds the minimized decision payload addressed to a single subscription.
+    ///
+    /// Only the claims listed in the subscription are copied from the verified
+    /// credential subjects; everything else is dropped.
+    async fn build_decision(
+        &self,
+        subscription: &rp_subscription::Model,
+        verification_model: &verification::Model,
+    ) -> Outcome<VerificationDecision>;
+
+    /// Signs a decision payload as a compact JWT using the active identity.
+    async fn sign_decision(&self, decision: &VerificationDecision) -> Outcome<String>;
+
+    /// Builds the outcome event of a finished verification addressed to `webhook_url`.
+    ///
+    /// # Errors
+    /// Returns a forbidden error when the verification has not completed or failed yet.
+    async fn build_event(
+        &self,
+        webhook_url: &str,
+        verification_model: &verification::Model,
+    ) -> Outcome<VerificationEvent>;
+
+    /// Builds, signs and posts the outcome event to every webhook.
+    ///
+    /// Like [`notify`](Self::notify), delivery failures are logged and skipped.
+    async fn notify_webhooks(
+        &self,
+        webhooks: &[String],
+        verification_model: &verification::Model,
+    ) -> Outcome<()>;
+
+    /// Builds, signs and delivers one decision per subscription.
+    ///
+    /// Delivery failures are logged and skipped so that a single unreachable
+    /// relying application does not prevent the rest from being notified.
+    async fn notify(
+        &self,
+        subscriptions: &[rp_subscription::Model],
+        verification_model: &verification::Model,
+    ) -> Outcome<()>;
+}
@@ -0,0 +1,247 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{HeaderMap, HeaderValue};
+use serde_json::{Map, Value};
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::RelyingPartyTrait;
+use crate::data::entities::received::verification;
+use crate::data::entities::shared::rp_subscription;
+use crate::errors::{Errors, Outcome};
+use crate::services::client::ClientTrait;
+use crate::services::signer::{SignerTrait, VaultKeySigner};
+use crate::services::vault::VaultService;
+use crate::types::http::HttpBody;
+use crate::types::verification::{
+    Decision, VERIFICATION_EVENT_TYP, VerificationDecision, VerificationEvent,
+    VerificationEventKind, VerificationStatus,
+};
+use crate::types::wallet::Identity;
+use crate::utils::{Clock, http_client};
+
+/// Relying-Party notifier signing decisions with the node's active identity.
+pub struct RelyingPartyService {
+    identity: Arc<RwLock<Identity>>,
+    signer: Arc<dyn SignerTrait>,
+}
+
+impl RelyingPartyService {
+    pub fn new(vault: Arc<VaultService>, identity: Arc<RwLock<Identity>>) -> Self {
+        Self {
+            identity,
+            signer: Arc::new(VaultKeySigner::new(vault)),
+        }
+    }
+
+    /// Signs through `signer`, e.g. a Vault Transit or KMS signer keeping the private keys
+    /// out of the process, instead of reading them from the vault.
+    pub fn with_signer(mut self, signer: Arc<dyn SignerTrait>) -> Self {
+        self.signer = signer;
+        self
+    }
+}
+
+#[async_trait]
+impl RelyingPartyTrait for RelyingPartyService {
+    async fn build_decision(
+        &self,
+        subscription: &rp_subscription::Model,
+        model: &verification::Model,
+    ) -> Outcome<VerificationDecision> {
+        let decision = match model.status {
+            VerificationStatus::Verified => Decision::Allow,
+            VerificationStatus::Failed
+            | VerificationStatus::Expired
+            | VerificationStatus::Revoked => Decision::Deny,
+            VerificationStatus::Pending | VerificationStatus::Presented => {
+                return Err(Errors::forbidden(
+                    "Unable to build a decision for an unfinished verification",
+                    None,
+                ));
+            }
+        };
+
+        let claims = match decision {
+            Decision::Allow => minimize_claims(model, &subscription.claims)?,
+            Decision::Deny => Map::new(),
+        };
+
+        let iss = self.identity.read().await.did().id().to_string();
+
+        Ok(VerificationDecision {
+            iss,
+            aud: subscription.callback_url.clone(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            iat: Clock::issued_now().timestamp(),
+            subscription_id: subscription.id.clone(),
+            verification_id: model.id.clone(),
+            decision,
+            holder: model.holder.clone(),
+            vc_types: model.vc_type.clone(),
+            purpose: model.purpose.clone(),
+            claims,
+        })
+    }
+
+    async fn sign_decision(&self, decision: &VerificationDecision) -> Outcome<String> {
+        self.sign("decision+jwt", &serde_json::to_value(decision)?)
+            .await
+    }
+
+    async fn build_event(
+        &self,
+        webhook_url: &str,
+        model: &verification::Model,
+    ) -> Outcome<VerificationEvent> {
+        let event = match model.status {
+            VerificationStatus::Verified => VerificationEventKind::Completed,
+            VerificationStatus::Failed => VerificationEventKind::Failed,
+            _ => {
+                return Err(Errors::forbidden(
+                    "Unable to build an event for an unfinished verification",
+                    None,
+                ));
+            }
+        };
+
+        let iss = self.identity.read().await.did().id().to_string();
+
+        Ok(VerificationEvent {
+            iss,
+            aud: webhook_url.to_string(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            iat: Clock::issued_now().timestamp(),
+            event,
+            verification_id: model.id.clone(),
+            status: model.status.clone(),
+            holder: model.holder.clone(),
+            vc_types: model.vc_type.clone(),
+            purpose: model.purpose.clone(),
+            ended_at: model.ended_at,
+        })
+    }
+
+    async fn notify_webhooks(
+        &self,
+        webhooks: &[String],
+        model: &verification::Model,
+    ) -> Outcome<()> {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/jwt"));
+
+        for webhook in webhooks {
+            info!("Notifying webhook {webhook} of verification {}", model.id);
+            let result: Outcome<()> = async {
+                let event = self.build_event(webhook, model).await?;
+                let jwt = self
+                    .sign(VERIFICATION_EVENT_TYP, &serde_json::to_value(&event)?)
+                    .await?;
+                http_client()
+                    .post(webhook, Some(headers.clone()), HttpBody::Raw(jwt))
+                    .await?;
+                Ok(())
+            }
+            .await;
+
+            if let Err(e) = result {
+                e.log();
+            }
+        }
+        Ok(())
+    }
+
+    async fn notify(
+        &self,
+        subscriptions: &[rp_subscription::Model],
+        model: &verification::Model,
+    ) -> Outcome<()> {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/jwt"));
+
+        for subscription in subscriptions {
+            info!("Notifying relying party subscription {}", subscription.id);
+            let result: Outcome<()> = async {
+                let decision = self.build_decision(subscription, model).await?;
+                let jwt = self.sign_decision(&decision).await?;
+                http_client()
+                    .post(
+                        &subscription.callback_url,
+                        Some(headers.clone()),
+                        HttpBody::Raw(jwt),
+                    )
+                    .await?;
+                Ok(())
+            }
+            .await;
+
+            if let Err(e) = result {
+                e.log();
+            }
+        }
+        Ok(())
+    }
+}
+
+// ===== Internal helpers ======================================================
+
+impl RelyingPartyService {
+    /// Signs `claims` as a compact JWT of type `typ` with the active identity.
+    async fn sign(&self, typ: &str, claims: &Value) -> Outcome<String> {
+        let (did, key_ref) = {
+            let lock = self.identity.read().await;
+            (lock.did().clone(), lock.key_ref().clone())
+        };
+        let jwt = self
+            .signer
+            .sign_enveloped(&did, &key_ref, typ, "json", claims)
+            .await?;
+        Ok(jwt.as_str().to_string())
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Collects only the requested claims out of every verified credential subject.
+///
+/// Claim names accept dotted paths (`address.countryCode`) to reach nested values.
+///
+/// Redacted verifications only offer the claims their profile kept.
+fn minimize_claims(
+    model: &verification::Model,
+    requested: &[String],
+) -> Outcome<Map<String, Value>> {
+    let mut claims = Map::new();
+    if requested.is_empty() {
+        return Ok(claims);
+    }
+
+    for subject in model.credential_subjects()? {
+        for name in requested {
+            let pointer = format!("/{}", name.replace('.', "/"));
+            if let Some(value) = subject.pointer(&pointer) {
+                claims.entry(name.clone()).or_insert_with(|| value.clone());
+            }
+        }
+    }
+    Ok(claims)
+}
@@ -0,0 +1,215 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use tracing::info;
+
+use super::memory::MemoryTable;
+use super::memory::received::{
+    RecvAccessTokenMemoryRepo, RecvEvidenceBundleMemoryRepo, RecvGrantMemoryRepo,
+    RecvInteractionMemoryRepo, RecvPresentationDefinitionMemoryRepo,
+    RecvVerificationAuditMemoryRepo, RecvVerificationMemoryRepo,
+};
+use super::memory::sent::{
+    SentGrantMemoryRepo, SentInteractionMemoryRepo, SentVerificationMemoryRepo,
+};
+use super::memory::shared::{
+    AuditEntryMemoryRepo, CredTemplateMemoryRepo, IssuanceMemoryRepo, ParticipantMemoryRepo,
+    QuotaCounterMemoryRepo, ReplayEntryMemoryRepo, ResourceReqMemoryRepo,
+    RevocationEventMemoryRepo, RpSubscriptionMemoryRepo, SharedStateMemoryRepo,
+    StatusEntryMemoryRepo, StatusListMemoryRepo, TrustedIssuerMemoryRepo,
+};
+use super::memory::wallet::{
+    DidMemoryRepo, KeyMemoryRepo, PendingPresentationMemoryRepo, VcMemoryRepo,
+};
+use super::postgres::RepoConnection;
+use super::postgres::received::{
+    RecvAccessTokenPostgresRepo, RecvEvidenceBundlePostgresRepo, RecvGrantPostgresRepo,
+    RecvInteractionPostgresRepo, RecvPresentationDefinitionPostgresRepo,
+    RecvVerificationAuditPostgresRepo, RecvVerificationPostgresRepo,
+};
+use super::postgres::sent::{
+    SentGrantPostgresRepo, SentInteractionPostgresRepo, SentVerificationPostgresRepo,
+};
+use super::postgres::shared::{
+    AuditEntryPostgresRepo, CredTemplatePostgresRepo, IssuancePostgresRepo,
+    ParticipantPostgresRepo, QuotaCounterPostgresRepo, ReplayEntryPostgresRepo,
+    ResourceReqPostgresRepo, RevocationEventPostgresRepo, RpSubscriptionPostgresRepo,
+    SharedStatePostgresRepo, StatusEntryPostgresRepo, StatusListPostgresRepo,
+    TrustedIssuerPostgresRepo,
+};
+use super::postgres::wallet::{
+    DidPostgresRepo, KeyPostgresRepo, PendingPresentationPostgresRepo, VcPostgresRepo,
+};
+use super::traits::received::{
+    RecvAccessTokenRepoTrait, RecvEvidenceBundleRepoTrait, RecvGrantRepoTrait,
+    RecvInteractionRepoTrait, RecvPresentationDefinitionRepoTrait, RecvVerificationAuditRepoTrait,
+    RecvVerificationRepoTrait,
+};
+use super::traits::sent::{
+    SentGrantRepoTrait, SentInteractionRepoTrait, SentVerificationRepoTrait,
+};
+use super::traits::shared::{
+    AuditEntryRepoTrait, CredTemplateRepoTrait, IssuanceRepoTrait, ParticipantRepoTrait,
+    QuotaCounterRepoTrait, ReplayEntryRepoTrait, ResourceReqRepoTrait, RevocationEventRepoTrait,
+    RpSubscriptionRepoTrait, SharedStateRepoTrait, StatusEntryRepoTrait, StatusListRepoTrait,
+    TrustedIssuerRepoTrait,
+};
+use super::traits::wallet::{
+    DidRepoTrait, KeyRepoTrait, PendingPresentationRepoTrait, VcRepoTrait,
+};
+use crate::config::traits::DatabaseConfigTrait;
+use crate::config::types::DbType;
+use crate::errors::Outcome;
+use crate::services::vault::{VaultService, VaultTrait};
+
+/// Every repository of the node, behind the storage backend selected by `db_type`.
+#[derive(Clone)]
+pub struct Repos {
+    pub participants: Arc<dyn ParticipantRepoTrait>,
+    pub resource_reqs: Arc<dyn ResourceReqRepoTrait>,
+    pub issuances: Arc<dyn IssuanceRepoTrait>,
+    pub cred_templates: Arc<dyn CredTemplateRepoTrait>,
+    pub rp_subscriptions: Arc<dyn RpSubscriptionRepoTrait>,
+    pub status_lists: Arc<dyn StatusListRepoTrait>,
+    pub status_entries: Arc<dyn StatusEntryRepoTrait>,
+    pub trusted_issuers: Arc<dyn TrustedIssuerRepoTrait>,
+    pub quota_counters: Arc<dyn QuotaCounterRepoTrait>,
+    pub replay_entries: Arc<dyn ReplayEntryRepoTrait>,
+    pub revocation_events: Arc<dyn RevocationEventRepoTrait>,
+    pub shared_state: Arc<dyn SharedStateRepoTrait>,
+    pub audit_entries: Arc<dyn AuditEntryRepoTrait>,
+    pub sent_grants: Arc<dyn SentGrantRepoTrait>,
+    pub sent_interactions: Arc<dyn SentInteractionRepoTrait>,
+    pub sent_verifications: Arc<dyn SentVerificationRepoTrait>,
+    pub recv_grants: Arc<dyn RecvGrantRepoTrait>,
+    pub recv_interactions: Arc<dyn RecvInteractionRepoTrait>,
+    pub recv_access_tokens: Arc<dyn RecvAccessTokenRepoTrait>,
+    pub recv_verifications: Arc<dyn RecvVerificationRepoTrait>,
+    pub presentation_definitions: Arc<dyn RecvPresentationDefinitionRepoTrait>,
+    pub verification_audits: Arc<dyn RecvVerificationAuditRepoTrait>,
+    pub evidence_bundles: Arc<dyn RecvEvidenceBundleRepoTrait>,
+    pub dids: Arc<dyn DidRepoTrait>,
+    pub keys: Arc<dyn KeyRepoTrait>,
+    pub vcs: Arc<dyn VcRepoTrait>,
+    pub pending_presentations: Arc<dyn PendingPresentationRepoTrait>,
+    /// Handle the Sea-ORM repositories share, `None` for the in-memory ones.
+    pub(super) connection: Option<RepoConnection>,
+}
+
+impl Repos {
+    /// Repositories over a Sea-ORM connection, Postgres or SQLite, or over an open transaction.
+    pub fn postgres(db: impl Into<RepoConnection>) -> Self {
+        let db: RepoConnection = db.into();
+        Self {
+            participants: Arc::new(ParticipantPostgresRepo::new(db.clone())),
+            resource_reqs: Arc::new(ResourceReqPostgresRepo::new(db.clone())),
+            issuances: Arc::new(IssuancePostgresRepo::new(db.clone())),
+            cred_templates: Arc::new(CredTemplatePostgresRepo::new(db.clone())),
+            rp_subscriptions: Arc::new(RpSubscriptionPostgresRepo::new(db.clone())),
+            status_lists: Arc::new(StatusListPostgresRepo::new(db.clone())),
+            status_entries: Arc::new(StatusEntryPostgresRepo::new(db.clone())),
+            trusted_issuers: Arc::new(TrustedIssuerPostgresRepo::new(db.clone())),
+            quota_counters: Arc::new(QuotaCounterPostgresRepo::new(db.clone())),
+            replay_entries: Arc::new(ReplayEntryPostgresRepo::new(db.clone())),
+            revocation_events: Arc::new(RevocationEventPostgresRepo::new(db.clone())),
+            shared_state: Arc::new(SharedStatePostgresRepo::new(db.clone())),
+            audit_entries: Arc::new(AuditEntryPostgresRepo::new(db.clone())),
+            sent_grants: Arc::new(SentGrantPostgresRepo::new(db.clone())),
+            sent_interactions: Arc::new(SentInteractionPostgresRepo::new(db.clone())),
+            sent_verifications: Arc::new(SentVerificationPostgresRepo::new(db.clone())),
+            recv_grants: Arc::new(RecvGrantPostgresRepo::new(db.clone())),
+            recv_interactions: Arc::new(RecvInteractionPostgresRepo::new(db.clone())),
+            recv_access_tokens: Arc::new(RecvAccessTokenPostgresRepo::new(db.clone())),
+            recv_verifications: Arc::new(RecvVerificationPostgresRepo::new(db.clone())),
+            presentation_definitions: Arc::new(RecvPresentationDefinitionPostgresRepo::new(
+                db.clone(),
+            )),
+            verification_audits: Arc::new(RecvVerificationAuditPostgresRepo::new(db.clone())),
+            evidence_bundles: Arc::new(RecvEvidenceBundlePostgresRepo::new(db.clone())),
+            dids: Arc::new(DidPostgresRepo::new(db.clone())),
+            keys: Arc::new(KeyPostgresRepo::new(db.clone())),
+            vcs: Arc::new(VcPostgresRepo::new(db.clone())),
+            pending_presentations: Arc::new(PendingPresentationPostgresRepo::new(db.clone())),
+            connection: Some(db),
+        }
+    }
+
+    /// Repositories kept in process memory, empty on every start.
+    pub fn memory() -> Self {
+        let audit_log = Arc::new(MemoryTable::new());
+        Self {
+            participants: Arc::new(ParticipantMemoryRepo::new().with_audit_log(audit_log.clone())),
+            resource_reqs: Arc::new(ResourceReqMemoryRepo::new()),
+            issuances: Arc::new(IssuanceMemoryRepo::new().with_audit_log(audit_log.clone())),
+            cred_templates: Arc::new(
+                CredTemplateMemoryRepo::new().with_audit_log(audit_log.clone()),
+            ),
+            rp_subscriptions: Arc::new(RpSubscriptionMemoryRepo::new()),
+            status_lists: Arc::new(StatusListMemoryRepo::new()),
+            status_entries: Arc::new(StatusEntryMemoryRepo::new()),
+            trusted_issuers: Arc::new(
+                TrustedIssuerMemoryRepo::new().with_audit_log(audit_log.clone()),
+            ),
+            quota_counters: Arc::new(QuotaCounterMemoryRepo::new()),
+            replay_entries: Arc::new(ReplayEntryMemoryRepo::new()),
+            revocation_events: Arc::new(RevocationEventMemoryRepo::new()),
+            shared_state: Arc::new(SharedStateMemoryRepo::new()),
+            audit_entries: Arc::new(AuditEntryMemoryRepo::shared(audit_log.clone())),
+            sent_grants: Arc::new(SentGrantMemoryRepo::new().with_audit_log(audit_log.clone())),
+            sent_interactions: Arc::new(
+                SentInteractionMemoryRepo::new().with_audit_log(audit_log.clone()),
+            ),
+            sent_verifications: Arc::new(
+                SentVerificationMemoryRepo::new().with_audit_log(audit_log.clone()),
+            ),
+            recv_grants: Arc::new(RecvGrantMemoryRepo::new().with_audit_log(audit_log.clone())),
+            recv_interactions: Arc::new(
+                RecvInteractionMemoryRepo::new().with_audit_log(audit_log.clone()),
+            ),
+            recv_access_tokens: Arc::new(RecvAccessTokenMemoryRepo::new()),
+            recv_verifications: Arc::new(
+                RecvVerificationMemoryRepo::new().with_audit_log(audit_log),
+            ),
+            presentation_definitions: Arc::new(RecvPresentationDefinitionMemoryRepo::new()),
+            verification_audits: Arc::new(RecvVerificationAuditMemoryRepo::new()),
+            evidence_bundles: Arc::new(RecvEvidenceBundleMemoryRepo::new()),
+            dids: Arc::new(DidMemoryRepo::new()),
+            keys: Arc::new(KeyMemoryRepo::new()),
+            vcs: Arc::new(VcMemoryRepo::new()),
+            pending_presentations: Arc::new(PendingPresentationMemoryRepo::new()),
+            connection: None,
+        }
+    }
+}
+
+/// Builds the repositories selected by `db_type`.
+///
+/// [`DbType::Memory`] needs neither a database nor migrations; every other engine connects
+/// through the vault credentials and expects the schema to be migrated already.
+pub async fn build_repos<T>(config: &T, vault: &VaultService) -> Outcome<Repos>
+where
+    T: DatabaseConfigTrait + Send + Sync,
+{
+    let db_type = &config.db().db_type;
+    info!("Starting repositories on {db_type}");
+    match db_type {
+        DbType::Memory => Ok(Repos::memory()),
+        _ => Ok(Repos::postgres(vault.get_db_connection(config).await?)),
+    }
+}
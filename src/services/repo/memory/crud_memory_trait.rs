@@ -0,0 +1,319 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::MemoryTable;
+use crate::data::entities::shared::audit_entry;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{
+    IntoOverwriteActive, audit_plan, model_version, version_conflict,
+};
+use crate::types::audit::AuditOperation;
+use crate::types::pagination::{Page, PageRequest, SortOrder};
+use async_trait::async_trait;
+use sea_orm::sea_query::{Value, ValueTuple};
+use sea_orm::{ActiveModelTrait, EntityTrait, ModelTrait, TryIntoModel};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Structural Mixin for CRUD execution over a process-local [`MemoryTable`].
+///
+/// Mirrors [`BasicPostgresRepo`](crate::services::repo::postgres::BasicPostgresRepo): plans
+/// and models go through the same [`IntoOverwriteActive`] conversions, so defaults and
+/// overwritten columns match what the database would store.
+///
+/// A second blanket [`CrudRepoTrait`](crate::services::repo::traits::CrudRepoTrait) impl
+/// would overlap with the Postgres one, so repos attach it with
+/// [`impl_memory_crud!`](crate::impl_memory_crud) instead.
+#[async_trait]
+pub trait BasicMemoryRepo: Send + Sync + 'static
+where
+    <Self::Entity as EntityTrait>::Model: IntoOverwriteActive<<Self::Entity as EntityTrait>::ActiveModel>
+        + Serialize
+        + Send
+        + Sync
+        + Clone
+        + 'static,
+    <Self::Entity as EntityTrait>::ActiveModel: ActiveModelTrait<Entity = Self::Entity>
+        + TryIntoModel<<Self::Entity as EntityTrait>::Model>
+        + Send
+        + Sync
+        + 'static,
+{
+    /// Sea-ORM entity whose rows the table holds.
+    type Entity: EntityTrait + Send + Sync + 'static;
+
+    /// Target Plan architecture used for insertions.
+    type Plan: IntoOverwriteActive<<Self::Entity as EntityTrait>::ActiveModel>
+        + Send
+        + Sync
+        + 'static;
+
+    /// Exposes the backing table.
+    fn table(&self) -> &MemoryTable<<Self::Entity as EntityTrait>::Model>;
+
+    /// Integer column bumped on every update, enabling optimistic concurrency control.
+    ///
+    /// Mirrors [`BasicPostgresRepo::version_column`](crate::services::repo::postgres::BasicPostgresRepo::version_column).
+    fn version_column(&self) -> Option<<Self::Entity as EntityTrait>::Column> {
+        None
+    }
+
+    /// Audit log creates, updates and deletes through this repository are appended to.
+    ///
+    /// Mirrors [`BasicPostgresRepo::audited`](crate::services::repo::postgres::BasicPostgresRepo::audited).
+    fn audit_log(&self) -> Option<&MemoryTable<audit_entry::Model>> {
+        None
+    }
+
+    async fn basic_get_all(
+        &self,
+        limit: Option<u64>,
+        offset: Option<u64>,
+    ) -> Outcome<Vec<<Self::Entity as EntityTrait>::Model>> {
+        Ok(self
+            .table()
+            .select(|_| true)
+            .await
+            .into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(100_000) as usize)
+            .collect())
+    }
+
+    async fn basic_get_page(
+        &self,
+        request: &PageRequest,
+    ) -> Outcome<Page<<Self::Entity as EntityTrait>::Model>> {
+        self.basic_page(|_| true, request).await
+    }
+
+    /// Selects the rows matching `predicate` over the window described by `request`.
+    ///
+    /// Sorts like Postgres does: nulls last in ascending order and ties broken by key.
+    async fn basic_page<F>(
+        &self,
+        predicate: F,
+        request: &PageRequest,
+    ) -> Outcome<Page<<Self::Entity as EntityTrait>::Model>>
+    where
+        F: Fn(&<Self::Entity as EntityTrait>::Model) -> bool + Send + Sync,
+    {
+        let mut rows = self.table().select(predicate).await;
+        if let Some(sort) = &request.sort {
+            let column = <Self::Entity as EntityTrait>::Column::from_str(sort)
+                .map_err(|_| Errors::validation(format!("Unknown sort column: {}", sort), None))?;
+            rows.sort_by(|a, b| compare_values(&a.get(column), &b.get(column)));
+        }
+        if request.order == SortOrder::Desc {
+            rows.reverse();
+        }
+
+        let total = rows.len() as u64;
+        let items = rows
+            .into_iter()
+            .skip(request.offset() as usize)
+            .take(request.limit() as usize)
+            .collect();
+        Ok(Page::new(items, total, request))
+    }
+
+    async fn basic_get_by_id(&self, id: &str) -> Outcome<<Self::Entity as EntityTrait>::Model> {
+        self.table()
+            .get(id)
+            .await
+            .ok_or_else(|| Errors::missing_resource(id, format!("Model not found: {}", id), None))
+    }
+
+    async fn basic_create(
+        &self,
+        plan: Self::Plan,
+    ) -> Outcome<<Self::Entity as EntityTrait>::Model> {
+        let (key, model) = into_row(plan.into_active())?;
+        let mut rows = self.table().write().await;
+        if rows.contains_key(&key) {
+            return Err(Errors::db(format!("Model {} already exists", key), None));
+        }
+        rows.insert(key, model.clone());
+        drop(rows);
+
+        self.basic_audit(AuditOperation::Create, None, Some(&model))
+            .await;
+        Ok(model)
+    }
+
+    async fn basic_update(
+        &self,
+        model: <Self::Entity as EntityTrait>::Model,
+    ) -> Outcome<<Self::Entity as EntityTrait>::Model> {
+        let expected = match self.version_column() {
+            Some(column) => Some((column, model_version(&model, column)?)),
+            None => None,
+        };
+        let mut active = model.into_active();
+        if let Some((column, version)) = expected {
+            active.set(column, (version + 1).into());
+        }
+
+        let (key, model) = into_row(active)?;
+        let mut rows = self.table().write().await;
+        let Some(row) = rows.get_mut(&key) else {
+            return Err(Errors::missing_resource(
+                &key,
+                format!("Model not found: {}", key),
+                None,
+            ));
+        };
+        if let Some((column, version)) = expected {
+            if model_version(&*row, column)? != version {
+                return Err(version_conflict(version));
+            }
+        }
+        let before = std::mem::replace(row, model.clone());
+        drop(rows);
+
+        self.basic_audit(AuditOperation::Update, Some(&before), Some(&model))
+            .await;
+        Ok(model)
+    }
+
+    async fn basic_delete(&self, id: &str) -> Outcome<()> {
+        let before = self.table().write().await.remove(id);
+        if let Some(before) = &before {
+            self.basic_audit(AuditOperation::Delete, Some(before), None)
+                .await;
+        }
+        Ok(())
+    }
+
+    /// Appends an entry for a write to the [`audit_log`](Self::audit_log), if there is one.
+    async fn basic_audit(
+        &self,
+        operation: AuditOperation,
+        before: Option<&<Self::Entity as EntityTrait>::Model>,
+        after: Option<&<Self::Entity as EntityTrait>::Model>,
+    ) {
+        let Some(log) = self.audit_log() else {
+            return;
+        };
+        let plan = audit_plan(operation, before, after);
+        match into_row::<_, audit_entry::Model>(plan.into_active()) {
+            Ok((key, entry)) => {
+                log.write().await.insert(key, entry);
+            }
+            Err(e) => e.log(),
+        }
+    }
+
+    /// Appends an update entry for every `(before, after)` pair of a bulk write.
+    async fn basic_audit_changes(
+        &self,
+        changes: &[(
+            <Self::Entity as EntityTrait>::Model,
+            <Self::Entity as EntityTrait>::Model,
+        )],
+    ) {
+        for (before, after) in changes {
+            self.basic_audit(AuditOperation::Update, Some(before), Some(after))
+                .await;
+        }
+    }
+
+    async fn basic_filter<F>(
+        &self,
+        predicate: F,
+        resource: &str,
+        id: &str,
+    ) -> Outcome<<Self::Entity as EntityTrait>::Model>
+    where
+        F: Fn(&<Self::Entity as EntityTrait>::Model) -> bool + Send + Sync,
+    {
+        self.table().find(predicate).await.ok_or_else(|| {
+            Errors::missing_resource(
+                id,
+                format!(
+                    "Unable to find model with column '{}' with value {}",
+                    resource, id
+                ),
+                None,
+            )
+        })
+    }
+}
+
+/// Converts an active model into its primary key and the row the database would return.
+pub fn into_row<A, M>(active: A) -> Outcome<(String, M)>
+where
+    A: ActiveModelTrait + TryIntoModel<M>,
+    M: ModelTrait,
+{
+    let key = match active.get_primary_key_value() {
+        Some(ValueTuple::One(Value::String(Some(key)))) => *key,
+        _ => return Err(Errors::db("Model has no string primary key", None)),
+    };
+    let model = active
+        .try_into_model()
+        .map_err(|e| Errors::db("Model is missing columns", Some(Box::new(e))))?;
+    Ok((key, model))
+}
+
+/// Evaluates the Postgres `@>` containment operator over two JSON documents.
+pub fn json_contains(container: &JsonValue, contained: &JsonValue) -> bool {
+    match (container, contained) {
+        (JsonValue::Object(outer), JsonValue::Object(inner)) => inner.iter().all(|(key, value)| {
+            outer
+                .get(key)
+                .is_some_and(|found| json_contains(found, value))
+        }),
+        (JsonValue::Array(outer), JsonValue::Array(inner)) => inner
+            .iter()
+            .all(|value| outer.iter().any(|found| json_contains(found, value))),
+        (JsonValue::Array(outer), scalar) if !scalar.is_object() => outer.contains(scalar),
+        (outer, inner) => outer == inner,
+    }
+}
+
+/// Orders two column values in ascending order, nulls last.
+///
+/// Values of unrelated types compare equal, leaving the key order untouched.
+pub fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => nulls_last(a, b, Ord::cmp),
+        (Value::SmallInt(a), Value::SmallInt(b)) => nulls_last(a, b, Ord::cmp),
+        (Value::Int(a), Value::Int(b)) => nulls_last(a, b, Ord::cmp),
+        (Value::BigInt(a), Value::BigInt(b)) => nulls_last(a, b, Ord::cmp),
+        (Value::Unsigned(a), Value::Unsigned(b)) => nulls_last(a, b, Ord::cmp),
+        (Value::BigUnsigned(a), Value::BigUnsigned(b)) => nulls_last(a, b, Ord::cmp),
+        (Value::Double(a), Value::Double(b)) => nulls_last(a, b, f64::total_cmp),
+        (Value::String(a), Value::String(b)) => nulls_last(a, b, Ord::cmp),
+        (Value::ChronoDateTimeUtc(a), Value::ChronoDateTimeUtc(b)) => nulls_last(a, b, Ord::cmp),
+        (Value::Json(a), Value::Json(b)) => {
+            nulls_last(a, b, |a, b| a.to_string().cmp(&b.to_string()))
+        }
+        _ => Ordering::Equal,
+    }
+}
+
+fn nulls_last<T>(a: &Option<T>, b: &Option<T>, cmp: impl Fn(&T, &T) -> Ordering) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => cmp(a, b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+
+use tokio::sync::{RwLock, RwLockWriteGuard};
+
+/// Process-local table keyed by the primary key of its rows.
+///
+/// Reads hand out clones of the stored rows; multi-step mutations take the write guard so
+/// that they run atomically, the same way the Postgres repos rely on a single statement.
+pub struct MemoryTable<M> {
+    rows: RwLock<HashMap<String, M>>,
+}
+
+impl<M> Default for MemoryTable<M> {
+    fn default() -> Self {
+        Self {
+            rows: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl<M: Clone> MemoryTable<M> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of the row stored under `key`.
+    pub async fn get(&self, key: &str) -> Option<M> {
+        self.rows.read().await.get(key).cloned()
+    }
+
+    /// Returns a copy of every row matching `predicate`, ordered by key.
+    pub async fn select(&self, predicate: impl Fn(&M) -> bool) -> Vec<M> {
+        let rows = self.rows.read().await;
+        let mut matching: Vec<_> = rows.iter().filter(|(_, row)| predicate(row)).collect();
+        matching.sort_by(|(a, _), (b, _)| a.cmp(b));
+        matching.into_iter().map(|(_, row)| row.clone()).collect()
+    }
+
+    /// Returns a copy of the first row, by key, matching `predicate`.
+    pub async fn find(&self, predicate: impl Fn(&M) -> bool) -> Option<M> {
+        self.select(predicate).await.into_iter().next()
+    }
+
+    /// Applies `apply` to every row matching `predicate` and returns how many were touched.
+    pub async fn update_where(
+        &self,
+        predicate: impl Fn(&M) -> bool,
+        mut apply: impl FnMut(&mut M),
+    ) -> u64 {
+        let mut rows = self.rows.write().await;
+        let mut affected = 0;
+        for row in rows.values_mut().filter(|row| predicate(row)) {
+            apply(row);
+            affected += 1;
+        }
+        affected
+    }
+
+    /// Applies `apply` to every row matching `predicate`, returning each one before and after.
+    pub async fn update_where_changes(
+        &self,
+        predicate: impl Fn(&M) -> bool,
+        mut apply: impl FnMut(&mut M),
+    ) -> Vec<(M, M)> {
+        let mut rows = self.rows.write().await;
+        rows.values_mut()
+            .filter(|row| predicate(row))
+            .map(|row| {
+                let before = row.clone();
+                apply(row);
+                (before, row.clone())
+            })
+            .collect()
+    }
+
+    /// Drops every row matching `predicate` and returns how many were removed.
+    pub async fn remove_where(&self, predicate: impl Fn(&M) -> bool) -> u64 {
+        let mut rows = self.rows.write().await;
+        let before = rows.len();
+        rows.retain(|_, row| !predicate(row));
+        (before - rows.len()) as u64
+    }
+
+    /// Locks the table for a read-modify-write sequence.
+    pub async fn write(&self) -> RwLockWriteGuard<'_, HashMap<String, M>> {
+        self.rows.write().await
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::access_token;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::received::RecvAccessTokenRepoTrait;
+use async_trait::async_trait;
+
+#[derive(Default)]
+pub struct RecvAccessTokenMemoryRepo {
+    table: MemoryTable<access_token::Model>,
+}
+
+impl RecvAccessTokenMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for RecvAccessTokenMemoryRepo {
+    type Entity = access_token::Entity;
+    type Plan = access_token::Plan;
+
+    fn table(&self) -> &MemoryTable<access_token::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(
+    RecvAccessTokenMemoryRepo,
+    access_token::Model,
+    access_token::Plan
+);
+
+#[async_trait]
+impl RecvAccessTokenRepoTrait for RecvAccessTokenMemoryRepo {
+    async fn filter_by_grant(&self, grant_id: &str) -> Outcome<Vec<access_token::Model>> {
+        Ok(self.table.select(|m| m.grant_id == grant_id).await)
+    }
+
+    async fn delete_by_grant(&self, grant_id: &str) -> Outcome<u64> {
+        Ok(self.table.remove_where(|m| m.grant_id == grant_id).await)
+    }
+}
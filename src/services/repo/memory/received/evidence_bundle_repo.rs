@@ -0,0 +1,61 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+
+use crate::data::entities::received::evidence_bundle;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::memory::{MemoryTable, into_row};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::traits::received::RecvEvidenceBundleRepoTrait;
+
+#[derive(Default)]
+pub struct RecvEvidenceBundleMemoryRepo {
+    table: MemoryTable<evidence_bundle::Model>,
+}
+
+impl RecvEvidenceBundleMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RecvEvidenceBundleRepoTrait for RecvEvidenceBundleMemoryRepo {
+    async fn seal(&self, plan: evidence_bundle::Plan) -> Outcome<evidence_bundle::Model> {
+        let (key, model) = into_row::<_, evidence_bundle::Model>(plan.into_active())?;
+        let mut rows = self.table.write().await;
+        if rows.contains_key(&key) {
+            return Err(Errors::db(
+                format!("Evidence bundle {} already sealed", key),
+                None,
+            ));
+        }
+        rows.insert(key, model.clone());
+        Ok(model)
+    }
+
+    async fn get_by_verification(
+        &self,
+        verification_id: &str,
+    ) -> Outcome<Option<evidence_bundle::Model>> {
+        Ok(self
+            .table
+            .find(|m| m.verification_id == verification_id)
+            .await)
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::grant;
+use crate::data::entities::shared::audit_entry;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, json_contains};
+use crate::services::repo::traits::received::RecvGrantRepoTrait;
+use crate::types::gnap::GrantStatus;
+use crate::types::gnap::grant_request::GrantKind;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct RecvGrantMemoryRepo {
+    table: MemoryTable<grant::Model>,
+    audit: Option<Arc<MemoryTable<audit_entry::Model>>>,
+}
+
+impl RecvGrantMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the writes through this repository in `log`.
+    pub fn with_audit_log(mut self, log: Arc<MemoryTable<audit_entry::Model>>) -> Self {
+        self.audit = Some(log);
+        self
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for RecvGrantMemoryRepo {
+    type Entity = grant::Entity;
+    type Plan = grant::Plan;
+
+    fn table(&self) -> &MemoryTable<grant::Model> {
+        &self.table
+    }
+
+    fn audit_log(&self) -> Option<&MemoryTable<audit_entry::Model>> {
+        self.audit.as_deref()
+    }
+}
+
+impl_memory_crud!(RecvGrantMemoryRepo, grant::Model, grant::Plan);
+
+#[async_trait]
+impl RecvGrantRepoTrait for RecvGrantMemoryRepo {
+    async fn filter_by_type(&self, kind: GrantKind) -> Outcome<Vec<grant::Model>> {
+        Ok(self.table.select(|m| m.kind == kind).await)
+    }
+
+    async fn filter_by_participant(&self, nick: &str) -> Outcome<Vec<grant::Model>> {
+        Ok(self.table.select(|m| m.participant_nick == nick).await)
+    }
+
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<grant::Model> {
+        let mut grant = self.basic_get_by_id(id).await?;
+        grant.extensions = extensions;
+        self.basic_update(grant).await
+    }
+
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<grant::Model>> {
+        Ok(self
+            .table
+            .select(|m| json_contains(&m.extensions, filter))
+            .await)
+    }
+
+    async fn expire_stale(&self, created_before: DateTime<Utc>) -> Outcome<u64> {
+        let now = Utc::now();
+        let changes = self
+            .table
+            .update_where_changes(
+                |m| {
+                    matches!(m.status, GrantStatus::Pending | GrantStatus::Processing)
+                        && m.created_at < created_before
+                },
+                |m| {
+                    m.status = GrantStatus::Rejected;
+                    m.ended_at = Some(now);
+                },
+            )
+            .await;
+        self.basic_audit_changes(&changes).await;
+        Ok(changes.len() as u64)
+    }
+
+    async fn get_archivable(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> Outcome<Vec<grant::Model>> {
+        let mut grants = self
+            .table
+            .select(|m| {
+                matches!(m.status, GrantStatus::Finalized | GrantStatus::Rejected)
+                    && m.ended_at.is_some_and(|ended_at| ended_at < cutoff)
+            })
+            .await;
+        grants.sort_by_key(|m| m.ended_at);
+        grants.truncate(limit as usize);
+        Ok(grants)
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        Ok(self.table.remove_where(|m| ids.contains(&m.id)).await)
+    }
+}
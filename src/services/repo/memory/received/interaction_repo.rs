@@ -0,0 +1,114 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::interaction;
+use crate::data::entities::received::interaction::Model;
+use crate::data::entities::shared::audit_entry;
+use crate::errors::{Errors, Outcome};
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::received::RecvInteractionRepoTrait;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct RecvInteractionMemoryRepo {
+    table: MemoryTable<interaction::Model>,
+    audit: Option<Arc<MemoryTable<audit_entry::Model>>>,
+}
+
+impl RecvInteractionMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the writes through this repository in `log`.
+    pub fn with_audit_log(mut self, log: Arc<MemoryTable<audit_entry::Model>>) -> Self {
+        self.audit = Some(log);
+        self
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for RecvInteractionMemoryRepo {
+    type Entity = interaction::Entity;
+    type Plan = interaction::Plan;
+
+    fn table(&self) -> &MemoryTable<interaction::Model> {
+        &self.table
+    }
+
+    fn audit_log(&self) -> Option<&MemoryTable<audit_entry::Model>> {
+        self.audit.as_deref()
+    }
+}
+
+impl_memory_crud!(
+    RecvInteractionMemoryRepo,
+    interaction::Model,
+    interaction::Plan
+);
+
+#[async_trait]
+impl RecvInteractionRepoTrait for RecvInteractionMemoryRepo {
+    async fn get_by_cont_id(&self, cont_id: &str) -> Outcome<Model> {
+        self.basic_filter(|m| m.continue_id == cont_id, "cont_id", cont_id)
+            .await
+    }
+
+    async fn rotate_continue_token(&self, cont_id: &str, presented: &str) -> Outcome<Model> {
+        let now = Utc::now();
+        let mut rows = self.table.write().await;
+        let model = rows
+            .values_mut()
+            .find(|m| m.continue_id == cont_id)
+            .ok_or_else(|| {
+                Errors::missing_resource(
+                    cont_id,
+                    format!(
+                        "Unable to find model with column 'cont_id' with value {}",
+                        cont_id
+                    ),
+                    None,
+                )
+            })?;
+        model.check_poll(now)?;
+        if model.continue_token != presented {
+            return Err(Errors::forbidden(
+                format!("Continue token for {cont_id} is not valid"),
+                None,
+            ));
+        }
+        let before = model.clone();
+        model.continue_token = Model::new_continue_token();
+        model.last_polled_at = Some(now);
+        let rotated = model.clone();
+        drop(rows);
+
+        self.basic_audit_changes(&[(before, rotated.clone())]).await;
+        Ok(rotated)
+    }
+
+    async fn filter_by_ids(&self, ids: &[String]) -> Outcome<Vec<Model>> {
+        Ok(self.table.select(|m| ids.contains(&m.id)).await)
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        Ok(self.table.remove_where(|m| ids.contains(&m.id)).await)
+    }
+}
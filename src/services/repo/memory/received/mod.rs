@@ -0,0 +1,32 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod access_token_repo;
+mod evidence_bundle_repo;
+mod grant_repo;
+mod interaction_repo;
+mod presentation_definition_repo;
+mod verification_audit_repo;
+mod verification_repo;
+
+pub use access_token_repo::RecvAccessTokenMemoryRepo;
+pub use evidence_bundle_repo::RecvEvidenceBundleMemoryRepo;
+pub use grant_repo::RecvGrantMemoryRepo;
+pub use interaction_repo::RecvInteractionMemoryRepo;
+pub use presentation_definition_repo::RecvPresentationDefinitionMemoryRepo;
+pub use verification_audit_repo::RecvVerificationAuditMemoryRepo;
+pub use verification_repo::RecvVerificationMemoryRepo;
@@ -0,0 +1,72 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::presentation_definition;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::received::RecvPresentationDefinitionRepoTrait;
+use async_trait::async_trait;
+use chrono::Utc;
+
+#[derive(Default)]
+pub struct RecvPresentationDefinitionMemoryRepo {
+    table: MemoryTable<presentation_definition::Model>,
+}
+
+impl RecvPresentationDefinitionMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for RecvPresentationDefinitionMemoryRepo {
+    type Entity = presentation_definition::Entity;
+    type Plan = presentation_definition::Plan;
+
+    fn table(&self) -> &MemoryTable<presentation_definition::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(
+    RecvPresentationDefinitionMemoryRepo,
+    presentation_definition::Model,
+    presentation_definition::Plan
+);
+
+#[async_trait]
+impl RecvPresentationDefinitionRepoTrait for RecvPresentationDefinitionMemoryRepo {
+    async fn find(&self, id: &str) -> Outcome<Option<presentation_definition::Model>> {
+        Ok(self.table.get(id).await)
+    }
+
+    async fn supersede(&self, profile: &str, current: &str) -> Outcome<u64> {
+        let now = Utc::now();
+        Ok(self
+            .table
+            .update_where(
+                |m| m.profile == profile && m.id != current && m.superseded_by.is_none(),
+                |m| {
+                    m.superseded_by = Some(current.to_string());
+                    m.superseded_at = Some(now);
+                },
+            )
+            .await)
+    }
+}
@@ -0,0 +1,70 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::verification_audit;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::received::RecvVerificationAuditRepoTrait;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[derive(Default)]
+pub struct RecvVerificationAuditMemoryRepo {
+    table: MemoryTable<verification_audit::Model>,
+}
+
+impl RecvVerificationAuditMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for RecvVerificationAuditMemoryRepo {
+    type Entity = verification_audit::Entity;
+    type Plan = verification_audit::Plan;
+
+    fn table(&self) -> &MemoryTable<verification_audit::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(
+    RecvVerificationAuditMemoryRepo,
+    verification_audit::Model,
+    verification_audit::Plan
+);
+
+#[async_trait]
+impl RecvVerificationAuditRepoTrait for RecvVerificationAuditMemoryRepo {
+    async fn get_by_verification(
+        &self,
+        verification_id: &str,
+    ) -> Outcome<Vec<verification_audit::Model>> {
+        let mut audits = self
+            .table
+            .select(|m| m.verification_id == verification_id)
+            .await;
+        audits.sort_by_key(|m| m.recorded_at);
+        Ok(audits)
+    }
+
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> Outcome<u64> {
+        Ok(self.table.remove_where(|m| m.recorded_at < cutoff).await)
+    }
+}
@@ -0,0 +1,162 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::verification;
+use crate::data::entities::shared::audit_entry;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::received::RecvVerificationRepoTrait;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::verification::{VerificationFilter, VerificationStatus};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct RecvVerificationMemoryRepo {
+    table: MemoryTable<verification::Model>,
+    audit: Option<Arc<MemoryTable<audit_entry::Model>>>,
+}
+
+impl RecvVerificationMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the writes through this repository in `log`.
+    pub fn with_audit_log(mut self, log: Arc<MemoryTable<audit_entry::Model>>) -> Self {
+        self.audit = Some(log);
+        self
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for RecvVerificationMemoryRepo {
+    type Entity = verification::Entity;
+    type Plan = verification::Plan;
+
+    fn table(&self) -> &MemoryTable<verification::Model> {
+        &self.table
+    }
+
+    fn audit_log(&self) -> Option<&MemoryTable<audit_entry::Model>> {
+        self.audit.as_deref()
+    }
+
+    fn version_column(&self) -> Option<verification::Column> {
+        Some(verification::Column::Version)
+    }
+}
+
+impl_memory_crud!(
+    RecvVerificationMemoryRepo,
+    verification::Model,
+    verification::Plan
+);
+
+#[async_trait]
+impl RecvVerificationRepoTrait for RecvVerificationMemoryRepo {
+    async fn get_by_state(&self, state: &str) -> Outcome<verification::Model> {
+        self.basic_filter(|m| m.state == state, "state", state)
+            .await
+    }
+
+    async fn search(
+        &self,
+        filter: &VerificationFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<verification::Model>> {
+        self.basic_page(
+            |m| {
+                filter
+                    .status
+                    .as_ref()
+                    .is_none_or(|status| &m.status == status)
+                    && filter
+                        .holder
+                        .as_ref()
+                        .is_none_or(|holder| m.holder.as_ref() == Some(holder))
+            },
+            request,
+        )
+        .await
+    }
+
+    async fn expire_stale(&self, now: DateTime<Utc>) -> Outcome<u64> {
+        let changes = self
+            .table
+            .update_where_changes(
+                |m| {
+                    matches!(
+                        m.status,
+                        VerificationStatus::Pending | VerificationStatus::Presented
+                    ) && m.expires_at < now
+                },
+                |m| {
+                    m.status = VerificationStatus::Expired;
+                    m.ended_at = Some(now);
+                    m.response_key = None;
+                    m.version += 1;
+                },
+            )
+            .await;
+        self.basic_audit_changes(&changes).await;
+        Ok(changes.len() as u64)
+    }
+
+    async fn revoke_holder(&self, holder: &str) -> Outcome<u64> {
+        let changes = self
+            .table
+            .update_where_changes(
+                |m| m.status == VerificationStatus::Verified && m.holder.as_deref() == Some(holder),
+                |m| {
+                    m.status = VerificationStatus::Revoked;
+                    m.version += 1;
+                },
+            )
+            .await;
+        self.basic_audit_changes(&changes).await;
+        Ok(changes.len() as u64)
+    }
+
+    async fn get_archivable(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> Outcome<Vec<verification::Model>> {
+        let mut verifications = self
+            .table
+            .select(|m| {
+                matches!(
+                    m.status,
+                    VerificationStatus::Verified
+                        | VerificationStatus::Failed
+                        | VerificationStatus::Expired
+                        | VerificationStatus::Revoked
+                ) && m.ended_at.is_some_and(|ended_at| ended_at < cutoff)
+            })
+            .await;
+        verifications.sort_by_key(|m| m.ended_at);
+        verifications.truncate(limit as usize);
+        Ok(verifications)
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        Ok(self.table.remove_where(|m| ids.contains(&m.id)).await)
+    }
+}
@@ -0,0 +1,148 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::sent::grant;
+use crate::data::entities::sent::grant::Model;
+use crate::data::entities::shared::audit_entry;
+use crate::errors::{Errors, Outcome};
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, into_row, json_contains};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::traits::sent::SentGrantRepoTrait;
+use crate::types::audit::AuditOperation;
+use crate::types::gnap::grant_request::GrantKind;
+use crate::types::gnap::{GrantStatus, RequestLifecycle};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct SentGrantMemoryRepo {
+    table: MemoryTable<grant::Model>,
+    audit: Option<Arc<MemoryTable<audit_entry::Model>>>,
+}
+
+impl SentGrantMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the writes through this repository in `log`.
+    pub fn with_audit_log(mut self, log: Arc<MemoryTable<audit_entry::Model>>) -> Self {
+        self.audit = Some(log);
+        self
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for SentGrantMemoryRepo {
+    type Entity = grant::Entity;
+    type Plan = grant::Plan;
+
+    fn table(&self) -> &MemoryTable<grant::Model> {
+        &self.table
+    }
+
+    fn audit_log(&self) -> Option<&MemoryTable<audit_entry::Model>> {
+        self.audit.as_deref()
+    }
+}
+
+impl_memory_crud!(SentGrantMemoryRepo, grant::Model, grant::Plan);
+
+#[async_trait]
+impl SentGrantRepoTrait for SentGrantMemoryRepo {
+    async fn filter_by_type(&self, kind: GrantKind) -> Outcome<Vec<Model>> {
+        Ok(self.table.select(|m| m.kind == kind).await)
+    }
+
+    async fn filter_by_participant(&self, participant_id: &str) -> Outcome<Vec<Model>> {
+        Ok(self
+            .table
+            .select(|m| m.participant_id == participant_id)
+            .await)
+    }
+
+    async fn advance(&self, mut model: Model, next: RequestLifecycle) -> Outcome<Model> {
+        let current = model.lifecycle;
+        model.transition(next)?;
+
+        let (key, model) = into_row::<_, Model>(model.into_active())?;
+        let mut rows = self.table.write().await;
+        let before = match rows.get_mut(&key) {
+            Some(row) if row.lifecycle == current => std::mem::replace(row, model.clone()),
+            _ => {
+                return Err(Errors::forbidden(
+                    format!("Grant request is no longer {current:?}"),
+                    None,
+                ));
+            }
+        };
+        drop(rows);
+
+        self.basic_audit(AuditOperation::Update, Some(&before), Some(&model))
+            .await;
+        Ok(model)
+    }
+
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<Model> {
+        let mut grant = self.basic_get_by_id(id).await?;
+        grant.extensions = extensions;
+        self.basic_update(grant).await
+    }
+
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<Model>> {
+        Ok(self
+            .table
+            .select(|m| json_contains(&m.extensions, filter))
+            .await)
+    }
+
+    async fn expire_stale(&self, created_before: DateTime<Utc>) -> Outcome<u64> {
+        let now = Utc::now();
+        let changes = self
+            .table
+            .update_where_changes(
+                |m| !m.lifecycle.is_terminal() && m.created_at < created_before,
+                |m| {
+                    m.lifecycle = RequestLifecycle::Denied;
+                    m.status = GrantStatus::Rejected;
+                    m.ended_at = Some(now);
+                },
+            )
+            .await;
+        self.basic_audit_changes(&changes).await;
+        Ok(changes.len() as u64)
+    }
+
+    async fn get_archivable(&self, cutoff: DateTime<Utc>, limit: u64) -> Outcome<Vec<Model>> {
+        let mut grants = self
+            .table
+            .select(|m| {
+                m.lifecycle.is_terminal() && m.ended_at.is_some_and(|ended_at| ended_at < cutoff)
+            })
+            .await;
+        grants.sort_by_key(|m| m.ended_at);
+        grants.truncate(limit as usize);
+        Ok(grants)
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        Ok(self.table.remove_where(|m| ids.contains(&m.id)).await)
+    }
+}
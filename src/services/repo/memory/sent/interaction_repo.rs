@@ -0,0 +1,70 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::sent::interaction;
+use crate::data::entities::shared::audit_entry;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::sent::SentInteractionRepoTrait;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct SentInteractionMemoryRepo {
+    table: MemoryTable<interaction::Model>,
+    audit: Option<Arc<MemoryTable<audit_entry::Model>>>,
+}
+
+impl SentInteractionMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the writes through this repository in `log`.
+    pub fn with_audit_log(mut self, log: Arc<MemoryTable<audit_entry::Model>>) -> Self {
+        self.audit = Some(log);
+        self
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for SentInteractionMemoryRepo {
+    type Entity = interaction::Entity;
+    type Plan = interaction::Plan;
+
+    fn table(&self) -> &MemoryTable<interaction::Model> {
+        &self.table
+    }
+
+    fn audit_log(&self) -> Option<&MemoryTable<audit_entry::Model>> {
+        self.audit.as_deref()
+    }
+}
+
+impl_memory_crud!(
+    SentInteractionMemoryRepo,
+    interaction::Model,
+    interaction::Plan
+);
+
+#[async_trait]
+impl SentInteractionRepoTrait for SentInteractionMemoryRepo {
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        Ok(self.table.remove_where(|m| ids.contains(&m.id)).await)
+    }
+}
@@ -0,0 +1,95 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::audit_entry;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::shared::AuditEntryRepoTrait;
+use crate::types::audit::AuditFilter;
+use crate::types::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Reads the log the audited memory repositories append to.
+#[derive(Default)]
+pub struct AuditEntryMemoryRepo {
+    table: Arc<MemoryTable<audit_entry::Model>>,
+}
+
+impl AuditEntryMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Repository over a log shared with the audited repositories.
+    pub fn shared(table: Arc<MemoryTable<audit_entry::Model>>) -> Self {
+        Self { table }
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for AuditEntryMemoryRepo {
+    type Entity = audit_entry::Entity;
+    type Plan = audit_entry::Plan;
+
+    fn table(&self) -> &MemoryTable<audit_entry::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(AuditEntryMemoryRepo, audit_entry::Model, audit_entry::Plan);
+
+#[async_trait]
+impl AuditEntryRepoTrait for AuditEntryMemoryRepo {
+    async fn search(
+        &self,
+        filter: &AuditFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<audit_entry::Model>> {
+        self.basic_page(
+            |m| {
+                filter
+                    .entity
+                    .as_ref()
+                    .is_none_or(|entity| &m.entity == entity)
+                    && filter
+                        .entity_id
+                        .as_ref()
+                        .is_none_or(|entity_id| &m.entity_id == entity_id)
+                    && filter
+                        .actor
+                        .as_ref()
+                        .is_none_or(|actor| m.actor.as_ref() == Some(actor))
+                    && filter
+                        .operation
+                        .is_none_or(|operation| m.operation == operation)
+            },
+            request,
+        )
+        .await
+    }
+
+    async fn history(&self, entity: &str, entity_id: &str) -> Outcome<Vec<audit_entry::Model>> {
+        let mut entries = self
+            .table
+            .select(|m| m.entity == entity && m.entity_id == entity_id)
+            .await;
+        entries.sort_by_key(|m| m.created_at);
+        Ok(entries)
+    }
+}
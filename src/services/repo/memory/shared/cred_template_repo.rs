@@ -0,0 +1,92 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::audit_entry;
+use crate::data::entities::shared::cred_template;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, into_row};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::traits::shared::CredTemplateRepoTrait;
+use crate::types::audit::AuditOperation;
+use crate::types::vcs::VcType;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct CredTemplateMemoryRepo {
+    table: MemoryTable<cred_template::Model>,
+    audit: Option<Arc<MemoryTable<audit_entry::Model>>>,
+}
+
+impl CredTemplateMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the writes through this repository in `log`.
+    pub fn with_audit_log(mut self, log: Arc<MemoryTable<audit_entry::Model>>) -> Self {
+        self.audit = Some(log);
+        self
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for CredTemplateMemoryRepo {
+    type Entity = cred_template::Entity;
+    type Plan = cred_template::Plan;
+
+    fn table(&self) -> &MemoryTable<cred_template::Model> {
+        &self.table
+    }
+
+    fn audit_log(&self) -> Option<&MemoryTable<audit_entry::Model>> {
+        self.audit.as_deref()
+    }
+}
+
+impl_memory_crud!(
+    CredTemplateMemoryRepo,
+    cred_template::Model,
+    cred_template::Plan
+);
+
+#[async_trait]
+impl CredTemplateRepoTrait for CredTemplateMemoryRepo {
+    async fn get_by_vc_type(&self, vc_type: &VcType) -> Outcome<cred_template::Model> {
+        self.basic_get_by_id(&vc_type.to_string()).await
+    }
+
+    async fn upsert(&self, plan: cred_template::Plan) -> Outcome<cred_template::Model> {
+        let (key, model) = into_row::<_, cred_template::Model>(plan.into_active())?;
+        let mut rows = self.table.write().await;
+        let before = rows.get(&key).cloned();
+        let row = rows.entry(key).or_insert_with(|| model.clone());
+        row.subject = model.subject;
+        row.updated_at = model.updated_at;
+        let template = row.clone();
+        drop(rows);
+
+        let operation = match before {
+            Some(_) => AuditOperation::Update,
+            None => AuditOperation::Create,
+        };
+        self.basic_audit(operation, before.as_ref(), Some(&template))
+            .await;
+        Ok(template)
+    }
+}
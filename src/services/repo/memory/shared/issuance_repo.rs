@@ -0,0 +1,121 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::audit_entry;
+use crate::data::entities::shared::issuance;
+use crate::data::entities::shared::issuance::Model;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, json_contains};
+use crate::services::repo::traits::shared::IssuanceRepoTrait;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct IssuanceMemoryRepo {
+    table: MemoryTable<issuance::Model>,
+    audit: Option<Arc<MemoryTable<audit_entry::Model>>>,
+}
+
+impl IssuanceMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the writes through this repository in `log`.
+    pub fn with_audit_log(mut self, log: Arc<MemoryTable<audit_entry::Model>>) -> Self {
+        self.audit = Some(log);
+        self
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for IssuanceMemoryRepo {
+    type Entity = issuance::Entity;
+    type Plan = issuance::Plan;
+
+    fn table(&self) -> &MemoryTable<issuance::Model> {
+        &self.table
+    }
+
+    fn audit_log(&self) -> Option<&MemoryTable<audit_entry::Model>> {
+        self.audit.as_deref()
+    }
+
+    fn version_column(&self) -> Option<issuance::Column> {
+        Some(issuance::Column::Version)
+    }
+}
+
+impl_memory_crud!(IssuanceMemoryRepo, issuance::Model, issuance::Plan);
+
+#[async_trait]
+impl IssuanceRepoTrait for IssuanceMemoryRepo {
+    async fn get_by_pre_auth_code(&self, code: &str) -> Outcome<Model> {
+        self.basic_filter(|m| m.pre_auth_code == code, "pre_auth_code", code)
+            .await
+    }
+    async fn get_by_token(&self, token: &str) -> Outcome<Model> {
+        self.basic_filter(|m| m.token == token, "token", token)
+            .await
+    }
+    async fn get_by_notification_id(&self, notification_id: &str) -> Outcome<Model> {
+        self.basic_filter(
+            |m| m.notification_id == notification_id,
+            "notification_id",
+            notification_id,
+        )
+        .await
+    }
+    async fn filter_by_subject(&self, subject_name: &str) -> Outcome<Vec<Model>> {
+        Ok(self.table.select(|m| m.subject_name == subject_name).await)
+    }
+    async fn filter_by_credential(&self, credential_id: &str) -> Outcome<Vec<Model>> {
+        Ok(self
+            .table
+            .select(|m| m.credential_id == credential_id)
+            .await)
+    }
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<Model> {
+        let mut issuance = self.basic_get_by_id(id).await?;
+        issuance.extensions = extensions;
+        self.basic_update(issuance).await
+    }
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<Model>> {
+        Ok(self
+            .table
+            .select(|m| json_contains(&m.extensions, filter))
+            .await)
+    }
+    async fn get_archivable(&self, cutoff: DateTime<Utc>, limit: u64) -> Outcome<Vec<Model>> {
+        let mut issuances = self
+            .table
+            .select(|m| match m.notified_at {
+                Some(notified_at) => notified_at < cutoff,
+                None => m.nonce_expires_at < cutoff,
+            })
+            .await;
+        issuances.sort_by_key(|m| m.nonce_expires_at);
+        issuances.truncate(limit as usize);
+        Ok(issuances)
+    }
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        Ok(self.table.remove_where(|m| ids.contains(&m.id)).await)
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod audit_entry_repo;
+mod cred_template_repo;
+mod issuance_repo;
+mod participant_repo;
+mod quota_counter_repo;
+mod replay_entry_repo;
+mod resource_req_repo;
+mod revocation_event_repo;
+mod rp_subscription_repo;
+mod shared_state_repo;
+mod status_entry_repo;
+mod status_list_repo;
+mod trusted_issuer_repo;
+
+pub use audit_entry_repo::AuditEntryMemoryRepo;
+pub use cred_template_repo::CredTemplateMemoryRepo;
+pub use issuance_repo::IssuanceMemoryRepo;
+pub use participant_repo::ParticipantMemoryRepo;
+pub use quota_counter_repo::QuotaCounterMemoryRepo;
+pub use replay_entry_repo::ReplayEntryMemoryRepo;
+pub use resource_req_repo::ResourceReqMemoryRepo;
+pub use revocation_event_repo::RevocationEventMemoryRepo;
+pub use rp_subscription_repo::RpSubscriptionMemoryRepo;
+pub use shared_state_repo::SharedStateMemoryRepo;
+pub use status_entry_repo::StatusEntryMemoryRepo;
+pub use status_list_repo::StatusListMemoryRepo;
+pub use trusted_issuer_repo::TrustedIssuerMemoryRepo;
@@ -0,0 +1,296 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::audit_entry;
+use crate::data::entities::shared::participant;
+use crate::errors::{Errors, Outcome};
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, into_row, json_contains};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::traits::shared::ParticipantRepoTrait;
+use crate::types::audit::AuditOperation;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::participants::{BusinessAttributes, ParticipantStatus, ParticipantType};
+use crate::utils::create_opaque_token;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct ParticipantMemoryRepo {
+    table: MemoryTable<participant::Model>,
+    audit: Option<Arc<MemoryTable<audit_entry::Model>>>,
+}
+
+impl ParticipantMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the writes through this repository in `log`.
+    pub fn with_audit_log(mut self, log: Arc<MemoryTable<audit_entry::Model>>) -> Self {
+        self.audit = Some(log);
+        self
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for ParticipantMemoryRepo {
+    type Entity = participant::Entity;
+    type Plan = participant::Plan;
+
+    fn table(&self) -> &MemoryTable<participant::Model> {
+        &self.table
+    }
+
+    fn audit_log(&self) -> Option<&MemoryTable<audit_entry::Model>> {
+        self.audit.as_deref()
+    }
+}
+
+impl_memory_crud!(ParticipantMemoryRepo, participant::Model, participant::Plan);
+
+#[async_trait]
+impl ParticipantRepoTrait for ParticipantMemoryRepo {
+    async fn get_me(&self) -> Outcome<participant::Model> {
+        self.basic_filter(|m| m.is_me, "is_me", "true").await
+    }
+
+    async fn filter_by_type(
+        &self,
+        participant_type: ParticipantType,
+    ) -> Outcome<Vec<participant::Model>> {
+        match participant_type {
+            ParticipantType::All => self.basic_get_all(None, None).await,
+            filter => Ok(self.table.select(|m| m.participant_type == filter).await),
+        }
+    }
+
+    async fn get_by_token(&self, token: &str) -> Outcome<participant::Model> {
+        let participant = self
+            .basic_filter(|m| m.token.as_deref() == Some(token), "token", token)
+            .await?;
+        if participant.token_expired() {
+            return Err(Errors::unauthorized(
+                format!("Access token of {} has expired", participant.participant_id),
+                None,
+            ));
+        }
+        participant.check_standing()?;
+        Ok(participant)
+    }
+
+    async fn get_by_instance_id(&self, instance_id: &str) -> Outcome<participant::Model> {
+        self.basic_filter(
+            |m| m.instance_id.as_deref() == Some(instance_id),
+            "instance_id",
+            instance_id,
+        )
+        .await
+    }
+
+    async fn rotate_token(
+        &self,
+        token: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Outcome<participant::Model> {
+        let mut rows = self.table.write().await;
+        let participant = rows
+            .values_mut()
+            .find(|m| m.token.as_deref() == Some(token))
+            .ok_or_else(|| {
+                Errors::missing_resource(
+                    token,
+                    format!(
+                        "Unable to find model with column 'token' with value {}",
+                        token
+                    ),
+                    None,
+                )
+            })?;
+        participant.check_standing()?;
+
+        let before = participant.clone();
+        participant.token = Some(create_opaque_token());
+        participant.token_expires_at = participant.cap_token_expiry(expires_at);
+        participant.last_interaction = Utc::now();
+        let rotated = participant.clone();
+        drop(rows);
+
+        self.basic_audit_changes(&[(before, rotated.clone())]).await;
+        Ok(rotated)
+    }
+
+    async fn filter_by_status(
+        &self,
+        status: ParticipantStatus,
+    ) -> Outcome<Vec<participant::Model>> {
+        Ok(self.table.select(|m| m.status == status).await)
+    }
+
+    async fn get_batch(&self, ids: &[String]) -> Outcome<Vec<participant::Model>> {
+        Ok(self.table.select(|m| ids.contains(&m.participant_id)).await)
+    }
+
+    async fn force_update(&self, plan: participant::Plan) -> Outcome<participant::Model> {
+        let (key, mate) = into_row::<_, participant::Model>(plan.into_active())?;
+        let mut rows = self.table.write().await;
+        let before = rows.get(&key).cloned();
+        let row = match rows.entry(key) {
+            Entry::Vacant(entry) => entry.insert(mate),
+            Entry::Occupied(entry) => {
+                let row = entry.into_mut();
+                row.base_url = mate.base_url;
+                row.last_interaction = mate.last_interaction;
+                row.token = mate.token;
+                row.token_expires_at = mate.token_expires_at;
+                row.participant_nick = mate.participant_nick;
+                row
+            }
+        };
+        let mate = row.clone();
+        drop(rows);
+
+        let operation = match before {
+            Some(_) => AuditOperation::Update,
+            None => AuditOperation::Create,
+        };
+        self.basic_audit(operation, before.as_ref(), Some(&mate))
+            .await;
+        Ok(mate)
+    }
+
+    async fn import_batch(
+        &self,
+        plans: Vec<participant::Plan>,
+    ) -> Outcome<Vec<participant::Model>> {
+        let mates = plans
+            .into_iter()
+            .map(|plan| into_row::<_, participant::Model>(plan.into_active()))
+            .collect::<Outcome<Vec<_>>>()?;
+        let mut rows = self.table.write().await;
+        let mut imported = Vec::with_capacity(mates.len());
+        let mut previous = Vec::with_capacity(mates.len());
+        for (key, mate) in mates {
+            previous.push(rows.get(&key).cloned());
+            let row = match rows.entry(key) {
+                Entry::Vacant(entry) => entry.insert(mate),
+                Entry::Occupied(entry) => {
+                    let row = entry.into_mut();
+                    row.base_url = mate.base_url;
+                    row.participant_nick = mate.participant_nick;
+                    row.participant_type = mate.participant_type;
+                    row.extensions = mate.extensions;
+                    row.last_interaction = mate.last_interaction;
+                    row
+                }
+            };
+            imported.push(row.clone());
+        }
+        drop(rows);
+
+        for (before, mate) in previous.iter().zip(&imported) {
+            let operation = match before {
+                Some(_) => AuditOperation::Update,
+                None => AuditOperation::Create,
+            };
+            self.basic_audit(operation, before.as_ref(), Some(mate))
+                .await;
+        }
+        Ok(imported)
+    }
+
+    async fn update_business(
+        &self,
+        id: &str,
+        attrs: BusinessAttributes,
+    ) -> Outcome<participant::Model> {
+        let mut mate = self.basic_get_by_id(id).await?;
+        mate.apply_business(attrs);
+        self.basic_update(mate).await
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        request: &PageRequest,
+    ) -> Outcome<Page<participant::Model>> {
+        let query = query.to_lowercase();
+        self.basic_page(
+            |m| {
+                [&m.participant_id, &m.participant_nick, &m.base_url]
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&query))
+            },
+            request,
+        )
+        .await
+    }
+
+    async fn filter_by_business(
+        &self,
+        filter: &BusinessAttributes,
+    ) -> Outcome<Vec<participant::Model>> {
+        let name = filter.legal_name.as_ref().map(|name| name.to_lowercase());
+        let country = filter.country_code.as_ref().map(|c| c.to_uppercase());
+        Ok(self
+            .table
+            .select(|m| {
+                name.as_ref().is_none_or(|name| {
+                    m.legal_name
+                        .as_ref()
+                        .is_some_and(|legal| legal.to_lowercase().contains(name.as_str()))
+                }) && country
+                    .as_ref()
+                    .is_none_or(|country| m.country_code.as_ref() == Some(country))
+                    && filter
+                        .registration_number
+                        .as_ref()
+                        .is_none_or(|number| m.registration_number.as_ref() == Some(number))
+                    && filter
+                        .registration_number_type
+                        .as_ref()
+                        .is_none_or(|kind| m.registration_number_type.as_ref() == Some(kind))
+            })
+            .await)
+    }
+
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<participant::Model> {
+        let mut mate = self.basic_get_by_id(id).await?;
+        mate.extensions = extensions;
+        self.basic_update(mate).await
+    }
+
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<participant::Model>> {
+        Ok(self
+            .table
+            .select(|m| json_contains(&m.extensions, filter))
+            .await)
+    }
+
+    async fn get_dependents(&self, id: &str) -> Outcome<Vec<participant::Model>> {
+        let (scoped, fragment) = (format!("{id}:"), format!("{id}#"));
+        Ok(self
+            .table
+            .select(|m| {
+                m.participant_id.starts_with(&scoped) || m.participant_id.starts_with(&fragment)
+            })
+            .await)
+    }
+}
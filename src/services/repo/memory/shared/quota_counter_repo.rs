@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::quota_counter;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, into_row};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::traits::shared::QuotaCounterRepoTrait;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::hash_map::Entry;
+
+#[derive(Default)]
+pub struct QuotaCounterMemoryRepo {
+    table: MemoryTable<quota_counter::Model>,
+}
+
+impl QuotaCounterMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for QuotaCounterMemoryRepo {
+    type Entity = quota_counter::Entity;
+    type Plan = quota_counter::Plan;
+
+    fn table(&self) -> &MemoryTable<quota_counter::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(
+    QuotaCounterMemoryRepo,
+    quota_counter::Model,
+    quota_counter::Plan
+);
+
+#[async_trait]
+impl QuotaCounterRepoTrait for QuotaCounterMemoryRepo {
+    async fn increment(&self, plan: quota_counter::Plan) -> Outcome<quota_counter::Model> {
+        let (key, model) = into_row::<_, quota_counter::Model>(plan.into_active())?;
+        let mut rows = self.table.write().await;
+        let row = match rows.entry(key) {
+            Entry::Vacant(entry) => entry.insert(model),
+            Entry::Occupied(entry) => {
+                let row = entry.into_mut();
+                row.count += 1;
+                row.updated_at = Utc::now();
+                row
+            }
+        };
+        Ok(row.clone())
+    }
+
+    async fn get_by_participant(
+        &self,
+        participant_id: &str,
+        since: DateTime<Utc>,
+    ) -> Outcome<Vec<quota_counter::Model>> {
+        let mut counters = self
+            .table
+            .select(|m| m.participant_id == participant_id && m.window_start >= since)
+            .await;
+        counters.sort_by(|a, b| b.window_start.cmp(&a.window_start));
+        Ok(counters)
+    }
+
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> Outcome<u64> {
+        Ok(self.table.remove_where(|m| m.window_start < cutoff).await)
+    }
+}
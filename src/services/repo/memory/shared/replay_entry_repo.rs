@@ -0,0 +1,79 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::replay_entry;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, into_row};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::traits::shared::ReplayEntryRepoTrait;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::hash_map::Entry;
+
+#[derive(Default)]
+pub struct ReplayEntryMemoryRepo {
+    table: MemoryTable<replay_entry::Model>,
+}
+
+impl ReplayEntryMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for ReplayEntryMemoryRepo {
+    type Entity = replay_entry::Entity;
+    type Plan = replay_entry::Plan;
+
+    fn table(&self) -> &MemoryTable<replay_entry::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(
+    ReplayEntryMemoryRepo,
+    replay_entry::Model,
+    replay_entry::Plan
+);
+
+#[async_trait]
+impl ReplayEntryRepoTrait for ReplayEntryMemoryRepo {
+    async fn consume(&self, plan: replay_entry::Plan) -> Outcome<bool> {
+        let now = Utc::now();
+        let (key, model) = into_row::<_, replay_entry::Model>(plan.into_active())?;
+        let mut rows = self.table.write().await;
+        match rows.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(model);
+                Ok(true)
+            }
+            Entry::Occupied(mut entry) if entry.get().expires_at < now => {
+                let row = entry.get_mut();
+                row.expires_at = model.expires_at;
+                row.created_at = model.created_at;
+                Ok(true)
+            }
+            Entry::Occupied(_) => Ok(false),
+        }
+    }
+
+    async fn purge_expired(&self, now: DateTime<Utc>) -> Outcome<u64> {
+        Ok(self.table.remove_where(|m| m.expires_at < now).await)
+    }
+}
@@ -0,0 +1,76 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::revocation_event;
+use crate::errors::{Errors, Outcome};
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, into_row};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::traits::shared::RevocationEventRepoTrait;
+use async_trait::async_trait;
+use sea_orm::ActiveValue;
+
+#[derive(Default)]
+pub struct RevocationEventMemoryRepo {
+    table: MemoryTable<revocation_event::Model>,
+}
+
+impl RevocationEventMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for RevocationEventMemoryRepo {
+    type Entity = revocation_event::Entity;
+    type Plan = revocation_event::Plan;
+
+    fn table(&self) -> &MemoryTable<revocation_event::Model> {
+        &self.table
+    }
+
+    /// Assigns the next feed position, as the database sequence does.
+    async fn basic_create(&self, plan: revocation_event::Plan) -> Outcome<revocation_event::Model> {
+        let mut rows = self.table.write().await;
+        let seq = rows.values().map(|m| m.seq).max().unwrap_or(0) + 1;
+        let mut active = plan.into_active();
+        active.seq = ActiveValue::Set(seq);
+        let (key, model) = into_row(active)?;
+        if rows.contains_key(&key) {
+            return Err(Errors::db(format!("Model {} already exists", key), None));
+        }
+        rows.insert(key, model.clone());
+        Ok(model)
+    }
+}
+
+impl_memory_crud!(
+    RevocationEventMemoryRepo,
+    revocation_event::Model,
+    revocation_event::Plan
+);
+
+#[async_trait]
+impl RevocationEventRepoTrait for RevocationEventMemoryRepo {
+    async fn since(&self, cursor: i64, limit: u64) -> Outcome<Vec<revocation_event::Model>> {
+        let mut events = self.table.select(|m| m.seq > cursor).await;
+        events.sort_by_key(|m| m.seq);
+        events.truncate(limit as usize);
+        Ok(events)
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::rp_subscription;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::shared::RpSubscriptionRepoTrait;
+use crate::types::vcs::VcType;
+use async_trait::async_trait;
+
+#[derive(Default)]
+pub struct RpSubscriptionMemoryRepo {
+    table: MemoryTable<rp_subscription::Model>,
+}
+
+impl RpSubscriptionMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for RpSubscriptionMemoryRepo {
+    type Entity = rp_subscription::Entity;
+    type Plan = rp_subscription::Plan;
+
+    fn table(&self) -> &MemoryTable<rp_subscription::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(
+    RpSubscriptionMemoryRepo,
+    rp_subscription::Model,
+    rp_subscription::Plan
+);
+
+#[async_trait]
+impl RpSubscriptionRepoTrait for RpSubscriptionMemoryRepo {
+    async fn get_matching(
+        &self,
+        holder: Option<&str>,
+        vc_types: &[VcType],
+        purpose: Option<&str>,
+    ) -> Outcome<Vec<rp_subscription::Model>> {
+        Ok(self
+            .table
+            .select(|sub| sub.matches(holder, vc_types, purpose))
+            .await)
+    }
+
+    async fn set_active(&self, id: &str, active: bool) -> Outcome<rp_subscription::Model> {
+        let mut sub = self.basic_get_by_id(id).await?;
+        sub.active = active;
+        self.basic_update(sub).await
+    }
+
+    async fn filter_by_participant(
+        &self,
+        participant_id: &str,
+    ) -> Outcome<Vec<rp_subscription::Model>> {
+        Ok(self
+            .table
+            .select(|sub| sub.participant_id.as_deref() == Some(participant_id))
+            .await)
+    }
+}
@@ -0,0 +1,92 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::shared_state;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, into_row};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::traits::shared::SharedStateRepoTrait;
+use async_trait::async_trait;
+use std::collections::hash_map::Entry;
+
+#[derive(Default)]
+pub struct SharedStateMemoryRepo {
+    table: MemoryTable<shared_state::Model>,
+}
+
+impl SharedStateMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for SharedStateMemoryRepo {
+    type Entity = shared_state::Entity;
+    type Plan = shared_state::Plan;
+
+    fn table(&self) -> &MemoryTable<shared_state::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(
+    SharedStateMemoryRepo,
+    shared_state::Model,
+    shared_state::Plan
+);
+
+#[async_trait]
+impl SharedStateRepoTrait for SharedStateMemoryRepo {
+    async fn find(&self, key: &str) -> Outcome<Option<shared_state::Model>> {
+        Ok(self.table.get(key).await)
+    }
+
+    async fn publish(&self, plan: shared_state::Plan) -> Outcome<bool> {
+        let epoch = plan.epoch;
+        self.upsert_where(plan, |stored| stored <= epoch).await
+    }
+
+    async fn claim(&self, plan: shared_state::Plan) -> Outcome<bool> {
+        let epoch = plan.epoch;
+        self.upsert_where(plan, |stored| stored < epoch).await
+    }
+}
+
+impl SharedStateMemoryRepo {
+    /// Inserts the entry, or overwrites the stored one when its epoch satisfies `condition`.
+    async fn upsert_where(
+        &self,
+        plan: shared_state::Plan,
+        condition: impl Fn(i64) -> bool,
+    ) -> Outcome<bool> {
+        let (key, model) = into_row::<_, shared_state::Model>(plan.into_active())?;
+        let mut rows = self.table.write().await;
+        match rows.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(model);
+                Ok(true)
+            }
+            Entry::Occupied(mut entry) if condition(entry.get().epoch) => {
+                entry.insert(model);
+                Ok(true)
+            }
+            Entry::Occupied(_) => Ok(false),
+        }
+    }
+}
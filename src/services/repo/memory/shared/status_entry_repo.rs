@@ -0,0 +1,77 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::status_entry;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::shared::StatusEntryRepoTrait;
+use crate::types::issuance::StatusPurpose;
+use async_trait::async_trait;
+
+#[derive(Default)]
+pub struct StatusEntryMemoryRepo {
+    table: MemoryTable<status_entry::Model>,
+}
+
+impl StatusEntryMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for StatusEntryMemoryRepo {
+    type Entity = status_entry::Entity;
+    type Plan = status_entry::Plan;
+
+    fn table(&self) -> &MemoryTable<status_entry::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(
+    StatusEntryMemoryRepo,
+    status_entry::Model,
+    status_entry::Plan
+);
+
+#[async_trait]
+impl StatusEntryRepoTrait for StatusEntryMemoryRepo {
+    async fn get_by_credential(
+        &self,
+        credential_id: &str,
+        purpose: StatusPurpose,
+    ) -> Outcome<status_entry::Model> {
+        self.basic_filter(
+            |m| m.credential_id == credential_id && m.purpose == purpose,
+            "credential_id",
+            credential_id,
+        )
+        .await
+    }
+
+    async fn get_all_by_credential(
+        &self,
+        credential_id: &str,
+    ) -> Outcome<Vec<status_entry::Model>> {
+        Ok(self
+            .table
+            .select(|m| m.credential_id == credential_id)
+            .await)
+    }
+}
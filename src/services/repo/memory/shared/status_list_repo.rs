@@ -0,0 +1,95 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::status_list;
+use crate::errors::{Errors, Outcome};
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, into_row};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::traits::shared::StatusListRepoTrait;
+use crate::types::issuance::{DEFAULT_STATUS_LIST_SIZE, StatusPurpose};
+use async_trait::async_trait;
+
+#[derive(Default)]
+pub struct StatusListMemoryRepo {
+    table: MemoryTable<status_list::Model>,
+}
+
+impl StatusListMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for StatusListMemoryRepo {
+    type Entity = status_list::Entity;
+    type Plan = status_list::Plan;
+
+    fn table(&self) -> &MemoryTable<status_list::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(StatusListMemoryRepo, status_list::Model, status_list::Plan);
+
+#[async_trait]
+impl StatusListRepoTrait for StatusListMemoryRepo {
+    async fn allocate(&self, purpose: StatusPurpose) -> Outcome<(status_list::Model, i32)> {
+        let mut rows = self.table.write().await;
+
+        let open = rows
+            .values()
+            .filter(|list| list.purpose == purpose && !list.is_full())
+            .max_by_key(|list| list.created_at)
+            .cloned();
+        let mut list = match open {
+            Some(list) => list,
+            None => {
+                into_row::<_, status_list::Model>(
+                    status_list::Plan {
+                        purpose,
+                        size: DEFAULT_STATUS_LIST_SIZE,
+                    }
+                    .into_active(),
+                )?
+                .1
+            }
+        };
+
+        let index = list.claim_random_index()?;
+        let (key, list) = into_row::<_, status_list::Model>(list.into_active())?;
+        rows.insert(key, list.clone());
+        Ok((list, index))
+    }
+
+    async fn set_bit(&self, id: &str, index: i32, value: bool) -> Outcome<status_list::Model> {
+        let mut rows = self.table.write().await;
+
+        let mut list = rows.get(id).cloned().ok_or_else(|| {
+            Errors::missing_resource(id, format!("Status list not found: {}", id), None)
+        })?;
+
+        let mut bits = list.bitstring();
+        bits.set(index, value)?;
+        list.bits = bits.into_bytes();
+
+        let (key, list) = into_row::<_, status_list::Model>(list.into_active())?;
+        rows.insert(key, list.clone());
+        Ok(list)
+    }
+}
@@ -0,0 +1,100 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::audit_entry;
+use crate::data::entities::shared::trusted_issuer;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::shared::TrustedIssuerRepoTrait;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::verification::TrustedIssuerFilter;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct TrustedIssuerMemoryRepo {
+    table: MemoryTable<trusted_issuer::Model>,
+    audit: Option<Arc<MemoryTable<audit_entry::Model>>>,
+}
+
+impl TrustedIssuerMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the writes through this repository in `log`.
+    pub fn with_audit_log(mut self, log: Arc<MemoryTable<audit_entry::Model>>) -> Self {
+        self.audit = Some(log);
+        self
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for TrustedIssuerMemoryRepo {
+    type Entity = trusted_issuer::Entity;
+    type Plan = trusted_issuer::Plan;
+
+    fn table(&self) -> &MemoryTable<trusted_issuer::Model> {
+        &self.table
+    }
+
+    fn audit_log(&self) -> Option<&MemoryTable<audit_entry::Model>> {
+        self.audit.as_deref()
+    }
+}
+
+impl_memory_crud!(
+    TrustedIssuerMemoryRepo,
+    trusted_issuer::Model,
+    trusted_issuer::Plan
+);
+
+#[async_trait]
+impl TrustedIssuerRepoTrait for TrustedIssuerMemoryRepo {
+    async fn get_by_issuer(&self, issuer_did: &str) -> Outcome<Vec<trusted_issuer::Model>> {
+        Ok(self.table.select(|m| m.issuer_did == issuer_did).await)
+    }
+
+    async fn get_by_source(&self, source: &str) -> Outcome<Vec<trusted_issuer::Model>> {
+        Ok(self
+            .table
+            .select(|m| m.source.as_deref() == Some(source))
+            .await)
+    }
+
+    async fn search(
+        &self,
+        filter: &TrustedIssuerFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<trusted_issuer::Model>> {
+        self.basic_page(
+            |m| {
+                filter
+                    .issuer_did
+                    .as_ref()
+                    .is_none_or(|did| &m.issuer_did == did)
+                    && filter
+                        .source
+                        .as_ref()
+                        .is_none_or(|source| m.source.as_ref() == Some(source))
+            },
+            request,
+        )
+        .await
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::wallet::did;
+use crate::errors::{Errors, Outcome};
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::wallet::DidRepoTrait;
+use async_trait::async_trait;
+
+#[derive(Default)]
+pub struct DidMemoryRepo {
+    table: MemoryTable<did::Model>,
+}
+
+impl DidMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for DidMemoryRepo {
+    type Entity = did::Entity;
+    type Plan = did::Model;
+
+    fn table(&self) -> &MemoryTable<did::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(DidMemoryRepo, did::Model, did::Model);
+
+#[async_trait]
+impl DidRepoTrait for DidMemoryRepo {
+    async fn get_by_did(&self, did: &str) -> Outcome<did::Model> {
+        self.basic_filter(|m| m.did == did, "did", did).await
+    }
+
+    async fn get_default(&self) -> Outcome<did::Model> {
+        self.basic_filter(|m| m.default, "default", "true").await
+    }
+
+    async fn set_default_id(&self, id: &str) -> Outcome<did::Model> {
+        self.set_default_where(|m| m.id == id, "id", id).await
+    }
+    async fn set_default_by_did(&self, did: &str) -> Outcome<did::Model> {
+        self.set_default_where(|m| m.did == did, "did", did).await
+    }
+    async fn delete_by_did(&self, did: &str) -> Outcome<()> {
+        let model = self.get_by_did(did).await?;
+        self.basic_delete(&model.id).await
+    }
+}
+
+impl DidMemoryRepo {
+    /// Moves the default flag to the DID matching `predicate`, in a single step.
+    async fn set_default_where(
+        &self,
+        predicate: impl Fn(&did::Model) -> bool,
+        resource: &str,
+        value: &str,
+    ) -> Outcome<did::Model> {
+        let mut rows = self.table.write().await;
+        let Some(key) = rows
+            .iter()
+            .find(|(_, m)| predicate(m))
+            .map(|(key, _)| key.clone())
+        else {
+            return Err(Errors::missing_resource(
+                value,
+                format!(
+                    "Unable to find model with column '{}' with value {}",
+                    resource, value
+                ),
+                None,
+            ));
+        };
+        for (id, model) in rows.iter_mut() {
+            model.default = *id == key;
+        }
+        Ok(rows[&key].clone())
+    }
+}
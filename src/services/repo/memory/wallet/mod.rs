@@ -0,0 +1,26 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod did_repo;
+mod key_repo;
+mod pending_presentation_repo;
+mod vc_repo;
+
+pub use did_repo::DidMemoryRepo;
+pub use key_repo::KeyMemoryRepo;
+pub use pending_presentation_repo::PendingPresentationMemoryRepo;
+pub use vc_repo::VcMemoryRepo;
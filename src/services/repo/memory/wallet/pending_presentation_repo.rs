@@ -0,0 +1,93 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::wallet::pending_presentation;
+use crate::errors::{Errors, Outcome};
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable, into_row};
+use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::traits::wallet::PendingPresentationRepoTrait;
+use crate::types::wallet::ConsentStatus;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[derive(Default)]
+pub struct PendingPresentationMemoryRepo {
+    table: MemoryTable<pending_presentation::Model>,
+}
+
+impl PendingPresentationMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for PendingPresentationMemoryRepo {
+    type Entity = pending_presentation::Entity;
+    type Plan = pending_presentation::Plan;
+
+    fn table(&self) -> &MemoryTable<pending_presentation::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(
+    PendingPresentationMemoryRepo,
+    pending_presentation::Model,
+    pending_presentation::Plan
+);
+
+#[async_trait]
+impl PendingPresentationRepoTrait for PendingPresentationMemoryRepo {
+    async fn filter_by_status(
+        &self,
+        status: ConsentStatus,
+    ) -> Outcome<Vec<pending_presentation::Model>> {
+        let mut pending = self.table.select(|m| m.status == status).await;
+        pending.sort_by_key(|m| m.created_at);
+        Ok(pending)
+    }
+
+    async fn created_after(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Outcome<Vec<pending_presentation::Model>> {
+        let mut pending = self.table.select(|m| m.created_at > since).await;
+        pending.sort_by_key(|m| m.created_at);
+        Ok(pending)
+    }
+
+    async fn advance(
+        &self,
+        model: pending_presentation::Model,
+        from: ConsentStatus,
+    ) -> Outcome<pending_presentation::Model> {
+        let (key, model) = into_row::<_, pending_presentation::Model>(model.into_active())?;
+        let mut rows = self.table.write().await;
+        match rows.get_mut(&key) {
+            Some(row) if row.status == from => *row = model.clone(),
+            _ => {
+                return Err(Errors::forbidden(
+                    format!("Presentation {key} is no longer {from:?}"),
+                    None,
+                ));
+            }
+        }
+        Ok(model)
+    }
+}
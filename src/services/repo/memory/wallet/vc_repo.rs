@@ -0,0 +1,61 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::wallet::vc;
+use crate::errors::Outcome;
+use crate::impl_memory_crud;
+use crate::services::repo::memory::{BasicMemoryRepo, MemoryTable};
+use crate::services::repo::traits::wallet::VcRepoTrait;
+use crate::types::vcs::{InputDescriptor, VcType};
+use async_trait::async_trait;
+
+#[derive(Default)]
+pub struct VcMemoryRepo {
+    table: MemoryTable<vc::Model>,
+}
+
+impl VcMemoryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BasicMemoryRepo for VcMemoryRepo {
+    type Entity = vc::Entity;
+    type Plan = vc::Model;
+
+    fn table(&self) -> &MemoryTable<vc::Model> {
+        &self.table
+    }
+}
+
+impl_memory_crud!(VcMemoryRepo, vc::Model, vc::Model);
+
+#[async_trait]
+impl VcRepoTrait for VcMemoryRepo {
+    async fn filter_by_type(&self, r#type: VcType) -> Outcome<Vec<vc::Model>> {
+        Ok(self.table.select(|m| m.vc_type == r#type).await)
+    }
+
+    async fn filter_by_desc(&self, input_descriptor: &InputDescriptor) -> Outcome<Vec<vc::Model>> {
+        Ok(self
+            .table
+            .select(|m| input_descriptor.is_satisfied_by(&m.parsed_document))
+            .await)
+    }
+}
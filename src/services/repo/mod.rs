@@ -16,5 +16,11 @@
  */
 
 pub mod disk;
+mod factory;
+pub mod memory;
 pub mod postgres;
 pub mod traits;
+mod transaction;
+
+pub use factory::{Repos, build_repos};
+pub use transaction::RepoTransaction;
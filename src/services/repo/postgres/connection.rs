@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr, ExecResult,
+    QueryResult, Statement, TransactionTrait,
+};
+
+/// Handle the Sea-ORM repositories run their statements on.
+///
+/// Either the shared pool or an open transaction, so that the same repository code takes part
+/// in a [`RepoTransaction`](crate::services::repo::RepoTransaction) unchanged.
+#[derive(Clone)]
+pub enum RepoConnection {
+    Pool(DatabaseConnection),
+    Transaction(Arc<DatabaseTransaction>),
+}
+
+impl RepoConnection {
+    /// Opens a transaction, nested as a savepoint when the handle is already one.
+    pub async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            RepoConnection::Pool(db) => db.begin().await,
+            RepoConnection::Transaction(txn) => txn.begin().await,
+        }
+    }
+}
+
+impl From<DatabaseConnection> for RepoConnection {
+    fn from(db: DatabaseConnection) -> Self {
+        RepoConnection::Pool(db)
+    }
+}
+
+#[async_trait]
+impl ConnectionTrait for RepoConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        match self {
+            RepoConnection::Pool(db) => db.get_database_backend(),
+            RepoConnection::Transaction(txn) => txn.get_database_backend(),
+        }
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        match self {
+            RepoConnection::Pool(db) => db.execute(stmt).await,
+            RepoConnection::Transaction(txn) => txn.execute(stmt).await,
+        }
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        match self {
+            RepoConnection::Pool(db) => db.execute_unprepared(sql).await,
+            RepoConnection::Transaction(txn) => txn.execute_unprepared(sql).await,
+        }
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        match self {
+            RepoConnection::Pool(db) => db.query_one(stmt).await,
+            RepoConnection::Transaction(txn) => txn.query_one(stmt).await,
+        }
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        match self {
+            RepoConnection::Pool(db) => db.query_all(stmt).await,
+            RepoConnection::Transaction(txn) => txn.query_all(stmt).await,
+        }
+    }
+}
@@ -15,24 +15,31 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::data::entities::shared::audit_entry;
 use crate::errors::{Errors, Outcome};
-use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::postgres::{IntoOverwriteActive, RepoConnection};
 use crate::services::repo::traits::CrudRepoTrait;
+use crate::types::audit::AuditOperation;
+use crate::types::pagination::{Page, PageRequest};
 use async_trait::async_trait;
 use sea_orm::{
-    ActiveModelBehavior, ActiveModelTrait, DatabaseConnection, EntityTrait, IntoActiveModel,
-    PrimaryKeyTrait, QuerySelect, Select,
+    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction,
+    DbErr, EntityName, EntityTrait, IntoActiveModel, Iterable, ModelTrait, PaginatorTrait,
+    PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryOrder, QuerySelect, Select, Value,
 };
+use serde::Serialize;
+use std::str::FromStr;
 
 /// Structural Mixin for automated Sea-ORM Postgres CRUD execution.
 ///
-/// Any repository containing a [`DatabaseConnection`] can implement this trait
+/// Any repository containing a [`RepoConnection`] can implement this trait
 /// to automatically qualify for a blanket [`CrudRepoTrait`] injection, minimizing boilerplate.
 #[async_trait]
 pub trait BasicPostgresRepo: Send + Sync + 'static
 where
     <Self::Entity as EntityTrait>::Model: IntoOverwriteActive<<Self::Entity as EntityTrait>::ActiveModel>
         + IntoActiveModel<<Self::Entity as EntityTrait>::ActiveModel>
+        + Serialize
         + Send
         + Sync
         + Clone
@@ -50,8 +57,24 @@ where
         + Sync
         + 'static;
 
-    /// Exposes the inner active database reference pool.
-    fn db(&self) -> &DatabaseConnection;
+    /// Exposes the pool or transaction the repository runs on.
+    fn db(&self) -> &RepoConnection;
+
+    /// Integer column bumped on every update, enabling optimistic concurrency control.
+    ///
+    /// When set, [`basic_update`](Self::basic_update) only overwrites a row still holding the
+    /// version `model` was read with, so concurrent writers cannot silently undo each other.
+    fn version_column(&self) -> Option<<Self::Entity as EntityTrait>::Column> {
+        None
+    }
+
+    /// Whether creates, updates and deletes through this repository land in the audit log.
+    ///
+    /// Each entry is written in the transaction of the write it records. Repository specific
+    /// bulk statements record their rows through [`record_audit`] the same way.
+    fn audited(&self) -> bool {
+        false
+    }
 
     async fn basic_get_all(
         &self,
@@ -66,9 +89,56 @@ where
             .map_err(|e| Errors::db("Unable to get all models", Some(Box::new(e))))
     }
 
+    async fn basic_get_page(
+        &self,
+        request: &PageRequest,
+    ) -> Outcome<Page<<Self::Entity as EntityTrait>::Model>> {
+        self.basic_page(Self::Entity::find(), request).await
+    }
+
+    /// Runs `query` over the window described by `request`, counting every matching row.
+    async fn basic_page(
+        &self,
+        query: Select<Self::Entity>,
+        request: &PageRequest,
+    ) -> Outcome<Page<<Self::Entity as EntityTrait>::Model>> {
+        let total = query
+            .clone()
+            .count(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to count models", Some(Box::new(e))))?;
+
+        let mut query = query;
+        if let Some(sort) = &request.sort {
+            let column = <Self::Entity as EntityTrait>::Column::from_str(sort)
+                .map_err(|_| Errors::validation(format!("Unknown sort column: {}", sort), None))?;
+            query = query.order_by(column, request.order.into());
+        }
+        for key in <Self::Entity as EntityTrait>::PrimaryKey::iter() {
+            query = query.order_by(key.into_column(), request.order.into());
+        }
+
+        let items = query
+            .limit(request.limit())
+            .offset(request.offset())
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get page of models", Some(Box::new(e))))?;
+        Ok(Page::new(items, total, request))
+    }
+
     async fn basic_get_by_id(&self, id: &str) -> Outcome<<Self::Entity as EntityTrait>::Model> {
+        self.basic_get_by_id_on(self.db(), id).await
+    }
+
+    /// Reads the row keyed `id` on `db`, the pool or a transaction opened from it.
+    async fn basic_get_by_id_on<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        id: &str,
+    ) -> Outcome<<Self::Entity as EntityTrait>::Model> {
         Self::Entity::find_by_id(id.to_string())
-            .one(self.db())
+            .one(db)
             .await
             .map_err(|e| {
                 Errors::db(
@@ -79,32 +149,100 @@ where
             .ok_or_else(|| Errors::missing_resource(id, format!("Model not found: {}", id), None))
     }
 
+    /// Opens a transaction for a write and its audit entries, nested when already in one.
+    async fn basic_begin(&self) -> Outcome<DatabaseTransaction> {
+        self.db()
+            .begin()
+            .await
+            .map_err(|e| Errors::db("Unable to begin transaction", Some(Box::new(e))))
+    }
+
     async fn basic_create(
         &self,
         plan: Self::Plan,
     ) -> Outcome<<Self::Entity as EntityTrait>::Model> {
         let am = plan.into_active();
-        am.insert(self.db())
+        if !self.audited() {
+            return am
+                .insert(self.db())
+                .await
+                .map_err(|e| Errors::db("Unable to create model", Some(Box::new(e))));
+        }
+
+        let txn = self.basic_begin().await?;
+        let model = am
+            .insert(&txn)
             .await
-            .map_err(|e| Errors::db("Unable to create model", Some(Box::new(e))))
+            .map_err(|e| Errors::db("Unable to create model", Some(Box::new(e))))?;
+        record_audit(&txn, AuditOperation::Create, None, Some(&model)).await?;
+        commit_audited(txn).await?;
+        Ok(model)
     }
 
+    /// Overwrites the row of `model`, comparing and bumping its version when the entity has one.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ConflictError`] when the row was updated since `model` was read.
     async fn basic_update(
         &self,
         model: <Self::Entity as EntityTrait>::Model,
     ) -> Outcome<<Self::Entity as EntityTrait>::Model> {
-        let am = model.into_active();
-        am.update(self.db())
-            .await
-            .map_err(|e| Errors::db("Unable to update model", Some(Box::new(e))))
+        if !self.audited() {
+            return self.basic_update_on(self.db(), model).await;
+        }
+
+        let txn = self.basic_begin().await?;
+        let before = self.basic_get_by_id_on(&txn, &model_key(&model)).await?;
+        let updated = self.basic_update_on(&txn, model).await?;
+        record_audit(&txn, AuditOperation::Update, Some(&before), Some(&updated)).await?;
+        commit_audited(txn).await?;
+        Ok(updated)
+    }
+
+    /// Overwrites the row of `model` on `db`, without recording the write.
+    async fn basic_update_on<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        model: <Self::Entity as EntityTrait>::Model,
+    ) -> Outcome<<Self::Entity as EntityTrait>::Model> {
+        match self.version_column() {
+            None => model
+                .into_active()
+                .update(db)
+                .await
+                .map_err(|e| Errors::db("Unable to update model", Some(Box::new(e)))),
+            Some(column) => {
+                let version = model_version(&model, column)?;
+                let mut am = model.into_active();
+                am.set(column, (version + 1).into());
+                Self::Entity::update(am)
+                    .filter(column.eq(version))
+                    .exec(db)
+                    .await
+                    .map_err(|e| match e {
+                        DbErr::RecordNotUpdated => version_conflict(version),
+                        e => Errors::db("Unable to update model", Some(Box::new(e))),
+                    })
+            }
+        }
     }
 
     async fn basic_delete(&self, id: &str) -> Outcome<()> {
-        Self::Entity::delete_by_id(id.to_string())
-            .exec(self.db())
-            .await
-            .map_err(|e| Errors::db(format!("delete {} failed", id), Some(Box::new(e))))?;
-        Ok(())
+        if !self.audited() {
+            return delete_by_id::<Self::Entity, _>(self.db(), id).await;
+        }
+
+        let txn = self.basic_begin().await?;
+        let before = match self.basic_get_by_id_on(&txn, id).await {
+            Ok(before) => Some(before),
+            Err(Errors::MissingResourceError { .. }) => None,
+            Err(e) => return Err(e),
+        };
+        delete_by_id::<Self::Entity, _>(&txn, id).await?;
+        if let Some(before) = &before {
+            record_audit(&txn, AuditOperation::Delete, Some(before), None).await?;
+        }
+        commit_audited(txn).await
     }
     async fn basic_filter(
         &self,
@@ -137,6 +275,93 @@ where
     }
 }
 
+/// Reads the version stored in `column` of `model`.
+pub fn model_version<M: ModelTrait>(
+    model: &M,
+    column: <M::Entity as EntityTrait>::Column,
+) -> Outcome<i32> {
+    match model.get(column) {
+        Value::Int(Some(version)) => Ok(version),
+        _ => Err(Errors::db("Version column does not hold an integer", None)),
+    }
+}
+
+/// Reads the primary key of a model keyed by a single string column.
+pub fn model_key<M: ModelTrait>(model: &M) -> String {
+    <M::Entity as EntityTrait>::PrimaryKey::iter()
+        .find_map(|key| match model.get(key.into_column()) {
+            Value::String(Some(key)) => Some(*key),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Describes a write to a row for the audit log, naming it after its table and key.
+pub fn audit_plan<M: ModelTrait + Serialize>(
+    operation: AuditOperation,
+    before: Option<&M>,
+    after: Option<&M>,
+) -> audit_entry::Plan {
+    let entity = M::Entity::default();
+    let entity_id = after.or(before).map(model_key).unwrap_or_default();
+    audit_entry::Plan::new(entity.table_name(), &entity_id, operation, before, after)
+}
+
+/// Appends an entry for a write to the audit log on `db`, the transaction the write ran in.
+///
+/// # Errors
+/// Returns an [`Errors::DatabaseError`] when the entry cannot be stored, so that the caller
+/// rolls the write back rather than leaving it unrecorded.
+pub async fn record_audit<C, M>(
+    db: &C,
+    operation: AuditOperation,
+    before: Option<&M>,
+    after: Option<&M>,
+) -> Outcome<()>
+where
+    C: ConnectionTrait,
+    M: ModelTrait + Serialize,
+{
+    audit_plan(operation, before, after)
+        .into_active()
+        .insert(db)
+        .await
+        .map_err(|e| Errors::db("Unable to record audit entry", Some(Box::new(e))))?;
+    Ok(())
+}
+
+/// Commits a write together with the audit entries recorded for it.
+pub async fn commit_audited(txn: DatabaseTransaction) -> Outcome<()> {
+    txn.commit()
+        .await
+        .map_err(|e| Errors::db("Unable to commit audited write", Some(Box::new(e))))
+}
+
+/// Deletes the row of `E` keyed `id` on `db`.
+async fn delete_by_id<E, C>(db: &C, id: &str) -> Outcome<()>
+where
+    E: EntityTrait,
+    E::PrimaryKey: PrimaryKeyTrait<ValueType = String>,
+    C: ConnectionTrait,
+{
+    E::delete_by_id(id.to_string())
+        .exec(db)
+        .await
+        .map_err(|e| Errors::db(format!("delete {} failed", id), Some(Box::new(e))))?;
+    Ok(())
+}
+
+/// Error returned when a row no longer holds the version an update was based on.
+pub fn version_conflict(version: i32) -> Errors {
+    Errors::conflict(
+        format!(
+            "Model was modified concurrently, version {} is stale",
+            version
+        ),
+        None,
+    )
+}
+
 // ========================================= BLANKET IMPL ==========================================
 #[async_trait]
 impl<R> CrudRepoTrait<<R::Entity as EntityTrait>::Model, R::Plan> for R
@@ -144,6 +369,7 @@ where
     R: BasicPostgresRepo,
     <R::Entity as EntityTrait>::Model: IntoOverwriteActive<<R::Entity as EntityTrait>::ActiveModel>
         + IntoActiveModel<<R::Entity as EntityTrait>::ActiveModel>
+        + Serialize
         + Send
         + Sync
         + Clone
@@ -160,6 +386,13 @@ where
         self.basic_get_all(limit, offset).await
     }
 
+    async fn get_page(
+        &self,
+        request: &PageRequest,
+    ) -> Outcome<Page<<R::Entity as EntityTrait>::Model>> {
+        self.basic_get_page(request).await
+    }
+
     async fn get_by_id(&self, id: &str) -> Outcome<<R::Entity as EntityTrait>::Model> {
         self.basic_get_by_id(id).await
     }
@@ -15,6 +15,7 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod connection;
 mod crud_postgres_trait;
 mod to_active;
 
@@ -22,5 +23,9 @@ pub mod received;
 pub mod sent;
 pub mod shared;
 pub mod wallet;
-pub use crud_postgres_trait::BasicPostgresRepo;
+pub use connection::RepoConnection;
+pub use crud_postgres_trait::{
+    BasicPostgresRepo, audit_plan, commit_audited, model_key, model_version, record_audit,
+    version_conflict,
+};
 pub use to_active::IntoOverwriteActive;
@@ -0,0 +1,64 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+use crate::data::entities::received::access_token;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
+use crate::services::repo::traits::received::RecvAccessTokenRepoTrait;
+
+pub struct RecvAccessTokenPostgresRepo {
+    db: RepoConnection,
+}
+
+impl RecvAccessTokenPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for RecvAccessTokenPostgresRepo {
+    type Entity = access_token::Entity;
+    type Plan = access_token::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl RecvAccessTokenRepoTrait for RecvAccessTokenPostgresRepo {
+    async fn filter_by_grant(&self, grant_id: &str) -> Outcome<Vec<access_token::Model>> {
+        access_token::Entity::find()
+            .filter(access_token::Column::GrantId.eq(grant_id))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get access tokens by grant", Some(Box::new(e))))
+    }
+
+    async fn delete_by_grant(&self, grant_id: &str) -> Outcome<u64> {
+        let result = access_token::Entity::delete_many()
+            .filter(access_token::Column::GrantId.eq(grant_id))
+            .exec(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to delete access tokens", Some(Box::new(e))))?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter};
+
+use crate::data::entities::received::evidence_bundle;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{IntoOverwriteActive, RepoConnection};
+use crate::services::repo::traits::received::RecvEvidenceBundleRepoTrait;
+
+pub struct RecvEvidenceBundlePostgresRepo {
+    db: RepoConnection,
+}
+
+impl RecvEvidenceBundlePostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl RecvEvidenceBundleRepoTrait for RecvEvidenceBundlePostgresRepo {
+    async fn seal(&self, plan: evidence_bundle::Plan) -> Outcome<evidence_bundle::Model> {
+        plan.into_active()
+            .insert(&self.db)
+            .await
+            .map_err(|e| Errors::db("Unable to seal evidence bundle", Some(Box::new(e))))
+    }
+
+    async fn get_by_verification(
+        &self,
+        verification_id: &str,
+    ) -> Outcome<Option<evidence_bundle::Model>> {
+        evidence_bundle::Entity::find()
+            .filter(evidence_bundle::Column::VerificationId.eq(verification_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| Errors::db("Unable to read evidence bundle", Some(Box::new(e))))
+    }
+}
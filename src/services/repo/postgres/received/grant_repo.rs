@@ -16,21 +16,32 @@
  */
 
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, PgBinOper};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+use serde_json::Value;
 
 use crate::data::entities::received::grant;
 use crate::errors::{Errors, Outcome};
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::services::repo::memory::json_contains;
+use crate::services::repo::postgres::{
+    BasicPostgresRepo, RepoConnection, commit_audited, record_audit,
+};
 use crate::services::repo::traits::received::RecvGrantRepoTrait;
+use crate::types::audit::AuditOperation;
+use crate::types::gnap::GrantStatus;
 use crate::types::gnap::grant_request::GrantKind;
 
 pub struct RecvGrantPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl RecvGrantPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -39,9 +50,13 @@ impl BasicPostgresRepo for RecvGrantPostgresRepo {
     type Entity = grant::Entity;
     type Plan = grant::Plan;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
+
+    fn audited(&self) -> bool {
+        true
+    }
 }
 
 #[async_trait]
@@ -53,4 +68,98 @@ impl RecvGrantRepoTrait for RecvGrantPostgresRepo {
             .await
             .map_err(|e| Errors::db("Unable to get grants by kind", Some(Box::new(e))))
     }
+
+    async fn filter_by_participant(&self, nick: &str) -> Outcome<Vec<grant::Model>> {
+        grant::Entity::find()
+            .filter(grant::Column::ParticipantNick.eq(nick))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get grants by participant", Some(Box::new(e))))
+    }
+
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<grant::Model> {
+        let mut grant = self.basic_get_by_id(id).await?;
+        grant.extensions = extensions;
+        self.basic_update(grant).await
+    }
+
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<grant::Model>> {
+        // `@>` only exists in Postgres; other engines evaluate the containment in memory.
+        if self.db().get_database_backend() != DatabaseBackend::Postgres {
+            let grants = self.basic_get_all(None, None).await?;
+            return Ok(grants
+                .into_iter()
+                .filter(|m| json_contains(&m.extensions, filter))
+                .collect());
+        }
+
+        grant::Entity::find()
+            .filter(
+                Expr::col(grant::Column::Extensions)
+                    .binary(PgBinOper::Contains, Expr::val(filter.clone())),
+            )
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to filter grants", Some(Box::new(e))))
+    }
+
+    async fn expire_stale(&self, created_before: DateTime<Utc>) -> Outcome<u64> {
+        let in_flight = [GrantStatus::Pending, GrantStatus::Processing];
+        let txn = self.basic_begin().await?;
+        let stale = grant::Entity::find()
+            .filter(grant::Column::Status.is_in(in_flight.clone()))
+            .filter(grant::Column::CreatedAt.lt(created_before))
+            .all(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to expire grants", Some(Box::new(e))))?;
+        if stale.is_empty() {
+            return Ok(0);
+        }
+        let ids: Vec<_> = stale.iter().map(|m| m.id.clone()).collect();
+        grant::Entity::update_many()
+            .col_expr(grant::Column::Status, Expr::value(GrantStatus::Rejected))
+            .col_expr(grant::Column::EndedAt, Expr::value(Utc::now()))
+            .filter(grant::Column::Id.is_in(ids.clone()))
+            .filter(grant::Column::Status.is_in(in_flight))
+            .exec(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to expire grants", Some(Box::new(e))))?;
+
+        let expired = grant::Entity::find()
+            .filter(grant::Column::Id.is_in(ids))
+            .filter(grant::Column::Status.eq(GrantStatus::Rejected))
+            .all(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to read expired grants", Some(Box::new(e))))?;
+        for row in &expired {
+            let before = stale.iter().find(|m| m.id == row.id);
+            record_audit(&txn, AuditOperation::Update, before, Some(row)).await?;
+        }
+        commit_audited(txn).await?;
+        Ok(expired.len() as u64)
+    }
+
+    async fn get_archivable(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> Outcome<Vec<grant::Model>> {
+        grant::Entity::find()
+            .filter(grant::Column::Status.is_in([GrantStatus::Finalized, GrantStatus::Rejected]))
+            .filter(grant::Column::EndedAt.lt(cutoff))
+            .order_by_asc(grant::Column::EndedAt)
+            .limit(limit)
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to list archivable grants", Some(Box::new(e))))
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        let result = grant::Entity::delete_many()
+            .filter(grant::Column::Id.is_in(ids.iter().cloned()))
+            .exec(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to delete grants", Some(Box::new(e))))?;
+        Ok(result.rows_affected)
+    }
 }
@@ -16,21 +16,26 @@
  */
 
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 
 use crate::data::entities::received::interaction;
 use crate::data::entities::received::interaction::Model;
-use crate::errors::Outcome;
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{
+    BasicPostgresRepo, RepoConnection, commit_audited, record_audit,
+};
 use crate::services::repo::traits::received::RecvInteractionRepoTrait;
+use crate::types::audit::AuditOperation;
 
 pub struct RecvInteractionPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl RecvInteractionPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -39,9 +44,13 @@ impl BasicPostgresRepo for RecvInteractionPostgresRepo {
     type Entity = interaction::Entity;
     type Plan = interaction::Plan;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
+
+    fn audited(&self) -> bool {
+        true
+    }
 }
 
 #[async_trait]
@@ -51,4 +60,61 @@ impl RecvInteractionRepoTrait for RecvInteractionPostgresRepo {
 
         self.basic_filter(query, "cont_id", cont_id).await
     }
+
+    async fn rotate_continue_token(&self, cont_id: &str, presented: &str) -> Outcome<Model> {
+        let before = self.get_by_cont_id(cont_id).await?;
+        let now = Utc::now();
+        before.check_poll(now)?;
+        let token = Model::new_continue_token();
+        // The poll window checked above only holds while `last_polled_at` is unchanged, so the
+        // update requires it along with the presented token.
+        let polled = match before.last_polled_at {
+            Some(last) => interaction::Column::LastPolledAt.eq(last),
+            None => interaction::Column::LastPolledAt.is_null(),
+        };
+        let txn = self.basic_begin().await?;
+        let result = interaction::Entity::update_many()
+            .col_expr(
+                interaction::Column::ContinueToken,
+                Expr::value(token.clone()),
+            )
+            .col_expr(interaction::Column::LastPolledAt, Expr::value(now))
+            .filter(interaction::Column::ContinueId.eq(cont_id))
+            .filter(interaction::Column::ContinueToken.eq(presented))
+            .filter(polled)
+            .exec(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to rotate continue token", Some(Box::new(e))))?;
+        if result.rows_affected == 0 {
+            drop(txn);
+            self.get_by_cont_id(cont_id).await?.check_poll(now)?;
+            return Err(Errors::forbidden(
+                format!("Continue token for {cont_id} is not valid"),
+                None,
+            ));
+        }
+        let mut model = before.clone();
+        model.continue_token = token;
+        model.last_polled_at = Some(now);
+        record_audit(&txn, AuditOperation::Update, Some(&before), Some(&model)).await?;
+        commit_audited(txn).await?;
+        Ok(model)
+    }
+
+    async fn filter_by_ids(&self, ids: &[String]) -> Outcome<Vec<Model>> {
+        interaction::Entity::find()
+            .filter(interaction::Column::Id.is_in(ids.iter().cloned()))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get interactions", Some(Box::new(e))))
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        let result = interaction::Entity::delete_many()
+            .filter(interaction::Column::Id.is_in(ids.iter().cloned()))
+            .exec(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to delete interactions", Some(Box::new(e))))?;
+        Ok(result.rows_affected)
+    }
 }
@@ -15,10 +15,18 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod access_token_repo;
+mod evidence_bundle_repo;
 mod grant_repo;
 mod interaction_repo;
+mod presentation_definition_repo;
+mod verification_audit_repo;
 mod verification_repo;
 
+pub use access_token_repo::RecvAccessTokenPostgresRepo;
+pub use evidence_bundle_repo::RecvEvidenceBundlePostgresRepo;
 pub use grant_repo::RecvGrantPostgresRepo;
 pub use interaction_repo::RecvInteractionPostgresRepo;
+pub use presentation_definition_repo::RecvPresentationDefinitionPostgresRepo;
+pub use verification_audit_repo::RecvVerificationAuditPostgresRepo;
 pub use verification_repo::RecvVerificationPostgresRepo;
@@ -0,0 +1,80 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+use crate::data::entities::received::presentation_definition;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
+use crate::services::repo::traits::received::RecvPresentationDefinitionRepoTrait;
+
+pub struct RecvPresentationDefinitionPostgresRepo {
+    db: RepoConnection,
+}
+
+impl RecvPresentationDefinitionPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for RecvPresentationDefinitionPostgresRepo {
+    type Entity = presentation_definition::Entity;
+    type Plan = presentation_definition::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl RecvPresentationDefinitionRepoTrait for RecvPresentationDefinitionPostgresRepo {
+    async fn find(&self, id: &str) -> Outcome<Option<presentation_definition::Model>> {
+        presentation_definition::Entity::find_by_id(id.to_string())
+            .one(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to read presentation definition", Some(Box::new(e))))
+    }
+
+    async fn supersede(&self, profile: &str, current: &str) -> Outcome<u64> {
+        let result = presentation_definition::Entity::update_many()
+            .col_expr(
+                presentation_definition::Column::SupersededBy,
+                Expr::value(current),
+            )
+            .col_expr(
+                presentation_definition::Column::SupersededAt,
+                Expr::value(Utc::now()),
+            )
+            .filter(presentation_definition::Column::Profile.eq(profile))
+            .filter(presentation_definition::Column::Id.ne(current))
+            .filter(presentation_definition::Column::SupersededBy.is_null())
+            .exec(self.db())
+            .await
+            .map_err(|e| {
+                Errors::db(
+                    "Unable to supersede presentation definitions",
+                    Some(Box::new(e)),
+                )
+            })?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,69 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+use crate::data::entities::received::verification_audit;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
+use crate::services::repo::traits::received::RecvVerificationAuditRepoTrait;
+
+pub struct RecvVerificationAuditPostgresRepo {
+    db: RepoConnection,
+}
+
+impl RecvVerificationAuditPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for RecvVerificationAuditPostgresRepo {
+    type Entity = verification_audit::Entity;
+    type Plan = verification_audit::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl RecvVerificationAuditRepoTrait for RecvVerificationAuditPostgresRepo {
+    async fn get_by_verification(
+        &self,
+        verification_id: &str,
+    ) -> Outcome<Vec<verification_audit::Model>> {
+        verification_audit::Entity::find()
+            .filter(verification_audit::Column::VerificationId.eq(verification_id))
+            .order_by_asc(verification_audit::Column::RecordedAt)
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to read verification audit", Some(Box::new(e))))
+    }
+
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> Outcome<u64> {
+        let result = verification_audit::Entity::delete_many()
+            .filter(verification_audit::Column::RecordedAt.lt(cutoff))
+            .exec(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to purge verification audit", Some(Box::new(e))))?;
+        Ok(result.rows_affected)
+    }
+}
@@ -16,20 +16,29 @@
  */
 
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
 
 use crate::data::entities::received::verification;
-use crate::errors::Outcome;
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{
+    BasicPostgresRepo, RepoConnection, commit_audited, record_audit,
+};
 use crate::services::repo::traits::received::RecvVerificationRepoTrait;
+use crate::types::audit::AuditOperation;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::verification::{VerificationFilter, VerificationStatus};
 
 pub struct RecvVerificationPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl RecvVerificationPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -38,9 +47,17 @@ impl BasicPostgresRepo for RecvVerificationPostgresRepo {
     type Entity = verification::Entity;
     type Plan = verification::Plan;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
+
+    fn audited(&self) -> bool {
+        true
+    }
+
+    fn version_column(&self) -> Option<verification::Column> {
+        Some(verification::Column::Version)
+    }
 }
 
 #[async_trait]
@@ -50,4 +67,146 @@ impl RecvVerificationRepoTrait for RecvVerificationPostgresRepo {
 
         self.basic_filter(query, "state", state).await
     }
+
+    async fn search(
+        &self,
+        filter: &VerificationFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<verification::Model>> {
+        let mut query = verification::Entity::find();
+        if let Some(status) = &filter.status {
+            query = query.filter(verification::Column::Status.eq(status.clone()));
+        }
+        if let Some(holder) = &filter.holder {
+            query = query.filter(verification::Column::Holder.eq(holder.as_str()));
+        }
+        self.basic_page(query, request).await
+    }
+
+    async fn expire_stale(&self, now: DateTime<Utc>) -> Outcome<u64> {
+        let txn = self.basic_begin().await?;
+        let open = [VerificationStatus::Pending, VerificationStatus::Presented];
+        let stale = verification::Entity::find()
+            .filter(verification::Column::Status.is_in(open.clone()))
+            .filter(verification::Column::ExpiresAt.lt(now))
+            .all(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to expire verification sessions", Some(Box::new(e))))?;
+        if stale.is_empty() {
+            return Ok(0);
+        }
+        let ids: Vec<_> = stale.iter().map(|m| m.id.clone()).collect();
+        verification::Entity::update_many()
+            .col_expr(
+                verification::Column::Status,
+                Expr::value(VerificationStatus::Expired),
+            )
+            .col_expr(verification::Column::EndedAt, Expr::value(now))
+            .col_expr(
+                verification::Column::ResponseKey,
+                Expr::value(Option::<String>::None),
+            )
+            .col_expr(
+                verification::Column::Version,
+                Expr::col(verification::Column::Version).add(1),
+            )
+            .filter(verification::Column::Id.is_in(ids))
+            .filter(verification::Column::Status.is_in(open))
+            .exec(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to expire verification sessions", Some(Box::new(e))))?;
+        let expired = self.audit_rows(&txn, &stale).await?;
+        commit_audited(txn).await?;
+        Ok(expired)
+    }
+
+    async fn revoke_holder(&self, holder: &str) -> Outcome<u64> {
+        let txn = self.basic_begin().await?;
+        let verified = verification::Entity::find()
+            .filter(verification::Column::Status.eq(VerificationStatus::Verified))
+            .filter(verification::Column::Holder.eq(holder))
+            .all(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to revoke verification sessions", Some(Box::new(e))))?;
+        if verified.is_empty() {
+            return Ok(0);
+        }
+        let ids: Vec<_> = verified.iter().map(|m| m.id.clone()).collect();
+        verification::Entity::update_many()
+            .col_expr(
+                verification::Column::Status,
+                Expr::value(VerificationStatus::Revoked),
+            )
+            .col_expr(
+                verification::Column::Version,
+                Expr::col(verification::Column::Version).add(1),
+            )
+            .filter(verification::Column::Id.is_in(ids))
+            .filter(verification::Column::Status.eq(VerificationStatus::Verified))
+            .exec(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to revoke verification sessions", Some(Box::new(e))))?;
+        let revoked = self.audit_rows(&txn, &verified).await?;
+        commit_audited(txn).await?;
+        Ok(revoked)
+    }
+
+    async fn get_archivable(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> Outcome<Vec<verification::Model>> {
+        verification::Entity::find()
+            .filter(verification::Column::Status.is_in([
+                VerificationStatus::Verified,
+                VerificationStatus::Failed,
+                VerificationStatus::Expired,
+                VerificationStatus::Revoked,
+            ]))
+            .filter(verification::Column::EndedAt.lt(cutoff))
+            .order_by_asc(verification::Column::EndedAt)
+            .limit(limit)
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to list archivable verifications", Some(Box::new(e))))
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        let result = verification::Entity::delete_many()
+            .filter(verification::Column::Id.is_in(ids.iter().cloned()))
+            .exec(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to delete verification sessions", Some(Box::new(e))))?;
+        Ok(result.rows_affected)
+    }
+}
+
+impl RecvVerificationPostgresRepo {
+    /// Records the bulk update of the `before` rows, reading their new state on `txn`.
+    ///
+    /// Returns how many of them the update changed.
+    async fn audit_rows(
+        &self,
+        txn: &DatabaseTransaction,
+        before: &[verification::Model],
+    ) -> Outcome<u64> {
+        let ids: Vec<_> = before.iter().map(|m| m.id.clone()).collect();
+        let after = verification::Entity::find()
+            .filter(verification::Column::Id.is_in(ids))
+            .all(txn)
+            .await
+            .map_err(|e| Errors::db("Unable to read updated verifications", Some(Box::new(e))))?;
+        let mut changed = 0;
+        for row in &after {
+            let Some(old) = before.iter().find(|m| m.id == row.id) else {
+                continue;
+            };
+            if old.version == row.version {
+                continue;
+            }
+            record_audit(txn, AuditOperation::Update, Some(old), Some(row)).await?;
+            changed += 1;
+        }
+        Ok(changed)
+    }
 }
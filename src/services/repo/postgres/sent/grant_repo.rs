@@ -16,22 +16,33 @@
  */
 
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, PgBinOper};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseBackend, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+use serde_json::Value;
 
 use crate::data::entities::sent::grant;
 use crate::data::entities::sent::grant::Model;
 use crate::errors::{Errors, Outcome};
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::services::repo::memory::json_contains;
+use crate::services::repo::postgres::{
+    BasicPostgresRepo, IntoOverwriteActive, RepoConnection, commit_audited, record_audit,
+};
 use crate::services::repo::traits::sent::SentGrantRepoTrait;
+use crate::types::audit::AuditOperation;
 use crate::types::gnap::grant_request::GrantKind;
+use crate::types::gnap::{GrantStatus, RequestLifecycle};
 
 pub struct SentGrantPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl SentGrantPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -40,9 +51,13 @@ impl BasicPostgresRepo for SentGrantPostgresRepo {
     type Entity = grant::Entity;
     type Plan = grant::Plan;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
+
+    fn audited(&self) -> bool {
+        true
+    }
 }
 
 #[async_trait]
@@ -54,4 +69,127 @@ impl SentGrantRepoTrait for SentGrantPostgresRepo {
             .await
             .map_err(|e| Errors::db("Unable to get grants by kind", Some(Box::new(e))))
     }
+
+    async fn filter_by_participant(&self, participant_id: &str) -> Outcome<Vec<Model>> {
+        grant::Entity::find()
+            .filter(grant::Column::ParticipantId.eq(participant_id))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get grants by participant", Some(Box::new(e))))
+    }
+
+    async fn advance(&self, mut model: Model, next: RequestLifecycle) -> Outcome<Model> {
+        let current = model.lifecycle;
+        model.transition(next)?;
+
+        let txn = self.basic_begin().await?;
+        let before = self.basic_get_by_id_on(&txn, &model.id).await?;
+        let advanced = grant::Entity::update(model.into_active())
+            .filter(grant::Column::Lifecycle.eq(current))
+            .exec(&txn)
+            .await
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => {
+                    Errors::forbidden(format!("Grant request is no longer {current:?}"), None)
+                }
+                e => Errors::db("Unable to advance grant request", Some(Box::new(e))),
+            })?;
+        record_audit(&txn, AuditOperation::Update, Some(&before), Some(&advanced)).await?;
+        commit_audited(txn).await?;
+        Ok(advanced)
+    }
+
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<Model> {
+        let mut grant = self.basic_get_by_id(id).await?;
+        grant.extensions = extensions;
+        self.basic_update(grant).await
+    }
+
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<Model>> {
+        // `@>` only exists in Postgres; other engines evaluate the containment in memory.
+        if self.db().get_database_backend() != DatabaseBackend::Postgres {
+            let grants = self.basic_get_all(None, None).await?;
+            return Ok(grants
+                .into_iter()
+                .filter(|m| json_contains(&m.extensions, filter))
+                .collect());
+        }
+
+        grant::Entity::find()
+            .filter(
+                Expr::col(grant::Column::Extensions)
+                    .binary(PgBinOper::Contains, Expr::val(filter.clone())),
+            )
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to filter grants", Some(Box::new(e))))
+    }
+
+    async fn expire_stale(&self, created_before: DateTime<Utc>) -> Outcome<u64> {
+        let in_flight = [
+            RequestLifecycle::Created,
+            RequestLifecycle::InteractionStarted,
+            RequestLifecycle::CallbackReceived,
+            RequestLifecycle::Continued,
+        ];
+        let txn = self.basic_begin().await?;
+        let stale = grant::Entity::find()
+            .filter(grant::Column::Lifecycle.is_in(in_flight))
+            .filter(grant::Column::CreatedAt.lt(created_before))
+            .all(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to expire grant requests", Some(Box::new(e))))?;
+        if stale.is_empty() {
+            return Ok(0);
+        }
+        let ids: Vec<_> = stale.iter().map(|m| m.id.clone()).collect();
+        grant::Entity::update_many()
+            .col_expr(
+                grant::Column::Lifecycle,
+                Expr::value(RequestLifecycle::Denied),
+            )
+            .col_expr(grant::Column::Status, Expr::value(GrantStatus::Rejected))
+            .col_expr(grant::Column::EndedAt, Expr::value(Utc::now()))
+            .filter(grant::Column::Id.is_in(ids.clone()))
+            .filter(grant::Column::Lifecycle.is_in(in_flight))
+            .exec(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to expire grant requests", Some(Box::new(e))))?;
+
+        let expired = grant::Entity::find()
+            .filter(grant::Column::Id.is_in(ids))
+            .filter(grant::Column::Lifecycle.eq(RequestLifecycle::Denied))
+            .all(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to read expired grant requests", Some(Box::new(e))))?;
+        for row in &expired {
+            let before = stale.iter().find(|m| m.id == row.id);
+            record_audit(&txn, AuditOperation::Update, before, Some(row)).await?;
+        }
+        commit_audited(txn).await?;
+        Ok(expired.len() as u64)
+    }
+
+    async fn get_archivable(&self, cutoff: DateTime<Utc>, limit: u64) -> Outcome<Vec<Model>> {
+        grant::Entity::find()
+            .filter(
+                grant::Column::Lifecycle
+                    .is_in([RequestLifecycle::TokenReceived, RequestLifecycle::Denied]),
+            )
+            .filter(grant::Column::EndedAt.lt(cutoff))
+            .order_by_asc(grant::Column::EndedAt)
+            .limit(limit)
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to list archivable grants", Some(Box::new(e))))
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        let result = grant::Entity::delete_many()
+            .filter(grant::Column::Id.is_in(ids.iter().cloned()))
+            .exec(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to delete grants", Some(Box::new(e))))?;
+        Ok(result.rows_affected)
+    }
 }
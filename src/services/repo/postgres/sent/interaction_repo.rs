@@ -16,19 +16,20 @@
  */
 
 use async_trait::async_trait;
-use sea_orm::DatabaseConnection;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 
 use crate::data::entities::sent::interaction;
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
 use crate::services::repo::traits::sent::SentInteractionRepoTrait;
 
 pub struct SentInteractionPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl SentInteractionPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -37,10 +38,23 @@ impl BasicPostgresRepo for SentInteractionPostgresRepo {
     type Entity = interaction::Entity;
     type Plan = interaction::Plan;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
+
+    fn audited(&self) -> bool {
+        true
+    }
 }
 
 #[async_trait]
-impl SentInteractionRepoTrait for SentInteractionPostgresRepo {}
+impl SentInteractionRepoTrait for SentInteractionPostgresRepo {
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        let result = interaction::Entity::delete_many()
+            .filter(interaction::Column::Id.is_in(ids.iter().cloned()))
+            .exec(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to delete interactions", Some(Box::new(e))))?;
+        Ok(result.rows_affected)
+    }
+}
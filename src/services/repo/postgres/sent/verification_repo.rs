@@ -16,19 +16,18 @@
  */
 
 use async_trait::async_trait;
-use sea_orm::DatabaseConnection;
 
 use crate::data::entities::sent::verification;
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
 use crate::services::repo::traits::sent::SentVerificationRepoTrait;
 
 pub struct SentVerificationPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl SentVerificationPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -37,9 +36,13 @@ impl BasicPostgresRepo for SentVerificationPostgresRepo {
     type Entity = verification::Entity;
     type Plan = verification::Plan;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
+
+    fn audited(&self) -> bool {
+        true
+    }
 }
 
 #[async_trait]
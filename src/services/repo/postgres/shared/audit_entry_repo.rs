@@ -0,0 +1,80 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+use crate::data::entities::shared::audit_entry;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
+use crate::services::repo::traits::shared::AuditEntryRepoTrait;
+use crate::types::audit::AuditFilter;
+use crate::types::pagination::{Page, PageRequest};
+
+pub struct AuditEntryPostgresRepo {
+    db: RepoConnection,
+}
+
+impl AuditEntryPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for AuditEntryPostgresRepo {
+    type Entity = audit_entry::Entity;
+    type Plan = audit_entry::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl AuditEntryRepoTrait for AuditEntryPostgresRepo {
+    async fn search(
+        &self,
+        filter: &AuditFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<audit_entry::Model>> {
+        let mut query = audit_entry::Entity::find();
+        if let Some(entity) = &filter.entity {
+            query = query.filter(audit_entry::Column::Entity.eq(entity.as_str()));
+        }
+        if let Some(entity_id) = &filter.entity_id {
+            query = query.filter(audit_entry::Column::EntityId.eq(entity_id.as_str()));
+        }
+        if let Some(actor) = &filter.actor {
+            query = query.filter(audit_entry::Column::Actor.eq(actor.as_str()));
+        }
+        if let Some(operation) = filter.operation {
+            query = query.filter(audit_entry::Column::Operation.eq(operation));
+        }
+        self.basic_page(query, request).await
+    }
+
+    async fn history(&self, entity: &str, entity_id: &str) -> Outcome<Vec<audit_entry::Model>> {
+        audit_entry::Entity::find()
+            .filter(audit_entry::Column::Entity.eq(entity))
+            .filter(audit_entry::Column::EntityId.eq(entity_id))
+            .order_by_asc(audit_entry::Column::CreatedAt)
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to read audit history", Some(Box::new(e))))
+    }
+}
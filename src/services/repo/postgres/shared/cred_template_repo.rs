@@ -0,0 +1,87 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::EntityTrait;
+use sea_orm::sea_query::OnConflict;
+
+use crate::data::entities::shared::cred_template;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{
+    BasicPostgresRepo, IntoOverwriteActive, RepoConnection, commit_audited, record_audit,
+};
+use crate::services::repo::traits::shared::CredTemplateRepoTrait;
+use crate::types::audit::AuditOperation;
+use crate::types::vcs::VcType;
+
+pub struct CredTemplatePostgresRepo {
+    db: RepoConnection,
+}
+
+impl CredTemplatePostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for CredTemplatePostgresRepo {
+    type Entity = cred_template::Entity;
+    type Plan = cred_template::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+
+    fn audited(&self) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+impl CredTemplateRepoTrait for CredTemplatePostgresRepo {
+    async fn get_by_vc_type(&self, vc_type: &VcType) -> Outcome<cred_template::Model> {
+        self.basic_get_by_id(&vc_type.to_string()).await
+    }
+
+    async fn upsert(&self, plan: cred_template::Plan) -> Outcome<cred_template::Model> {
+        let txn = self.basic_begin().await?;
+        let before = cred_template::Entity::find_by_id(plan.vc_type.to_string())
+            .one(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to upsert credential template", Some(Box::new(e))))?;
+        let template = cred_template::Entity::insert(plan.into_active())
+            .on_conflict(
+                OnConflict::column(cred_template::Column::VcType)
+                    .update_columns([
+                        cred_template::Column::Subject,
+                        cred_template::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to upsert credential template", Some(Box::new(e))))?;
+        let operation = match before {
+            Some(_) => AuditOperation::Update,
+            None => AuditOperation::Create,
+        };
+        record_audit(&txn, operation, before.as_ref(), Some(&template)).await?;
+        commit_audited(txn).await?;
+        Ok(template)
+    }
+}
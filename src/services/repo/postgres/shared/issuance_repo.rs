@@ -17,19 +17,26 @@
 
 use crate::data::entities::shared::issuance;
 use crate::data::entities::shared::issuance::Model;
-use crate::errors::Outcome;
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::memory::json_contains;
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
 use crate::services::repo::traits::shared::IssuanceRepoTrait;
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, PgBinOper};
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, DatabaseBackend, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+use serde_json::Value;
 
 pub struct IssuancePostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl IssuancePostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -38,9 +45,17 @@ impl BasicPostgresRepo for IssuancePostgresRepo {
     type Entity = issuance::Entity;
     type Plan = issuance::Plan;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
+
+    fn audited(&self) -> bool {
+        true
+    }
+
+    fn version_column(&self) -> Option<issuance::Column> {
+        Some(issuance::Column::Version)
+    }
 }
 
 #[async_trait]
@@ -55,4 +70,74 @@ impl IssuanceRepoTrait for IssuancePostgresRepo {
 
         self.basic_filter(query, "token", token).await
     }
+    async fn get_by_notification_id(&self, notification_id: &str) -> Outcome<Model> {
+        let query =
+            issuance::Entity::find().filter(issuance::Column::NotificationId.eq(notification_id));
+
+        self.basic_filter(query, "notification_id", notification_id)
+            .await
+    }
+    async fn filter_by_subject(&self, subject_name: &str) -> Outcome<Vec<Model>> {
+        issuance::Entity::find()
+            .filter(issuance::Column::SubjectName.eq(subject_name))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get issuances by subject", Some(Box::new(e))))
+    }
+    async fn filter_by_credential(&self, credential_id: &str) -> Outcome<Vec<Model>> {
+        issuance::Entity::find()
+            .filter(issuance::Column::CredentialId.eq(credential_id))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get issuances by credential", Some(Box::new(e))))
+    }
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<Model> {
+        let mut issuance = self.basic_get_by_id(id).await?;
+        issuance.extensions = extensions;
+        self.basic_update(issuance).await
+    }
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<Model>> {
+        // `@>` only exists in Postgres; other engines evaluate the containment in memory.
+        if self.db().get_database_backend() != DatabaseBackend::Postgres {
+            let issuances = self.basic_get_all(None, None).await?;
+            return Ok(issuances
+                .into_iter()
+                .filter(|m| json_contains(&m.extensions, filter))
+                .collect());
+        }
+
+        issuance::Entity::find()
+            .filter(
+                Expr::col(issuance::Column::Extensions)
+                    .binary(PgBinOper::Contains, Expr::val(filter.clone())),
+            )
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to filter issuances", Some(Box::new(e))))
+    }
+    async fn get_archivable(&self, cutoff: DateTime<Utc>, limit: u64) -> Outcome<Vec<Model>> {
+        issuance::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(issuance::Column::NotifiedAt.lt(cutoff))
+                    .add(
+                        Condition::all()
+                            .add(issuance::Column::NotifiedAt.is_null())
+                            .add(issuance::Column::NonceExpiresAt.lt(cutoff)),
+                    ),
+            )
+            .order_by_asc(issuance::Column::NonceExpiresAt)
+            .limit(limit)
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to list archivable issuances", Some(Box::new(e))))
+    }
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64> {
+        let result = issuance::Entity::delete_many()
+            .filter(issuance::Column::Id.is_in(ids.iter().cloned()))
+            .exec(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to delete issuances", Some(Box::new(e))))?;
+        Ok(result.rows_affected)
+    }
 }
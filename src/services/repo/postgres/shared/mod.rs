@@ -15,10 +15,30 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod audit_entry_repo;
+mod cred_template_repo;
 mod issuance_repo;
 mod participant_repo;
+mod quota_counter_repo;
+mod replay_entry_repo;
 mod resource_req_repo;
+mod revocation_event_repo;
+mod rp_subscription_repo;
+mod shared_state_repo;
+mod status_entry_repo;
+mod status_list_repo;
+mod trusted_issuer_repo;
 
+pub use audit_entry_repo::AuditEntryPostgresRepo;
+pub use cred_template_repo::CredTemplatePostgresRepo;
 pub use issuance_repo::IssuancePostgresRepo;
 pub use participant_repo::ParticipantPostgresRepo;
+pub use quota_counter_repo::QuotaCounterPostgresRepo;
+pub use replay_entry_repo::ReplayEntryPostgresRepo;
 pub use resource_req_repo::ResourceReqPostgresRepo;
+pub use revocation_event_repo::RevocationEventPostgresRepo;
+pub use rp_subscription_repo::RpSubscriptionPostgresRepo;
+pub use shared_state_repo::SharedStatePostgresRepo;
+pub use status_entry_repo::StatusEntryPostgresRepo;
+pub use status_list_repo::StatusListPostgresRepo;
+pub use trusted_issuer_repo::TrustedIssuerPostgresRepo;
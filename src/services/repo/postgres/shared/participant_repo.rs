@@ -17,21 +17,28 @@
 
 use crate::data::entities::shared::participant;
 use crate::errors::{Errors, Outcome};
-use crate::services::repo::postgres::BasicPostgresRepo;
-use crate::services::repo::postgres::IntoOverwriteActive;
+use crate::services::repo::memory::json_contains;
+use crate::services::repo::postgres::{
+    BasicPostgresRepo, IntoOverwriteActive, RepoConnection, commit_audited, record_audit,
+};
 use crate::services::repo::traits::shared::ParticipantRepoTrait;
-use crate::types::participants::ParticipantType;
+use crate::types::audit::AuditOperation;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::participants::{BusinessAttributes, ParticipantStatus, ParticipantType};
+use crate::utils::create_opaque_token;
 use async_trait::async_trait;
-use sea_orm::sea_query::OnConflict;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, Func, LikeExpr, OnConflict, PgBinOper};
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, DatabaseBackend, EntityTrait, QueryFilter};
+use serde_json::Value;
 
 pub struct ParticipantPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl ParticipantPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -40,9 +47,13 @@ impl BasicPostgresRepo for ParticipantPostgresRepo {
     type Entity = participant::Entity;
     type Plan = participant::Plan;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
+
+    fn audited(&self) -> bool {
+        true
+    }
 }
 
 #[async_trait]
@@ -72,7 +83,80 @@ impl ParticipantRepoTrait for ParticipantPostgresRepo {
     async fn get_by_token(&self, token: &str) -> Outcome<participant::Model> {
         let query = participant::Entity::find().filter(participant::Column::Token.eq(token));
 
-        self.basic_filter(query, "token", token).await
+        let participant = self.basic_filter(query, "token", token).await?;
+        if participant.token_expired() {
+            return Err(Errors::unauthorized(
+                format!("Access token of {} has expired", participant.participant_id),
+                None,
+            ));
+        }
+        participant.check_standing()?;
+        Ok(participant)
+    }
+
+    async fn get_by_instance_id(&self, instance_id: &str) -> Outcome<participant::Model> {
+        let query =
+            participant::Entity::find().filter(participant::Column::InstanceId.eq(instance_id));
+
+        self.basic_filter(query, "instance_id", instance_id).await
+    }
+
+    async fn rotate_token(
+        &self,
+        token: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Outcome<participant::Model> {
+        let query = participant::Entity::find().filter(participant::Column::Token.eq(token));
+        let participant = self.basic_filter(query, "token", token).await?;
+        participant.check_standing()?;
+
+        let expires_at = participant.cap_token_expiry(expires_at);
+        let fresh = create_opaque_token();
+        let txn = self.basic_begin().await?;
+        let result = participant::Entity::update_many()
+            .col_expr(participant::Column::Token, Expr::value(fresh))
+            .col_expr(participant::Column::TokenExpiresAt, Expr::value(expires_at))
+            .col_expr(
+                participant::Column::LastInteraction,
+                Expr::value(Utc::now()),
+            )
+            .filter(participant::Column::ParticipantId.eq(&participant.participant_id))
+            .filter(participant::Column::Token.eq(token))
+            .exec(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to rotate participant token", Some(Box::new(e))))?;
+        if result.rows_affected == 0 {
+            return Err(Errors::unauthorized(
+                format!(
+                    "Access token of {} was already rotated",
+                    participant.participant_id
+                ),
+                None,
+            ));
+        }
+        let rotated = self
+            .basic_get_by_id_on(&txn, &participant.participant_id)
+            .await?;
+        record_audit(
+            &txn,
+            AuditOperation::Update,
+            Some(&participant),
+            Some(&rotated),
+        )
+        .await?;
+        commit_audited(txn).await?;
+        Ok(rotated)
+    }
+
+    async fn filter_by_status(
+        &self,
+        status: ParticipantStatus,
+    ) -> Outcome<Vec<participant::Model>> {
+        participant::Entity::find()
+            .filter(participant::Column::Status.eq(status))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get participants by status", Some(Box::new(e))))
     }
 
     async fn get_batch(&self, ids: &[String]) -> Outcome<Vec<participant::Model>> {
@@ -85,20 +169,303 @@ impl ParticipantRepoTrait for ParticipantPostgresRepo {
     }
 
     async fn force_update(&self, plan: participant::Plan) -> Outcome<participant::Model> {
+        let txn = self.basic_begin().await?;
+        let before = participant::Entity::find_by_id(plan.participant_id.clone())
+            .one(&txn)
+            .await
+            .map_err(|e| Errors::db("Error forcing creating mate", Some(Box::new(e))))?;
         let active_mate = plan.into_active();
-        participant::Entity::insert(active_mate)
+        let mate = participant::Entity::insert(active_mate)
             .on_conflict(
                 OnConflict::column(participant::Column::ParticipantId)
                     .update_columns([
                         participant::Column::BaseUrl,
                         participant::Column::LastInteraction,
                         participant::Column::Token,
+                        participant::Column::TokenExpiresAt,
                         participant::Column::ParticipantNick,
                     ])
                     .to_owned(),
             )
-            .exec_with_returning(self.db())
+            .exec_with_returning(&txn)
+            .await
+            .map_err(|e| Errors::db("Error forcing creating mate", Some(Box::new(e))))?;
+        let operation = match before {
+            Some(_) => AuditOperation::Update,
+            None => AuditOperation::Create,
+        };
+        record_audit(&txn, operation, before.as_ref(), Some(&mate)).await?;
+        commit_audited(txn).await?;
+        Ok(mate)
+    }
+
+    async fn import_batch(
+        &self,
+        plans: Vec<participant::Plan>,
+    ) -> Outcome<Vec<participant::Model>> {
+        if plans.is_empty() {
+            return Ok(Vec::new());
+        }
+        let ids: Vec<_> = plans
+            .iter()
+            .map(|plan| plan.participant_id.clone())
+            .collect();
+        let txn = self.basic_begin().await?;
+        let existing = participant::Entity::find()
+            .filter(participant::Column::ParticipantId.is_in(ids))
+            .all(&txn)
+            .await
+            .map_err(|e| Errors::db("Error importing participants", Some(Box::new(e))))?;
+        let mates =
+            participant::Entity::insert_many(plans.into_iter().map(|plan| plan.into_active()))
+                .on_conflict(
+                    OnConflict::column(participant::Column::ParticipantId)
+                        .update_columns([
+                            participant::Column::BaseUrl,
+                            participant::Column::ParticipantNick,
+                            participant::Column::ParticipantType,
+                            participant::Column::Extensions,
+                            participant::Column::LastInteraction,
+                        ])
+                        .to_owned(),
+                )
+                .exec_with_returning_many(&txn)
+                .await
+                .map_err(|e| Errors::db("Error importing participants", Some(Box::new(e))))?;
+        for mate in &mates {
+            let before = existing
+                .iter()
+                .find(|m| m.participant_id == mate.participant_id);
+            let operation = match before {
+                Some(_) => AuditOperation::Update,
+                None => AuditOperation::Create,
+            };
+            record_audit(&txn, operation, before, Some(mate)).await?;
+        }
+        commit_audited(txn).await?;
+        Ok(mates)
+    }
+
+    async fn update_business(
+        &self,
+        id: &str,
+        attrs: BusinessAttributes,
+    ) -> Outcome<participant::Model> {
+        let mut mate = self.basic_get_by_id(id).await?;
+        mate.apply_business(attrs);
+        self.basic_update(mate).await
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        request: &PageRequest,
+    ) -> Outcome<Page<participant::Model>> {
+        // `ILIKE` is Postgres only; lowering both sides matches case-insensitively everywhere.
+        let pattern = format!("%{}%", escape_like(query).to_lowercase());
+        let matches = [
+            participant::Column::ParticipantId,
+            participant::Column::ParticipantNick,
+            participant::Column::BaseUrl,
+        ]
+        .into_iter()
+        .fold(Condition::any(), |condition, column| {
+            condition.add(
+                Expr::expr(Func::lower(Expr::col(column)))
+                    .like(LikeExpr::new(pattern.as_str()).escape('\\')),
+            )
+        });
+
+        let select = participant::Entity::find().filter(matches);
+        self.basic_page(select, request).await
+    }
+
+    async fn filter_by_business(
+        &self,
+        filter: &BusinessAttributes,
+    ) -> Outcome<Vec<participant::Model>> {
+        let mut query = participant::Entity::find();
+        if let Some(name) = &filter.legal_name {
+            let pattern = format!("%{}%", escape_like(name).to_lowercase());
+            query = query.filter(
+                Expr::expr(Func::lower(Expr::col(participant::Column::LegalName)))
+                    .like(LikeExpr::new(pattern).escape('\\')),
+            );
+        }
+        if let Some(country) = &filter.country_code {
+            query = query.filter(participant::Column::CountryCode.eq(country.to_uppercase()));
+        }
+        if let Some(number) = &filter.registration_number {
+            query = query.filter(participant::Column::RegistrationNumber.eq(number));
+        }
+        if let Some(number_type) = &filter.registration_number_type {
+            query = query.filter(participant::Column::RegistrationNumberType.eq(number_type));
+        }
+
+        query
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to filter participants", Some(Box::new(e))))
+    }
+
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<participant::Model> {
+        let mut mate = self.basic_get_by_id(id).await?;
+        mate.extensions = extensions;
+        self.basic_update(mate).await
+    }
+
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<participant::Model>> {
+        // `@>` only exists in Postgres; other engines evaluate the containment in memory.
+        if self.db().get_database_backend() != DatabaseBackend::Postgres {
+            let mates = self.basic_get_all(None, None).await?;
+            return Ok(mates
+                .into_iter()
+                .filter(|m| json_contains(&m.extensions, filter))
+                .collect());
+        }
+
+        participant::Entity::find()
+            .filter(
+                Expr::col(participant::Column::Extensions)
+                    .binary(PgBinOper::Contains, Expr::val(filter.clone())),
+            )
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to filter participants", Some(Box::new(e))))
+    }
+
+    async fn get_dependents(&self, id: &str) -> Outcome<Vec<participant::Model>> {
+        let escaped = escape_like(id);
+        let (scoped, fragment) = (format!("{escaped}:%"), format!("{escaped}#%"));
+        participant::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(
+                        participant::Column::ParticipantId.like(LikeExpr::new(scoped).escape('\\')),
+                    )
+                    .add(
+                        participant::Column::ParticipantId
+                            .like(LikeExpr::new(fragment).escape('\\')),
+                    ),
+            )
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get dependent participants", Some(Box::new(e))))
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Escapes the `LIKE` wildcards in `value`, with `\` as escape character, so it matches literally.
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::Database;
+    use sea_orm_migration::{MigrationTrait, SchemaManager};
+    use serde_json::json;
+
+    use super::*;
+    use crate::data::migrations::portable;
+    use crate::data::migrations::shared::{
+        participant as migration, participant_columns, rp_subscription, string_list_json,
+    };
+
+    fn plan(id: &str, extensions: Value) -> participant::Plan {
+        participant::Plan {
+            participant_id: id.to_string(),
+            participant_nick: id.to_string(),
+            participant_type: ParticipantType::Agent,
+            base_url: format!("https://{id}.example"),
+            token: None,
+            token_expires_at: None,
+            token_key: None,
+            extra_fields: None,
+            is_me: false,
+            extensions: Some(extensions),
+            status: None,
+            expires_at: None,
+        }
+    }
+
+    async fn sqlite_repo() -> ParticipantPostgresRepo {
+        let db = Database::connect("sqlite::memory:")
+            .await
+            .expect("sqlite opens");
+        let manager = SchemaManager::new(&db);
+        let migrations: [&dyn MigrationTrait; 5] = [
+            &migration::Migration,
+            &participant_columns::Migration,
+            &portable::ResourceReq,
+            &rp_subscription::Migration,
+            &string_list_json::Migration,
+        ];
+        for step in migrations {
+            step.up(&manager)
+                .await
+                .expect("migration applies on sqlite");
+        }
+        ParticipantPostgresRepo::new(db)
+    }
+
+    #[tokio::test]
+    async fn sqlite_runs_string_list_migrations_and_filters_extensions() {
+        let repo = sqlite_repo().await;
+        repo.basic_create(plan(
+            "did:web:a",
+            json!({ "sector": "energy", "tags": ["x", "y"] }),
+        ))
+        .await
+        .expect("participant a");
+        repo.basic_create(plan("did:web:b", json!({ "sector": "health" })))
+            .await
+            .expect("participant b");
+
+        let found = repo
+            .filter_by_extensions(&json!({ "tags": ["y"] }))
+            .await
+            .expect("filter runs on sqlite");
+        let ids: Vec<_> = found.iter().map(|m| m.participant_id.as_str()).collect();
+        assert_eq!(ids, ["did:web:a"]);
+
+        let found = repo
+            .filter_by_extensions(&json!({ "sector": "health" }))
+            .await
+            .expect("filter runs on sqlite");
+        let ids: Vec<_> = found.iter().map(|m| m.participant_id.as_str()).collect();
+        assert_eq!(ids, ["did:web:b"]);
+    }
+
+    #[tokio::test]
+    async fn legal_name_filter_matches_wildcards_literally() {
+        let repo = sqlite_repo().await;
+        for (id, legal_name) in [("did:web:a", "100% Energy"), ("did:web:b", "100 Energy")] {
+            repo.basic_create(plan(id, json!({})))
+                .await
+                .expect("participant");
+            let attrs = BusinessAttributes {
+                legal_name: Some(legal_name.to_string()),
+                ..Default::default()
+            };
+            repo.update_business(id, attrs)
+                .await
+                .expect("business attributes");
+        }
+
+        let filter = BusinessAttributes {
+            legal_name: Some("0% e".to_string()),
+            ..Default::default()
+        };
+        let found = repo
+            .filter_by_business(&filter)
             .await
-            .map_err(|e| Errors::db("Error forcing creating mate", Some(Box::new(e))))
+            .expect("filter runs on sqlite");
+        let ids: Vec<_> = found.iter().map(|m| m.participant_id.as_str()).collect();
+        assert_eq!(ids, ["did:web:a"]);
     }
 }
@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+use crate::data::entities::shared::quota_counter;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, IntoOverwriteActive, RepoConnection};
+use crate::services::repo::traits::shared::QuotaCounterRepoTrait;
+
+pub struct QuotaCounterPostgresRepo {
+    db: RepoConnection,
+}
+
+impl QuotaCounterPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for QuotaCounterPostgresRepo {
+    type Entity = quota_counter::Entity;
+    type Plan = quota_counter::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl QuotaCounterRepoTrait for QuotaCounterPostgresRepo {
+    async fn increment(&self, plan: quota_counter::Plan) -> Outcome<quota_counter::Model> {
+        quota_counter::Entity::insert(plan.into_active())
+            .on_conflict(
+                OnConflict::column(quota_counter::Column::Id)
+                    .value(
+                        quota_counter::Column::Count,
+                        Expr::col((quota_counter::Entity, quota_counter::Column::Count)).add(1),
+                    )
+                    .value(quota_counter::Column::UpdatedAt, Expr::value(Utc::now()))
+                    .to_owned(),
+            )
+            .exec_with_returning(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to increment quota counter", Some(Box::new(e))))
+    }
+
+    async fn get_by_participant(
+        &self,
+        participant_id: &str,
+        since: DateTime<Utc>,
+    ) -> Outcome<Vec<quota_counter::Model>> {
+        quota_counter::Entity::find()
+            .filter(quota_counter::Column::ParticipantId.eq(participant_id))
+            .filter(quota_counter::Column::WindowStart.gte(since))
+            .order_by_desc(quota_counter::Column::WindowStart)
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get quota counters", Some(Box::new(e))))
+    }
+
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> Outcome<u64> {
+        let result = quota_counter::Entity::delete_many()
+            .filter(quota_counter::Column::WindowStart.lt(cutoff))
+            .exec(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to purge quota counters", Some(Box::new(e))))?;
+        Ok(result.rows_affected)
+    }
+}
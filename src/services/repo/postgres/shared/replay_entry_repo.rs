@@ -0,0 +1,78 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+use crate::data::entities::shared::replay_entry;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, IntoOverwriteActive, RepoConnection};
+use crate::services::repo::traits::shared::ReplayEntryRepoTrait;
+
+pub struct ReplayEntryPostgresRepo {
+    db: RepoConnection,
+}
+
+impl ReplayEntryPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for ReplayEntryPostgresRepo {
+    type Entity = replay_entry::Entity;
+    type Plan = replay_entry::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl ReplayEntryRepoTrait for ReplayEntryPostgresRepo {
+    async fn consume(&self, plan: replay_entry::Plan) -> Outcome<bool> {
+        let now = Utc::now();
+        let rows = replay_entry::Entity::insert(plan.into_active())
+            .on_conflict(
+                OnConflict::column(replay_entry::Column::Id)
+                    .update_columns([
+                        replay_entry::Column::ExpiresAt,
+                        replay_entry::Column::CreatedAt,
+                    ])
+                    .action_and_where(
+                        Expr::col((replay_entry::Entity, replay_entry::Column::ExpiresAt)).lt(now),
+                    )
+                    .to_owned(),
+            )
+            .exec_without_returning(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to record replay entry", Some(Box::new(e))))?;
+        Ok(rows > 0)
+    }
+
+    async fn purge_expired(&self, now: DateTime<Utc>) -> Outcome<u64> {
+        let result = replay_entry::Entity::delete_many()
+            .filter(replay_entry::Column::ExpiresAt.lt(now))
+            .exec(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to purge replay entries", Some(Box::new(e))))?;
+        Ok(result.rows_affected)
+    }
+}
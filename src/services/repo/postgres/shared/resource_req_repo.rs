@@ -16,19 +16,18 @@
  */
 
 use async_trait::async_trait;
-use sea_orm::DatabaseConnection;
 
 use crate::data::entities::shared::resource_req;
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
 use crate::services::repo::traits::shared::ResourceReqRepoTrait;
 
 pub struct ResourceReqPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl ResourceReqPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -37,7 +36,7 @@ impl BasicPostgresRepo for ResourceReqPostgresRepo {
     type Entity = resource_req::Entity;
     type Plan = resource_req::Model;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
 }
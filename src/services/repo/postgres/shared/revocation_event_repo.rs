@@ -0,0 +1,57 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+use crate::data::entities::shared::revocation_event;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
+use crate::services::repo::traits::shared::RevocationEventRepoTrait;
+
+pub struct RevocationEventPostgresRepo {
+    db: RepoConnection,
+}
+
+impl RevocationEventPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for RevocationEventPostgresRepo {
+    type Entity = revocation_event::Entity;
+    type Plan = revocation_event::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl RevocationEventRepoTrait for RevocationEventPostgresRepo {
+    async fn since(&self, cursor: i64, limit: u64) -> Outcome<Vec<revocation_event::Model>> {
+        revocation_event::Entity::find()
+            .filter(revocation_event::Column::Seq.gt(cursor))
+            .order_by_asc(revocation_event::Column::Seq)
+            .limit(limit)
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to read revocation events", Some(Box::new(e))))
+    }
+}
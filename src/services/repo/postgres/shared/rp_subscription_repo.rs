@@ -0,0 +1,83 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+use crate::data::entities::shared::rp_subscription;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
+use crate::services::repo::traits::shared::RpSubscriptionRepoTrait;
+use crate::types::vcs::VcType;
+
+pub struct RpSubscriptionPostgresRepo {
+    db: RepoConnection,
+}
+
+impl RpSubscriptionPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for RpSubscriptionPostgresRepo {
+    type Entity = rp_subscription::Entity;
+    type Plan = rp_subscription::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl RpSubscriptionRepoTrait for RpSubscriptionPostgresRepo {
+    async fn get_matching(
+        &self,
+        holder: Option<&str>,
+        vc_types: &[VcType],
+        purpose: Option<&str>,
+    ) -> Outcome<Vec<rp_subscription::Model>> {
+        let subs = rp_subscription::Entity::find()
+            .filter(rp_subscription::Column::Active.eq(true))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get rp subscriptions", Some(Box::new(e))))?;
+
+        Ok(subs
+            .into_iter()
+            .filter(|sub| sub.matches(holder, vc_types, purpose))
+            .collect())
+    }
+
+    async fn set_active(&self, id: &str, active: bool) -> Outcome<rp_subscription::Model> {
+        let mut sub = self.basic_get_by_id(id).await?;
+        sub.active = active;
+        self.basic_update(sub).await
+    }
+
+    async fn filter_by_participant(
+        &self,
+        participant_id: &str,
+    ) -> Outcome<Vec<rp_subscription::Model>> {
+        rp_subscription::Entity::find()
+            .filter(rp_subscription::Column::ParticipantId.eq(participant_id))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get rp subscriptions", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,89 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::EntityTrait;
+use sea_orm::sea_query::{Expr, OnConflict, SimpleExpr};
+
+use crate::data::entities::shared::shared_state;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, IntoOverwriteActive, RepoConnection};
+use crate::services::repo::traits::shared::SharedStateRepoTrait;
+
+pub struct SharedStatePostgresRepo {
+    db: RepoConnection,
+}
+
+impl SharedStatePostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for SharedStatePostgresRepo {
+    type Entity = shared_state::Entity;
+    type Plan = shared_state::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl SharedStateRepoTrait for SharedStatePostgresRepo {
+    async fn find(&self, key: &str) -> Outcome<Option<shared_state::Model>> {
+        shared_state::Entity::find_by_id(key)
+            .one(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to read shared state", Some(Box::new(e))))
+    }
+
+    async fn publish(&self, plan: shared_state::Plan) -> Outcome<bool> {
+        let epoch = plan.epoch;
+        let column = Expr::col((shared_state::Entity, shared_state::Column::Epoch));
+        self.upsert_where(plan, column.lte(epoch)).await
+    }
+
+    async fn claim(&self, plan: shared_state::Plan) -> Outcome<bool> {
+        let epoch = plan.epoch;
+        let column = Expr::col((shared_state::Entity, shared_state::Column::Epoch));
+        self.upsert_where(plan, column.lt(epoch)).await
+    }
+}
+
+impl SharedStatePostgresRepo {
+    /// Inserts the entry, or overwrites the stored one when it satisfies `condition`.
+    async fn upsert_where(&self, plan: shared_state::Plan, condition: SimpleExpr) -> Outcome<bool> {
+        let rows = shared_state::Entity::insert(plan.into_active())
+            .on_conflict(
+                OnConflict::column(shared_state::Column::Key)
+                    .update_columns([
+                        shared_state::Column::Value,
+                        shared_state::Column::Epoch,
+                        shared_state::Column::InstanceId,
+                        shared_state::Column::UpdatedAt,
+                    ])
+                    .action_and_where(condition)
+                    .to_owned(),
+            )
+            .exec_without_returning(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to publish shared state", Some(Box::new(e))))?;
+        Ok(rows > 0)
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+use crate::data::entities::shared::status_entry;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
+use crate::services::repo::traits::shared::StatusEntryRepoTrait;
+use crate::types::issuance::StatusPurpose;
+
+pub struct StatusEntryPostgresRepo {
+    db: RepoConnection,
+}
+
+impl StatusEntryPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for StatusEntryPostgresRepo {
+    type Entity = status_entry::Entity;
+    type Plan = status_entry::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl StatusEntryRepoTrait for StatusEntryPostgresRepo {
+    async fn get_by_credential(
+        &self,
+        credential_id: &str,
+        purpose: StatusPurpose,
+    ) -> Outcome<status_entry::Model> {
+        let query = status_entry::Entity::find()
+            .filter(status_entry::Column::CredentialId.eq(credential_id))
+            .filter(status_entry::Column::Purpose.eq(purpose));
+        self.basic_filter(query, "credential_id", credential_id)
+            .await
+    }
+
+    async fn get_all_by_credential(
+        &self,
+        credential_id: &str,
+    ) -> Outcome<Vec<status_entry::Model>> {
+        status_entry::Entity::find()
+            .filter(status_entry::Column::CredentialId.eq(credential_id))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get status entries", Some(Box::new(e))))
+    }
+}
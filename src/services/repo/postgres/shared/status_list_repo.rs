@@ -0,0 +1,124 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+use crate::data::entities::shared::status_list;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, IntoOverwriteActive, RepoConnection};
+use crate::services::repo::traits::shared::StatusListRepoTrait;
+use crate::types::issuance::{DEFAULT_STATUS_LIST_SIZE, StatusPurpose};
+
+pub struct StatusListPostgresRepo {
+    db: RepoConnection,
+}
+
+impl StatusListPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for StatusListPostgresRepo {
+    type Entity = status_list::Entity;
+    type Plan = status_list::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl StatusListRepoTrait for StatusListPostgresRepo {
+    async fn allocate(&self, purpose: StatusPurpose) -> Outcome<(status_list::Model, i32)> {
+        let txn = self
+            .db()
+            .begin()
+            .await
+            .map_err(|e| Errors::db("Unable to start transaction", Some(Box::new(e))))?;
+
+        let open = status_list::Entity::find()
+            .filter(status_list::Column::Purpose.eq(purpose))
+            .filter(
+                Expr::col(status_list::Column::NextIndex).lt(Expr::col(status_list::Column::Size)),
+            )
+            .order_by_desc(status_list::Column::CreatedAt)
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to get open status list", Some(Box::new(e))))?;
+
+        let mut list = match open {
+            Some(list) => list,
+            None => status_list::Plan {
+                purpose,
+                size: DEFAULT_STATUS_LIST_SIZE,
+            }
+            .into_active()
+            .insert(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to create status list", Some(Box::new(e))))?,
+        };
+
+        let index = list.claim_random_index()?;
+        let list = list
+            .into_active()
+            .update(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to update status list", Some(Box::new(e))))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| Errors::db("Unable to commit status allocation", Some(Box::new(e))))?;
+        Ok((list, index))
+    }
+
+    async fn set_bit(&self, id: &str, index: i32, value: bool) -> Outcome<status_list::Model> {
+        let txn = self
+            .db()
+            .begin()
+            .await
+            .map_err(|e| Errors::db("Unable to start transaction", Some(Box::new(e))))?;
+
+        let mut list = status_list::Entity::find_by_id(id.to_string())
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to get status list", Some(Box::new(e))))?
+            .ok_or_else(|| {
+                Errors::missing_resource(id, format!("Status list not found: {}", id), None)
+            })?;
+
+        let mut bits = list.bitstring();
+        bits.set(index, value)?;
+        list.bits = bits.into_bytes();
+
+        let list = list
+            .into_active()
+            .update(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to update status list", Some(Box::new(e))))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| Errors::db("Unable to commit status change", Some(Box::new(e))))?;
+        Ok(list)
+    }
+}
@@ -0,0 +1,84 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+use crate::data::entities::shared::trusted_issuer;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
+use crate::services::repo::traits::shared::TrustedIssuerRepoTrait;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::verification::TrustedIssuerFilter;
+
+pub struct TrustedIssuerPostgresRepo {
+    db: RepoConnection,
+}
+
+impl TrustedIssuerPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for TrustedIssuerPostgresRepo {
+    type Entity = trusted_issuer::Entity;
+    type Plan = trusted_issuer::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+
+    fn audited(&self) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+impl TrustedIssuerRepoTrait for TrustedIssuerPostgresRepo {
+    async fn get_by_issuer(&self, issuer_did: &str) -> Outcome<Vec<trusted_issuer::Model>> {
+        trusted_issuer::Entity::find()
+            .filter(trusted_issuer::Column::IssuerDid.eq(issuer_did))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get trusted issuers", Some(Box::new(e))))
+    }
+
+    async fn get_by_source(&self, source: &str) -> Outcome<Vec<trusted_issuer::Model>> {
+        trusted_issuer::Entity::find()
+            .filter(trusted_issuer::Column::Source.eq(source))
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to get synced trusted issuers", Some(Box::new(e))))
+    }
+
+    async fn search(
+        &self,
+        filter: &TrustedIssuerFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<trusted_issuer::Model>> {
+        let mut query = trusted_issuer::Entity::find();
+        if let Some(issuer_did) = &filter.issuer_did {
+            query = query.filter(trusted_issuer::Column::IssuerDid.eq(issuer_did.as_str()));
+        }
+        if let Some(source) = &filter.source {
+            query = query.filter(trusted_issuer::Column::Source.eq(source.as_str()));
+        }
+        self.basic_page(query, request).await
+    }
+}
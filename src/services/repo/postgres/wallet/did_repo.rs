@@ -16,20 +16,33 @@
  */
 
 use crate::data::entities::wallet::did;
-use crate::errors::Outcome;
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
 use crate::services::repo::traits::CrudRepoTrait;
 use crate::services::repo::traits::wallet::DidRepoTrait;
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 
 pub struct DidPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl DidPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+
+    /// Drops the default flag of the current default DID, if there is one.
+    async fn clear_default(&self) -> Outcome<()> {
+        match self.get_default().await {
+            Ok(mut previous) => {
+                previous.default = false;
+                self.basic_update(previous).await?;
+                Ok(())
+            }
+            Err(Errors::MissingResourceError { .. }) => Ok(()),
+            Err(e) => Err(e),
+        }
     }
 }
 
@@ -38,7 +51,7 @@ impl BasicPostgresRepo for DidPostgresRepo {
     type Entity = did::Entity;
     type Plan = did::Model;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
 }
@@ -56,17 +69,13 @@ impl DidRepoTrait for DidPostgresRepo {
     }
 
     async fn set_default_id(&self, id: &str) -> Outcome<did::Model> {
-        let mut def_model = self.get_default().await?;
-        def_model.default = false;
-        self.basic_update(def_model).await?;
+        self.clear_default().await?;
         let mut def_model = self.basic_get_by_id(id).await?;
         def_model.default = true;
         self.update(def_model).await
     }
     async fn set_default_by_did(&self, did: &str) -> Outcome<did::Model> {
-        let mut def_model = self.get_default().await?;
-        def_model.default = false;
-        self.basic_update(def_model).await?;
+        self.clear_default().await?;
         let mut def_model = self.get_by_did(did).await?;
         def_model.default = true;
         self.update(def_model).await
@@ -16,18 +16,17 @@
  */
 
 use crate::data::entities::wallet::key;
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
 use crate::services::repo::traits::wallet::KeyRepoTrait;
 use async_trait::async_trait;
-use sea_orm::DatabaseConnection;
 
 pub struct KeyPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl KeyPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -36,7 +35,7 @@ impl BasicPostgresRepo for KeyPostgresRepo {
     type Entity = key::Entity;
     type Plan = key::Model;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
 }
@@ -16,8 +16,10 @@
  */
 mod did_repo;
 mod key_repo;
+mod pending_presentation_repo;
 mod vc_repo;
 
 pub use did_repo::DidPostgresRepo;
 pub use key_repo::KeyPostgresRepo;
+pub use pending_presentation_repo::PendingPresentationPostgresRepo;
 pub use vc_repo::VcPostgresRepo;
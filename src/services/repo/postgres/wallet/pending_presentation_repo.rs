@@ -0,0 +1,90 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::wallet::pending_presentation;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, IntoOverwriteActive, RepoConnection};
+use crate::services::repo::traits::wallet::PendingPresentationRepoTrait;
+use crate::types::wallet::ConsentStatus;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ColumnTrait, DbErr, EntityTrait, QueryFilter, QueryOrder};
+
+pub struct PendingPresentationPostgresRepo {
+    db: RepoConnection,
+}
+
+impl PendingPresentationPostgresRepo {
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
+    }
+}
+
+#[async_trait]
+impl BasicPostgresRepo for PendingPresentationPostgresRepo {
+    type Entity = pending_presentation::Entity;
+    type Plan = pending_presentation::Plan;
+
+    fn db(&self) -> &RepoConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl PendingPresentationRepoTrait for PendingPresentationPostgresRepo {
+    async fn filter_by_status(
+        &self,
+        status: ConsentStatus,
+    ) -> Outcome<Vec<pending_presentation::Model>> {
+        pending_presentation::Entity::find()
+            .filter(pending_presentation::Column::Status.eq(status))
+            .order_by_asc(pending_presentation::Column::CreatedAt)
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to list pending presentations", Some(Box::new(e))))
+    }
+
+    async fn created_after(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Outcome<Vec<pending_presentation::Model>> {
+        pending_presentation::Entity::find()
+            .filter(pending_presentation::Column::CreatedAt.gt(since))
+            .order_by_asc(pending_presentation::Column::CreatedAt)
+            .all(self.db())
+            .await
+            .map_err(|e| Errors::db("Unable to list pending presentations", Some(Box::new(e))))
+    }
+
+    async fn advance(
+        &self,
+        model: pending_presentation::Model,
+        from: ConsentStatus,
+    ) -> Outcome<pending_presentation::Model> {
+        let id = model.id.clone();
+        pending_presentation::Entity::update(model.into_active())
+            .filter(pending_presentation::Column::Status.eq(from.clone()))
+            .exec(self.db())
+            .await
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => {
+                    Errors::forbidden(format!("Presentation {id} is no longer {from:?}"), None)
+                }
+                e => Errors::db("Unable to update pending presentation", Some(Box::new(e))),
+            })
+    }
+}
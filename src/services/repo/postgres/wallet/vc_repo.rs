@@ -16,21 +16,22 @@
  */
 
 use crate::data::entities::wallet::vc;
-use crate::errors::{Errors, Outcome};
-use crate::services::repo::postgres::BasicPostgresRepo;
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::services::repo::postgres::{BasicPostgresRepo, RepoConnection};
 use crate::services::repo::traits::wallet::VcRepoTrait;
 use crate::types::vcs::{InputDescriptor, VcType};
+use crate::types::verification::input_descriptor::{PathStep, path_steps};
 use async_trait::async_trait;
 use sea_orm::prelude::Expr;
-use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter};
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, DatabaseBackend, EntityTrait, QueryFilter};
 
 pub struct VcPostgresRepo {
-    db: DatabaseConnection,
+    db: RepoConnection,
 }
 
 impl VcPostgresRepo {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: impl Into<RepoConnection>) -> Self {
+        Self { db: db.into() }
     }
 }
 
@@ -39,7 +40,7 @@ impl BasicPostgresRepo for VcPostgresRepo {
     type Entity = vc::Entity;
     type Plan = vc::Model;
 
-    fn db(&self) -> &DatabaseConnection {
+    fn db(&self) -> &RepoConnection {
         &self.db
     }
 }
@@ -54,50 +55,78 @@ impl VcRepoTrait for VcPostgresRepo {
             .map_err(|e| Errors::db("Unable to get participant by type", Some(Box::new(e))))
     }
     async fn filter_by_desc(&self, input_descriptor: &InputDescriptor) -> Outcome<Vec<vc::Model>> {
+        // Los operadores JSONB y `~` sólo existen en Postgres; en el resto de motores
+        // (SQLite) evaluamos el descriptor en memoria sobre el documento ya parseado.
+        if self.db().get_database_backend() != DatabaseBackend::Postgres {
+            let models = self.basic_get_all(None, None).await?;
+            return Ok(models
+                .into_iter()
+                .filter(|model| input_descriptor.is_satisfied_by(&model.parsed_document))
+                .collect());
+        }
+
         let mut condition = Condition::all();
 
         for field in &input_descriptor.constraints.fields {
+            if field.optional == Some(true) {
+                continue;
+            }
             if let Some(json_path) = field.path.first() {
-                let pattern = &field.filter.pattern;
-
                 // Traducimos el JSONPath a la sintaxis que entiende Postgres para JSONB.
                 // `->>` extrae como texto; `->` navega manteniendo el tipo JSONB.
                 // Cuando el campo destino es un array JSON (ej. `type`), `->>` devuelve
                 // la representación textual del array y el operador `~` de Postgres
                 // puede buscar el patrón dentro de esa cadena.
-                let pg_json_accessor = match json_path.as_str() {
-                    "$.vc.type" => r#""parsed_document" -> 'vc' ->> 'type'"#.to_string(),
-                    "$.type" => r#""parsed_document" ->> 'type'"#.to_string(),
-                    // Fallback genérico para otros campos (ej. $.credentialSubject.id)
-                    _ => {
-                        let segments: Vec<&str> =
-                            json_path.trim_start_matches("$.").split('.').collect();
-                        match segments.as_slice() {
-                            [] => continue,
-                            [single] => {
-                                format!(r#""parsed_document" ->> '{}'"#, single)
-                            }
-                            [head @ .., last] => {
-                                let nav = head
-                                    .iter()
-                                    .map(|s| format!("-> '{}'", s))
-                                    .collect::<Vec<_>>()
-                                    .join(" ");
-                                format!(r#""parsed_document" {} ->> '{}'"#, nav, last)
-                            }
-                        }
-                    }
+                // `path_steps` sólo admite nombres sin comillas ni espacios, así que los
+                // pasos pueden escribirse en la consulta tal cual.
+                let steps = path_steps(json_path).ok_or_else(|| {
+                    Errors::format(
+                        BadFormat::Received,
+                        format!("Unsupported JSONPath '{json_path}' in input descriptor"),
+                        None,
+                    )
+                })?;
+                let Some((last, head)) = steps.split_last() else {
+                    continue;
+                };
+                let step = |step: &PathStep| match step {
+                    PathStep::Key(key) => format!("'{key}'"),
+                    PathStep::Index(index) => index.to_string(),
+                };
+                let nav: String = head.iter().map(|s| format!(" -> {}", step(s))).collect();
+                let pg_json_accessor = format!(r#""parsed_document"{nav} ->> {}"#, step(last));
+
+                let Some(filter) = &field.filter else {
+                    condition =
+                        condition.add(Expr::cust(format!("{} IS NOT NULL", pg_json_accessor)));
+                    continue;
                 };
 
                 // Usamos el operador `~` de Postgres para evaluar el patrón como
                 // expresión regular, que es el comportamiento estándar del campo
                 // `pattern` en la especificación DIF Presentation Exchange.
-                let sql_expr = format!("{} ~ $1", pg_json_accessor);
-
-                condition = condition.add(Expr::cust_with_values(
-                    sql_expr,
-                    [sea_orm::Value::from(pattern.as_str())],
-                ));
+                if let Some(pattern) = &filter.pattern {
+                    condition = condition.add(Expr::cust_with_values(
+                        format!("{} ~ $1", pg_json_accessor),
+                        [sea_orm::Value::from(pattern.as_str())],
+                    ));
+                }
+                if let Some(expected) = &filter.r#const {
+                    condition = condition.add(Expr::cust_with_values(
+                        format!("{} = $1", pg_json_accessor),
+                        [sea_orm::Value::from(filter_text(expected))],
+                    ));
+                }
+                if let Some(allowed) = &filter.r#enum {
+                    let mut any = Condition::any();
+                    for value in allowed {
+                        any = any.add(Expr::cust_with_values(
+                            format!("{} = $1", pg_json_accessor),
+                            [sea_orm::Value::from(filter_text(value))],
+                        ));
+                    }
+                    condition = condition.add(any);
+                }
             }
         }
 
@@ -113,3 +142,11 @@ impl VcRepoTrait for VcPostgresRepo {
             })
     }
 }
+
+/// Renders a filter value the way `->>` renders the stored JSON value as text.
+fn filter_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -16,6 +16,7 @@
  */
 
 use crate::errors::Outcome;
+use crate::types::pagination::{Page, PageRequest};
 use async_trait::async_trait;
 
 /// Unified Data Access Object (DAO) core contract.
@@ -32,6 +33,11 @@ where
     /// Default thresholds are determined by the underlying engine.
     async fn get_all(&self, limit: Option<u64>, offset: Option<u64>) -> Outcome<Vec<M>>;
 
+    /// Retrieves one sorted page of domain models along with the total row count.
+    ///
+    /// Fails with a validation error when the requested sort column does not exist.
+    async fn get_page(&self, request: &PageRequest) -> Outcome<Page<M>>;
+
     /// Fetches a unique domain identity. Returns a missing resource error if not found.
     async fn get_by_id(&self, id: &str) -> Outcome<M>;
 
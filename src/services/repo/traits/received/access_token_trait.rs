@@ -0,0 +1,34 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::access_token::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use async_trait::async_trait;
+
+/// Data Repository Contract for the labeled access tokens of received grants.
+///
+/// Inherits foundational CRUD layers from [`CrudRepoTrait`], keyed by the token value. Only
+/// the tokens of multiple token requests are kept here, one row per label.
+#[async_trait]
+pub trait RecvAccessTokenRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Lists the tokens issued for grant `grant_id`.
+    async fn filter_by_grant(&self, grant_id: &str) -> Outcome<Vec<Model>>;
+
+    /// Deletes the tokens issued for grant `grant_id`, returning how many were removed.
+    async fn delete_by_grant(&self, grant_id: &str) -> Outcome<u64>;
+}
@@ -0,0 +1,36 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::evidence_bundle::{Model, Plan};
+use crate::errors::Outcome;
+use async_trait::async_trait;
+
+/// Data Repository Contract for sealed verification evidence.
+///
+/// Deliberately not a [`CrudRepoTrait`](crate::services::repo::traits::CrudRepoTrait): bundles
+/// are written once and can be neither updated nor deleted.
+#[async_trait]
+pub trait RecvEvidenceBundleRepoTrait: Send + Sync + 'static {
+    /// Stores a freshly signed bundle.
+    ///
+    /// # Errors
+    /// Fails when a bundle was already sealed for the same verification.
+    async fn seal(&self, plan: Plan) -> Outcome<Model>;
+
+    /// Returns the bundle sealed for the verification `verification_id`, if any.
+    async fn get_by_verification(&self, verification_id: &str) -> Outcome<Option<Model>>;
+}
@@ -20,6 +20,8 @@ use crate::errors::Outcome;
 use crate::services::repo::traits::CrudRepoTrait;
 use crate::types::gnap::grant_request::GrantKind;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
 
 /// Data Repository Contract for Inbound GNAP Grant Requests (*Received Grants*).
 ///
@@ -29,4 +31,25 @@ use async_trait::async_trait;
 pub trait RecvGrantRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
     /// Filters incoming grants by their specific operational request nature ([`GrantKind`]).
     async fn filter_by_type(&self, kind: GrantKind) -> Outcome<Vec<Model>>;
+
+    /// Lists every grant requested by the participant known as `nick`.
+    async fn filter_by_participant(&self, nick: &str) -> Outcome<Vec<Model>>;
+
+    /// Replaces the integrator-defined `extensions` object of a grant.
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<Model>;
+
+    /// Lists grants whose `extensions` contain every key/value pair of `filter` (JSONB `@>`).
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<Model>>;
+
+    /// Rejects every `Pending` or `Processing` grant created before `created_before`, so that
+    /// abandoned requests age out like finished ones.
+    ///
+    /// Returns the number of grants closed.
+    async fn expire_stale(&self, created_before: DateTime<Utc>) -> Outcome<u64>;
+
+    /// Lists up to `limit` finalized or rejected grants that ended before `cutoff`, oldest first.
+    async fn get_archivable(&self, cutoff: DateTime<Utc>, limit: u64) -> Outcome<Vec<Model>>;
+
+    /// Deletes the grants with the given ids, returning how many were removed.
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64>;
 }
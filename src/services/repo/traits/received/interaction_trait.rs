@@ -32,4 +32,19 @@ pub trait RecvInteractionRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync {
     /// Executed when a client returns to the continuation endpoint to claim tokens
     /// after the out-of-band user interaction has finalized successfully.
     async fn get_by_cont_id(&self, cont_id: &str) -> Outcome<Model>;
+
+    /// Swaps the continuation access token of `cont_id` for a fresh one (RFC 9635 §5).
+    ///
+    /// Only succeeds while `presented` is still the current token, so every token is
+    /// honoured once: a replayed or superseded token is refused as forbidden, and a client
+    /// polling before `continue_wait` elapsed is rate limited. The returned
+    /// record carries the rotated token to hand back in the continuation response. The call's
+    /// `httpsig` proof is checked beforehand with [`Model::check_proof`].
+    async fn rotate_continue_token(&self, cont_id: &str, presented: &str) -> Outcome<Model>;
+
+    /// Returns the interactions with the given ids, skipping the unknown ones.
+    async fn filter_by_ids(&self, ids: &[String]) -> Outcome<Vec<Model>>;
+
+    /// Deletes the interactions with the given ids, returning how many were removed.
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64>;
 }
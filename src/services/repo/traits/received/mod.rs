@@ -14,10 +14,18 @@
  * You should have received a copy of the GNU General Public License
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
+mod access_token_trait;
+mod evidence_bundle_trait;
 mod grant_trait;
 mod interaction_trait;
+mod presentation_definition_trait;
+mod verification_audit_trait;
 mod verification_trait;
 
+pub use access_token_trait::RecvAccessTokenRepoTrait;
+pub use evidence_bundle_trait::RecvEvidenceBundleRepoTrait;
 pub use grant_trait::RecvGrantRepoTrait;
 pub use interaction_trait::RecvInteractionRepoTrait;
+pub use presentation_definition_trait::RecvPresentationDefinitionRepoTrait;
+pub use verification_audit_trait::RecvVerificationAuditRepoTrait;
 pub use verification_trait::RecvVerificationRepoTrait;
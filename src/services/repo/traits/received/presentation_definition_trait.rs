@@ -0,0 +1,37 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::presentation_definition::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use async_trait::async_trait;
+
+/// Data Repository Contract for the presentation definitions served by reference.
+///
+/// Rows are immutable once published, except for their supersession marker.
+#[async_trait]
+pub trait RecvPresentationDefinitionRepoTrait:
+    CrudRepoTrait<Model, Plan> + Send + Sync + 'static
+{
+    /// Returns the definition published under `id`, if any.
+    async fn find(&self, id: &str) -> Outcome<Option<Model>>;
+
+    /// Marks every live definition of `profile` other than `current` as superseded by it.
+    ///
+    /// Returns the number of definitions superseded.
+    async fn supersede(&self, profile: &str, current: &str) -> Outcome<u64>;
+}
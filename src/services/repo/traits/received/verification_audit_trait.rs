@@ -0,0 +1,36 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::received::verification_audit::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Data Repository Contract for the evidence recorded on received wallet responses.
+///
+/// Entries are append-only: one per response handled, whatever its outcome.
+#[async_trait]
+pub trait RecvVerificationAuditRepoTrait:
+    CrudRepoTrait<Model, Plan> + Send + Sync + 'static
+{
+    /// Returns every entry recorded for the verification `verification_id`, oldest first.
+    async fn get_by_verification(&self, verification_id: &str) -> Outcome<Vec<Model>>;
+
+    /// Deletes the entries recorded before `cutoff`, returning how many were dropped.
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> Outcome<u64>;
+}
@@ -18,7 +18,10 @@
 use crate::data::entities::received::verification::{Model, Plan};
 use crate::errors::Outcome;
 use crate::services::repo::traits::CrudRepoTrait;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::verification::VerificationFilter;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 /// Data Repository Contract for Received OpenID4VP Presentation Requests.
 ///
@@ -31,4 +34,28 @@ pub trait RecvVerificationRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync +
     /// Essential for securely mapping incoming token/presentation callback handshakes
     /// back to the initial authorization transactional context.
     async fn get_by_state(&self, state: &str) -> Outcome<Model>;
+
+    /// Lists one page of the sessions matching `filter`.
+    async fn search(
+        &self,
+        filter: &VerificationFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<Model>>;
+
+    /// Marks every `Pending` or `Presented` session whose `expires_at` is before `now` as `Expired`.
+    ///
+    /// Returns the number of sessions closed.
+    async fn expire_stale(&self, now: DateTime<Utc>) -> Outcome<u64>;
+
+    /// Marks every `Verified` session presented by `holder` as `Revoked`.
+    ///
+    /// Returns the number of sessions invalidated.
+    async fn revoke_holder(&self, holder: &str) -> Outcome<u64>;
+
+    /// Lists up to `limit` finished sessions (verified, failed, expired or revoked) that
+    /// ended before `cutoff`, oldest first.
+    async fn get_archivable(&self, cutoff: DateTime<Utc>, limit: u64) -> Outcome<Vec<Model>>;
+
+    /// Deletes the sessions with the given ids, returning how many were removed.
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64>;
 }
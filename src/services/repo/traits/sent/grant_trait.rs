@@ -18,8 +18,11 @@
 use crate::data::entities::sent::grant::{Model, Plan};
 use crate::errors::Outcome;
 use crate::services::repo::traits::CrudRepoTrait;
+use crate::types::gnap::RequestLifecycle;
 use crate::types::gnap::grant_request::GrantKind;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
 
 /// Data Repository Contract for Outbound Grant Requests (*Sent Grants*).
 ///
@@ -33,4 +36,35 @@ pub trait SentGrantRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static
     /// Essential for orchestrating background tasks, handling status polling loops
     /// for pending interactions, or separating credential-issuance grants from standard data access tokens.
     async fn filter_by_type(&self, kind: GrantKind) -> Outcome<Vec<Model>>;
+
+    /// Lists every grant requested from the participant `participant_id`.
+    async fn filter_by_participant(&self, participant_id: &str) -> Outcome<Vec<Model>>;
+
+    /// Replaces the integrator-defined `extensions` object of a grant.
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<Model>;
+
+    /// Lists grants whose `extensions` contain every key/value pair of `filter` (JSONB `@>`).
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<Model>>;
+
+    /// Moves the grant to `next` and persists it, only if nobody advanced it meanwhile.
+    ///
+    /// Other changes made to `model` are written along with the new lifecycle step.
+    ///
+    /// # Errors
+    /// Returns a forbidden error when the step is out of order or the stored grant already
+    /// moved on.
+    async fn advance(&self, model: Model, next: RequestLifecycle) -> Outcome<Model>;
+
+    /// Denies every grant created before `created_before` that has not reached a final
+    /// lifecycle step, so that abandoned requests age out like finished ones.
+    ///
+    /// Returns the number of grants closed.
+    async fn expire_stale(&self, created_before: DateTime<Utc>) -> Outcome<u64>;
+
+    /// Lists up to `limit` grants that reached a final lifecycle step before `cutoff`, oldest
+    /// first.
+    async fn get_archivable(&self, cutoff: DateTime<Utc>, limit: u64) -> Outcome<Vec<Model>>;
+
+    /// Deletes the grants with the given ids, returning how many were removed.
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64>;
 }
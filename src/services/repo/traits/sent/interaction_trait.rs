@@ -16,6 +16,7 @@
  */
 
 use crate::data::entities::sent::interaction::{Model, Plan};
+use crate::errors::Outcome;
 use crate::services::repo::traits::CrudRepoTrait;
 use async_trait::async_trait;
 
@@ -25,4 +26,7 @@ use async_trait::async_trait;
 /// interactive authentication hooks (such as redirect URIs or user codes) demanded by
 /// external Authorization Servers to complete a dynamic grant approval loop.
 #[async_trait]
-pub trait SentInteractionRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {}
+pub trait SentInteractionRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Deletes the interactions with the given ids, returning how many were removed.
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64>;
+}
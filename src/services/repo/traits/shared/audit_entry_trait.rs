@@ -0,0 +1,33 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::audit_entry::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use crate::types::audit::AuditFilter;
+use crate::types::pagination::{Page, PageRequest};
+use async_trait::async_trait;
+
+/// Data Repository Contract for the append-only log of audited writes.
+#[async_trait]
+pub trait AuditEntryRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Lists one page of the entries matching `filter`.
+    async fn search(&self, filter: &AuditFilter, request: &PageRequest) -> Outcome<Page<Model>>;
+
+    /// Returns every entry recorded for one row, oldest first.
+    async fn history(&self, entity: &str, entity_id: &str) -> Outcome<Vec<Model>>;
+}
@@ -0,0 +1,35 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::cred_template::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use crate::types::vcs::VcType;
+use async_trait::async_trait;
+
+/// Data Repository Contract for runtime-managed credential subject templates.
+///
+/// Complements the statically configured templates, letting operators change issued
+/// credential contents without redeploying the node.
+#[async_trait]
+pub trait CredTemplateRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Retrieves the template bound to a credential type.
+    async fn get_by_vc_type(&self, vc_type: &VcType) -> Outcome<Model>;
+
+    /// Creates or replaces the template bound to the plan credential type.
+    async fn upsert(&self, plan: Plan) -> Outcome<Model>;
+}
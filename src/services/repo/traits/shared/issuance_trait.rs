@@ -19,6 +19,8 @@ use crate::data::entities::shared::issuance::{Model, Plan};
 use crate::errors::Outcome;
 use crate::services::repo::traits::CrudRepoTrait;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
 
 /// Data Repository Contract for OpenID4VCI v1.0 Issuance Sessions.
 ///
@@ -40,4 +42,29 @@ pub trait IssuanceRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static
     /// Executed at the `/credential` endpoint to guarantee that the incoming request
     /// possesses authorized coverage over the requested Verifiable Credentials configuration layout.
     async fn get_by_token(&self, token: &str) -> Outcome<Model>;
+
+    /// Locates the issuance session that handed out a given `notification_id`.
+    ///
+    /// Executed at the `/notification` endpoint to record the wallet-reported credential event.
+    async fn get_by_notification_id(&self, notification_id: &str) -> Outcome<Model>;
+
+    /// Lists the issuance sessions opened for the participant known as `subject_name`.
+    async fn filter_by_subject(&self, subject_name: &str) -> Outcome<Vec<Model>>;
+
+    /// Lists the issuance sessions that minted (or are minting) `credential_id`.
+    async fn filter_by_credential(&self, credential_id: &str) -> Outcome<Vec<Model>>;
+
+    /// Replaces the integrator-defined `extensions` object of an issuance session.
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<Model>;
+
+    /// Lists issuance sessions whose `extensions` contain every key/value pair of `filter` (JSONB `@>`).
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<Model>>;
+
+    /// Lists up to `limit` sessions over before `cutoff`: either the wallet notified the
+    /// outcome before it, or the last `c_nonce` handed out expired before it without any
+    /// notification. Oldest first.
+    async fn get_archivable(&self, cutoff: DateTime<Utc>, limit: u64) -> Outcome<Vec<Model>>;
+
+    /// Deletes the sessions with the given ids, returning how many were removed.
+    async fn delete_many(&self, ids: &[String]) -> Outcome<u64>;
 }
@@ -15,10 +15,30 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod audit_entry_trait;
+mod cred_template_trait;
 mod issuance_trait;
 mod participant_trait;
+mod quota_counter_trait;
+mod replay_entry_trait;
 mod resource_req_trait;
+mod revocation_event_trait;
+mod rp_subscription_trait;
+mod shared_state_trait;
+mod status_entry_trait;
+mod status_list_trait;
+mod trusted_issuer_trait;
 
+pub use audit_entry_trait::AuditEntryRepoTrait;
+pub use cred_template_trait::CredTemplateRepoTrait;
 pub use issuance_trait::IssuanceRepoTrait;
 pub use participant_trait::ParticipantRepoTrait;
+pub use quota_counter_trait::QuotaCounterRepoTrait;
+pub use replay_entry_trait::ReplayEntryRepoTrait;
 pub use resource_req_trait::ResourceReqRepoTrait;
+pub use revocation_event_trait::RevocationEventRepoTrait;
+pub use rp_subscription_trait::RpSubscriptionRepoTrait;
+pub use shared_state_trait::SharedStateRepoTrait;
+pub use status_entry_trait::StatusEntryRepoTrait;
+pub use status_list_trait::StatusListRepoTrait;
+pub use trusted_issuer_trait::TrustedIssuerRepoTrait;
@@ -18,8 +18,11 @@
 use crate::data::entities::shared::participant::{Model, Plan};
 use crate::errors::Outcome;
 use crate::services::repo::traits::CrudRepoTrait;
-use crate::types::participants::ParticipantType;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::participants::{BusinessAttributes, ParticipantStatus, ParticipantType};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
 
 /// Data Repository Contract for Participant Domain Management.
 ///
@@ -34,11 +37,63 @@ pub trait ParticipantRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'stat
     async fn filter_by_type(&self, participant_type: ParticipantType) -> Outcome<Vec<Model>>;
 
     /// Locates an active participant bound to a specific API bearer or authorization token.
+    ///
+    /// Expired tokens are refused as unauthorized; the participant must
+    /// [rotate](Self::rotate_token) its token to keep calling. Tokens of mates that are not
+    /// [active](Model::check_standing) are refused too.
     async fn get_by_token(&self, token: &str) -> Outcome<Model>;
 
+    /// Locates the participant pre-registered as GNAP client instance `instance_id`.
+    async fn get_by_instance_id(&self, instance_id: &str) -> Outcome<Model>;
+
+    /// Replaces `token` with a fresh one valid until `expires_at` (GNAP token rotation).
+    ///
+    /// Expired tokens can still be rotated; the old value stops being accepted right away. The
+    /// fresh token never outlives the membership, and only active mates may rotate.
+    async fn rotate_token(&self, token: &str, expires_at: Option<DateTime<Utc>>) -> Outcome<Model>;
+
+    /// Lists the participants whose stored lifecycle state is `status`.
+    ///
+    /// Lapsed memberships keep their stored state until swept, so they are not listed as
+    /// `Expired` before that.
+    async fn filter_by_status(&self, status: ParticipantStatus) -> Outcome<Vec<Model>>;
+
     /// Optimized vectorized query to retrieve multiple records simultaneously, reducing DB roundtrips.
     async fn get_batch(&self, ids: &[String]) -> Outcome<Vec<Model>>;
 
     /// Performs an upsert-style force update bypassing standard transaction mutation checks.
     async fn force_update(&self, plan: Plan) -> Outcome<Model>;
+
+    /// Upserts many participants in one statement (bulk import).
+    ///
+    /// New ids are inserted as planned; existing ones only get their nick, type, base URL and
+    /// extensions overwritten, keeping tokens, lifecycle state and verified attributes. Like other bulk
+    /// statements, the writes are not audited.
+    async fn import_batch(&self, plans: Vec<Plan>) -> Outcome<Vec<Model>>;
+
+    /// Stores the business attributes extracted from a participant's verified credentials.
+    ///
+    /// Only the attributes present in `attrs` are overwritten.
+    async fn update_business(&self, id: &str, attrs: BusinessAttributes) -> Outcome<Model>;
+
+    /// Lists one page of the participants whose id, nick or base URL contain `query`.
+    ///
+    /// Matching is case-insensitive (`ILIKE`) and `%` or `_` in `query` match themselves; an
+    /// empty query matches every participant.
+    async fn search(&self, query: &str, request: &PageRequest) -> Outcome<Page<Model>>;
+
+    /// Lists participants matching every attribute set in `filter`.
+    ///
+    /// `legal_name` matches as a case-insensitive substring; the other fields match exactly.
+    async fn filter_by_business(&self, filter: &BusinessAttributes) -> Outcome<Vec<Model>>;
+
+    /// Replaces the integrator-defined `extensions` object of a participant.
+    async fn update_extensions(&self, id: &str, extensions: Value) -> Outcome<Model>;
+
+    /// Lists participants whose `extensions` contain every key/value pair of `filter` (JSONB `@>`).
+    async fn filter_by_extensions(&self, filter: &Value) -> Outcome<Vec<Model>>;
+
+    /// Lists the sub-identities of `id`: participants whose DID is nested under it, either as
+    /// a did:web sub-path (`{id}:...`) or as a DID URL (`{id}#...`).
+    async fn get_dependents(&self, id: &str) -> Outcome<Vec<Model>>;
 }
@@ -0,0 +1,39 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::quota_counter::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Data Repository Contract for the persisted per-participant quota counters.
+#[async_trait]
+pub trait QuotaCounterRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Atomically adds one to the counter of the window, creating it on first use.
+    async fn increment(&self, plan: Plan) -> Outcome<Model>;
+
+    /// Lists the counters of a participant whose window started at or after `since`.
+    async fn get_by_participant(
+        &self,
+        participant_id: &str,
+        since: DateTime<Utc>,
+    ) -> Outcome<Vec<Model>>;
+
+    /// Deletes every counter whose window started before `cutoff`, returning how many were dropped.
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> Outcome<u64>;
+}
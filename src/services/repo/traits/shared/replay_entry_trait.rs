@@ -0,0 +1,34 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::replay_entry::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Data Repository Contract for the values already consumed by presentations.
+#[async_trait]
+pub trait ReplayEntryRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Atomically records the value, returning `false` when a live entry already holds it.
+    ///
+    /// An entry past its `expires_at` is taken over as if it did not exist.
+    async fn consume(&self, plan: Plan) -> Outcome<bool>;
+
+    /// Deletes every entry that expired before `now`, returning how many were dropped.
+    async fn purge_expired(&self, now: DateTime<Utc>) -> Outcome<u64>;
+}
@@ -0,0 +1,28 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::revocation_event::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use async_trait::async_trait;
+
+/// Data Repository Contract for the append-only revocation feed.
+#[async_trait]
+pub trait RevocationEventRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Returns up to `limit` events recorded after the `cursor` sequence number, oldest first.
+    async fn since(&self, cursor: i64, limit: u64) -> Outcome<Vec<Model>>;
+}
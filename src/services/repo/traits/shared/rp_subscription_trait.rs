@@ -0,0 +1,43 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::rp_subscription::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use crate::types::vcs::VcType;
+use async_trait::async_trait;
+
+/// Data Repository Contract for Relying-Party verification subscriptions.
+///
+/// Extends the foundational [`CrudRepoTrait`] with the lookups required to fan out verification
+/// decisions towards the relying applications that registered interest in them.
+#[async_trait]
+pub trait RpSubscriptionRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Retrieves every active subscription whose filter matches the finished verification.
+    async fn get_matching(
+        &self,
+        holder: Option<&str>,
+        vc_types: &[VcType],
+        purpose: Option<&str>,
+    ) -> Outcome<Vec<Model>>;
+
+    /// Toggles a subscription on or off without deleting its registration.
+    async fn set_active(&self, id: &str, active: bool) -> Outcome<Model>;
+
+    /// Lists the subscriptions scoped to the holder `participant_id`.
+    async fn filter_by_participant(&self, participant_id: &str) -> Outcome<Vec<Model>>;
+}
@@ -0,0 +1,42 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::shared_state::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use async_trait::async_trait;
+
+/// Data Repository Contract for the state handed over between an active and a standby instance.
+///
+/// Every write carries the epoch of its writer, so an instance fenced off by a promotion can
+/// no longer overwrite what its successor published.
+#[async_trait]
+pub trait SharedStateRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Returns the entry stored under `key`, if any.
+    async fn find(&self, key: &str) -> Outcome<Option<Model>>;
+
+    /// Stores the entry unless it was last written with a higher epoch.
+    ///
+    /// Returns `false` when the write was fenced off.
+    async fn publish(&self, plan: Plan) -> Outcome<bool>;
+
+    /// Stores the entry only if it was last written with a lower epoch, so a single writer
+    /// can claim a given epoch.
+    ///
+    /// Returns `false` when another writer got there first.
+    async fn claim(&self, plan: Plan) -> Outcome<bool>;
+}
@@ -0,0 +1,36 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::status_entry::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use crate::types::issuance::StatusPurpose;
+use async_trait::async_trait;
+
+/// Data Repository Contract mapping issued credentials to their status list positions.
+#[async_trait]
+pub trait StatusEntryRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Retrieves the entry of a credential for a given status purpose.
+    async fn get_by_credential(
+        &self,
+        credential_id: &str,
+        purpose: StatusPurpose,
+    ) -> Outcome<Model>;
+
+    /// Retrieves every status entry allocated to a credential.
+    async fn get_all_by_credential(&self, credential_id: &str) -> Outcome<Vec<Model>>;
+}
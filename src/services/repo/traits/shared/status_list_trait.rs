@@ -0,0 +1,33 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::status_list::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use crate::types::issuance::StatusPurpose;
+use async_trait::async_trait;
+
+/// Data Repository Contract for Bitstring Status Lists maintained by the issuer.
+#[async_trait]
+pub trait StatusListRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Reserves the next free index of an open list for `purpose`, opening a new list when
+    /// every existing one is full. Returns the list the index belongs to and the index itself.
+    async fn allocate(&self, purpose: StatusPurpose) -> Outcome<(Model, i32)>;
+
+    /// Sets or clears the bit at `index` of list `id` under a row lock.
+    async fn set_bit(&self, id: &str, index: i32, value: bool) -> Outcome<Model>;
+}
@@ -0,0 +1,40 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::trusted_issuer::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use crate::types::pagination::{Page, PageRequest};
+use crate::types::verification::TrustedIssuerFilter;
+use async_trait::async_trait;
+
+/// Data Repository Contract for the trusted issuer registry.
+#[async_trait]
+pub trait TrustedIssuerRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Retrieves every registration of an issuer DID.
+    async fn get_by_issuer(&self, issuer_did: &str) -> Outcome<Vec<Model>>;
+
+    /// Retrieves every registration synced from an external registry.
+    async fn get_by_source(&self, source: &str) -> Outcome<Vec<Model>>;
+
+    /// Lists one page of the registrations matching `filter`.
+    async fn search(
+        &self,
+        filter: &TrustedIssuerFilter,
+        request: &PageRequest,
+    ) -> Outcome<Page<Model>>;
+}
@@ -17,8 +17,10 @@
 
 mod did_trait;
 mod key_trait;
+mod pending_presentation_trait;
 mod vc_trait;
 
 pub use did_trait::DidRepoTrait;
 pub use key_trait::KeyRepoTrait;
+pub use pending_presentation_trait::PendingPresentationRepoTrait;
 pub use vc_trait::VcRepoTrait;
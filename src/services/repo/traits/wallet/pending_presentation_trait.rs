@@ -0,0 +1,42 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::wallet::pending_presentation::{Model, Plan};
+use crate::errors::Outcome;
+use crate::services::repo::traits::CrudRepoTrait;
+use crate::types::wallet::ConsentStatus;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Data Repository Contract for presentation requests awaiting the holder's consent.
+///
+/// Inherits foundational CRUD layers from [`CrudRepoTrait`]. Keeps every request the wallet
+/// was asked to answer together with its candidate credentials and the decision taken.
+#[async_trait]
+pub trait PendingPresentationRepoTrait: CrudRepoTrait<Model, Plan> + Send + Sync + 'static {
+    /// Lists the requests in `status`, oldest first.
+    async fn filter_by_status(&self, status: ConsentStatus) -> Outcome<Vec<Model>>;
+
+    /// Lists the requests received after `since`, oldest first.
+    async fn created_after(&self, since: DateTime<Utc>) -> Outcome<Vec<Model>>;
+
+    /// Persists `model`, only if the stored request is still in status `from`.
+    ///
+    /// # Errors
+    /// Returns a forbidden error when the request moved on since it was read.
+    async fn advance(&self, model: Model, from: ConsentStatus) -> Outcome<Model>;
+}
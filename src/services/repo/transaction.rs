@@ -0,0 +1,124 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::future::Future;
+use std::sync::Arc;
+
+use sea_orm::DatabaseTransaction;
+
+use super::Repos;
+use super::postgres::RepoConnection;
+use crate::errors::{Errors, Outcome};
+
+/// Unit of work spanning several repositories.
+///
+/// Its [`Repos`] all run on one database transaction: what they write stays invisible to
+/// other connections until [`commit`](Self::commit), and is discarded by
+/// [`rollback`](Self::rollback) or when the unit is dropped uncommitted.
+///
+/// In-memory repositories have no transactions; their writes apply immediately and a
+/// rollback leaves them in place.
+pub struct RepoTransaction {
+    repos: Repos,
+    txn: Option<Arc<DatabaseTransaction>>,
+}
+
+impl RepoTransaction {
+    /// Repositories bound to the transaction.
+    ///
+    /// Clones taken from them must be dropped before committing.
+    pub fn repos(&self) -> &Repos {
+        &self.repos
+    }
+
+    /// Makes every write of the unit visible at once.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::DatabaseError`] when the commit fails or a repository bound to the
+    /// transaction is still alive, in which case nothing is committed.
+    pub async fn commit(self) -> Outcome<()> {
+        let Self { repos, txn } = self;
+        drop(repos);
+        let Some(txn) = txn else {
+            return Ok(());
+        };
+        let txn = Arc::try_unwrap(txn)
+            .map_err(|_| Errors::db("Transaction is still used by a repository", None))?;
+        txn.commit()
+            .await
+            .map_err(|e| Errors::db("Unable to commit transaction", Some(Box::new(e))))
+    }
+
+    /// Discards every write of the unit.
+    ///
+    /// When a repository bound to the transaction is still alive, the rollback happens once
+    /// it is dropped.
+    pub async fn rollback(self) -> Outcome<()> {
+        let Self { repos, txn } = self;
+        drop(repos);
+        let Some(Ok(txn)) = txn.map(Arc::try_unwrap) else {
+            return Ok(());
+        };
+        txn.rollback()
+            .await
+            .map_err(|e| Errors::db("Unable to roll back transaction", Some(Box::new(e))))
+    }
+}
+
+impl Repos {
+    /// Opens a unit of work over every repository, nested as a savepoint when `self` is
+    /// already bound to a transaction.
+    pub async fn begin(&self) -> Outcome<RepoTransaction> {
+        let Some(connection) = &self.connection else {
+            return Ok(RepoTransaction {
+                repos: self.clone(),
+                txn: None,
+            });
+        };
+        let txn = connection
+            .begin()
+            .await
+            .map(Arc::new)
+            .map_err(|e| Errors::db("Unable to start transaction", Some(Box::new(e))))?;
+        Ok(RepoTransaction {
+            repos: Repos::postgres(RepoConnection::Transaction(txn.clone())),
+            txn: Some(txn),
+        })
+    }
+
+    /// Runs `work` on the repositories of a fresh unit of work, committing it when `work`
+    /// succeeds and rolling it back when it fails.
+    pub async fn transaction<F, Fut, T>(&self, work: F) -> Outcome<T>
+    where
+        F: FnOnce(Repos) -> Fut,
+        Fut: Future<Output = Outcome<T>>,
+    {
+        let txn = self.begin().await?;
+        match work(txn.repos().clone()).await {
+            Ok(value) => {
+                txn.commit().await?;
+                Ok(value)
+            }
+            Err(e) => {
+                if let Err(rollback) = txn.rollback().await {
+                    rollback.log();
+                }
+                Err(e)
+            }
+        }
+    }
+}
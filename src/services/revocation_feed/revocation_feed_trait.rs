@@ -0,0 +1,57 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::config::types::RevocationFeedSource;
+use crate::errors::Outcome;
+use crate::types::revocation::{RevocationEvent, RevocationFeedPage};
+use async_trait::async_trait;
+
+/// Revocation feed service.
+///
+/// Signs the feed pages an authority publishes and, on participant agents, fetches and
+/// authenticates the pages of the authority being followed.
+#[async_trait]
+pub trait RevocationFeedTrait: Send + Sync + 'static {
+    /// Builds the page serving `events`, recorded after `cursor`, issued by the active identity.
+    async fn build_page(
+        &self,
+        cursor: i64,
+        events: Vec<RevocationEvent>,
+        has_more: bool,
+    ) -> Outcome<RevocationFeedPage>;
+
+    /// Signs a feed page as a compact JWT using the active identity.
+    async fn sign_page(&self, page: &RevocationFeedPage) -> Outcome<String>;
+
+    /// Fetches the page following `cursor` from `source`.
+    ///
+    /// The page must be signed by the configured authority DID and bound to `cursor`.
+    async fn fetch_page(
+        &self,
+        source: &RevocationFeedSource,
+        cursor: i64,
+        limit: u64,
+    ) -> Outcome<RevocationFeedPage>;
+
+    /// Returns the cursor of the last page fully ingested.
+    async fn cursor(&self) -> Outcome<i64>;
+
+    /// Moves the cursor past a page once all its events have been ingested.
+    ///
+    /// The cursor is persisted, so a restart does not replay the feed from the start.
+    async fn set_cursor(&self, cursor: i64) -> Outcome<()>;
+}
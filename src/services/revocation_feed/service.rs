@@ -0,0 +1,213 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::RevocationFeedTrait;
+use crate::capabilities::{DidResolver, Verifier};
+use crate::config::types::RevocationFeedSource;
+use crate::errors::{BadFormat, Errors, Outcome, PetitionFailure};
+use crate::services::client::ClientTrait;
+use crate::services::signer::{SignerTrait, VaultKeySigner};
+use crate::services::vault::{VaultService, VaultTrait};
+use crate::types::jwt::Jwt;
+use crate::types::revocation::{REVOCATION_FEED_TYP, RevocationEvent, RevocationFeedPage};
+use crate::types::wallet::Identity;
+use crate::utils::{Clock, ResponseExt, http_client};
+
+/// Default vault path of the subscriber cursor.
+const DEFAULT_CURSOR_PATH: &str = "revocation/feed-cursor";
+
+/// Revocation feed service signing with the node's active identity.
+///
+/// The subscriber cursor is kept in the vault and loaded on first use, so a restart resumes
+/// the feed instead of invalidating every entry again.
+pub struct RevocationFeedService {
+    vault: Arc<VaultService>,
+    identity: Arc<RwLock<Identity>>,
+    signer: Arc<dyn SignerTrait>,
+    resolver: DidResolver,
+    cursor_path: String,
+    cursor: RwLock<Option<i64>>,
+}
+
+/// Subscriber cursor as stored in the vault.
+#[derive(Serialize, Deserialize)]
+struct StoredCursor {
+    cursor: i64,
+}
+
+impl RevocationFeedService {
+    pub fn new(vault: Arc<VaultService>, identity: Arc<RwLock<Identity>>) -> Self {
+        Self {
+            vault: vault.clone(),
+            identity,
+            signer: Arc::new(VaultKeySigner::new(vault)),
+            resolver: DidResolver::default(),
+            cursor_path: DEFAULT_CURSOR_PATH.to_string(),
+            cursor: RwLock::new(None),
+        }
+    }
+
+    /// Keeps the subscriber cursor at `path`, see
+    /// [`get_feed_cursor_path`](crate::config::traits::RevocationFeedConfigTrait::get_feed_cursor_path).
+    pub fn with_cursor_path(mut self, path: impl Into<String>) -> Self {
+        self.cursor_path = path.into();
+        self
+    }
+
+    /// Signs through `signer`, e.g. a Vault Transit or KMS signer keeping the private keys
+    /// out of the process, instead of reading them from the vault.
+    pub fn with_signer(mut self, signer: Arc<dyn SignerTrait>) -> Self {
+        self.signer = signer;
+        self
+    }
+
+    /// Resolves signer DIDs through `resolver`, e.g. one with a Universal Resolver fallback.
+    pub fn with_did_resolver(mut self, resolver: DidResolver) -> Self {
+        self.resolver = resolver;
+        self
+    }
+}
+
+#[async_trait]
+impl RevocationFeedTrait for RevocationFeedService {
+    async fn build_page(
+        &self,
+        cursor: i64,
+        events: Vec<RevocationEvent>,
+        has_more: bool,
+    ) -> Outcome<RevocationFeedPage> {
+        let iss = self.identity.read().await.did().id().to_string();
+        let next_cursor = events.last().map_or(cursor, |event| event.seq);
+
+        Ok(RevocationFeedPage {
+            iss,
+            iat: Clock::issued_now().timestamp(),
+            cursor,
+            next_cursor,
+            has_more,
+            events,
+        })
+    }
+
+    async fn sign_page(&self, page: &RevocationFeedPage) -> Outcome<String> {
+        let (did, key_ref) = {
+            let lock = self.identity.read().await;
+            (lock.did().clone(), lock.key_ref().clone())
+        };
+        let claims = serde_json::to_value(page)?;
+
+        let jwt = self
+            .signer
+            .sign_enveloped(&did, &key_ref, REVOCATION_FEED_TYP, "json", &claims)
+            .await?;
+        Ok(jwt.as_str().to_string())
+    }
+
+    async fn fetch_page(
+        &self,
+        source: &RevocationFeedSource,
+        cursor: i64,
+        limit: u64,
+    ) -> Outcome<RevocationFeedPage> {
+        info!(
+            "Fetching revocation feed after {cursor} from {}",
+            source.url
+        );
+
+        let separator = if source.url.contains('?') { '&' } else { '?' };
+        let url = format!("{}{separator}cursor={cursor}&limit={limit}", source.url);
+        let res = http_client().get(&url, None).await?;
+        if !res.status().is_success() {
+            return Err(Errors::petition(
+                &url,
+                "GET",
+                Some(res.status()),
+                PetitionFailure::HttpStatus(res.status()),
+                "Revocation feed retrieval failed",
+                None,
+            ));
+        }
+
+        let jwt = Jwt::parse(res.parse_text().await?.trim())?;
+        if jwt.header().typ.as_deref() != Some(REVOCATION_FEED_TYP) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Revocation feed typ must be '{REVOCATION_FEED_TYP}'"),
+                None,
+            ));
+        }
+
+        let (kid, page) =
+            Verifier::verify_enveloped::<RevocationFeedPage>(&self.resolver, &jwt, None).await?;
+        if kid.did().id() != source.authority_did || page.iss != source.authority_did {
+            return Err(Errors::security(
+                "Revocation feed is not signed by the authority",
+                None,
+            ));
+        }
+        if page.cursor != cursor || page.next_cursor < cursor {
+            return Err(Errors::security(
+                "Revocation feed page does not match the requested cursor",
+                None,
+            ));
+        }
+        Ok(page)
+    }
+
+    async fn cursor(&self) -> Outcome<i64> {
+        if let Some(cursor) = *self.cursor.read().await {
+            return Ok(cursor);
+        }
+        let mut lock = self.cursor.write().await;
+        if let Some(cursor) = *lock {
+            return Ok(cursor);
+        }
+        let stored = match self
+            .vault
+            .read::<StoredCursor>(None, &self.cursor_path)
+            .await
+        {
+            Ok(stored) => stored.cursor,
+            Err(Errors::MissingResourceError { .. }) => 0,
+            Err(e) => return Err(e),
+        };
+        *lock = Some(stored);
+        Ok(stored)
+    }
+
+    async fn set_cursor(&self, cursor: i64) -> Outcome<()> {
+        if cursor <= self.cursor().await? {
+            return Ok(());
+        }
+        let mut lock = self.cursor.write().await;
+        if lock.is_some_and(|current| current >= cursor) {
+            return Ok(());
+        }
+        self.vault
+            .write(None, &self.cursor_path, &StoredCursor { cursor })
+            .await?;
+        *lock = Some(cursor);
+        Ok(())
+    }
+}
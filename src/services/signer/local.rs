@@ -0,0 +1,76 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use super::SignerTrait;
+use crate::capabilities::{Did, Signer};
+use crate::errors::Outcome;
+use crate::services::vault::{VaultService, VaultTrait};
+use crate::types::jwt::Jwt;
+use crate::types::keys::{Alg, PrivateKey, PublicKey, SigningCtx};
+use crate::types::secrets::PemHelper;
+use crate::types::wallet::KeyRef;
+
+/// Signer reading the private key PEM out of the vault and signing in process.
+///
+/// The default for deployments whose keys are stored as plain vault secrets.
+pub struct VaultKeySigner {
+    vault: Arc<VaultService>,
+}
+
+impl VaultKeySigner {
+    pub fn new(vault: Arc<VaultService>) -> Self {
+        Self { vault }
+    }
+
+    async fn private_key(&self, key: &str) -> Outcome<PrivateKey> {
+        let pem_helper: PemHelper = self.vault.read(None, key).await?;
+        PrivateKey::try_from(pem_helper)
+    }
+}
+
+#[async_trait]
+impl SignerTrait for VaultKeySigner {
+    async fn alg(&self, key: &str) -> Outcome<Alg> {
+        Ok(self.private_key(key).await?.alg())
+    }
+
+    async fn sign_bytes(&self, key: &str, data: &[u8], alg: Alg) -> Outcome<Vec<u8>> {
+        self.private_key(key).await?.sign_bytes(data, alg)
+    }
+
+    async fn public_key(&self, key: &str) -> Outcome<PublicKey> {
+        Ok(self.private_key(key).await?.public_key())
+    }
+
+    async fn sign_enveloped(
+        &self,
+        did: &Did,
+        key_ref: &KeyRef,
+        typ: &str,
+        cty: &str,
+        value: &Value,
+    ) -> Outcome<Jwt> {
+        let key = self.private_key(key_ref.internal()).await?;
+        let sig_ctx = SigningCtx::new(did.clone(), key, key_ref.fragment().to_string());
+        Signer::sign_enveloped(&sig_ctx, typ, cty, value)
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::capabilities::{Did, Signer};
+use crate::errors::Outcome;
+use crate::types::jwt::Jwt;
+use crate::types::keys::{Alg, PublicKey};
+use crate::types::wallet::KeyRef;
+
+/// Signing abstraction over keys that may never leave their key store.
+///
+/// Keys are addressed by the internal part of their [`KeyRef`]: a vault path for keys read
+/// into the process, a key name or id for Vault Transit or a cloud KMS.
+#[async_trait]
+pub trait SignerTrait: Send + Sync + 'static {
+    /// Returns the JWS algorithm `key` signs with.
+    async fn alg(&self, key: &str) -> Outcome<Alg>;
+
+    /// Signs `data` with `key` under `alg`.
+    async fn sign_bytes(&self, key: &str, data: &[u8], alg: Alg) -> Outcome<Vec<u8>>;
+
+    /// Returns the public half of `key`.
+    async fn public_key(&self, key: &str) -> Outcome<PublicKey>;
+
+    /// Signs `value` as a compact JWT identified as key `key_ref` of `did`.
+    async fn sign_enveloped(
+        &self,
+        did: &Did,
+        key_ref: &KeyRef,
+        typ: &str,
+        cty: &str,
+        value: &Value,
+    ) -> Outcome<Jwt> {
+        let alg = self.alg(key_ref.internal()).await?;
+        let kid = format!("{}#{}", did.id(), key_ref.fragment());
+        let signing_input = Signer::signing_input(kid, alg.clone(), typ, cty, value)?;
+        let sig_bytes = self
+            .sign_bytes(key_ref.internal(), signing_input.as_bytes(), alg)
+            .await?;
+        Signer::assemble(&signing_input, &sig_bytes)
+    }
+}
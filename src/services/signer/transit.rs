@@ -0,0 +1,74 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::SignerTrait;
+use crate::errors::{Errors, Outcome};
+use crate::services::vault::VaultService;
+use crate::services::vault::vault_rs::RealVaultService;
+use crate::types::keys::{Alg, PublicKey};
+
+/// Signer backed by the Vault Transit secrets engine.
+///
+/// Keys are named by their Transit key name; the private key never leaves Vault, which only
+/// hands back signatures over the data sent to it.
+pub struct TransitSigner {
+    vault: Arc<VaultService>,
+    mount: String,
+    alg: Alg,
+}
+
+impl TransitSigner {
+    /// Signs with the Transit engine mounted at `mount`, whose keys sign under `alg`.
+    pub fn new(vault: Arc<VaultService>, mount: impl Into<String>, alg: Alg) -> Self {
+        Self {
+            vault,
+            mount: mount.into(),
+            alg,
+        }
+    }
+
+    fn engine(&self) -> Outcome<&RealVaultService> {
+        match &*self.vault {
+            VaultService::Real(vault) => Ok(vault),
+            _ => Err(Errors::not_impl(
+                "Transit signing needs the HashiCorp Vault backend",
+                None,
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl SignerTrait for TransitSigner {
+    async fn alg(&self, _key: &str) -> Outcome<Alg> {
+        Ok(self.alg.clone())
+    }
+
+    async fn sign_bytes(&self, key: &str, data: &[u8], alg: Alg) -> Outcome<Vec<u8>> {
+        self.engine()?
+            .transit_sign(&self.mount, key, data, &alg)
+            .await
+    }
+
+    async fn public_key(&self, key: &str) -> Outcome<PublicKey> {
+        self.engine()?.transit_public_key(&self.mount, key).await
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+
+use super::StandbyTrait;
+use crate::types::standby::InstanceRole;
+
+struct RoleState {
+    role: InstanceRole,
+    epoch: i64,
+    last_sync_at: Option<DateTime<Utc>>,
+}
+
+/// In-memory role keeper. A restarted instance starts over from its configured role and
+/// catches up with the leader lease on its first handoff round.
+pub struct StandbyService {
+    instance_id: String,
+    state: RwLock<RoleState>,
+}
+
+impl StandbyService {
+    pub fn new(instance_id: impl Into<String>, role: InstanceRole) -> Self {
+        Self {
+            instance_id: instance_id.into(),
+            state: RwLock::new(RoleState {
+                role,
+                epoch: 0,
+                last_sync_at: None,
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl StandbyTrait for StandbyService {
+    fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    async fn role(&self) -> (InstanceRole, i64) {
+        let state = self.state.read().await;
+        (state.role, state.epoch)
+    }
+
+    async fn set_role(&self, role: InstanceRole, epoch: i64) {
+        let mut state = self.state.write().await;
+        state.role = role;
+        state.epoch = epoch;
+    }
+
+    async fn last_sync(&self) -> Option<DateTime<Utc>> {
+        self.state.read().await.last_sync_at
+    }
+
+    async fn record_sync(&self, at: DateTime<Utc>) {
+        self.state.write().await.last_sync_at = Some(at);
+    }
+}
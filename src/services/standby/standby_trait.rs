@@ -0,0 +1,61 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::errors::{Errors, Outcome};
+use crate::types::standby::InstanceRole;
+
+/// Role keeper of an instance taking part in an active/standby pair.
+///
+/// Holds the role and the fencing epoch it was granted with; the handoff itself is driven by
+/// the standby module.
+#[async_trait]
+pub trait StandbyTrait: Send + Sync + 'static {
+    /// Returns the identifier recorded on the leader lease.
+    fn instance_id(&self) -> &str;
+
+    /// Returns the current role and the epoch it was granted with.
+    async fn role(&self) -> (InstanceRole, i64);
+
+    /// Switches to `role` under `epoch`.
+    async fn set_role(&self, role: InstanceRole, epoch: i64);
+
+    /// Returns when the state was last published or applied.
+    async fn last_sync(&self) -> Option<DateTime<Utc>>;
+
+    /// Records a completed handoff round.
+    async fn record_sync(&self, at: DateTime<Utc>);
+
+    /// Checks that this instance may write, i.e. it is not following an active instance.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] while the instance is on standby.
+    async fn ensure_active(&self) -> Outcome<()> {
+        match self.role().await.0 {
+            InstanceRole::Active => Ok(()),
+            InstanceRole::Standby => Err(Errors::forbidden(
+                format!(
+                    "Instance {} is on standby and does not accept writes",
+                    self.instance_id()
+                ),
+                None,
+            )),
+        }
+    }
+}
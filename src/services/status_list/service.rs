@@ -0,0 +1,111 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::json;
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::StatusListTrait;
+use crate::config::traits::HostsConfigTrait;
+use crate::config::types::HostType;
+use crate::data::entities::shared::status_list;
+use crate::errors::Outcome;
+use crate::services::issuer::oid4vci_1_0::IssuerConfig;
+use crate::services::signer::{SignerTrait, VaultKeySigner};
+use crate::services::vault::VaultService;
+use crate::types::vcs::doc::VCStatus;
+use crate::types::wallet::Identity;
+use crate::utils::Clock;
+
+/// Status list publisher signing lists with the node's active issuer identity.
+///
+/// Lists are served next to the issuer endpoints, under `{api_path}/issuer/status-lists`.
+pub struct StatusListService {
+    config: IssuerConfig,
+    identity: Arc<RwLock<Identity>>,
+    signer: Arc<dyn SignerTrait>,
+}
+
+impl StatusListService {
+    pub fn new(
+        config: IssuerConfig,
+        vault: Arc<VaultService>,
+        identity: Arc<RwLock<Identity>>,
+    ) -> Self {
+        Self {
+            config,
+            identity,
+            signer: Arc::new(VaultKeySigner::new(vault)),
+        }
+    }
+
+    /// Signs through `signer`, e.g. a Vault Transit or KMS signer keeping the private keys
+    /// out of the process, instead of reading them from the vault.
+    pub fn with_signer(mut self, signer: Arc<dyn SignerTrait>) -> Self {
+        self.signer = signer;
+        self
+    }
+}
+
+#[async_trait]
+impl StatusListTrait for StatusListService {
+    fn list_url(&self, list_id: &str) -> String {
+        format!(
+            "{}{}/issuer/status-lists/{}",
+            self.config.get_external_host(HostType::Http),
+            self.config.get_api_path(),
+            list_id
+        )
+    }
+
+    fn build_entry(&self, list: &status_list::Model, index: i32) -> VCStatus {
+        VCStatus::bitstring(&self.list_url(&list.id), index, list.purpose)
+    }
+
+    async fn sign_status_list(&self, list: &status_list::Model) -> Outcome<String> {
+        info!("Signing status list {}", list.id);
+
+        let (did, key_ref) = {
+            let lock = self.identity.read().await;
+            (lock.did().clone(), lock.key_ref().clone())
+        };
+
+        let url = self.list_url(&list.id);
+        let credential = json!({
+            "@context": ["https://www.w3.org/ns/credentials/v2"],
+            "id": url,
+            "type": ["VerifiableCredential", "BitstringStatusListCredential"],
+            "issuer": did.id(),
+            "validFrom": Clock::issued_now().to_rfc3339(),
+            "credentialSubject": {
+                "id": format!("{url}#list"),
+                "type": "BitstringStatusList",
+                "statusPurpose": list.purpose,
+                "encodedList": list.bitstring().encode()?,
+            },
+        });
+
+        let jwt = self
+            .signer
+            .sign_enveloped(&did, &key_ref, "vc+jwt", "vc", &credential)
+            .await?;
+        Ok(jwt.as_str().to_string())
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::entities::shared::status_list;
+use crate::errors::Outcome;
+use crate::types::vcs::doc::VCStatus;
+use async_trait::async_trait;
+
+/// Bitstring Status List (W3C) issuer-side service specification.
+///
+/// Turns allocated list positions into `credentialStatus` entries and publishes the signed
+/// `BitstringStatusListCredential` relying parties dereference to check a credential.
+#[async_trait]
+pub trait StatusListTrait: Send + Sync + 'static {
+    /// Public URL the status list credential `list_id` is served at.
+    fn list_url(&self, list_id: &str) -> String;
+
+    /// Builds the `credentialStatus` entry to embed for position `index` of `list`.
+    fn build_entry(&self, list: &status_list::Model, index: i32) -> VCStatus;
+
+    /// Signs the current state of `list` as a `BitstringStatusListCredential` JWT.
+    async fn sign_status_list(&self, list: &status_list::Model) -> Outcome<String>;
+}
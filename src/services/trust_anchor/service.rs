@@ -0,0 +1,250 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use super::TrustAnchorTrait;
+use crate::capabilities::{DidResolver, Verifier};
+use crate::config::types::{RegistryFormat, TrustRegistry};
+use crate::data::entities::shared::trusted_issuer;
+use crate::errors::{BadFormat, Errors, Outcome, PetitionFailure};
+use crate::services::client::ClientTrait;
+use crate::types::jwt::Jwt;
+use crate::types::vcs::VcType;
+use crate::types::verification::TrustSyncStatus;
+use crate::utils::{ResponseExt, http_client};
+
+/// Upper bound on EBSI pages followed in a single round, guarding against looping links.
+const MAX_EBSI_PAGES: usize = 100;
+
+/// Trust anchor client speaking plain JSON lists and the EBSI Trusted Issuers Registry API.
+pub struct TrustAnchorService {
+    statuses: RwLock<HashMap<String, TrustSyncStatus>>,
+    resolver: DidResolver,
+}
+
+impl TrustAnchorService {
+    pub fn new() -> Self {
+        Self {
+            statuses: RwLock::new(HashMap::new()),
+            resolver: DidResolver::default(),
+        }
+    }
+
+    /// Resolves registry signer DIDs through `resolver`, e.g. one with a Universal Resolver
+    /// fallback.
+    pub fn with_did_resolver(mut self, resolver: DidResolver) -> Self {
+        self.resolver = resolver;
+        self
+    }
+}
+
+impl Default for TrustAnchorService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TrustAnchorTrait for TrustAnchorService {
+    async fn fetch_issuers(&self, registry: &TrustRegistry) -> Outcome<Vec<trusted_issuer::Plan>> {
+        info!("Fetching trusted issuers from {}", registry.url);
+
+        let entries = match registry.format {
+            RegistryFormat::Json => {
+                parse_json_list(&fetch_signed(&self.resolver, registry).await?)?
+            }
+            RegistryFormat::Ebsi => fetch_ebsi(&registry.url).await?,
+        };
+
+        let mut plans = Vec::with_capacity(entries.len());
+        for (did, vc_type) in entries {
+            let vc_type = match (vc_type, &registry.vc_type) {
+                (Err(raw), _) => {
+                    warn!("Skipping {did} from {}: unknown type {raw}", registry.url);
+                    continue;
+                }
+                (Ok(Some(listed)), Some(scope)) if &listed != scope => {
+                    warn!(
+                        "Skipping {did} from {}: {listed} is out of scope",
+                        registry.url
+                    );
+                    continue;
+                }
+                (Ok(listed), scope) => scope.clone().or(listed),
+            };
+            plans.push(trusted_issuer::Plan {
+                issuer_did: did,
+                vc_type,
+                name: None,
+                source: Some(registry.url.clone()),
+            });
+        }
+        Ok(plans)
+    }
+
+    async fn record_status(&self, status: TrustSyncStatus) {
+        self.statuses
+            .write()
+            .await
+            .insert(status.registry.clone(), status);
+    }
+
+    async fn sync_status(&self) -> Vec<TrustSyncStatus> {
+        self.statuses.read().await.values().cloned().collect()
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Entry of a registry listing: the issuer DID and the raw type it is listed for, kept as
+/// `Err` when it does not name a known credential type.
+type ListedIssuer = (String, Result<Option<VcType>, String>);
+
+/// Rejects registry endpoints not served over `https`.
+fn require_https(url: &str) -> Outcome<()> {
+    if !url.starts_with("https://") {
+        return Err(Errors::security(
+            format!("Trust registry {url} must be served over https"),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+async fn fetch_response(url: &str) -> Outcome<reqwest::Response> {
+    require_https(url)?;
+    let res = http_client().get(url, None).await?;
+    if !res.status().is_success() {
+        return Err(Errors::petition(
+            url,
+            "GET",
+            Some(res.status()),
+            PetitionFailure::HttpStatus(res.status()),
+            "Trust registry retrieval failed",
+            None,
+        ));
+    }
+    Ok(res)
+}
+
+async fn fetch_json(url: &str) -> Outcome<Value> {
+    fetch_response(url).await?.parse_json().await
+}
+
+/// Downloads a JWS-signed listing and returns its payload once the signature is checked
+/// against the key of the registry `signer`.
+async fn fetch_signed(resolver: &DidResolver, registry: &TrustRegistry) -> Outcome<Value> {
+    let signer = registry.signer.as_deref().ok_or_else(|| {
+        Errors::security(
+            format!("Trust registry {} has no signer configured", registry.url),
+            None,
+        )
+    })?;
+
+    let body = fetch_response(&registry.url).await?.parse_text().await?;
+    let jwt = Jwt::parse(body.trim())?;
+    let (kid, payload) = Verifier::verify_enveloped::<Value>(resolver, &jwt, None).await?;
+    if kid.did().id() != signer {
+        return Err(Errors::security(
+            format!("Trust registry {} is not signed by {signer}", registry.url),
+            None,
+        ));
+    }
+    Ok(payload)
+}
+
+/// Walks the paginated EBSI TIR `issuers` listing.
+async fn fetch_ebsi(url: &str) -> Outcome<Vec<ListedIssuer>> {
+    let mut entries = Vec::new();
+    let mut next = Some(url.to_string());
+    let mut pages = 0;
+
+    while let Some(page_url) = next.take() {
+        pages += 1;
+        if pages > MAX_EBSI_PAGES {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "EBSI registry exceeded the maximum number of pages",
+                None,
+            ));
+        }
+
+        let page = fetch_json(&page_url).await?;
+        let items = page["items"]
+            .as_array()
+            .ok_or_else(|| Errors::format(BadFormat::Received, "EBSI page has no items", None))?;
+        if items.is_empty() {
+            break;
+        }
+        for item in items {
+            if let Some(did) = item["did"].as_str() {
+                entries.push((did.to_string(), Ok(None)));
+            }
+        }
+
+        next = page["links"]["next"]
+            .as_str()
+            .filter(|link| *link != page_url)
+            .map(str::to_string);
+    }
+    Ok(entries)
+}
+
+/// Reads a list of DIDs or `{ "did", "vc_type" }` objects, optionally wrapped in an
+/// `issuers` or `items` field.
+fn parse_json_list(value: &Value) -> Outcome<Vec<ListedIssuer>> {
+    let list = match value {
+        Value::Array(list) => list,
+        Value::Object(obj) => obj
+            .get("issuers")
+            .or_else(|| obj.get("items"))
+            .and_then(Value::as_array)
+            .ok_or_else(|| {
+                Errors::format(BadFormat::Received, "Trust registry lists no issuers", None)
+            })?,
+        _ => {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Unexpected trust registry payload",
+                None,
+            ));
+        }
+    };
+
+    Ok(list
+        .iter()
+        .filter_map(|entry| match entry {
+            Value::String(did) => Some((did.clone(), Ok(None))),
+            Value::Object(obj) => {
+                let did = obj.get("did").or_else(|| obj.get("issuer"))?.as_str()?;
+                let vc_type = match obj.get("vc_type").or_else(|| obj.get("vcType")) {
+                    None | Some(Value::Null) => Ok(None),
+                    Some(Value::String(raw)) => raw.parse().map(Some).map_err(|_| raw.clone()),
+                    Some(other) => Err(other.to_string()),
+                };
+                Some((did.to_string(), vc_type))
+            }
+            _ => None,
+        })
+        .collect())
+}
@@ -0,0 +1,38 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::config::types::TrustRegistry;
+use crate::data::entities::shared::trusted_issuer;
+use crate::errors::Outcome;
+use crate::types::verification::TrustSyncStatus;
+use async_trait::async_trait;
+
+/// External trust anchor client.
+///
+/// Pulls trusted issuer lists from remote registries (EBSI TIR, Gaia-X registry exports, ...)
+/// and remembers how the last sync round against each of them went.
+#[async_trait]
+pub trait TrustAnchorTrait: Send + Sync + 'static {
+    /// Downloads the issuers listed by `registry` as registration plans tagged with its URL.
+    async fn fetch_issuers(&self, registry: &TrustRegistry) -> Outcome<Vec<trusted_issuer::Plan>>;
+
+    /// Stores the outcome of a sync round.
+    async fn record_status(&self, status: TrustSyncStatus);
+
+    /// Returns the latest outcome for every registry synced so far.
+    async fn sync_status(&self) -> Vec<TrustSyncStatus>;
+}
@@ -0,0 +1,154 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use aws_sdk_kms::Client;
+use aws_sdk_kms::primitives::Blob;
+use aws_sdk_kms::types::{MessageType, SigningAlgorithmSpec};
+use p256::ecdsa::Signature as P256Signature;
+
+use crate::errors::{Errors, Outcome};
+use crate::services::signer::SignerTrait;
+use crate::types::keys::{Alg, PublicKey};
+
+/// Signer backed by AWS KMS asymmetric keys.
+///
+/// The private key never leaves KMS: the data to sign is sent to the key and only the
+/// signature comes back. RSA keys are supported, with PKCS#1 v1.5 and PSS padding, as well as
+/// ECC_NIST_P256 keys under ES256.
+pub struct KmsSigner {
+    client: Client,
+    alg: Option<Alg>,
+}
+
+impl KmsSigner {
+    pub async fn new() -> Self {
+        let config = aws_config::load_from_env().await;
+        Self::custom(Client::new(&config))
+    }
+
+    pub fn custom(client: Client) -> Self {
+        Self { client, alg: None }
+    }
+
+    /// Sets the algorithm reported for the keys when signing through [`SignerTrait`].
+    ///
+    /// Without it, the algorithm follows the type of each key: RS256 for RSA, ES256 for P-256.
+    pub fn with_alg(mut self, alg: Alg) -> Self {
+        self.alg = Some(alg);
+        self
+    }
+
+    /// Signs `data` with KMS key `key_id` (id, ARN or alias) under `alg`.
+    ///
+    /// # Errors
+    /// Returns a not implemented error for algorithms KMS cannot produce for RSA or P-256 keys,
+    /// and a vault error when KMS refuses to sign.
+    pub async fn sign_bytes(&self, key_id: &str, data: &[u8], alg: Alg) -> Outcome<Vec<u8>> {
+        let ecdsa = alg == Alg::Es256;
+        let spec = match alg {
+            Alg::Rs256 => SigningAlgorithmSpec::RsassaPkcs1V15Sha256,
+            Alg::Rs384 => SigningAlgorithmSpec::RsassaPkcs1V15Sha384,
+            Alg::Rs512 => SigningAlgorithmSpec::RsassaPkcs1V15Sha512,
+            Alg::Ps256 => SigningAlgorithmSpec::RsassaPssSha256,
+            Alg::Ps384 => SigningAlgorithmSpec::RsassaPssSha384,
+            Alg::Ps512 => SigningAlgorithmSpec::RsassaPssSha512,
+            Alg::Es256 => SigningAlgorithmSpec::EcdsaSha256,
+            other => {
+                return Err(Errors::not_impl(
+                    format!("Unsupported KMS signing alg {other}"),
+                    None,
+                ));
+            }
+        };
+        let output = self
+            .client
+            .sign()
+            .key_id(key_id)
+            .message(Blob::new(data))
+            .message_type(MessageType::Raw)
+            .signing_algorithm(spec)
+            .send()
+            .await
+            .map_err(|e| {
+                Errors::vault(
+                    format!("KMS key {key_id} refused to sign"),
+                    Some(Box::new(e)),
+                )
+            })?;
+        let signature = output.signature().ok_or_else(|| {
+            Errors::vault(format!("KMS key {key_id} returned no signature"), None)
+        })?;
+        if !ecdsa {
+            return Ok(signature.as_ref().to_vec());
+        }
+
+        // KMS returns ECDSA signatures DER-encoded, JWS expects the fixed-size r || s form
+        let signature = P256Signature::from_der(signature.as_ref()).map_err(|e| {
+            Errors::vault(
+                format!("KMS key {key_id} returned a malformed ECDSA signature"),
+                Some(Box::new(e)),
+            )
+        })?;
+        Ok(signature.to_bytes().to_vec())
+    }
+
+    /// Returns the public half of KMS key `key_id`, e.g. to publish it in the DID document.
+    pub async fn public_key(&self, key_id: &str) -> Outcome<PublicKey> {
+        let output = self
+            .client
+            .get_public_key()
+            .key_id(key_id)
+            .send()
+            .await
+            .map_err(|e| {
+                Errors::vault(
+                    format!("Unable to read public key of KMS key {key_id}"),
+                    Some(Box::new(e)),
+                )
+            })?;
+        let der = output
+            .public_key()
+            .ok_or_else(|| Errors::vault(format!("KMS key {key_id} has no public key"), None))?;
+        PublicKey::try_from_pkcs8_der(der.as_ref())
+    }
+}
+
+#[async_trait]
+impl SignerTrait for KmsSigner {
+    async fn alg(&self, key: &str) -> Outcome<Alg> {
+        if let Some(alg) = &self.alg {
+            return Ok(alg.clone());
+        }
+        match KmsSigner::public_key(self, key).await? {
+            PublicKey::Rsa { .. } => Ok(Alg::Rs256),
+            PublicKey::P256 { .. } => Ok(Alg::Es256),
+            PublicKey::Ed25519 { .. } => Err(Errors::not_impl(
+                format!("KMS key {key} is Ed25519, which this signer cannot use"),
+                None,
+            )),
+        }
+    }
+
+    async fn sign_bytes(&self, key: &str, data: &[u8], alg: Alg) -> Outcome<Vec<u8>> {
+        KmsSigner::sign_bytes(self, key, data, alg).await
+    }
+
+    async fn public_key(&self, key: &str) -> Outcome<PublicKey> {
+        KmsSigner::public_key(self, key).await
+    }
+}
@@ -0,0 +1,217 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use aws_sdk_secretsmanager::Client;
+use sea_orm::{Database, DatabaseConnection};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tracing::info;
+
+use super::super::vault_rs::RealVaultService;
+use crate::config::traits::DatabaseConfigTrait;
+use crate::errors::{Errors, Outcome};
+use crate::services::vault::VaultTrait;
+use crate::types::secrets::DbSecrets;
+
+/// Vault service backed by AWS Secrets Manager.
+///
+/// Every vault path maps onto a secret named `{AWS_SECRETS_PREFIX}{mount}/{path}` holding the
+/// JSON value as its secret string. Credentials and region come from the default AWS provider
+/// chain, so the node runs on its instance or task role without static keys.
+pub struct AwsVaultService {
+    client: Client,
+    prefix: String,
+    vault_path: PathBuf,
+    db_path: String,
+}
+
+impl AwsVaultService {
+    pub async fn new() -> Outcome<Self> {
+        let config = aws_config::load_from_env().await;
+        Self::custom(Client::new(&config))
+    }
+
+    pub fn custom(client: Client) -> Outcome<Self> {
+        let prefix = std::env::var("AWS_SECRETS_PREFIX").unwrap_or_default();
+        let vault_path = PathBuf::from(
+            std::env::var("VAULT_PATH")
+                .map_err(|e| Errors::vault("VAULT_PATH env var not set", Some(Box::new(e))))?,
+        );
+        let db_path = std::env::var("VAULT_APP_DB")
+            .map_err(|e| Errors::vault("VAULT_APP_DB env var not set", Some(Box::new(e))))?;
+
+        Ok(Self {
+            client,
+            prefix,
+            vault_path,
+            db_path,
+        })
+    }
+
+    fn secret_name(&self, mount: Option<&str>, path: &str) -> String {
+        match mount {
+            Some(mount) => format!("{}{mount}/{path}", self.prefix),
+            None => format!("{}{path}", self.prefix),
+        }
+    }
+}
+
+#[async_trait]
+impl VaultTrait for AwsVaultService {
+    async fn read<T>(&self, mount: Option<&str>, path: &str) -> Outcome<T>
+    where
+        T: DeserializeOwned + Send,
+    {
+        let secret = self.basic_read(mount, path).await?;
+        Ok(serde_json::from_value(secret)?)
+    }
+
+    async fn basic_read(&self, mount: Option<&str>, path: &str) -> Outcome<Value> {
+        let name = self.secret_name(mount, path);
+        let output = self
+            .client
+            .get_secret_value()
+            .secret_id(&name)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.as_service_error()
+                    .is_some_and(|se| se.is_resource_not_found_exception())
+                {
+                    return Errors::missing_resource(
+                        &name,
+                        format!("No secret stored at {name}"),
+                        Some(Box::new(e)),
+                    );
+                }
+                Errors::vault(
+                    format!("Error reading secret {name} from AWS"),
+                    Some(Box::new(e)),
+                )
+            })?;
+        let raw = output
+            .secret_string()
+            .ok_or_else(|| Errors::vault(format!("Secret {name} holds no string value"), None))?;
+        Ok(serde_json::from_str(raw)?)
+    }
+
+    async fn write<T>(&self, mount: Option<&str>, path: &str, secret: &T) -> Outcome<()>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let name = self.secret_name(mount, path);
+        let value = serde_json::to_string(secret)?;
+        let put = self
+            .client
+            .put_secret_value()
+            .secret_id(&name)
+            .secret_string(&value)
+            .send()
+            .await;
+        match put {
+            Ok(_) => Ok(()),
+            Err(e)
+                if e.as_service_error()
+                    .is_some_and(|e| e.is_resource_not_found_exception()) =>
+            {
+                self.client
+                    .create_secret()
+                    .name(&name)
+                    .secret_string(value)
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        Errors::vault(
+                            format!("Error creating secret {name} in AWS"),
+                            Some(Box::new(e)),
+                        )
+                    })?;
+                Ok(())
+            }
+            Err(e) => Err(Errors::vault(
+                format!("Error writing secret {name} to AWS"),
+                Some(Box::new(e)),
+            )),
+        }
+    }
+
+    async fn delete(&self, mount: Option<&str>, path: &str) -> Outcome<()> {
+        let name = self.secret_name(mount, path);
+        let deleted = self
+            .client
+            .delete_secret()
+            .secret_id(&name)
+            .force_delete_without_recovery(true)
+            .send()
+            .await;
+        match deleted {
+            Ok(_) => Ok(()),
+            Err(e)
+                if e.as_service_error()
+                    .is_some_and(|e| e.is_resource_not_found_exception()) =>
+            {
+                Ok(())
+            }
+            Err(e) => Err(Errors::vault(
+                format!("Error deleting secret {name} from AWS"),
+                Some(Box::new(e)),
+            )),
+        }
+    }
+
+    async fn write_all_secrets(&self, map: Option<HashMap<String, Value>>) -> Outcome<()> {
+        let to_write = match map {
+            Some(m) => m,
+            None => RealVaultService::app_secrets(&self.vault_path.join("secrets"))?,
+        };
+        for (path, secret) in to_write {
+            self.write(None, &path, &secret).await?;
+        }
+        Ok(())
+    }
+
+    async fn check_mount(&self) -> Outcome<()> {
+        self.client
+            .list_secrets()
+            .max_results(1)
+            .send()
+            .await
+            .map_err(|e| {
+                Errors::vault("AWS Secrets Manager is not reachable", Some(Box::new(e)))
+            })?;
+        info!("AWS Secrets Manager reachable");
+        Ok(())
+    }
+
+    async fn get_db_connection<T>(&self, config: &T) -> Outcome<DatabaseConnection>
+    where
+        T: DatabaseConfigTrait + Send + Sync,
+    {
+        let db_secrets: DbSecrets = self
+            .read(None, &self.db_path)
+            .await
+            .map_err(|e| Errors::vault("Not able to retrieve env files", Some(Box::new(e))))?;
+        Database::connect(config.get_connect_options(config.get_full_db_url(&db_secrets)))
+            .await
+            .map_err(|e| Errors::db("Error connecting to database", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,217 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use sea_orm::{Database, DatabaseConnection};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::super::vault_rs::RealVaultService;
+use crate::config::traits::DatabaseConfigTrait;
+use crate::errors::{Errors, Outcome};
+use crate::services::vault::VaultTrait;
+use crate::types::crypto::EnvelopeKey;
+use crate::types::secrets::{DbSecrets, SecretFile};
+use crate::utils::read_json;
+
+/// Vault service backed by a single passphrase-encrypted local file.
+///
+/// Stands in for HashiCorp Vault in small deployments and CI environments: every secret is
+/// held in memory and the whole set is sealed again into the [`SecretFile`] on each write.
+pub struct EncryptedFileVaultService {
+    file: PathBuf,
+    key: EnvelopeKey,
+    secrets: RwLock<BTreeMap<String, Value>>,
+    vault_path: PathBuf,
+    db_path: String,
+}
+
+impl EncryptedFileVaultService {
+    /// Opens the secret file at `VAULT_FILE` with `VAULT_FILE_PASSPHRASE`.
+    ///
+    /// A missing file starts an empty store, written on the first secret stored.
+    pub fn new() -> Outcome<Self> {
+        let file = PathBuf::from(
+            std::env::var("VAULT_FILE")
+                .map_err(|e| Errors::vault("VAULT_FILE env var not set", Some(Box::new(e))))?,
+        );
+        let passphrase = std::env::var("VAULT_FILE_PASSPHRASE").map_err(|e| {
+            Errors::vault("VAULT_FILE_PASSPHRASE env var not set", Some(Box::new(e)))
+        })?;
+        let vault_path = PathBuf::from(
+            std::env::var("VAULT_PATH")
+                .map_err(|e| Errors::vault("VAULT_PATH env var not set", Some(Box::new(e))))?,
+        );
+        let db_path = std::env::var("VAULT_APP_DB")
+            .map_err(|e| Errors::vault("VAULT_APP_DB env var not set", Some(Box::new(e))))?;
+
+        let (key, secrets) = if file.exists() {
+            let sealed: SecretFile = read_json(&file)?;
+            let key = sealed.key_for(&passphrase)?;
+            let secrets = sealed.open(&key)?;
+            (key, secrets)
+        } else {
+            info!("Secret file {} not found, starting empty", file.display());
+            (SecretFile::new_key(&passphrase)?, BTreeMap::new())
+        };
+
+        Ok(Self {
+            file,
+            key,
+            secrets: RwLock::new(secrets),
+            vault_path,
+            db_path,
+        })
+    }
+
+    fn entry(mount: Option<&str>, path: &str) -> String {
+        match mount {
+            Some(mount) => format!("{mount}/{path}"),
+            None => path.to_string(),
+        }
+    }
+
+    /// Seals `secrets` into the file, replacing it only once the new content is on disk.
+    ///
+    /// The staged file is created fresh with mode 0600, so the sealed secrets are never
+    /// readable by other users whatever the process umask.
+    fn persist(&self, secrets: &BTreeMap<String, Value>) -> Outcome<()> {
+        let sealed = SecretFile::seal(secrets, &self.key)?;
+        let data = serde_json::to_vec_pretty(&sealed)?;
+        let staged = self.file.with_extension("tmp");
+        let write_err = |e: std::io::Error| {
+            Errors::write(
+                staged.display().to_string(),
+                "Unable to stage secret file",
+                Some(Box::new(e)),
+            )
+        };
+
+        match fs::remove_file(&staged) {
+            Err(e) if e.kind() != ErrorKind::NotFound => return Err(write_err(e)),
+            _ => {}
+        }
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options.open(&staged).map_err(write_err)?;
+        file.write_all(&data).map_err(write_err)?;
+        file.sync_all().map_err(write_err)?;
+
+        fs::rename(&staged, &self.file).map_err(|e| {
+            Errors::write(
+                self.file.display().to_string(),
+                "Unable to replace secret file",
+                Some(Box::new(e)),
+            )
+        })
+    }
+}
+
+#[async_trait]
+impl VaultTrait for EncryptedFileVaultService {
+    async fn read<T>(&self, mount: Option<&str>, path: &str) -> Outcome<T>
+    where
+        T: DeserializeOwned + Send,
+    {
+        let secret = self.basic_read(mount, path).await?;
+        Ok(serde_json::from_value(secret)?)
+    }
+
+    async fn basic_read(&self, mount: Option<&str>, path: &str) -> Outcome<Value> {
+        let entry = Self::entry(mount, path);
+        self.secrets
+            .read()
+            .await
+            .get(&entry)
+            .cloned()
+            .ok_or_else(|| {
+                Errors::missing_resource(&entry, format!("No secret stored at {entry}"), None)
+            })
+    }
+
+    async fn write<T>(&self, mount: Option<&str>, path: &str, secret: &T) -> Outcome<()>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let value = serde_json::to_value(secret)?;
+        let mut secrets = self.secrets.write().await;
+        let mut updated = secrets.clone();
+        updated.insert(Self::entry(mount, path), value);
+        self.persist(&updated)?;
+        *secrets = updated;
+        Ok(())
+    }
+
+    async fn delete(&self, mount: Option<&str>, path: &str) -> Outcome<()> {
+        let mut secrets = self.secrets.write().await;
+        let entry = Self::entry(mount, path);
+        if !secrets.contains_key(&entry) {
+            return Ok(());
+        }
+        let mut updated = secrets.clone();
+        updated.remove(&entry);
+        self.persist(&updated)?;
+        *secrets = updated;
+        Ok(())
+    }
+
+    async fn write_all_secrets(&self, map: Option<HashMap<String, Value>>) -> Outcome<()> {
+        let to_write = match map {
+            Some(m) => m,
+            None => RealVaultService::app_secrets(&self.vault_path.join("secrets"))?,
+        };
+        let mut secrets = self.secrets.write().await;
+        let mut updated = secrets.clone();
+        updated.extend(to_write);
+        self.persist(&updated)?;
+        *secrets = updated;
+        Ok(())
+    }
+
+    async fn check_mount(&self) -> Outcome<()> {
+        if self.file.exists() {
+            return Ok(());
+        }
+        let secrets = self.secrets.read().await;
+        self.persist(&secrets)
+    }
+
+    async fn get_db_connection<T>(&self, config: &T) -> Outcome<DatabaseConnection>
+    where
+        T: DatabaseConfigTrait + Send + Sync,
+    {
+        let db_secrets: DbSecrets = self
+            .read(None, &self.db_path)
+            .await
+            .map_err(|e| Errors::vault("Not able to retrieve env files", Some(Box::new(e))))?;
+        Database::connect(config.get_connect_options(config.get_full_db_url(&db_secrets)))
+            .await
+            .map_err(|e| Errors::db("Error connecting to database", Some(Box::new(e))))
+    }
+}
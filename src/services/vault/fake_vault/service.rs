@@ -58,13 +58,11 @@ impl VaultTrait for FakeVaultService {
     where
         T: DeserializeOwned + Send,
     {
-        let path = self.path.join(path);
-        read_json(path)
+        read_json(self.secret_file(path)?)
     }
 
     async fn basic_read(&self, _mount: Option<&str>, path: &str) -> Outcome<Value> {
-        let path = self.path.join(path);
-        read_json(path)
+        read_json(self.secret_file(path)?)
     }
 
     async fn write<T>(&self, _mount: Option<&str>, path: &str, secret: &T) -> Outcome<()>
@@ -75,6 +73,19 @@ impl VaultTrait for FakeVaultService {
         write_json(path, secret)
     }
 
+    async fn delete(&self, _mount: Option<&str>, path: &str) -> Outcome<()> {
+        let file = self.path.join(path);
+        match std::fs::remove_file(&file) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(Errors::write(
+                file.display().to_string(),
+                "Error deleting secret file",
+                Some(Box::new(e)),
+            )),
+        }
+    }
+
     async fn write_all_secrets(&self, map: Option<HashMap<String, Value>>) -> Outcome<()> {
         if map.is_none() {
             self.write_all_pems()?;
@@ -94,7 +105,7 @@ impl VaultTrait for FakeVaultService {
         let path = self.path.join(&self.db_path);
 
         let db_secrets: DbSecrets = read_json(path)?;
-        Database::connect(config.get_full_db_url(&db_secrets))
+        Database::connect(config.get_connect_options(config.get_full_db_url(&db_secrets)))
             .await
             .map_err(|e| Errors::db("Error connecting to database", Some(Box::new(e))))
     }
@@ -125,10 +136,25 @@ impl FakeVaultService {
     {
         let pem_file = Self::json_to_pem_extension(json_file);
         let path = self.path.join(pem_file);
+        if !path.exists() {
+            return Ok(());
+        }
         let pem = read(path)?;
         let value = parser(&pem)?;
         write_json(self.path.join(json_file), &value)
     }
+    /// Returns the file holding the secret at `path`, which must exist.
+    fn secret_file(&self, path: &str) -> Outcome<PathBuf> {
+        let file = self.path.join(path);
+        if !file.exists() {
+            return Err(Errors::missing_resource(
+                path,
+                format!("No secret stored at {path}"),
+                None,
+            ));
+        }
+        Ok(file)
+    }
     pub fn json_to_pem_extension(s: &str) -> String {
         s.replace(".json", ".pem")
     }
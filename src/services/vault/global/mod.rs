@@ -17,4 +17,4 @@
 
 mod service;
 
-pub use service::VaultService;
+pub use service::{VaultService, spawn_token_renewal};
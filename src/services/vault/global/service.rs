@@ -16,18 +16,29 @@
  */
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use async_trait::async_trait;
-use sea_orm::DatabaseConnection;
+use sea_orm::{Database, DatabaseConnection};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use tokio::task::JoinHandle;
+use tracing::info;
 
+#[cfg(feature = "aws")]
+use super::super::aws::AwsVaultService;
+use super::super::encrypted_file::EncryptedFileVaultService;
 use super::super::fake_vault::FakeVaultService;
 use super::super::vault_rs::RealVaultService;
-use crate::config::traits::DatabaseConfigTrait;
-use crate::errors::Outcome;
+use crate::config::traits::{DatabaseConfigTrait, VaultConfigTrait};
+use crate::config::types::VaultConfig;
+use crate::errors::{Errors, Outcome};
 use crate::services::vault::VaultTrait;
+use crate::types::dids::DidBuilder;
+use crate::types::keys::{KeyGenSpec, PrivateKey};
+use crate::types::secrets::{PemHelper, VaultHealth};
+use crate::utils::expect_from_env;
 
 /// Dispatcher Enum for Vault Strategies.
 ///
@@ -38,6 +49,113 @@ pub enum VaultService {
     Real(RealVaultService),
     /// Isolated file-based implementation designed for local testing and CI/CD pipelines.
     Fake(FakeVaultService),
+    /// Passphrase-encrypted local file for deployments running without a Vault cluster.
+    Encrypted(EncryptedFileVaultService),
+    /// AWS Secrets Manager, for cloud deployments running on an IAM role.
+    #[cfg(feature = "aws")]
+    Aws(AwsVaultService),
+}
+
+impl VaultService {
+    /// Builds the backend named by `VAULT_BACKEND`: `vault` (default), `file`, `encrypted-file`
+    /// or `aws`.
+    pub async fn from_env() -> Outcome<Self> {
+        Self::from_config(&VaultConfig::default()).await
+    }
+
+    /// Builds the backend named by `VAULT_BACKEND`, logging into Vault as `config` says.
+    ///
+    /// When `config` enables key bootstrap, the agent key pair is generated on first run.
+    pub async fn from_config<C: VaultConfigTrait>(config: &C) -> Outcome<Self> {
+        let vault = Self::backend(config).await?;
+        if let Some(spec) = config.get_key_bootstrap() {
+            vault.bootstrap_keys(spec).await?;
+        }
+        Ok(vault)
+    }
+
+    async fn backend<C: VaultConfigTrait>(config: &C) -> Outcome<Self> {
+        let backend = std::env::var("VAULT_BACKEND").unwrap_or_else(|_| "vault".to_string());
+        match backend.as_str() {
+            "vault" => Ok(VaultService::Real(RealVaultService::connect(config).await?)),
+            "file" => Ok(VaultService::Fake(FakeVaultService::new()?)),
+            "encrypted-file" => Ok(VaultService::Encrypted(EncryptedFileVaultService::new()?)),
+            #[cfg(feature = "aws")]
+            "aws" => Ok(VaultService::Aws(AwsVaultService::new().await?)),
+            #[cfg(not(feature = "aws"))]
+            "aws" => Err(Errors::not_impl(
+                "The aws vault backend needs a build with the aws feature",
+                None,
+            )),
+            other => Err(Errors::vault(
+                format!("Unknown VAULT_BACKEND '{other}'"),
+                None,
+            )),
+        }
+    }
+
+    /// Probes the backend, reporting local backends as always ready.
+    pub async fn health(&self) -> VaultHealth {
+        match self {
+            VaultService::Real(v) => v.health().await,
+            _ => VaultHealth::Ready,
+        }
+    }
+
+    /// Generates the agent key pair unless one is already stored, returning whether it did.
+    ///
+    /// Only a key reported missing is generated; any other read failure is returned, so an
+    /// unreachable vault never gets the stored key replaced.
+    ///
+    /// The keys are written where `write_all_secrets` would put the PEM files, so the wallet
+    /// onboarding derives the did:jwk or did:web document from them as usual and fresh
+    /// installs need no key files on disk.
+    pub async fn bootstrap_keys(&self, spec: &KeyGenSpec) -> Outcome<bool> {
+        // An unreachable backend would read as a missing key and have it overwritten
+        let health = self.health().await;
+        if !health.is_ready() {
+            return Err(Errors::vault(
+                format!("Vault is {health}, refusing to bootstrap keys"),
+                None,
+            ));
+        }
+        let priv_path = expect_from_env("VAULT_APP_PRIV_KEY");
+        match self.read::<PemHelper>(None, &priv_path).await {
+            Ok(_) => {
+                info!("Agent key already stored, skipping key bootstrap");
+                return Ok(false);
+            }
+            Err(Errors::MissingResourceError { .. }) => {}
+            Err(e) => return Err(e),
+        }
+
+        let key = PrivateKey::generate(spec)?;
+        let private = PemHelper::priv_from_pem(&key.to_pkcs8_pem()?)?;
+        let public = PemHelper::pub_from_pem(&key.public_key().to_pem()?)?;
+
+        self.check_mount().await?;
+        self.write(None, &priv_path, &private).await?;
+        self.write(None, &expect_from_env("VAULT_APP_PUB_PKEY"), &public)
+            .await?;
+
+        let did = DidBuilder::new_jwk(private.pem()).build()?;
+        info!("Generated {} agent key for {}", key.kty(), did.id());
+        Ok(true)
+    }
+}
+
+/// Spawns the job renewing the Vault token obtained through a login method.
+///
+/// Returns `None` for backends and methods with nothing to renew.
+pub fn spawn_token_renewal(vault: Arc<VaultService>) -> Option<JoinHandle<()>> {
+    match &*vault {
+        VaultService::Real(real) if real.needs_renewal() => Some(tokio::spawn(async move {
+            if let VaultService::Real(real) = &*vault {
+                real.keep_token_alive().await;
+            }
+        })),
+        _ => None,
+    }
 }
 
 #[async_trait]
@@ -49,6 +167,9 @@ impl VaultTrait for VaultService {
         match self {
             VaultService::Real(v) => v.read(mount, path).await,
             VaultService::Fake(v) => v.read(mount, path).await,
+            VaultService::Encrypted(v) => v.read(mount, path).await,
+            #[cfg(feature = "aws")]
+            VaultService::Aws(v) => v.read(mount, path).await,
         }
     }
 
@@ -56,6 +177,9 @@ impl VaultTrait for VaultService {
         match self {
             VaultService::Real(v) => v.basic_read(mount, path).await,
             VaultService::Fake(v) => v.basic_read(mount, path).await,
+            VaultService::Encrypted(v) => v.basic_read(mount, path).await,
+            #[cfg(feature = "aws")]
+            VaultService::Aws(v) => v.basic_read(mount, path).await,
         }
     }
 
@@ -66,6 +190,19 @@ impl VaultTrait for VaultService {
         match self {
             VaultService::Real(v) => v.write(mount, path, secret).await,
             VaultService::Fake(v) => v.write(mount, path, secret).await,
+            VaultService::Encrypted(v) => v.write(mount, path, secret).await,
+            #[cfg(feature = "aws")]
+            VaultService::Aws(v) => v.write(mount, path, secret).await,
+        }
+    }
+
+    async fn delete(&self, mount: Option<&str>, path: &str) -> Outcome<()> {
+        match self {
+            VaultService::Real(v) => v.delete(mount, path).await,
+            VaultService::Fake(v) => v.delete(mount, path).await,
+            VaultService::Encrypted(v) => v.delete(mount, path).await,
+            #[cfg(feature = "aws")]
+            VaultService::Aws(v) => v.delete(mount, path).await,
         }
     }
 
@@ -73,6 +210,9 @@ impl VaultTrait for VaultService {
         match self {
             VaultService::Real(v) => v.write_all_secrets(map).await,
             VaultService::Fake(v) => v.write_all_secrets(map).await,
+            VaultService::Encrypted(v) => v.write_all_secrets(map).await,
+            #[cfg(feature = "aws")]
+            VaultService::Aws(v) => v.write_all_secrets(map).await,
         }
     }
 
@@ -80,6 +220,9 @@ impl VaultTrait for VaultService {
         match self {
             VaultService::Real(v) => v.check_mount().await,
             VaultService::Fake(v) => v.check_mount().await,
+            VaultService::Encrypted(v) => v.check_mount().await,
+            #[cfg(feature = "aws")]
+            VaultService::Aws(v) => v.check_mount().await,
         }
     }
 
@@ -87,9 +230,18 @@ impl VaultTrait for VaultService {
     where
         T: DatabaseConfigTrait + Send + Sync,
     {
+        if let Some(url) = config.get_embedded_db_url() {
+            info!("Opening embedded database at {url}");
+            return Database::connect(config.get_connect_options(url))
+                .await
+                .map_err(|e| Errors::db("Error opening embedded database", Some(Box::new(e))));
+        }
         match self {
             VaultService::Real(v) => v.get_db_connection(config).await,
             VaultService::Fake(v) => v.get_db_connection(config).await,
+            VaultService::Encrypted(v) => v.get_db_connection(config).await,
+            #[cfg(feature = "aws")]
+            VaultService::Aws(v) => v.get_db_connection(config).await,
         }
     }
 }
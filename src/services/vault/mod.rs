@@ -15,10 +15,13 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+#[cfg(feature = "aws")]
+pub mod aws;
+pub mod encrypted_file;
 pub mod fake_vault;
 pub mod global;
 pub mod vault_rs;
 pub mod vault_trait;
 
-pub use global::VaultService;
+pub use global::{VaultService, spawn_token_renewal};
 pub use vault_trait::VaultTrait;
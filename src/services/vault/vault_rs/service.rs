@@ -18,30 +18,51 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use ed25519_dalek::VerifyingKey as Ed25519VerifyingKey;
 use sea_orm::{Database, DatabaseConnection};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
-use tracing::info;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+use vaultrs::api::AuthInfo;
 use vaultrs::api::sys::requests::EnableEngineRequestBuilder;
-use vaultrs::client::{VaultClient, VaultClientSettings, VaultClientSettingsBuilder};
-use vaultrs::kv2;
-use vaultrs::sys::mount;
+use vaultrs::api::transit::requests::SignDataRequestBuilder;
+use vaultrs::api::transit::responses::ReadKeyData;
+use vaultrs::api::transit::{HashAlgorithm, MarshalingAlgorithm, SignatureAlgorithm};
+use vaultrs::auth::{approle, kubernetes};
+use vaultrs::client::{Client, VaultClient, VaultClientSettings, VaultClientSettingsBuilder};
+use vaultrs::error::ClientError;
+use vaultrs::sys::{self, ServerStatus, mount};
+use vaultrs::{kv2, token, transit};
 
-use crate::config::traits::DatabaseConfigTrait;
-use crate::errors::{Errors, Outcome};
+use crate::config::traits::{DatabaseConfigTrait, VaultConfigTrait};
+use crate::config::types::{VaultAuth, VaultRetries};
+use crate::errors::{BadFormat, Errors, Outcome};
 use crate::services::vault::VaultTrait;
-use crate::types::secrets::{DbSecrets, PemHelper, StringHelper};
+use crate::types::keys::{Alg, PublicKey};
+use crate::types::secrets::{DbSecrets, PemHelper, StringHelper, VaultHealth};
 use crate::utils::{expect_from_env, read, read_json};
 
+/// Renewed leases shorter than this are replaced by a fresh login.
+const MIN_RENEWED_LEASE: Duration = Duration::from_secs(60);
+
+/// Delay before retrying a failed login.
+const RETRY_LOGIN: Duration = Duration::from_secs(30);
+
 /// Production Vault service backed by HashiCorp Vault.
 ///
 /// Communicates via HTTP/S using the `vaultrs` crate ecosystem. Manages active engine mounts,
 /// checks seals, and pulls raw secrets containing cryptographic material and dynamic DB configurations.
 pub struct RealVaultService {
-    client: Arc<VaultClient>,
+    client: Arc<RwLock<VaultClient>>,
+    auth: VaultAuth,
+    retries: VaultRetries,
     mount: String,
     vault_path: PathBuf,
     db_path: String,
@@ -68,12 +89,309 @@ impl RealVaultService {
             .map_err(|e| Errors::vault("Error building custom vault", Some(Box::new(e))))?;
 
         Ok(Self {
-            client: Arc::new(client),
+            client: Arc::new(RwLock::new(client)),
+            auth: VaultAuth::Token,
+            retries: VaultRetries::default(),
             mount,
             vault_path,
             db_path,
         })
     }
+
+    /// Builds the client and logs in with `auth`.
+    pub async fn with_auth(auth: VaultAuth) -> Outcome<Self> {
+        let mut vault = Self::new()?;
+        vault.auth = auth;
+        vault.login().await?;
+        Ok(vault)
+    }
+
+    /// Builds the client and logs in as `config` says.
+    ///
+    /// Unlike [`with_auth`](Self::with_auth), a Vault still down does not fail the startup:
+    /// the login is retried once Vault answers a call rejecting the missing token.
+    pub async fn connect<C: VaultConfigTrait>(config: &C) -> Outcome<Self> {
+        let mut vault = Self::new()?;
+        vault.auth = config.get_vault_auth().clone();
+        vault.retries = config.get_vault_retries().clone();
+        if let Err(e) = vault.login().await {
+            warn!("Vault login failed, retrying on first use: {e}");
+        }
+        Ok(vault)
+    }
+
+    /// Logs in with the configured method, returning the lease of the new token.
+    ///
+    /// Static tokens have no lease to track, so `None` is returned for them.
+    pub async fn login(&self) -> Outcome<Option<Duration>> {
+        let info = match &self.auth {
+            VaultAuth::Token => return Ok(None),
+            VaultAuth::AppRole {
+                mount,
+                role_id,
+                secret_id_env,
+            } => {
+                let secret_id = std::env::var(secret_id_env).map_err(|e| {
+                    Errors::vault(
+                        format!("{secret_id_env} env var not set"),
+                        Some(Box::new(e)),
+                    )
+                })?;
+                approle::login(&*self.client.read().await, mount, role_id, &secret_id).await
+            }
+            VaultAuth::Kubernetes {
+                mount,
+                role,
+                jwt_path,
+            } => {
+                let jwt = read(jwt_path)?;
+                kubernetes::login(&*self.client.read().await, mount, role, jwt.trim()).await
+            }
+        }
+        .map_err(|e| Errors::vault("Error logging into vault", Some(Box::new(e))))?;
+
+        Ok(Some(self.use_token(info).await))
+    }
+
+    /// Tells whether the token comes from a login method and has to be renewed.
+    pub fn needs_renewal(&self) -> bool {
+        !matches!(self.auth, VaultAuth::Token)
+    }
+
+    /// Extends the lease of the current token, logging in again once it cannot be renewed.
+    pub async fn refresh_token(&self) -> Outcome<Option<Duration>> {
+        if !self.needs_renewal() {
+            return Ok(None);
+        }
+        let renewed = token::renew_self(&*self.client.read().await, None).await;
+        match renewed {
+            Ok(info) if info.renewable && info.lease_duration >= MIN_RENEWED_LEASE.as_secs() => {
+                Ok(Some(self.use_token(info).await))
+            }
+            Ok(_) => self.login().await,
+            Err(e) => {
+                warn!("Vault token renewal failed, logging in again: {e}");
+                self.login().await
+            }
+        }
+    }
+
+    /// Renews the token for as long as the service lives, two thirds into every lease.
+    pub async fn keep_token_alive(&self) {
+        loop {
+            let wait = match self.refresh_token().await {
+                Ok(Some(lease)) => lease * 2 / 3,
+                Ok(None) => return,
+                Err(e) => {
+                    warn!("Unable to refresh vault token: {e}");
+                    RETRY_LOGIN
+                }
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    async fn use_token(&self, info: AuthInfo) -> Duration {
+        self.client.write().await.set_token(&info.client_token);
+        info!("Vault token valid for {}s", info.lease_duration);
+        Duration::from_secs(info.lease_duration)
+    }
+
+    // ===== RESILIENCE ============================================================================
+
+    /// Probes the server status.
+    pub async fn health(&self) -> VaultHealth {
+        match sys::status(&*self.client.read().await).await {
+            ServerStatus::OK | ServerStatus::PERFSTANDBY => VaultHealth::Ready,
+            ServerStatus::STANDBY => VaultHealth::Standby,
+            ServerStatus::SEALED => VaultHealth::Sealed,
+            ServerStatus::UNINITIALIZED => VaultHealth::Uninitialized,
+            _ => VaultHealth::Unreachable,
+        }
+    }
+
+    /// Runs `call` until it succeeds, backing off while Vault is unavailable and logging in
+    /// again the first time it rejects the token.
+    async fn retrying<T, F, Fut>(&self, op: &str, mut call: F) -> Result<T, ClientError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, ClientError>>,
+    {
+        let max_backoff = Duration::from_secs(self.retries.max_backoff_secs);
+        let mut backoff = Duration::from_millis(self.retries.backoff_ms).min(max_backoff);
+        let mut relogged = false;
+        let mut attempt = 1;
+        loop {
+            let err = match call().await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt >= self.retries.attempts => return Err(e),
+                Err(e) if is_unavailable(&e) => e,
+                Err(e) if is_auth_failure(&e) && self.needs_renewal() && !relogged => e,
+                Err(e) => return Err(e),
+            };
+            warn!(
+                "Vault {op} failed (attempt {attempt}/{}): {err}",
+                self.retries.attempts
+            );
+
+            if is_auth_failure(&err) {
+                relogged = true;
+                match self.login().await {
+                    Ok(_) => {
+                        attempt += 1;
+                        continue;
+                    }
+                    Err(e) => warn!("Vault login failed: {e}"),
+                }
+            }
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(max_backoff);
+            attempt += 1;
+        }
+    }
+
+    // ===== TRANSIT ===============================================================================
+
+    /// Signs `data` with Transit key `name` of the engine mounted at `mount`.
+    ///
+    /// # Errors
+    /// Returns a not implemented error for algorithms other than RSA, ES256 and EdDSA, and a
+    /// vault error when Transit refuses to sign or returns a malformed signature.
+    pub async fn transit_sign(
+        &self,
+        mount: &str,
+        name: &str,
+        data: &[u8],
+        alg: &Alg,
+    ) -> Outcome<Vec<u8>> {
+        let (hash, padding) = match alg {
+            Alg::Rs256 => (
+                Some(HashAlgorithm::Sha2_256),
+                Some(SignatureAlgorithm::Pkcs1v15),
+            ),
+            Alg::Rs384 => (
+                Some(HashAlgorithm::Sha2_384),
+                Some(SignatureAlgorithm::Pkcs1v15),
+            ),
+            Alg::Rs512 => (
+                Some(HashAlgorithm::Sha2_512),
+                Some(SignatureAlgorithm::Pkcs1v15),
+            ),
+            Alg::Ps256 => (Some(HashAlgorithm::Sha2_256), Some(SignatureAlgorithm::Pss)),
+            Alg::Ps384 => (Some(HashAlgorithm::Sha2_384), Some(SignatureAlgorithm::Pss)),
+            Alg::Ps512 => (Some(HashAlgorithm::Sha2_512), Some(SignatureAlgorithm::Pss)),
+            Alg::Es256 => (Some(HashAlgorithm::Sha2_256), None),
+            Alg::EdDsa => (None, None),
+            other => {
+                return Err(Errors::not_impl(
+                    format!("Unsupported Transit signing alg {other}"),
+                    None,
+                ));
+            }
+        };
+        let mut opts = SignDataRequestBuilder::default();
+        if let Some(hash) = hash {
+            opts.hash_algorithm(hash);
+        }
+        if let Some(padding) = padding {
+            opts.signature_algorithm(padding);
+        }
+        // JWS marshaling yields the raw `r || s` pair instead of an ASN.1 structure
+        let jws = *alg == Alg::Es256;
+        if jws {
+            opts.marshaling_algorithm(MarshalingAlgorithm::Jws);
+        }
+
+        let input = STANDARD.encode(data);
+        let response = self
+            .retrying("transit sign", || {
+                let mut opts = opts.clone();
+                let input = &input;
+                async move {
+                    transit::data::sign(
+                        &*self.client.read().await,
+                        mount,
+                        name,
+                        input,
+                        Some(&mut opts),
+                    )
+                    .await
+                }
+            })
+            .await
+            .map_err(|e| {
+                Errors::vault(
+                    format!("Transit key {mount}/{name} refused to sign"),
+                    Some(Box::new(e)),
+                )
+            })?;
+
+        // Transit prefixes signatures with the key version: `vault:v<N>:<base64>`
+        let encoded = response.signature.rsplit(':').next().unwrap_or_default();
+        let decoded = match jws {
+            true => URL_SAFE_NO_PAD.decode(encoded),
+            false => STANDARD.decode(encoded),
+        };
+        decoded.map_err(|e| {
+            Errors::vault(
+                format!("Transit key {mount}/{name} returned a malformed signature"),
+                Some(Box::new(e)),
+            )
+        })
+    }
+
+    /// Returns the public half of the latest version of Transit key `name`.
+    pub async fn transit_public_key(&self, mount: &str, name: &str) -> Outcome<PublicKey> {
+        let key = self
+            .retrying("transit key read", || async {
+                transit::key::read(&*self.client.read().await, mount, name).await
+            })
+            .await
+            .map_err(|e| {
+                Errors::vault(
+                    format!("Unable to read Transit key {mount}/{name}"),
+                    Some(Box::new(e)),
+                )
+            })?;
+        let ReadKeyData::Asymmetric(versions) = key.keys else {
+            return Err(Errors::vault(
+                format!("Transit key {mount}/{name} is not an asymmetric key"),
+                None,
+            ));
+        };
+        let entry = versions
+            .get(&key.latest_version.to_string())
+            .ok_or_else(|| {
+                Errors::vault(
+                    format!("Transit key {mount}/{name} has no latest version"),
+                    None,
+                )
+            })?;
+
+        if entry.public_key.starts_with("-----BEGIN") {
+            return PublicKey::try_from_pkcs8_pem(&entry.public_key);
+        }
+        // Ed25519 keys come as the bare base64 encoded point
+        let raw: [u8; 32] = STANDARD
+            .decode(&entry.public_key)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| {
+                Errors::format(
+                    BadFormat::Received,
+                    format!("Transit key {mount}/{name} has a malformed public key"),
+                    None,
+                )
+            })?;
+        let vk = Ed25519VerifyingKey::from_bytes(&raw).map_err(|e| {
+            Errors::format(
+                BadFormat::Received,
+                format!("Transit key {mount}/{name} has an invalid Ed25519 key"),
+                Some(Box::new(e)),
+            )
+        })?;
+        Ok(PublicKey::Ed25519 { vk })
+    }
 }
 
 #[async_trait]
@@ -87,11 +405,20 @@ impl VaultTrait for RealVaultService {
     }
     async fn basic_read(&self, mount: Option<&str>, path: &str) -> Outcome<Value> {
         let mount = mount.unwrap_or(&self.mount);
-        kv2::read(&*self.client, mount, path).await.map_err(|e| {
-            Errors::vault(
+        self.retrying("read", || async {
+            kv2::read(&*self.client.read().await, mount, path).await
+        })
+        .await
+        .map_err(|e| match e {
+            ClientError::APIError { code: 404, .. } => Errors::missing_resource(
+                format!("{mount}/{path}"),
+                format!("No secret stored at {mount}/{path}"),
+                Some(Box::new(e)),
+            ),
+            e => Errors::vault(
                 format!("Error reading from vault at {mount}/{path}"),
                 Some(Box::new(e)),
-            )
+            ),
         })
     }
     async fn write<T>(&self, mount: Option<&str>, path: &str, secret: &T) -> Outcome<()>
@@ -99,18 +426,37 @@ impl VaultTrait for RealVaultService {
         T: Serialize + Send + Sync,
     {
         let mount = mount.unwrap_or(&self.mount);
-        kv2::set(&*self.client, mount, path, secret)
-            .await
-            .map_err(|e| {
-                Errors::vault(
-                    format!("Error writing to vault at {mount}/{path}"),
-                    Some(Box::new(e)),
-                )
-            })?;
+        self.retrying("write", || async {
+            kv2::set(&*self.client.read().await, mount, path, secret).await
+        })
+        .await
+        .map_err(|e| {
+            Errors::vault(
+                format!("Error writing to vault at {mount}/{path}"),
+                Some(Box::new(e)),
+            )
+        })?;
 
         Ok(())
     }
 
+    async fn delete(&self, mount: Option<&str>, path: &str) -> Outcome<()> {
+        let mount = mount.unwrap_or(&self.mount);
+        let deleted = self
+            .retrying("delete", || async {
+                kv2::delete_metadata(&*self.client.read().await, mount, path).await
+            })
+            .await;
+        match deleted {
+            Ok(()) => Ok(()),
+            Err(ClientError::APIError { code: 404, .. }) => Ok(()),
+            Err(e) => Err(Errors::vault(
+                format!("Error deleting vault secret at {mount}/{path}"),
+                Some(Box::new(e)),
+            )),
+        }
+    }
+
     async fn write_all_secrets(&self, map: Option<HashMap<String, Value>>) -> Outcome<()> {
         let to_write = match map {
             Some(m) => m,
@@ -124,7 +470,10 @@ impl VaultTrait for RealVaultService {
     }
 
     async fn check_mount(&self) -> Outcome<()> {
-        let existing_mounts = mount::list(&*self.client)
+        let existing_mounts = self
+            .retrying("mount list", || async {
+                mount::list(&*self.client.read().await).await
+            })
             .await
             .map_err(|e| Errors::vault("Error listing mounts", Some(Box::new(e))))?;
 
@@ -135,7 +484,7 @@ impl VaultTrait for RealVaultService {
             let mut data = EnableEngineRequestBuilder::default();
             let data = data.options(opts);
 
-            mount::enable(&*self.client, &self.mount, "kv", Some(data))
+            mount::enable(&*self.client.read().await, &self.mount, "kv", Some(data))
                 .await
                 .map_err(|e| {
                     Errors::vault(
@@ -159,7 +508,7 @@ impl VaultTrait for RealVaultService {
             .read(None, &self.db_path)
             .await
             .map_err(|e| Errors::vault("Not able to retrieve env files", Some(Box::new(e))))?;
-        Database::connect(config.get_full_db_url(&db_secrets))
+        Database::connect(config.get_connect_options(config.get_full_db_url(&db_secrets)))
             .await
             .map_err(|e| Errors::db("Error connecting to database", Some(Box::new(e))))
     }
@@ -199,11 +548,15 @@ impl RealVaultService {
         to_read: T,
         env: &str,
         parser: S,
+        required: bool,
     ) -> Outcome<()>
     where
         T: AsRef<Path>,
         S: FnOnce(&str) -> Outcome<PemHelper>,
     {
+        if !required && !to_read.as_ref().exists() {
+            return Ok(());
+        }
         let vault_path = expect_from_env(env);
         let pem = read(to_read)?;
         let helper = parser(&pem)?;
@@ -212,10 +565,13 @@ impl RealVaultService {
         Ok(())
     }
 
-    fn secrets(&self) -> Outcome<HashMap<String, Value>> {
+    /// Reads the application secrets kept as files under `secret_path`, keyed by the vault
+    /// path each one is stored at.
+    ///
+    /// The agent key pair may be left out by nodes that bootstrap their own keys; the other
+    /// files but `wallet.json` are required.
+    pub(crate) fn app_secrets(secret_path: &Path) -> Outcome<HashMap<String, Value>> {
         let mut map: HashMap<String, Value> = HashMap::new();
-        let config_path = self.vault_path.join("config");
-        let secret_path = self.vault_path.join("secrets");
 
         Self::insert_json(&mut map, secret_path.join("db.json"), "VAULT_APP_DB", true)?;
         Self::insert_json(
@@ -229,15 +585,24 @@ impl RealVaultService {
             secret_path.join("private_key.pem"),
             "VAULT_APP_PRIV_KEY",
             PemHelper::priv_from_pem,
+            false,
         )?;
         Self::insert_parsed_pem(
             &mut map,
             secret_path.join("public_key.pem"),
             "VAULT_APP_PUB_PKEY",
             PemHelper::pub_from_pem,
+            false,
         )?;
         Self::insert_pem(&mut map, secret_path.join("cert.pem"), "VAULT_APP_CERT")?;
 
+        Ok(map)
+    }
+
+    fn secrets(&self) -> Outcome<HashMap<String, Value>> {
+        let config_path = self.vault_path.join("config");
+        let mut map = Self::app_secrets(&self.vault_path.join("secrets"))?;
+
         Self::insert_pem(
             &mut map,
             config_path.join("vault-cert.pem"),
@@ -257,3 +622,28 @@ impl RealVaultService {
         Ok(map)
     }
 }
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+/// Tells whether Vault could not be reached or is sealed, on standby or overloaded.
+fn is_unavailable(e: &ClientError) -> bool {
+    matches!(
+        e,
+        ClientError::RestClientError { .. }
+            | ClientError::APIError {
+                code: 429 | 500..=599,
+                ..
+            }
+    )
+}
+
+/// Tells whether Vault rejected the token, e.g. lost or expired during an outage.
+fn is_auth_failure(e: &ClientError) -> bool {
+    matches!(
+        e,
+        ClientError::APIError {
+            code: 401 | 403,
+            ..
+        }
+    )
+}
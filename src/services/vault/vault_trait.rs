@@ -49,6 +49,11 @@ pub trait VaultTrait: Send + Sync + 'static {
     where
         T: Serialize + Send + Sync;
 
+    /// Permanently removes the secret stored at the specified vault path.
+    ///
+    /// Deleting a path that holds no secret is not an error.
+    async fn delete(&self, mount: Option<&str>, path: &str) -> Outcome<()>;
+
     // ===== PROVISIONING & CONFIGURATION ==========================================================
 
     /// Seeds multiple secrets into the vault at once.
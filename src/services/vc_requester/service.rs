@@ -0,0 +1,246 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::http::header::{AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::VcRequesterTrait;
+use crate::capabilities::HttpSig;
+use crate::errors::{BadFormat, Errors, Outcome, PetitionFailure};
+use crate::services::client::ClientTrait;
+use crate::services::signer::{SignerTrait, VaultKeySigner};
+use crate::services::vault::VaultService;
+use crate::types::gnap::ContinueRequest;
+use crate::types::gnap::access_token::{AccessToken, RotatedToken};
+use crate::types::gnap::grant_request::GrantRequest;
+use crate::types::gnap::grant_request::client::{Client, ClientKey, KeyProof};
+use crate::types::gnap::grant_response::GrantResponse;
+use crate::types::http::HttpBody;
+use crate::types::keys::KeySource;
+use crate::types::wallet::{CredentialRefreshEvent, Identity, KeyRef};
+use crate::utils::{ResponseExt, http_client};
+
+/// Seconds to back off when a `429` comes without a usable `Retry-After`.
+const DEFAULT_RETRY_AFTER: u64 = 5;
+
+/// Outbound GNAP client signing its calls with the node's active identity.
+pub struct VcRequesterService {
+    identity: Arc<RwLock<Identity>>,
+    signer: Arc<dyn SignerTrait>,
+}
+
+impl VcRequesterService {
+    pub fn new(vault: Arc<VaultService>, identity: Arc<RwLock<Identity>>) -> Self {
+        Self {
+            identity,
+            signer: Arc::new(VaultKeySigner::new(vault)),
+        }
+    }
+
+    /// Signs requests through `signer`, e.g. a Vault Transit or KMS signer keeping the private
+    /// keys out of the process, instead of reading them from the vault.
+    pub fn with_signer(mut self, signer: Arc<dyn SignerTrait>) -> Self {
+        self.signer = signer;
+        self
+    }
+}
+
+#[async_trait]
+impl VcRequesterTrait for VcRequesterService {
+    async fn client(&self) -> Outcome<Client> {
+        let key = self
+            .signer
+            .public_key(self.key_ref().await.internal())
+            .await?;
+        let did = self.identity.read().await.did().id().to_string();
+        Ok(Client {
+            key: ClientKey::jwk(KeyProof::HttpSig, key.public_jwk()),
+            class_id: Some(did),
+            display: None,
+        })
+    }
+
+    async fn request_grant(
+        &self,
+        grant_endpoint: &str,
+        request: &GrantRequest,
+    ) -> Outcome<GrantResponse> {
+        info!("Requesting grant from {grant_endpoint}");
+        let body = serde_json::to_string(request)?;
+        self.send(grant_endpoint, body, None).await
+    }
+
+    async fn continue_grant(
+        &self,
+        continue_uri: &str,
+        continue_token: &str,
+        interact_ref: Option<&str>,
+    ) -> Outcome<GrantResponse> {
+        info!("Continuing grant at {continue_uri}");
+        let body = match interact_ref {
+            Some(interact_ref) => serde_json::to_string(&ContinueRequest {
+                interact_ref: interact_ref.to_string(),
+            })?,
+            None => String::new(),
+        };
+        let authorization = format!("GNAP {continue_token}");
+        self.send(continue_uri, body, Some(&authorization)).await
+    }
+
+    async fn rotate_token(&self, manage_uri: &str, manage_token: &str) -> Outcome<AccessToken> {
+        info!("Rotating access token at {manage_uri}");
+        let authorization = format!("GNAP {manage_token}");
+        let headers = self
+            .signed_headers(manage_uri, "", Some(&authorization))
+            .await?;
+        let res = http_client()
+            .post(manage_uri, Some(headers), HttpBody::Raw(String::new()))
+            .await?;
+        if !res.status().is_success() {
+            return Err(Errors::petition(
+                manage_uri,
+                "POST",
+                Some(res.status()),
+                PetitionFailure::HttpStatus(res.status()),
+                "Authorization server refused to rotate the access token",
+                None,
+            ));
+        }
+        let rotated: RotatedToken = res.parse_json().await?;
+        Ok(rotated.access_token)
+    }
+
+    async fn notify_refresh(
+        &self,
+        webhooks: &[String],
+        event: &CredentialRefreshEvent,
+    ) -> Outcome<()> {
+        let body = serde_json::to_string(event)?;
+        for webhook in webhooks {
+            info!(
+                "Notifying webhook {webhook} of credential refresh {}",
+                event.vc_id
+            );
+            let result: Outcome<()> = async {
+                let headers = self.signed_headers(webhook, &body, None).await?;
+                http_client()
+                    .post(webhook, Some(headers), HttpBody::Raw(body.clone()))
+                    .await?;
+                Ok(())
+            }
+            .await;
+
+            if let Err(e) = result {
+                e.log();
+            }
+        }
+        Ok(())
+    }
+}
+
+// ===== Internal helpers ======================================================
+
+impl VcRequesterService {
+    /// Returns the key bound to the active identity.
+    async fn key_ref(&self) -> KeyRef {
+        self.identity.read().await.key_ref().clone()
+    }
+
+    /// Signs and posts `body`, reading the answer as a grant response.
+    ///
+    /// GNAP reports refusals through an error body on a client error status, so only
+    /// server errors are turned into petition failures. A `429` becomes a rate limit error
+    /// carrying the authority's `Retry-After`.
+    async fn send(
+        &self,
+        url: &str,
+        body: String,
+        authorization: Option<&str>,
+    ) -> Outcome<GrantResponse> {
+        let headers = self.signed_headers(url, &body, authorization).await?;
+        let res = http_client()
+            .post(url, Some(headers), HttpBody::Raw(body))
+            .await?;
+        if res.status() == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = res
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(DEFAULT_RETRY_AFTER);
+            return Err(Errors::rate_limit(
+                format!("Authorization server at {url} asked to slow down"),
+                None,
+            )
+            .with_retry_after(retry_after));
+        }
+        if res.status().is_server_error() {
+            return Err(Errors::petition(
+                url,
+                "POST",
+                Some(res.status()),
+                PetitionFailure::HttpStatus(res.status()),
+                "Authorization server failed to answer the grant request",
+                None,
+            ));
+        }
+        res.parse_json().await
+    }
+
+    /// Builds the `httpsig` headers of a JSON POST of `body` to `url`.
+    async fn signed_headers(
+        &self,
+        url: &str,
+        body: &str,
+        authorization: Option<&str>,
+    ) -> Outcome<HeaderMap> {
+        let key_ref = self.key_ref().await;
+        let key = self.signer.public_key(key_ref.internal()).await?;
+        let alg = self.signer.alg(key_ref.internal()).await?;
+        let key_source = KeySource::PublicKey(key);
+        let draft = HttpSig::prepare(
+            &key_source,
+            alg.clone(),
+            "POST",
+            url,
+            body.as_bytes(),
+            authorization,
+        );
+        let signature = self
+            .signer
+            .sign_bytes(key_ref.internal(), draft.signature_base(), alg)
+            .await?;
+        let mut headers = draft.finish(&signature)?;
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        if let Some(authorization) = authorization {
+            let value = HeaderValue::from_str(authorization).map_err(|e| {
+                Errors::format(
+                    BadFormat::Sent,
+                    "Continuation token is not a valid header value",
+                    Some(Box::new(e)),
+                )
+            })?;
+            headers.insert(AUTHORIZATION, value);
+        }
+        Ok(headers)
+    }
+}
@@ -0,0 +1,73 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::errors::Outcome;
+use crate::types::gnap::access_token::AccessToken;
+use crate::types::gnap::grant_request::GrantRequest;
+use crate::types::gnap::grant_request::client::Client;
+use crate::types::gnap::grant_response::GrantResponse;
+use crate::types::wallet::CredentialRefreshEvent;
+use async_trait::async_trait;
+
+/// Outbound GNAP client used by the holder to ask remote authorities for credentials.
+///
+/// Every call is signed with the active identity key (`httpsig` proofing), the same key
+/// advertised in the [`Client`] section of the grant request.
+#[async_trait]
+pub trait VcRequesterTrait: Send + Sync + 'static {
+    /// Describes the local agent as a GNAP client instance.
+    async fn client(&self) -> Outcome<Client>;
+
+    /// Posts `request` to the grant endpoint of a remote authority.
+    ///
+    /// # Errors
+    /// Returns a petition error when the authority answers with a server error or the
+    /// response cannot be read as a GNAP grant response.
+    async fn request_grant(
+        &self,
+        grant_endpoint: &str,
+        request: &GrantRequest,
+    ) -> Outcome<GrantResponse>;
+
+    /// Calls the continuation URI of a pending grant.
+    ///
+    /// `interact_ref` is sent once the finish callback arrived; without it the call is a
+    /// plain status poll. Polling faster than the authority allows yields a rate limit error
+    /// carrying the seconds to [wait](crate::errors::Errors::retry_after).
+    async fn continue_grant(
+        &self,
+        continue_uri: &str,
+        continue_token: &str,
+        interact_ref: Option<&str>,
+    ) -> Outcome<GrantResponse>;
+
+    /// Rotates an access token through its token management URI (RFC 9635 §6.1).
+    ///
+    /// # Errors
+    /// Returns a petition error when the authority refuses the rotation, in which case the
+    /// grant has to be requested again.
+    async fn rotate_token(&self, manage_uri: &str, manage_token: &str) -> Outcome<AccessToken>;
+
+    /// Pushes the outcome of a credential renewal to every webhook, signed like a grant call.
+    ///
+    /// Delivery failures are logged, not returned.
+    async fn notify_refresh(
+        &self,
+        webhooks: &[String],
+        event: &CredentialRefreshEvent,
+    ) -> Outcome<()>;
+}
@@ -16,6 +16,10 @@
  */
 
 pub mod oid4vp_draft20;
+mod response_key;
+mod status_list_cache;
 mod verifier_trait;
 
+pub use response_key::load_response_key;
+pub use status_list_cache::StatusListCache;
 pub use verifier_trait::VerifierTrait;
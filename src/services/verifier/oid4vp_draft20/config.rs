@@ -15,14 +15,35 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use crate::config::traits::HostsConfigTrait;
-use crate::config::types::CommonHostsConfig;
+use std::time::Duration;
+
+use crate::capabilities::EcdhEsKey;
+use crate::config::traits::{HostsConfigTrait, VerifyReqConfigTrait};
+use crate::config::types::{CommonHostsConfig, StatusCheckMode};
 use crate::types::vcs::VcType;
+use crate::types::verification::input_descriptor::DescriptorConstraint;
+use crate::types::verification::{
+    AudienceTemplate, QueryLanguage, RedactionProfile, ResponseEncryption, ResponseMode, VpFormat,
+    VpPolicy,
+};
+use crate::utils::DEFAULT_CLOCK_SKEW_LEEWAY;
 
 pub struct VerifierConfig {
     hosts: CommonHostsConfig,
     api_path: String,
     requested_vcs: Vec<VcType>,
+    policy: Option<VpPolicy>,
+    status_check: StatusCheckMode,
+    response_mode: ResponseMode,
+    constraints: Vec<DescriptorConstraint>,
+    query_language: QueryLanguage,
+    vp_formats: Vec<VpFormat>,
+    response_encryption: ResponseEncryption,
+    static_response_key: Option<EcdhEsKey>,
+    session_ttl: Duration,
+    redaction: Option<RedactionProfile>,
+    clock_leeway: Duration,
+    accepted_audiences: Vec<AudienceTemplate>,
 }
 
 impl VerifierConfig {
@@ -31,15 +52,157 @@ impl VerifierConfig {
             hosts,
             api_path,
             requested_vcs,
+            policy: None,
+            status_check: StatusCheckMode::default(),
+            response_mode: ResponseMode::default(),
+            constraints: Vec::new(),
+            query_language: QueryLanguage::default(),
+            vp_formats: VpFormat::defaults(),
+            response_encryption: ResponseEncryption::default(),
+            static_response_key: None,
+            session_ttl: Duration::from_secs(600),
+            redaction: None,
+            clock_leeway: DEFAULT_CLOCK_SKEW_LEEWAY,
+            accepted_audiences: Vec::new(),
         }
     }
 
+    /// Applies the deployment's verification requirements, clock leeway included.
+    ///
+    /// The static response key lives in the vault and is set with
+    /// [`Self::with_static_response_key`].
+    pub fn from_verify_req(
+        hosts: CommonHostsConfig,
+        api_path: String,
+        verify_req: &impl VerifyReqConfigTrait,
+    ) -> Self {
+        let mut config = Self::new(hosts, api_path, verify_req.get_requested_vcs().to_vec())
+            .with_policy(verify_req.get_policy())
+            .with_status_check(verify_req.get_status_check())
+            .with_response_mode(verify_req.verify_req_config().response_mode)
+            .with_constraints(verify_req.get_constraints().to_vec())
+            .with_query_language(verify_req.get_query_language())
+            .with_vp_formats(verify_req.get_vp_formats().to_vec())
+            .with_response_encryption(verify_req.get_response_encryption())
+            .with_session_ttl(verify_req.get_session_ttl())
+            .with_clock_leeway(verify_req.get_clock_leeway())
+            .with_accepted_audiences(verify_req.get_accepted_audiences().to_vec());
+        if let Some(profile) = verify_req.get_redaction_profile() {
+            config = config.with_redaction_profile(profile.clone());
+        }
+        config
+    }
+
+    /// Requests the types referenced by the policy and evaluates presentations against it.
+    pub fn with_policy(mut self, policy: VpPolicy) -> Self {
+        self.requested_vcs = policy.vc_types();
+        self.policy = Some(policy);
+        self
+    }
+
+    pub fn with_status_check(mut self, status_check: StatusCheckMode) -> Self {
+        self.status_check = status_check;
+        self
+    }
+
+    pub fn with_response_mode(mut self, response_mode: ResponseMode) -> Self {
+        self.response_mode = response_mode;
+        self
+    }
+
+    pub fn with_constraints(mut self, constraints: Vec<DescriptorConstraint>) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    pub fn with_query_language(mut self, query_language: QueryLanguage) -> Self {
+        self.query_language = query_language;
+        self
+    }
+
+    /// Accepts `ldp_vp` presentations, `jwt_vp_json` ones, or both.
+    pub fn with_vp_formats(mut self, vp_formats: Vec<VpFormat>) -> Self {
+        self.vp_formats = vp_formats;
+        self
+    }
+
+    pub fn with_response_encryption(mut self, response_encryption: ResponseEncryption) -> Self {
+        self.response_encryption = response_encryption;
+        self
+    }
+
+    /// Encrypts responses to a long-lived agent key instead of a per-exchange ephemeral one.
+    pub fn with_static_response_key(mut self, key: EcdhEsKey) -> Self {
+        self.static_response_key = Some(key);
+        self
+    }
+
+    pub fn with_session_ttl(mut self, session_ttl: Duration) -> Self {
+        self.session_ttl = session_ttl;
+        self
+    }
+
+    pub fn with_redaction_profile(mut self, profile: RedactionProfile) -> Self {
+        self.redaction = Some(profile);
+        self
+    }
+
+    /// Tolerates `clock_leeway` of drift on `nbf`/`iat`/`exp` and VC validity windows.
+    pub fn with_clock_leeway(mut self, clock_leeway: Duration) -> Self {
+        self.clock_leeway = clock_leeway;
+        self
+    }
+
+    /// Also accepts presentations bound to any of `audiences`, rendered for the session.
+    pub fn with_accepted_audiences(mut self, audiences: Vec<AudienceTemplate>) -> Self {
+        self.accepted_audiences = audiences;
+        self
+    }
+
     pub fn get_requested_vcs(&self) -> &[VcType] {
         &self.requested_vcs
     }
+    pub fn get_policy(&self) -> VpPolicy {
+        self.policy
+            .clone()
+            .unwrap_or_else(|| VpPolicy::all_of(&self.requested_vcs))
+    }
     pub fn get_api_path(&self) -> &str {
         &self.api_path
     }
+    pub fn get_status_check(&self) -> StatusCheckMode {
+        self.status_check
+    }
+    pub fn get_response_mode(&self) -> ResponseMode {
+        self.response_mode
+    }
+    pub fn get_constraints(&self) -> &[DescriptorConstraint] {
+        &self.constraints
+    }
+    pub fn get_query_language(&self) -> QueryLanguage {
+        self.query_language
+    }
+    pub fn get_vp_formats(&self) -> &[VpFormat] {
+        &self.vp_formats
+    }
+    pub fn get_response_encryption(&self) -> ResponseEncryption {
+        self.response_encryption
+    }
+    pub fn get_static_response_key(&self) -> Option<&EcdhEsKey> {
+        self.static_response_key.as_ref()
+    }
+    pub fn get_session_ttl(&self) -> Duration {
+        self.session_ttl
+    }
+    pub fn get_redaction_profile(&self) -> Option<&RedactionProfile> {
+        self.redaction.as_ref()
+    }
+    pub fn get_clock_leeway(&self) -> Duration {
+        self.clock_leeway
+    }
+    pub fn get_accepted_audiences(&self) -> &[AudienceTemplate] {
+        &self.accepted_audiences
+    }
 }
 
 impl HostsConfigTrait for VerifierConfig {
@@ -15,22 +15,33 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::time::Duration;
+
 use async_trait::async_trait;
 use chrono::Utc;
-use tracing::info;
+use serde_json::{Map, Value, json};
+use tracing::{info, warn};
 use urlencoding::encode;
 
 use super::super::VerifierTrait;
 use super::VerifierConfig;
-use crate::capabilities::{Did, Kid, Verifier};
+use crate::capabilities::{Did, DidResolver, EcdhEsKey, JWE_ALG, JWE_ENC_SUPPORTED, Kid, Verifier};
 use crate::config::traits::HostsConfigTrait;
-use crate::config::types::HostType;
+use crate::config::types::{HostType, StatusCheckMode};
 use crate::data::entities::received::verification::{Model, Plan};
 use crate::errors::{BadFormat, Errors, Outcome};
-use crate::types::jwt::{Jwt, VCJwtClaims, VPJwtClaims};
-use crate::types::vcs::{VPDef, W3cDataModelVersion};
-use crate::types::verification::VerificationStatus;
-use crate::utils::{has_expired, is_active};
+use crate::services::verifier::StatusListCache;
+use crate::types::crypto::Proof;
+use crate::types::jwt::{Jwt, SD_JWT_VC_TYP, SdJwt, SdJwtVcClaims, VCJwtClaims, VPJwtClaims};
+use crate::types::keys::Alg;
+use crate::types::vcs::doc::{VCStatus, VcDocument};
+use crate::types::vcs::{VPDef, VcType, W3cDataModelVersion};
+use crate::types::verification::{
+    AudienceTemplate, AuthorizationResponse, DcqlQuery, PresentationSubmission, QueryLanguage,
+    ResponseEncryption, ResponseMode, VerificationStatus, VpFormat, VpPolicy, credential_query_id,
+};
+use crate::types::vps::LdpVp;
+use crate::utils::{encode_url_safe_no_pad, has_expired_with, is_active_with};
 
 /// Verifiable Presentation verification service backed by an OpenID4VP implementation.
 ///
@@ -39,21 +50,48 @@ use crate::utils::{has_expired, is_active};
 /// defined via the DIF Presentation Exchange.
 pub struct VerifierService {
     config: VerifierConfig,
+    status_lists: StatusListCache,
+    resolver: DidResolver,
 }
 
 impl VerifierService {
     pub fn new(config: VerifierConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            status_lists: StatusListCache::new(),
+            resolver: DidResolver::default(),
+        }
+    }
+
+    /// Resolves holder, issuer and status list DIDs through `resolver`, e.g. one with a
+    /// Universal Resolver fallback.
+    pub fn with_did_resolver(mut self, resolver: DidResolver) -> Self {
+        self.status_lists = self.status_lists.with_did_resolver(resolver.clone());
+        self.resolver = resolver;
+        self
     }
 }
 
 #[async_trait]
 impl VerifierTrait for VerifierService {
     fn build_vp_plan(&self, id: &str) -> Outcome<Plan> {
+        self.build_vp_plan_with_mode(id, self.config.get_response_mode())
+    }
+
+    fn build_vp_plan_with_mode(&self, id: &str, response_mode: ResponseMode) -> Outcome<Plan> {
         info!("Managing OIDC4VP");
 
-        let host_url = self.config.get_host(HostType::Http);
-        let client_id = format!("{}{}/verifier/verify", host_url, self.config.get_api_path());
+        if response_mode == ResponseMode::Query {
+            return Err(Errors::validation(
+                "The query response mode exposes the vp_token in URLs, use fragment instead",
+                None,
+            ));
+        }
+        let client_id = AudienceTemplate::default().render(
+            &self.config.get_external_host(HostType::Http),
+            self.config.get_api_path(),
+            response_endpoint(response_mode),
+        );
         let requested_vcs = self.config.get_requested_vcs();
         if requested_vcs.is_empty() {
             return Err(Errors::unauthorized(
@@ -62,119 +100,808 @@ impl VerifierTrait for VerifierService {
             ));
         }
 
+        // JARM is only offered for `direct_post`; redirect based modes stay plain.
+        let response_key = match (self.config.get_response_encryption(), response_mode) {
+            (ResponseEncryption::Disabled, _) => None,
+            (_, ResponseMode::DirectPost) => Some(match self.config.get_static_response_key() {
+                Some(key) => key.kid(),
+                None => EcdhEsKey::generate().private_jwk(),
+            }),
+            _ => None,
+        };
+
         Ok(Plan {
             id: id.to_string(),
             audience: client_id,
             vc_type: requested_vcs.to_vec(),
+            purpose: None,
+            response_mode,
+            query_language: self.config.get_query_language(),
+            response_key,
+            ttl_secs: self.config.get_session_ttl().as_secs() as i64,
+            webhook_url: None,
         })
     }
 
-    fn generate_verification_uri(&self, model: &Model) -> String {
+    fn generate_verification_uri(&self, model: &Model) -> Outcome<String> {
         info!("Generating verification exchange URI");
 
-        let host_url = format!(
-            "{}{}/verifier",
-            self.config.get_host(HostType::Http),
-            self.config.get_api_path()
-        );
-        let pd_uri = format!("{}/pd/{}", host_url, model.state);
-        let response_uri = format!("{}/verify/{}", host_url, model.state);
-
-        let uri = format!(
-            "openid4vp://authorize\
-             ?response_type=vp_token\
-             &client_id={}\
-             &response_mode=direct_post\
-             &presentation_definition_uri={}\
-             &client_id_scheme=redirect_uri\
-             &nonce={}\
-             &response_uri={}",
-            encode(&model.audience),
-            encode(&pd_uri),
-            model.nonce,
-            encode(&response_uri),
-        );
+        let query: Vec<String> = self
+            .authorization_params(model)?
+            .into_iter()
+            .map(|(name, value)| match value {
+                Value::String(value) => format!("{name}={}", encode(&value)),
+                value => format!("{name}={}", encode(&value.to_string())),
+            })
+            .collect();
+        let uri = format!("openid4vp://authorize?{}", query.join("&"));
         info!("Uri generated successfully: {uri}");
-        uri
+        Ok(uri)
+    }
+
+    fn generate_request_uri(&self, model: &Model) -> String {
+        let request_uri = format!("{}/request/{}", self.verifier_url(), model.id);
+        format!(
+            "openid4vp://authorize?client_id={}&request_uri={}",
+            encode(&model.audience),
+            encode(&request_uri)
+        )
+    }
+
+    fn generate_request_object(&self, model: &Model) -> Outcome<String> {
+        info!("Generating request object of verification {}", model.id);
+
+        // `redirect_uri` clients cannot sign their requests, so the object is an unsecured JWT.
+        let header = json!({ "alg": "none", "typ": "oauth-authz-req+jwt" });
+        let claims = Value::Object(self.authorization_params(model)?);
+        Ok(format!(
+            "{}.{}.",
+            encode_url_safe_no_pad(header.to_string()),
+            encode_url_safe_no_pad(claims.to_string())
+        ))
     }
 
     fn generate_vpd(&self, verification: &Model) -> Outcome<VPDef> {
         info!("Generating VP definition");
 
-        Ok(VPDef::new(
+        VPDef::with_constraints(
             &verification.id,
             &verification.vc_type,
             W3cDataModelVersion::default(),
-        ))
+            self.config.get_constraints(),
+        )
+        .with_formats(self.config.get_vp_formats())
+        .with_policy(&self.config.get_policy())
+        .content_addressed()
+    }
+
+    fn generate_dcql(&self, verification: &Model) -> Outcome<DcqlQuery> {
+        info!("Generating DCQL query");
+
+        Ok(
+            DcqlQuery::new(&verification.vc_type, self.config.get_constraints())
+                .with_policy(&self.config.get_policy()),
+        )
     }
 
-    async fn verify_all(&self, model: &mut Model, vp_token: &str) -> Outcome<()> {
+    fn decrypt_response(&self, model: &Model, response: &str) -> Outcome<AuthorizationResponse> {
+        info!("Decrypting authorization response");
+
+        let key = self.response_key(model)?.ok_or_else(|| {
+            Errors::forbidden("Verification does not expect an encrypted response", None)
+        })?;
+        let plaintext = key.decrypt(response)?;
+        AuthorizationResponse::from_decrypted(&plaintext)
+    }
+
+    fn accepts_plain_response(&self, model: &Model) -> bool {
+        model.response_key.is_none()
+            || self.config.get_response_encryption() != ResponseEncryption::Required
+    }
+
+    async fn verify_all(
+        &self,
+        model: &mut Model,
+        vp_token: &str,
+        presentation_submission: Option<&str>,
+    ) -> Outcome<()> {
         info!("Verifying all");
+        if model.status == VerificationStatus::Pending {
+            model.transition(VerificationStatus::Presented)?;
+        }
+        model.vpt = Some(vp_token.to_string());
 
         let result: Outcome<()> = async {
-            let (vcs, holder_did) = self.verify_vp(model, vp_token).await?;
+            // The session fixed the query language, and with it the shape of the vp_token.
+            let keyed = model.query_language == QueryLanguage::Dcql;
+            if !keyed {
+                let ldp = LdpVp::is_ldp_vp(vp_token);
+                let format = if ldp {
+                    VpFormat::LdpVp
+                } else if VpFormat::is_sd_jwt(vp_token) {
+                    VpFormat::SdJwtVc
+                } else {
+                    VpFormat::JwtVpJson
+                };
+                if !self.config.get_vp_formats().contains(&format) {
+                    return Err(Errors::security(
+                        format!("Presentation format '{format}' was not requested"),
+                        None,
+                    ));
+                }
+            }
+            let presentations = match keyed {
+                true => split_keyed_vp_token(model, vp_token)?
+                    .into_iter()
+                    .map(|(vc_type, presentation)| (Some(vc_type), presentation))
+                    .collect(),
+                false => vec![(None, vp_token.to_string())],
+            };
 
-            for vc in vcs {
-                self.verify_vc(&vc, &holder_did).await?;
-                model.vcs.push(vc)
+            let mut holder: Option<Did> = None;
+            let mut presented: Vec<VcType> = Vec::new();
+            for (queried, presentation) in presentations {
+                // DCQL responses carry no presentation definition id to match against.
+                let (vcs, holder_did) = self.verify_vp(model, &presentation, !keyed).await?;
+                if !keyed {
+                    let submission = presentation_submission.ok_or_else(|| {
+                        Errors::format(
+                            BadFormat::Received,
+                            "Presentation Exchange responses require a presentation_submission",
+                            None,
+                        )
+                    })?;
+                    let submission = PresentationSubmission::parse(submission)?;
+                    let vpd = self.generate_vpd(model)?;
+                    if model.pd_id.as_ref().is_some_and(|pd_id| *pd_id != vpd.id) {
+                        return Err(Errors::security(
+                            "Presentation definition was superseded during the exchange",
+                            None,
+                        ));
+                    }
+                    validate_submission(&vpd, &submission, &presentation)?;
+                }
+                if holder.as_ref().is_some_and(|h| h.id() != holder_did.id()) {
+                    return Err(Errors::security(
+                        "Presentations were not issued by the same holder",
+                        None,
+                    ));
+                }
+
+                let mut types = Vec::new();
+                for vc in vcs {
+                    types.extend(self.verify_vc(&vc, &holder_did).await?);
+                    model.vcs.push(vc)
+                }
+                // A DCQL entry only answers the credential query it is keyed by.
+                if let Some(queried) = queried {
+                    if !types.contains(&queried) {
+                        return Err(Errors::security(
+                            format!(
+                                "Presentation for credential query '{}' does not hold a {queried} credential",
+                                credential_query_id(&queried)
+                            ),
+                            None,
+                        ));
+                    }
+                }
+                presented.extend(types);
+                holder = Some(holder_did);
             }
-            Ok(())
+            validate_policy(&self.config.get_policy(), &presented)
         }
         .await;
 
-        model.ended_at = Some(Utc::now());
-        model.status = match &result {
-            Ok(()) => {
-                info!("VP & VC validated successfully");
-                VerificationStatus::Verified
-            }
-            Err(_) => VerificationStatus::Failed,
-        };
-
+        if result.is_ok() {
+            info!("VP & VC validated successfully");
+        }
         result
     }
+
+    fn redact(&self, model: &mut Model) {
+        if let Some(profile) = self.config.get_redaction_profile() {
+            info!("Redacting verification with profile {}", profile.name);
+            model.redact(profile);
+        }
+    }
 }
 
 // ===== Internal helpers ======================================================
 
 impl VerifierService {
-    async fn verify_vp(&self, model: &mut Model, vp_token: &str) -> Outcome<(Vec<String>, Did)> {
+    fn verifier_url(&self) -> String {
+        format!(
+            "{}{}/verifier",
+            self.config.get_external_host(HostType::Http),
+            self.config.get_api_path()
+        )
+    }
+
+    /// Authorization request parameters of the exchange, shared by the by-value URI and the
+    /// request object served by reference.
+    fn authorization_params(&self, model: &Model) -> Outcome<Map<String, Value>> {
+        let mut params = Map::new();
+        params.insert("response_type".to_string(), json!("vp_token"));
+        params.insert("client_id".to_string(), json!(model.audience));
+        params.insert("client_id_scheme".to_string(), json!("redirect_uri"));
+
+        // DCQL queries travel inline, Presentation Exchange definitions are fetched by reference.
+        match model.query_language {
+            QueryLanguage::PresentationExchange => {
+                let pd_id = match &model.pd_id {
+                    Some(pd_id) => pd_id.clone(),
+                    None => self.generate_vpd(model)?.id,
+                };
+                let pd_uri = format!("{}/pd/{}", self.verifier_url(), pd_id);
+                params.insert("presentation_definition_uri".to_string(), json!(pd_uri));
+            }
+            QueryLanguage::Dcql => {
+                let query = DcqlQuery::new(&model.vc_type, self.config.get_constraints())
+                    .with_policy(&self.config.get_policy());
+                params.insert("dcql_query".to_string(), serde_json::to_value(&query)?);
+            }
+        }
+
+        match self.encryption_metadata(model) {
+            Some(metadata) => {
+                let response_mode = format!("{}.jwt", model.response_mode);
+                params.insert("response_mode".to_string(), json!(response_mode));
+                params.insert("client_metadata".to_string(), metadata);
+            }
+            None => {
+                let response_mode = model.response_mode.to_string();
+                params.insert("response_mode".to_string(), json!(response_mode));
+            }
+        }
+        params.insert("nonce".to_string(), json!(model.nonce));
+        params.insert("state".to_string(), json!(model.state));
+
+        // The audience already is the per-state endpoint the wallet must answer to, which with
+        // `client_id_scheme=redirect_uri` has to equal the client_id.
+        let return_param = match model.response_mode {
+            ResponseMode::DirectPost => "response_uri",
+            ResponseMode::Fragment | ResponseMode::Query => "redirect_uri",
+        };
+        params.insert(return_param.to_string(), json!(model.audience));
+        Ok(params)
+    }
+
+    /// Audiences a presentation for `model` may be bound to: the session one first, then the
+    /// configured alternatives rendered for the session.
+    fn accepted_audiences(&self, model: &Model) -> Vec<String> {
+        let host = self.config.get_external_host(HostType::Http);
+        let endpoint = response_endpoint(model.response_mode);
+        let mut audiences = vec![model.audience.clone()];
+        for template in self.config.get_accepted_audiences() {
+            let audience = template.render(&host, self.config.get_api_path(), endpoint);
+            let audience = AudienceTemplate::bind_state(&audience, &model.state);
+            if !audiences.contains(&audience) {
+                audiences.push(audience);
+            }
+        }
+        audiences
+    }
+
+    async fn verify_vp(
+        &self,
+        model: &mut Model,
+        vp_token: &str,
+        check_id: bool,
+    ) -> Outcome<(Vec<String>, Did)> {
+        if LdpVp::is_ldp_vp(vp_token) {
+            return self.verify_ldp_vp(model, vp_token, check_id).await;
+        }
+        if VpFormat::is_sd_jwt(vp_token) {
+            return self.verify_sd_jwt_vp(model, vp_token).await;
+        }
         info!("Verifying vp");
-        model.vpt = Some(vp_token.to_string());
 
         let jwt = Jwt::parse(vp_token)?;
+        let audiences = self.accepted_audiences(model);
+        let audiences: Vec<&str> = audiences.iter().map(String::as_str).collect();
         let (holder_kid, claims) =
-            Verifier::verify_enveloped::<VPJwtClaims>(&jwt, Some(&model.audience)).await?;
+            Verifier::verify_enveloped_for::<VPJwtClaims>(&self.resolver, &jwt, &audiences).await?;
 
         validate_vp_holder(&claims, &holder_kid)?;
         model.holder = Some(holder_kid.did().id().to_string());
-        validate_vp_id(&claims, model)?;
+        if check_id {
+            validate_vp_id(&claims, model)?;
+        }
         validate_nonce(&claims, model)?;
 
         info!("VP verification successful");
         Ok((claims.vp.verifiable_credential, holder_kid.did().to_owned()))
     }
 
-    async fn verify_vc(&self, vc_token: &str, holder_did: &Did) -> Outcome<()> {
+    /// Verifies a JSON-LD presentation secured with a Data Integrity proof.
+    ///
+    /// The proof must be an `authentication` proof bound to the exchange nonce (`challenge`)
+    /// and audience (`domain`), made by the holder of the presentation.
+    async fn verify_ldp_vp(
+        &self,
+        model: &mut Model,
+        vp_token: &str,
+        check_id: bool,
+    ) -> Outcome<(Vec<String>, Did)> {
+        info!("Verifying ldp vp");
+
+        let vp = LdpVp::parse(vp_token)?;
+        let (_, proof, holder_kid) =
+            Verifier::verify_data_integrity(&self.resolver, vp.document()).await?;
+
+        validate_ldp_vp_proof(&proof, model, &self.accepted_audiences(model))?;
+        check_eq_opt(vp.holder(), holder_kid.did().id(), "VP holder & kid")?;
+        model.holder = Some(holder_kid.did().id().to_string());
+        if check_id && vp.id() != Some(model.id.as_str()) {
+            return Err(Errors::security("Invalid id, it does not match", None));
+        }
+
+        info!("LDP VP verification successful");
+        Ok((vp.credentials(), holder_kid.did().to_owned()))
+    }
+
+    /// Verifies an SD-JWT VC presented on its own.
+    ///
+    /// The Key Binding JWT stands in for the VP: it must be signed by the holder bound in
+    /// `cnf` and carry the exchange audience and nonce. SD-JWT presentations have no id to
+    /// match against the exchange.
+    async fn verify_sd_jwt_vp(
+        &self,
+        model: &mut Model,
+        vp_token: &str,
+    ) -> Outcome<(Vec<String>, Did)> {
+        info!("Verifying sd-jwt presentation");
+
+        let vp_token = vp_token.trim();
+        let sd_jwt = SdJwt::parse(vp_token)?;
+        let audiences = self.accepted_audiences(model);
+        let audiences: Vec<&str> = audiences.iter().map(String::as_str).collect();
+        let (_, holder, _) = Verifier::verify_sd_jwt(
+            &self.resolver,
+            &sd_jwt,
+            &audiences,
+            &model.nonce,
+            self.config.get_clock_leeway(),
+        )
+        .await?;
+
+        model.holder = Some(holder.id().to_string());
+
+        info!("SD-JWT key binding verification successful");
+        Ok((vec![vp_token.to_string()], holder))
+    }
+
+    /// Resolves the key the response of the exchange must be encrypted to.
+    ///
+    /// `response_key` holds either the private JWK of an ephemeral key or the kid of the
+    /// static agent key.
+    fn response_key(&self, model: &Model) -> Outcome<Option<EcdhEsKey>> {
+        let Some(stored) = model.response_key.as_deref() else {
+            return Ok(None);
+        };
+        if stored.starts_with('{') {
+            return EcdhEsKey::from_private_jwk(stored).map(Some);
+        }
+        match self.config.get_static_response_key() {
+            Some(key) if key.kid() == stored => Ok(Some(key.clone())),
+            _ => Err(Errors::security(
+                "Response encryption key of the exchange is no longer available",
+                None,
+            )),
+        }
+    }
+
+    /// `client_metadata` advertising the response encryption key of the exchange.
+    fn encryption_metadata(&self, model: &Model) -> Option<Value> {
+        let key = match self.response_key(model) {
+            Ok(key) => key?,
+            Err(e) => {
+                warn!("Unable to load response encryption key, requesting a plain response: {e}");
+                return None;
+            }
+        };
+        let algs: Vec<String> = Alg::supported().iter().map(|a| a.to_string()).collect();
+        let mut vp_formats = json!({
+            "jwt_vp_json": { "alg": algs },
+            "jwt_vc_json": { "alg": algs },
+        });
+        if self.config.get_vp_formats().contains(&VpFormat::LdpVp) {
+            let ldp = json!({
+                "proof_type": [
+                    "DataIntegrityProof",
+                    "Ed25519Signature2020",
+                    "EcdsaSecp256r1Signature2019"
+                ]
+            });
+            vp_formats["ldp_vp"] = ldp.clone();
+            vp_formats["ldp_vc"] = ldp;
+        }
+        if self.config.get_vp_formats().contains(&VpFormat::SdJwtVc) {
+            vp_formats["vc+sd-jwt"] = json!({
+                "sd-jwt_alg_values": algs,
+                "kb-jwt_alg_values": algs,
+            });
+        }
+        Some(json!({
+            "jwks": { "keys": [key.public_jwk()] },
+            "authorization_encrypted_response_alg": JWE_ALG,
+            "authorization_encrypted_response_enc": "A256GCM",
+            "encrypted_response_enc_values_supported": JWE_ENC_SUPPORTED,
+            "vp_formats": vp_formats,
+        }))
+    }
+
+    /// Verifies a credential, returning the types it carries.
+    ///
+    /// JSON-LD credentials (`ldp_vc`) embedded in an `ldp_vp` are told apart by their JSON text,
+    /// SD-JWT VCs by their `~`-separated disclosures.
+    async fn verify_vc(&self, vc_token: &str, holder_did: &Did) -> Outcome<Vec<VcType>> {
+        if vc_token.trim_start().starts_with('{') {
+            return self.verify_ldp_vc(vc_token, holder_did).await;
+        }
+        if VpFormat::is_sd_jwt(vc_token) {
+            return self.verify_sd_jwt_vc(vc_token, holder_did).await;
+        }
         info!("Verifying vc");
 
         let jwt = Jwt::parse(vc_token)?;
-        let (iss_kid, claims) = Verifier::verify_enveloped::<VCJwtClaims>(&jwt, None).await?;
+        let (iss_kid, claims) =
+            Verifier::verify_enveloped::<VCJwtClaims>(&self.resolver, &jwt, None).await?;
 
         validate_vc_issuer(&claims, &iss_kid)?;
         validate_vc_id(&claims)?;
         validate_vc_sub(&claims, holder_did)?;
-        // TODO: trusted-issuer list once available
-        validate_valid_from(&claims)?;
-        validate_valid_until(&claims)?;
+        let leeway = self.config.get_clock_leeway();
+        validate_valid_from(&claims, leeway)?;
+        validate_valid_until(&claims, leeway)?;
+        validate_validity_window(claims.vc_doc(), leeway)?;
+        self.validate_status(claims.vc_doc()).await?;
 
         info!("VC verification successful");
-        Ok(())
+        Ok(vc_types(claims.vc_doc()))
+    }
+
+    /// Verifies a JSON-LD credential secured with an `assertionMethod` Data Integrity proof.
+    async fn verify_ldp_vc(&self, vc: &str, holder_did: &Did) -> Outcome<Vec<VcType>> {
+        info!("Verifying ldp vc");
+
+        let document: Value = serde_json::from_str(vc).map_err(|e| {
+            Errors::format(
+                BadFormat::Received,
+                "ldp_vc credential is not valid JSON",
+                Some(Box::new(e)),
+            )
+        })?;
+        let (unsecured, proof, iss_kid) =
+            Verifier::verify_data_integrity(&self.resolver, &document).await?;
+        if proof.proof_purpose.as_deref() != Some("assertionMethod") {
+            return Err(Errors::security(
+                "VC proof purpose must be assertionMethod",
+                None,
+            ));
+        }
+
+        let doc: VcDocument = serde_json::from_value(unsecured)?;
+        if doc.issuer.id() != iss_kid.did().id() {
+            return Err(Errors::security("VC issuer & kid does not match", None));
+        }
+        validate_subject(&doc, holder_did)?;
+        validate_validity_window(&doc, self.config.get_clock_leeway())?;
+        self.validate_status(&doc).await?;
+
+        info!("LDP VC verification successful");
+        Ok(vc_types(&doc))
+    }
+
+    /// Verifies the issuer-signed part of an SD-JWT VC and its disclosed claims.
+    ///
+    /// Disclosures are checked against the `_sd` digests when rebuilding the claim set, so
+    /// every claim validated here was covered by the issuer signature.
+    async fn verify_sd_jwt_vc(&self, vc: &str, holder_did: &Did) -> Outcome<Vec<VcType>> {
+        info!("Verifying sd-jwt vc");
+
+        let sd_jwt = SdJwt::parse(vc.trim())?;
+        if !matches!(
+            sd_jwt.jwt().header().typ.as_deref(),
+            Some(SD_JWT_VC_TYP | "vc+sd-jwt")
+        ) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("SD-JWT VC typ must be '{SD_JWT_VC_TYP}'"),
+                None,
+            ));
+        }
+        let (iss_kid, _) =
+            Verifier::verify_enveloped::<Value>(&self.resolver, sd_jwt.jwt(), None).await?;
+        let claims: SdJwtVcClaims = serde_json::from_value(sd_jwt.disclosed_claims()?)?;
+
+        if claims.iss != iss_kid.did().id() {
+            return Err(Errors::security("VC iss & kid does not match", None));
+        }
+        if claims
+            .sub
+            .as_deref()
+            .is_some_and(|sub| sub != holder_did.id())
+        {
+            return Err(Errors::security("VC sub & holder does not match", None));
+        }
+        let leeway = self.config.get_clock_leeway();
+        if let Some(nbf) = claims.nbf {
+            is_active_with(nbf, leeway)?;
+        }
+        if let Some(exp) = claims.exp {
+            has_expired_with(exp, leeway)?;
+        }
+        if claims.claims.contains_key("status") {
+            // SD-JWT VCs reference IETF Token Status Lists, not Bitstring Status Lists.
+            let e = Errors::not_impl("Token Status List checks are not supported", None);
+            match self.config.get_status_check() {
+                StatusCheckMode::Mandatory => return Err(e),
+                StatusCheckMode::BestEffort => {
+                    warn!("Unable to check VC status, accepting it anyway: {e}")
+                }
+            }
+        }
+
+        info!("SD-JWT VC verification successful");
+        Ok(claims.vct.parse().ok().into_iter().collect())
+    }
+
+    async fn validate_status(&self, doc: &VcDocument) -> Outcome<()> {
+        let Some(status) = &doc.credential_status else {
+            return Ok(());
+        };
+        info!("Validating credential status");
+
+        match self.check_status(status, doc.issuer.id()).await {
+            Ok(None) => {
+                info!("VC status is valid");
+                Ok(())
+            }
+            Ok(Some(state)) => Err(Errors::security(format!("VC has been {state}"), None)),
+            Err(e) => match self.config.get_status_check() {
+                StatusCheckMode::Mandatory => Err(e),
+                StatusCheckMode::BestEffort => {
+                    warn!("Unable to check VC status, accepting it anyway: {e}");
+                    Ok(())
+                }
+            },
+        }
+    }
+
+    /// Returns the state the credential is flagged with, or `None` when it is in good standing.
+    async fn check_status(&self, status: &VCStatus, issuer: &str) -> Outcome<Option<&'static str>> {
+        if !matches!(
+            status.r#type.as_str(),
+            "BitstringStatusListEntry" | "StatusList2021Entry"
+        ) {
+            return Err(Errors::not_impl(
+                format!("Unsupported credentialStatus type '{}'", status.r#type),
+                None,
+            ));
+        }
+
+        let purpose = status.status_purpose.as_deref().unwrap_or_default();
+        let state = match purpose {
+            "revocation" => "revoked",
+            "suspension" => "suspended",
+            other => {
+                return Err(Errors::not_impl(
+                    format!("Unsupported credentialStatus purpose '{other}'"),
+                    None,
+                ));
+            }
+        };
+
+        let (Some(url), Some(index)) = (
+            status.status_list_credential.as_deref(),
+            status.status_list_index.as_deref(),
+        ) else {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "credentialStatus lacks statusListCredential or statusListIndex",
+                None,
+            ));
+        };
+        let index: i32 = index.parse().map_err(|e| {
+            Errors::parse("statusListIndex is not a valid integer", Some(Box::new(e)))
+        })?;
+
+        let list = self.status_lists.lookup(url).await?;
+        if list.issuer != issuer {
+            return Err(Errors::security(
+                "Status list issuer & VC issuer do not match",
+                None,
+            ));
+        }
+        if list.purpose.as_deref().is_some_and(|p| p != purpose) {
+            return Err(Errors::security(
+                "Status list purpose does not match credentialStatus",
+                None,
+            ));
+        }
+
+        Ok(list.bits.get(index)?.then_some(state))
     }
 }
 
 // ===== Free validators (pure logic, no `self`) ===============================
 
+/// Splits a DCQL `vp_token` object into its presentations, each with the type it answers.
+///
+/// Entries may be missing for types the policy lets the holder leave out, but every entry
+/// must be keyed by the id of a credential query of the session.
+fn split_keyed_vp_token(model: &Model, vp_token: &str) -> Outcome<Vec<(VcType, String)>> {
+    info!("Splitting vp_token keyed by credential query id");
+    let entries: Map<String, Value> = serde_json::from_str(vp_token).map_err(|e| {
+        Errors::format(
+            BadFormat::Received,
+            "vp_token is not a valid DCQL response",
+            Some(Box::new(e)),
+        )
+    })?;
+    if let Some(unknown) = entries
+        .keys()
+        .find(|id| !model.vc_type.iter().any(|t| credential_query_id(t) == **id))
+    {
+        return Err(Errors::security(
+            format!("vp_token answers unknown credential query '{unknown}'"),
+            None,
+        ));
+    }
+
+    let mut presentations = Vec::new();
+    for vc_type in &model.vc_type {
+        let id = credential_query_id(vc_type);
+        let Some(entry) = entries.get(&id) else {
+            continue;
+        };
+        let items = match entry {
+            Value::Array(items) if !items.is_empty() => items.iter().collect(),
+            Value::String(_) => vec![entry],
+            _ => Vec::new(),
+        };
+        if items.is_empty() {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Invalid presentation for credential query '{id}'"),
+                None,
+            ));
+        }
+        for item in items {
+            let presentation = item.as_str().ok_or_else(|| {
+                Errors::format(
+                    BadFormat::Received,
+                    format!("Presentation for credential query '{id}' is not a string"),
+                    None,
+                )
+            })?;
+            presentations.push((vc_type.clone(), presentation.to_string()));
+        }
+    }
+    if presentations.is_empty() {
+        return Err(Errors::format(
+            BadFormat::Received,
+            "vp_token holds no presentation for any credential query",
+            None,
+        ));
+    }
+    Ok(presentations)
+}
+
+fn validate_policy(policy: &VpPolicy, presented: &[VcType]) -> Outcome<()> {
+    info!("Validating presented credentials against policy");
+    if !policy.is_satisfied_by(presented) {
+        return Err(Errors::security(
+            "Presented credentials do not satisfy the verification policy",
+            None,
+        ));
+    }
+    info!("Credential policy satisfied");
+    Ok(())
+}
+
+/// Checks a Presentation Exchange submission against the definition of the exchange.
+///
+/// Every entry must name a requested input descriptor and lead, through its nested paths, to a
+/// credential of the VP satisfying it. The matched descriptors must then fulfil the definition.
+fn validate_submission(
+    vpd: &VPDef,
+    submission: &PresentationSubmission,
+    vp_token: &str,
+) -> Outcome<()> {
+    info!("Validating presentation submission");
+    if submission.definition_id != vpd.id {
+        return Err(Errors::security(
+            "Submission does not answer the presentation definition",
+            None,
+        ));
+    }
+
+    let ldp = LdpVp::is_ldp_vp(vp_token);
+    let root = match ldp {
+        true => serde_json::from_str(vp_token)?,
+        false => Value::String(vp_token.to_string()),
+    };
+    // Bare SD-JWT VCs are submitted as `vc+sd-jwt` (or `dc+sd-jwt`) entries pointing at `$`.
+    let presentation_format = if ldp {
+        "ldp_vp"
+    } else if VpFormat::is_sd_jwt(vp_token) {
+        "+sd-jwt"
+    } else {
+        "jwt_vp"
+    };
+    let mut matched: Vec<&str> = Vec::new();
+    for entry in &submission.descriptor_map {
+        let id = &entry.id;
+        let descriptor = vpd
+            .input_descriptors
+            .iter()
+            .find(|d| &d.id == id)
+            .ok_or_else(|| {
+                Errors::format(
+                    BadFormat::Received,
+                    format!("Submission references unknown input descriptor '{id}'"),
+                    None,
+                )
+            })?;
+        let format_ok = match presentation_format {
+            "+sd-jwt" => entry.format.ends_with(presentation_format),
+            _ => entry.format.starts_with(presentation_format),
+        };
+        if !format_ok {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Descriptor '{id}' must point into a {presentation_format} presentation"),
+                None,
+            ));
+        }
+
+        let (format, credential) = entry.resolve(&root)?;
+        if !descriptor.accepts_format(&format) {
+            return Err(Errors::security(
+                format!("Descriptor '{id}' does not accept format '{format}'"),
+                None,
+            ));
+        }
+        if !descriptor.is_satisfied_by(&credential) {
+            return Err(Errors::security(
+                format!("Credential submitted for '{id}' does not satisfy its descriptor"),
+                None,
+            ));
+        }
+        // Only SD-JWT VCs can withhold claims; whatever they disclose must be asked for.
+        if descriptor.limits_disclosure() {
+            let sd_jwt = match presentation_format {
+                "+sd-jwt" => SdJwt::parse(vp_token)?,
+                _ => {
+                    return Err(Errors::security(
+                        format!("Descriptor '{id}' requires selective disclosure"),
+                        None,
+                    ));
+                }
+            };
+            let disclosed: Vec<&str> = sd_jwt.disclosures().iter().map(|d| d.name()).collect();
+            if !descriptor.covers_disclosures(&disclosed) {
+                return Err(Errors::security(
+                    format!("Credential submitted for '{id}' discloses claims not asked for"),
+                    None,
+                ));
+            }
+        }
+        matched.push(&descriptor.id);
+    }
+
+    if !vpd.is_satisfied_by(&matched) {
+        return Err(Errors::security(
+            "Submission does not satisfy the presentation definition",
+            None,
+        ));
+    }
+    info!("Presentation submission validated");
+    Ok(())
+}
+
 fn validate_nonce(claims: &VPJwtClaims, model: &Model) -> Outcome<()> {
     info!("Validating nonce");
     if model.nonce != claims.nonce {
@@ -184,6 +911,24 @@ fn validate_nonce(claims: &VPJwtClaims, model: &Model) -> Outcome<()> {
     Ok(())
 }
 
+fn validate_ldp_vp_proof(proof: &Proof, model: &Model, audiences: &[String]) -> Outcome<()> {
+    info!("Validating VP proof binding");
+    if proof.proof_purpose.as_deref() != Some("authentication") {
+        return Err(Errors::security(
+            "VP proof purpose must be authentication",
+            None,
+        ));
+    }
+    if proof.challenge.as_deref() != Some(model.nonce.as_str()) {
+        return Err(Errors::security("Invalid nonce, it does not match", None));
+    }
+    if !audiences.iter().any(|aud| proof.has_domain(aud)) {
+        return Err(Errors::security("VP proof domain does not match", None));
+    }
+    info!("VP proof is bound to the exchange");
+    Ok(())
+}
+
 fn validate_vp_holder(claims: &VPJwtClaims, holder_kid: &Kid) -> Outcome<()> {
     info!("Validating VP subject");
     check_eq_opt(
@@ -232,9 +977,17 @@ fn validate_vc_id(claims: &VCJwtClaims) -> Outcome<()> {
 }
 
 fn validate_vc_sub(claims: &VCJwtClaims, holder_did: &Did) -> Outcome<()> {
+    check_eq_opt(
+        claims.sub(),
+        holder_did.id(),
+        "VCT sub & and holder from vp",
+    )?;
+    validate_subject(claims.vc_doc(), holder_did)
+}
+
+fn validate_subject(doc: &VcDocument, holder_did: &Did) -> Outcome<()> {
     info!("Validating VC subject");
-    let cred_sub_id = claims
-        .vc_doc()
+    let cred_sub_id = doc
         .credential_subject
         .get("id")
         .and_then(|v| v.as_str())
@@ -246,11 +999,6 @@ fn validate_vc_sub(claims: &VCJwtClaims, holder_did: &Did) -> Outcome<()> {
             )
         })?;
 
-    check_eq_opt(
-        claims.sub(),
-        holder_did.id(),
-        "VCT sub & and holder from vp",
-    )?;
     if cred_sub_id != holder_did.id() {
         return Err(Errors::security(
             "VC credentialSubject does not match holder",
@@ -261,30 +1009,36 @@ fn validate_vc_sub(claims: &VCJwtClaims, holder_did: &Did) -> Outcome<()> {
     Ok(())
 }
 
-fn validate_valid_from(claims: &VCJwtClaims) -> Outcome<()> {
+fn validate_valid_from(claims: &VCJwtClaims, leeway: Duration) -> Outcome<()> {
     info!("Validating issuance date");
     if let Some(nbf) = claims.nbf() {
-        is_active(nbf)?;
+        is_active_with(nbf, leeway)?;
     }
     if let Some(iat) = claims.iat() {
-        is_active(iat)?;
-    }
-    if let Some(valid_from) = claims.vc_doc().valid_from {
-        if valid_from > Utc::now() {
-            return Err(Errors::security("VC is not valid yet", None));
-        }
-        info!("VC has started its validity period");
+        is_active_with(iat, leeway)?;
     }
     Ok(())
 }
 
-fn validate_valid_until(claims: &VCJwtClaims) -> Outcome<()> {
+fn validate_valid_until(claims: &VCJwtClaims, leeway: Duration) -> Outcome<()> {
     info!("Validating expiration date");
     if let Some(exp) = claims.exp() {
-        has_expired(exp)?;
+        has_expired_with(exp, leeway)?;
     }
-    if let Some(valid_until) = claims.vc_doc().valid_until {
-        if Utc::now() > valid_until {
+    Ok(())
+}
+
+/// Checks the `validFrom`/`validUntil` window of the credential document.
+fn validate_validity_window(doc: &VcDocument, leeway: Duration) -> Outcome<()> {
+    info!("Validating validity period");
+    if let Some(valid_from) = doc.valid_from {
+        if valid_from > Utc::now() + leeway {
+            return Err(Errors::security("VC is not valid yet", None));
+        }
+        info!("VC has started its validity period");
+    }
+    if let Some(valid_until) = doc.valid_until {
+        if Utc::now() - leeway > valid_until {
             return Err(Errors::security("VC has expired", None));
         }
         info!("VC has not expired yet");
@@ -292,6 +1046,10 @@ fn validate_valid_until(claims: &VCJwtClaims) -> Outcome<()> {
     Ok(())
 }
 
+fn vc_types(doc: &VcDocument) -> Vec<VcType> {
+    doc.r#type.iter().filter_map(|t| t.parse().ok()).collect()
+}
+
 fn check_eq_opt(actual: Option<&str>, expected: &str, ctx: &str) -> Outcome<()> {
     if let Some(a) = actual {
         if a != expected {
@@ -301,3 +1059,11 @@ fn check_eq_opt(actual: Option<&str>, expected: &str, ctx: &str) -> Outcome<()>
     }
     Ok(())
 }
+
+/// Verifier route the wallet answers to under `response_mode`.
+fn response_endpoint(response_mode: ResponseMode) -> &'static str {
+    match response_mode {
+        ResponseMode::DirectPost => "verify",
+        ResponseMode::Fragment | ResponseMode::Query => "callback",
+    }
+}
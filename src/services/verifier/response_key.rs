@@ -0,0 +1,46 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use tracing::info;
+
+use crate::capabilities::EcdhEsKey;
+use crate::errors::{Errors, Outcome};
+use crate::services::vault::{VaultService, VaultTrait};
+use crate::types::keys::{Crv, Kty};
+use crate::types::secrets::PemHelper;
+
+/// Loads the agent's long-lived response encryption key from the vault.
+///
+/// The key is stored like any other agent key, as a [`PemHelper`] wrapping a P-256 PKCS#8
+/// PEM, and is handed to
+/// [`VerifierConfig::with_static_response_key`](super::oid4vp_draft20::VerifierConfig::with_static_response_key).
+///
+/// # Errors
+/// Returns an [`Errors::ForbiddenError`](crate::errors::Errors::ForbiddenError) when the
+/// stored key is not an EC P-256 key.
+pub async fn load_response_key(vault: &VaultService, path: &str) -> Outcome<EcdhEsKey> {
+    let pem: PemHelper = vault.read(None, path).await?;
+    if pem.kty() != &Kty::Ec || pem.crv() != Some(&Crv::P256) {
+        return Err(Errors::forbidden(
+            format!("Response encryption key at {path} must be an EC P-256 key"),
+            None,
+        ));
+    }
+    let key = EcdhEsKey::from_pkcs8_pem(pem.pem())?;
+    info!("Loaded response encryption key {}", key.kid());
+    Ok(key)
+}
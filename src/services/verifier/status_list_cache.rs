@@ -0,0 +1,237 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use tokio::sync::RwLock;
+use tracing::info;
+
+use crate::capabilities::{DidResolver, Kid, Verifier};
+use crate::errors::{BadFormat, Errors, Outcome, PetitionFailure};
+use crate::services::client::ClientTrait;
+use crate::types::issuance::{Bitstring, MAX_STATUS_LIST_BYTES};
+use crate::types::jwt::Jwt;
+use crate::types::vcs::VcIssuer;
+use crate::utils::{ResponseExt, has_expired, http_client};
+
+/// Time a fetched status list is trusted before it is downloaded again.
+pub const DEFAULT_STATUS_LIST_TTL: Duration = Duration::from_secs(300);
+
+const STATUS_LIST_CREDENTIAL_TYPES: [&str; 2] =
+    ["BitstringStatusListCredential", "StatusList2021Credential"];
+
+/// Verified content of a status list credential.
+#[derive(Debug)]
+pub struct StatusListSnapshot {
+    /// DID of the issuer that signed the list.
+    pub issuer: String,
+    /// `statusPurpose` declared by the list, if any.
+    pub purpose: Option<String>,
+    /// Decoded status bits.
+    pub bits: Bitstring,
+    /// End of validity declared by the list (`validUntil`, `expirationDate` or JWT `exp`).
+    pub expires_at: Option<DateTime<Utc>>,
+    fetched_at: Instant,
+}
+
+impl StatusListSnapshot {
+    fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|exp| exp <= Utc::now())
+    }
+}
+
+/// In-memory cache of verified `BitstringStatusList` / `StatusList2021` credentials.
+///
+/// Lists are fetched over HTTP, their proof (enveloped JWT or embedded Data Integrity) is
+/// verified and the decoded bitstring is kept for [`DEFAULT_STATUS_LIST_TTL`] unless configured
+/// otherwise.
+pub struct StatusListCache {
+    ttl: Duration,
+    entries: RwLock<HashMap<String, Arc<StatusListSnapshot>>>,
+    resolver: DidResolver,
+}
+
+impl StatusListCache {
+    pub fn new() -> Self {
+        Self::with_ttl(DEFAULT_STATUS_LIST_TTL)
+    }
+
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+            resolver: DidResolver::default(),
+        }
+    }
+
+    /// Resolves status list issuer DIDs through `resolver`, e.g. one with a Universal
+    /// Resolver fallback.
+    pub fn with_did_resolver(mut self, resolver: DidResolver) -> Self {
+        self.resolver = resolver;
+        self
+    }
+
+    /// Returns the verified status list served at `url`, fetching it when absent or stale.
+    pub async fn lookup(&self, url: &str) -> Outcome<Arc<StatusListSnapshot>> {
+        if let Some(snapshot) = self.entries.read().await.get(url) {
+            if snapshot.fetched_at.elapsed() < self.ttl && !snapshot.is_expired() {
+                return Ok(snapshot.clone());
+            }
+        }
+
+        let snapshot = Arc::new(self.fetch(url).await?);
+        self.entries
+            .write()
+            .await
+            .insert(url.to_string(), snapshot.clone());
+        Ok(snapshot)
+    }
+
+    /// Drops every cached list so the next lookup hits the network.
+    pub async fn clear(&self) {
+        self.entries.write().await.clear();
+    }
+
+    async fn fetch(&self, url: &str) -> Outcome<StatusListSnapshot> {
+        info!("Fetching status list {url}");
+
+        let res = http_client().get(url, None).await?;
+        if !res.status().is_success() {
+            return Err(Errors::petition(
+                url,
+                "GET",
+                Some(res.status()),
+                PetitionFailure::HttpStatus(res.status()),
+                "Status list retrieval failed",
+                None,
+            ));
+        }
+        let body = res.parse_text().await?;
+        let body = body.trim();
+
+        let (signer, doc, jwt_exp) = if body.starts_with('{') {
+            let doc: Value = serde_json::from_str(body)?;
+            Verifier::verify_embed(&self.resolver, &doc).await?;
+            (proof_signer(&doc)?, doc, None)
+        } else {
+            let jwt = Jwt::parse(body)?;
+            let (kid, payload) =
+                Verifier::verify_enveloped::<Value>(&self.resolver, &jwt, None).await?;
+            let exp = payload["exp"].as_i64();
+            if let Some(exp) = exp {
+                has_expired(exp)?;
+            }
+            let doc = match payload.get("vc") {
+                Some(vc) => vc.clone(),
+                None => payload,
+            };
+            (kid.did().id().to_string(), doc, exp)
+        };
+
+        let is_status_list = doc["type"].as_array().is_some_and(|types| {
+            types.iter().any(|t| {
+                t.as_str()
+                    .is_some_and(|t| STATUS_LIST_CREDENTIAL_TYPES.contains(&t))
+            })
+        });
+        if !is_status_list {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("{url} is not a status list credential"),
+                None,
+            ));
+        }
+
+        let issuer: VcIssuer = serde_json::from_value(doc["issuer"].clone())?;
+        if issuer.id() != signer {
+            return Err(Errors::security(
+                "Status list issuer & signer do not match",
+                None,
+            ));
+        }
+
+        let expires_at = match doc["validUntil"]
+            .as_str()
+            .or_else(|| doc["expirationDate"].as_str())
+        {
+            Some(until) => Some(until.parse::<DateTime<Utc>>().map_err(|e| {
+                Errors::parse(
+                    "Status list validity is not a valid date",
+                    Some(Box::new(e)),
+                )
+            })?),
+            None => jwt_exp.and_then(|exp| DateTime::from_timestamp(exp, 0)),
+        };
+        if expires_at.is_some_and(|exp| exp <= Utc::now()) {
+            return Err(Errors::security("Status list credential has expired", None));
+        }
+
+        let subject = &doc["credentialSubject"];
+        let encoded = subject["encodedList"].as_str().ok_or_else(|| {
+            Errors::format(
+                BadFormat::Received,
+                "Status list credential has no encodedList",
+                None,
+            )
+        })?;
+
+        info!("Status list {url} verified");
+        Ok(StatusListSnapshot {
+            issuer: issuer.id().to_string(),
+            purpose: subject["statusPurpose"].as_str().map(str::to_string),
+            bits: Bitstring::decode(encoded, MAX_STATUS_LIST_BYTES)?,
+            expires_at,
+            fetched_at: Instant::now(),
+        })
+    }
+}
+
+impl Default for StatusListCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Resolves the DID behind the embedded proof(s), which must all come from the same signer.
+fn proof_signer(doc: &Value) -> Outcome<String> {
+    let proofs = match &doc["proof"] {
+        Value::Array(proofs) => proofs.iter().collect::<Vec<_>>(),
+        proof => vec![proof],
+    };
+
+    let mut signer: Option<String> = None;
+    for proof in proofs {
+        let method = proof["verificationMethod"].as_str().ok_or_else(|| {
+            Errors::format(BadFormat::Received, "Proof has no verificationMethod", None)
+        })?;
+        let did = Kid::parse(method)?.did().id().to_string();
+        match &signer {
+            Some(prev) if *prev != did => {
+                return Err(Errors::security(
+                    "Status list proofs come from different signers",
+                    None,
+                ));
+            }
+            _ => signer = Some(did),
+        }
+    }
+    signer.ok_or_else(|| Errors::format(BadFormat::Received, "Missing proof", None))
+}
@@ -18,6 +18,7 @@
 use crate::data::entities::received::verification::{Model, Plan};
 use crate::errors::Outcome;
 use crate::types::vcs::VPDef;
+use crate::types::verification::{AuthorizationResponse, DcqlQuery, ResponseMode};
 use async_trait::async_trait;
 
 /// Verifiable Presentation verification service.
@@ -30,28 +31,101 @@ pub trait VerifierTrait: Send + Sync + 'static {
     /// Creates a new verification plan associated with a grant.
     ///
     /// The resulting [`Plan`] establishes the expected cryptographic audience
-    /// (the Verifier's endpoint) and the array of allowed VC types.
+    /// (the Verifier's endpoint, rendered from the configured audience template) and the
+    /// array of allowed VC types.
     fn build_vp_plan(&self, id: &str) -> Outcome<Plan>;
 
+    /// Same as [`build_vp_plan`](Self::build_vp_plan), overriding the configured
+    /// response mode (e.g. with the one of the matching requirement profile).
+    ///
+    /// `fragment` plans point the wallet at the verifier callback route instead of the
+    /// `direct_post` endpoint, so the audience changes accordingly. `direct_post` plans get an
+    /// ephemeral encryption key when response encryption is enabled.
+    ///
+    /// # Errors
+    /// Rejects the `query` response mode, which would carry the `vp_token` in the URL.
+    fn build_vp_plan_with_mode(&self, id: &str, response_mode: ResponseMode) -> Outcome<Plan>;
+
     /// Generates the wallet-facing verification URI used to
     /// initiate the presentation flow.
     ///
-    /// Compiles an `openid4vp://` scheme deployment utilizing `direct_post` mode
-    /// and points the wallet to the ephemeral presentation definition endpoint.
-    fn generate_verification_uri(&self, verification_model: &Model) -> String;
+    /// Compiles an `openid4vp://` scheme deployment using the response mode stored in the
+    /// [`Model`] and either points the wallet to the content addressed presentation definition
+    /// bound to the session or inlines a `dcql_query`, depending on the configured query
+    /// language. Exchanges holding an encryption key ask for `direct_post.jwt` and publish the
+    /// key in `client_metadata`.
+    ///
+    /// # Errors
+    /// Returns an error when the presentation definition or DCQL query cannot be built.
+    fn generate_verification_uri(&self, verification_model: &Model) -> Outcome<String>;
+
+    /// Generates a short `openid4vp://` URI passing the request by reference.
+    ///
+    /// Only carries the `client_id` and the `request_uri` the wallet fetches the
+    /// [request object](Self::generate_request_object) from, so nothing of the exchange
+    /// leaks through the QR code or the page rendering it.
+    fn generate_request_uri(&self, verification_model: &Model) -> String;
+
+    /// Builds the request object served at the `request_uri` of the exchange.
+    ///
+    /// Carries the same parameters as [`generate_verification_uri`](Self::generate_verification_uri)
+    /// in an unsecured JWT, since `redirect_uri` clients cannot sign their requests.
+    ///
+    /// # Errors
+    /// Returns an error when the presentation definition or DCQL query cannot be built.
+    fn generate_request_object(&self, verification_model: &Model) -> Outcome<String>;
 
     /// Builds the Presentation Definition describing the
     /// credentials that must be presented.
     ///
     /// Follows the DIF Presentation Exchange specification to restrict
-    /// the submission to the requested types within the [`Model`].
+    /// the submission to the requested types within the [`Model`]. The definition is content
+    /// addressed: its id, and so its URI, changes whenever the profile behind it does.
     fn generate_vpd(&self, verification_model: &Model) -> Outcome<VPDef>;
 
+    /// Builds the DCQL query describing the credentials that must be presented.
+    ///
+    /// Each requested type becomes a credential query whose id keys the matching
+    /// presentation in the returned `vp_token`.
+    fn generate_dcql(&self, verification_model: &Model) -> Outcome<DcqlQuery>;
+
+    /// Decrypts a `direct_post.jwt` response (JARM) with the ephemeral key of the exchange.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`](crate::errors::Errors::ForbiddenError) when the
+    /// exchange published no key, and a security error when the JWE cannot be decrypted.
+    fn decrypt_response(
+        &self,
+        verification_model: &Model,
+        response: &str,
+    ) -> Outcome<AuthorizationResponse>;
+
+    /// Whether an unencrypted response is acceptable for the exchange.
+    fn accepts_plain_response(&self, verification_model: &Model) -> bool;
+
     /// Verifies all received presentations and updates the
     /// verification model with the validation results.
     ///
     /// This validates the outer VP envelope (nonce, holder signature, expiration)
-    /// as well as each nested Verifiable Credential inside the token. Updates
-    /// the mutable [`Model`] status to reflect success or failure.
-    async fn verify_all(&self, verification_model: &mut Model, vp_token: &str) -> Outcome<()>;
+    /// as well as each nested Verifiable Credential inside the token. A pending
+    /// [`Model`] is moved to `Presented`; the terminal transition is left to the
+    /// caller, which may run further checks before settling the outcome.
+    ///
+    /// DCQL responses are accepted as a JSON object keyed by credential query id, each
+    /// entry holding one presentation or an array of them.
+    ///
+    /// Presentation Exchange responses must come with their `presentation_submission`; each
+    /// descriptor map entry is followed into the VP and the credential it points at must
+    /// satisfy the referenced input descriptor.
+    async fn verify_all(
+        &self,
+        verification_model: &mut Model,
+        vp_token: &str,
+        presentation_submission: Option<&str>,
+    ) -> Outcome<()>;
+
+    /// Applies the configured redaction profile to a finished verification before it is stored.
+    ///
+    /// Without a profile the raw `vp_token` and credentials are kept untouched.
+    fn redact(&self, verification_model: &mut Model);
 }
@@ -0,0 +1,74 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use tracing::info;
+
+use super::WalletTrait;
+use super::fafnir::{FafnirConfig, FafnirService};
+use super::native::{NativeWalletConfig, NativeWalletService};
+use super::walt_id::{WaltIdConfig, WaltIdService};
+use crate::config::types::{CommonHostsConfig, DidConfig, WalletConfig, WalletProvider};
+use crate::errors::Outcome;
+use crate::services::repo::traits::wallet::{DidRepoTrait, KeyRepoTrait, VcRepoTrait};
+use crate::services::vault::VaultService;
+use crate::types::dids::DidService;
+
+/// Collaborators a wallet backend may need; each backend takes only the ones it uses.
+pub struct WalletDeps {
+    pub vault: Arc<VaultService>,
+    pub dids: Arc<dyn DidRepoTrait>,
+    pub keys: Arc<dyn KeyRepoTrait>,
+    pub vcs: Arc<dyn VcRepoTrait>,
+    pub services: Vec<DidService>,
+}
+
+/// Builds the wallet backend selected by `wallet.provider`.
+pub async fn build_wallet(
+    hosts: CommonHostsConfig,
+    wallet: WalletConfig,
+    did: DidConfig,
+    deps: WalletDeps,
+) -> Outcome<Arc<dyn WalletTrait>> {
+    let provider = wallet.provider;
+    info!("Starting wallet backend {provider:?}");
+    let backend: Arc<dyn WalletTrait> = match provider {
+        WalletProvider::WaltId => {
+            let config = WaltIdConfig::new(hosts, wallet, did);
+            Arc::new(WaltIdService::new(config, deps.vault, deps.services).await?)
+        }
+        WalletProvider::Native => {
+            let config = NativeWalletConfig::new(hosts, did).with_selection(wallet.selection);
+            let service = NativeWalletService::new(
+                config,
+                deps.vault,
+                deps.dids,
+                deps.keys,
+                deps.vcs,
+                deps.services,
+            )
+            .await?;
+            Arc::new(service)
+        }
+        WalletProvider::CustomUrl => {
+            let config = FafnirConfig::new(hosts, wallet, did);
+            Arc::new(FafnirService::new(config, deps.vault, deps.services).await?)
+        }
+    };
+    Ok(backend)
+}
@@ -58,7 +58,7 @@ impl FafnirService {
     ) -> Outcome<Self> {
         let (did_doc, keys) = Self::bootstrap(&config, vault, &services).await?;
         let did = Did::parse(&did_doc.id)?;
-        let identity = Identity::new(did, did_doc, keys);
+        let identity = Self::dual_stack(&config, Identity::new(did, did_doc, keys))?;
         Ok(Self {
             config,
             identity: Arc::new(RwLock::new(identity)),
@@ -134,6 +134,14 @@ impl FafnirService {
 
         Ok((did_model.did_document, did_model.default_key))
     }
+
+    /// Adds the `did:jwk` alias to `identity` when the did:web config asks for a dual stack.
+    fn dual_stack(config: &FafnirConfig, identity: Identity) -> Outcome<Identity> {
+        if config.did_config().is_dual_stack() {
+            return identity.with_jwk_alias();
+        }
+        Ok(identity)
+    }
 }
 
 #[async_trait]
@@ -355,7 +363,7 @@ impl WalletTrait for FafnirService {
 
     // ===== PROTOCOL HANDLING =====================================================================
 
-    async fn process_oid4vci(&self, uri: &str) -> Outcome<()> {
+    async fn process_oid4vci(&self, uri: &str, tx_code: Option<&str>) -> Outcome<()> {
         info!("FafnirService: process_oid4vci({})", uri);
         let url = format!("{}/oid4vci", self.config.get_wallet_api_url(HostType::Http));
         let res = http_client()
@@ -364,6 +372,7 @@ impl WalletTrait for FafnirService {
                 Some(json_headers()),
                 HttpBody::json(&OidcUri {
                     uri: uri.to_string(),
+                    tx_code: tx_code.map(str::to_string),
                 })?,
             )
             .await?;
@@ -380,6 +389,7 @@ impl WalletTrait for FafnirService {
                 Some(json_headers()),
                 HttpBody::json(&OidcUri {
                     uri: uri.to_string(),
+                    tx_code: None,
                 })?,
             )
             .await?;
@@ -481,6 +491,7 @@ impl FafnirService {
     async fn replace_identity_from(&self, model: &did::Model) -> Outcome<()> {
         let did = Did::parse(&model.did)?;
         let new = Identity::new(did, model.did_document.clone(), model.default_key.clone());
+        let new = Self::dual_stack(&self.config, new)?;
         let mut guard = self.identity.write().await;
         *guard = new;
         Ok(())
@@ -15,8 +15,15 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod factory;
 pub mod fafnir;
+pub mod native;
+mod offer;
+mod registry;
 mod wallet_trait;
 pub mod walt_id;
 
+pub use factory::{WalletDeps, build_wallet};
+pub use offer::{check_trusted_credential, resolve_credential_offer};
+pub use registry::WalletRegistry;
 pub use wallet_trait::WalletTrait;
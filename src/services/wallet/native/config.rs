@@ -0,0 +1,58 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::config::traits::{DidConfigTrait, HostsConfigTrait};
+use crate::config::types::{CommonHostsConfig, DidConfig};
+use crate::types::wallet::CredentialSelection;
+
+pub struct NativeWalletConfig {
+    hosts: CommonHostsConfig,
+
+    did: DidConfig,
+    selection: CredentialSelection,
+}
+
+impl NativeWalletConfig {
+    pub fn new(hosts: CommonHostsConfig, did: DidConfig) -> Self {
+        Self {
+            hosts,
+            did,
+            selection: CredentialSelection::default(),
+        }
+    }
+
+    pub fn with_selection(mut self, selection: CredentialSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    pub fn get_selection(&self) -> &CredentialSelection {
+        &self.selection
+    }
+}
+
+impl HostsConfigTrait for NativeWalletConfig {
+    fn hosts(&self) -> &CommonHostsConfig {
+        &self.hosts
+    }
+}
+
+impl DidConfigTrait for NativeWalletConfig {
+    fn did_config(&self) -> &DidConfig {
+        &self.did
+    }
+}
@@ -0,0 +1,1200 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use super::config::NativeWalletConfig;
+use crate::capabilities::{Did, DidResolver};
+use crate::config::traits::DidConfigTrait;
+use crate::config::types::DidConfig;
+use crate::data::entities::wallet::{did, key, vc};
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::services::client::ClientTrait;
+use crate::services::repo::traits::shared::TrustedIssuerRepoTrait;
+use crate::services::repo::traits::wallet::{DidRepoTrait, KeyRepoTrait, VcRepoTrait};
+use crate::services::signer::{SignerTrait, VaultKeySigner};
+use crate::services::vault::{VaultService, VaultTrait};
+use crate::services::wallet::offer::{settle_tx_code, tx_code_required};
+use crate::services::wallet::{WalletTrait, check_trusted_credential};
+use crate::types::dids::{DidBuilder, DidDocument, DidService, DidType};
+use crate::types::http::HttpBody;
+use crate::types::issuance::{
+    AuthServerMetadata, CredReqProof, CredentialRequest, DidPossession, GiveVC, IssuerMetadata,
+    IssuingToken, NotificationEvent, NotificationRequest, OidcGrantType, TokenRequest, VcBody,
+};
+use crate::types::jwt::{Jwt, SdJwt, VCJwtClaims, VPJwtClaims};
+use crate::types::secrets::PemHelper;
+use crate::types::vcs::doc::VcDocument;
+use crate::types::vcs::{InputDescriptor, VPDef, VcFormat, VcType, W3cDataModelVersion};
+use crate::types::verification::{
+    DescriptorMapEntry, PresentationSubmission, PresentedCredential, VpFormat,
+};
+use crate::types::vps::VpDocument;
+use crate::types::wallet::{
+    BackedUpKey, DidSearch, Identity, KeyBackup, KeyBackupContent, KeyImportSummary, KeyRef,
+    OnboardingStatus, OnboardingStep, PresentationCandidate, ResolvedOffer, WalletInfo,
+};
+use crate::utils::{
+    Clock, ResponseExt, encode_url_safe_no_pad, expect_from_env, http_client, json_headers,
+};
+
+use async_trait::async_trait;
+use axum::http::header::AUTHORIZATION;
+use axum::http::{HeaderValue, StatusCode};
+use chrono::{DateTime, Utc};
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tracing::info;
+
+const PROOF_TYP: &str = "openid4vci-proof+jwt";
+const VP_LIFETIME_SECS: i64 = 300;
+/// Vault prefix under which registered private keys are stored.
+const KEY_PATH_PREFIX: &str = "crypto/keys";
+
+/// Wallet implementation running in-process, without an external wallet API.
+///
+/// Private keys live in the vault under the key id, while key metadata, DIDs and
+/// credentials are kept in the local repositories. OID4VCI and OID4VP exchanges are
+/// driven directly against the issuer and verifier endpoints.
+pub struct NativeWalletService {
+    config: NativeWalletConfig,
+    vault: Arc<VaultService>,
+    dids: Arc<dyn DidRepoTrait>,
+    keys: Arc<dyn KeyRepoTrait>,
+    vcs: Arc<dyn VcRepoTrait>,
+    identity: Arc<RwLock<Identity>>,
+    services: Vec<DidService>,
+    signer: Arc<dyn SignerTrait>,
+    resolver: DidResolver,
+}
+
+impl NativeWalletService {
+    /// Creates the native wallet and initializes the local identity cache.
+    pub async fn new(
+        config: NativeWalletConfig,
+        vault: Arc<VaultService>,
+        dids: Arc<dyn DidRepoTrait>,
+        keys: Arc<dyn KeyRepoTrait>,
+        vcs: Arc<dyn VcRepoTrait>,
+        services: Vec<DidService>,
+    ) -> Outcome<Self> {
+        let signer: Arc<dyn SignerTrait> = Arc::new(VaultKeySigner::new(vault.clone()));
+        let (base, _) = Self::bootstrap(
+            &config,
+            &vault,
+            signer.as_ref(),
+            dids.as_ref(),
+            keys.as_ref(),
+            &services,
+        )
+        .await?;
+        let identity = identity_from(&config, &base)?;
+        Ok(Self {
+            config,
+            signer,
+            vault,
+            dids,
+            keys,
+            vcs,
+            identity: Arc::new(RwLock::new(identity)),
+            services,
+            resolver: DidResolver::default(),
+        })
+    }
+
+    /// Signs presentations and key proofs through `signer` instead of reading the identity
+    /// keys from the vault.
+    pub fn with_signer(mut self, signer: Arc<dyn SignerTrait>) -> Self {
+        self.signer = signer;
+        self
+    }
+
+    /// Resolves credential issuer DIDs through `resolver`, e.g. one with a Universal Resolver
+    /// fallback.
+    pub fn with_did_resolver(mut self, resolver: DidResolver) -> Self {
+        self.resolver = resolver;
+        self
+    }
+
+    /// Initializes the wallet identity, running only the onboarding steps still missing.
+    ///
+    /// If a default DID is already stored it is reused. Otherwise, the agent key is
+    /// registered, the base DID is created from it and made the default, each step reusing
+    /// what an interrupted run already stored. Returns the default DID and the steps run.
+    async fn bootstrap(
+        config: &NativeWalletConfig,
+        vault: &VaultService,
+        signer: &dyn SignerTrait,
+        dids: &dyn DidRepoTrait,
+        keys: &dyn KeyRepoTrait,
+        services: &[DidService],
+    ) -> Outcome<(did::Model, Vec<OnboardingStep>)> {
+        // ===== IF DATA IS SAVED IN WALLET RETRIEVE ===============================================
+        if let Ok(base) = dids.get_default().await {
+            return Ok((base, Vec::new()));
+        }
+        let mut performed = Vec::new();
+
+        // ===== REGISTER KEY ======================================================================
+        let priv_vault_path = expect_from_env("VAULT_APP_PRIV_KEY");
+        let key_data: PemHelper = vault.read(None, &priv_vault_path).await?;
+
+        let key_model = match keys.get_by_id(&priv_vault_path).await {
+            Ok(model) => model,
+            Err(_) => {
+                let key_req = key::Plan {
+                    id: priv_vault_path,
+                    alias: "base".to_string(),
+                    pem: key_data.pem().to_string(),
+                };
+                performed.push(OnboardingStep::RegisterKey);
+                save_key(vault, keys, key_req).await?
+            }
+        };
+
+        // ===== REGISTER DID ======================================================================
+        let did_builder = base_did_builder(config, &key_data)?;
+        let base = match dids.get_by_did(did_builder.build()?.id()).await {
+            Ok(model) => model,
+            Err(_) => {
+                let services = if services.is_empty() {
+                    None
+                } else {
+                    Some(services.to_vec())
+                };
+                let did_req = did::Plan {
+                    alias: "base".to_string(),
+                    builder: did_builder,
+                    keys: vec![key_model.id],
+                    service: services,
+                };
+                performed.push(OnboardingStep::RegisterDid);
+                save_did(signer, dids, keys, did_req).await?
+            }
+        };
+
+        // ===== SET DEFAULT =======================================================================
+        if base.r#default {
+            return Ok((base, performed));
+        }
+        performed.push(OnboardingStep::SetDefault);
+        let base = dids.set_default_id(&base.id).await?;
+        Ok((base, performed))
+    }
+}
+
+#[async_trait]
+impl WalletTrait for NativeWalletService {
+    // ===== CORE WALLET STATE =====================================================================
+    async fn link(&self) -> Outcome<()> {
+        let default = self.dids.get_default().await?;
+        self.replace_identity_from(&default).await
+    }
+
+    async fn onboarding_status(&self) -> Outcome<OnboardingStatus> {
+        if let Ok(default) = self.dids.get_default().await {
+            return Ok(OnboardingStatus::complete(default.did));
+        }
+
+        let priv_vault_path = expect_from_env("VAULT_APP_PRIV_KEY");
+        let key_data: PemHelper = self.vault.read(None, &priv_vault_path).await?;
+        let did = base_did_builder(&self.config, &key_data)?.build()?;
+        Ok(OnboardingStatus {
+            key_registered: self.keys.get_by_id(&priv_vault_path).await.is_ok(),
+            did: Some(did.id().to_string()),
+            did_registered: self.dids.get_by_did(did.id()).await.is_ok(),
+            default_set: false,
+            performed: Vec::new(),
+        })
+    }
+
+    async fn onboard(&self) -> Outcome<OnboardingStatus> {
+        let (base, performed) = Self::bootstrap(
+            &self.config,
+            &self.vault,
+            self.signer.as_ref(),
+            self.dids.as_ref(),
+            self.keys.as_ref(),
+            &self.services,
+        )
+        .await?;
+        self.replace_identity_from(&base).await?;
+        if !performed.is_empty() {
+            info!("Onboarding resumed, ran {:?}", performed);
+        }
+        Ok(OnboardingStatus {
+            performed,
+            ..OnboardingStatus::complete(base.did)
+        })
+    }
+
+    async fn get_wallet(&self) -> Outcome<WalletInfo> {
+        let dids = self.retrieve_all_dids().await?;
+
+        Ok(WalletInfo {
+            id: "native-local".to_string(),
+            name: "native-wallet".to_string(),
+            created_on: String::new(),
+            added_on: String::new(),
+            permission: "Administrator".to_string(),
+            dids,
+        })
+    }
+
+    async fn get_did(&self) -> Outcome<Did> {
+        let identity = self.identity.read().await;
+        Ok(identity.did().clone())
+    }
+
+    async fn get_did_doc(&self) -> Outcome<DidDocument> {
+        let identity = self.identity.read().await;
+        Ok(identity.did_doc().clone())
+    }
+
+    fn get_identity(&self) -> Arc<RwLock<Identity>> {
+        self.identity.clone()
+    }
+
+    // ===== STORAGE (READ ONLY) ===================================================================
+    async fn retrieve_did(&self, search: DidSearch) -> Outcome<did::Model> {
+        match search {
+            DidSearch::Id(id) => self.dids.get_by_id(&id).await,
+            DidSearch::Did(did) => self.dids.get_by_did(&did).await,
+        }
+    }
+
+    async fn retrieve_default_did(&self) -> Outcome<did::Model> {
+        self.dids.get_default().await
+    }
+
+    async fn retrieve_all_dids(&self) -> Outcome<Vec<did::Model>> {
+        self.dids.get_all(None, None).await
+    }
+
+    async fn retrieve_key(&self, id: &str) -> Outcome<key::Model> {
+        self.keys.get_by_id(id).await
+    }
+
+    async fn retrieve_all_keys(&self) -> Outcome<Vec<key::Model>> {
+        self.keys.get_all(None, None).await
+    }
+
+    async fn retrieve_vc(&self, id: &str) -> Outcome<vc::Model> {
+        self.vcs.get_by_id(id).await
+    }
+
+    async fn retrieve_all_vcs(&self) -> Outcome<Vec<vc::Model>> {
+        self.vcs.get_all(None, None).await
+    }
+
+    // ===== STORAGE (MUTATIONS) ===================================================================
+
+    /// Stores the key under a vault path derived here; the id carried by `plan` is ignored.
+    async fn register_key(&self, plan: key::Plan) -> Outcome<key::Model> {
+        let plan = key::Plan {
+            id: format!("{KEY_PATH_PREFIX}/{}", uuid::Uuid::new_v4()),
+            ..plan
+        };
+        save_key(&self.vault, self.keys.as_ref(), plan).await
+    }
+
+    async fn register_did(&self, mut plan: did::Plan) -> Outcome<did::Model> {
+        if plan.service.is_none() && !self.services.is_empty() {
+            plan.service = Some(self.services.clone());
+        }
+        let model = save_did(
+            self.signer.as_ref(),
+            self.dids.as_ref(),
+            self.keys.as_ref(),
+            plan,
+        )
+        .await?;
+        self.maybe_update_identity(&model).await?;
+        Ok(model)
+    }
+
+    async fn store_vc(&self, plan: vc::Plan) -> Outcome<vc::Model> {
+        let model = vc_model(plan.vc_body)?;
+        self.vcs.create(model).await
+    }
+
+    async fn set_default_did(&self, search: DidSearch) -> Outcome<did::Model> {
+        info!("NativeWalletService: set_default_did");
+        let id = self.retrieve_did(search).await?.id;
+        let model = self.dids.set_default_id(&id).await?;
+        self.replace_identity_from(&model).await?;
+        Ok(model)
+    }
+
+    // ===== DID-KEY MANAGEMENT ====================================================================
+
+    async fn add_key_to_did(&self, search: DidSearch, key_id: String) -> Outcome<did::Model> {
+        let mut model = self.retrieve_did(search).await?;
+        if model.r#type != DidType::Web {
+            return Err(Errors::not_impl(
+                format!("DID method {} does not support multiple keys", model.r#type),
+                None,
+            ));
+        }
+        if model.keys.iter().any(|k| k.internal() == key_id) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Key {key_id} is already attached to the DID"),
+                None,
+            ));
+        }
+
+        self.keys.get_by_id(&key_id).await?;
+        let key = self.signer.public_key(&key_id).await?;
+        let fragment = next_fragment(&model.keys);
+        model.did_document.add_key(&key, Some(&fragment));
+        model.keys.push(KeyRef::new(key_id, fragment));
+
+        let model = self.dids.update(model).await?;
+        self.maybe_update_identity(&model).await?;
+        Ok(model)
+    }
+
+    async fn remove_key_from_did(&self, search: DidSearch, key_id: String) -> Outcome<did::Model> {
+        let mut model = self.retrieve_did(search).await?;
+        if model.default_key.internal() == key_id {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Refusing to remove the default key of the DID. Switch the default key first.",
+                None,
+            ));
+        }
+        let key_ref = find_key_ref(&model, &key_id)?;
+
+        model.did_document.delete_key(key_ref.fragment());
+        model.keys.retain(|k| k.internal() != key_id);
+
+        let model = self.dids.update(model).await?;
+        self.maybe_update_identity(&model).await?;
+        Ok(model)
+    }
+
+    async fn set_default_key(&self, search: DidSearch, key_id: String) -> Outcome<did::Model> {
+        let mut model = self.retrieve_did(search).await?;
+        model.default_key = find_key_ref(&model, &key_id)?;
+
+        let model = self.dids.update(model).await?;
+        self.maybe_update_identity(&model).await?;
+        Ok(model)
+    }
+
+    // ===== DELETE OPERATIONS =====================================================================
+
+    /// Removes the private key from the vault along with its metadata.
+    async fn delete_key(&self, id: &str) -> Outcome<()> {
+        let in_use = self
+            .retrieve_all_dids()
+            .await?
+            .iter()
+            .any(|d| d.keys.iter().any(|k| k.internal() == id));
+        if in_use {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Refusing to delete a key still attached to a DID. Detach it first.",
+                None,
+            ));
+        }
+        self.vault.delete(None, id).await?;
+        self.keys.delete(id).await
+    }
+
+    async fn delete_did(&self, search: DidSearch) -> Outcome<()> {
+        let target = self.retrieve_did(search).await?;
+
+        let active = self.identity.read().await.did().id().to_string();
+        if active == target.did {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Refusing to delete the active identity's DID. Switch the default first.",
+                None,
+            ));
+        }
+
+        self.dids.delete(&target.id).await
+    }
+
+    async fn delete_vc(&self, id: &str) -> Outcome<()> {
+        self.vcs.delete(id).await
+    }
+
+    // ===== BACKUP ================================================================================
+
+    async fn export_keys(&self, passphrase: &str) -> Outcome<KeyBackup> {
+        let mut keys = Vec::new();
+        for model in self.keys.get_all(None, None).await? {
+            let pem: PemHelper = self.vault.read(None, &model.id).await?;
+            keys.push(BackedUpKey {
+                id: model.id,
+                alias: model.alias,
+                pem: pem.pem().to_string(),
+            });
+        }
+        let content = KeyBackupContent {
+            keys,
+            dids: self.dids.get_all(None, None).await?,
+        };
+        info!(
+            "Exporting {} keys and {} DIDs",
+            content.keys.len(),
+            content.dids.len()
+        );
+
+        // Key derivation is deliberately slow; keep it off the async workers.
+        let passphrase = passphrase.to_string();
+        tokio::task::spawn_blocking(move || KeyBackup::seal(&content, &passphrase))
+            .await
+            .map_err(|e| Errors::crazy("Key backup task failed", Some(Box::new(e))))?
+    }
+
+    async fn import_keys(&self, backup: KeyBackup, passphrase: &str) -> Outcome<KeyImportSummary> {
+        let passphrase = passphrase.to_string();
+        let content = tokio::task::spawn_blocking(move || backup.open(&passphrase))
+            .await
+            .map_err(|e| Errors::crazy("Key backup task failed", Some(Box::new(e))))??;
+
+        let mut summary = KeyImportSummary::default();
+        for key in content.keys {
+            if self.keys.get_by_id(&key.id).await.is_ok() {
+                let stored: PemHelper = self.vault.read(None, &key.id).await?;
+                if stored.pem().trim() != key.pem.trim() {
+                    return Err(Errors::format(
+                        BadFormat::Received,
+                        format!("Key {} is already stored with different material", key.id),
+                        None,
+                    ));
+                }
+                summary.skipped_keys.push(key.id);
+                continue;
+            }
+            let plan = key::Plan {
+                id: key.id.clone(),
+                alias: key.alias,
+                pem: key.pem,
+            };
+            save_key(&self.vault, self.keys.as_ref(), plan).await?;
+            summary.imported_keys.push(key.id);
+        }
+
+        for mut model in content.dids {
+            if self.dids.get_by_did(&model.did).await.is_ok() {
+                summary.skipped_dids.push(model.did);
+                continue;
+            }
+            for key_ref in &model.keys {
+                self.keys.get_by_id(key_ref.internal()).await?;
+            }
+            model.r#default = false;
+            let did = model.did.clone();
+            self.dids.create(model).await?;
+            summary.imported_dids.push(did);
+        }
+
+        info!(
+            "Imported {} keys and {} DIDs from backup",
+            summary.imported_keys.len(),
+            summary.imported_dids.len()
+        );
+        Ok(summary)
+    }
+
+    // ===== PROTOCOL HANDLING =====================================================================
+
+    /// Redeems a pre-authorized code offer and stores every credential received.
+    async fn process_oid4vci(&self, uri: &str, tx_code: Option<&str>) -> Outcome<()> {
+        info!("NativeWalletService: process_oid4vci({})", uri);
+        let offer = self.resolve_oid4vci_offer(uri).await?;
+        self.redeem_oid4vci_offer(uri, offer, tx_code, None).await
+    }
+
+    async fn redeem_oid4vci_offer(
+        &self,
+        uri: &str,
+        offer: ResolvedOffer,
+        tx_code: Option<&str>,
+        allow_list: Option<&dyn TrustedIssuerRepoTrait>,
+    ) -> Outcome<()> {
+        info!("NativeWalletService: redeem_oid4vci_offer({})", uri);
+        let ResolvedOffer { offer, metadata } = offer;
+        let grant = offer
+            .grants
+            .and_then(|grants| grants.pre_authorized_code)
+            .ok_or_else(|| {
+                Errors::not_impl("Only the pre-authorized code flow is supported", None)
+            })?;
+        let tx_code = settle_tx_code(grant.tx_code.as_ref(), tx_code)?;
+
+        // ===== DISCOVERY =========================================================================
+        let issuer = offer.credential_issuer.trim_end_matches('/').to_string();
+        let auth_server = grant
+            .authorization_server
+            .or_else(|| metadata.authorization_servers.clone()?.into_iter().next())
+            .unwrap_or_else(|| issuer.clone());
+        let auth_metadata: AuthServerMetadata = fetch_json(&format!(
+            "{}/.well-known/oauth-authorization-server",
+            auth_server.trim_end_matches('/')
+        ))
+        .await?;
+
+        // ===== TOKEN =============================================================================
+        let token_req = TokenRequest {
+            grant_type: OidcGrantType::PreAuthorizedCode,
+            pre_authorized_code: grant.pre_authorized_code,
+            tx_code,
+            client_id: None,
+        };
+        let url = &auth_metadata.token_endpoint;
+        let res = http_client()
+            .post(url, None, HttpBody::form(&token_req)?)
+            .await?;
+        if let (Some(config), StatusCode::BAD_REQUEST) = (&grant.tx_code, res.status()) {
+            return Err(tx_code_required(
+                config,
+                "Issuer rejected the transaction code",
+            ));
+        }
+        let token: IssuingToken = parse_res_or_fail(res, url, "POST").await?;
+        let headers = bearer_headers(&token.access_token)?;
+
+        // ===== CREDENTIALS =======================================================================
+        let mut c_nonce = token.c_nonce.clone();
+        for config_id in offer.credential_configuration_ids {
+            let nonce = match c_nonce.take() {
+                Some(nonce) => nonce,
+                None => fresh_nonce(&metadata).await?,
+            };
+            let proof = self
+                .proof_of_possession(&offer.credential_issuer, nonce)
+                .await?;
+            let cred_req = CredentialRequest {
+                credential_configuration_id: Some(config_id),
+                credential_identifier: None,
+                proof: Some(CredReqProof::Jwt {
+                    jwt: proof.as_str().to_string(),
+                }),
+                proofs: None,
+                credential_response_encryption: None,
+            };
+
+            let url = &metadata.credential_endpoint;
+            let res = http_client()
+                .post(url, Some(headers.clone()), HttpBody::json(&cred_req)?)
+                .await?;
+            let response: GiveVC = parse_res_or_fail(res, url, "POST").await?;
+            let credentials = response.credentials.ok_or_else(|| {
+                Errors::not_impl("Deferred credential issuance is not supported", None)
+            })?;
+
+            for item in credentials {
+                let model = vc_model(item.credential)?;
+                if let Some(registry) = allow_list {
+                    check_trusted_credential(&self.resolver, registry, &model).await?;
+                }
+                let model = self.vcs.create(model).await?;
+                info!("Stored credential {} of type {}", model.id, model.vc_type);
+            }
+
+            if let (Some(endpoint), Some(notification_id)) =
+                (&metadata.notification_endpoint, response.notification_id)
+            {
+                let notification = NotificationRequest {
+                    notification_id,
+                    event: NotificationEvent::CredentialAccepted,
+                    event_description: None,
+                };
+                let res = http_client()
+                    .post(
+                        endpoint,
+                        Some(headers.clone()),
+                        HttpBody::json(&notification)?,
+                    )
+                    .await?;
+                check_or_fail(res, endpoint, "POST")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Answers a Presentation Exchange request over `direct_post` with a JWT VP.
+    async fn process_oid4vp(&self, uri: &str) -> Outcome<()> {
+        info!("NativeWalletService: process_oid4vp({})", uri);
+        let candidates = self.presentation_candidates(uri).await?;
+        let selected = self.config.get_selection().select(&candidates)?;
+        self.present_credentials(uri, selected).await
+    }
+
+    async fn presentation_candidates(&self, uri: &str) -> Outcome<Vec<PresentationCandidate>> {
+        let (_, pd) = authorization_request(uri).await?;
+        let stored = self.retrieve_all_vcs().await?;
+        let now = Clock::now();
+        Ok(pd
+            .input_descriptors
+            .iter()
+            .map(|descriptor| {
+                let mut matching: Vec<&vc::Model> = stored
+                    .iter()
+                    .filter(|vc| presentable_jwt(vc, descriptor, now).is_some())
+                    .collect();
+                self.config.get_selection().rank(&mut matching);
+                PresentationCandidate {
+                    descriptor_id: descriptor.id.clone(),
+                    credentials: matching.into_iter().map(|vc| vc.id.clone()).collect(),
+                }
+            })
+            .collect())
+    }
+
+    async fn present_credentials(&self, uri: &str, selected: Vec<String>) -> Outcome<()> {
+        let (params, pd) = authorization_request(uri).await?;
+        let param = |name: &str| {
+            params.get(name).ok_or_else(|| {
+                Errors::format(
+                    BadFormat::Received,
+                    format!("Authorization request is missing '{name}'"),
+                    None,
+                )
+            })
+        };
+        let client_id = param("client_id")?;
+        let nonce = param("nonce")?;
+        let response_uri = param("response_uri")?;
+
+        // ===== CREDENTIAL SELECTION ==============================================================
+        let stored = self.retrieve_all_vcs().await?;
+        let chosen: Vec<&vc::Model> = selected
+            .iter()
+            .filter_map(|id| stored.iter().find(|vc| &vc.id == id))
+            .collect();
+        let now = Clock::now();
+        let mut matched: Vec<&str> = Vec::new();
+        let mut credentials: Vec<String> = Vec::new();
+        for descriptor in &pd.input_descriptors {
+            let candidate = chosen
+                .iter()
+                .find_map(|vc| presentable_jwt(vc, descriptor, now).map(|jwt| (vc, jwt)));
+            if let Some((vc, jwt)) = candidate {
+                info!(
+                    "Credential {} selected for descriptor {}",
+                    vc.id, descriptor.id
+                );
+                matched.push(&descriptor.id);
+                credentials.push(jwt.clone());
+            }
+        }
+        if !pd.is_satisfied_by(&matched) {
+            return Err(Errors::missing_resource(
+                &pd.id,
+                "No stored credentials satisfy the presentation definition",
+                None,
+            ));
+        }
+
+        // ===== PRESENTATION ======================================================================
+        let descriptor_map = matched
+            .iter()
+            .enumerate()
+            .map(|(i, id)| DescriptorMapEntry {
+                id: id.to_string(),
+                format: "jwt_vp".to_string(),
+                path: "$".to_string(),
+                path_nested: Some(Box::new(DescriptorMapEntry {
+                    id: id.to_string(),
+                    format: "jwt_vc_json".to_string(),
+                    path: format!("$.vp.verifiableCredential[{i}]"),
+                    path_nested: None,
+                })),
+            })
+            .collect();
+        let submission = PresentationSubmission {
+            id: uuid::Uuid::new_v4().to_string(),
+            definition_id: pd.id.clone(),
+            descriptor_map,
+        };
+
+        let (did, key_ref) = self.signing_identity().await;
+        let holder = did.id().to_string();
+        let iat = Clock::now().timestamp();
+        let claims = VPJwtClaims {
+            aud: client_id.clone(),
+            nonce: nonce.clone(),
+            iss: Some(holder.clone()),
+            sub: Some(holder.clone()),
+            jti: Some(format!("urn:uuid:{}", uuid::Uuid::new_v4())),
+            nbf: Some(iat),
+            exp: Some(iat + VP_LIFETIME_SECS),
+            iat: Some(iat),
+            vp: VpDocument {
+                context: vec![W3cDataModelVersion::V2.context().to_string()],
+                id: pd.id.clone(),
+                r#type: vec!["VerifiablePresentation".to_string()],
+                holder: Some(holder),
+                verifiable_credential: credentials,
+            },
+        };
+        let vp_token = self
+            .signer
+            .sign_enveloped(
+                &did,
+                &key_ref,
+                "JWT",
+                "json",
+                &serde_json::to_value(&claims)?,
+            )
+            .await?;
+
+        // ===== RESPONSE ==========================================================================
+        let mut form = HashMap::from([
+            ("vp_token".to_string(), vp_token.as_str().to_string()),
+            (
+                "presentation_submission".to_string(),
+                serde_json::to_string(&submission)?,
+            ),
+        ]);
+        if let Some(state) = params.get("state") {
+            form.insert("state".to_string(), state.clone());
+        }
+
+        let res = http_client()
+            .post(response_uri, None, HttpBody::Form(form))
+            .await?;
+        check_or_fail(res, response_uri, "POST")
+    }
+}
+
+// ===== INTERNAL HELPERS ==========================================================================
+impl NativeWalletService {
+    /// Returns the active identity and its default key.
+    async fn signing_identity(&self) -> (Did, KeyRef) {
+        let identity = self.identity.read().await;
+        (identity.did().clone(), identity.key_ref().clone())
+    }
+
+    /// Signs the OID4VCI key proof binding the issuer nonce to the active identity.
+    async fn proof_of_possession(&self, issuer: &str, nonce: String) -> Outcome<Jwt> {
+        let (did, key_ref) = self.signing_identity().await;
+        let claims = DidPossession {
+            iss: Some(did.id().to_string()),
+            aud: issuer.to_string(),
+            iat: Clock::now().timestamp(),
+            nonce,
+        };
+        self.signer
+            .sign_enveloped(
+                &did,
+                &key_ref,
+                PROOF_TYP,
+                "json",
+                &serde_json::to_value(&claims)?,
+            )
+            .await
+    }
+
+    /// If the model represents the new default DID, replace the cached identity.
+    async fn maybe_update_identity(&self, model: &did::Model) -> Outcome<()> {
+        if model.r#default {
+            self.replace_identity_from(model).await?;
+        }
+        Ok(())
+    }
+
+    /// Unconditionally replace the cached identity from the model.
+    async fn replace_identity_from(&self, model: &did::Model) -> Outcome<()> {
+        let new = identity_from(&self.config, model)?;
+        let mut guard = self.identity.write().await;
+        *guard = new;
+        Ok(())
+    }
+}
+
+/// Builds the identity of a stored DID, adding the `did:jwk` alias when the did:web config
+/// asks for a dual stack.
+fn identity_from(config: &NativeWalletConfig, model: &did::Model) -> Outcome<Identity> {
+    let did = Did::parse(&model.did)?;
+    let identity = Identity::new(did, model.did_document.clone(), model.default_key.clone());
+    if config.did_config().is_dual_stack() {
+        return identity.with_jwk_alias();
+    }
+    Ok(identity)
+}
+
+/// Builds the base DID the configuration asks for over the agent key.
+fn base_did_builder(config: &NativeWalletConfig, key_data: &PemHelper) -> Outcome<DidBuilder> {
+    match config.did_config() {
+        DidConfig::Jwk => Ok(DidBuilder::new_jwk(key_data.pem())),
+        DidConfig::Web { web_config } => Ok(DidBuilder::new_web(
+            &web_config.domain,
+            web_config.path.as_deref(),
+            web_config.port.as_deref(),
+        )),
+        DidConfig::Other(did) => Err(Errors::not_impl(
+            format!("did type {did} not supported"),
+            None,
+        )),
+    }
+}
+
+/// Writes the private key to the vault under its id and records its metadata.
+async fn save_key(
+    vault: &VaultService,
+    keys: &dyn KeyRepoTrait,
+    plan: key::Plan,
+) -> Outcome<key::Model> {
+    let pem = PemHelper::priv_from_pem(&plan.pem)?;
+    vault.write(None, &plan.id, &pem).await?;
+
+    keys.create(key::Model {
+        id: plan.id,
+        alias: plan.alias,
+        kty: pem.kty().clone(),
+        crv: pem.crv().cloned(),
+        created_at: Clock::now(),
+    })
+    .await
+}
+
+/// Builds the DID and its document over registered keys, the first key becoming the default.
+///
+/// The first DID stored in the wallet becomes its default one.
+async fn save_did(
+    signer: &dyn SignerTrait,
+    dids: &dyn DidRepoTrait,
+    keys: &dyn KeyRepoTrait,
+    plan: did::Plan,
+) -> Outcome<did::Model> {
+    let did = plan.builder.build()?;
+    if plan.keys.is_empty() || (did.r#type() == DidType::Jwk && plan.keys.len() != 1) {
+        return Err(Errors::format(
+            BadFormat::Received,
+            format!("Invalid key count for a {} DID", did.r#type()),
+            None,
+        ));
+    }
+    if dids.get_by_did(did.id()).await.is_ok() {
+        return Err(Errors::format(
+            BadFormat::Received,
+            format!("DID {} is already stored", did.id()),
+            None,
+        ));
+    }
+
+    let mut material = Vec::new();
+    let mut refs = Vec::new();
+    for (i, key_id) in plan.keys.iter().enumerate() {
+        keys.get_by_id(key_id).await?;
+        let key = signer.public_key(key_id).await?;
+        let jwk_did = format!(
+            "did:jwk:{}",
+            encode_url_safe_no_pad(serde_json::to_vec(&key.public_jwk())?)
+        );
+        if did.r#type() == DidType::Jwk && jwk_did != did.id() {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "did:jwk does not derive from the given key",
+                None,
+            ));
+        }
+        let fragment = i.to_string();
+        material.push((key, fragment.clone()));
+        refs.push(KeyRef::new(key_id, fragment));
+    }
+
+    let mut did_document = DidDocument::base(&did, material);
+    if let Some(services) = plan.service.clone() {
+        did_document.add_services(services);
+    }
+    let first = dids.get_all(Some(1), None).await?.is_empty();
+
+    dids.create(did::Model {
+        id: uuid::Uuid::new_v4().to_string(),
+        did: did.id().to_string(),
+        alias: plan.alias,
+        r#default: first,
+        r#type: did.r#type(),
+        default_key: refs[0].clone(),
+        keys: refs,
+        did_document,
+        service: plan.service,
+    })
+    .await
+}
+
+/// Returns the lowest numeric fragment not used by `keys`.
+fn next_fragment(keys: &[KeyRef]) -> String {
+    let mut i = 0;
+    while keys.iter().any(|k| k.fragment() == i.to_string()) {
+        i += 1;
+    }
+    i.to_string()
+}
+
+fn find_key_ref(model: &did::Model, key_id: &str) -> Outcome<KeyRef> {
+    model
+        .keys
+        .iter()
+        .find(|k| k.internal() == key_id)
+        .cloned()
+        .ok_or_else(|| Errors::missing_resource(key_id, "Key not attached to the DID", None))
+}
+
+/// Decodes a credential as received from an issuer into its wallet record.
+///
+/// JWT credentials are indexed by their payload and SD-JWT ones by their disclosed claims,
+/// so that Presentation Exchange paths resolve against `parsed_document` as a verifier would.
+pub(crate) fn vc_model(vc_body: VcBody) -> Outcome<vc::Model> {
+    let (raw, vc_format, parsed_document, valid_until) = match &vc_body {
+        VcBody::Jwt(raw) if VpFormat::is_sd_jwt(raw) => {
+            let claims = SdJwt::parse(raw)?.disclosed_claims()?;
+            let exp = claims.get("exp").and_then(Value::as_i64);
+            (
+                raw.clone(),
+                VcFormat::SdJwtVc,
+                claims,
+                exp.and_then(timestamp),
+            )
+        }
+        VcBody::Jwt(raw) => {
+            let jwt = Jwt::parse(raw)?;
+            let claims: VCJwtClaims = jwt.unsafe_claims()?;
+            let valid_until = claims
+                .vc_doc()
+                .valid_until
+                .or_else(|| claims.exp().and_then(timestamp));
+            let format = match claims {
+                VCJwtClaims::V1(_) => VcFormat::JwtVcJson,
+                VCJwtClaims::V2(_) => VcFormat::JwtVcJsonLd,
+            };
+            (
+                raw.clone(),
+                format,
+                jwt.unverified_payload().clone(),
+                valid_until,
+            )
+        }
+        VcBody::JsonLd(doc) => {
+            let parsed: VcDocument = serde_json::from_value(doc.clone())?;
+            (
+                doc.to_string(),
+                VcFormat::LdpVc,
+                doc.clone(),
+                parsed.valid_until,
+            )
+        }
+    };
+
+    let presented = PresentedCredential::decode(&raw)?;
+    let vc_type = presented
+        .r#type
+        .iter()
+        .find(|t| t.as_str() != "VerifiableCredential")
+        .and_then(|t| VcType::from_str(t).ok())
+        .ok_or_else(|| Errors::format(BadFormat::Received, "Credential has no type", None))?;
+
+    Ok(vc::Model {
+        id: uuid::Uuid::new_v4().to_string(),
+        vc_body,
+        vc_type,
+        vc_format,
+        holder_did: presented
+            .credential_subject
+            .get("id")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        issuer_did: presented.issuer,
+        parsed_document,
+        valid_until,
+        added_on: Clock::now(),
+    })
+}
+
+fn timestamp(secs: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(secs, 0)
+}
+
+/// Reads the query parameters of an `openid-credential-offer://` or `openid4vp://` uri.
+fn uri_params(uri: &str) -> Outcome<HashMap<String, String>> {
+    let (_, query) = uri.split_once('?').ok_or_else(|| {
+        Errors::format(BadFormat::Received, "Uri carries no query parameters", None)
+    })?;
+    serde_urlencoded::from_str(query).map_err(|e| {
+        Errors::format(
+            BadFormat::Received,
+            "Invalid uri query parameters",
+            Some(Box::new(e)),
+        )
+    })
+}
+
+/// Reads an OID4VP authorization request and its presentation definition.
+///
+/// Only `vp_token` responses over `direct_post` answering a Presentation Exchange
+/// definition, by value or by reference, are supported. The verifier is identified by the
+/// `redirect_uri` client id scheme, so the response URI must be its client id; otherwise a
+/// request could name any verifier as audience while collecting the presentation elsewhere.
+async fn authorization_request(uri: &str) -> Outcome<(HashMap<String, String>, VPDef)> {
+    let params = uri_params(uri)?;
+    let param = |name: &str| {
+        params.get(name).ok_or_else(|| {
+            Errors::format(
+                BadFormat::Received,
+                format!("Authorization request is missing '{name}'"),
+                None,
+            )
+        })
+    };
+
+    if param("response_type")? != "vp_token" {
+        return Err(Errors::not_impl(
+            "Only vp_token responses are supported",
+            None,
+        ));
+    }
+    if param("response_mode")? != "direct_post" {
+        return Err(Errors::not_impl(
+            "Only the direct_post response mode is supported",
+            None,
+        ));
+    }
+    let scheme = params
+        .get("client_id_scheme")
+        .map_or("redirect_uri", String::as_str);
+    if scheme != "redirect_uri" {
+        return Err(Errors::not_impl(
+            format!("Client id scheme '{scheme}' is not supported"),
+            None,
+        ));
+    }
+    if param("response_uri")? != param("client_id")? {
+        return Err(Errors::security(
+            "Authorization request response_uri does not match its client_id",
+            None,
+        ));
+    }
+
+    let pd: VPDef = match (
+        params.get("presentation_definition"),
+        params.get("presentation_definition_uri"),
+    ) {
+        (Some(pd), _) => serde_json::from_str(pd)?,
+        (None, Some(url)) => fetch_json(url).await?,
+        (None, None) => {
+            return Err(Errors::not_impl(
+                "Only Presentation Exchange requests are supported",
+                None,
+            ));
+        }
+    };
+    Ok((params, pd))
+}
+
+/// Returns the JWT of `vc` when it is still valid and answers `descriptor`.
+///
+/// A JWT VC reveals every claim, so it never answers a descriptor limiting disclosure.
+fn presentable_jwt<'a>(
+    vc: &'a vc::Model,
+    descriptor: &InputDescriptor,
+    now: DateTime<Utc>,
+) -> Option<&'a String> {
+    match &vc.vc_body {
+        VcBody::Jwt(jwt)
+            if matches!(vc.vc_format, VcFormat::JwtVcJson | VcFormat::JwtVcJsonLd)
+                && vc.valid_until.is_none_or(|until| until > now)
+                && descriptor.accepts_format("jwt_vc_json")
+                && !descriptor.limits_disclosure()
+                && descriptor.is_satisfied_by(&vc.parsed_document) =>
+        {
+            Some(jwt)
+        }
+        _ => None,
+    }
+}
+
+/// Fetches a fresh `c_nonce` from the issuer's Nonce Endpoint.
+async fn fresh_nonce(metadata: &IssuerMetadata) -> Outcome<String> {
+    let url = metadata
+        .nonce_endpoint
+        .as_deref()
+        .ok_or_else(|| Errors::not_impl("Issuer offers no way to obtain a fresh c_nonce", None))?;
+    let res = http_client()
+        .post(url, Some(json_headers()), HttpBody::None)
+        .await?;
+    let body: Value = parse_res_or_fail(res, url, "POST").await?;
+    body.get("c_nonce")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| Errors::format(BadFormat::Received, "Nonce response has no c_nonce", None))
+}
+
+fn bearer_headers(token: &str) -> Outcome<axum::http::HeaderMap> {
+    let mut headers = json_headers();
+    let value = HeaderValue::from_str(&format!("Bearer {token}")).map_err(|e| {
+        Errors::format(
+            BadFormat::Received,
+            "Invalid access token",
+            Some(Box::new(e)),
+        )
+    })?;
+    headers.insert(AUTHORIZATION, value);
+    Ok(headers)
+}
+
+async fn fetch_json<T: DeserializeOwned>(url: &str) -> Outcome<T> {
+    let res = http_client().get(url, Some(json_headers())).await?;
+    parse_res_or_fail(res, url, "GET").await
+}
+
+/// Asks the caller for a Transaction Code, describing the one the offer expects.
+async fn parse_res_or_fail<T: DeserializeOwned>(
+    res: Response,
+    url: &str,
+    method: &str,
+) -> Outcome<T> {
+    if res.status().is_success() {
+        res.parse_json().await
+    } else {
+        Err(Errors::wallet(
+            url,
+            method,
+            Some(res.status()),
+            "unexpected http status",
+            None,
+        ))
+    }
+}
+
+fn check_or_fail(res: Response, url: &str, method: &str) -> Outcome<()> {
+    if res.status().is_success() {
+        Ok(())
+    } else {
+        Err(Errors::wallet(
+            url,
+            method,
+            Some(res.status()),
+            "unexpected http status",
+            None,
+        ))
+    }
+}
@@ -0,0 +1,167 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::capabilities::{DidResolver, Kid, Verifier};
+use crate::data::entities::wallet::vc;
+use crate::errors::{BadFormat, Errors, MissingAction, Outcome, PetitionFailure};
+use crate::services::client::ClientTrait;
+use crate::services::repo::traits::shared::TrustedIssuerRepoTrait;
+use crate::types::issuance::{IssuerMetadata, TxCodeConfig, VcBody, VcCredOffer};
+use crate::types::jwt::{Jwt, SdJwt};
+use crate::types::verification::VpFormat;
+use crate::types::wallet::ResolvedOffer;
+use crate::utils::{ResponseExt, http_client, json_headers};
+use tracing::warn;
+
+/// Fetches the credential offer behind an `openid-credential-offer://` uri and the metadata
+/// of its issuer, checking they belong together.
+///
+/// Only touches public endpoints, so every wallet backend shares it.
+pub async fn resolve_credential_offer(uri: &str) -> Outcome<ResolvedOffer> {
+    let (_, query) = uri.split_once('?').ok_or_else(|| {
+        Errors::format(BadFormat::Received, "Uri carries no query parameters", None)
+    })?;
+    let params: HashMap<String, String> = serde_urlencoded::from_str(query).map_err(|e| {
+        Errors::format(
+            BadFormat::Received,
+            "Invalid uri query parameters",
+            Some(Box::new(e)),
+        )
+    })?;
+
+    let offer: VcCredOffer = match (
+        params.get("credential_offer"),
+        params.get("credential_offer_uri"),
+    ) {
+        (Some(offer), _) => serde_json::from_str(offer)?,
+        (None, Some(url)) => fetch_json(url).await?,
+        (None, None) => {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Credential offer uri carries no offer",
+                None,
+            ));
+        }
+    };
+
+    let issuer = offer.credential_issuer.trim_end_matches('/');
+    let metadata: IssuerMetadata =
+        fetch_json(&format!("{issuer}/.well-known/openid-credential-issuer")).await?;
+    metadata.check_offer(&offer)?;
+    Ok(ResolvedOffer { offer, metadata })
+}
+
+/// Checks, holder side, that a received credential was signed by an issuer registered for its
+/// type, before it is stored.
+///
+/// The issuer is the one the credential itself names, and the signature must come from one of
+/// its keys: neither the offer nor the issuer metadata are trusted to say who issued it. The
+/// issuer DID is resolved through `resolver`.
+///
+/// # Errors
+/// Returns an [`Errors::SecurityError`] when the credential is not signed by its issuer or the
+/// issuer is not registered for its type.
+pub async fn check_trusted_credential(
+    resolver: &DidResolver,
+    registry: &dyn TrustedIssuerRepoTrait,
+    credential: &vc::Model,
+) -> Outcome<()> {
+    let kid = match &credential.vc_body {
+        VcBody::Jwt(raw) if VpFormat::is_sd_jwt(raw) => {
+            signer_of(resolver, SdJwt::parse(raw)?.jwt()).await?
+        }
+        VcBody::Jwt(raw) => signer_of(resolver, &Jwt::parse(raw)?).await?,
+        VcBody::JsonLd(doc) => Verifier::verify_data_integrity(resolver, doc).await?.2,
+    };
+    let issuer = credential.issuer_did.as_str();
+    if kid.did().id() != issuer {
+        return Err(Errors::security(
+            format!(
+                "Credential of type {} is not signed by its issuer {issuer}",
+                credential.vc_type
+            ),
+            None,
+        ));
+    }
+
+    let registrations = registry.get_by_issuer(issuer).await?;
+    if !registrations.iter().any(|r| r.covers(&credential.vc_type)) {
+        return Err(Errors::security(
+            format!("Issuer {issuer} is not trusted for {}", credential.vc_type),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+/// Settles the Transaction Code sent when redeeming an offer whose grant asks for `required`.
+///
+/// # Errors
+/// Returns a [`MissingAction::TxCode`] error carrying the requirements when the offer needs a
+/// code and none was given, or a format error when `tx_code` does not meet them.
+pub fn settle_tx_code(
+    required: Option<&TxCodeConfig>,
+    tx_code: Option<&str>,
+) -> Outcome<Option<String>> {
+    match (required, tx_code) {
+        (Some(config), Some(code)) => {
+            config.check(code)?;
+            Ok(Some(code.to_string()))
+        }
+        (Some(config), None) => Err(tx_code_required(
+            config,
+            "Offer requires a transaction code",
+        )),
+        (None, Some(_)) => {
+            warn!("Ignoring transaction code for an offer that does not require one");
+            Ok(None)
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+/// Asks the holder for the Transaction Code described by `config`.
+pub fn tx_code_required(config: &TxCodeConfig, reason: &str) -> Errors {
+    let details = serde_json::to_string(config).unwrap_or_default();
+    Errors::missing_action(MissingAction::TxCode, reason, None).with_details(details)
+}
+
+async fn signer_of(resolver: &DidResolver, jwt: &Jwt) -> Outcome<Kid> {
+    Ok(Verifier::verify_enveloped::<Value>(resolver, jwt, None)
+        .await?
+        .0)
+}
+
+async fn fetch_json<T: DeserializeOwned>(url: &str) -> Outcome<T> {
+    let res = http_client().get(url, Some(json_headers())).await?;
+    if !res.status().is_success() {
+        return Err(Errors::petition(
+            url,
+            "GET",
+            Some(res.status()),
+            PetitionFailure::HttpStatus(res.status()),
+            "Credential issuer answered with an unexpected status",
+            None,
+        ));
+    }
+    res.parse_json().await
+}
@@ -0,0 +1,113 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use super::WalletTrait;
+use crate::errors::{BadFormat, Errors, Outcome};
+
+/// Set of wallets served side by side, one per tenant, selected by id or alias.
+///
+/// Every wallet keeps its own DIDs, keys and identity; the registry only routes to them.
+/// It is filled at startup, each entry usually built by [`super::build_wallet`].
+#[derive(Default, Clone)]
+pub struct WalletRegistry {
+    wallets: BTreeMap<String, Arc<dyn WalletTrait>>,
+    aliases: BTreeMap<String, String>,
+}
+
+impl WalletRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `wallet` under `id`, optionally reachable through `alias` too.
+    ///
+    /// Both end up as a path segment of the wallet routes, so they must be slugs made of ASCII
+    /// letters, digits, `-` and `_`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`] if the id or alias is not a slug or is already taken.
+    pub fn register(
+        &mut self,
+        id: impl Into<String>,
+        alias: Option<String>,
+        wallet: Arc<dyn WalletTrait>,
+    ) -> Outcome<()> {
+        let id = id.into();
+        if let Some(bad) = std::iter::once(&id).chain(&alias).find(|key| !is_slug(key)) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Wallet id or alias '{bad}' is not a path-safe slug"),
+                None,
+            ));
+        }
+        let taken = |key: &str| self.wallets.contains_key(key) || self.aliases.contains_key(key);
+        if taken(&id) || alias.as_deref().is_some_and(taken) || alias.as_ref() == Some(&id) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Wallet '{id}' clashes with a registered wallet id or alias"),
+                None,
+            ));
+        }
+        if let Some(alias) = alias {
+            self.aliases.insert(alias, id.clone());
+        }
+        self.wallets.insert(id, wallet);
+        Ok(())
+    }
+
+    /// Resolves a wallet by id or alias.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::MissingResourceError`] if no wallet answers to `key`.
+    pub fn get(&self, key: &str) -> Outcome<Arc<dyn WalletTrait>> {
+        let id = self.aliases.get(key).map(String::as_str).unwrap_or(key);
+        self.wallets
+            .get(id)
+            .cloned()
+            .ok_or_else(|| Errors::missing_resource(key, "Wallet not registered", None))
+    }
+
+    /// Returns the registered wallets by id.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Arc<dyn WalletTrait>)> {
+        self.wallets
+            .iter()
+            .map(|(id, wallet)| (id.as_str(), wallet))
+    }
+
+    /// Returns every alias with the id it points to.
+    pub fn aliases(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.aliases
+            .iter()
+            .map(|(alias, id)| (alias.as_str(), id.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.wallets.is_empty()
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn is_slug(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
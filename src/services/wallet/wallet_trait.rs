@@ -17,10 +17,15 @@
 
 use crate::capabilities::Did;
 use crate::data::entities::wallet::{did, key, vc};
-use crate::errors::Outcome;
+use crate::errors::{Errors, Outcome};
+use crate::services::repo::traits::shared::TrustedIssuerRepoTrait;
 use crate::types::dids::DidDocument;
-use crate::types::wallet::{DidSearch, Identity, WalletInfo};
+use crate::types::wallet::{
+    DidSearch, Identity, KeyBackup, KeyImportSummary, OnboardingStatus, PresentationCandidate,
+    ResolvedOffer, WalletInfo,
+};
 use async_trait::async_trait;
+use serde_json::Value;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -48,6 +53,28 @@ pub trait WalletTrait: Send + Sync + 'static {
     /// Returns the wallet identity reference shared across services.
     fn get_identity(&self) -> Arc<RwLock<Identity>>;
 
+    // ===== ONBOARDING ============================================================================
+
+    /// Reports which onboarding steps the wallet has gone through.
+    ///
+    /// Remote backends onboard on their own side and keep the default, which is not implemented.
+    async fn onboarding_status(&self) -> Outcome<OnboardingStatus> {
+        Err(Errors::not_impl(
+            "This wallet does not report its onboarding",
+            None,
+        ))
+    }
+
+    /// Runs the onboarding steps still missing and switches to the resulting identity.
+    ///
+    /// Safe to call again after a failure: completed steps are detected and skipped.
+    async fn onboard(&self) -> Outcome<OnboardingStatus> {
+        Err(Errors::not_impl(
+            "This wallet cannot be onboarded locally",
+            None,
+        ))
+    }
+
     // ===== STORAGE (READ ONLY) ===================================================================
 
     /// Retrieves a DID by internal id or by DID string.
@@ -74,6 +101,8 @@ pub trait WalletTrait: Send + Sync + 'static {
     // ===== STORAGE (MUTATIONS) ===================================================================
 
     /// Registers a new cryptographic key.
+    ///
+    /// Wallets may store the key under an id of their own; the returned model carries it.
     async fn register_key(&self, plan: key::Plan) -> Outcome<key::Model>;
 
     /// Registers a new DID associated with a set of keys.
@@ -110,11 +139,111 @@ pub trait WalletTrait: Send + Sync + 'static {
     /// Deletes a verifiable credential by its identifier.
     async fn delete_vc(&self, id: &str) -> Outcome<()>;
 
+    // ===== BACKUP ================================================================================
+
+    /// Exports every private key and DID of the wallet, encrypted under `passphrase`.
+    ///
+    /// Backends keeping keys outside the agent keep the default, which is not implemented.
+    async fn export_keys(&self, passphrase: &str) -> Outcome<KeyBackup> {
+        let _ = passphrase;
+        Err(Errors::not_impl("This wallet cannot export its keys", None))
+    }
+
+    /// Restores the keys and DIDs of `backup`, skipping the ones already stored.
+    ///
+    /// Imported DIDs never replace the default one; use [`WalletTrait::set_default_did`].
+    ///
+    /// # Errors
+    /// Returns a security error when `passphrase` does not open the backup.
+    async fn import_keys(&self, backup: KeyBackup, passphrase: &str) -> Outcome<KeyImportSummary> {
+        let _ = (backup, passphrase);
+        Err(Errors::not_impl("This wallet cannot import keys", None))
+    }
+
+    // ===== SESSION HANDOFF =======================================================================
+
+    /// Returns the backend session the wallet is logged in with, so a standby instance can
+    /// take it over without logging in again.
+    ///
+    /// Backends without a session keep the default.
+    async fn export_session(&self) -> Option<Value> {
+        None
+    }
+
+    /// Resumes a session exported by [`export_session`](Self::export_session) on another
+    /// instance.
+    async fn resume_session(&self, session: Value) -> Outcome<()> {
+        let _ = session;
+        Ok(())
+    }
+
     // ===== PROTOCOL HANDLING =====================================================================
 
+    /// Fetches the credential offer at `uri` with the metadata of its issuer, without
+    /// redeeming it, so the caller can vet the issuer and the offered configurations.
+    ///
+    /// # Errors
+    /// Returns a format error when the metadata were not published by the offering issuer
+    /// or do not describe every offered configuration.
+    async fn resolve_oid4vci_offer(&self, uri: &str) -> Outcome<ResolvedOffer> {
+        super::resolve_credential_offer(uri).await
+    }
+
     /// Processes an OID4VCI issuance flow from a URI.
-    async fn process_oid4vci(&self, uri: &str) -> Outcome<()>;
+    ///
+    /// `tx_code` is the Transaction Code the holder received out-of-band. When the offer
+    /// requires one and none is given, returns a [`MissingAction::TxCode`] error carrying the
+    /// offer's requirements as details, so the caller can retry with the code.
+    async fn process_oid4vci(&self, uri: &str, tx_code: Option<&str>) -> Outcome<()>;
+
+    /// Redeems the credential offer at `uri`, already fetched by
+    /// [`resolve_oid4vci_offer`](Self::resolve_oid4vci_offer), without fetching it again.
+    ///
+    /// With an `allow_list`, every credential received is checked with
+    /// [`check_trusted_credential`](super::check_trusted_credential) before it is stored.
+    /// Backends redeeming offers remotely keep the default, which hands `uri` over to
+    /// [`process_oid4vci`](Self::process_oid4vci) and cannot honour an allow list.
+    async fn redeem_oid4vci_offer(
+        &self,
+        uri: &str,
+        offer: ResolvedOffer,
+        tx_code: Option<&str>,
+        allow_list: Option<&dyn TrustedIssuerRepoTrait>,
+    ) -> Outcome<()> {
+        let _ = offer;
+        if allow_list.is_some() {
+            return Err(Errors::not_impl(
+                "This wallet cannot vet credentials before storing them",
+                None,
+            ));
+        }
+        self.process_oid4vci(uri, tx_code).await
+    }
 
     /// Processes an OID4VP presentation flow from a URI.
     async fn process_oid4vp(&self, uri: &str) -> Outcome<()>;
+
+    /// Lists, per input descriptor of the OID4VP request at `uri`, the stored credentials
+    /// able to answer it, without presenting anything.
+    ///
+    /// Backends that cannot split the exchange keep the default, which is not implemented.
+    async fn presentation_candidates(&self, uri: &str) -> Outcome<Vec<PresentationCandidate>> {
+        let _ = uri;
+        Err(Errors::not_impl(
+            "This wallet cannot hold presentations for consent",
+            None,
+        ))
+    }
+
+    /// Answers the OID4VP request at `uri` with exactly the credentials `selected`.
+    ///
+    /// # Errors
+    /// Returns a missing resource error when the selection does not satisfy the request.
+    async fn present_credentials(&self, uri: &str, selected: Vec<String>) -> Outcome<()> {
+        let _ = (uri, selected);
+        Err(Errors::not_impl(
+            "This wallet cannot present a chosen set of credentials",
+            None,
+        ))
+    }
 }
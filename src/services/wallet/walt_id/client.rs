@@ -0,0 +1,336 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::future::Future;
+use std::sync::Arc;
+
+use axum::http::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use axum::http::{HeaderMap, HeaderValue};
+use reqwest::Response;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use super::config::{WalletOp, WaltIdConfig};
+use crate::config::traits::WalletConfigTrait;
+use crate::config::types::HostType;
+use crate::errors::{BadFormat, Errors, MissingAction, Outcome, PetitionFailure};
+use crate::services::client::{ClientService, ClientTrait};
+use crate::services::vault::{VaultService, VaultTrait};
+use crate::types::http::HttpBody;
+use crate::types::secrets::SemiWaltIdSecrets;
+use crate::types::wallet::waltid::{AuthJwtClaims, WalletLoginResponse, WalletSession};
+use crate::utils::{
+    ParseHeaderExt, ResponseExt, decode_url_safe_no_pad, expect_from_env, json_headers,
+};
+
+/// Seconds before expiry at which the wallet session token is renewed.
+const TOKEN_REFRESH_MARGIN_SECS: u64 = 60;
+
+/// Authenticated transport to the walt.id wallet-api.
+///
+/// Holds the account session and bounds every call by the deadline of its operation family.
+pub struct WaltIdClient {
+    config: WaltIdConfig,
+    vault: Arc<VaultService>,
+    session: Mutex<WalletSession>,
+    /// Serializes re-logins so concurrent calls seeing an expiring token log in only once.
+    token_refresh: Mutex<()>,
+    client: ClientService,
+}
+
+impl WaltIdClient {
+    pub fn new(config: WaltIdConfig, vault: Arc<VaultService>) -> Self {
+        // Deadlines are enforced per operation; the transport only stops the slowest ones.
+        let client = ClientService::new(10, config.get_max_timeout().as_secs(), 0);
+        Self {
+            config,
+            vault,
+            session: Mutex::new(WalletSession {
+                account_id: None,
+                token: None,
+                token_exp: None,
+                wallets: vec![],
+            }),
+            token_refresh: Mutex::new(()),
+            client,
+        }
+    }
+
+    pub fn config(&self) -> &WaltIdConfig {
+        &self.config
+    }
+
+    pub fn session(&self) -> &Mutex<WalletSession> {
+        &self.session
+    }
+
+    /// Calls the wallet-api `path`, failing on any non-success status.
+    pub async fn request(
+        &self,
+        method: &str,
+        path: &str,
+        body: HttpBody,
+        use_auth: bool,
+        is_json: bool,
+        error_msg: &str,
+    ) -> Outcome<Response> {
+        let url = self.url(path);
+        let mut headers = if is_json {
+            json_headers()
+        } else {
+            let mut h = HeaderMap::new();
+            h.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+            h.insert(ACCEPT, HeaderValue::from_static("application/json"));
+            h
+        };
+
+        if use_auth {
+            let token = self.get_token().await?;
+            headers.insert(AUTHORIZATION, format!("Bearer {}", token).parse_header()?);
+        }
+
+        let op = WalletOp::classify(method, path);
+        let url = url.as_str();
+        let call = |headers: HeaderMap, body: HttpBody| async move {
+            let res = self.send(op, method, url, headers, body).await?;
+            if res.status().is_success() {
+                Ok(res)
+            } else {
+                Err(Errors::wallet(
+                    url,
+                    method,
+                    Some(res.status()),
+                    error_msg,
+                    None,
+                ))
+            }
+        };
+
+        if op == WalletOp::Read {
+            // Reads are idempotent, so a transient failure is simply sent again.
+            self.retry_step(
+                path,
+                || call(headers.clone(), HttpBody::None),
+                || async { None },
+            )
+            .await
+        } else {
+            call(headers, body).await
+        }
+    }
+
+    /// Sends a wallet-api call bounded by the deadline of its operation family.
+    ///
+    /// Hitting the deadline drops the in-flight request. Callers read the session before
+    /// sending, so no wallet lock is held while the call runs and a hung wallet cannot
+    /// stall onboarding or the other operations.
+    pub async fn send(
+        &self,
+        op: WalletOp,
+        method: &str,
+        url: &str,
+        headers: HeaderMap,
+        body: HttpBody,
+    ) -> Outcome<Response> {
+        let call = async {
+            match method {
+                "GET" => self.client.get(url, Some(headers)).await,
+                "POST" => self.client.post(url, Some(headers), body).await,
+                "DELETE" => self.client.delete(url, Some(headers), body).await,
+                _ => Err(Errors::not_impl(format!("Method {}", method), None)),
+            }
+        };
+
+        let deadline = self.config.get_timeout(op);
+        tokio::time::timeout(deadline, call).await.map_err(|_| {
+            warn!("Wallet {op:?} call to {url} cancelled after {deadline:?}");
+            Errors::petition(
+                url,
+                method,
+                None,
+                PetitionFailure::Timeout,
+                format!("Wallet call exceeded its {deadline:?} deadline"),
+                None,
+            )
+        })?
+    }
+
+    /// Runs an exchange step, retrying it while the wallet fails transiently.
+    ///
+    /// Before each retry `landed` checks whether the failed attempt took effect anyway; its
+    /// value is then returned instead of repeating a step that is not idempotent.
+    pub async fn retry_step<T, F, Fut, L, LFut>(
+        &self,
+        step: &str,
+        mut call: F,
+        mut landed: L,
+    ) -> Outcome<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Outcome<T>>,
+        L: FnMut() -> LFut,
+        LFut: Future<Output = Option<T>>,
+    {
+        let retries = self.config.get_retries();
+        let mut backoff = retries.backoff;
+        let mut attempt = 1;
+        loop {
+            let err = match call().await {
+                Ok(value) => return Ok(value),
+                Err(e) if e.is_transient() && attempt < retries.attempts => e,
+                Err(e) => return Err(e),
+            };
+            warn!(
+                "Wallet step '{step}' failed (attempt {attempt}/{}): {}",
+                retries.attempts,
+                err.reason()
+            );
+            tokio::time::sleep(backoff).await;
+            if let Some(value) = landed().await {
+                info!("Wallet step '{step}' took effect despite the failure");
+                return Ok(value);
+            }
+            backoff *= 2;
+            attempt += 1;
+        }
+    }
+
+    /// Registers the account kept in the vault, telling whether it was new.
+    pub async fn register(&self) -> Outcome<bool> {
+        info!("Registering in web wallet");
+        let url = self.url("/auth/register");
+        let db_path = expect_from_env("VAULT_APP_WALLET");
+        let body = self.vault.read(None, &db_path).await?;
+
+        let res = self
+            .send(
+                WalletOp::Auth,
+                "POST",
+                &url,
+                json_headers(),
+                HttpBody::Json(body),
+            )
+            .await?;
+
+        if res.status().is_success() {
+            info!("Wallet account registration successful");
+            Ok(true)
+        } else if res.status().as_u16() == 409 {
+            warn!("Wallet account has already registered");
+            Ok(false)
+        } else {
+            Err(Errors::wallet(
+                &url,
+                "POST",
+                Some(res.status()),
+                "Petition to register Wallet failed",
+                None,
+            ))
+        }
+    }
+
+    pub async fn login(&self) -> Outcome<()> {
+        info!("Login into web wallet");
+
+        let db_path = expect_from_env("VAULT_APP_WALLET");
+        let body: SemiWaltIdSecrets = self.vault.read(None, &db_path).await?;
+
+        // Sent directly: going through `request` would make token renewal recurse into itself.
+        let url = self.url("/auth/login");
+        let res = self
+            .send(
+                WalletOp::Auth,
+                "POST",
+                &url,
+                json_headers(),
+                HttpBody::json(&body)?,
+            )
+            .await?;
+        if !res.status().is_success() {
+            return Err(Errors::wallet(
+                &url,
+                "POST",
+                Some(res.status()),
+                "Petition to login into Wallet failed",
+                None,
+            ));
+        }
+
+        let json_res: WalletLoginResponse = res.parse_json().await?;
+
+        let jwt = json_res.token;
+        let jwt_parts: Vec<&str> = jwt.split('.').collect();
+        if jwt_parts.len() != 3 {
+            return Err(Errors::format(
+                BadFormat::Sent,
+                "The jwt does not have the correct format",
+                None,
+            ));
+        }
+
+        let decoded = decode_url_safe_no_pad(jwt_parts[1])?;
+        let claims: AuthJwtClaims = serde_json::from_slice(&decoded)?;
+
+        let mut session = self.session.lock().await;
+        session.account_id = Some(json_res.id);
+        session.token_exp = Some(claims.exp);
+        session.token = Some(jwt);
+
+        info!("Login data saved successfully");
+        Ok(())
+    }
+
+    /// Returns a session token valid for at least [`TOKEN_REFRESH_MARGIN_SECS`], logging in
+    /// again when the current one is about to expire.
+    pub async fn get_token(&self) -> Outcome<String> {
+        if let Some(token) = self.fresh_token().await {
+            return Ok(token);
+        }
+
+        let _refresh = self.token_refresh.lock().await;
+        // Another caller may have renewed the token while this one waited for the lock.
+        if let Some(token) = self.fresh_token().await {
+            return Ok(token);
+        }
+        info!("Wallet session token expired or about to expire, renewing it");
+        self.login().await?;
+
+        let session = self.session.lock().await;
+        session.token.clone().ok_or_else(|| {
+            Errors::missing_action(
+                MissingAction::Token,
+                "There is no token available for use",
+                None,
+            )
+        })
+    }
+
+    async fn fresh_token(&self) -> Option<String> {
+        let session = self.session.lock().await;
+        session
+            .fresh_token(TOKEN_REFRESH_MARGIN_SECS)
+            .map(str::to_string)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!(
+            "{}/wallet-api{}",
+            self.config.get_wallet_api_url(HostType::Http),
+            path
+        )
+    }
+}
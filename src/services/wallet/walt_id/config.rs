@@ -15,15 +15,87 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::time::Duration;
+
 use crate::config::traits::{DidConfigTrait, HostsConfigTrait, WalletConfigTrait};
 use crate::config::types::DidConfig;
 use crate::config::types::{CommonHostsConfig, WalletConfig};
 
+/// Family of walt.id wallet-api calls sharing a timeout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalletOp {
+    /// `/auth/*` registration and login.
+    Auth,
+    /// Plain reads of wallets, keys, DIDs and credentials.
+    Read,
+    /// Creation and deletion of keys, DIDs and credentials.
+    Write,
+    /// `/exchange/*` calls, which reach out to issuers and verifiers themselves.
+    Exchange,
+}
+
+impl WalletOp {
+    /// Classifies a wallet-api call from its method and path.
+    pub fn classify(method: &str, path: &str) -> Self {
+        if path.starts_with("/auth/") {
+            WalletOp::Auth
+        } else if path.contains("/exchange/") {
+            WalletOp::Exchange
+        } else if method == "GET" {
+            WalletOp::Read
+        } else {
+            WalletOp::Write
+        }
+    }
+}
+
+/// Per-operation deadlines of the walt.id wallet calls.
+#[derive(Clone, Debug)]
+pub struct WalletTimeouts {
+    pub auth: Duration,
+    pub read: Duration,
+    pub write: Duration,
+    pub exchange: Duration,
+}
+
+impl Default for WalletTimeouts {
+    fn default() -> Self {
+        Self {
+            auth: Duration::from_secs(10),
+            read: Duration::from_secs(10),
+            write: Duration::from_secs(15),
+            exchange: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Retry policy of the exchange steps hitting a transient wallet failure.
+#[derive(Clone, Debug)]
+pub struct WalletRetries {
+    /// Attempts made per step, the first one included.
+    pub attempts: u32,
+    /// Delay before the first retry, doubled on every further one.
+    pub backoff: Duration,
+}
+
+impl Default for WalletRetries {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            backoff: Duration::from_millis(500),
+        }
+    }
+}
+
 pub struct WaltIdConfig {
     hosts: CommonHostsConfig,
     ssi_wallet_config: WalletConfig,
 
     did_config: DidConfig,
+    timeouts: WalletTimeouts,
+    retries: WalletRetries,
+    /// Id or name of the account wallet to operate on; the first one when unset.
+    wallet: Option<String>,
 }
 
 impl WaltIdConfig {
@@ -36,8 +108,50 @@ impl WaltIdConfig {
             hosts,
             ssi_wallet_config,
             did_config,
+            timeouts: WalletTimeouts::default(),
+            retries: WalletRetries::default(),
+            wallet: None,
+        }
+    }
+
+    /// Binds the service to the account wallet with the given id or name.
+    pub fn with_wallet(mut self, wallet: impl Into<String>) -> Self {
+        self.wallet = Some(wallet.into());
+        self
+    }
+
+    pub fn get_wallet_selector(&self) -> Option<&str> {
+        self.wallet.as_deref()
+    }
+
+    pub fn with_timeouts(mut self, timeouts: WalletTimeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    pub fn get_timeout(&self, op: WalletOp) -> Duration {
+        match op {
+            WalletOp::Auth => self.timeouts.auth,
+            WalletOp::Read => self.timeouts.read,
+            WalletOp::Write => self.timeouts.write,
+            WalletOp::Exchange => self.timeouts.exchange,
         }
     }
+
+    pub fn with_retries(mut self, retries: WalletRetries) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    pub fn get_retries(&self) -> &WalletRetries {
+        &self.retries
+    }
+
+    /// Longest of the configured deadlines, used as the transport timeout of the wallet client.
+    pub fn get_max_timeout(&self) -> Duration {
+        let t = &self.timeouts;
+        t.auth.max(t.read).max(t.write).max(t.exchange)
+    }
 }
 
 impl HostsConfigTrait for WaltIdConfig {
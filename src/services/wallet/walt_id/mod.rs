@@ -15,8 +15,10 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod client;
 mod config;
 mod service;
 
-pub use config::WaltIdConfig;
+pub use client::WaltIdClient;
+pub use config::{WaltIdConfig, WalletOp, WalletRetries, WalletTimeouts};
 pub use service::WaltIdService;
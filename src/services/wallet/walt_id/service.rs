@@ -15,98 +15,326 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde_json::Value;
+use tracing::{info, warn};
+use urlencoding::{decode, encode};
 
 use super::super::WalletTrait;
+use super::super::native::vc_model;
+use super::super::offer::settle_tx_code;
+use super::client::WaltIdClient;
+use super::config::WaltIdConfig;
 use crate::capabilities::Did;
+use crate::config::traits::{DidConfigTrait, WalletConfigTrait};
+use crate::config::types::DidConfig;
 use crate::data::entities::wallet::{did, key, vc};
-use crate::errors::Outcome;
-use crate::types::dids::DidDocument;
-use crate::types::wallet::{DidSearch, Identity, WalletInfo};
+use crate::errors::{BadFormat, Errors, MissingAction, Outcome};
+use crate::services::vault::{VaultService, VaultTrait};
+use crate::types::dids::{DidBuilder, DidDocument, DidService, DidType};
+use crate::types::http::HttpBody;
+use crate::types::issuance::VcBody;
+use crate::types::keys::{Crv, Kty};
+use crate::types::secrets::PemHelper;
+use crate::types::vcs::VPDef;
+use crate::types::wallet::waltid::{
+    KeyDefinition, MatchVCsRequest, MatchingVCs, ModifiedWalletInfo, WalletCredentials,
+    WalletInfoResponse, WalletOperationHistory, WalletSession, WaltIdDidsInfo,
+};
+use crate::types::wallet::{
+    DidSearch, Identity, KeyRef, PresentationCandidate, ResolvedOffer, WalletInfo,
+};
+use crate::utils::{Clock, ResponseExt, expect_from_env};
 
-pub struct WaltIdService {}
+/// Wallet implementation backed by an account of the walt.id wallet-api.
+///
+/// The service operates on the account wallet picked by the configured selector and keeps a
+/// local cache of the identity bound to its default DID. walt.id keys its DIDs by the DID
+/// itself and binds each of them to a single key.
+pub struct WaltIdService {
+    client: WaltIdClient,
+    identity: Arc<RwLock<Identity>>,
+    services: Vec<DidService>,
+}
 
 impl WaltIdService {
-    pub async fn new() -> Outcome<Self> {
-        Ok(Self {})
+    pub async fn new(
+        config: WaltIdConfig,
+        vault: Arc<VaultService>,
+        services: Vec<DidService>,
+    ) -> Outcome<Self> {
+        let client = WaltIdClient::new(config, vault.clone());
+        // A repeated registration is answered with a conflict, so it is safe to retry.
+        client
+            .retry_step("register", || client.register(), || async { None })
+            .await?;
+        client
+            .retry_step("login", || client.login(), || async { None })
+            .await?;
+        let base = Self::bootstrap(&client, vault).await?;
+        let identity = Self::identity_of(&client, &services, &base)?;
+        Ok(Self {
+            client,
+            identity: Arc::new(RwLock::new(identity)),
+            services,
+        })
+    }
+
+    /// Initializes the wallet identity.
+    ///
+    /// A default DID of the configured method is reused. Otherwise the agent key is imported
+    /// from the vault and a DID of that method is created on it and made the default; the
+    /// `did:key` walt.id gives every new account is left aside.
+    async fn bootstrap(client: &WaltIdClient, vault: Arc<VaultService>) -> Outcome<did::Model> {
+        let wallet = Self::wallet_of(client).await?;
+        let config = client.config().did_config();
+        let dids = Self::fetch_dids(client, &wallet.id).await?;
+        if let Some(base) = dids
+            .into_iter()
+            .find(|d| d.default && has_method(config, &d.did))
+        {
+            return did_model(base);
+        }
+
+        // ===== REGISTER KEY ======================================================================
+        let priv_vault_path = expect_from_env("VAULT_APP_PRIV_KEY");
+        let key_data: PemHelper = vault.read(None, &priv_vault_path).await?;
+        let key_id = Self::import_key(client, &wallet.id, key_data.pem()).await?;
+
+        // ===== REGISTER DID ======================================================================
+        let did_builder = match config {
+            DidConfig::Jwk => DidBuilder::new_jwk(key_data.pem()),
+            DidConfig::Web { web_config } => DidBuilder::new_web(
+                &web_config.domain,
+                web_config.path.as_deref(),
+                web_config.port.as_deref(),
+            ),
+            DidConfig::Other(did) => {
+                return Err(Errors::not_impl(
+                    format!("did type {did} not supported"),
+                    None,
+                ));
+            }
+        };
+        let did =
+            Self::create_did(client, &wallet.id, &did_builder.build()?, &key_id, "base").await?;
+        Self::mark_default(client, &wallet.id, &did).await?;
+        Self::fetch_did(client, &wallet.id, &did).await
+    }
+
+    /// Builds the identity of `model`.
+    ///
+    /// walt.id publishes no services in its DID documents, so the configured ones are added to
+    /// the cached document.
+    fn identity_of(
+        client: &WaltIdClient,
+        services: &[DidService],
+        model: &did::Model,
+    ) -> Outcome<Identity> {
+        let did = Did::parse(&model.did)?;
+        let mut did_doc = model.did_document.clone();
+        if !services.is_empty() {
+            did_doc.add_services(services.to_vec());
+        }
+        let identity = Identity::new(did, did_doc, model.default_key.clone());
+        if client.config().did_config().is_dual_stack() {
+            return identity.with_jwk_alias();
+        }
+        Ok(identity)
     }
 }
 
 #[async_trait]
 impl WalletTrait for WaltIdService {
+    // ===== CORE WALLET STATE =====================================================================
     async fn link(&self) -> Outcome<()> {
-        todo!()
+        self.client
+            .retry_step("login", || self.client.login(), || async { None })
+            .await?;
+        let default = self.retrieve_default_did().await?;
+        self.replace_identity_from(&default).await
+    }
+
+    async fn export_session(&self) -> Option<Value> {
+        let session = self.client.session().lock().await;
+        serde_json::to_value(&*session).ok()
+    }
+
+    async fn resume_session(&self, session: Value) -> Outcome<()> {
+        let session: WalletSession = serde_json::from_value(session)?;
+        *self.client.session().lock().await = session;
+        Ok(())
     }
 
     async fn get_wallet(&self) -> Outcome<WalletInfo> {
-        todo!()
+        let wallet = self.selected_wallet().await?;
+        let dids = Self::fetch_dids(&self.client, &wallet.id)
+            .await?
+            .into_iter()
+            .map(did_model)
+            .collect::<Outcome<Vec<_>>>()?;
+
+        Ok(WalletInfo {
+            id: wallet.id,
+            name: wallet.name,
+            created_on: wallet.created_on,
+            added_on: wallet.added_on,
+            permission: wallet.permission,
+            dids,
+        })
     }
 
     async fn get_did(&self) -> Outcome<Did> {
-        todo!()
+        let identity = self.identity.read().await;
+        Ok(identity.did().clone())
     }
 
     async fn get_did_doc(&self) -> Outcome<DidDocument> {
-        todo!()
+        let identity = self.identity.read().await;
+        Ok(identity.did_doc().clone())
     }
 
     fn get_identity(&self) -> Arc<RwLock<Identity>> {
-        todo!()
+        self.identity.clone()
     }
 
-    async fn retrieve_did(&self, _search: DidSearch) -> Outcome<did::Model> {
-        todo!()
+    // ===== STORAGE (READ ONLY) ===================================================================
+    async fn retrieve_did(&self, search: DidSearch) -> Outcome<did::Model> {
+        let wallet = self.selected_wallet().await?;
+        Self::fetch_did(&self.client, &wallet.id, search.as_str()).await
     }
 
     async fn retrieve_default_did(&self) -> Outcome<did::Model> {
-        todo!()
+        let wallet = self.selected_wallet().await?;
+        did_model(self.default_did(&wallet).await?)
     }
 
     async fn retrieve_all_dids(&self) -> Outcome<Vec<did::Model>> {
-        todo!()
+        let wallet = self.selected_wallet().await?;
+        Self::fetch_dids(&self.client, &wallet.id)
+            .await?
+            .into_iter()
+            .map(did_model)
+            .collect()
     }
 
-    async fn retrieve_key(&self, _id: &str) -> Outcome<key::Model> {
-        todo!()
+    async fn retrieve_key(&self, id: &str) -> Outcome<key::Model> {
+        self.retrieve_all_keys()
+            .await?
+            .into_iter()
+            .find(|key| key.id == id)
+            .ok_or_else(|| Errors::missing_resource(id, "Key not stored in wallet", None))
     }
 
     async fn retrieve_all_keys(&self) -> Outcome<Vec<key::Model>> {
-        todo!()
+        let wallet = self.selected_wallet().await?;
+        let path = format!("/wallet/{}/keys", wallet.id);
+        let keys: Vec<KeyDefinition> = self
+            .client
+            .request(
+                "GET",
+                &path,
+                HttpBody::None,
+                true,
+                true,
+                "Petition to retrieve keys failed",
+            )
+            .await?
+            .parse_json()
+            .await?;
+        Ok(keys.into_iter().map(key_model).collect())
     }
 
-    async fn retrieve_vc(&self, _id: &str) -> Outcome<vc::Model> {
-        todo!()
+    async fn retrieve_vc(&self, id: &str) -> Outcome<vc::Model> {
+        let wallet = self.selected_wallet().await?;
+        let path = format!("/wallet/{}/credentials/{}", wallet.id, encode(id));
+        let credential: WalletCredentials = self
+            .client
+            .request(
+                "GET",
+                &path,
+                HttpBody::None,
+                true,
+                true,
+                "Petition to retrieve credential failed",
+            )
+            .await?
+            .parse_json()
+            .await?;
+        stored_vc(credential)
     }
 
     async fn retrieve_all_vcs(&self) -> Outcome<Vec<vc::Model>> {
-        todo!()
+        let wallet = self.selected_wallet().await?;
+        let credentials = self.fetch_credentials(&wallet).await?;
+        Ok(credentials
+            .into_iter()
+            .filter_map(|credential| {
+                let id = credential.id.clone();
+                stored_vc(credential)
+                    .inspect_err(|e| warn!("Skipping unreadable credential {id}: {}", e.reason()))
+                    .ok()
+            })
+            .collect())
     }
 
-    async fn register_key(&self, _plan: key::Plan) -> Outcome<key::Model> {
-        todo!()
+    // ===== STORAGE (MUTATIONS) ===================================================================
+
+    async fn register_key(&self, plan: key::Plan) -> Outcome<key::Model> {
+        let pem = PemHelper::priv_from_pem(&plan.pem)?;
+        let wallet = self.selected_wallet().await?;
+        // walt.id names imported keys itself, so `plan.id` is not kept.
+        let id = Self::import_key(&self.client, &wallet.id, pem.pem()).await?;
+        Ok(key::Model {
+            id,
+            alias: plan.alias,
+            kty: pem.kty().clone(),
+            crv: pem.crv().cloned(),
+            created_at: Clock::now(),
+        })
     }
 
-    async fn register_did(&self, _plan: did::Plan) -> Outcome<did::Model> {
-        todo!()
+    async fn register_did(&self, plan: did::Plan) -> Outcome<did::Model> {
+        let [key_id] = plan.keys.as_slice() else {
+            return Err(single_key());
+        };
+        if plan.service.is_some() {
+            warn!("walt.id does not publish DID services, ignoring the planned ones");
+        }
+        let wallet = self.selected_wallet().await?;
+        let did = plan.builder.build()?;
+        let did = Self::create_did(&self.client, &wallet.id, &did, key_id, &plan.alias).await?;
+        let model = Self::fetch_did(&self.client, &wallet.id, &did).await?;
+        self.maybe_update_identity(&model).await?;
+        Ok(model)
     }
 
     async fn store_vc(&self, _plan: vc::Plan) -> Outcome<vc::Model> {
-        todo!()
+        Err(Errors::not_impl(
+            "walt.id only stores credentials received through OID4VCI",
+            None,
+        ))
     }
 
-    async fn set_default_did(&self, _search: DidSearch) -> Outcome<did::Model> {
-        todo!()
+    async fn set_default_did(&self, search: DidSearch) -> Outcome<did::Model> {
+        info!("WaltIdService: set_default_did");
+        let wallet = self.selected_wallet().await?;
+        Self::mark_default(&self.client, &wallet.id, search.as_str()).await?;
+        let model = Self::fetch_did(&self.client, &wallet.id, search.as_str()).await?;
+        self.replace_identity_from(&model).await?;
+        Ok(model)
     }
 
-    async fn add_key_to_did(
-        &self,
-        _search: DidSearch,
-        _key_id: String,
-    ) -> Outcome<did::Model> {
-        todo!()
+    // ===== DID-KEY MANAGEMENT ====================================================================
+
+    async fn add_key_to_did(&self, _search: DidSearch, _key_id: String) -> Outcome<did::Model> {
+        Err(single_key())
     }
 
     async fn remove_key_from_did(
@@ -114,34 +342,613 @@ impl WalletTrait for WaltIdService {
         _search: DidSearch,
         _key_id: String,
     ) -> Outcome<did::Model> {
-        todo!()
+        Err(single_key())
+    }
+
+    async fn set_default_key(&self, _search: DidSearch, _key_id: String) -> Outcome<did::Model> {
+        Err(single_key())
+    }
+
+    // ===== DELETE OPERATIONS =====================================================================
+
+    async fn delete_key(&self, id: &str) -> Outcome<()> {
+        let wallet = self.selected_wallet().await?;
+        let path = format!("/wallet/{}/keys/{}", wallet.id, encode(id));
+        self.client
+            .request(
+                "DELETE",
+                &path,
+                HttpBody::None,
+                true,
+                false,
+                "Petition to delete key failed",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_did(&self, search: DidSearch) -> Outcome<()> {
+        let active = self.identity.read().await.did().id().to_string();
+        if active == search.as_str() {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Refusing to delete the active identity's DID. Switch the default first.",
+                None,
+            ));
+        }
+
+        let wallet = self.selected_wallet().await?;
+        let path = format!("/wallet/{}/dids/{}", wallet.id, encode(search.as_str()));
+        self.client
+            .request(
+                "DELETE",
+                &path,
+                HttpBody::None,
+                true,
+                false,
+                "Petition to delete did failed",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_vc(&self, id: &str) -> Outcome<()> {
+        let wallet = self.selected_wallet().await?;
+        let path = format!("/wallet/{}/credentials/{}", wallet.id, encode(id));
+        self.client
+            .request(
+                "DELETE",
+                &path,
+                HttpBody::None,
+                true,
+                false,
+                "Petition to delete vc failed",
+            )
+            .await?;
+        Ok(())
+    }
+
+    // ===== PROTOCOL HANDLING =====================================================================
+
+    async fn process_oid4vci(&self, uri: &str, tx_code: Option<&str>) -> Outcome<()> {
+        info!("WaltIdService: process_oid4vci({})", uri);
+        let ResolvedOffer { offer, .. } = self.resolve_oid4vci_offer(uri).await?;
+        let grant = offer
+            .grants
+            .and_then(|grants| grants.pre_authorized_code)
+            .ok_or_else(|| {
+                Errors::not_impl("Only the pre-authorized code flow is supported", None)
+            })?;
+        let tx_code = settle_tx_code(grant.tx_code.as_ref(), tx_code)?;
+
+        let wallet = self.selected_wallet().await?;
+        let did = self.get_did().await?;
+        let mut path = format!(
+            "/wallet/{}/exchange/useOfferRequest?did={}&requireUserInput=false",
+            wallet.id,
+            encode(did.id())
+        );
+        if let Some(code) = &tx_code {
+            path.push_str(&format!("&pinOrTxCode={}", encode(code)));
+        }
+
+        // A failed use of the offer may still have stored the credential.
+        let known: HashSet<String> = self
+            .fetch_credentials(&wallet)
+            .await?
+            .into_iter()
+            .map(|credential| credential.id)
+            .collect();
+        self.client
+            .retry_step(
+                "use offer",
+                || async {
+                    self.client
+                        .request(
+                            "POST",
+                            &path,
+                            HttpBody::Raw(uri.to_string()),
+                            true,
+                            true,
+                            "Petition to accept the credential offer failed",
+                        )
+                        .await
+                        .map(|_| ())
+                },
+                || async { self.credential_landed(&wallet, &known).await.then_some(()) },
+            )
+            .await
+    }
+
+    async fn process_oid4vp(&self, uri: &str) -> Outcome<()> {
+        info!("WaltIdService: process_oid4vp({})", uri);
+        let wallet = self.selected_wallet().await?;
+        let vpd = self.resolve_presentation(&wallet, uri).await?;
+        let candidates = self.ranked_candidates(&wallet, &vpd).await?;
+        let matched: Vec<&str> = candidates
+            .iter()
+            .filter(|c| !c.credentials.is_empty())
+            .map(|c| c.descriptor_id.as_str())
+            .collect();
+        // Descriptors left out by the submission requirements may go unmatched.
+        if !vpd.is_satisfied_by(&matched) {
+            return Err(Errors::missing_action(
+                MissingAction::Credentials,
+                "There are no VCs that match the specified input descriptor",
+                None,
+            ));
+        }
+        let selected = self
+            .client
+            .config()
+            .get_credential_selection()
+            .select(&candidates)?;
+        self.present(&wallet, uri, selected).await
+    }
+
+    async fn presentation_candidates(&self, uri: &str) -> Outcome<Vec<PresentationCandidate>> {
+        let wallet = self.selected_wallet().await?;
+        let vpd = self.resolve_presentation(&wallet, uri).await?;
+        self.ranked_candidates(&wallet, &vpd).await
+    }
+
+    async fn present_credentials(&self, uri: &str, selected: Vec<String>) -> Outcome<()> {
+        let wallet = self.selected_wallet().await?;
+        self.present(&wallet, uri, selected).await
+    }
+}
+
+impl WaltIdService {
+    /// Account wallet this service operates on: the configured one, or the first.
+    async fn selected_wallet(&self) -> Outcome<ModifiedWalletInfo> {
+        Self::wallet_of(&self.client).await
     }
 
-    async fn set_default_key(
+    async fn wallet_of(client: &WaltIdClient) -> Outcome<ModifiedWalletInfo> {
+        let res = client
+            .request(
+                "GET",
+                "/wallet/accounts/wallets",
+                HttpBody::None,
+                true,
+                true,
+                "Petition to retrieve Wallet information failed",
+            )
+            .await?;
+        let info: WalletInfoResponse = res.parse_json().await?;
+        let selector = client.config().get_wallet_selector();
+        info.wallets
+            .into_iter()
+            .find(|w| selector.is_none_or(|s| w.id == s || w.name == s))
+            .ok_or_else(|| {
+                Errors::missing_action(MissingAction::Wallet, "There is no wallet available", None)
+            })
+    }
+
+    /// Resolves the presentation definition of the OID4VP request at `uri`.
+    async fn resolve_presentation(&self, wallet: &ModifiedWalletInfo, uri: &str) -> Outcome<VPDef> {
+        let path = format!("/wallet/{}/exchange/resolvePresentationRequest", wallet.id);
+        let resolved = self
+            .client
+            .retry_step(
+                "resolve presentation",
+                || async {
+                    self.client
+                        .request(
+                            "POST",
+                            &path,
+                            HttpBody::Raw(uri.to_string()),
+                            true,
+                            false,
+                            "Error joining the exchange",
+                        )
+                        .await?
+                        .parse_text()
+                        .await
+                },
+                || async { None },
+            )
+            .await?;
+
+        let decoded = decode(&resolved)
+            .map_err(|e| Errors::parse("Unable to decode vpd", Some(Box::new(e))))?;
+        let url = Url::parse(&decoded)
+            .map_err(|e| Errors::parse("Unable to extract url from string", Some(Box::new(e))))?;
+        let vpd = url
+            .query_pairs()
+            .find(|(k, _)| k == "presentation_definition")
+            .map(|(_, v)| v.into_owned())
+            .ok_or_else(|| {
+                Errors::format(
+                    BadFormat::Received,
+                    "Missing query parameter presentation_definition",
+                    None,
+                )
+            })?;
+        Ok(serde_json::from_str(&vpd)?)
+    }
+
+    /// Lists the credentials matching every input descriptor, ranked by the configured
+    /// selection strategy.
+    async fn ranked_candidates(
         &self,
-        _search: DidSearch,
-        _key_id: String,
-    ) -> Outcome<did::Model> {
-        todo!()
+        wallet: &ModifiedWalletInfo,
+        vpd: &VPDef,
+    ) -> Outcome<Vec<PresentationCandidate>> {
+        let path = format!(
+            "/wallet/{}/exchange/matchCredentialsForPresentationDefinition",
+            wallet.id
+        );
+        let selection = self.client.config().get_credential_selection();
+        let mut candidates = Vec::with_capacity(vpd.input_descriptors.len());
+        for descriptor in &vpd.input_descriptors {
+            let single = VPDef {
+                id: "temporal_id".to_string(),
+                input_descriptors: vec![descriptor.clone()],
+                submission_requirements: None,
+            };
+            let body = HttpBody::json(&single)?;
+            // Matching reads the wallet only, so a transient failure is simply sent again.
+            let vcs: Vec<MatchingVCs> = self
+                .client
+                .retry_step(
+                    "match credentials",
+                    || async {
+                        self.client
+                            .request(
+                                "POST",
+                                &path,
+                                body.clone(),
+                                true,
+                                true,
+                                "Petition to match credentials failed",
+                            )
+                            .await?
+                            .parse_json()
+                            .await
+                    },
+                    || async { None },
+                )
+                .await?;
+            let mut matching: Vec<&MatchingVCs> = vcs.iter().collect();
+            selection.rank(&mut matching);
+            candidates.push(PresentationCandidate {
+                descriptor_id: descriptor.id.clone(),
+                credentials: matching.into_iter().map(|vc| vc.id.clone()).collect(),
+            });
+        }
+        Ok(candidates)
+    }
+
+    /// Answers the OID4VP request at `uri` with `selected`, signed by the default DID.
+    async fn present(
+        &self,
+        wallet: &ModifiedWalletInfo,
+        uri: &str,
+        selected: Vec<String>,
+    ) -> Outcome<()> {
+        let did = self.default_did(wallet).await?;
+        let path = format!("/wallet/{}/exchange/usePresentationRequest", wallet.id);
+        let body = HttpBody::json(&MatchVCsRequest {
+            did: did.did,
+            presentation_request: uri.to_string(),
+            selected_credentials: selected,
+        })?;
+
+        // A failed presentation may still have reached the verifier.
+        let since = Utc::now();
+        self.client
+            .retry_step(
+                "present",
+                || async {
+                    self.client
+                        .request(
+                            "POST",
+                            &path,
+                            body.clone(),
+                            true,
+                            true,
+                            "Petition to present credentials failed",
+                        )
+                        .await
+                        .map(|_| ())
+                },
+                || async {
+                    self.presentation_registered(wallet, since)
+                        .await
+                        .then_some(())
+                },
+            )
+            .await
+    }
+
+    async fn default_did(&self, wallet: &ModifiedWalletInfo) -> Outcome<WaltIdDidsInfo> {
+        let dids = match &wallet.dids {
+            Some(dids) => dids.clone(),
+            None => Self::fetch_dids(&self.client, &wallet.id).await?,
+        };
+        dids.into_iter().find(|did| did.default).ok_or_else(|| {
+            Errors::missing_action(MissingAction::Did, "The wallet has no default DID", None)
+        })
+    }
+
+    /// Whether the wallet history records a presentation made at or after `since`.
+    async fn presentation_registered(
+        &self,
+        wallet: &ModifiedWalletInfo,
+        since: DateTime<Utc>,
+    ) -> bool {
+        let path = format!("/wallet/{}/history", wallet.id);
+        let history: Outcome<Vec<WalletOperationHistory>> = async {
+            self.client
+                .request(
+                    "GET",
+                    &path,
+                    HttpBody::None,
+                    true,
+                    true,
+                    "Petition to retrieve wallet history failed",
+                )
+                .await?
+                .parse_json()
+                .await
+        }
+        .await;
+        history.is_ok_and(|history| {
+            history
+                .iter()
+                .any(|op| op.operation == "usePresentationRequest" && op.timestamp >= since)
+        })
     }
 
-    async fn delete_key(&self, _id: &str) -> Outcome<()> {
-        todo!()
+    async fn fetch_dids(client: &WaltIdClient, wallet_id: &str) -> Outcome<Vec<WaltIdDidsInfo>> {
+        let path = format!("/wallet/{wallet_id}/dids");
+        client
+            .request(
+                "GET",
+                &path,
+                HttpBody::None,
+                true,
+                true,
+                "Petition to retrieve Wallet DIDs failed",
+            )
+            .await?
+            .parse_json()
+            .await
     }
 
-    async fn delete_did(&self, _search: DidSearch) -> Outcome<()> {
-        todo!()
+    async fn fetch_did(client: &WaltIdClient, wallet_id: &str, did: &str) -> Outcome<did::Model> {
+        let path = format!("/wallet/{wallet_id}/dids/{}", encode(did));
+        let info: WaltIdDidsInfo = client
+            .request(
+                "GET",
+                &path,
+                HttpBody::None,
+                true,
+                true,
+                "Petition to retrieve did failed",
+            )
+            .await?
+            .parse_json()
+            .await?;
+        did_model(info)
     }
 
-    async fn delete_vc(&self, _id: &str) -> Outcome<()> {
-        todo!()
+    /// Imports the private key in `pem`, returning the id walt.id stored it under.
+    async fn import_key(client: &WaltIdClient, wallet_id: &str, pem: &str) -> Outcome<String> {
+        let path = format!("/wallet/{wallet_id}/keys/import");
+        let id = client
+            .request(
+                "POST",
+                &path,
+                HttpBody::Raw(pem.to_string()),
+                true,
+                false,
+                "Petition to register key failed",
+            )
+            .await?
+            .parse_text()
+            .await?;
+        Ok(id.trim().trim_matches('"').to_string())
     }
 
-    async fn process_oid4vci(&self, _uri: &str) -> Outcome<()> {
-        todo!()
+    /// Creates `did` on the key `key_id`, returning the DID walt.id registered.
+    async fn create_did(
+        client: &WaltIdClient,
+        wallet_id: &str,
+        did: &Did,
+        key_id: &str,
+        alias: &str,
+    ) -> Outcome<String> {
+        let path = match did {
+            Did::Jwk(_) => format!(
+                "/wallet/{wallet_id}/dids/create/jwk?keyId={}&alias={}",
+                encode(key_id),
+                encode(alias)
+            ),
+            Did::Web(web) => {
+                let domain = match web.port() {
+                    Some(port) => format!("{}:{port}", web.domain()),
+                    None => web.domain().to_string(),
+                };
+                format!(
+                    "/wallet/{wallet_id}/dids/create/web?keyId={}&alias={}&domain={}&path={}",
+                    encode(key_id),
+                    encode(alias),
+                    encode(&domain),
+                    encode(web.path().as_deref().unwrap_or(""))
+                )
+            }
+            Did::External(external) => {
+                return Err(Errors::not_impl(
+                    format!("did type {} not supported", external.method()),
+                    None,
+                ));
+            }
+        };
+        let did = client
+            .request(
+                "POST",
+                &path,
+                HttpBody::None,
+                true,
+                true,
+                "Petition to register did failed",
+            )
+            .await?
+            .parse_text()
+            .await?;
+        Ok(did.trim().trim_matches('"').to_string())
+    }
+
+    async fn mark_default(client: &WaltIdClient, wallet_id: &str, did: &str) -> Outcome<()> {
+        let path = format!("/wallet/{wallet_id}/dids/default?did={}", encode(did));
+        client
+            .request(
+                "POST",
+                &path,
+                HttpBody::None,
+                true,
+                true,
+                "Petition to set did as default failed",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn fetch_credentials(
+        &self,
+        wallet: &ModifiedWalletInfo,
+    ) -> Outcome<Vec<WalletCredentials>> {
+        let path = format!("/wallet/{}/credentials?showDeleted=false", wallet.id);
+        self.client
+            .request(
+                "GET",
+                &path,
+                HttpBody::None,
+                true,
+                true,
+                "Petition to retrieve credentials failed",
+            )
+            .await?
+            .parse_json()
+            .await
     }
 
-    async fn process_oid4vp(&self, _uri: &str) -> Outcome<()> {
-        todo!()
+    /// Whether the wallet holds a credential outside `known`.
+    ///
+    /// A wallet still failing to list its credentials counts as not landed.
+    async fn credential_landed(
+        &self,
+        wallet: &ModifiedWalletInfo,
+        known: &HashSet<String>,
+    ) -> bool {
+        match self.fetch_credentials(wallet).await {
+            Ok(credentials) => credentials.iter().any(|c| !known.contains(&c.id)),
+            Err(_) => false,
+        }
+    }
+
+    /// If the model represents the new default DID, replace the cached identity.
+    async fn maybe_update_identity(&self, model: &did::Model) -> Outcome<()> {
+        if model.r#default {
+            self.replace_identity_from(model).await?;
+        }
+        Ok(())
+    }
+
+    /// Unconditionally replace the cached identity from the model.
+    async fn replace_identity_from(&self, model: &did::Model) -> Outcome<()> {
+        let new = Self::identity_of(&self.client, &self.services, model)?;
+        let mut guard = self.identity.write().await;
+        *guard = new;
+        Ok(())
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn single_key() -> Errors {
+    Errors::not_impl("walt.id binds every DID to a single key", None)
+}
+
+/// Whether `did` uses the method `config` asks for.
+fn has_method(config: &DidConfig, did: &str) -> bool {
+    match config {
+        DidConfig::Jwk => did.starts_with("did:jwk:"),
+        DidConfig::Web { .. } => did.starts_with("did:web:"),
+        DidConfig::Other(method) => did.starts_with(&format!("did:{method}:")),
+    }
+}
+
+/// Reads a walt.id DID, whose id is the DID itself and whose single key signs for it.
+fn did_model(info: WaltIdDidsInfo) -> Outcome<did::Model> {
+    let did_document: DidDocument = serde_json::from_str(&info.document)?;
+    let r#type = match Did::parse(&info.did)? {
+        Did::Jwk(_) => DidType::Jwk,
+        Did::Web(_) => DidType::Web,
+        Did::External(_) => DidType::Other,
+    };
+    let vm = did_document.verification_method.first().ok_or_else(|| {
+        Errors::format(
+            BadFormat::Received,
+            format!("DID document of {} has no verification method", info.did),
+            None,
+        )
+    })?;
+    let fragment = vm.id.rsplit_once('#').map_or(vm.id.as_str(), |(_, f)| f);
+    let default_key = KeyRef::new(info.key_id, fragment);
+
+    Ok(did::Model {
+        id: info.did.clone(),
+        did: info.did,
+        alias: info.alias,
+        r#default: info.default,
+        r#type,
+        keys: vec![default_key.clone()],
+        default_key,
+        service: did_document.service.clone(),
+        did_document,
+    })
+}
+
+/// Reads a walt.id key, whose algorithm names the curve.
+///
+/// walt.id does not report when a key was created, so `created_at` is left at the epoch.
+fn key_model(key: KeyDefinition) -> key::Model {
+    let (kty, crv) = match key.algorithm.as_str() {
+        "Ed25519" => (Kty::Okp, Some(Crv::Ed25519)),
+        "secp256r1" => (Kty::Ec, Some(Crv::P256)),
+        "secp256k1" => (Kty::Ec, Some(Crv::Secp256k1)),
+        "RSA" => (Kty::Rsa, None),
+        other => (Kty::Other(other.to_string()), None),
+    };
+    key::Model {
+        id: key.key_id.id,
+        alias: String::new(),
+        kty,
+        crv,
+        created_at: DateTime::<Utc>::default(),
+    }
+}
+
+/// Reads a walt.id credential, keeping the id and the date the wallet gave it.
+fn stored_vc(credential: WalletCredentials) -> Outcome<vc::Model> {
+    let vc_body = match serde_json::from_str::<Value>(&credential.document) {
+        Ok(doc) if !credential.format.contains("jwt") => VcBody::JsonLd(doc),
+        // walt.id keeps the disclosures of an SD-JWT apart from its issuer-signed JWT.
+        _ if !credential.disclosures.is_empty() => VcBody::Jwt(format!(
+            "{}~{}~",
+            credential.document,
+            credential.disclosures.trim_matches('~')
+        )),
+        _ => VcBody::Jwt(credential.document),
+    };
+
+    let mut model = vc_model(vc_body)?;
+    model.id = credential.id;
+    if let Ok(added_on) = DateTime::parse_from_rfc3339(&credential.added_on) {
+        model.added_on = added_on.with_timezone(&Utc);
     }
+    Ok(model)
 }
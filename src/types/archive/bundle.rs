@@ -0,0 +1,129 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+
+use chrono::{DateTime, Utc};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::data::entities::received::{grant, interaction};
+use crate::errors::{Errors, Outcome};
+
+/// Family of flow records exported together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchiveKind {
+    Verifications,
+    Issuances,
+    Interactions,
+}
+
+impl Display for ArchiveKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ArchiveKind::Verifications => "verifications",
+            ArchiveKind::Issuances => "issuances",
+            ArchiveKind::Interactions => "interactions",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A finished GNAP grant archived along with its interaction, when it had one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedGrant {
+    pub grant: grant::Model,
+    pub interaction: Option<interaction::Model>,
+}
+
+/// Self-describing batch of records, stored as one GZIP-compressed JSON object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveBundle {
+    pub id: String,
+    pub kind: ArchiveKind,
+    pub exported_at: DateTime<Utc>,
+    pub records: Vec<Value>,
+}
+
+impl ArchiveBundle {
+    pub fn new<T: Serialize>(kind: ArchiveKind, records: &[T]) -> Outcome<Self> {
+        let records = records
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind,
+            exported_at: Utc::now(),
+            records,
+        })
+    }
+
+    /// Object key of the bundle: `{prefix}/{kind}/{yyyy}/{mm}/{dd}/{id}.json.gz`.
+    pub fn object_key(&self, prefix: &str) -> String {
+        let key = format!(
+            "{}/{}/{}.json.gz",
+            self.kind,
+            self.exported_at.format("%Y/%m/%d"),
+            self.id
+        );
+        if prefix.is_empty() {
+            key
+        } else {
+            format!("{prefix}/{key}")
+        }
+    }
+
+    /// Serializes the bundle and GZIP-compresses it.
+    pub fn compress(&self) -> Outcome<Vec<u8>> {
+        let json = serde_json::to_vec(self)?;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&json)
+            .map_err(|e| Errors::parse("Unable to compress archive bundle", Some(Box::new(e))))?;
+        encoder
+            .finish()
+            .map_err(|e| Errors::parse("Unable to compress archive bundle", Some(Box::new(e))))
+    }
+}
+
+/// Outcome of archiving one family of records.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveReport {
+    pub kind: ArchiveKind,
+    /// Records exported and then pruned from the database.
+    pub archived: u64,
+    /// Keys of the bundles written during the round.
+    pub objects: Vec<String>,
+}
+
+impl ArchiveReport {
+    pub fn new(kind: ArchiveKind) -> Self {
+        Self {
+            kind,
+            archived: 0,
+            objects: Vec::new(),
+        }
+    }
+}
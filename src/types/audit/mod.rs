@@ -0,0 +1,155 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{self, Display, Formatter};
+use std::future::Future;
+
+use sea_orm::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::utils::encode_url_safe_no_pad;
+
+/// Fields whose values never reach the audit log; only the fact that they changed does.
+pub const REDACTED_FIELDS: &[&str] = &[
+    "token",
+    "token_key",
+    "client_key",
+    "pre_auth_code",
+    "nonce",
+    "credential",
+    "response_key",
+    "vpt",
+    "vcs",
+    "receipt",
+    "state",
+    "continue_token",
+    "interact_ref",
+    "manage_token",
+    "labeled_tokens",
+];
+
+/// Placeholder stored instead of a redacted value.
+pub const REDACTED: &str = "[redacted]";
+
+/// Kind of write an audit entry records.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, DeriveActiveEnum, EnumIter, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOperation {
+    #[sea_orm(string_value = "create")]
+    Create,
+    #[sea_orm(string_value = "update")]
+    Update,
+    #[sea_orm(string_value = "delete")]
+    Delete,
+}
+
+impl Display for AuditOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            AuditOperation::Create => "create",
+            AuditOperation::Update => "update",
+            AuditOperation::Delete => "delete",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Criteria narrowing a listing of audit entries. Unset fields match every entry.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AuditFilter {
+    /// Table the audited row belongs to.
+    pub entity: Option<String>,
+    pub entity_id: Option<String>,
+    pub actor: Option<String>,
+    pub operation: Option<AuditOperation>,
+}
+
+// ===== ACTOR =====================================================================================
+
+tokio::task_local! {
+    static AUDIT_ACTOR: String;
+}
+
+/// Runs `fut` with `actor` recorded as the author of every audited write it makes.
+///
+/// The actor does not follow work handed to `tokio::spawn`.
+pub async fn with_audit_actor<F: Future>(actor: impl Into<String>, fut: F) -> F::Output {
+    AUDIT_ACTOR.scope(actor.into(), fut).await
+}
+
+/// Returns the actor of the current task, if one was set.
+pub fn current_audit_actor() -> Option<String> {
+    AUDIT_ACTOR.try_with(Clone::clone).ok()
+}
+
+/// Actor standing for the bearer of `token`, without storing the token itself.
+pub fn token_actor(token: &str) -> String {
+    let digest = encode_url_safe_no_pad(Sha256::digest(token.as_bytes()));
+    format!("token:{}", &digest[..16])
+}
+
+// ===== SNAPSHOTS =================================================================================
+
+/// Serializes an audited row as is; [`redact_snapshot`] masks it before it is stored.
+pub fn audit_snapshot<M: Serialize>(model: &M) -> Option<Value> {
+    serde_json::to_value(model).ok()
+}
+
+/// Masks the values of [`REDACTED_FIELDS`] in a snapshot.
+pub fn redact_snapshot(mut snapshot: Value) -> Value {
+    if let Value::Object(fields) = &mut snapshot {
+        for (key, field) in fields.iter_mut() {
+            *field = redact_field(key, field);
+        }
+    }
+    snapshot
+}
+
+fn redact_field(key: &str, value: &Value) -> Value {
+    match REDACTED_FIELDS.contains(&key) && !value.is_null() {
+        true => Value::String(REDACTED.to_string()),
+        false => value.clone(),
+    }
+}
+
+/// Lists the top-level fields that differ between two snapshots as `{field: {before, after}}`.
+///
+/// Snapshots are compared unmasked, so a changed redacted field is listed with masked values.
+pub fn audit_diff(before: Option<&Value>, after: Option<&Value>) -> Value {
+    let empty = Map::new();
+    let before = before.and_then(Value::as_object).unwrap_or(&empty);
+    let after = after.and_then(Value::as_object).unwrap_or(&empty);
+
+    let mut diff = Map::new();
+    for key in before.keys().chain(after.keys()) {
+        if diff.contains_key(key) {
+            continue;
+        }
+        let old = before.get(key).unwrap_or(&Value::Null);
+        let new = after.get(key).unwrap_or(&Value::Null);
+        if old != new {
+            let mut change = Map::new();
+            change.insert("before".to_string(), redact_field(key, old));
+            change.insert("after".to_string(), redact_field(key, new));
+            diff.insert(key.clone(), Value::Object(change));
+        }
+    }
+    Value::Object(diff)
+}
@@ -0,0 +1,76 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Protocol state pruned by the cleanup job, each with its own retention.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupTable {
+    /// GNAP grants received from participants, along with their interactions.
+    RecvGrants,
+    /// GNAP grants sent to authorities, along with their interactions.
+    SentGrants,
+    /// OpenID4VCI issuance sessions.
+    Issuances,
+    /// OpenID4VP verification sessions.
+    Verifications,
+}
+
+impl CleanupTable {
+    pub const ALL: [CleanupTable; 4] = [
+        CleanupTable::RecvGrants,
+        CleanupTable::SentGrants,
+        CleanupTable::Issuances,
+        CleanupTable::Verifications,
+    ];
+}
+
+impl Display for CleanupTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            CleanupTable::RecvGrants => "recv_grants",
+            CleanupTable::SentGrants => "sent_grants",
+            CleanupTable::Issuances => "issuances",
+            CleanupTable::Verifications => "verifications",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Outcome of one cleanup round over a table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CleanupReport {
+    pub table: CleanupTable,
+    /// Records left unfinished past their deadline and closed during the round.
+    pub expired: u64,
+    /// Finished records deleted for being older than the table's retention.
+    pub removed: u64,
+}
+
+impl CleanupReport {
+    pub fn new(table: CleanupTable) -> Self {
+        Self {
+            table,
+            expired: 0,
+            removed: 0,
+        }
+    }
+}
@@ -25,9 +25,17 @@
 //!   having a `Canon` is a compile-time guarantee that the bytes are
 //!   canonical. Callers that take `&Canon` cannot accidentally receive
 //!   non-canonical data.
+//! - [`RdfCanon`] — RDFC-1.0 canonical N-Quads of a JSON-LD document, signed by
+//!   the RDF Data Integrity cryptosuites.
+//! - [`SealedEnvelope`] — passphrase-protected AES-256-GCM envelope behind the
+//!   local secret file and the wallet key backups.
 
 mod canon;
 mod proof;
+mod rdfc;
+mod sealed_envelope;
 
 pub use canon::Canon;
 pub use proof::Proof;
+pub use rdfc::RdfCanon;
+pub use sealed_envelope::*;
@@ -16,18 +16,36 @@
  */
 
 use crate::errors::{BadFormat, Errors, Outcome};
-use crate::types::crypto::Canon;
+use crate::types::crypto::{Canon, RdfCanon};
 use crate::types::keys::{Alg, Cryptosuite, PublicKey};
+use crate::utils::StringOrArr;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Proof {
     pub r#type: String,
-    pub cryptosuite: Cryptosuite,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cryptosuite: Option<Cryptosuite>,
     #[serde(rename = "verificationMethod")]
     pub verification_method: String,
     #[serde(rename = "proofValue")]
     pub proof_value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(
+        rename = "proofPurpose",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub proof_purpose: Option<String>,
+    /// Verifier nonce a presentation proof is bound to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<String>,
+    /// Audience(s) a presentation proof is bound to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<StringOrArr>,
 }
 
 impl Proof {
@@ -44,9 +62,84 @@ impl Proof {
             .map_err(|e| Errors::parse("base58 decode of proofValue failed", Some(Box::new(e))))
     }
 
-    pub fn verify_with(&self, key: &PublicKey, canon: &Canon) -> Outcome<()> {
-        let sig = self.signature()?;
-        let alg = Alg::from_cryptosuite(&self.cryptosuite);
-        key.verify_bytes(canon.as_ref(), &sig, &alg)
+    /// Whether the proof is bound to the audience `domain`.
+    pub fn has_domain(&self, domain: &str) -> bool {
+        match &self.domain {
+            Some(StringOrArr::String(d)) => d == domain,
+            Some(StringOrArr::Arr(ds)) => ds.iter().any(|d| d == domain),
+            None => false,
+        }
+    }
+
+    /// Cryptosuite of the proof.
+    ///
+    /// `DataIntegrityProof` entries name it in `cryptosuite`; the legacy `Ed25519Signature2020`
+    /// and `EcdsaSecp256r1Signature2019` proof types carry none and imply their RDF suite.
+    pub fn suite(&self) -> Outcome<Cryptosuite> {
+        match (self.r#type.as_str(), &self.cryptosuite) {
+            ("DataIntegrityProof", Some(suite)) => Ok(suite.clone()),
+            ("DataIntegrityProof", None) => Err(Errors::format(
+                BadFormat::Received,
+                "DataIntegrityProof is missing its cryptosuite",
+                None,
+            )),
+            ("Ed25519Signature2020", _) => Ok(Cryptosuite::EddsaRdfc2022),
+            ("EcdsaSecp256r1Signature2019", _) => Ok(Cryptosuite::EcdsaRdfc2019),
+            (other, _) => Err(Errors::not_impl(
+                format!("Unsupported proof type '{other}'"),
+                None,
+            )),
+        }
+    }
+
+    /// Bytes signed by a Data Integrity proof over `document` (stripped of its `proof`).
+    ///
+    /// The SHA-256 of the canonical proof options, which inherit the document `@context`,
+    /// followed by the SHA-256 of the canonical document. The options are signed too, so
+    /// `created`, `proofPurpose`, `challenge` and `domain` cannot be swapped. The JCS suites
+    /// canonicalize with [`Canon`], the RDF ones with [`RdfCanon`].
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FeatureNotImplError`] for cryptosuites other than the EdDSA and
+    /// ECDSA ones.
+    pub async fn data_integrity_input(&self, document: &Value) -> Outcome<Vec<u8>> {
+        let mut options = serde_json::to_value(self)?;
+        if let Some(options) = options.as_object_mut() {
+            options.remove("proofValue");
+            if let Some(context) = document.get("@context") {
+                options.insert("@context".to_string(), context.clone());
+            }
+        }
+
+        let suite = self.suite()?;
+        let mut input = Vec::with_capacity(64);
+        match suite {
+            Cryptosuite::EddsaJcs2022 | Cryptosuite::EcdsaJcs2019 => {
+                input.extend(Sha256::digest(Canon::try_from(&options)?.as_ref()));
+                input.extend(Sha256::digest(Canon::try_from(document)?.as_ref()));
+            }
+            Cryptosuite::EddsaRdfc2022 | Cryptosuite::EcdsaRdfc2019 => {
+                input.extend(Sha256::digest(
+                    RdfCanon::from_document(&options).await?.as_ref(),
+                ));
+                input.extend(Sha256::digest(
+                    RdfCanon::from_document(document).await?.as_ref(),
+                ));
+            }
+            suite => {
+                return Err(Errors::not_impl(
+                    format!("Unsupported Data Integrity cryptosuite '{suite}'"),
+                    None,
+                ));
+            }
+        }
+        Ok(input)
+    }
+
+    /// Checks the proof signature of `document` (stripped of its `proof`) against `key`.
+    pub async fn verify(&self, key: &PublicKey, document: &Value) -> Outcome<()> {
+        let input = self.data_integrity_input(document).await?;
+        let alg = Alg::from_cryptosuite(&self.suite()?);
+        key.verify_bytes(&input, &self.signature()?, &alg)
     }
 }
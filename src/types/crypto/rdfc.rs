@@ -0,0 +1,659 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use json_ld::syntax::Parse;
+use json_ld::{IriBuf, JsonLdProcessor, RemoteDocument};
+use rdf_types::RdfDisplay;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::errors::{BadFormat, Errors, Outcome};
+
+/// Upper bound on the N-degree hashing steps spent on one dataset.
+///
+/// Crafted graphs can make RDFC-1.0 explode combinatorially, so canonicalization gives up
+/// past this budget instead of pinning the verifier.
+const MAX_N_DEGREE_CALLS: usize = 4096;
+const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+
+/// The only `@context` documents expansion may resolve, bundled with the crate.
+///
+/// Nothing is fetched while canonicalizing: a document naming any other context is refused,
+/// so an attacker cannot point the verifier at arbitrary URLs or swap term definitions under a
+/// signed credential.
+const PINNED_CONTEXTS: &[(&str, &str)] = &[
+    (
+        "https://www.w3.org/2018/credentials/v1",
+        include_str!("contexts/credentials_v1.jsonld"),
+    ),
+    (
+        "https://www.w3.org/ns/credentials/v2",
+        include_str!("contexts/credentials_v2.jsonld"),
+    ),
+    (
+        "https://w3id.org/security/data-integrity/v1",
+        include_str!("contexts/data_integrity_v1.jsonld"),
+    ),
+    (
+        "https://w3id.org/security/data-integrity/v2",
+        include_str!("contexts/data_integrity_v2.jsonld"),
+    ),
+    (
+        "https://w3id.org/security/suites/ed25519-2020/v1",
+        include_str!("contexts/ed25519_2020_v1.jsonld"),
+    ),
+    (
+        "https://w3id.org/vc/status-list/2021/v1",
+        include_str!("contexts/status_list_2021_v1.jsonld"),
+    ),
+];
+
+/// RDFC-1.0 canonical N-Quads of a JSON-LD document.
+///
+/// The RDF counterpart of [`Canon`](super::Canon), used by the `*-rdfc-*` Data Integrity
+/// cryptosuites and the legacy `Ed25519Signature2020` proofs. The document is expanded to RDF
+/// against the bundled [`PINNED_CONTEXTS`], then blank nodes are relabelled deterministically
+/// and the quads sorted.
+pub struct RdfCanon {
+    value: String,
+}
+
+impl RdfCanon {
+    /// Converts `document` to RDF and canonicalizes the resulting dataset.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`] for contexts that are not pinned, and a parse error
+    /// when the document cannot be expanded or its dataset is too costly to canonicalize.
+    pub async fn from_document(document: &Value) -> Outcome<Self> {
+        check_contexts(document.get("@context"))?;
+
+        let (json, _) = json_ld::syntax::Value::parse_str(&document.to_string())
+            .map_err(|e| Errors::parse(format!("JSON-LD document could not be read: {e}"), None))?;
+        let input = RemoteDocument::new(None, None, json);
+        let mut loader = pinned_loader()?;
+        let mut generator = rdf_types::generator::Blank::new();
+        let mut rdf = input
+            .to_rdf(&mut generator, &mut loader)
+            .await
+            .map_err(|e| Errors::parse(format!("JSON-LD to RDF conversion failed: {e}"), None))?;
+
+        let mut nquads = String::new();
+        for quad in rdf.cloned_quads() {
+            let _ = writeln!(nquads, "{} .", quad.rdf_display());
+        }
+        Self::from_nquads(&nquads)
+    }
+
+    /// Canonicalizes an N-Quads dataset.
+    pub fn from_nquads(nquads: &str) -> Outcome<Self> {
+        let quads = nquads
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(parse_quad)
+            .collect::<Outcome<Vec<_>>>()?;
+        Ok(Self {
+            value: Canonicalizer::new(quads).run()?,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+}
+
+impl AsRef<[u8]> for RdfCanon {
+    fn as_ref(&self) -> &[u8] {
+        self.value.as_bytes()
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    Iri(String),
+    Blank(String),
+    Literal {
+        value: String,
+        datatype: String,
+        lang: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Quad {
+    subject: Term,
+    predicate: Term,
+    object: Term,
+    graph: Option<Term>,
+}
+
+impl Quad {
+    /// Blank nodes of the quad along with their position, as RDFC-1.0 labels them.
+    fn blanks(&self) -> Vec<(&str, &'static str)> {
+        [
+            (Some(&self.subject), "s"),
+            (Some(&self.object), "o"),
+            (self.graph.as_ref(), "g"),
+        ]
+        .into_iter()
+        .filter_map(|(term, position)| match term {
+            Some(Term::Blank(id)) => Some((id.as_str(), position)),
+            _ => None,
+        })
+        .collect()
+    }
+
+    /// Canonical N-Quads line, blank node labels mapped through `label`.
+    fn serialize(&self, label: &dyn Fn(&str) -> String) -> String {
+        let mut line = format!(
+            "{} {} {}",
+            serialize_term(&self.subject, label),
+            serialize_term(&self.predicate, label),
+            serialize_term(&self.object, label)
+        );
+        if let Some(graph) = &self.graph {
+            line.push(' ');
+            line.push_str(&serialize_term(graph, label));
+        }
+        line.push_str(" .\n");
+        line
+    }
+}
+
+fn serialize_term(term: &Term, label: &dyn Fn(&str) -> String) -> String {
+    match term {
+        Term::Iri(iri) => format!("<{iri}>"),
+        Term::Blank(id) => format!("_:{}", label(id)),
+        Term::Literal {
+            value,
+            datatype,
+            lang,
+        } => {
+            let mut out = String::from("\"");
+            for c in value.chars() {
+                match c {
+                    '"' => out.push_str("\\\""),
+                    '\\' => out.push_str("\\\\"),
+                    '\n' => out.push_str("\\n"),
+                    '\r' => out.push_str("\\r"),
+                    c => out.push(c),
+                }
+            }
+            out.push('"');
+            match lang {
+                Some(lang) => {
+                    out.push('@');
+                    out.push_str(lang);
+                }
+                None if datatype != XSD_STRING => {
+                    out.push_str("^^<");
+                    out.push_str(datatype);
+                    out.push('>');
+                }
+                None => {}
+            }
+            out
+        }
+    }
+}
+
+/// Issues sequential blank node identifiers, remembering the order they were issued in.
+#[derive(Debug, Clone)]
+struct IdIssuer {
+    prefix: &'static str,
+    issued: Vec<String>,
+    ids: HashMap<String, String>,
+}
+
+impl IdIssuer {
+    fn new(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            issued: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+
+    fn get(&self, id: &str) -> Option<&String> {
+        self.ids.get(id)
+    }
+
+    fn issue(&mut self, id: &str) -> String {
+        if let Some(issued) = self.ids.get(id) {
+            return issued.clone();
+        }
+        let issued = format!("{}{}", self.prefix, self.issued.len());
+        self.issued.push(id.to_string());
+        self.ids.insert(id.to_string(), issued.clone());
+        issued
+    }
+}
+
+/// State of the RDFC-1.0 canonicalization algorithm over one dataset.
+struct Canonicalizer {
+    quads: Vec<Quad>,
+    blank_quads: HashMap<String, Vec<usize>>,
+    canonical: IdIssuer,
+    n_degree_calls: usize,
+}
+
+impl Canonicalizer {
+    fn new(quads: Vec<Quad>) -> Self {
+        let mut blank_quads: HashMap<String, Vec<usize>> = HashMap::new();
+        for (index, quad) in quads.iter().enumerate() {
+            for (id, _) in quad.blanks() {
+                let entry = blank_quads.entry(id.to_string()).or_default();
+                if entry.last() != Some(&index) {
+                    entry.push(index);
+                }
+            }
+        }
+        Self {
+            quads,
+            blank_quads,
+            canonical: IdIssuer::new("c14n"),
+            n_degree_calls: 0,
+        }
+    }
+
+    fn run(mut self) -> Outcome<String> {
+        let mut hash_blanks: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut blanks: Vec<&String> = self.blank_quads.keys().collect();
+        blanks.sort();
+        for id in blanks {
+            hash_blanks
+                .entry(self.hash_first_degree(id))
+                .or_default()
+                .push(id.clone());
+        }
+
+        let mut shared = Vec::new();
+        for (_, ids) in hash_blanks {
+            match ids.as_slice() {
+                [id] => {
+                    self.canonical.issue(id);
+                }
+                _ => shared.push(ids),
+            }
+        }
+
+        for ids in shared {
+            let mut paths = Vec::new();
+            for id in ids {
+                if self.canonical.get(&id).is_some() {
+                    continue;
+                }
+                let mut issuer = IdIssuer::new("b");
+                issuer.issue(&id);
+                paths.push(self.hash_n_degree(&id, issuer)?);
+            }
+            paths.sort_by(|a, b| a.0.cmp(&b.0));
+            for (_, issuer) in paths {
+                for id in issuer.issued {
+                    self.canonical.issue(&id);
+                }
+            }
+        }
+
+        let label = |id: &str| self.canonical.get(id).cloned().unwrap_or_default();
+        let mut lines: Vec<String> = self.quads.iter().map(|q| q.serialize(&label)).collect();
+        lines.sort();
+        lines.dedup();
+        Ok(lines.concat())
+    }
+
+    fn hash_first_degree(&self, id: &str) -> String {
+        let label = |other: &str| if other == id { "a" } else { "z" }.to_string();
+        let mut lines: Vec<String> = self.blank_quads[id]
+            .iter()
+            .map(|&index| self.quads[index].serialize(&label))
+            .collect();
+        lines.sort();
+        sha256_hex(lines.concat().as_bytes())
+    }
+
+    fn hash_related(
+        &self,
+        related: &str,
+        quad: &Quad,
+        issuer: &IdIssuer,
+        position: &str,
+    ) -> String {
+        let mut input = position.to_string();
+        if position != "g" {
+            input.push_str(&serialize_term(&quad.predicate, &|id| id.to_string()));
+        }
+        match self.canonical.get(related).or_else(|| issuer.get(related)) {
+            Some(issued) => {
+                input.push_str("_:");
+                input.push_str(issued);
+            }
+            None => input.push_str(&self.hash_first_degree(related)),
+        }
+        sha256_hex(input.as_bytes())
+    }
+
+    fn hash_n_degree(&mut self, id: &str, mut issuer: IdIssuer) -> Outcome<(String, IdIssuer)> {
+        self.n_degree_calls += 1;
+        if self.n_degree_calls > MAX_N_DEGREE_CALLS {
+            return Err(Errors::parse(
+                "RDF dataset is too costly to canonicalize",
+                None,
+            ));
+        }
+
+        let mut related_blanks: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for &index in &self.blank_quads[id] {
+            let quad = &self.quads[index];
+            for (related, position) in quad.blanks() {
+                if related != id {
+                    let hash = self.hash_related(related, quad, &issuer, position);
+                    related_blanks
+                        .entry(hash)
+                        .or_default()
+                        .push(related.to_string());
+                }
+            }
+        }
+
+        let mut data = String::new();
+        for (hash, blanks) in related_blanks {
+            data.push_str(&hash);
+            let mut chosen_path = String::new();
+            let mut chosen_issuer = None;
+
+            for permutation in permutations(&blanks) {
+                let mut issuer_copy = issuer.clone();
+                let mut path = String::new();
+                let mut recursion = Vec::new();
+                let mut pruned = false;
+
+                for related in &permutation {
+                    match self.canonical.get(related) {
+                        Some(canonical) => path.push_str(&format!("_:{canonical}")),
+                        None => {
+                            if issuer_copy.get(related).is_none() {
+                                recursion.push(related.clone());
+                            }
+                            path.push_str(&format!("_:{}", issuer_copy.issue(related)));
+                        }
+                    }
+                    if worse_path(&path, &chosen_path) {
+                        pruned = true;
+                        break;
+                    }
+                }
+                if pruned {
+                    continue;
+                }
+
+                for related in recursion {
+                    let (result_hash, result_issuer) =
+                        self.hash_n_degree(&related, issuer_copy.clone())?;
+                    path.push_str(&format!("_:{}", issuer_copy.issue(&related)));
+                    path.push_str(&format!("<{result_hash}>"));
+                    issuer_copy = result_issuer;
+                    if worse_path(&path, &chosen_path) {
+                        pruned = true;
+                        break;
+                    }
+                }
+                if pruned {
+                    continue;
+                }
+
+                if chosen_issuer.is_none() || path < chosen_path {
+                    chosen_path = path;
+                    chosen_issuer = Some(issuer_copy);
+                }
+            }
+
+            data.push_str(&chosen_path);
+            if let Some(chosen) = chosen_issuer {
+                issuer = chosen;
+            }
+        }
+
+        Ok((sha256_hex(data.as_bytes()), issuer))
+    }
+}
+
+/// Whether `path` can no longer beat the path chosen so far.
+fn worse_path(path: &str, chosen: &str) -> bool {
+    !chosen.is_empty() && path.len() >= chosen.len() && path > chosen
+}
+
+/// Every ordering of `items`, in lexicographic order of their indices.
+fn permutations(items: &[String]) -> Vec<Vec<String>> {
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    let mut all = vec![order.iter().map(|&i| items[i].clone()).collect()];
+    loop {
+        let Some(pivot) = (1..order.len()).rev().find(|&i| order[i - 1] < order[i]) else {
+            return all;
+        };
+        let swap = (pivot..order.len())
+            .rev()
+            .find(|&i| order[i] > order[pivot - 1])
+            .unwrap_or(pivot);
+        order.swap(pivot - 1, swap);
+        order[pivot..].reverse();
+        all.push(order.iter().map(|&i| items[i].clone()).collect());
+    }
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+/// Refuses remote contexts other than the [`PINNED_CONTEXTS`].
+///
+/// Embedded context objects pass; any URL they import in turn is still refused by the loader.
+fn check_contexts(context: Option<&Value>) -> Outcome<()> {
+    match context {
+        Some(Value::String(url)) if !PINNED_CONTEXTS.iter().any(|(pinned, _)| pinned == url) => {
+            Err(Errors::format(
+                BadFormat::Received,
+                format!("JSON-LD context '{url}' is not supported"),
+                None,
+            ))
+        }
+        Some(Value::Array(contexts)) => contexts.iter().try_for_each(|c| check_contexts(Some(c))),
+        _ => Ok(()),
+    }
+}
+
+/// Loader resolving the [`PINNED_CONTEXTS`] and nothing else.
+fn pinned_loader() -> Outcome<HashMap<IriBuf, RemoteDocument>> {
+    PINNED_CONTEXTS
+        .iter()
+        .map(|(url, document)| {
+            let iri = IriBuf::new(url.to_string()).map_err(|_| {
+                Errors::crazy(
+                    format!("Pinned JSON-LD context '{url}' is not an IRI"),
+                    None,
+                )
+            })?;
+            let (json, _) = json_ld::syntax::Value::parse_str(document).map_err(|e| {
+                Errors::crazy(
+                    format!("Pinned JSON-LD context '{url}' is malformed: {e}"),
+                    None,
+                )
+            })?;
+            Ok((iri.clone(), RemoteDocument::new(Some(iri), None, json)))
+        })
+        .collect()
+}
+
+fn parse_quad(line: &str) -> Outcome<Quad> {
+    let mut rest = line;
+    let mut terms = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() || rest.starts_with('.') {
+            break;
+        }
+        let (term, tail) = parse_term(rest)?;
+        terms.push(term);
+        rest = tail;
+    }
+
+    let bad = || {
+        Errors::format(
+            BadFormat::Received,
+            format!("Malformed N-Quads line: {line}"),
+            None,
+        )
+    };
+    let mut terms = terms.into_iter();
+    let (Some(subject), Some(predicate), Some(object)) = (terms.next(), terms.next(), terms.next())
+    else {
+        return Err(bad());
+    };
+    let graph = terms.next();
+    if terms.next().is_some() || !matches!(predicate, Term::Iri(_)) {
+        return Err(bad());
+    }
+    Ok(Quad {
+        subject,
+        predicate,
+        object,
+        graph,
+    })
+}
+
+fn parse_term(input: &str) -> Outcome<(Term, &str)> {
+    let bad = || {
+        Errors::format(
+            BadFormat::Received,
+            format!("Malformed N-Quads term: {input}"),
+            None,
+        )
+    };
+    if let Some(rest) = input.strip_prefix('<') {
+        let end = rest.find('>').ok_or_else(bad)?;
+        return Ok((Term::Iri(rest[..end].to_string()), &rest[end + 1..]));
+    }
+    if let Some(rest) = input.strip_prefix("_:") {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        return Ok((Term::Blank(rest[..end].to_string()), &rest[end..]));
+    }
+    let rest = input.strip_prefix('"').ok_or_else(bad)?;
+
+    let mut value = String::new();
+    let mut chars = rest.char_indices();
+    let end = loop {
+        let (index, c) = chars.next().ok_or_else(bad)?;
+        match c {
+            '"' => break index,
+            '\\' => {
+                let (_, escaped) = chars.next().ok_or_else(bad)?;
+                match escaped {
+                    't' => value.push('\t'),
+                    'b' => value.push('\u{8}'),
+                    'n' => value.push('\n'),
+                    'r' => value.push('\r'),
+                    'f' => value.push('\u{c}'),
+                    'u' | 'U' => {
+                        let len = if escaped == 'u' { 4 } else { 8 };
+                        let hex: String = (0..len)
+                            .map(|_| chars.next().map(|(_, c)| c))
+                            .collect::<Option<_>>()
+                            .ok_or_else(bad)?;
+                        let code = u32::from_str_radix(&hex, 16).map_err(|_| bad())?;
+                        value.push(char::from_u32(code).ok_or_else(bad)?);
+                    }
+                    c => value.push(c),
+                }
+            }
+            c => value.push(c),
+        }
+    };
+
+    let rest = &rest[end + 1..];
+    if let Some(tagged) = rest.strip_prefix('@') {
+        let end = tagged.find(char::is_whitespace).unwrap_or(tagged.len());
+        let term = Term::Literal {
+            value,
+            datatype: "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString".to_string(),
+            lang: Some(tagged[..end].to_string()),
+        };
+        return Ok((term, &tagged[end..]));
+    }
+    if let Some(typed) = rest.strip_prefix("^^") {
+        let (Term::Iri(datatype), tail) = parse_term(typed)? else {
+            return Err(bad());
+        };
+        let term = Term::Literal {
+            value,
+            datatype,
+            lang: None,
+        };
+        return Ok((term, tail));
+    }
+    let term = Term::Literal {
+        value,
+        datatype: XSD_STRING.to_string(),
+        lang: None,
+    };
+    Ok((term, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn pinned_contexts_are_bundled() {
+        let loader = pinned_loader().expect("bundled contexts parse");
+        assert_eq!(loader.len(), PINNED_CONTEXTS.len());
+    }
+
+    #[test]
+    fn unpinned_contexts_are_rejected() {
+        for context in [
+            json!("https://example.com/credentials/v1"),
+            json!("http://www.w3.org/ns/credentials/v2"),
+            json!(["https://www.w3.org/ns/credentials/v2", "file:///etc/passwd"]),
+        ] {
+            assert!(
+                matches!(
+                    check_contexts(Some(&context)),
+                    Err(Errors::FormatError { .. })
+                ),
+                "{context} must be rejected"
+            );
+        }
+        let pinned = json!([
+            "https://www.w3.org/ns/credentials/v2",
+            "https://w3id.org/security/data-integrity/v2",
+            { "ex": "https://example.com/vocab#" }
+        ]);
+        assert!(check_contexts(Some(&pinned)).is_ok());
+    }
+}
@@ -0,0 +1,227 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use pbkdf2::pbkdf2_hmac_array;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::utils::{decode_url_safe_no_pad, encode_url_safe_no_pad};
+
+/// Version of the [`SealedEnvelope`] produced by this build.
+pub const SEALED_ENVELOPE_VERSION: u8 = 1;
+
+/// PBKDF2 rounds applied to new envelopes, after the OWASP recommendation for HMAC-SHA256.
+pub const SEALED_ENVELOPE_ITERATIONS: u32 = 600_000;
+
+/// PBKDF2 rounds accepted when opening an envelope, so a crafted header can neither weaken
+/// the derivation nor stall the node before the envelope is authenticated.
+pub const MIN_SEALED_ENVELOPE_ITERATIONS: u32 = 100_000;
+pub const MAX_SEALED_ENVELOPE_ITERATIONS: u32 = 10_000_000;
+
+const MIN_PASSPHRASE_LEN: usize = 12;
+const KDF: &str = "PBKDF2-HMAC-SHA256";
+const CIPHER: &str = "A256GCM";
+
+/// Key sealing a [`SealedEnvelope`], derived once from the passphrase and the envelope salt.
+pub struct EnvelopeKey {
+    key: [u8; 32],
+    salt: Vec<u8>,
+    iterations: u32,
+}
+
+impl EnvelopeKey {
+    /// Derives the key of a new envelope from `passphrase` under a fresh salt.
+    ///
+    /// `what` names the sealed artefact in error messages.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`] when the passphrase is too short to protect secrets.
+    pub fn derive(passphrase: &str, what: &str) -> Outcome<Self> {
+        if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!(
+                    "Passphrase of the {what} must be at least {MIN_PASSPHRASE_LEN} characters"
+                ),
+                None,
+            ));
+        }
+        let salt: [u8; 16] = rand::random();
+        Ok(Self {
+            key: pbkdf2_hmac_array::<Sha256, 32>(
+                passphrase.as_bytes(),
+                &salt,
+                SEALED_ENVELOPE_ITERATIONS,
+            ),
+            salt: salt.to_vec(),
+            iterations: SEALED_ENVELOPE_ITERATIONS,
+        })
+    }
+}
+
+/// Passphrase-protected AES-256-GCM envelope shared by secret files and key backups.
+///
+/// The key is derived with PBKDF2-HMAC-SHA256; every envelope parameter, plus any context
+/// the owner binds, is authenticated as additional data, so a tampered header fails to open.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SealedEnvelope {
+    pub version: u8,
+    pub kdf: String,
+    pub iterations: u32,
+    pub salt: String,
+    pub cipher: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+impl SealedEnvelope {
+    /// Encrypts `plaintext` under `key`, binding `context` (if any) as additional data.
+    pub fn seal(plaintext: &[u8], key: &EnvelopeKey, context: Option<&str>) -> Outcome<Self> {
+        let nonce: [u8; 12] = rand::random();
+        let mut envelope = Self {
+            version: SEALED_ENVELOPE_VERSION,
+            kdf: KDF.to_string(),
+            iterations: key.iterations,
+            salt: encode_url_safe_no_pad(&key.salt),
+            cipher: CIPHER.to_string(),
+            nonce: encode_url_safe_no_pad(nonce),
+            ciphertext: String::new(),
+        };
+
+        let ciphertext = Aes256Gcm::new(&key.key.into())
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plaintext,
+                    aad: envelope.aad(context).as_bytes(),
+                },
+            )
+            .map_err(|_| Errors::crazy("Unable to encrypt sealed envelope", None))?;
+        envelope.ciphertext = encode_url_safe_no_pad(ciphertext);
+        Ok(envelope)
+    }
+
+    /// Derives the key of this envelope from `passphrase`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`] for envelopes this build cannot read or whose
+    /// iteration count is outside [`MIN_SEALED_ENVELOPE_ITERATIONS`] and
+    /// [`MAX_SEALED_ENVELOPE_ITERATIONS`].
+    pub fn key_for(&self, passphrase: &str, what: &str) -> Outcome<EnvelopeKey> {
+        if self.version != SEALED_ENVELOPE_VERSION || self.kdf != KDF || self.cipher != CIPHER {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!(
+                    "Unsupported {what} v{} ({}, {})",
+                    self.version, self.kdf, self.cipher
+                ),
+                None,
+            ));
+        }
+        let rounds = MIN_SEALED_ENVELOPE_ITERATIONS..=MAX_SEALED_ENVELOPE_ITERATIONS;
+        if !rounds.contains(&self.iterations) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!(
+                    "PBKDF2 rounds of the {what} must be between {MIN_SEALED_ENVELOPE_ITERATIONS} \
+                     and {MAX_SEALED_ENVELOPE_ITERATIONS}"
+                ),
+                None,
+            ));
+        }
+        let salt = decode_url_safe_no_pad(&self.salt)?;
+        Ok(EnvelopeKey {
+            key: pbkdf2_hmac_array::<Sha256, 32>(passphrase.as_bytes(), &salt, self.iterations),
+            salt,
+            iterations: self.iterations,
+        })
+    }
+
+    /// Decrypts the envelope with `key`, checking the `context` it was sealed with.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::SecurityError`] when the key is wrong or the envelope was altered.
+    pub fn open(&self, key: &EnvelopeKey, context: Option<&str>, what: &str) -> Outcome<Vec<u8>> {
+        let nonce = decode_url_safe_no_pad(&self.nonce)?;
+        if nonce.len() != 12 {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Nonce of the {what} must be 96 bits"),
+                None,
+            ));
+        }
+        let ciphertext = decode_url_safe_no_pad(&self.ciphertext)?;
+
+        Aes256Gcm::new(&key.key.into())
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &ciphertext,
+                    aad: self.aad(context).as_bytes(),
+                },
+            )
+            .map_err(|_| Errors::security(format!("Wrong passphrase or altered {what}"), None))
+    }
+
+    /// Envelope parameters authenticated alongside the ciphertext.
+    fn aad(&self, context: Option<&str>) -> String {
+        let header = format!(
+            "{}.{}.{}.{}.{}",
+            self.version, self.kdf, self.iterations, self.salt, self.cipher
+        );
+        match context {
+            Some(context) => format!("{header}.{context}"),
+            None => header,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envelope(iterations: u32) -> SealedEnvelope {
+        SealedEnvelope {
+            version: SEALED_ENVELOPE_VERSION,
+            kdf: KDF.to_string(),
+            iterations,
+            salt: encode_url_safe_no_pad([0u8; 16]),
+            cipher: CIPHER.to_string(),
+            nonce: encode_url_safe_no_pad([0u8; 12]),
+            ciphertext: String::new(),
+        }
+    }
+
+    #[test]
+    fn iterations_outside_the_accepted_range_are_rejected() {
+        for iterations in [
+            0,
+            1,
+            MIN_SEALED_ENVELOPE_ITERATIONS - 1,
+            MAX_SEALED_ENVELOPE_ITERATIONS + 1,
+        ] {
+            let result = envelope(iterations).key_for("correct horse battery", "secret file");
+            assert!(
+                matches!(result, Err(Errors::FormatError { .. })),
+                "{iterations} rounds must be rejected"
+            );
+        }
+    }
+}
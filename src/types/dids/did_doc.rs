@@ -15,11 +15,11 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use super::{DidService, VerificationMethod};
+use super::{DidService, VerificationMaterial, VerificationMethod};
 use crate::capabilities::Did;
-use crate::errors::Outcome;
-use crate::types::keys::PrivateKey;
-use crate::utils::StringOrArr;
+use crate::errors::{Errors, Outcome};
+use crate::types::keys::PublicKey;
+use crate::utils::{StringOrArr, encode_url_safe_no_pad};
 use sea_orm::{FromJsonQueryResult};
 use serde::{Deserialize, Serialize};
 
@@ -55,7 +55,7 @@ pub struct DidDocument {
 }
 
 impl DidDocument {
-    pub fn base(did: &Did, key_with_frag: Vec<(PrivateKey, String)>) -> DidDocument {
+    pub fn base(did: &Did, key_with_frag: Vec<(PublicKey, String)>) -> DidDocument {
         let vms: Vec<VerificationMethod> = key_with_frag
             .iter()
             .map(|(key, vm_frag)| VerificationMethod::new(did, &key, &vm_frag))
@@ -84,7 +84,7 @@ impl DidDocument {
         self.service = Some(services);
     }
 
-    pub fn add_key(&mut self, key: &PrivateKey, vm_frag: Option<&str>) {
+    pub fn add_key(&mut self, key: &PublicKey, vm_frag: Option<&str>) {
         let did = Did::parse(&self.id).unwrap(); // THE CREATION MAKES PANIC IMPOSSIBLE
         let len = self.verification_method.len().to_string();
         let key_frag = vm_frag.unwrap_or(&len).to_string();
@@ -98,4 +98,50 @@ impl DidDocument {
         self.verification_method
             .retain(|vm| vm.id != vm_id);
     }
+
+    /// Returns the identifiers listed in `alsoKnownAs`.
+    pub fn aliases(&self) -> Vec<String> {
+        match &self.also_known_as {
+            None => Vec::new(),
+            Some(StringOrArr::String(alias)) => vec![alias.clone()],
+            Some(StringOrArr::Arr(aliases)) => aliases.clone(),
+        }
+    }
+
+    /// Lists `did` in `alsoKnownAs`, keeping the entries already there.
+    pub fn add_alias(&mut self, did: &Did) {
+        let mut aliases = self.aliases();
+        if !aliases.iter().any(|alias| alias == did.id()) {
+            aliases.push(did.id().to_string());
+        }
+        self.also_known_as = Some(StringOrArr::Arr(aliases));
+    }
+
+    /// Derives the `did:jwk` of the verification method `vm_frag`.
+    ///
+    /// The JWK is encoded exactly as [`DidBuilder`](super::DidBuilder) does, so the result is
+    /// the same DID the wallet would have created from the private key.
+    pub fn jwk_alias(&self, vm_frag: &str) -> Outcome<Did> {
+        let vm_id = format!("{}#{}", self.id, vm_frag);
+        let vm = self
+            .verification_method
+            .iter()
+            .find(|vm| vm.id == vm_id)
+            .ok_or_else(|| {
+                Errors::missing_resource(&vm_id, "Verification method not found", None)
+            })?;
+
+        let jwk = match &vm.material {
+            VerificationMaterial::JsonWebKey { public_key_jwk }
+            | VerificationMaterial::JsonWebKey2020 { public_key_jwk } => public_key_jwk,
+            VerificationMaterial::Multikey { .. } => {
+                return Err(Errors::not_impl(
+                    "did:jwk aliases require a JWK verification method",
+                    None,
+                ));
+            }
+        };
+        let jwk = serde_json::to_vec(jwk)?;
+        Did::parse(&format!("did:jwk:{}", encode_url_safe_no_pad(jwk)))
+    }
 }
@@ -30,6 +30,10 @@ pub enum DidType {
 
     #[sea_orm(string_value = "web")]
     Web,
+
+    /// Method without native support, resolved through the Universal Resolver fallback.
+    #[sea_orm(string_value = "other")]
+    Other,
 }
 
 impl Display for DidType {
@@ -37,6 +41,7 @@ impl Display for DidType {
         let s = match self {
             DidType::Jwk => "Jwk",
             DidType::Web => "Web",
+            DidType::Other => "Other",
         };
         write!(f, "{s}")
     }
@@ -49,6 +54,7 @@ impl FromStr for DidType {
         match s {
             "Jwk" => Ok(DidType::Jwk),
             "Web" => Ok(DidType::Web),
+            "Other" => Ok(DidType::Other),
             did => Err(Errors::not_impl(
                 format!("DidType {did} not supported"),
                 None,
@@ -129,3 +135,25 @@ impl WebDid {
         }
     }
 }
+
+/// DID of a method ymir cannot resolve natively.
+#[derive(Debug, Clone)]
+pub struct ExternalDid {
+    id: String,
+    method: String,
+}
+
+impl ExternalDid {
+    pub fn new(id: impl Into<String>, method: impl Into<String>) -> ExternalDid {
+        ExternalDid {
+            id: id.into(),
+            method: method.into(),
+        }
+    }
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+}
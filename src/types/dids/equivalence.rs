@@ -0,0 +1,44 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// JOSE `typ` header carried by signed DID equivalence statements.
+pub const EQUIVALENCE_TYP: &str = "did-equivalence+jwt";
+
+/// Statement by `iss` that `sub` is controlled by the same key.
+///
+/// One statement is signed under each DID of the pair, so a peer holding both can check the
+/// binding in either direction without trusting the web host alone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DidEquivalence {
+    pub iss: String,
+    pub sub: String,
+    pub iat: i64,
+}
+
+/// Signed equivalence statements published by the agent.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DidEquivalenceProof {
+    /// Primary DID of the agent.
+    pub did: String,
+    /// Every other DID the agent answers to.
+    #[serde(rename = "alsoKnownAs")]
+    pub also_known_as: Vec<String>,
+    /// Compact JWTs carrying a [`DidEquivalence`] each.
+    pub statements: Vec<String>,
+}
@@ -19,10 +19,12 @@ mod did_builder;
 mod did_doc;
 mod did_service;
 mod did_type;
+mod equivalence;
 mod ver_method;
 
 pub use did_builder::*;
 pub use did_doc::DidDocument;
 pub use did_service::*;
 pub use did_type::*;
+pub use equivalence::{DidEquivalence, DidEquivalenceProof, EQUIVALENCE_TYP};
 pub use ver_method::*;
@@ -16,7 +16,7 @@
  */
 
 use crate::capabilities::Did;
-use crate::types::keys::PrivateKey;
+use crate::types::keys::PublicKey;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -51,7 +51,7 @@ pub enum VerificationMaterial {
 }
 
 impl VerificationMethod {
-    pub fn new(did: &Did, key: &PrivateKey, vm_frag: &str) -> Self {
+    pub fn new(did: &Did, key: &PublicKey, vm_frag: &str) -> Self {
         Self {
             id: format!("{}#{}", did.id(), vm_frag),
             controller: did.id().to_string(),
@@ -0,0 +1,128 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::errors::{BadFormat, Errors, Outcome};
+
+/// Maximum serialized size, in bytes, of the `extensions` object of a single record.
+pub const MAX_EXTENSIONS_BYTES: usize = 16 * 1024;
+/// Maximum number of top-level keys in an `extensions` object.
+pub const MAX_EXTENSIONS_KEYS: usize = 64;
+/// Maximum length of a top-level `extensions` key.
+pub const MAX_EXTENSION_KEY_LEN: usize = 128;
+
+/// Entity carrying integrator-defined `extensions` data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtensionTarget {
+    Participant,
+    Issuance,
+    SentGrant,
+    ReceivedGrant,
+}
+
+impl Display for ExtensionTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ExtensionTarget::Participant => "participant",
+            ExtensionTarget::Issuance => "issuance",
+            ExtensionTarget::SentGrant => "sent grant",
+            ExtensionTarget::ReceivedGrant => "received grant",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Record id paired with its `extensions`, as listed by searches.
+///
+/// Keeps tokens and codes of the underlying record out of search responses.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExtensionRecord {
+    pub id: String,
+    pub extensions: Value,
+}
+
+/// Empty `extensions` object stored by default.
+pub fn empty_extensions() -> Value {
+    Value::Object(Map::new())
+}
+
+/// Checks that `extensions` is a JSON object within the size limits.
+///
+/// # Errors
+/// Returns an [`Errors::FormatError`] describing the first violated limit.
+pub fn validate_extensions(extensions: &Value) -> Outcome<()> {
+    let Some(object) = extensions.as_object() else {
+        return Err(Errors::format(
+            BadFormat::Received,
+            "extensions must be a JSON object",
+            None,
+        ));
+    };
+    if object.len() > MAX_EXTENSIONS_KEYS {
+        return Err(Errors::format(
+            BadFormat::Received,
+            format!("extensions cannot hold more than {MAX_EXTENSIONS_KEYS} keys"),
+            None,
+        ));
+    }
+    if let Some(key) = object
+        .keys()
+        .find(|k| k.is_empty() || k.len() > MAX_EXTENSION_KEY_LEN)
+    {
+        return Err(Errors::format(
+            BadFormat::Received,
+            format!("Invalid extensions key '{key}'"),
+            None,
+        ));
+    }
+    let size = serde_json::to_vec(extensions)
+        .map(|bytes| bytes.len())
+        .unwrap_or_default();
+    if size > MAX_EXTENSIONS_BYTES {
+        return Err(Errors::format(
+            BadFormat::Received,
+            format!("extensions exceed {MAX_EXTENSIONS_BYTES} bytes ({size})"),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+/// Applies a JSON merge patch (RFC 7386): `null` removes a key and nested objects are merged.
+pub fn merge_extensions(target: &mut Value, patch: Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+    if !target.is_object() {
+        *target = empty_extensions();
+    }
+    if let Value::Object(current) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                current.remove(&key);
+            } else {
+                merge_extensions(current.entry(key).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
@@ -15,8 +15,12 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::time::Duration;
+
+use super::TokenManagement;
 use crate::data::entities::shared::resource_req;
 use crate::types::gnap::grant_request::access::{AccessTokenFlag, ResourceAccess};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -26,7 +30,7 @@ pub struct AccessToken {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub manage: Option<Value>,
+    pub manage: Option<TokenManagement>,
     pub access: ResourceAccess,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expires_in: Option<u64>,
@@ -55,4 +59,56 @@ impl AccessToken {
             flags: model.flags,
         }
     }
+
+    /// Tells whether the token is a bearer token rather than bound to the client key.
+    pub fn is_bearer(&self) -> bool {
+        self.flags
+            .as_ref()
+            .is_some_and(|flags| flags.contains(&AccessTokenFlag::Bearer))
+    }
+
+    /// Makes the token expire `ttl` after being issued.
+    pub fn with_expiry(mut self, ttl: Duration) -> Self {
+        self.expires_in = Some(ttl.as_secs());
+        self
+    }
+
+    /// Lets the client rotate the token through the management `uri`.
+    pub fn with_manage(mut self, uri: impl Into<String>, token: impl Into<String>) -> Self {
+        self.manage = Some(TokenManagement::new(uri, token));
+        self
+    }
+
+    /// Returns the instant the token stops being valid when issued at `issued_at`.
+    pub fn expires_at(&self, issued_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.expires_in
+            .map(|secs| issued_at + chrono::Duration::seconds(secs as i64))
+    }
+}
+
+/// The `access_token` member of an approved grant response (RFC 9635 §3.2).
+///
+/// Mirrors the request: one token, or one token per requested label.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum AccessTokens {
+    Single(AccessToken),
+    Multiple(Vec<AccessToken>),
+}
+
+impl AccessTokens {
+    /// Returns the token issued under `label`.
+    pub fn get(&self, label: &str) -> Option<&AccessToken> {
+        self.as_slice()
+            .iter()
+            .find(|token| token.label.as_deref() == Some(label))
+    }
+
+    /// Returns every issued token.
+    pub fn as_slice(&self) -> &[AccessToken] {
+        match self {
+            AccessTokens::Single(token) => std::slice::from_ref(token),
+            AccessTokens::Multiple(tokens) => tokens,
+        }
+    }
 }
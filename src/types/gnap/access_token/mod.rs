@@ -17,6 +17,10 @@
 
 mod access_token;
 mod continue_token;
+mod stored_token;
+mod token_management;
 
-pub use access_token::AccessToken;
+pub use access_token::{AccessToken, AccessTokens};
 pub use continue_token::ContinueToken;
+pub use stored_token::StoredToken;
+pub use token_management::{RotatedToken, TokenManagement};
@@ -0,0 +1,52 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::FromJsonQueryResult;
+use serde::{Deserialize, Serialize};
+
+use super::AccessToken;
+use crate::utils::Clock;
+
+/// Access token kept by the client, with what it needs to use and rotate it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromJsonQueryResult)]
+pub struct StoredToken {
+    pub label: Option<String>,
+    pub value: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub manage_uri: Option<String>,
+    pub manage_token: Option<String>,
+}
+
+impl StoredToken {
+    /// Keeps `token`, issued or rotated by the authority at `issued_at`.
+    pub fn new(token: AccessToken, issued_at: DateTime<Utc>) -> Self {
+        Self {
+            expires_at: token.expires_at(issued_at),
+            manage_uri: token.manage.as_ref().map(|m| m.uri.clone()),
+            manage_token: token.manage.map(|m| m.access_token.value),
+            label: token.label,
+            value: token.value,
+        }
+    }
+
+    /// Tells whether the token expires within `margin` from now.
+    pub fn expiring(&self, margin: Duration) -> bool {
+        self.expires_at
+            .is_some_and(|at| at <= Clock::now() + margin)
+    }
+}
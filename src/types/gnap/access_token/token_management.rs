@@ -0,0 +1,45 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use super::{AccessToken, ContinueToken};
+
+/// Token management details of an issued access token (RFC 9635 §3.2.1).
+///
+/// The client rotates the access token by posting to `uri` with `access_token` as its
+/// GNAP authorization, without going through the interaction again.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TokenManagement {
+    pub uri: String,
+    pub access_token: ContinueToken,
+}
+
+impl TokenManagement {
+    pub fn new(uri: impl Into<String>, token: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            access_token: ContinueToken::new(token),
+        }
+    }
+}
+
+/// Answer of the token management URI to a rotation call (RFC 9635 §6.1).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RotatedToken {
+    pub access_token: AccessToken,
+}
@@ -15,9 +15,13 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 
-use super::{AccessTokenFlag, ResourceAccess};
+use super::{AccessTokenFlag, AccessType, ResourceAccess};
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::types::gnap::grant_request::interact::InteractAction;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AccessTokenRequest {
@@ -27,3 +31,75 @@ pub struct AccessTokenRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub flags: Option<Vec<AccessTokenFlag>>,
 }
+
+impl AccessTokenRequest {
+    /// Asks for an API access token allowing `actions`.
+    pub fn api_access(actions: Vec<InteractAction>, label: Option<String>) -> Self {
+        Self {
+            access: ResourceAccess {
+                r#type: AccessType::ApiAccess,
+                actions: Some(actions),
+                locations: None,
+                datatypes: None,
+                identifier: None,
+                privileges: None,
+            },
+            label,
+            flags: None,
+        }
+    }
+}
+
+/// The `access_token` member of a grant request (RFC 9635 §2.1).
+///
+/// A request either asks for one token or for several, each told apart by its label.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum AccessTokenRequests {
+    Single(AccessTokenRequest),
+    Multiple(Vec<AccessTokenRequest>),
+}
+
+impl AccessTokenRequests {
+    /// Asks for one API access token per `(label, actions)` pair.
+    pub fn labeled(tokens: Vec<(String, Vec<InteractAction>)>) -> Self {
+        AccessTokenRequests::Multiple(
+            tokens
+                .into_iter()
+                .map(|(label, actions)| AccessTokenRequest::api_access(actions, Some(label)))
+                .collect(),
+        )
+    }
+    /// Returns every requested token.
+    pub fn as_slice(&self) -> &[AccessTokenRequest] {
+        match self {
+            AccessTokenRequests::Single(request) => std::slice::from_ref(request),
+            AccessTokenRequests::Multiple(requests) => requests,
+        }
+    }
+
+    /// Checks that a multiple token request labels every token, each label once.
+    pub fn check_labels(&self) -> Outcome<()> {
+        let AccessTokenRequests::Multiple(requests) = self else {
+            return Ok(());
+        };
+        let mut seen = HashSet::new();
+        for request in requests {
+            let Some(label) = request.label.as_deref() else {
+                return Err(Errors::format(
+                    BadFormat::Received,
+                    "Every access token of a multiple token request needs a label",
+                    None,
+                ));
+            };
+            if !seen.insert(label) {
+                return Err(Errors::format(
+                    BadFormat::Received,
+                    format!("Access token label {label} is requested more than once"),
+                    None,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use super::{Client, ClientKey};
+
+/// The `client` member of a grant request (RFC 9635 §2.3).
+///
+/// A client pre-registered with the authority, or given an `instance_id` by an earlier grant,
+/// sends that identifier alone instead of its whole key.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ClientInstance {
+    Reference(String),
+    Full(Client),
+}
+
+impl ClientInstance {
+    /// Returns the key the request carries, if it was sent in full.
+    pub fn key(&self) -> Option<&ClientKey> {
+        match self {
+            ClientInstance::Reference(_) => None,
+            ClientInstance::Full(client) => Some(&client.key),
+        }
+    }
+
+    /// Returns the instance identifier the request refers to, if any.
+    pub fn instance_id(&self) -> Option<&str> {
+        match self {
+            ClientInstance::Reference(id) => Some(id),
+            ClientInstance::Full(_) => None,
+        }
+    }
+}
+
+impl From<Client> for ClientInstance {
+    fn from(client: Client) -> Self {
+        ClientInstance::Full(client)
+    }
+}
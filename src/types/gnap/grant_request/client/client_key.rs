@@ -19,6 +19,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::{KeyMaterial, KeyProof};
+use crate::errors::{Errors, Outcome};
+use crate::types::keys::DbKeySource;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ClientKey {
@@ -41,4 +43,21 @@ impl ClientKey {
             material: KeyMaterial::Cert { cert },
         }
     }
+
+    /// Returns the key material to store and check later requests against.
+    ///
+    /// # Errors
+    /// Returns a not implemented error for proofing methods other than `httpsig`.
+    pub fn to_db(&self) -> Outcome<DbKeySource> {
+        if !matches!(self.proof, KeyProof::HttpSig) {
+            return Err(Errors::not_impl(
+                format!("Key proofing method {} is not supported", self.proof),
+                None,
+            ));
+        }
+        Ok(match &self.material {
+            KeyMaterial::Jwk { jwk } => DbKeySource::PublicKey(jwk.clone()),
+            KeyMaterial::Cert { cert } => DbKeySource::Cert(cert.clone()),
+        })
+    }
 }
@@ -16,11 +16,13 @@
  */
 
 mod client;
+mod client_instance;
 mod client_key;
 mod key_material;
 mod key_proof;
 
 pub use client::Client;
+pub use client_instance::ClientInstance;
 pub use client_key::ClientKey;
 pub use key_material::KeyMaterial;
 pub use key_proof::KeyProof;
@@ -15,8 +15,8 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use super::access::{AccessTokenRequest, AccessType, ResourceAccess};
-use super::client::Client;
+use super::access::{AccessTokenRequest, AccessTokenRequests};
+use super::client::ClientInstance;
 use super::grant_request_kind::GrantRequestKind;
 use super::interact::{InteractAction, InteractRequest};
 use super::subject::SubjectRequest;
@@ -31,7 +31,7 @@ pub struct GrantRequest {
     pub kind: GrantRequestKind,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subject: Option<SubjectRequest>,
-    pub client: Client,
+    pub client: ClientInstance,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -39,7 +39,11 @@ pub struct GrantRequest {
 }
 
 impl GrantRequest {
-    pub fn new_vc(client: Client, vc_type: Vec<VcTypeConfig>, model: &interaction::Model) -> Self {
+    pub fn new_vc(
+        client: impl Into<ClientInstance>,
+        vc_type: Vec<VcTypeConfig>,
+        model: &interaction::Model,
+    ) -> Self {
         let credential_request = AccessCredentialRequest {
             credential_configurations: vc_type,
             label: None,
@@ -48,34 +52,48 @@ impl GrantRequest {
         Self {
             kind: GrantRequestKind::CredentialRequest { credential_request },
             subject: None,
-            client,
+            client: client.into(),
             user: None,
             interact: Some(InteractRequest::new(model.clone())),
         }
     }
 
     pub fn new_token(
-        client: Client,
+        client: impl Into<ClientInstance>,
         actions: Vec<InteractAction>,
         model: &interaction::Model,
     ) -> Self {
-        let access_token = AccessTokenRequest {
-            access: ResourceAccess {
-                r#type: AccessType::ApiAccess,
-                actions: Some(actions),
-                locations: None,
-                datatypes: None,
-                identifier: None,
-                privileges: None,
-            },
-            label: None,
-            flags: None,
-        };
+        let access_token =
+            AccessTokenRequests::Single(AccessTokenRequest::api_access(actions, None));
 
         Self {
             kind: GrantRequestKind::AccessToken { access_token },
-            subject: None,
-            client,
+            subject: Some(SubjectRequest::ids(&["did", "opaque"])),
+            client: client.into(),
+            user: None,
+            interact: Some(InteractRequest::new(model.clone())),
+        }
+    }
+
+    /// Asks for one access token per `(label, actions)` pair in a single grant.
+    pub fn new_labeled_tokens(
+        client: impl Into<ClientInstance>,
+        tokens: Vec<(String, Vec<InteractAction>)>,
+        model: &interaction::Model,
+    ) -> Self {
+        Self::new_tokens(client, AccessTokenRequests::labeled(tokens), model)
+    }
+
+    /// Asks for the access tokens of `access_token` in a single grant.
+    pub fn new_tokens(
+        client: impl Into<ClientInstance>,
+        access_token: AccessTokenRequests,
+        model: &interaction::Model,
+    ) -> Self {
+        Self {
+            kind: GrantRequestKind::AccessToken { access_token },
+            subject: Some(SubjectRequest::ids(&["did", "opaque"])),
+            client: client.into(),
             user: None,
             interact: Some(InteractRequest::new(model.clone())),
         }
@@ -19,14 +19,14 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use sea_orm::entity::prelude::*;
 
-use super::access::AccessTokenRequest;
+use super::access::AccessTokenRequests;
 use crate::types::gnap::grant_request::credential_request::AccessCredentialRequest;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum GrantRequestKind {
     AccessToken {
-        access_token: AccessTokenRequest,
+        access_token: AccessTokenRequests,
     },
     CredentialRequest {
         credential_request: AccessCredentialRequest,
@@ -15,16 +15,26 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::utils::encode_url_safe_no_pad;
 use crate::{impl_seaorm_via_str, impl_serde_via_str};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use sha3::{Sha3_256, Sha3_384, Sha3_512};
 use std::convert::Infallible;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
+/// Hash algorithm used for GNAP interaction finish hashes (RFC 9635 §4.2.3).
+///
+/// Identifiers come from the IANA "Named Information Hash Algorithm" registry.
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum HashMethod {
     Sha256,
     Sha384,
     Sha512,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
     Other(String),
 }
 
@@ -34,6 +44,9 @@ impl Display for HashMethod {
             HashMethod::Sha256 => "sha-256",
             HashMethod::Sha384 => "sha-384",
             HashMethod::Sha512 => "sha-512",
+            HashMethod::Sha3_256 => "sha3-256",
+            HashMethod::Sha3_384 => "sha3-384",
+            HashMethod::Sha3_512 => "sha3-512",
             HashMethod::Other(other) => other.as_str(),
         };
         write!(f, "{}", s)
@@ -46,8 +59,11 @@ impl FromStr for HashMethod {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_ascii_lowercase().as_str() {
             "sha-256" | "sha256" => Ok(HashMethod::Sha256),
-            "sha-384" | "sha384" => Ok(HashMethod::Sha256),
-            "sha-512" | "sha512" => Ok(HashMethod::Sha256),
+            "sha-384" | "sha384" => Ok(HashMethod::Sha384),
+            "sha-512" | "sha512" => Ok(HashMethod::Sha512),
+            "sha3-256" => Ok(HashMethod::Sha3_256),
+            "sha3-384" => Ok(HashMethod::Sha3_384),
+            "sha3-512" => Ok(HashMethod::Sha3_512),
             _ => Ok(HashMethod::Other(s.to_string())),
         }
     }
@@ -55,3 +71,57 @@ impl FromStr for HashMethod {
 
 impl_serde_via_str!(HashMethod);
 impl_seaorm_via_str!(HashMethod, 32);
+
+impl HashMethod {
+    pub fn is_supported(&self) -> bool {
+        !matches!(self, HashMethod::Other(_))
+    }
+
+    /// Resolves the method to use for a client's request, defaulting to `sha-256` when the
+    /// client did not ask for one.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`] when the client requested an unsupported method, so
+    /// the grant is rejected instead of silently producing a hash the client cannot check.
+    pub fn negotiate(requested: Option<HashMethod>) -> Outcome<HashMethod> {
+        let method = requested.unwrap_or(HashMethod::Sha256);
+        if !method.is_supported() {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Unsupported interaction hash_method '{method}'"),
+                None,
+            ));
+        }
+        Ok(method)
+    }
+
+    pub fn digest(&self, input: &[u8]) -> Outcome<Vec<u8>> {
+        Ok(match self {
+            HashMethod::Sha256 => Sha256::digest(input).to_vec(),
+            HashMethod::Sha384 => Sha384::digest(input).to_vec(),
+            HashMethod::Sha512 => Sha512::digest(input).to_vec(),
+            HashMethod::Sha3_256 => Sha3_256::digest(input).to_vec(),
+            HashMethod::Sha3_384 => Sha3_384::digest(input).to_vec(),
+            HashMethod::Sha3_512 => Sha3_512::digest(input).to_vec(),
+            HashMethod::Other(other) => {
+                return Err(Errors::not_impl(
+                    format!("Hash method '{other}' is not supported"),
+                    None,
+                ));
+            }
+        })
+    }
+
+    /// Computes the interaction finish hash: the base64url digest of the client nonce, AS nonce,
+    /// interaction reference and grant endpoint joined by `\n` (RFC 9635 §4.2.3).
+    pub fn interaction_hash(
+        &self,
+        client_nonce: &str,
+        as_nonce: &str,
+        interact_ref: &str,
+        grant_endpoint: &str,
+    ) -> Outcome<String> {
+        let input = format!("{client_nonce}\n{as_nonce}\n{interact_ref}\n{grant_endpoint}");
+        Ok(encode_url_safe_no_pad(self.digest(input.as_bytes())?))
+    }
+}
@@ -27,3 +27,14 @@ pub struct SubjectRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sub_ids: Option<Value>,
 }
+
+impl SubjectRequest {
+    /// Asks for the subject identifiers of the authenticated holder in `formats`.
+    pub fn ids(formats: &[&str]) -> Self {
+        Self {
+            sub_id_formats: Some(formats.iter().map(|f| f.to_string()).collect()),
+            assertion_formats: None,
+            sub_ids: None,
+        }
+    }
+}
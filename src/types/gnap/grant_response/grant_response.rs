@@ -22,7 +22,7 @@ use super::interact::InteractResponse;
 use super::subject::SubjectResponse;
 use crate::data::entities::received::interaction;
 use crate::data::entities::shared::resource_req;
-use crate::types::gnap::access_token::{AccessToken, ContinueToken};
+use crate::types::gnap::access_token::{AccessToken, AccessTokens, ContinueToken};
 use crate::types::vcs::VcTypeConfig;
 use serde::{Deserialize, Serialize};
 
@@ -71,7 +71,7 @@ pub struct ErrorResponse {
 #[serde(untagged)]
 pub enum GrantResponseKind {
     AccessToken {
-        access_token: AccessToken,
+        access_token: AccessTokens,
     },
     CredentialResponse {
         credential_response: CredentialResponse,
@@ -79,12 +79,41 @@ pub enum GrantResponseKind {
 }
 
 impl GrantResponse {
+    /// Returns the continuation the authority handed back, if the grant can be continued.
+    pub fn continuation(&self) -> Option<&Continuation> {
+        match self {
+            GrantResponse::Approved(approved) => approved.r#continue.as_ref(),
+            GrantResponse::Pending(pending) => Some(&pending.r#continue),
+            GrantResponse::Processing(processing) => Some(&processing.r#continue),
+            GrantResponse::Error(_) => None,
+        }
+    }
+
+    /// Tells the client who the authority authenticated during the interaction.
+    ///
+    /// Only an approved grant carries a subject; other responses are left as they are.
+    pub fn with_subject(mut self, subject: SubjectResponse) -> Self {
+        if let GrantResponse::Approved(approved) = &mut self {
+            approved.subject = Some(subject);
+        }
+        self
+    }
+
     pub fn token_approved(token: impl Into<String>, model: &resource_req::Model) -> Self {
+        Self::token_issued(AccessToken::new(token, model.clone()))
+    }
+
+    /// Approves the grant with a prepared token, e.g. one carrying an expiry and a
+    /// management URI for rotation.
+    pub fn token_issued(access_token: AccessToken) -> Self {
+        Self::tokens_issued(AccessTokens::Single(access_token))
+    }
+
+    /// Approves a grant asking for several labeled tokens with one token per label.
+    pub fn tokens_issued(access_token: AccessTokens) -> Self {
         let res = ApprovedResponse {
             r#continue: None,
-            kind: GrantResponseKind::AccessToken {
-                access_token: AccessToken::new(token, model.clone()),
-            },
+            kind: GrantResponseKind::AccessToken { access_token },
             subject: None,
             instance_id: None,
         };
@@ -111,11 +140,7 @@ impl GrantResponse {
     pub fn pending(uri: impl Into<String>, model: &interaction::Model) -> Self {
         // BY DEFAULT IN THIS USE CASE, VERIFICATION IS DONE THROUGH OID4VC, THAT IS WHY THE REST REMAIN AS NONE
         GrantResponse::Pending(PendingResponse {
-            r#continue: Continuation {
-                uri: model.continue_endpoint.clone(),
-                wait: None,
-                access_token: ContinueToken::new(model.continue_token.clone()),
-            },
+            r#continue: continuation(model),
             interact: InteractResponse {
                 oid4vp: Some(uri.into()),
                 redirect: None,
@@ -131,11 +156,7 @@ impl GrantResponse {
 
     pub fn processing(model: &interaction::Model) -> Self {
         GrantResponse::Processing(ProcessingResponse {
-            r#continue: Continuation {
-                uri: model.continue_endpoint.clone(),
-                wait: None,
-                access_token: ContinueToken::new(model.continue_token.clone()),
-            },
+            r#continue: continuation(model),
             instance_id: Some(model.id.clone()),
         })
     }
@@ -152,3 +173,12 @@ impl GrantResponse {
     //     }
     // }
 }
+
+/// Builds the continuation of `model`, advertising how long the client must wait to poll.
+fn continuation(model: &interaction::Model) -> Continuation {
+    Continuation {
+        uri: model.continue_endpoint.clone(),
+        wait: model.continue_wait.map(|secs| secs.max(0) as u64),
+        access_token: ContinueToken::new(model.continue_token.clone()),
+    }
+}
@@ -26,4 +26,4 @@ pub use continuation::Continuation;
 pub use credential_response::CredentialResponse;
 pub use error_code::ErrorCode;
 pub use grant_response::*;
-pub use subject::SubjectResponse;
+pub use subject::{SubjectId, SubjectResponse};
@@ -15,9 +15,15 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use sea_orm::FromJsonQueryResult;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::data::entities::received::verification;
+use crate::errors::{Errors, Outcome};
+use crate::types::gnap::grant_request::SubjectRequest;
+use crate::types::verification::VerificationStatus;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SubjectResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,3 +33,76 @@ pub struct SubjectResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
 }
+
+impl SubjectResponse {
+    /// Describes the holder authenticated by a verified OIDC4VP `session`.
+    ///
+    /// The holder DID and the participant slug it was registered under are returned, limited
+    /// to the `sub_id_formats` the client asked for, if any.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ForbiddenError`] when the session was not verified.
+    pub fn verified(
+        session: &verification::Model,
+        participant_slug: &str,
+        requested: Option<&SubjectRequest>,
+    ) -> Outcome<Self> {
+        let (VerificationStatus::Verified, Some(holder)) = (&session.status, &session.holder)
+        else {
+            return Err(Errors::forbidden(
+                format!("Verification {} did not authenticate a holder", session.id),
+                None,
+            ));
+        };
+        let formats = requested.and_then(|r| r.sub_id_formats.as_deref());
+        let sub_ids = [
+            SubjectId::Did {
+                url: holder.clone(),
+            },
+            SubjectId::Opaque {
+                id: participant_slug.to_string(),
+            },
+        ]
+        .into_iter()
+        .filter(|id| formats.is_none_or(|f| f.iter().any(|format| format == id.format())))
+        .map(|id| serde_json::to_value(id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            sub_ids: Some(sub_ids),
+            assertion: None,
+            updated_at: session.ended_at.map(|at| at.to_rfc3339()),
+        })
+    }
+
+    /// Returns the subject identifiers in a format this crate understands.
+    pub fn ids(&self) -> Vec<SubjectId> {
+        self.sub_ids
+            .iter()
+            .flatten()
+            .filter_map(|id| serde_json::from_value(id.clone()).ok())
+            .collect()
+    }
+}
+
+/// Subject identifier naming who the authority authenticated (RFC 9493).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, FromJsonQueryResult)]
+#[serde(tag = "format", rename_all = "snake_case")]
+pub enum SubjectId {
+    Did { url: String },
+    Opaque { id: String },
+    Email { email: String },
+    IssSub { iss: String, sub: String },
+}
+
+impl SubjectId {
+    /// Returns the identifier format name.
+    pub fn format(&self) -> &'static str {
+        match self {
+            SubjectId::Did { .. } => "did",
+            SubjectId::Opaque { .. } => "opaque",
+            SubjectId::Email { .. } => "email",
+            SubjectId::IssSub { .. } => "iss_sub",
+        }
+    }
+}
@@ -20,11 +20,13 @@ mod callback;
 mod continue_request;
 pub mod grant_request;
 pub mod grant_response;
+mod request_lifecycle;
 mod status;
 mod vc_decision_approval;
 
 pub use callback::{ApprovedCallbackBody, CallbackBody, RejectedCallbackBody};
 pub use continue_request::ContinueRequest;
+pub use request_lifecycle::RequestLifecycle;
 pub use status::GrantStatus;
 pub use vc_decision_approval::VcDecisionApproval;
 
@@ -0,0 +1,74 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Lifecycle of a grant request sent to an Authorization Server.
+///
+/// `Created → InteractionStarted → CallbackReceived → Continued → TokenReceived | Denied`.
+/// Requests answered without interaction go `Created → TokenReceived`, and polling clients
+/// skip the callback with `InteractionStarted → Continued`. Any live state may be `Denied`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, DeriveActiveEnum, EnumIter, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+pub enum RequestLifecycle {
+    /// The grant request has been stored but not answered yet.
+    #[sea_orm(string_value = "Created")]
+    Created,
+    /// The AS asked for interaction and returned its continuation details.
+    #[sea_orm(string_value = "InteractionStarted")]
+    InteractionStarted,
+    /// The finish callback arrived with its `interact_ref` and hash.
+    #[sea_orm(string_value = "CallbackReceived")]
+    CallbackReceived,
+    /// A continuation request has been sent to the AS.
+    #[sea_orm(string_value = "Continued")]
+    Continued,
+    /// The AS issued the access token or credential.
+    #[sea_orm(string_value = "TokenReceived")]
+    TokenReceived,
+    /// The AS or the resource owner refused the grant.
+    #[sea_orm(string_value = "Denied")]
+    Denied,
+}
+
+impl RequestLifecycle {
+    /// Whether the request has reached a final state.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            RequestLifecycle::TokenReceived | RequestLifecycle::Denied
+        )
+    }
+
+    /// Whether the state machine allows moving from `self` to `next`.
+    ///
+    /// `Continued → Continued` covers an AS answering a continuation with another wait.
+    pub fn can_transition_to(&self, next: &RequestLifecycle) -> bool {
+        use RequestLifecycle::*;
+        if *next == Denied {
+            return !self.is_terminal();
+        }
+        matches!(
+            (self, next),
+            (Created, InteractionStarted | TokenReceived)
+                | (InteractionStarted, CallbackReceived | Continued)
+                | (CallbackReceived, Continued)
+                | (Continued, Continued | TokenReceived)
+        )
+    }
+}
@@ -0,0 +1,53 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// State of the circuit breaker guarding one destination `host:port`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Calls flow normally.
+    Closed,
+    /// Calls fail fast until the cooldown elapses.
+    Open,
+    /// A single probe call is let through to decide whether to close again.
+    HalfOpen,
+}
+
+impl Display for CircuitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitState::Closed => write!(f, "closed"),
+            CircuitState::Open => write!(f, "open"),
+            CircuitState::HalfOpen => write!(f, "half-open"),
+        }
+    }
+}
+
+/// Diagnostic view of the circuit of one destination host.
+#[derive(Debug, Clone, Serialize)]
+pub struct CircuitStatus {
+    /// Destination as `host:port`, so services sharing a host trip separately.
+    pub host: String,
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    /// Seconds left before an open circuit lets a probe through.
+    pub retry_in_secs: Option<u64>,
+}
@@ -0,0 +1,51 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use uuid::Uuid;
+
+/// Header carrying the correlation id of a multi-hop flow, in and out.
+pub const CORRELATION_HEADER: &str = "x-correlation-id";
+
+/// Longest correlation id accepted from a peer; longer ones are replaced.
+const MAX_CORRELATION_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static CORRELATION_ID: String;
+}
+
+/// Runs `fut` with `id` sent as the correlation id of every outbound call it makes.
+///
+/// The id does not follow work handed to `tokio::spawn`.
+pub async fn with_correlation_id<F: Future>(id: impl Into<String>, fut: F) -> F::Output {
+    CORRELATION_ID.scope(id.into(), fut).await
+}
+
+/// Returns the correlation id of the current task, if one was set.
+pub fn current_correlation_id() -> Option<String> {
+    CORRELATION_ID.try_with(Clone::clone).ok()
+}
+
+/// Keeps the correlation id received from a peer when it is a short printable token, or
+/// starts a new one.
+pub fn accept_correlation_id(received: Option<&str>) -> String {
+    received
+        .map(str::trim)
+        .filter(|id| !id.is_empty() && id.len() <= MAX_CORRELATION_ID_LEN)
+        .filter(|id| id.bytes().all(|b| b.is_ascii_graphic()))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string())
+}
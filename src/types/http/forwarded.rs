@@ -0,0 +1,32 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+tokio::task_local! {
+    static FORWARDED_BASE: String;
+}
+
+/// Runs `fut` with `base` as the public base URL announced by a trusted proxy.
+///
+/// The base does not follow work handed to `tokio::spawn`.
+pub async fn with_forwarded_base<F: Future>(base: impl Into<String>, fut: F) -> F::Output {
+    FORWARDED_BASE.scope(base.into(), fut).await
+}
+
+/// Returns the public base URL of the current request, if a trusted proxy announced one.
+pub fn current_forwarded_base() -> Option<String> {
+    FORWARDED_BASE.try_with(Clone::clone).ok()
+}
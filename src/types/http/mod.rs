@@ -15,6 +15,16 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod circuit;
+mod correlation;
+mod forwarded;
+
+pub use circuit::{CircuitState, CircuitStatus};
+pub use correlation::{
+    CORRELATION_HEADER, accept_correlation_id, current_correlation_id, with_correlation_id,
+};
+pub use forwarded::{current_forwarded_base, with_forwarded_base};
+
 use std::collections::HashMap;
 
 use serde::Serialize;
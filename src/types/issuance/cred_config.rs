@@ -57,6 +57,11 @@ pub struct CredentialConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credential_metadata: Option<CredentialMetadata>,
 
+    /// DID signing this credential when it differs from the agent identity. Non-standard
+    /// extension; wallets resolve it to check the credential `iss`. OPTIONAL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer_did: Option<String>,
+
     /// Format + the format-specific fields it requires. Wire JSON: `format` plus
     /// the variant fields appear flattened at this struct's level.
     #[serde(flatten)]
@@ -97,6 +102,7 @@ impl CredentialConfiguration {
             credential_signing_alg_values_supported: Some(Alg::supported()),
             proof_types_supported: Some(proof_types),
             credential_metadata: None,
+            issuer_did: None,
             format_data: FormatSpecific::JwtVcJson {
                 credential_definition: CredentialDefinition {
                     r#type: vec!["VerifiableCredential".to_string(), vc_type.to_string()],
@@ -105,6 +111,20 @@ impl CredentialConfiguration {
             },
         }
     }
+
+    /// Build a `dc+sd-jwt` credential configuration with sensible defaults.
+    ///
+    /// Same defaults as [`Self::jwt_vc_json`]; `vct` is the credential type. Holder binding
+    /// is mandatory for SD-JWT VCs, so `cryptographic_binding_methods_supported` is always set.
+    pub fn sd_jwt_vc(vc_type: &VcType) -> Self {
+        let base = Self::jwt_vc_json(vc_type);
+        Self {
+            format_data: FormatSpecific::SdJwtVc {
+                vct: vc_type.to_string(),
+            },
+            ..base
+        }
+    }
 }
 
 // ════════════════════════════════════════════════════════════════════════════════
@@ -154,7 +174,7 @@ pub enum FormatSpecific {
         credential_definition: CredentialDefinition,
     },
 
-    #[serde(rename = "vc+sd-jwt")]
+    #[serde(rename = "dc+sd-jwt", alias = "vc+sd-jwt")]
     SdJwtVc { vct: String },
 
     #[serde(rename = "mso_mdoc")]
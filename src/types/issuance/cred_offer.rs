@@ -17,6 +17,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::errors::{BadFormat, Errors, Outcome};
 use crate::types::vcs::VcTypeConfig;
 
 // ════════════════════════════════════════════════════════════════════════════════
@@ -147,6 +148,34 @@ pub struct TxCodeConfig {
     pub description: Option<String>,
 }
 
+impl TxCodeConfig {
+    /// Checks `code` against the announced character set and length before it is sent.
+    pub fn check(&self, code: &str) -> Outcome<()> {
+        if self
+            .input_mode
+            .as_ref()
+            .is_none_or(|mode| *mode == TxCodeInputMode::Numeric)
+            && !code.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(Errors::format(
+                BadFormat::Sent,
+                "Transaction code must be numeric",
+                None,
+            ));
+        }
+        if let Some(length) = self.length {
+            if code.chars().count() != length as usize {
+                return Err(Errors::format(
+                    BadFormat::Sent,
+                    format!("Transaction code must be {length} characters long"),
+                    None,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Character set for a Transaction Code.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -19,6 +19,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::utils::create_opaque_token;
 
+/// Lifetime in seconds of every `c_nonce` handed out by the token endpoint.
+pub const DEFAULT_NONCE_EXPIRES_IN: u32 = 300;
+
 /// Token Response returned by the AS Token Endpoint (OIDC4VCI 1.0 §6.2).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssuingToken {
@@ -64,7 +67,7 @@ impl IssuingToken {
     /// `refresh_token`, `scope`, and `authorization_details` are left as
     /// `None`; set them via field access if needed.
     pub fn new(token: impl Into<String>, nonce: Option<String>, expires_in: u32) -> Self {
-        let c_nonce_expires_in = nonce.as_ref().map(|_| 3600);
+        let c_nonce_expires_in = nonce.as_ref().map(|_| DEFAULT_NONCE_EXPIRES_IN);
 
         Self {
             access_token: token.into(),
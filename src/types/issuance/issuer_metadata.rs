@@ -17,7 +17,8 @@
 
 use std::collections::HashMap;
 
-use super::{CredentialConfiguration, DisplayLogo};
+use super::{CredentialConfiguration, DisplayLogo, VcCredOffer};
+use crate::errors::{BadFormat, Errors, Outcome};
 use crate::types::vcs::{VcFormat, VcType, VcTypeConfig};
 use serde::{Deserialize, Serialize};
 
@@ -86,6 +87,7 @@ impl IssuerMetadata {
                 let config = VcTypeConfig::new(vc_type.clone(), format.clone());
                 let cred_config = match format {
                     VcFormat::JwtVcJson => CredentialConfiguration::jwt_vc_json(config.vc_type()),
+                    VcFormat::SdJwtVc => CredentialConfiguration::sd_jwt_vc(config.vc_type()),
                     _ => continue,
                 };
                 supported.insert(config, cred_config);
@@ -99,7 +101,7 @@ impl IssuerMetadata {
             authorization_servers: Some(vec![issuer.to_string()]),
             nonce_endpoint: None,
             deferred_credential_endpoint: None,
-            notification_endpoint: None,
+            notification_endpoint: Some(format!("{}{}/notification", issuer, api_path)),
             credential_response_encryption: None,
             credential_request_encryption: None,
             batch_credential_issuance: None,
@@ -107,6 +109,42 @@ impl IssuerMetadata {
             display: None,
         }
     }
+
+    /// Checks these metadata were published by the issuer of `offer` and describe every
+    /// credential configuration it offers (OIDC4VCI 1.0 §12.2.4).
+    pub fn check_offer(&self, offer: &VcCredOffer) -> Outcome<()> {
+        if self.credential_issuer.trim_end_matches('/')
+            != offer.credential_issuer.trim_end_matches('/')
+        {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!(
+                    "Issuer metadata belong to {} instead of {}",
+                    self.credential_issuer, offer.credential_issuer
+                ),
+                None,
+            ));
+        }
+        if offer.credential_configuration_ids.is_empty() {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Credential offer names no credential configuration",
+                None,
+            ));
+        }
+        if let Some(config) = offer.credential_configuration_ids.iter().find(|config| {
+            !self
+                .credential_configurations_supported
+                .contains_key(*config)
+        }) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Issuer does not support the offered configuration {config}"),
+                None,
+            ));
+        }
+        Ok(())
+    }
 }
 
 // ════════════════════════════════════════════════════════════════════════════════
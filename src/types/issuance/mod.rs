@@ -21,7 +21,9 @@ pub use cred_req::*;
 pub use did_possession::*;
 pub use iss_token::*;
 pub use issuer_metadata::*;
+pub use notification::*;
 pub use oauth_server::*;
+pub use status_list::*;
 pub use token_req::*;
 pub use vc_issuing::*;
 
@@ -32,6 +34,8 @@ mod cred_req;
 mod did_possession;
 mod iss_token;
 mod issuer_metadata;
+mod notification;
 mod oauth_server;
+mod status_list;
 mod token_req;
 mod vc_issuing;
@@ -0,0 +1,49 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Notification Request sent by the wallet to the Notification Endpoint (OIDC4VCI 1.0 §11.1).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationRequest {
+    /// Identifier received alongside the issued credential. REQUIRED.
+    pub notification_id: String,
+
+    /// Event the wallet is reporting. REQUIRED.
+    pub event: NotificationEvent,
+
+    /// Human-readable additional information about the event. OPTIONAL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_description: Option<String>,
+}
+
+/// Credential lifecycle events a wallet can report back to the issuer.
+#[derive(Clone, Debug, Eq, PartialEq, DeriveActiveEnum, EnumIter, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationEvent {
+    /// The credential was successfully stored in the wallet.
+    #[sea_orm(string_value = "credential_accepted")]
+    CredentialAccepted,
+    /// The wallet could not store the credential.
+    #[sea_orm(string_value = "credential_failure")]
+    CredentialFailure,
+    /// The holder deleted the credential, or rejected it before storage.
+    #[sea_orm(string_value = "credential_deleted")]
+    CredentialDeleted,
+}
@@ -0,0 +1,149 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{self, Display, Formatter};
+use std::io::{Read, Write};
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use sea_orm::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::utils::{decode_url_safe_no_pad, encode_url_safe_no_pad};
+
+/// Minimum list length recommended by Bitstring Status List v1.0 for herd privacy (16KB).
+pub const DEFAULT_STATUS_LIST_SIZE: i32 = 131_072;
+
+/// Largest decompressed status list accepted from a third party (16MB, ~134M entries).
+pub const MAX_STATUS_LIST_BYTES: usize = 16 * 1024 * 1024;
+
+/// Purpose a status list (and every entry pointing to it) is used for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, DeriveActiveEnum, EnumIter, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+#[serde(rename_all = "snake_case")]
+pub enum StatusPurpose {
+    /// Permanent cancellation; a set bit can never be cleared.
+    #[sea_orm(string_value = "revocation")]
+    Revocation,
+    /// Temporary hold; a set bit may be cleared to reinstate the credential.
+    #[sea_orm(string_value = "suspension")]
+    Suspension,
+}
+
+impl Display for StatusPurpose {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            StatusPurpose::Revocation => "revocation",
+            StatusPurpose::Suspension => "suspension",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Raw bitstring backing a status list. Index 0 is the most significant bit of the first byte.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bitstring(Vec<u8>);
+
+impl Bitstring {
+    /// Creates an all-zero bitstring able to hold `size` entries.
+    pub fn new(size: i32) -> Self {
+        let bytes = (size.max(0) as usize).div_ceil(8);
+        Self(vec![0; bytes])
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len() * 8
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, index: i32) -> Outcome<bool> {
+        let (byte, mask) = self.locate(index)?;
+        Ok(self.0[byte] & mask != 0)
+    }
+
+    pub fn set(&mut self, index: i32, value: bool) -> Outcome<()> {
+        let (byte, mask) = self.locate(index)?;
+        match value {
+            true => self.0[byte] |= mask,
+            false => self.0[byte] &= !mask,
+        }
+        Ok(())
+    }
+
+    /// GZIP-compresses the bitstring and encodes it as a multibase base64url `encodedList`.
+    pub fn encode(&self) -> Outcome<String> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&self.0)
+            .map_err(|e| Errors::parse("Unable to compress status list", Some(Box::new(e))))?;
+        let compressed = encoder
+            .finish()
+            .map_err(|e| Errors::parse("Unable to compress status list", Some(Box::new(e))))?;
+        Ok(format!("u{}", encode_url_safe_no_pad(compressed)))
+    }
+
+    /// Decodes an `encodedList` value as found in a `BitstringStatusList` credential.
+    ///
+    /// The multibase `u` prefix is optional so `StatusList2021` lists decode as well; a bare
+    /// GZIP stream always starts with `H4sI`, so the prefix cannot be confused with data.
+    ///
+    /// Decompression stops past `max_len` bytes so a small payload cannot inflate without bound.
+    pub fn decode(encoded: &str, max_len: usize) -> Outcome<Self> {
+        let b64 = encoded.strip_prefix('u').unwrap_or(encoded);
+        let compressed = decode_url_safe_no_pad(b64)?;
+        let mut bytes = Vec::new();
+        GzDecoder::new(compressed.as_slice())
+            .take(max_len as u64 + 1)
+            .read_to_end(&mut bytes)
+            .map_err(|e| Errors::parse("Unable to decompress status list", Some(Box::new(e))))?;
+        if bytes.len() > max_len {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Status list exceeds {max_len} bytes"),
+                None,
+            ));
+        }
+        Ok(Self(bytes))
+    }
+
+    fn locate(&self, index: i32) -> Outcome<(usize, u8)> {
+        let idx = usize::try_from(index)
+            .ok()
+            .filter(|i| *i < self.len())
+            .ok_or_else(|| {
+                Errors::format(
+                    BadFormat::Unknown,
+                    format!("Status list index {index} out of bounds"),
+                    None,
+                )
+            })?;
+        Ok((idx / 8, 0x80 >> (idx % 8)))
+    }
+}
@@ -57,6 +57,12 @@ impl GiveVC {
         }
     }
 
+    /// Attaches the identifier the wallet must echo back at the Notification Endpoint.
+    pub fn with_notification(mut self, notification_id: impl Into<String>) -> Self {
+        self.notification_id = Some(notification_id.into());
+        self
+    }
+
     /// Build a deferred response containing only a transaction id.
     pub fn deferred(transaction_id: impl Into<String>) -> Self {
         Self {
@@ -19,12 +19,14 @@ mod vp;
 
 mod header;
 mod jwt;
+mod sd_jwt;
 mod vc_claims;
 mod vc_claims_builder;
 mod w3c_vc;
 
 pub use header::*;
 pub use jwt::*;
+pub use sd_jwt::*;
 pub use vc_claims::*;
 pub use vc_claims_builder::*;
 pub use vp::VPJwtClaims;
@@ -0,0 +1,340 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::Jwt;
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::utils::{create_opaque_token, decode_url_safe_no_pad, encode_url_safe_no_pad};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use sha2::{Digest, Sha256};
+
+/// Hash algorithm used for disclosure digests (`_sd_alg`).
+pub const SD_ALG: &str = "sha-256";
+/// Media type of an issued SD-JWT VC.
+pub const SD_JWT_VC_TYP: &str = "dc+sd-jwt";
+/// Media type of a Key Binding JWT.
+pub const KB_JWT_TYP: &str = "kb+jwt";
+
+// ===== DISCLOSURES ===============================================================================
+
+/// A single object-property disclosure: `[salt, claim_name, claim_value]`.
+#[derive(Debug, Clone)]
+pub struct Disclosure {
+    name: String,
+    value: Value,
+    encoded: String,
+}
+
+impl Disclosure {
+    /// Creates a disclosure for `name`/`value` with a fresh random salt.
+    pub fn new(name: &str, value: Value) -> Outcome<Self> {
+        let salt = create_opaque_token();
+        let array = json!([salt, name, value]);
+        let encoded = encode_url_safe_no_pad(serde_json::to_vec(&array)?);
+        Ok(Self {
+            name: name.to_string(),
+            value,
+            encoded,
+        })
+    }
+
+    /// Decodes a base64url disclosure as received in an SD-JWT presentation.
+    pub fn parse(encoded: &str) -> Outcome<Self> {
+        let bytes = decode_url_safe_no_pad(encoded)?;
+        let array: Vec<Value> = serde_json::from_slice(&bytes)?;
+        let [_, name, value]: [Value; 3] = array.try_into().map_err(|_| {
+            Errors::format(
+                BadFormat::Received,
+                "Disclosure must be [salt, name, value]",
+                None,
+            )
+        })?;
+        let name = name.as_str().ok_or_else(|| {
+            Errors::format(
+                BadFormat::Received,
+                "Disclosure claim name must be a string",
+                None,
+            )
+        })?;
+        if name == "_sd" || name == "..." {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Disclosure uses reserved claim name '{name}'"),
+                None,
+            ));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            value,
+            encoded: encoded.to_string(),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+    pub fn as_str(&self) -> &str {
+        &self.encoded
+    }
+
+    /// Base64url SHA-256 digest of the encoded disclosure, as placed in `_sd`.
+    pub fn digest(&self) -> String {
+        sd_digest(&self.encoded)
+    }
+}
+
+/// Base64url SHA-256 digest over an ASCII input, as used by `_sd` and `sd_hash`.
+pub fn sd_digest(input: &str) -> String {
+    encode_url_safe_no_pad(Sha256::digest(input.as_bytes()))
+}
+
+/// Replaces every property of `object` except those in `keep` with a disclosure digest.
+///
+/// Digests are written to a sorted `_sd` array so their order does not leak the original
+/// claim order. Returns the disclosures that must travel alongside the issuer-signed JWT.
+pub fn conceal(object: &mut Map<String, Value>, keep: &[&str]) -> Outcome<Vec<Disclosure>> {
+    let names: Vec<String> = object
+        .keys()
+        .filter(|k| !keep.contains(&k.as_str()) && k.as_str() != "_sd")
+        .cloned()
+        .collect();
+
+    let mut disclosures = Vec::with_capacity(names.len());
+    for name in names {
+        if let Some(value) = object.remove(&name) {
+            disclosures.push(Disclosure::new(&name, value)?);
+        }
+    }
+
+    let mut digests: Vec<String> = disclosures.iter().map(Disclosure::digest).collect();
+    digests.sort();
+    if !digests.is_empty() {
+        object.insert("_sd".to_string(), json!(digests));
+    }
+    Ok(disclosures)
+}
+
+// ===== SD-JWT ENVELOPE ===========================================================================
+
+/// Claims of an SD-JWT VC issuer-signed JWT (`dc+sd-jwt`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SdJwtVcClaims {
+    pub iss: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    pub iat: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    pub vct: String,
+    /// Holder key binding, `{"kid": "<did url>"}` or `{"jwk": {...}}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cnf: Option<Value>,
+    #[serde(rename = "_sd_alg")]
+    pub sd_alg: String,
+    #[serde(flatten)]
+    pub claims: Map<String, Value>,
+}
+
+impl SdJwtVcClaims {
+    /// Builds the payload for `vct` bound to the holder key `holder_kid` (a DID URL).
+    ///
+    /// `subject` holds the plain claims; they are only turned into disclosures when the
+    /// issuer signs the credential.
+    pub fn new(
+        iss: &str,
+        vct: &str,
+        holder_kid: &str,
+        subject: Map<String, Value>,
+        iat: i64,
+    ) -> Self {
+        let sub = holder_kid.split('#').next().map(str::to_string);
+        Self {
+            iss: iss.to_string(),
+            sub,
+            iat,
+            nbf: None,
+            exp: None,
+            vct: vct.to_string(),
+            cnf: Some(json!({ "kid": holder_kid })),
+            sd_alg: SD_ALG.to_string(),
+            claims: subject,
+        }
+    }
+}
+
+/// Claims of a Key Binding JWT appended by the holder on presentation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KbJwtClaims {
+    pub iat: i64,
+    pub aud: String,
+    pub nonce: String,
+    pub sd_hash: String,
+}
+
+/// A parsed SD-JWT in compact serialization: `<jwt>~<disclosure>~...~[<kb-jwt>]`.
+#[derive(Debug)]
+pub struct SdJwt {
+    jwt: Jwt,
+    disclosures: Vec<Disclosure>,
+    kb_jwt: Option<Jwt>,
+}
+
+impl SdJwt {
+    pub fn new(jwt: Jwt, disclosures: Vec<Disclosure>) -> Self {
+        Self {
+            jwt,
+            disclosures,
+            kb_jwt: None,
+        }
+    }
+
+    pub fn parse(raw: &str) -> Outcome<Self> {
+        let mut parts: Vec<&str> = raw.split('~').collect();
+        if parts.len() < 2 {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "SD-JWT has wrong format",
+                None,
+            ));
+        }
+        let last = parts.pop().unwrap_or_default();
+        let kb_jwt = match last.is_empty() {
+            true => None,
+            false => Some(Jwt::parse(last)?),
+        };
+        let jwt = Jwt::parse(parts[0])?;
+        let disclosures = parts[1..]
+            .iter()
+            .map(|d| Disclosure::parse(d))
+            .collect::<Outcome<Vec<_>>>()?;
+
+        Ok(Self {
+            jwt,
+            disclosures,
+            kb_jwt,
+        })
+    }
+
+    pub fn jwt(&self) -> &Jwt {
+        &self.jwt
+    }
+    pub fn disclosures(&self) -> &[Disclosure] {
+        &self.disclosures
+    }
+    pub fn kb_jwt(&self) -> Option<&Jwt> {
+        self.kb_jwt.as_ref()
+    }
+
+    /// Compact serialization without the Key Binding JWT, ending in `~`.
+    pub fn issuance_string(&self) -> String {
+        let mut out = format!("{}~", self.jwt.as_str());
+        for disclosure in &self.disclosures {
+            out.push_str(disclosure.as_str());
+            out.push('~');
+        }
+        out
+    }
+
+    /// The `sd_hash` a Key Binding JWT must carry for this presentation.
+    pub fn sd_hash(&self) -> String {
+        sd_digest(&self.issuance_string())
+    }
+
+    /// Rebuilds the issuer payload with every presented disclosure resolved into place.
+    ///
+    /// # Errors
+    /// Fails if `_sd_alg` is unsupported, a disclosure is not referenced by an `_sd` digest, or
+    /// a digest is referenced twice.
+    pub fn disclosed_claims(&self) -> Outcome<Value> {
+        let mut payload = self.jwt.unverified_payload().clone();
+        let alg = payload
+            .get("_sd_alg")
+            .and_then(Value::as_str)
+            .unwrap_or(SD_ALG);
+        if alg != SD_ALG {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Unsupported _sd_alg '{alg}'"),
+                None,
+            ));
+        }
+
+        let mut pending: Vec<(String, &Disclosure)> =
+            self.disclosures.iter().map(|d| (d.digest(), d)).collect();
+        resolve(&mut payload, &mut pending)?;
+        if !pending.is_empty() {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Disclosure is not referenced by the SD-JWT",
+                None,
+            ));
+        }
+
+        if let Some(obj) = payload.as_object_mut() {
+            obj.remove("_sd_alg");
+        }
+        Ok(payload)
+    }
+}
+
+impl std::fmt::Display for SdJwt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.issuance_string())?;
+        if let Some(kb) = &self.kb_jwt {
+            f.write_str(kb.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+fn resolve(value: &mut Value, pending: &mut Vec<(String, &Disclosure)>) -> Outcome<()> {
+    match value {
+        Value::Object(obj) => {
+            if let Some(Value::Array(digests)) = obj.remove("_sd") {
+                for digest in digests.iter().filter_map(Value::as_str) {
+                    let Some(pos) = pending.iter().position(|(d, _)| d == digest) else {
+                        continue;
+                    };
+                    let (_, disclosure) = pending.swap_remove(pos);
+                    if obj.contains_key(disclosure.name()) {
+                        return Err(Errors::format(
+                            BadFormat::Received,
+                            format!("Disclosed claim '{}' already present", disclosure.name()),
+                            None,
+                        ));
+                    }
+                    obj.insert(disclosure.name().to_string(), disclosure.value().clone());
+                }
+            }
+            for child in obj.values_mut() {
+                resolve(child, pending)?;
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                resolve(item, pending)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
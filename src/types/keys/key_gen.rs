@@ -0,0 +1,48 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// Key pair generated for a node bootstrapping without pre-provisioned keys.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kty", rename_all = "snake_case")]
+pub enum KeyGenSpec {
+    /// RSA key with a `bits` long modulus, signing with RS256.
+    Rsa {
+        #[serde(default = "default_rsa_bits")]
+        bits: usize,
+    },
+    /// Ed25519 key, signing with EdDSA.
+    Ed25519,
+    /// P-256 key, signing with ES256.
+    P256,
+}
+
+impl Default for KeyGenSpec {
+    fn default() -> Self {
+        KeyGenSpec::Rsa {
+            bits: default_rsa_bits(),
+        }
+    }
+}
+
+/// Shortest RSA modulus accepted for generated keys.
+pub const MIN_RSA_BITS: usize = 2048;
+
+fn default_rsa_bits() -> usize {
+    MIN_RSA_BITS
+}
@@ -15,7 +15,9 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 use super::{Alg, Certificate, PublicKey};
+use crate::capabilities::HttpSig;
 use crate::errors::Outcome;
+use axum::http::HeaderMap;
 use sea_orm::FromJsonQueryResult;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -51,3 +53,26 @@ pub enum DbKeySource {
     Cert(String),
     PublicKey(Value),
 }
+
+impl DbKeySource {
+    /// Parses the stored certificate PEM or public JWK.
+    pub fn key_source(&self) -> Outcome<KeySource> {
+        match self {
+            DbKeySource::Cert(pem) => Ok(KeySource::Cert(Certificate::try_from_pem(pem)?)),
+            DbKeySource::PublicKey(jwk) => {
+                Ok(KeySource::PublicKey(PublicKey::parse_from_jwk(jwk)?))
+            }
+        }
+    }
+
+    /// Checks that a request to `url` carries an `httpsig` proof made with this key.
+    pub fn verify_request(
+        &self,
+        headers: &HeaderMap,
+        method: &str,
+        url: &str,
+        body: &[u8],
+    ) -> Outcome<()> {
+        HttpSig::verify(headers, &self.key_source()?, method, url, body)
+    }
+}
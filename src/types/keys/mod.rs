@@ -19,6 +19,7 @@ mod alg;
 mod certificate;
 mod crv;
 mod crypto_suite;
+mod key_gen;
 mod key_source;
 mod kty;
 mod private_key;
@@ -29,6 +30,7 @@ pub use alg::Alg;
 pub use certificate::Certificate;
 pub use crv::Crv;
 pub use crypto_suite::Cryptosuite;
+pub use key_gen::{KeyGenSpec, MIN_RSA_BITS};
 pub use key_source::{DbKeySource, KeySource};
 pub use kty::Kty;
 pub use private_key::PrivateKey;
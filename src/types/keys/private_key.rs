@@ -15,13 +15,15 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use super::{Alg, Crv, Cryptosuite, Kty, PublicKey};
+use super::{Alg, Crv, Cryptosuite, KeyGenSpec, Kty, MIN_RSA_BITS, PublicKey};
 use crate::errors::{BadFormat, Errors, Outcome};
 use crate::types::secrets::PemHelper;
 use ed25519_dalek::SigningKey as Ed25519SigningKey;
+use p256::ecdsa::{Signature as P256Signature, SigningKey as P256SigningKey};
+use rand::rngs::OsRng;
 use rsa::RsaPrivateKey;
 use rsa::pkcs1v15::SigningKey as PkcsSigningKey;
-use rsa::pkcs8::DecodePrivateKey;
+use rsa::pkcs8::{DecodePrivateKey, EncodePrivateKey, LineEnding};
 use rsa::pss::SigningKey as PssSigningKey;
 use rsa::signature::Signer;
 use rsa::signature::{RandomizedSigner, SignatureEncoding};
@@ -31,9 +33,43 @@ use sha2::{Sha256, Sha384, Sha512};
 pub enum PrivateKey {
     Rsa { sk: RsaPrivateKey },
     Ed25519 { sk: Ed25519SigningKey },
+    P256 { sk: P256SigningKey },
 }
 
 impl PrivateKey {
+    /// Generates a fresh key pair as `spec` says.
+    pub fn generate(spec: &KeyGenSpec) -> Outcome<Self> {
+        match spec {
+            KeyGenSpec::Rsa { bits } if *bits < MIN_RSA_BITS => Err(Errors::format(
+                BadFormat::Received,
+                format!("RSA keys need at least {MIN_RSA_BITS} bits, got {bits}"),
+                None,
+            )),
+            KeyGenSpec::Rsa { bits } => {
+                let sk = RsaPrivateKey::new(&mut OsRng, *bits)
+                    .map_err(|e| Errors::crazy("Unable to generate RSA key", Some(Box::new(e))))?;
+                Ok(Self::Rsa { sk })
+            }
+            KeyGenSpec::Ed25519 => Ok(Self::Ed25519 {
+                sk: Ed25519SigningKey::generate(&mut OsRng),
+            }),
+            KeyGenSpec::P256 => Ok(Self::P256 {
+                sk: P256SigningKey::random(&mut OsRng),
+            }),
+        }
+    }
+
+    /// Encodes the key as a PKCS#8 PEM, the format it is stored in the vault as.
+    pub fn to_pkcs8_pem(&self) -> Outcome<String> {
+        let pem = match self {
+            Self::Rsa { sk } => sk.to_pkcs8_pem(LineEnding::LF),
+            Self::Ed25519 { sk } => sk.to_pkcs8_pem(LineEnding::LF),
+            Self::P256 { sk } => sk.to_pkcs8_pem(LineEnding::LF),
+        }
+        .map_err(|e| Errors::crazy("Unable to encode private key", Some(Box::new(e))))?;
+        Ok(pem.to_string())
+    }
+
     pub fn try_from_pkcs8_pem(pem: &str) -> Outcome<Self> {
         if let Ok(sk) = parse_rsa(pem) {
             return Ok(Self::Rsa { sk });
@@ -43,9 +79,13 @@ impl PrivateKey {
             return Ok(Self::Ed25519 { sk });
         }
 
+        if let Ok(sk) = parse_p256(pem) {
+            return Ok(Self::P256 { sk });
+        }
+
         Err(Errors::format(
             BadFormat::Received,
-            "PEM is not a supported Ed25519/RSA PKCS#8",
+            "PEM is not a supported Ed25519/P-256/RSA PKCS#8",
             None,
         ))
     }
@@ -58,6 +98,9 @@ impl PrivateKey {
             (Kty::Okp, Some(Crv::Ed25519)) => Ok(PrivateKey::Ed25519 {
                 sk: parse_ed25519(pem)?,
             }),
+            (Kty::Ec, Some(Crv::P256)) => Ok(PrivateKey::P256 {
+                sk: parse_p256(pem)?,
+            }),
             _ => Err(Errors::not_impl(
                 format!("Unsupported key/alg combination: kty={kty}, crv={crv:?}"),
                 None,
@@ -68,6 +111,7 @@ impl PrivateKey {
         match self {
             Self::Rsa { .. } => Kty::Rsa,
             Self::Ed25519 { .. } => Kty::Okp,
+            Self::P256 { .. } => Kty::Ec,
         }
     }
 
@@ -75,18 +119,21 @@ impl PrivateKey {
         match self {
             Self::Rsa { .. } => None,
             Self::Ed25519 { .. } => Some(Crv::Ed25519),
+            Self::P256 { .. } => Some(Crv::P256),
         }
     }
     pub fn alg(&self) -> Alg {
         match self {
             PrivateKey::Rsa { .. } => Alg::Rs256,
             PrivateKey::Ed25519 { .. } => Alg::EdDsa,
+            PrivateKey::P256 { .. } => Alg::Es256,
         }
     }
 
     pub fn cryptosuite(&self) -> Outcome<Cryptosuite> {
         match self {
             Self::Ed25519 { .. } => Ok(Cryptosuite::EddsaJcs2022),
+            Self::P256 { .. } => Ok(Cryptosuite::EcdsaJcs2019),
             Self::Rsa { .. } => Err(Errors::not_impl(
                 "RSA does not have an active cryptosuite",
                 None,
@@ -102,6 +149,9 @@ impl PrivateKey {
             Self::Ed25519 { sk: pk } => PublicKey::Ed25519 {
                 vk: pk.verifying_key(),
             },
+            Self::P256 { sk } => PublicKey::P256 {
+                vk: *sk.verifying_key(),
+            },
         }
     }
 
@@ -127,6 +177,16 @@ impl PrivateKey {
                 let sig = sk.sign(data);
                 Ok(sig.to_bytes().to_vec())
             }
+            PrivateKey::P256 { sk } => match alg {
+                Alg::Es256 => {
+                    let sig: P256Signature = sk.sign(data);
+                    Ok(sig.to_bytes().to_vec())
+                }
+                other => Err(Errors::not_impl(
+                    format!("Unsupported alg  {}", other),
+                    None,
+                )),
+            },
         }
     }
 }
@@ -167,3 +227,8 @@ fn parse_ed25519(pem: &str) -> Outcome<Ed25519SigningKey> {
     Ed25519SigningKey::from_pkcs8_pem(pem)
         .map_err(|e| Errors::parse("Invalid Ed25519 PKCS#8 PEM", Some(Box::new(e))))
 }
+
+fn parse_p256(pem: &str) -> Outcome<P256SigningKey> {
+    P256SigningKey::from_pkcs8_pem(pem)
+        .map_err(|e| Errors::parse("Invalid P-256 PKCS#8 PEM", Some(Box::new(e))))
+}
@@ -21,8 +21,10 @@ use crate::types::keys::{Alg, Crv, Kty};
 use crate::types::secrets::PemHelper;
 use crate::utils::{decode_url_safe_no_pad, encode_url_safe_no_pad};
 use ed25519_dalek::{Signature as Ed25519Signature, VerifyingKey as Ed25519VerifyingKey};
+use p256::EncodedPoint;
+use p256::ecdsa::{Signature as P256Signature, VerifyingKey as P256VerifyingKey};
 use rsa::pkcs1v15::{Signature as PkcsSignature, VerifyingKey as PkcsVerifyingKey};
-use rsa::pkcs8::DecodePublicKey;
+use rsa::pkcs8::{DecodePublicKey, EncodePublicKey, LineEnding};
 use rsa::pss::{Signature as PssSignature, VerifyingKey as PssVerifyingKey};
 use rsa::signature::Verifier;
 use rsa::traits::PublicKeyParts;
@@ -36,6 +38,7 @@ use x509_parser::prelude::*;
 pub enum PublicKey {
     Rsa { vk: RsaPublicKey },
     Ed25519 { vk: Ed25519VerifyingKey },
+    P256 { vk: P256VerifyingKey },
 }
 
 impl PublicKey {
@@ -46,10 +49,13 @@ impl PublicKey {
         if let Ok(vk) = parse_ed25519_pem(pem) {
             return Ok(PublicKey::Ed25519 { vk });
         }
+        if let Ok(vk) = P256VerifyingKey::from_public_key_pem(pem) {
+            return Ok(PublicKey::P256 { vk });
+        }
 
         Err(Errors::format(
             BadFormat::Received,
-            "PEM is not a supported Ed25519/RSA PKCS#8",
+            "PEM is not a supported Ed25519/P-256/RSA PKCS#8",
             None,
         ))
     }
@@ -60,10 +66,13 @@ impl PublicKey {
         if let Ok(vk) = parse_ed25519_der(der) {
             return Ok(PublicKey::Ed25519 { vk });
         }
+        if let Ok(vk) = P256VerifyingKey::from_public_key_der(der) {
+            return Ok(PublicKey::P256 { vk });
+        }
 
         Err(Errors::format(
             BadFormat::Received,
-            "PEM is not a supported Ed25519/RSA PKCS#8",
+            "PEM is not a supported Ed25519/P-256/RSA PKCS#8",
             None,
         ))
     }
@@ -76,6 +85,10 @@ impl PublicKey {
             (Kty::Okp, Some(Crv::Ed25519)) => Ok(PublicKey::Ed25519 {
                 vk: parse_ed25519_pem(pem)?,
             }),
+            (Kty::Ec, Some(Crv::P256)) => Ok(PublicKey::P256 {
+                vk: P256VerifyingKey::from_public_key_pem(pem)
+                    .map_err(|e| Errors::parse("Invalid P-256 PKCS#8 PEM", Some(Box::new(e))))?,
+            }),
             _ => Err(Errors::not_impl(
                 format!("Unsupported key/alg combination: kty={kty}, crv={crv:?}"),
                 None,
@@ -130,6 +143,11 @@ impl PublicKey {
                 Ok(PublicKey::Ed25519 { vk })
             }
 
+            (Kty::Ec, Some(Crv::P256)) => {
+                let vk = p256_public_key_from_jwk(jwk)?;
+                Ok(PublicKey::P256 { vk })
+            }
+
             _ => Err(Errors::not_impl(
                 format!("Unsupported key/alg combination: kty={kty}, crv={crv:?}"),
                 None,
@@ -162,12 +180,27 @@ impl PublicKey {
                 pk.verify(data, &signature)
                     .map_err(|e| Errors::forbidden("Invalid Signature", Some(Box::new(e))))
             }
+            PublicKey::P256 { vk } => {
+                if *alg != Alg::Es256 {
+                    return Err(Errors::not_impl(format!("Unsupported alg  {}", alg), None));
+                }
+                let signature = P256Signature::from_slice(sig).map_err(|e| {
+                    Errors::format(
+                        BadFormat::Received,
+                        "invalid ES256 signature encoding",
+                        Some(Box::new(e)),
+                    )
+                })?;
+                vk.verify(data, &signature)
+                    .map_err(|e| Errors::forbidden("Invalid Signature", Some(Box::new(e))))
+            }
         }
     }
     pub fn kty(&self) -> Kty {
         match self {
             Self::Rsa { .. } => Kty::Rsa,
             Self::Ed25519 { .. } => Kty::Okp,
+            Self::P256 { .. } => Kty::Ec,
         }
     }
 
@@ -175,6 +208,7 @@ impl PublicKey {
         match self {
             Self::Rsa { .. } => None,
             Self::Ed25519 { .. } => Some(Crv::Ed25519),
+            Self::P256 { .. } => Some(Crv::P256),
         }
     }
     pub fn jwk_thumbprint(&self) -> String {
@@ -187,6 +221,16 @@ impl PublicKey {
         encode_url_safe_no_pad(hash)
     }
 
+    /// Encodes the key as a SubjectPublicKeyInfo PEM.
+    pub fn to_pem(&self) -> Outcome<String> {
+        match self {
+            PublicKey::Rsa { vk } => vk.to_public_key_pem(LineEnding::LF),
+            PublicKey::Ed25519 { vk } => vk.to_public_key_pem(LineEnding::LF),
+            PublicKey::P256 { vk } => vk.to_public_key_pem(LineEnding::LF),
+        }
+        .map_err(|e| Errors::crazy("Unable to encode public key", Some(Box::new(e))))
+    }
+
     pub fn public_jwk(&self) -> Value {
         match self {
             PublicKey::Rsa { vk } => {
@@ -203,6 +247,15 @@ impl PublicKey {
                     "x": encode_url_safe_no_pad(vk.to_bytes()),
                 })
             }
+            PublicKey::P256 { vk } => {
+                let point = vk.to_encoded_point(false);
+                json!({
+                    "kty": "EC",
+                    "crv": "P-256",
+                    "x": encode_url_safe_no_pad(point.x().map(|x| x.as_slice()).unwrap_or_default()),
+                    "y": encode_url_safe_no_pad(point.y().map(|y| y.as_slice()).unwrap_or_default()),
+                })
+            }
         }
     }
 }
@@ -320,3 +373,38 @@ pub fn ed25519_public_key_from_jwk(jwk: &Value) -> Outcome<Ed25519VerifyingKey>
         )
     })
 }
+
+pub fn p256_public_key_from_jwk(jwk: &Value) -> Outcome<P256VerifyingKey> {
+    let coordinate = |name: &str| -> Outcome<Vec<u8>> {
+        let b64 = jwk.get(name).and_then(|v| v.as_str()).ok_or_else(|| {
+            Errors::format(
+                BadFormat::Received,
+                format!("EC JWK missing '{name}'"),
+                None,
+            )
+        })?;
+        let bytes = decode_url_safe_no_pad(b64)?;
+        if bytes.len() != 32 {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!(
+                    "P-256 coordinate '{name}' must be 32 bytes, got {}",
+                    bytes.len()
+                ),
+                None,
+            ));
+        }
+        Ok(bytes)
+    };
+    let (x, y) = (coordinate("x")?, coordinate("y")?);
+
+    let point =
+        EncodedPoint::from_affine_coordinates(x.as_slice().into(), y.as_slice().into(), false);
+    P256VerifyingKey::from_encoded_point(&point).map_err(|err| {
+        Errors::format(
+            BadFormat::Received,
+            "Invalid P-256 public key coordinates",
+            Some(Box::new(err)),
+        )
+    })
+}
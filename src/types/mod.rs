@@ -15,15 +15,25 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+pub mod archive;
+pub mod audit;
+pub mod cleanup;
+pub mod config_snapshot;
 pub mod crypto;
 pub mod dids;
+pub mod extensions;
 pub mod gnap;
 pub mod http;
 pub mod issuance;
 pub mod jwt;
 pub mod keys;
+pub mod logging;
+pub mod pagination;
 pub mod participants;
+pub mod quota;
+pub mod revocation;
 pub mod secrets;
+pub mod standby;
 pub mod vcs;
 pub mod verification;
 pub mod vps;
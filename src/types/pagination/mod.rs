@@ -0,0 +1,121 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm::Order;
+use serde::{Deserialize, Serialize};
+
+/// Page size used when a request does not ask for one.
+pub const DEFAULT_PAGE_SIZE: u64 = 100;
+
+/// Largest page a single request may ask for.
+pub const MAX_PAGE_SIZE: u64 = 1_000;
+
+/// Direction rows are sorted in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl From<SortOrder> for Order {
+    fn from(order: SortOrder) -> Self {
+        match order {
+            SortOrder::Asc => Order::Asc,
+            SortOrder::Desc => Order::Desc,
+        }
+    }
+}
+
+/// Window and ordering of a list query, deserializable from `?limit=&offset=&sort=&order=`.
+///
+/// Rows are ordered by primary key unless `sort` names a column; the primary key then
+/// breaks ties so that consecutive pages never overlap.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PageRequest {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    /// Snake case name of the column to sort by.
+    pub sort: Option<String>,
+    #[serde(default)]
+    pub order: SortOrder,
+}
+
+impl PageRequest {
+    pub fn new(limit: u64, offset: u64) -> Self {
+        Self {
+            limit: Some(limit),
+            offset: Some(offset),
+            ..Self::default()
+        }
+    }
+
+    /// Sorts the page by `column` in `order`.
+    pub fn sorted_by(mut self, column: impl Into<String>, order: SortOrder) -> Self {
+        self.sort = Some(column.into());
+        self.order = order;
+        self
+    }
+
+    /// Effective page size, capped by [`MAX_PAGE_SIZE`].
+    pub fn limit(&self) -> u64 {
+        self.limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE)
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.offset.unwrap_or(0)
+    }
+}
+
+/// One page of a list query together with the size of the whole result.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Number of rows matching the query across every page.
+    pub total: u64,
+    pub limit: u64,
+    pub offset: u64,
+    pub has_more: bool,
+}
+
+impl<T> Page<T> {
+    /// Wraps the rows fetched for `request` out of `total` matching ones.
+    pub fn new(items: Vec<T>, total: u64, request: &PageRequest) -> Self {
+        let offset = request.offset();
+        Self {
+            has_more: offset + (items.len() as u64) < total,
+            items,
+            total,
+            limit: request.limit(),
+            offset,
+        }
+    }
+
+    /// Converts every item, keeping the page info.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            limit: self.limit,
+            offset: self.offset,
+            has_more: self.has_more,
+        }
+    }
+}
@@ -0,0 +1,133 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::types::jwt::{Jwt, VCJwtClaims};
+use crate::types::vcs::VcType;
+use crate::types::vcs::doc::VcDocument;
+use crate::types::vcs::vc_specs::legal_person::LegalPersonCredentialSubject;
+use crate::types::vcs::vc_specs::legal_reg_number::{
+    Eori, Euid, LeiCode, LocalRegistrationNumber, TaxId, VatId,
+};
+
+/// Business attributes of a participant extracted from its verified credentials.
+///
+/// Every field is optional: a `gx:LegalPerson` carries a name and a country, while a
+/// registration number credential only carries the number (and sometimes its country).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BusinessAttributes {
+    pub legal_name: Option<String>,
+    pub country_code: Option<String>,
+    pub registration_number: Option<String>,
+    pub registration_number_type: Option<String>,
+}
+
+impl BusinessAttributes {
+    /// Extracts the attributes carried by a single credential, if its type has any.
+    pub fn from_vc(doc: &VcDocument) -> Option<Self> {
+        let vc_type = doc.specialized_type()?;
+        let subject = doc.credential_subject.clone();
+
+        let attrs = match &vc_type {
+            VcType::LegalPerson => {
+                let s: LegalPersonCredentialSubject = parse(&vc_type, subject)?;
+                Self {
+                    legal_name: Some(s.schema_name),
+                    country_code: Some(s.gx_legal_address.country_code),
+                    registration_number: Some(
+                        s.gx_registration_number.gx_registration_number_value,
+                    ),
+                    registration_number_type: Some(
+                        s.gx_registration_number.gx_registration_number_type,
+                    ),
+                }
+            }
+            VcType::VatId => {
+                let s: VatId = parse(&vc_type, subject)?;
+                Self::registration(&vc_type, s.vat_id, s.country_code)
+            }
+            VcType::LeiCode => {
+                let s: LeiCode = parse(&vc_type, subject)?;
+                Self::registration(&vc_type, s.lei_code, Some(s.country_code))
+            }
+            VcType::Eori => {
+                let s: Eori = parse(&vc_type, subject)?;
+                Self::registration(&vc_type, s.eori, s.country)
+            }
+            VcType::Euid => {
+                let s: Euid = parse(&vc_type, subject)?;
+                Self::registration(&vc_type, s.euid, None)
+            }
+            VcType::TaxId => {
+                let s: TaxId = parse(&vc_type, subject)?;
+                Self::registration(&vc_type, s.tax_id, None)
+            }
+            VcType::LocalRegistrationNumber => {
+                let s: LocalRegistrationNumber = parse(&vc_type, subject)?;
+                Self::registration(&vc_type, s.local, None)
+            }
+            _ => return None,
+        };
+        Some(attrs)
+    }
+
+    /// Extracts and merges the attributes of every credential JWT of a finished verification.
+    ///
+    /// The JWTs must already have been verified; their payloads are read without checks.
+    /// Credentials that cannot be decoded are skipped.
+    pub fn from_vc_jwts(vcs: &[String]) -> Self {
+        vcs.iter()
+            .filter_map(|raw| Jwt::parse(raw).ok())
+            .filter_map(|jwt| jwt.unsafe_claims::<VCJwtClaims>().ok())
+            .filter_map(|claims| Self::from_vc(claims.vc_doc()))
+            .fold(Self::default(), Self::merge)
+    }
+
+    /// Combines two attribute sets, keeping values already present in `self`.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            legal_name: self.legal_name.or(other.legal_name),
+            country_code: self.country_code.or(other.country_code),
+            registration_number: self.registration_number.or(other.registration_number),
+            registration_number_type: self
+                .registration_number_type
+                .or(other.registration_number_type),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn registration(vc_type: &VcType, number: String, country: Option<String>) -> Self {
+        Self {
+            legal_name: None,
+            country_code: country,
+            registration_number: Some(number),
+            registration_number_type: Some(vc_type.to_string()),
+        }
+    }
+}
+
+fn parse<T: serde::de::DeserializeOwned>(vc_type: &VcType, subject: Value) -> Option<T> {
+    serde_json::from_value(subject)
+        .map_err(|e| warn!("Unable to extract business attributes from {vc_type}: {e}"))
+        .ok()
+}
@@ -15,6 +15,16 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod business_attrs;
+mod status;
+mod transfer;
+
+pub use business_attrs::BusinessAttributes;
+pub use status::{ParticipantStatus, StatusChange};
+pub use transfer::{
+    ParticipantRecord, TransferFormat, parse_participants, render_participants,
+};
+
 use crate::errors::{BadFormat, Errors};
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -0,0 +1,79 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Lifecycle state of a business mate.
+///
+/// Only `Active` mates may use their access tokens. A mate is created `Active` unless its plan
+/// says otherwise, and an `Active` or `Invited` mate past its `expires_at` counts as `Expired`.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, EnumIter, DeriveActiveEnum, Serialize, Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+pub enum ParticipantStatus {
+    #[sea_orm(string_value = "Invited")]
+    Invited,
+    #[default]
+    #[sea_orm(string_value = "Active")]
+    Active,
+    #[sea_orm(string_value = "Suspended")]
+    Suspended,
+    #[sea_orm(string_value = "Expired")]
+    Expired,
+}
+
+impl ParticipantStatus {
+    /// Tells whether a mate may move from this state to `to`.
+    ///
+    /// Invited mates are activated, active and suspended mates toggle between each other, any
+    /// of them may expire and an expired mate can only be reactivated.
+    pub fn can_transition_to(&self, to: ParticipantStatus) -> bool {
+        use ParticipantStatus::*;
+        matches!(
+            (self, to),
+            (Invited, Active | Expired)
+                | (Active, Suspended | Expired)
+                | (Suspended, Active | Expired)
+                | (Expired, Active)
+        )
+    }
+}
+
+impl Display for ParticipantStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParticipantStatus::Invited => write!(f, "Invited"),
+            ParticipantStatus::Active => write!(f, "Active"),
+            ParticipantStatus::Suspended => write!(f, "Suspended"),
+            ParticipantStatus::Expired => write!(f, "Expired"),
+        }
+    }
+}
+
+/// Body of a lifecycle transition request.
+///
+/// `expires_at`, when given, replaces the membership expiry before the transition is applied,
+/// so an expired mate can be renewed and reactivated in one step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub status: ParticipantStatus,
+    pub expires_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,269 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::data::entities::shared::participant::{Model, Plan};
+use crate::errors::{AnyError, BadFormat, Errors, Outcome};
+use crate::types::extensions::validate_extensions;
+use crate::types::participants::ParticipantType;
+
+/// Required columns of a participant CSV file, in export order.
+const CSV_COLUMNS: [&str; 4] = ["id", "slug", "base_url", "type"];
+/// Optional last column holding the JSON-encoded `extensions` object.
+const CSV_EXTENSIONS: &str = "extensions";
+
+/// Portable description of a participant, as imported and exported in bulk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParticipantRecord {
+    pub id: String,
+    pub slug: String,
+    pub base_url: String,
+    pub r#type: ParticipantType,
+    /// Integrator-defined data; left untouched on existing participants when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Value>,
+}
+
+/// Encoding of a participant list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+impl TransferFormat {
+    /// Picks the format announced by a `Content-Type` header, defaulting to JSON.
+    pub fn from_content_type(content_type: Option<&str>) -> Self {
+        match content_type {
+            Some(value) if value.starts_with("text/csv") => TransferFormat::Csv,
+            _ => TransferFormat::Json,
+        }
+    }
+
+    /// Returns the media type of the format.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            TransferFormat::Json => "application/json",
+            TransferFormat::Csv => "text/csv",
+        }
+    }
+}
+
+impl ParticipantRecord {
+    /// Turns the record into the plan of a participant that is not this host.
+    pub fn into_plan(self) -> Plan {
+        Plan {
+            participant_id: self.id,
+            participant_nick: self.slug,
+            participant_type: self.r#type,
+            base_url: self.base_url,
+            token: None,
+            token_expires_at: None,
+            token_key: None,
+            extra_fields: None,
+            is_me: false,
+            extensions: self.extensions,
+            status: None,
+            expires_at: None,
+        }
+    }
+
+    /// Checks that every field is set, `base_url` is an absolute URL and `extensions` is within
+    /// the size limits.
+    pub fn validate(&self) -> Outcome<()> {
+        if self.id.trim().is_empty() || self.slug.trim().is_empty() {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "participant records need an id and a slug",
+                None,
+            ));
+        }
+        reqwest::Url::parse(&self.base_url).map_err(|e| {
+            Errors::format(
+                BadFormat::Received,
+                format!("invalid base_url of participant {}", self.id),
+                Some(Box::new(e)),
+            )
+        })?;
+        if let Some(extensions) = &self.extensions {
+            validate_extensions(extensions)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&Model> for ParticipantRecord {
+    fn from(model: &Model) -> Self {
+        Self {
+            id: model.participant_id.clone(),
+            slug: model.participant_nick.clone(),
+            base_url: model.base_url.clone(),
+            r#type: model.participant_type.clone(),
+            extensions: Some(model.extensions.clone()),
+        }
+    }
+}
+
+/// Decodes a participant list sent in `format`.
+///
+/// CSV input needs a header row naming the `id`, `slug`, `base_url` and `type` columns, in any
+/// order, and may add an `extensions` column of JSON objects; fields may be double-quoted, line
+/// breaks included, and blank lines are skipped.
+pub fn parse_participants(body: &str, format: TransferFormat) -> Outcome<Vec<ParticipantRecord>> {
+    match format {
+        TransferFormat::Json => serde_json::from_str(body).map_err(|e| {
+            Errors::format(
+                BadFormat::Received,
+                "invalid participant list",
+                Some(Box::new(e)),
+            )
+        }),
+        TransferFormat::Csv => parse_csv(body),
+    }
+}
+
+/// Encodes a participant list in `format`.
+///
+/// CSV cells starting with a spreadsheet formula trigger (`=`, `+`, `-`, `@`, tab or carriage
+/// return) are prefixed with `'` so they are shown as text; [`parse_participants`] strips it.
+pub fn render_participants(
+    records: &[ParticipantRecord],
+    format: TransferFormat,
+) -> Outcome<String> {
+    match format {
+        TransferFormat::Json => Ok(serde_json::to_string(records)?),
+        TransferFormat::Csv => render_csv(records),
+    }
+}
+
+fn render_csv(records: &[ParticipantRecord]) -> Outcome<String> {
+    let failed = |e: AnyError| Errors::crazy("Unable to write participant CSV", Some(e));
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(CSV_COLUMNS.iter().chain([&CSV_EXTENSIONS]))
+        .map_err(|e| failed(Box::new(e)))?;
+    for record in records {
+        let kind = record.r#type.to_string();
+        let extensions = record
+            .extensions
+            .as_ref()
+            .map(Value::to_string)
+            .unwrap_or_default();
+        let fields = [
+            &record.id,
+            &record.slug,
+            &record.base_url,
+            &kind,
+            &extensions,
+        ];
+        writer
+            .write_record(fields.iter().map(|field| guard_formula(field)))
+            .map_err(|e| failed(Box::new(e)))?;
+    }
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| failed(Box::new(e.into_error())))?;
+    String::from_utf8(bytes).map_err(|e| failed(Box::new(e)))
+}
+
+fn parse_csv(body: &str) -> Outcome<Vec<ParticipantRecord>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(body.as_bytes());
+    let header = reader.headers().map_err(csv_error)?.clone();
+    if header.iter().all(str::is_empty) {
+        return Ok(Vec::new());
+    }
+    let mut positions = [0; CSV_COLUMNS.len()];
+    for (position, column) in positions.iter_mut().zip(CSV_COLUMNS) {
+        *position = header
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(column))
+            .ok_or_else(|| {
+                Errors::format(
+                    BadFormat::Received,
+                    format!("participant CSV lacks the {column} column"),
+                    None,
+                )
+            })?;
+    }
+    let extensions_position = header
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(CSV_EXTENSIONS));
+
+    let mut records = Vec::new();
+    for row in reader.records() {
+        let row = row.map_err(csv_error)?;
+        if row.iter().all(str::is_empty) {
+            continue;
+        }
+        let field = |i: usize| unguard_formula(row.get(positions[i]).unwrap_or_default());
+        let extensions = match extensions_position.and_then(|i| row.get(i)) {
+            Some(cell) if !cell.is_empty() => Some(serde_json::from_str(cell).map_err(|e| {
+                Errors::format(
+                    BadFormat::Received,
+                    format!("invalid extensions of participant {}", field(0)),
+                    Some(Box::new(e)),
+                )
+            })?),
+            _ => None,
+        };
+        records.push(ParticipantRecord {
+            id: field(0).to_string(),
+            slug: field(1).to_string(),
+            base_url: field(2).to_string(),
+            r#type: ParticipantType::from_str(field(3))?,
+            extensions,
+        });
+    }
+    Ok(records)
+}
+
+fn csv_error(error: csv::Error) -> Errors {
+    let reason = match error.position() {
+        Some(position) => format!("invalid participant CSV on line {}", position.line()),
+        None => "invalid participant CSV".to_string(),
+    };
+    Errors::format(BadFormat::Received, reason, Some(Box::new(error)))
+}
+
+/// Whether a spreadsheet would evaluate a cell starting like `value` as a formula.
+fn is_formula(value: &str) -> bool {
+    value.starts_with(['=', '+', '-', '@', '\t', '\r'])
+}
+
+fn guard_formula(value: &str) -> String {
+    if is_formula(value) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    }
+}
+
+fn unguard_formula(value: &str) -> &str {
+    match value.strip_prefix('\'') {
+        Some(rest) if is_formula(rest) => rest,
+        _ => value,
+    }
+}
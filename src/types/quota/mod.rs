@@ -0,0 +1,65 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{self, Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Operation metered by a per-participant quota.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, DeriveActiveEnum, EnumIter, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaKind {
+    /// Credentials issued to the participant.
+    #[sea_orm(string_value = "issuance")]
+    Issuance,
+    /// Presentations the participant submitted for verification.
+    #[sea_orm(string_value = "verification")]
+    Verification,
+}
+
+impl Display for QuotaKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            QuotaKind::Issuance => "issuance",
+            QuotaKind::Verification => "verification",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Consumption of a quota within its current window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuotaUsage {
+    pub participant_id: String,
+    pub kind: QuotaKind,
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    pub used: i32,
+    pub limit: u32,
+}
+
+impl QuotaUsage {
+    pub fn remaining(&self) -> u32 {
+        self.limit.saturating_sub(self.used.max(0) as u32)
+    }
+    pub fn is_exceeded(&self) -> bool {
+        self.used.max(0) as u32 > self.limit
+    }
+}
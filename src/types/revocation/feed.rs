@@ -0,0 +1,64 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::data::entities::shared::revocation_event;
+
+/// JOSE `typ` of signed revocation feed pages.
+pub const REVOCATION_FEED_TYP: &str = "revocation-feed+jwt";
+
+/// One revocation published by the authority.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevocationEvent {
+    /// Position of the event in the feed.
+    pub seq: i64,
+    /// DID of the revoked participant.
+    pub subject: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub revoked_at: DateTime<Utc>,
+}
+
+impl From<revocation_event::Model> for RevocationEvent {
+    fn from(model: revocation_event::Model) -> Self {
+        Self {
+            seq: model.seq,
+            subject: model.subject,
+            reason: model.reason,
+            revoked_at: model.revoked_at,
+        }
+    }
+}
+
+/// Signed page of the revocation feed.
+///
+/// Pages are bound to the `cursor` they were requested with, so a subscriber can tell a
+/// stale page replayed at it from the one it asked for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevocationFeedPage {
+    pub iss: String,
+    pub iat: i64,
+    /// Sequence number the page starts after.
+    pub cursor: i64,
+    /// Cursor to request the following page with.
+    pub next_cursor: i64,
+    /// Whether more events are already waiting after `next_cursor`.
+    pub has_more: bool,
+    pub events: Vec<RevocationEvent>,
+}
@@ -0,0 +1,102 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod feed;
+
+pub use feed::{REVOCATION_FEED_TYP, RevocationEvent, RevocationFeedPage};
+
+use serde::{Deserialize, Serialize};
+
+/// Record a revocation would leave dangling.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImpactKind {
+    /// Bearer token the participant uses to call this authority.
+    ParticipantToken,
+    /// Grant this authority received from the participant.
+    ReceivedGrant,
+    /// Grant this authority requested from the participant.
+    SentGrant,
+    /// OpenID4VCI issuance session.
+    Issuance,
+    /// Status list position of an issued credential.
+    StatusEntry,
+    /// Participant whose DID is nested under the revoked one.
+    SubIdentity,
+    /// Relying-party subscription that keeps being notified.
+    RpSubscription,
+}
+
+/// One record affected by a revocation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImpactItem {
+    pub kind: ImpactKind,
+    pub id: String,
+    pub detail: Option<String>,
+}
+
+impl ImpactItem {
+    pub fn new(kind: ImpactKind, id: impl Into<String>, detail: Option<String>) -> Self {
+        Self {
+            kind,
+            id: id.into(),
+            detail,
+        }
+    }
+}
+
+/// Dry-run report of what revoking a participant or credential would affect.
+///
+/// Nothing is changed when building it; it only lists the records an operator should expect
+/// to be invalidated, interrupted or orphaned.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RevocationImpact {
+    pub target: String,
+    /// Tokens still usable by or on behalf of the target.
+    pub active_tokens: Vec<ImpactItem>,
+    /// Grant negotiations and issuance sessions not finished yet.
+    pub in_flight_flows: Vec<ImpactItem>,
+    /// Identities derived from the target.
+    pub sub_identities: Vec<ImpactItem>,
+    /// Recurring work that would keep running for the target.
+    pub scheduled_jobs: Vec<ImpactItem>,
+    /// Status list bits the revocation would set.
+    pub status_entries: Vec<ImpactItem>,
+}
+
+impl RevocationImpact {
+    pub fn new(target: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Number of affected records.
+    pub fn total(&self) -> usize {
+        self.active_tokens.len()
+            + self.in_flight_flows.len()
+            + self.sub_identities.len()
+            + self.scheduled_jobs.len()
+            + self.status_entries.len()
+    }
+
+    /// Whether the revocation would affect nothing besides the target itself.
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+}
@@ -0,0 +1,29 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// Credentials of a claim source, stored in the vault under its `secrets_path`.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ClaimSourceSecrets {
+    /// Bearer token sent to REST sources.
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Password LDAP sources bind with.
+    #[serde(default)]
+    pub password: Option<String>,
+}
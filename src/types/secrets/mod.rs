@@ -15,12 +15,20 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod claim_source;
 mod db;
+mod object_store;
 mod pem_helper;
+mod secret_file;
 mod string_helper;
+mod vault_health;
 mod wallet;
 
+pub use claim_source::*;
 pub use db::*;
+pub use object_store::*;
 pub use pem_helper::*;
+pub use secret_file::*;
 pub use string_helper::*;
+pub use vault_health::*;
 pub use wallet::*;
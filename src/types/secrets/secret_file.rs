@@ -0,0 +1,62 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::errors::Outcome;
+use crate::types::crypto::{EnvelopeKey, SealedEnvelope};
+
+const WHAT: &str = "secret file";
+
+/// Local file holding every secret of a node, sealed in a [`SealedEnvelope`].
+///
+/// Lets deployments without HashiCorp Vault keep their keys and credentials encrypted at rest.
+/// The plaintext maps each secret path to its JSON value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SecretFile(pub SealedEnvelope);
+
+impl SecretFile {
+    /// Derives the key of a new secret file from `passphrase` under a fresh salt.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`](crate::errors::Errors::FormatError) when the
+    /// passphrase is too short to protect secrets.
+    pub fn new_key(passphrase: &str) -> Outcome<EnvelopeKey> {
+        EnvelopeKey::derive(passphrase, WHAT)
+    }
+
+    /// Encrypts `secrets` under `key`.
+    pub fn seal(secrets: &BTreeMap<String, Value>, key: &EnvelopeKey) -> Outcome<Self> {
+        let plaintext = serde_json::to_vec(secrets)?;
+        Ok(Self(SealedEnvelope::seal(&plaintext, key, None)?))
+    }
+
+    /// Derives the key of this file from `passphrase`.
+    pub fn key_for(&self, passphrase: &str) -> Outcome<EnvelopeKey> {
+        self.0.key_for(passphrase, WHAT)
+    }
+
+    /// Decrypts the secrets with `key`.
+    pub fn open(&self, key: &EnvelopeKey) -> Outcome<BTreeMap<String, Value>> {
+        let plaintext = self.0.open(key, None, WHAT)?;
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+}
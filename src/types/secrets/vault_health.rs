@@ -0,0 +1,56 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// State of the secrets backend as last probed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VaultHealth {
+    /// Active node, serving reads and writes.
+    Ready,
+    /// Standby node, forwarding requests to the active one.
+    Standby,
+    /// Reachable but sealed until an operator unseals it.
+    Sealed,
+    /// Reachable but never initialized.
+    Uninitialized,
+    /// Not answering, or answering in an unexpected state.
+    Unreachable,
+}
+
+impl VaultHealth {
+    /// Tells whether secrets can be read and written.
+    pub fn is_ready(&self) -> bool {
+        matches!(self, VaultHealth::Ready | VaultHealth::Standby)
+    }
+}
+
+impl Display for VaultHealth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            VaultHealth::Ready => "ready",
+            VaultHealth::Standby => "standby",
+            VaultHealth::Sealed => "sealed",
+            VaultHealth::Uninitialized => "uninitialized",
+            VaultHealth::Unreachable => "unreachable",
+        };
+        f.write_str(s)
+    }
+}
@@ -0,0 +1,76 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::data::entities::wallet::did;
+
+/// Shared state key holding the [`LeaderLease`].
+pub const LEADER_STATE_KEY: &str = "standby.leader";
+/// Shared state key holding the latest [`StateSnapshot`].
+pub const SNAPSHOT_STATE_KEY: &str = "standby.snapshot";
+
+/// Part an instance plays in an active/standby pair.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstanceRole {
+    /// Serves the dataspace and publishes its ephemeral state.
+    #[default]
+    Active,
+    /// Follows the active instance, ready to be promoted.
+    Standby,
+}
+
+/// Record of the last promotion. Its epoch fences off every previous active instance.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LeaderLease {
+    pub instance_id: String,
+    pub epoch: i64,
+    pub promoted_at: DateTime<Utc>,
+}
+
+/// In-memory state the active instance hands over, so a promoted standby does not have to
+/// onboard its wallet again.
+///
+/// Nonces, replay entries and verification sessions already live in the database. The wallet
+/// session carries the backend login of the active instance and is as sensitive as the shared
+/// database holding it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    /// Default DID of the wallet the active instance operates with.
+    pub identity: Option<did::Model>,
+    /// Last revocation feed page ingested.
+    pub revocation_cursor: i64,
+    /// Backend session of the wallet, for wallets that keep one.
+    #[serde(default)]
+    pub wallet_session: Option<Value>,
+    pub taken_at: DateTime<Utc>,
+}
+
+/// Role of this instance, as reported to operators.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StandbyStatus {
+    pub instance_id: String,
+    pub role: InstanceRole,
+    pub epoch: i64,
+    /// Instance holding the leader lease, if a promotion ever happened.
+    pub leader: Option<String>,
+    /// When the state was last published (active) or applied (standby).
+    pub last_sync_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,237 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::marker::PhantomData;
+
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+
+use crate::errors::{Errors, Outcome};
+use crate::types::jwt::{VCJwtClaims, VcJwtClaimsBuilder};
+use crate::types::vcs::doc::{VCEvidence, VCSchema, VCStatus, VcDocumentBuilder};
+use crate::types::vcs::{VcIssuer, VcType, W3cDataModelVersion};
+use crate::utils::{Clock, Missing, Present};
+
+/// Type-state builder producing spec-correct `jwt_vc_json` claim sets.
+///
+/// The issuer and the credential subject are required at compile time; `build` only exists
+/// once both are set. The JWT registered claims are derived from the document so they can
+/// never disagree with it: `iss` from the issuer, `sub` from `credentialSubject.id`, `jti`
+/// from the credential id, `nbf`/`exp` from the validity window and `iat` from the issuance
+/// clock. The context and type arrays always start with the base entries of the data model.
+#[derive(Debug, Clone)]
+pub struct CredentialBuilder<ISS, SUB> {
+    model: W3cDataModelVersion,
+    vc_type: VcType,
+    id: Option<String>,
+    contexts: Vec<String>,
+    issuer: Option<VcIssuer>,
+    subject: Option<Map<String, Value>>,
+    name: Option<String>,
+    description: Option<String>,
+    valid_from: Option<DateTime<Utc>>,
+    valid_until: Option<DateTime<Utc>>,
+    credential_status: Option<VCStatus>,
+    credential_schema: Option<Vec<VCSchema>>,
+    evidence: Option<Vec<VCEvidence>>,
+    _marker: PhantomData<(ISS, SUB)>,
+}
+
+impl CredentialBuilder<Missing, Missing> {
+    pub fn new(vc_type: &VcType, model: W3cDataModelVersion) -> Self {
+        CredentialBuilder {
+            model,
+            vc_type: vc_type.clone(),
+            id: None,
+            contexts: Vec::new(),
+            issuer: None,
+            subject: None,
+            name: None,
+            description: None,
+            valid_from: None,
+            valid_until: None,
+            credential_status: None,
+            credential_schema: None,
+            evidence: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<ISS, SUB> CredentialBuilder<ISS, SUB> {
+    pub fn issuer(self, issuer: VcIssuer) -> CredentialBuilder<Present, SUB> {
+        CredentialBuilder {
+            model: self.model,
+            vc_type: self.vc_type,
+            id: self.id,
+            contexts: self.contexts,
+            issuer: Some(issuer),
+            subject: self.subject,
+            name: self.name,
+            description: self.description,
+            valid_from: self.valid_from,
+            valid_until: self.valid_until,
+            credential_status: self.credential_status,
+            credential_schema: self.credential_schema,
+            evidence: self.evidence,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets the `credentialSubject` of `holder_did`, whose DID becomes the subject `id`.
+    pub fn subject(
+        self,
+        holder_did: impl Into<String>,
+        mut claims: Map<String, Value>,
+    ) -> CredentialBuilder<ISS, Present> {
+        claims.insert("id".to_string(), Value::String(holder_did.into()));
+        CredentialBuilder {
+            model: self.model,
+            vc_type: self.vc_type,
+            id: self.id,
+            contexts: self.contexts,
+            issuer: self.issuer,
+            subject: Some(claims),
+            name: self.name,
+            description: self.description,
+            valid_from: self.valid_from,
+            valid_until: self.valid_until,
+            credential_status: self.credential_status,
+            credential_schema: self.credential_schema,
+            evidence: self.evidence,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets the credential id. Defaults to a fresh `urn:uuid:`.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Appends a JSON-LD context after the base context of the data model.
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.contexts.push(context.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the start of the validity window. Defaults to the issuance time.
+    pub fn valid_from(mut self, valid_from: DateTime<Utc>) -> Self {
+        self.valid_from = Some(valid_from);
+        self
+    }
+
+    pub fn valid_until(mut self, valid_until: DateTime<Utc>) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+
+    pub fn credential_status(mut self, credential_status: VCStatus) -> Self {
+        self.credential_status = Some(credential_status);
+        self
+    }
+
+    pub fn credential_schema(mut self, credential_schema: Vec<VCSchema>) -> Self {
+        self.credential_schema = Some(credential_schema);
+        self
+    }
+
+    pub fn evidence(mut self, evidence: Vec<VCEvidence>) -> Self {
+        self.evidence = Some(evidence);
+        self
+    }
+}
+
+impl CredentialBuilder<Present, Present> {
+    /// Assembles the claim set.
+    ///
+    /// # Errors
+    /// Returns a validation error when the validity window ends before it starts.
+    pub fn build(self) -> Outcome<VCJwtClaims> {
+        let iat = Clock::issued_now();
+        let valid_from = self.valid_from.unwrap_or(iat);
+        if self.valid_until.is_some_and(|until| until <= valid_from) {
+            return Err(Errors::validation(
+                "Credential validUntil must be after validFrom",
+                None,
+            ));
+        }
+
+        let id = self
+            .id
+            .unwrap_or_else(|| format!("urn:uuid:{}", uuid::Uuid::new_v4()));
+        let issuer = self.issuer.unwrap();
+        let subject = self.subject.unwrap();
+        let holder = subject
+            .get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let mut doc = VcDocumentBuilder::new(&self.vc_type, self.model.clone())
+            .id(&id)
+            .issuer(issuer)
+            .credential_subject(Value::Object(subject))
+            .valid_from(valid_from);
+        for context in self.contexts {
+            if !doc.context.contains(&context) {
+                doc.context.push(context);
+            }
+        }
+        if let Some(name) = self.name {
+            doc = doc.name(name);
+        }
+        if let Some(description) = self.description {
+            doc = doc.description(description);
+        }
+        if let Some(valid_until) = self.valid_until {
+            doc = doc.valid_until(valid_until);
+        }
+        if let Some(status) = self.credential_status {
+            doc = doc.credential_status(status);
+        }
+        if let Some(schema) = self.credential_schema {
+            doc = doc.credential_schema(schema);
+        }
+        if let Some(evidence) = self.evidence {
+            doc = doc.evidence(evidence);
+        }
+        let doc = doc.build();
+
+        let mut claims = VcJwtClaimsBuilder::new(self.model)
+            .iss(doc.issuer_did())
+            .jti(id)
+            .iat(iat)
+            .nbf(valid_from);
+        if let Some(holder) = holder {
+            claims = claims.sub(holder);
+        }
+        if let Some(valid_until) = doc.valid_until {
+            claims = claims.exp(valid_until);
+        }
+        Ok(claims.vc(doc).build())
+    }
+}
@@ -15,10 +15,31 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::types::issuance::StatusPurpose;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct VCStatus {
     pub id: String,
     pub r#type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_purpose: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_list_index: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_list_credential: Option<String>,
+}
+
+impl VCStatus {
+    /// Builds a `BitstringStatusListEntry` pointing at `index` of the list served at `list_url`.
+    pub fn bitstring(list_url: &str, index: i32, purpose: StatusPurpose) -> Self {
+        Self {
+            id: format!("{list_url}#{index}"),
+            r#type: "BitstringStatusListEntry".to_string(),
+            status_purpose: Some(purpose.to_string()),
+            status_list_index: Some(index.to_string()),
+            status_list_credential: Some(list_url.to_string()),
+        }
+    }
 }
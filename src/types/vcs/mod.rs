@@ -15,6 +15,7 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod credential_builder;
 pub mod doc;
 mod vc_format;
 pub mod vc_issuer;
@@ -24,6 +25,7 @@ mod vc_type_config;
 mod w3c_data_model;
 
 pub use crate::types::issuance::build_ctx::BuildCtx;
+pub use credential_builder::CredentialBuilder;
 pub use crate::types::verification::input_descriptor::InputDescriptor;
 pub use crate::types::verification::vp_def::VPDef;
 pub use vc_format::VcFormat;
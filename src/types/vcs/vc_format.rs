@@ -37,7 +37,7 @@ impl Display for VcFormat {
             VcFormat::JwtVcJson => "jwt_vc_json",
             VcFormat::JwtVcJsonLd => "jwt_vc_json-ld",
             VcFormat::LdpVc => "ldp_vc",
-            VcFormat::SdJwtVc => "vc+sd-jwt",
+            VcFormat::SdJwtVc => "dc+sd-jwt",
             VcFormat::MsoMdoc => "mso_mdoc",
             VcFormat::Other(s) => s.as_str(),
         };
@@ -53,7 +53,7 @@ impl FromStr for VcFormat {
             "jwt_vc_json" => VcFormat::JwtVcJson,
             "jwt_vc_json-ld" => VcFormat::JwtVcJsonLd,
             "ldp_vc" => VcFormat::LdpVc,
-            "vc+sd-jwt" => VcFormat::SdJwtVc,
+            "dc+sd-jwt" | "vc+sd-jwt" => VcFormat::SdJwtVc,
             "mso_mdoc" => VcFormat::MsoMdoc,
             other => VcFormat::Other(other.to_string()),
         })
@@ -62,7 +62,7 @@ impl FromStr for VcFormat {
 
 impl VcFormat {
     pub fn supported() -> &'static [VcFormat] {
-        &[VcFormat::JwtVcJson]
+        &[VcFormat::JwtVcJson, VcFormat::SdJwtVc]
     }
     pub fn is_supported(&self) -> bool {
         Self::supported().contains(self)
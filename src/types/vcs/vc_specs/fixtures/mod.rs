@@ -0,0 +1,159 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Canonical JSON examples of every `vc_specs` credential subject.
+//!
+//! Each fixture is a golden file: deserializing it and serializing the result back must
+//! reproduce it key for key. The round-trip tests below pin the `serde` renames, so a wrong
+//! namespace or a stray snake_case key fails here instead of at a partner's validator.
+//! Downstream crates can reuse them as test inputs through the `test-utils` feature.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::errors::Outcome;
+
+pub const LEGAL_PERSON: &str = include_str!("legal_person.json");
+/// `gx:LegalPerson` subject with every optional property left out.
+pub const LEGAL_PERSON_MINIMAL: &str = include_str!("legal_person_minimal.json");
+/// Complete `gx:LegalPerson` credential document wrapping a subject.
+pub const LEGAL_PERSON_VC: &str = include_str!("legal_person_vc.json");
+pub const VAT_ID: &str = include_str!("vat_id.json");
+pub const EORI: &str = include_str!("eori.json");
+pub const EUID: &str = include_str!("euid.json");
+pub const LEI_CODE: &str = include_str!("lei_code.json");
+pub const LOCAL_REG_NUMBER: &str = include_str!("local_reg_number.json");
+pub const TAX_ID: &str = include_str!("tax_id.json");
+pub const TERMS_AND_CONDITIONS: &str = include_str!("terms_and_conditions.json");
+pub const DATASPACE_PARTICIPANT: &str = include_str!("dataspace_participant.json");
+pub const GX_LABEL: &str = include_str!("gx_label.json");
+
+/// Parses a fixture into its typed model.
+pub fn golden<T: DeserializeOwned>(fixture: &str) -> Outcome<T> {
+    Ok(serde_json::from_str(fixture)?)
+}
+
+/// Parses a fixture as untyped JSON, the reference a serialized model is compared against.
+pub fn golden_value(fixture: &str) -> Outcome<Value> {
+    Ok(serde_json::from_str(fixture)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::*;
+    use crate::types::vcs::doc::VcDocument;
+    use crate::types::vcs::vc_specs::dataspace::DataSpaceParticipant;
+    use crate::types::vcs::vc_specs::gx_label::GxLabelCredSubject;
+    use crate::types::vcs::vc_specs::legal_person::LegalPersonCredentialSubject;
+    use crate::types::vcs::vc_specs::legal_reg_number::{
+        Eori, Euid, LeiCode, LocalRegistrationNumber, TaxId, VatId,
+    };
+    use crate::types::vcs::vc_specs::terms_and_conds::TermsAndConditionsCredSub;
+
+    /// Deserializes `fixture` as `T` and checks serializing it back reproduces the golden file.
+    fn assert_round_trip<T: DeserializeOwned + Serialize>(fixture: &str) -> Value {
+        let expected = golden_value(fixture).expect("fixture is valid JSON");
+        let model: T = golden(fixture).expect("fixture matches the model");
+        let actual = serde_json::to_value(&model).expect("model serializes");
+        assert_eq!(
+            actual,
+            expected,
+            "round trip of {}",
+            std::any::type_name::<T>()
+        );
+        actual
+    }
+
+    /// Checks every key of a Gaia-X subject is namespaced, so a missing `rename` is caught.
+    fn assert_namespaced(value: &Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, nested) in map {
+                    assert!(
+                        matches!(key.as_str(), "id" | "@type" | "digestSRI")
+                            || ["gx:", "schema:", "vcard:"]
+                                .iter()
+                                .any(|ns| key.starts_with(ns)),
+                        "key '{key}' has no Gaia-X namespace"
+                    );
+                    assert!(!key.contains('_'), "key '{key}' is snake_case");
+                    assert_namespaced(nested);
+                }
+            }
+            Value::Array(items) => items.iter().for_each(assert_namespaced),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn legal_person_round_trips() {
+        assert_namespaced(&assert_round_trip::<LegalPersonCredentialSubject>(
+            LEGAL_PERSON,
+        ));
+    }
+
+    #[test]
+    fn legal_person_minimal_skips_optionals() {
+        assert_namespaced(&assert_round_trip::<LegalPersonCredentialSubject>(
+            LEGAL_PERSON_MINIMAL,
+        ));
+    }
+
+    #[test]
+    fn legal_person_vc_round_trips() {
+        let document = assert_round_trip::<VcDocument>(LEGAL_PERSON_VC);
+        assert_namespaced(&document["credentialSubject"]);
+        let subject: LegalPersonCredentialSubject =
+            serde_json::from_value(document["credentialSubject"].clone())
+                .expect("subject matches the model");
+        assert_eq!(
+            subject.gx_legal_address.postal_code.as_deref(),
+            Some("28040")
+        );
+    }
+
+    #[test]
+    fn registration_numbers_round_trip() {
+        assert_namespaced(&assert_round_trip::<VatId>(VAT_ID));
+        assert_namespaced(&assert_round_trip::<Eori>(EORI));
+        assert_namespaced(&assert_round_trip::<Euid>(EUID));
+        assert_namespaced(&assert_round_trip::<LeiCode>(LEI_CODE));
+        assert_namespaced(&assert_round_trip::<LocalRegistrationNumber>(
+            LOCAL_REG_NUMBER,
+        ));
+        assert_namespaced(&assert_round_trip::<TaxId>(TAX_ID));
+    }
+
+    #[test]
+    fn terms_and_conditions_round_trip() {
+        assert_namespaced(&assert_round_trip::<TermsAndConditionsCredSub>(
+            TERMS_AND_CONDITIONS,
+        ));
+    }
+
+    #[test]
+    fn gx_label_round_trips() {
+        assert_namespaced(&assert_round_trip::<GxLabelCredSubject>(GX_LABEL));
+    }
+
+    #[test]
+    fn dataspace_participant_round_trips() {
+        assert_round_trip::<DataSpaceParticipant>(DATASPACE_PARTICIPANT);
+    }
+}
@@ -83,6 +83,7 @@ pub struct Address {
 }
 
 impl LegalPersonCredentialSubject {
+    #[deprecated(note = "hardcodes UPM data, configure a `CredentialTemplate` instead")]
     pub fn new4gaia(
         kid: &str,
         vc_type: &VcType,
@@ -16,7 +16,10 @@
  */
 
 pub mod dataspace;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod fixtures;
 pub mod gx_label;
 pub mod legal_person;
 pub mod legal_reg_number;
+pub mod template;
 pub mod terms_and_conds;
@@ -0,0 +1,172 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::data::entities::shared::participant;
+use crate::errors::{Errors, Outcome};
+use crate::types::vcs::{BuildCtx, VcType};
+use crate::utils::Clock;
+
+/// Deployment-provided `credentialSubject` skeleton for a given credential type.
+///
+/// Every string inside `subject` may carry `{{variable}}` placeholders that are resolved
+/// against [`TemplateVars`] at issuance time. A string consisting of a single placeholder
+/// is replaced by the raw JSON value, so objects and arrays can be injected as well.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CredentialTemplate {
+    pub vc_type: VcType,
+    pub subject: Value,
+}
+
+impl CredentialTemplate {
+    pub fn new(vc_type: VcType, subject: Value) -> Self {
+        Self { vc_type, subject }
+    }
+
+    /// Resolves every placeholder, failing on variables that were not provided.
+    pub fn render(&self, vars: &TemplateVars) -> Outcome<Value> {
+        render_value(&self.subject, vars)
+    }
+}
+
+/// Variables available to credential templates.
+///
+/// Built incrementally from the issuance context; names are dotted paths such as
+/// `kid`, `participant.nick` or `claims.registration_number`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateVars {
+    vars: Map<String, Value>,
+}
+
+impl TemplateVars {
+    /// Seeds the variables every template can rely on: `kid`, `now` and `today`.
+    pub fn new(kid: impl Into<String>) -> Self {
+        let now = Clock::issued_now();
+        Self::default()
+            .var("kid", kid.into())
+            .var("now", now.to_rfc3339())
+            .var("today", now.format("%Y-%m-%d").to_string())
+    }
+
+    pub fn var(mut self, key: impl Into<String>, value: impl Serialize) -> Self {
+        let value = serde_json::to_value(value).unwrap_or(Value::Null);
+        self.vars.insert(key.into(), value);
+        self
+    }
+
+    /// Exposes the participant record as `participant.*` plus its `extra_fields`.
+    pub fn participant(self, participant: &participant::Model) -> Self {
+        let mut vars = self
+            .var("participant.id", &participant.participant_id)
+            .var("participant.nick", &participant.participant_nick)
+            .var("participant.base_url", &participant.base_url)
+            .var("participant.type", participant.participant_type.to_string());
+        if let Value::Object(extra) = &participant.extra_fields {
+            for (key, value) in extra {
+                vars = vars.var(format!("participant.{}", key), value);
+            }
+        }
+        vars
+    }
+
+    /// Exposes the issuance context as `subject_name`, `holder_did` and `claims.*`.
+    pub fn build_ctx(self, ctx: &BuildCtx) -> Self {
+        let mut vars = self.var("subject_name", &ctx.subject_name);
+        if let Some(holder_did) = &ctx.holder_did {
+            vars = vars.var("holder_did", holder_did);
+        }
+        if let Value::Object(claims) = &ctx.claims {
+            for (key, value) in claims {
+                vars = vars.var(format!("claims.{}", key), value);
+            }
+        }
+        vars
+    }
+
+    /// Applies `escape` to every string variable, before rendering into a query language.
+    pub fn escaped(mut self, escape: impl Fn(&str) -> String) -> Self {
+        for value in self.vars.values_mut() {
+            if let Value::String(s) = value {
+                *s = escape(s);
+            }
+        }
+        self
+    }
+
+    /// Renders a standalone string such as a lookup URL, failing on undefined variables.
+    pub fn render(&self, input: &str) -> Outcome<String> {
+        Ok(match render_str(input, self)? {
+            Value::String(s) => s,
+            other => other.to_string(),
+        })
+    }
+
+    fn get(&self, key: &str) -> Outcome<&Value> {
+        self.vars.get(key).ok_or_else(|| {
+            Errors::validation(format!("Template variable '{}' is not defined", key), None)
+        })
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn render_value(value: &Value, vars: &TemplateVars) -> Outcome<Value> {
+    match value {
+        Value::String(s) => render_str(s, vars),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| render_value(item, vars))
+            .collect::<Outcome<Vec<_>>>()
+            .map(Value::Array),
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), render_value(v, vars)?)))
+            .collect::<Outcome<Map<_, _>>>()
+            .map(Value::Object),
+        other => Ok(other.clone()),
+    }
+}
+
+fn render_str(input: &str, vars: &TemplateVars) -> Outcome<Value> {
+    let trimmed = input.trim();
+    if let Some(key) = trimmed
+        .strip_prefix("{{")
+        .and_then(|s| s.strip_suffix("}}"))
+        .filter(|key| !key.contains("{{"))
+    {
+        return vars.get(key.trim()).cloned();
+    }
+
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..].find("}}").ok_or_else(|| {
+            Errors::validation(format!("Unclosed placeholder in template: {}", input), None)
+        })?;
+        out.push_str(&rest[..start]);
+        let key = rest[start + 2..start + end].trim();
+        match vars.get(key)? {
+            Value::String(s) => out.push_str(s),
+            other => out.push_str(&other.to_string()),
+        }
+        rest = &rest[start + end + 2..];
+    }
+    out.push_str(rest);
+    Ok(Value::String(out))
+}
@@ -32,7 +32,7 @@ pub enum W3cDataModelVersion {
 impl W3cDataModelVersion {
     pub fn context(&self) -> &'static str {
         match self {
-            W3cDataModelVersion::V1 => "https://www.w3.org/ns/credentials/v1",
+            W3cDataModelVersion::V1 => "https://www.w3.org/2018/credentials/v1",
             W3cDataModelVersion::V2 => "https://www.w3.org/ns/credentials/v2",
         }
     }
@@ -69,3 +69,20 @@ impl Default for W3cDataModelVersion {
         W3cDataModelVersion::V2
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contexts_are_the_published_vcdm_ones() {
+        assert_eq!(
+            W3cDataModelVersion::V1.context(),
+            "https://www.w3.org/2018/credentials/v1"
+        );
+        assert_eq!(
+            W3cDataModelVersion::V2.context(),
+            "https://www.w3.org/ns/credentials/v2"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+use urlencoding::encode;
+
+use crate::errors::{BadFormat, Errors, Outcome};
+
+const STATE_PLACEHOLDER: &str = "{state}";
+const PLACEHOLDERS: [&str; 4] = ["{host}", "{api_path}", "{endpoint}", STATE_PLACEHOLDER];
+
+/// Template of a URL a verification session accepts as presentation audience.
+///
+/// Placeholders: `{host}`, `{api_path}`, `{endpoint}` (`verify` or `callback`, after the
+/// response mode) and `{state}`. The session audience itself always follows the
+/// [default](Self::default) template, whose `{host}` already honors the configured public
+/// URL; further templates only add audiences, e.g. the internal URL of the agent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AudienceTemplate(String);
+
+impl Default for AudienceTemplate {
+    fn default() -> Self {
+        Self("{host}{api_path}/verifier/{endpoint}/{state}".to_string())
+    }
+}
+
+impl TryFrom<String> for AudienceTemplate {
+    type Error = Errors;
+
+    fn try_from(template: String) -> Outcome<Self> {
+        Self::new(template)
+    }
+}
+
+impl From<AudienceTemplate> for String {
+    fn from(template: AudienceTemplate) -> Self {
+        template.0
+    }
+}
+
+impl AudienceTemplate {
+    /// Parses a template, which must hold a single `{state}` as a path segment of its own
+    /// and no placeholder other than the known ones.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`] for any other template.
+    pub fn new(template: impl Into<String>) -> Outcome<Self> {
+        let template = template.into();
+        let invalid = |reason: &str| {
+            Errors::format(
+                BadFormat::Received,
+                format!("Invalid audience template '{template}': {reason}"),
+                None,
+            )
+        };
+        if template.matches(STATE_PLACEHOLDER).count() != 1 {
+            return Err(invalid("it must contain {state} exactly once"));
+        }
+        let (before, after) = template.split_once(STATE_PLACEHOLDER).unwrap_or_default();
+        if !before.ends_with('/') || !(after.is_empty() || after.starts_with(['/', '?'])) {
+            return Err(invalid("{state} must be a path segment of its own"));
+        }
+        let stripped = PLACEHOLDERS
+            .iter()
+            .fold(template.clone(), |rest, placeholder| {
+                rest.replace(placeholder, "")
+            });
+        if stripped.contains(['{', '}']) {
+            return Err(invalid("it contains an unknown placeholder"));
+        }
+        Ok(Self(template))
+    }
+
+    /// Fills every placeholder but `{state}`, which is only known once the session is stored.
+    pub fn render(&self, host: &str, api_path: &str, endpoint: &str) -> String {
+        self.0
+            .replace("{host}", host)
+            .replace("{api_path}", api_path)
+            .replace("{endpoint}", endpoint)
+    }
+
+    /// Substitutes the session state, percent-encoded, into an audience returned by
+    /// [`Self::render`].
+    pub fn bind_state(audience: &str, state: &str) -> String {
+        audience.replace(STATE_PLACEHOLDER, &encode(state))
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use sea_orm::FromJsonQueryResult;
+use serde::{Deserialize, Serialize};
+
+use crate::data::entities::received::verification::Model;
+use crate::errors::Outcome;
+use crate::types::vcs::VcType;
+use crate::types::verification::ResponseMode;
+
+/// Result of a single check run against a wallet response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, FromJsonQueryResult)]
+pub struct AuditCheck {
+    pub name: String,
+    pub passed: bool,
+    /// Failure reason, absent on checks that passed.
+    pub detail: Option<String>,
+}
+
+impl AuditCheck {
+    pub fn passed(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            passed: true,
+            detail: None,
+        }
+    }
+
+    pub fn failed(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            passed: false,
+            detail: Some(detail.into()),
+        }
+    }
+
+    /// Records whether `outcome` succeeded, keeping its error as the failure reason.
+    pub fn from_outcome<T>(name: impl Into<String>, outcome: &Outcome<T>) -> Self {
+        match outcome {
+            Ok(_) => Self::passed(name),
+            Err(e) => Self::failed(name, e.to_string()),
+        }
+    }
+}
+
+/// Snapshot of the request the wallet answered, as it stood when the response arrived.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, FromJsonQueryResult)]
+pub struct AuditedRequest {
+    pub vc_type: Vec<VcType>,
+    pub purpose: Option<String>,
+    pub audience: String,
+    pub nonce: String,
+    pub state: String,
+    pub response_mode: ResponseMode,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<&Model> for AuditedRequest {
+    fn from(model: &Model) -> Self {
+        Self {
+            vc_type: model.vc_type.clone(),
+            purpose: model.purpose.clone(),
+            audience: model.audience.clone(),
+            nonce: model.nonce.clone(),
+            state: model.state.clone(),
+            response_mode: model.response_mode,
+            created_at: model.created_at,
+            expires_at: model.expires_at,
+        }
+    }
+}
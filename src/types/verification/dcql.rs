@@ -0,0 +1,265 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use reqwest::Url;
+use sea_orm::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::types::vcs::VcType;
+use crate::types::verification::VpPolicy;
+use crate::types::verification::input_descriptor::{
+    DescriptorConstraint, InputDescriptorConstraintsFields,
+};
+
+/// Credential format requested through DCQL; the only one the verifier accepts.
+pub const DCQL_FORMAT: &str = "jwt_vc_json";
+
+/// How the verifier describes the credentials it wants in the authorization request.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, DeriveActiveEnum, EnumIter,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+#[serde(rename_all = "snake_case")]
+pub enum QueryLanguage {
+    /// DIF Presentation Exchange, referenced through `presentation_definition_uri`.
+    #[default]
+    #[sea_orm(string_value = "presentation_exchange")]
+    PresentationExchange,
+    /// Digital Credentials Query Language, inlined as `dcql_query`.
+    #[sea_orm(string_value = "dcql")]
+    Dcql,
+}
+
+/// Digital Credentials Query Language query (OpenID4VP 1.0).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DcqlQuery {
+    pub credentials: Vec<CredentialQuery>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credential_sets: Option<Vec<CredentialSetQuery>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CredentialQuery {
+    /// Key under which the wallet returns the matching presentation in the `vp_token`.
+    pub id: String,
+    pub format: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub multiple: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claims: Option<Vec<ClaimsQuery>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClaimsQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Claims path pointer: strings select keys, integers array indexes and `null` any element.
+    pub path: Vec<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<Value>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CredentialSetQuery {
+    pub options: Vec<Vec<String>>,
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+fn default_required() -> bool {
+    true
+}
+
+/// Credential query id used for a credential type; DCQL ids only allow alphanumerics, `_` and `-`.
+pub fn credential_query_id(vc_type: &VcType) -> String {
+    vc_type
+        .to_string()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+impl DcqlQuery {
+    /// Builds one credential query per requested type, carrying over the configured field constraints.
+    pub fn new(vc_types: &[VcType], constraints: &[DescriptorConstraint]) -> Self {
+        let credentials = vc_types
+            .iter()
+            .map(|vc_type| {
+                let claims: Vec<ClaimsQuery> = constraints
+                    .iter()
+                    .filter(|c| &c.vc_type == vc_type)
+                    .flat_map(|c| c.fields.iter())
+                    .filter(|f| !f.optional.unwrap_or(false))
+                    .filter_map(ClaimsQuery::from_field)
+                    .collect();
+                CredentialQuery {
+                    id: credential_query_id(vc_type),
+                    format: DCQL_FORMAT.to_string(),
+                    multiple: false,
+                    meta: Some(json!({ "type_values": [[vc_type.to_string()]] })),
+                    claims: (!claims.is_empty()).then_some(claims),
+                }
+            })
+            .collect();
+        DcqlQuery {
+            credentials,
+            credential_sets: None,
+        }
+    }
+
+    /// Expresses an AND/OR policy as a single required credential set, one option per alternative.
+    pub fn with_policy(mut self, policy: &VpPolicy) -> Self {
+        if policy.is_conjunction() {
+            return self;
+        }
+        let options = policy
+            .options()
+            .iter()
+            .map(|option| option.iter().map(credential_query_id).collect())
+            .collect();
+        self.credential_sets = Some(vec![CredentialSetQuery {
+            options,
+            required: true,
+        }]);
+        self
+    }
+
+    /// Reads the `dcql_query` parameter of an authorization request URI, if present.
+    pub fn from_request_uri(uri: &str) -> Outcome<Option<Self>> {
+        let parsed = Url::parse(uri).map_err(|e| {
+            Errors::format(
+                BadFormat::Received,
+                "Invalid authorization request uri",
+                Some(Box::new(e)),
+            )
+        })?;
+        let Some((_, raw)) = parsed.query_pairs().find(|(k, _)| k == "dcql_query") else {
+            return Ok(None);
+        };
+        let query = serde_json::from_str(&raw).map_err(|e| {
+            Errors::format(BadFormat::Received, "Invalid dcql_query", Some(Box::new(e)))
+        })?;
+        Ok(Some(query))
+    }
+
+    pub fn ids(&self) -> Vec<&str> {
+        self.credentials.iter().map(|c| c.id.as_str()).collect()
+    }
+}
+
+impl ClaimsQuery {
+    /// Maps a Presentation Exchange field onto a claims path; fields using patterns only require presence.
+    fn from_field(field: &InputDescriptorConstraintsFields) -> Option<Self> {
+        let path = field.path.first()?;
+        let trimmed = path
+            .strip_prefix("$.vc.")
+            .or_else(|| path.strip_prefix("$."))
+            .unwrap_or(path);
+        let path: Vec<Value> = trimmed
+            .split('.')
+            .filter(|s| !s.is_empty())
+            .map(|s| Value::String(s.to_string()))
+            .collect();
+        if path.is_empty() {
+            return None;
+        }
+        let values = field
+            .filter
+            .as_ref()
+            .and_then(|f| match (&f.r#const, &f.r#enum) {
+                (Some(c), _) => Some(vec![c.clone()]),
+                (None, Some(e)) => Some(e.clone()),
+                _ => None,
+            });
+        Some(ClaimsQuery {
+            id: field.id.clone(),
+            path,
+            values,
+        })
+    }
+
+    fn matches(&self, credential: &Value) -> bool {
+        let mut selected = vec![credential];
+        for segment in &self.path {
+            selected = selected
+                .into_iter()
+                .flat_map(|v| -> Vec<&Value> {
+                    match segment {
+                        Value::String(key) => v.get(key).into_iter().collect(),
+                        Value::Number(n) => n
+                            .as_u64()
+                            .and_then(|i| v.get(i as usize))
+                            .into_iter()
+                            .collect(),
+                        Value::Null => v.as_array().map(|a| a.iter().collect()).unwrap_or_default(),
+                        _ => Vec::new(),
+                    }
+                })
+                .collect();
+        }
+        match &self.values {
+            Some(values) => selected.iter().any(|v| values.contains(v)),
+            None => !selected.is_empty(),
+        }
+    }
+}
+
+impl CredentialQuery {
+    /// Whether a decoded credential (JWT claims or bare VC) satisfies this query.
+    pub fn matches(&self, document: &Value) -> bool {
+        if self.format != DCQL_FORMAT {
+            return false;
+        }
+        let credential = document.get("vc").unwrap_or(document);
+        let types: Vec<&str> = match credential.get("type") {
+            Some(Value::Array(a)) => a.iter().filter_map(Value::as_str).collect(),
+            Some(Value::String(s)) => vec![s.as_str()],
+            _ => Vec::new(),
+        };
+        let type_ok = self
+            .meta
+            .as_ref()
+            .and_then(|m| m.get("type_values"))
+            .and_then(Value::as_array)
+            .map(|options| {
+                options.iter().any(|option| {
+                    option.as_array().is_some_and(|required| {
+                        required
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .all(|t| types.iter().any(|have| have.eq_ignore_ascii_case(t)))
+                    })
+                })
+            })
+            .unwrap_or(true);
+        type_ok
+            && self
+                .claims
+                .as_ref()
+                .is_none_or(|claims| claims.iter().all(|c| c.matches(credential)))
+    }
+}
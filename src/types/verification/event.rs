@@ -0,0 +1,55 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::vcs::VcType;
+use crate::types::verification::VerificationStatus;
+
+/// JOSE `typ` of signed verification webhook events.
+pub const VERIFICATION_EVENT_TYP: &str = "verification-event+jwt";
+
+/// What happened to the verification an event reports on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VerificationEventKind {
+    Completed,
+    Failed,
+}
+
+/// Signed event pushed to verifier webhooks once a verification reaches its outcome.
+///
+/// Unlike [`VerificationDecision`](super::VerificationDecision) it carries no claims: it only
+/// tells downstream components which session finished and how, so they can stop polling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationEvent {
+    pub iss: String,
+    pub aud: String,
+    pub jti: String,
+    pub iat: i64,
+    pub event: VerificationEventKind,
+    pub verification_id: String,
+    pub status: VerificationStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holder: Option<String>,
+    pub vc_types: Vec<VcType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm::FromJsonQueryResult;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::dids::DidDocument;
+use crate::types::vcs::VcType;
+use crate::types::verification::{AuditCheck, VerificationStatus};
+
+/// JOSE `typ` header carried by signed evidence bundles.
+pub const EVIDENCE_TYP: &str = "evidence+jwt";
+
+/// Everything the verifier relied on to decide a verification, frozen when it finished.
+///
+/// Signed by the verifier's active identity and never rewritten, so a dispute raised months
+/// later is settled against the exact inputs of the decision rather than today's state of the
+/// DIDs and status lists involved.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromJsonQueryResult)]
+pub struct EvidenceBundle {
+    pub iss: String,
+    pub jti: String,
+    pub iat: i64,
+    pub verification_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holder: Option<String>,
+    pub status: VerificationStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<i64>,
+    pub policy: EvidencePolicy,
+    /// Every wallet response handled for the verification, oldest first.
+    pub decisions: Vec<EvidenceDecision>,
+    pub did_documents: Vec<DidEvidence>,
+    pub status_lists: Vec<StatusListEvidence>,
+}
+
+/// Policy profile the presentation was checked against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvidencePolicy {
+    pub vc_types: Vec<VcType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presentation_definition_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presentation_definition: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redaction_profile: Option<String>,
+}
+
+/// Checks run against one wallet response and the outcome they led to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvidenceDecision {
+    pub recorded_at: i64,
+    /// Base64url SHA-256 digest of the `vp_token`, absent for wallet errors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vp_token_digest: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presentation_submission: Option<String>,
+    pub checks: Vec<AuditCheck>,
+    pub outcome: VerificationStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+}
+
+/// DID document of the holder or an issuer, as resolved while packaging.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DidEvidence {
+    pub did: String,
+    pub resolved_at: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<DidDocument>,
+    /// Why the DID could not be resolved; kept so the gap is part of the record.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Status list credential referenced by a presented credential, as served while packaging.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusListEvidence {
+    pub url: String,
+    pub retrieved_at: i64,
+    /// Raw status list credential, JWT or JSON-LD, exactly as served.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credential: Option<String>,
+    /// Base64url SHA-256 digest of `credential`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
@@ -0,0 +1,36 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use super::VerificationStatus;
+
+/// Criteria narrowing a listing of verification sessions. Unset fields match every session.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct VerificationFilter {
+    pub status: Option<VerificationStatus>,
+    /// DID of the holder that answered the session.
+    pub holder: Option<String>,
+}
+
+/// Criteria narrowing a listing of trusted issuer registrations.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TrustedIssuerFilter {
+    pub issuer_did: Option<String>,
+    /// External registry the registrations were synced from.
+    pub source: Option<String>,
+}
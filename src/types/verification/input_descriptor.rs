@@ -17,18 +17,33 @@
 
 use crate::types::keys::Alg;
 use crate::types::vcs::{VcType, W3cDataModelVersion};
+use crate::types::verification::VpFormat;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct InputDescriptor {
     pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+    /// Groups referenced by the `submission_requirements` of the definition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<Vec<String>>,
     pub format: InputDescriptorFormat,
     pub constraints: InputDescriptorConstraints,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct InputDescriptorFormat {
-    jwt_vc_json: InputDescriptorFormatJWTJson,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    jwt_vc_json: Option<InputDescriptorFormatJWTJson>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ldp_vc: Option<InputDescriptorFormatLdp>,
+    #[serde(rename = "vc+sd-jwt", default, skip_serializing_if = "Option::is_none")]
+    sd_jwt: Option<InputDescriptorFormatSdJwt>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -36,21 +51,78 @@ pub struct InputDescriptorFormatJWTJson {
     pub alg: Vec<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InputDescriptorFormatLdp {
+    pub proof_type: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InputDescriptorFormatSdJwt {
+    #[serde(rename = "sd-jwt_alg_values")]
+    pub sd_jwt_alg_values: Vec<String>,
+    #[serde(rename = "kb-jwt_alg_values")]
+    pub kb_jwt_alg_values: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct InputDescriptorConstraints {
     pub fields: Vec<InputDescriptorConstraintsFields>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit_disclosure: Option<LimitDisclosure>,
+}
+
+/// Whether the holder must (or should) only disclose the constrained fields.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LimitDisclosure {
+    Required,
+    Preferred,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct InputDescriptorConstraintsFields {
+    /// JSONPath expressions tried in order; the first one resolving is evaluated.
     pub path: Vec<String>,
-    pub filter: InputDescriptorConstraintsFieldsFilter,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<InputDescriptorConstraintsFieldsFilter>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// JSON Schema subset used as a field filter.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct InputDescriptorConstraintsFieldsFilter {
-    pub r#type: String,
-    pub pattern: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    #[serde(rename = "const", default, skip_serializing_if = "Option::is_none")]
+    pub r#const: Option<Value>,
+    #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
+    pub r#enum: Option<Vec<Value>>,
+}
+
+/// Extra requirements attached to the input descriptor of a requested credential type.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DescriptorConstraint {
+    /// Credential type the constraint applies to.
+    pub vc_type: VcType,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Human-readable reason shown to the holder.
+    #[serde(default)]
+    pub purpose: Option<String>,
+    #[serde(default)]
+    pub limit_disclosure: Option<LimitDisclosure>,
+    /// Fields appended after the credential type match.
+    #[serde(default)]
+    pub fields: Vec<InputDescriptorConstraintsFields>,
 }
 
 impl InputDescriptor {
@@ -65,18 +137,220 @@ impl InputDescriptor {
             .collect();
         InputDescriptor {
             id: vc_type.to_string(),
+            name: None,
+            purpose: None,
+            group: None,
             format: InputDescriptorFormat {
-                jwt_vc_json: InputDescriptorFormatJWTJson { alg: supported_alg },
+                jwt_vc_json: Some(InputDescriptorFormatJWTJson { alg: supported_alg }),
+                ldp_vc: None,
+                sd_jwt: None,
             },
             constraints: InputDescriptorConstraints {
                 fields: vec![InputDescriptorConstraintsFields {
                     path,
-                    filter: InputDescriptorConstraintsFieldsFilter {
-                        r#type: "string".to_string(),
-                        pattern: vc_type.to_string(),
-                    },
+                    id: None,
+                    name: None,
+                    purpose: None,
+                    filter: Some(InputDescriptorConstraintsFieldsFilter {
+                        r#type: Some("string".to_string()),
+                        pattern: Some(vc_type.to_string()),
+                        ..Default::default()
+                    }),
+                    optional: None,
                 }],
+                limit_disclosure: None,
             },
         }
     }
+
+    /// Layers a configured constraint on top of the type match.
+    pub fn constrained(mut self, constraint: &DescriptorConstraint) -> Self {
+        self.name = constraint.name.clone();
+        self.purpose = constraint.purpose.clone();
+        self.constraints.limit_disclosure = constraint.limit_disclosure;
+        self.constraints
+            .fields
+            .extend(constraint.fields.iter().cloned());
+        self
+    }
+
+    /// Requests the credential formats embedded in the given presentation formats.
+    ///
+    /// JSON-LD credentials are matched on their top-level `type` and SD-JWT VCs on their
+    /// `vct`, so those paths are added to the type match.
+    pub fn with_formats(mut self, formats: &[VpFormat]) -> Self {
+        let jwt = self.format.jwt_vc_json.take();
+        self.format.jwt_vc_json = jwt.filter(|_| formats.contains(&VpFormat::JwtVpJson));
+        if formats.contains(&VpFormat::LdpVp) {
+            self.format.ldp_vc = Some(InputDescriptorFormatLdp {
+                proof_type: vec!["DataIntegrityProof".to_string()],
+            });
+            self.add_type_path("$.type");
+        }
+        if formats.contains(&VpFormat::SdJwtVc) {
+            let algs: Vec<String> = Alg::supported().iter().map(|a| a.to_string()).collect();
+            self.format.sd_jwt = Some(InputDescriptorFormatSdJwt {
+                sd_jwt_alg_values: algs.clone(),
+                kb_jwt_alg_values: algs,
+            });
+            self.add_type_path("$.vct");
+        }
+        self
+    }
+
+    /// Whether the descriptor accepts credentials submitted in `format`.
+    ///
+    /// `jwt_vc` is accepted as the legacy name of `jwt_vc_json`.
+    pub fn accepts_format(&self, format: &str) -> bool {
+        match format {
+            "jwt_vc_json" | "jwt_vc" => self.format.jwt_vc_json.is_some(),
+            "ldp_vc" => self.format.ldp_vc.is_some(),
+            "vc+sd-jwt" | "dc+sd-jwt" => self.format.sd_jwt.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Whether a decoded credential (JWT claims) satisfies every non-optional field.
+    pub fn is_satisfied_by(&self, credential: &Value) -> bool {
+        self.constraints
+            .fields
+            .iter()
+            .filter(|field| field.optional != Some(true))
+            .all(|field| field.is_satisfied_by(credential))
+    }
+
+    /// Whether the holder must disclose nothing beyond the constrained fields.
+    pub fn limits_disclosure(&self) -> bool {
+        self.constraints.limit_disclosure == Some(LimitDisclosure::Required)
+    }
+
+    /// Whether every selectively `disclosed` claim is named by a step of some field path.
+    pub fn covers_disclosures(&self, disclosed: &[&str]) -> bool {
+        let named: Vec<String> = self
+            .constraints
+            .fields
+            .iter()
+            .flat_map(|field| &field.path)
+            .filter_map(|path| path_steps(path))
+            .flatten()
+            .filter_map(|step| match step {
+                PathStep::Key(key) => Some(key),
+                PathStep::Index(_) => None,
+            })
+            .collect();
+        disclosed
+            .iter()
+            .all(|name| named.iter().any(|key| key == name))
+    }
+
+    fn add_type_path(&mut self, path: &str) {
+        if let Some(type_match) = self.constraints.fields.first_mut() {
+            if !type_match.path.iter().any(|p| p == path) {
+                type_match.path.push(path.to_string());
+            }
+        }
+    }
+}
+
+impl InputDescriptorConstraintsFields {
+    /// Evaluates the filter against the first path resolving in `credential`.
+    pub fn is_satisfied_by(&self, credential: &Value) -> bool {
+        let Some(value) = self.path.iter().find_map(|p| select_path(credential, p)) else {
+            return false;
+        };
+        let Some(filter) = &self.filter else {
+            return true;
+        };
+        match value {
+            Value::Array(items) => items.iter().any(|item| filter.accepts(item)),
+            value => filter.accepts(value),
+        }
+    }
+}
+
+impl InputDescriptorConstraintsFieldsFilter {
+    /// Checks a single value against the filter.
+    ///
+    /// `pattern` is a regular expression searched anywhere in string values, as the Postgres
+    /// `~` the repositories filter with; one that does not compile matches nothing.
+    pub fn accepts(&self, value: &Value) -> bool {
+        let type_ok = self.r#type.as_deref().is_none_or(|t| match t {
+            "string" => value.is_string(),
+            "number" => value.is_number(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "boolean" => value.is_boolean(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => true,
+        });
+        let pattern_ok = self.pattern.as_deref().is_none_or(|p| {
+            value
+                .as_str()
+                .is_some_and(|s| Regex::new(p).is_ok_and(|re| re.is_match(s)))
+        });
+        let const_ok = self.r#const.as_ref().is_none_or(|c| c == value);
+        let enum_ok = self.r#enum.as_ref().is_none_or(|e| e.contains(value));
+        type_ok && pattern_ok && const_ok && enum_ok
+    }
+}
+
+/// One step of a JSONPath accepted in field constraints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathStep {
+    /// Object member, written `.name` or `['name']`.
+    Key(String),
+    /// Array element, written `[0]`.
+    Index(usize),
+}
+
+/// Parses a JSONPath made of member (`.name`, `['name']`) and index (`[0]`) steps.
+///
+/// Member names are limited to ASCII letters, digits and `_`, `-`, `@`, `:`, so a parsed path
+/// can be spelled out in a query without escaping. Returns `None` for wildcards, filters and
+/// any other syntax.
+pub fn path_steps(path: &str) -> Option<Vec<PathStep>> {
+    let valid_key = |key: &str| {
+        !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '@' | ':'))
+    };
+    let mut rest = path.trim().strip_prefix('$')?;
+    let mut steps = Vec::new();
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            let (key, tail) = after.split_at(end);
+            if !valid_key(key) {
+                return None;
+            }
+            steps.push(PathStep::Key(key.to_string()));
+            rest = tail;
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let (step, tail) = after.split_once(']')?;
+            let quoted = step
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+                .or_else(|| step.strip_prefix('"').and_then(|s| s.strip_suffix('"')));
+            steps.push(match quoted {
+                Some(key) if valid_key(key) => PathStep::Key(key.to_string()),
+                Some(_) => return None,
+                None => PathStep::Index(step.parse::<usize>().ok()?),
+            });
+            rest = tail;
+        } else {
+            return None;
+        }
+    }
+    Some(steps)
+}
+
+/// Resolves a JSONPath accepted by [`path_steps`] in `document`.
+pub fn select_path<'a>(document: &'a Value, path: &str) -> Option<&'a Value> {
+    path_steps(path)?
+        .iter()
+        .try_fold(document, |current, step| match step {
+            PathStep::Key(key) => current.get(key),
+            PathStep::Index(index) => current.get(index),
+        })
 }
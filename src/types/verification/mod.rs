@@ -15,11 +15,54 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod audience;
+mod audit;
+mod dcql;
+mod decision;
+mod event;
+mod evidence;
+mod filter;
+mod presentation_submission;
+mod presented;
 pub mod input_descriptor;
+mod receipt;
+mod redaction;
+mod replay;
+mod requirements;
+mod response_encryption;
+mod response_mode;
+mod session;
 mod status;
+mod trust_sync;
 mod verify_payload;
 pub mod vp_def;
 mod vp_doc;
+mod vp_format;
+mod vp_policy;
 
+pub use audience::AudienceTemplate;
+pub use audit::{AuditCheck, AuditedRequest};
+pub use dcql::{
+    ClaimsQuery, CredentialQuery, CredentialSetQuery, DCQL_FORMAT, DcqlQuery, QueryLanguage,
+    credential_query_id,
+};
+pub use decision::{Decision, VerificationDecision};
+pub use event::{VERIFICATION_EVENT_TYP, VerificationEvent, VerificationEventKind};
+pub use evidence::{
+    DidEvidence, EVIDENCE_TYP, EvidenceBundle, EvidenceDecision, EvidencePolicy, StatusListEvidence,
+};
+pub use filter::{TrustedIssuerFilter, VerificationFilter};
+pub use presentation_submission::{DescriptorMapEntry, PresentationSubmission};
+pub use presented::PresentedCredential;
+pub use receipt::{RECEIPT_TYP, ReceiptPolicy, VerificationReceipt};
+pub use redaction::{RedactedCredential, RedactionProfile};
+pub use replay::{ReplayKind, presentation_jtis, vp_token_digest};
+pub use requirements::TokenRequirements;
+pub use response_encryption::{AuthorizationResponse, ResponseEncryption};
+pub use response_mode::ResponseMode;
+pub use session::VerificationSession;
 pub use status::VerificationStatus;
+pub use trust_sync::TrustSyncStatus;
 pub use verify_payload::VerifyPayload;
+pub use vp_format::VpFormat;
+pub use vp_policy::{SubmissionRequirement, SubmissionRule, VpPolicy};
@@ -0,0 +1,98 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::errors::{BadFormat, Errors, Outcome};
+use crate::types::jwt::{Jwt, SdJwt};
+use crate::types::verification::input_descriptor::select_path;
+
+/// DIF Presentation Exchange `presentation_submission` sent next to a `vp_token`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PresentationSubmission {
+    pub id: String,
+    pub definition_id: String,
+    pub descriptor_map: Vec<DescriptorMapEntry>,
+}
+
+/// Points an input descriptor at the credential submitted for it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DescriptorMapEntry {
+    pub id: String,
+    pub format: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_nested: Option<Box<DescriptorMapEntry>>,
+}
+
+impl PresentationSubmission {
+    /// Parses the form-posted JSON text of a submission.
+    pub fn parse(raw: &str) -> Outcome<Self> {
+        serde_json::from_str(raw).map_err(|e| {
+            Errors::format(
+                BadFormat::Received,
+                "Invalid presentation_submission",
+                Some(Box::new(e)),
+            )
+        })
+    }
+}
+
+impl DescriptorMapEntry {
+    /// Follows the entry, and its nested entries, starting at `document`.
+    ///
+    /// JWT-encoded values (`jwt_vp*`, `jwt_vc*` formats) are decoded before the next path is
+    /// applied, so the nested path of a `jwt_vp` entry runs over the VP claims. SD-JWT VCs
+    /// (`vc+sd-jwt`, `dc+sd-jwt`) decode to their disclosed claims. Returns the
+    /// format and the decoded document of the innermost entry. Signatures are not checked here.
+    pub fn resolve(&self, document: &Value) -> Outcome<(String, Value)> {
+        let selected = select_path(document, &self.path).ok_or_else(|| {
+            Errors::format(
+                BadFormat::Received,
+                format!(
+                    "Path '{}' of descriptor '{}' does not resolve",
+                    self.path, self.id
+                ),
+                None,
+            )
+        })?;
+
+        let decoded = match selected {
+            Value::String(token) if self.format.ends_with("+sd-jwt") => {
+                SdJwt::parse(token)?.disclosed_claims()?
+            }
+            Value::String(token) if self.format.starts_with("jwt_") => {
+                Jwt::parse(token)?.unverified_payload().clone()
+            }
+            value => value.clone(),
+        };
+
+        match &self.path_nested {
+            None => Ok((self.format.clone(), decoded)),
+            Some(nested) if nested.id == self.id => nested.resolve(&decoded),
+            Some(nested) => Err(Errors::format(
+                BadFormat::Received,
+                format!(
+                    "Nested descriptor '{}' does not match its parent '{}'",
+                    nested.id, self.id
+                ),
+                None,
+            )),
+        }
+    }
+}
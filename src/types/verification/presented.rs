@@ -0,0 +1,95 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm::FromJsonQueryResult;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::errors::Outcome;
+use crate::types::jwt::{Jwt, SdJwt, VCJwtClaims};
+use crate::types::vcs::doc::{VCStatus, VcDocument};
+
+/// Registered SD-JWT VC claims that describe the credential rather than its subject.
+const SD_JWT_ENVELOPE_CLAIMS: &[&str] = &[
+    "iss", "sub", "iat", "nbf", "exp", "vct", "cnf", "status", "jti",
+];
+
+/// Stored credential decoded from the form it was presented in, without checking its proof.
+///
+/// Gives claim minimisation, redaction and receipts a single view over `jwt_vc_json`,
+/// `ldp_vc` and SD-JWT VC credentials. For SD-JWT VCs only the disclosed claims are visible
+/// and the subject is rebuilt from them, keyed by `sub` as its `id`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromJsonQueryResult)]
+#[serde(rename_all = "camelCase")]
+pub struct PresentedCredential {
+    pub id: String,
+    pub r#type: Vec<String>,
+    pub issuer: String,
+    pub credential_subject: Value,
+    /// Status entry the verifier checked, kept so the list can be fetched again as evidence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credential_status: Option<VCStatus>,
+}
+
+impl PresentedCredential {
+    /// Decodes a credential as stored on a verification.
+    pub fn decode(vc: &str) -> Outcome<Self> {
+        let vc = vc.trim();
+        if vc.starts_with('{') {
+            let doc: VcDocument = serde_json::from_str(vc)?;
+            return Ok(Self::from_doc(&doc));
+        }
+        if vc.contains('~') {
+            return Ok(Self::from_sd_jwt(SdJwt::parse(vc)?.disclosed_claims()?));
+        }
+        let claims: VCJwtClaims = Jwt::parse(vc)?.unsafe_claims()?;
+        Ok(Self::from_doc(claims.vc_doc()))
+    }
+
+    fn from_doc(doc: &VcDocument) -> Self {
+        Self {
+            id: doc.id.clone(),
+            r#type: doc.r#type.clone(),
+            issuer: doc.issuer.id().to_string(),
+            credential_subject: doc.credential_subject.clone(),
+            credential_status: doc.credential_status.clone(),
+        }
+    }
+
+    fn from_sd_jwt(claims: Value) -> Self {
+        let str_claim = |name: &str| claims.get(name).and_then(Value::as_str).map(str::to_string);
+
+        let mut subject: Map<String, Value> = claims
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(name, _)| !SD_JWT_ENVELOPE_CLAIMS.contains(&name.as_str()))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        if let Some(sub) = str_claim("sub") {
+            subject.insert("id".to_string(), Value::String(sub));
+        }
+
+        Self {
+            id: str_claim("jti").unwrap_or_default(),
+            r#type: str_claim("vct").into_iter().collect(),
+            issuer: str_claim("iss").unwrap_or_default(),
+            credential_subject: Value::Object(subject),
+            credential_status: None,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::vcs::VcType;
+
+/// JOSE `typ` header carried by signed verification receipts.
+pub const RECEIPT_TYP: &str = "receipt+jwt";
+
+/// Portable proof that a holder passed verification at a given time.
+///
+/// Signed by the verifier's active identity and handed back to the holder, who can show it to
+/// third parties without presenting the underlying credentials again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationReceipt {
+    pub iss: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    pub jti: String,
+    pub iat: i64,
+    pub verification_id: String,
+    pub verified_at: i64,
+    /// Base64url SHA-256 digest over the verified credential subjects.
+    pub claims_digest: String,
+    pub policy: ReceiptPolicy,
+}
+
+/// Policy profile the holder was verified against.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptPolicy {
+    pub vc_types: Vec<VcType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+}
@@ -0,0 +1,116 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm::FromJsonQueryResult;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::errors::Outcome;
+use crate::types::verification::PresentedCredential;
+
+/// Named rules deciding which verified claims are persisted on a verification.
+///
+/// Claim paths are dotted (`address.countryCode`) and relative to the credential subject.
+/// Once a profile applies, the raw `vp_token` and credentials are dropped and only the
+/// redacted subjects are stored.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RedactionProfile {
+    /// Identifier recorded with the stored data for later audits.
+    pub name: String,
+    /// Claims kept out of every credential subject. Empty keeps every claim not dropped.
+    #[serde(default)]
+    pub keep: Vec<String>,
+    /// Claims never stored, applied after `keep`.
+    #[serde(default)]
+    pub drop: Vec<String>,
+}
+
+/// Verified credential reduced to the claims its redaction profile lets through.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, FromJsonQueryResult)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactedCredential {
+    pub id: String,
+    pub r#type: Vec<String>,
+    pub issuer: String,
+    pub credential_subject: Value,
+}
+
+impl RedactionProfile {
+    /// Decodes an already verified credential and redacts its subject.
+    pub fn redact_vc(&self, vc: &str) -> Outcome<RedactedCredential> {
+        let credential = PresentedCredential::decode(vc)?;
+        Ok(RedactedCredential {
+            credential_subject: self.redact_subject(&credential.credential_subject),
+            id: credential.id,
+            r#type: credential.r#type,
+            issuer: credential.issuer,
+        })
+    }
+
+    /// Applies `keep` and then `drop` to a credential subject. The subject `id` is always kept.
+    pub fn redact_subject(&self, subject: &Value) -> Value {
+        let mut redacted = match self.keep.is_empty() {
+            true => subject.clone(),
+            false => {
+                let mut kept = Value::Object(Map::new());
+                for path in self.keep.iter().map(String::as_str).chain(["id"]) {
+                    if let Some(value) = subject.pointer(&pointer(path)) {
+                        insert_path(&mut kept, path, value.clone());
+                    }
+                }
+                kept
+            }
+        };
+        for path in &self.drop {
+            remove_path(&mut redacted, path);
+        }
+        redacted
+    }
+}
+
+// ===== MODULE LOCAL HELPER PROCEDURES ============================================================
+
+fn pointer(path: &str) -> String {
+    format!("/{}", path.replace('.', "/"))
+}
+
+fn insert_path(target: &mut Value, path: &str, value: Value) {
+    let mut current = target;
+    let mut segments = path.split('.').peekable();
+    while let Some(segment) = segments.next() {
+        let Value::Object(map) = current else {
+            return;
+        };
+        if segments.peek().is_none() {
+            map.insert(segment.to_string(), value);
+            return;
+        }
+        current = map
+            .entry(segment.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+}
+
+fn remove_path(target: &mut Value, path: &str) {
+    let (parent, leaf) = match path.rsplit_once('.') {
+        Some((parent, leaf)) => (target.pointer_mut(&pointer(parent)), leaf),
+        None => (Some(target), path),
+    };
+    if let Some(Value::Object(map)) = parent {
+        map.remove(leaf);
+    }
+}
@@ -0,0 +1,86 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{self, Display, Formatter};
+
+use sea_orm::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::types::jwt::Jwt;
+use crate::utils::encode_url_safe_no_pad;
+
+/// Value remembered to reject replayed presentations.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, DeriveActiveEnum, EnumIter, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+#[serde(rename_all = "snake_case")]
+pub enum ReplayKind {
+    /// Digest of a whole `vp_token` submitted to the verifier.
+    #[sea_orm(string_value = "vp_token")]
+    VpToken,
+    /// Nonce of the exchange the presentation answered.
+    #[sea_orm(string_value = "vp_nonce")]
+    VpNonce,
+    /// `jti` of a verified presentation JWT.
+    #[sea_orm(string_value = "vp_jti")]
+    VpJti,
+}
+
+impl Display for ReplayKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ReplayKind::VpToken => "vp_token",
+            ReplayKind::VpNonce => "vp_nonce",
+            ReplayKind::VpJti => "vp_jti",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Base64url SHA-256 digest under which a `vp_token` is remembered.
+pub fn vp_token_digest(vp_token: &str) -> String {
+    encode_url_safe_no_pad(Sha256::digest(vp_token.trim().as_bytes()))
+}
+
+/// Reads the `jti` of every presentation JWT of a `vp_token`, bare or keyed by DCQL query id.
+///
+/// Signatures are not checked here; only call it on tokens that were already verified.
+pub fn presentation_jtis(vp_token: &str) -> Vec<String> {
+    let presentations: Vec<String> = match serde_json::from_str::<Value>(vp_token) {
+        Ok(Value::Object(entries)) => entries
+            .values()
+            .flat_map(|entry| match entry {
+                Value::Array(items) => items.clone(),
+                other => vec![other.clone()],
+            })
+            .filter_map(|item| item.as_str().map(str::to_string))
+            .collect(),
+        _ => vec![vp_token.trim().to_string()],
+    };
+
+    presentations
+        .iter()
+        .filter_map(|presentation| Jwt::parse(presentation).ok())
+        .filter_map(|jwt| {
+            jwt.unverified_payload()
+                .get("jti")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        })
+        .collect()
+}
@@ -0,0 +1,82 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::errors::{BadFormat, Errors, Outcome};
+
+/// Whether wallets must encrypt their `direct_post` authorization responses (JARM).
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseEncryption {
+    /// Plain `direct_post` responses only; no encryption key is published.
+    #[default]
+    Disabled,
+    /// An encryption key is published, but plain responses are still accepted as a fallback.
+    Preferred,
+    /// Only `direct_post.jwt` responses encrypted to the published key are accepted.
+    Required,
+}
+
+/// Authorization response parameters posted by a wallet to the verifier.
+///
+/// Encrypted responses only carry `response`, the JWE wrapping the other parameters.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AuthorizationResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vp_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presentation_submission: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_description: Option<String>,
+}
+
+impl AuthorizationResponse {
+    /// Reads the decrypted JSON payload of a `direct_post.jwt` response.
+    ///
+    /// Structured values (a DCQL `vp_token` object, a `presentation_submission`) are kept
+    /// as their JSON text, as if they had been form-posted.
+    pub fn from_decrypted(plaintext: &[u8]) -> Outcome<Self> {
+        let payload: Value = serde_json::from_slice(plaintext).map_err(|e| {
+            Errors::format(
+                BadFormat::Received,
+                "Decrypted authorization response is not JSON",
+                Some(Box::new(e)),
+            )
+        })?;
+        let field = |name: &str| match payload.get(name) {
+            None | Some(Value::Null) => None,
+            Some(Value::String(s)) => Some(s.clone()),
+            Some(other) => Some(other.to_string()),
+        };
+        Ok(Self {
+            response: None,
+            vp_token: field("vp_token"),
+            presentation_submission: field("presentation_submission"),
+            state: field("state"),
+            error: field("error"),
+            error_description: field("error_description"),
+        })
+    }
+}
@@ -0,0 +1,53 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{self, Display, Formatter};
+
+use sea_orm::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// OpenID4VP `response_mode` the wallet uses to return the `vp_token`.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, DeriveActiveEnum, EnumIter, Serialize, Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseMode {
+    /// The wallet POSTs the response straight to the verifier (cross-device friendly).
+    #[default]
+    #[sea_orm(string_value = "direct_post")]
+    DirectPost,
+    /// The browser is redirected with the response in the URL fragment (same-device only).
+    #[sea_orm(string_value = "fragment")]
+    Fragment,
+    /// The browser is redirected with the response in the query string (same-device only).
+    ///
+    /// Never offered to wallets: the `vp_token` would land in access logs and browser history.
+    #[sea_orm(string_value = "query")]
+    Query,
+}
+
+impl Display for ResponseMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ResponseMode::DirectPost => "direct_post",
+            ResponseMode::Fragment => "fragment",
+            ResponseMode::Query => "query",
+        };
+        write!(f, "{s}")
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::data::entities::received::verification::Model;
+use crate::types::verification::VerificationStatus;
+
+/// Pollable view of a verification session, without the presented tokens nor the holder.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VerificationSession {
+    pub id: String,
+    pub status: VerificationStatus,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Model> for VerificationSession {
+    fn from(model: &Model) -> Self {
+        Self {
+            id: model.id.clone(),
+            status: model.status.clone(),
+            created_at: model.created_at,
+            expires_at: model.expires_at,
+            ended_at: model.ended_at,
+        }
+    }
+}
@@ -18,13 +18,52 @@
 use sea_orm::prelude::*;
 use serde::{Deserialize, Serialize};
 
+/// Lifecycle of a verification session.
+///
+/// `Pending → Presented → Verified | Failed`, with `Pending → Failed` for wallet errors and
+/// `Pending | Presented → Expired` once the session outlives its TTL. A `Verified` session moves to
+/// `Revoked` when the authority later revokes its holder.
 #[derive(Clone, Debug, Eq, PartialEq, DeriveActiveEnum, EnumIter, Serialize, Deserialize)]
 #[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
 pub enum VerificationStatus {
+    /// Waiting for the wallet.
     #[sea_orm(string_value = "Pending")]
     Pending,
+    /// A response has been received and is being checked.
+    #[sea_orm(string_value = "Presented")]
+    Presented,
     #[sea_orm(string_value = "Verified")]
     Verified,
     #[sea_orm(string_value = "Failed")]
     Failed,
+    /// The wallet never answered, or its response was never settled, within the session TTL.
+    #[sea_orm(string_value = "Expired")]
+    Expired,
+    /// The holder was revoked after the presentation had been verified.
+    #[sea_orm(string_value = "Revoked")]
+    Revoked,
+}
+
+impl VerificationStatus {
+    /// Whether the session has reached a final state.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            VerificationStatus::Verified
+                | VerificationStatus::Failed
+                | VerificationStatus::Expired
+                | VerificationStatus::Revoked
+        )
+    }
+
+    /// Whether the state machine allows moving from `self` to `next`.
+    pub fn can_transition_to(&self, next: &VerificationStatus) -> bool {
+        use VerificationStatus::*;
+        matches!(
+            (self, next),
+            (Pending, Presented | Failed | Expired)
+                | (Presented, Verified | Failed | Expired)
+                | (Verified, Revoked)
+        )
+    }
 }
@@ -16,25 +16,112 @@
  */
 
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
+use crate::errors::Outcome;
+use crate::types::crypto::Canon;
 use crate::types::vcs::{InputDescriptor, VcType, W3cDataModelVersion};
+use crate::types::verification::input_descriptor::DescriptorConstraint;
+use crate::types::verification::{SubmissionRequirement, VpFormat, VpPolicy};
+use crate::utils::encode_url_safe_no_pad;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VPDef {
     pub id: String,
     pub input_descriptors: Vec<InputDescriptor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submission_requirements: Option<Vec<SubmissionRequirement>>,
 }
 
 impl VPDef {
     pub fn new(id: impl Into<String>, vc_types: &[VcType], model: W3cDataModelVersion) -> Self {
+        Self::with_constraints(id, vc_types, model, &[])
+    }
+
+    /// Builds the definition, applying the constraint configured for each requested type.
+    pub fn with_constraints(
+        id: impl Into<String>,
+        vc_types: &[VcType],
+        model: W3cDataModelVersion,
+        constraints: &[DescriptorConstraint],
+    ) -> Self {
         let input_descriptors = vc_types
             .iter()
-            .map(|vc_type| InputDescriptor::new(vc_type, model.clone()))
+            .map(|vc_type| {
+                let descriptor = InputDescriptor::new(vc_type, model.clone());
+                match constraints.iter().find(|c| &c.vc_type == vc_type) {
+                    Some(constraint) => descriptor.constrained(constraint),
+                    None => descriptor,
+                }
+            })
             .collect();
 
         VPDef {
             id: id.into(),
             input_descriptors,
+            submission_requirements: None,
+        }
+    }
+
+    /// Restricts every descriptor to the credential formats of the accepted presentation formats.
+    pub fn with_formats(mut self, formats: &[VpFormat]) -> Self {
+        self.input_descriptors = self
+            .input_descriptors
+            .into_iter()
+            .map(|descriptor| descriptor.with_formats(formats))
+            .collect();
+        self
+    }
+
+    /// Expresses an AND/OR policy through descriptor groups and submission requirements.
+    ///
+    /// Plain conjunctions leave the definition untouched, as every descriptor is required by default.
+    pub fn with_policy(mut self, policy: &VpPolicy) -> Self {
+        if policy.is_conjunction() {
+            return self;
+        }
+        for descriptor in &mut self.input_descriptors {
+            descriptor.group = Some(vec![descriptor.id.clone()]);
         }
+        self.submission_requirements = Some(policy.submission_requirements());
+        self
+    }
+
+    /// Returns the SHA-256 of the JCS form of the definition, its id left out.
+    pub fn content_hash(&self) -> Outcome<String> {
+        let mut value = serde_json::to_value(self)?;
+        if let Some(object) = value.as_object_mut() {
+            object.remove("id");
+        }
+        let canon = Canon::try_from(&value)?;
+        Ok(encode_url_safe_no_pad(Sha256::digest(canon.as_ref())))
+    }
+
+    /// Replaces the id with `pd-{content_hash}`, so any change to the definition changes its
+    /// id and the URI it is served at.
+    pub fn content_addressed(mut self) -> Outcome<Self> {
+        self.id = format!("pd-{}", self.content_hash()?);
+        Ok(self)
+    }
+
+    /// Whether submitting the descriptors with the given ids fulfils the definition.
+    pub fn is_satisfied_by(&self, matched: &[&str]) -> bool {
+        let Some(requirements) = &self.submission_requirements else {
+            return self
+                .input_descriptors
+                .iter()
+                .all(|d| matched.contains(&d.id.as_str()));
+        };
+        let groups: Vec<(&[String], bool)> = self
+            .input_descriptors
+            .iter()
+            .map(|d| {
+                (
+                    d.group.as_deref().unwrap_or_default(),
+                    matched.contains(&d.id.as_str()),
+                )
+            })
+            .collect();
+        requirements.iter().all(|r| r.is_satisfied_by(&groups))
     }
 }
@@ -0,0 +1,67 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Presentation format a verifier accepts in the `vp_token`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VpFormat {
+    /// JWS-enveloped presentation carrying `jwt_vc_json` credentials.
+    #[serde(rename = "jwt_vp_json")]
+    JwtVpJson,
+    /// JSON-LD presentation secured with a Data Integrity proof, carrying `ldp_vc` credentials.
+    #[serde(rename = "ldp_vp")]
+    LdpVp,
+    /// SD-JWT VC presented on its own, bound to the exchange by a Key Binding JWT.
+    #[serde(rename = "vc+sd-jwt", alias = "dc+sd-jwt")]
+    SdJwtVc,
+}
+
+impl VpFormat {
+    /// Format of the credentials embedded in presentations of this format.
+    pub fn credential_format(&self) -> &'static str {
+        match self {
+            VpFormat::JwtVpJson => "jwt_vc_json",
+            VpFormat::LdpVp => "ldp_vc",
+            VpFormat::SdJwtVc => "vc+sd-jwt",
+        }
+    }
+
+    /// Whether `vp_token` is a bare SD-JWT (`<jwt>~<disclosure>~...~<kb-jwt>`).
+    pub fn is_sd_jwt(vp_token: &str) -> bool {
+        let vp_token = vp_token.trim();
+        !vp_token.starts_with('{') && vp_token.contains('~')
+    }
+
+    /// Formats requested when nothing is configured.
+    pub fn defaults() -> Vec<VpFormat> {
+        vec![VpFormat::JwtVpJson]
+    }
+}
+
+impl Display for VpFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            VpFormat::JwtVpJson => "jwt_vp_json",
+            VpFormat::LdpVp => "ldp_vp",
+            VpFormat::SdJwtVc => "vc+sd-jwt",
+        };
+        write!(f, "{s}")
+    }
+}
@@ -0,0 +1,209 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::vcs::VcType;
+
+/// Boolean combination of credential types a presentation has to satisfy.
+///
+/// Expressed in config as nested single-key objects, e.g.
+/// `{"any": [{"all": ["gx:LegalPerson", "gx:TermsAndConditions"]}, "DataSpaceParticipant"]}`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum VpPolicy {
+    /// A single credential type.
+    Credential(VcType),
+    /// Every nested policy must hold.
+    All { all: Vec<VpPolicy> },
+    /// At least one nested policy must hold.
+    Any { any: Vec<VpPolicy> },
+}
+
+/// Presentation Exchange rule applied by a submission requirement.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SubmissionRule {
+    All,
+    Pick,
+}
+
+/// Presentation Exchange `submission_requirements` entry.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SubmissionRequirement {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub rule: SubmissionRule,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<usize>,
+    /// Input descriptor group the requirement draws from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_nested: Option<Vec<SubmissionRequirement>>,
+}
+
+impl VpPolicy {
+    /// Policy requiring every given type, the behaviour when no policy is configured.
+    pub fn all_of(vc_types: &[VcType]) -> Self {
+        VpPolicy::All {
+            all: vc_types.iter().cloned().map(VpPolicy::Credential).collect(),
+        }
+    }
+
+    /// Credential types referenced by the policy, without duplicates and in order of appearance.
+    pub fn vc_types(&self) -> Vec<VcType> {
+        let mut out = Vec::new();
+        self.collect_types(&mut out);
+        out
+    }
+
+    /// Whether the policy is a plain conjunction, which needs no submission requirements.
+    pub fn is_conjunction(&self) -> bool {
+        match self {
+            VpPolicy::Credential(_) => true,
+            VpPolicy::All { all } => all.iter().all(VpPolicy::is_conjunction),
+            VpPolicy::Any { any } => any.len() == 1 && any[0].is_conjunction(),
+        }
+    }
+
+    /// Evaluates the policy against the credential types that were presented.
+    pub fn is_satisfied_by(&self, presented: &[VcType]) -> bool {
+        match self {
+            VpPolicy::Credential(vc_type) => presented.contains(vc_type),
+            VpPolicy::All { all } => all.iter().all(|p| p.is_satisfied_by(presented)),
+            VpPolicy::Any { any } => any.iter().any(|p| p.is_satisfied_by(presented)),
+        }
+    }
+
+    /// Alternative sets of types satisfying the policy (disjunctive normal form).
+    pub fn options(&self) -> Vec<Vec<VcType>> {
+        match self {
+            VpPolicy::Credential(vc_type) => vec![vec![vc_type.clone()]],
+            VpPolicy::Any { any } => {
+                let mut options: Vec<Vec<VcType>> = Vec::new();
+                for option in any.iter().flat_map(VpPolicy::options) {
+                    if !options.contains(&option) {
+                        options.push(option);
+                    }
+                }
+                options
+            }
+            VpPolicy::All { all } => all.iter().fold(vec![Vec::new()], |acc, policy| {
+                let nested = policy.options();
+                acc.iter()
+                    .flat_map(|left| {
+                        nested.iter().map(move |right| {
+                            let mut option = left.clone();
+                            for vc_type in right {
+                                if !option.contains(vc_type) {
+                                    option.push(vc_type.clone());
+                                }
+                            }
+                            option
+                        })
+                    })
+                    .collect()
+            }),
+        }
+    }
+
+    /// Translates the policy into submission requirements, one descriptor group per type.
+    pub fn submission_requirements(&self) -> Vec<SubmissionRequirement> {
+        match self {
+            VpPolicy::All { all } => all.iter().map(VpPolicy::submission_requirement).collect(),
+            other => vec![other.submission_requirement()],
+        }
+    }
+
+    fn submission_requirement(&self) -> SubmissionRequirement {
+        let (rule, min, from, from_nested) = match self {
+            VpPolicy::Credential(vc_type) => {
+                (SubmissionRule::All, None, Some(vc_type.to_string()), None)
+            }
+            VpPolicy::All { all } => (
+                SubmissionRule::All,
+                None,
+                None,
+                Some(all.iter().map(VpPolicy::submission_requirement).collect()),
+            ),
+            VpPolicy::Any { any } => (
+                SubmissionRule::Pick,
+                Some(1),
+                None,
+                Some(any.iter().map(VpPolicy::submission_requirement).collect()),
+            ),
+        };
+        SubmissionRequirement {
+            name: None,
+            rule,
+            count: None,
+            min,
+            max: None,
+            from,
+            from_nested,
+        }
+    }
+
+    fn collect_types(&self, out: &mut Vec<VcType>) {
+        match self {
+            VpPolicy::Credential(vc_type) => {
+                if !out.contains(vc_type) {
+                    out.push(vc_type.clone());
+                }
+            }
+            VpPolicy::All { all: policies } | VpPolicy::Any { any: policies } => {
+                policies.iter().for_each(|p| p.collect_types(out))
+            }
+        }
+    }
+}
+
+impl SubmissionRequirement {
+    /// Evaluates the requirement given the groups of every satisfied input descriptor.
+    ///
+    /// `groups` holds, per descriptor of the definition, its groups and whether it was matched.
+    pub fn is_satisfied_by(&self, groups: &[(&[String], bool)]) -> bool {
+        let (total, matched) = match (&self.from, &self.from_nested) {
+            (Some(group), _) => {
+                let in_group: Vec<bool> = groups
+                    .iter()
+                    .filter(|(g, _)| g.contains(group))
+                    .map(|(_, matched)| *matched)
+                    .collect();
+                (in_group.len(), in_group.iter().filter(|m| **m).count())
+            }
+            (None, Some(nested)) => (
+                nested.len(),
+                nested.iter().filter(|r| r.is_satisfied_by(groups)).count(),
+            ),
+            (None, None) => return false,
+        };
+        match self.rule {
+            SubmissionRule::All => total > 0 && matched == total,
+            SubmissionRule::Pick => {
+                self.count.is_none_or(|c| matched == c)
+                    && self.min.is_none_or(|m| matched >= m)
+                    && self.max.is_none_or(|m| matched <= m)
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde_json::Value;
+
+use crate::errors::{BadFormat, Errors, Outcome};
+
+/// JSON-LD Verifiable Presentation secured with an embedded Data Integrity proof (`ldp_vp`).
+///
+/// Only the shape is checked when parsing; the proof is verified with
+/// [`Verifier::verify_data_integrity`](crate::capabilities::Verifier::verify_data_integrity).
+#[derive(Debug, Clone)]
+pub struct LdpVp {
+    document: Value,
+}
+
+impl LdpVp {
+    /// Whether a `vp_token` is a JSON-LD presentation rather than a JWS or a DCQL response.
+    pub fn is_ldp_vp(vp_token: &str) -> bool {
+        serde_json::from_str::<Value>(vp_token).is_ok_and(|value| is_presentation(&value))
+    }
+
+    /// Parses the JSON text of a presentation.
+    pub fn parse(vp_token: &str) -> Outcome<Self> {
+        let document: Value = serde_json::from_str(vp_token).map_err(|e| {
+            Errors::format(
+                BadFormat::Received,
+                "ldp_vp presentation is not valid JSON",
+                Some(Box::new(e)),
+            )
+        })?;
+        if !is_presentation(&document) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Document is not a secured VerifiablePresentation",
+                None,
+            ));
+        }
+        Ok(Self { document })
+    }
+
+    pub fn document(&self) -> &Value {
+        &self.document
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.document.get("id").and_then(Value::as_str)
+    }
+
+    /// Presentation `holder`, given either as a string or as an object with an `id`.
+    pub fn holder(&self) -> Option<&str> {
+        match self.document.get("holder")? {
+            Value::String(holder) => Some(holder),
+            holder => holder.get("id").and_then(Value::as_str),
+        }
+    }
+
+    /// Embedded credentials: JSON-LD objects (`ldp_vc`) are returned as their JSON text,
+    /// enveloped ones (`jwt_vc_json`) as the compact JWS.
+    pub fn credentials(&self) -> Vec<String> {
+        let items = match self.document.get("verifiableCredential") {
+            Some(Value::Array(items)) => items.iter().collect(),
+            Some(item) => vec![item],
+            None => Vec::new(),
+        };
+        items
+            .into_iter()
+            .map(|item| match item {
+                Value::String(jwt) => jwt.clone(),
+                ldp => ldp.to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Whether `value` claims to be a presentation and carries a proof.
+fn is_presentation(value: &Value) -> bool {
+    let typed = match value.get("type") {
+        Some(Value::Array(types)) => types
+            .iter()
+            .any(|t| t.as_str() == Some("VerifiablePresentation")),
+        Some(Value::String(t)) => t == "VerifiablePresentation",
+        _ => false,
+    };
+    typed && value.get("proof").is_some()
+}
@@ -21,6 +21,13 @@
 //! In this codebase the VP is modelled with the credentials carried as
 //! their JWT compact serialisation strings, suitable for use with the
 //! JWS-enveloped flow.
+//!
+//! [`LdpVp`] covers the JSON-LD alternative secured with a Data Integrity
+//! proof, whose credentials are embedded as objects.
+
+mod ldp_vp;
+
+pub use ldp_vp::LdpVp;
 
 use serde::{Deserialize, Serialize};
 
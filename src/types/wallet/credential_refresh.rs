@@ -0,0 +1,51 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::vcs::VcType;
+
+/// Outcome of the automatic renewal of a stored credential, pushed to the refresh webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialRefreshEvent {
+    /// Stored credential that was due for renewal.
+    pub vc_id: String,
+    pub vc_type: VcType,
+    /// Authority the renewal was requested from, when one could be found.
+    pub authority: Option<String>,
+    /// Expiry of the credential being renewed.
+    pub valid_until: Option<DateTime<Utc>>,
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub outcome: RefreshOutcome,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum RefreshOutcome {
+    /// The authority issued a new credential, which replaced the old one in the wallet.
+    Renewed {
+        flow_id: String,
+        new_vc_ids: Vec<String>,
+    },
+    /// The old credential was kept; the renewal is tried again on the next round.
+    Failed {
+        flow_id: Option<String>,
+        reason: String,
+    },
+}
@@ -0,0 +1,110 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::data::entities::wallet::vc;
+use crate::errors::{Errors, MissingAction, Outcome};
+use crate::types::wallet::PresentationCandidate;
+
+/// Rule picking the credential presented when several answer the same input descriptor.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CredentialSelection {
+    /// The first match, in storage order.
+    #[default]
+    First,
+    /// The most recently stored credential.
+    Newest,
+    /// The credential closest to its `validUntil`; credentials without one come last.
+    SoonestToExpire,
+    /// Credentials of the listed issuer DIDs, in that order; other issuers come last.
+    IssuerPriority(Vec<String>),
+    /// Never picks: an ambiguous descriptor is reported back with its candidates.
+    Manual,
+}
+
+/// Properties of a stored credential the selection strategies rank on.
+///
+/// Implemented by the native wallet records and by the credentials a remote wallet lists.
+pub trait SelectableCredential {
+    fn added_on(&self) -> Option<DateTime<Utc>>;
+
+    fn valid_until(&self) -> Option<DateTime<Utc>>;
+
+    fn issuer_did(&self) -> Option<&str>;
+}
+
+impl SelectableCredential for vc::Model {
+    fn added_on(&self) -> Option<DateTime<Utc>> {
+        Some(self.added_on)
+    }
+
+    fn valid_until(&self) -> Option<DateTime<Utc>> {
+        self.valid_until
+    }
+
+    fn issuer_did(&self) -> Option<&str> {
+        Some(&self.issuer_did)
+    }
+}
+
+impl CredentialSelection {
+    /// Orders `vcs` by preference, the preferred credential first.
+    ///
+    /// The order is stable, so ties keep their storage order.
+    pub fn rank<C: SelectableCredential>(&self, vcs: &mut [&C]) {
+        match self {
+            CredentialSelection::First | CredentialSelection::Manual => {}
+            CredentialSelection::Newest => {
+                vcs.sort_by(|a, b| b.added_on().cmp(&a.added_on()));
+            }
+            CredentialSelection::SoonestToExpire => {
+                vcs.sort_by_key(|vc| (vc.valid_until().is_none(), vc.valid_until()));
+            }
+            CredentialSelection::IssuerPriority(issuers) => {
+                vcs.sort_by_key(|vc| {
+                    issuers
+                        .iter()
+                        .position(|did| Some(did.as_str()) == vc.issuer_did())
+                        .unwrap_or(issuers.len())
+                });
+            }
+        }
+    }
+
+    /// Picks one credential per descriptor out of candidates already [ranked](Self::rank).
+    ///
+    /// # Errors
+    /// Under [`CredentialSelection::Manual`], returns a missing action error carrying the
+    /// candidates as details when any descriptor has more than one.
+    pub fn select(&self, candidates: &[PresentationCandidate]) -> Outcome<Vec<String>> {
+        if *self == CredentialSelection::Manual
+            && candidates.iter().any(|c| c.credentials.len() > 1)
+        {
+            let details = serde_json::to_string(candidates)?;
+            return Err(Errors::missing_action(
+                MissingAction::Credentials,
+                "Several credentials answer the presentation request, pick them explicitly",
+                None,
+            )
+            .with_details(details));
+        }
+        Ok(PresentationCandidate::default_selection(candidates))
+    }
+}
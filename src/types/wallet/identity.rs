@@ -17,6 +17,7 @@
 
 use super::KeyRef;
 use crate::capabilities::Did;
+use crate::errors::Outcome;
 use crate::types::dids::DidDocument;
 
 #[derive(Debug, Clone)]
@@ -24,16 +25,38 @@ pub struct Identity {
     did: Did,
     did_doc: DidDocument,
     keys_ref: KeyRef,
+    aliases: Vec<Did>,
 }
 
 impl Identity {
     pub fn new(did: Did, did_doc: DidDocument, keys_ref: KeyRef) -> Self {
+        let aliases = did_doc
+            .aliases()
+            .iter()
+            .filter_map(|alias| Did::parse(alias).ok())
+            .collect();
         Self {
             did,
             did_doc,
             keys_ref,
+            aliases,
         }
     }
+    /// Publishes the `did:jwk` of the signing key next to the primary DID.
+    ///
+    /// The derived DID is listed in the document's `alsoKnownAs` and accepted by
+    /// [`Identity::is_self`]. A `did:jwk` identity is returned untouched.
+    pub fn with_jwk_alias(mut self) -> Outcome<Self> {
+        if matches!(self.did, Did::Jwk(_)) {
+            return Ok(self);
+        }
+        let alias = self.did_doc.jwk_alias(self.keys_ref.fragment())?;
+        self.did_doc.add_alias(&alias);
+        if !self.is_self(alias.id()) {
+            self.aliases.push(alias);
+        }
+        Ok(self)
+    }
     pub fn did(&self) -> &Did {
         &self.did
     }
@@ -43,4 +66,11 @@ impl Identity {
     pub fn key_ref(&self) -> &KeyRef {
         &self.keys_ref
     }
+    pub fn aliases(&self) -> &[Did] {
+        &self.aliases
+    }
+    /// Whether `id` names this identity, either by its primary DID or by one of its aliases.
+    pub fn is_self(&self, id: &str) -> bool {
+        self.did.id() == id || self.aliases.iter().any(|alias| alias.id() == id)
+    }
 }
@@ -0,0 +1,95 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::data::entities::wallet::did;
+use crate::errors::Outcome;
+use crate::types::crypto::{EnvelopeKey, SealedEnvelope};
+use crate::utils::Clock;
+
+const WHAT: &str = "key backup";
+
+/// Private key carried by a backup, exactly as stored in the vault.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackedUpKey {
+    pub id: String,
+    pub alias: String,
+    pub pem: String,
+}
+
+/// Plaintext of a backup: the private keys of a wallet and the DIDs built over them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyBackupContent {
+    pub keys: Vec<BackedUpKey>,
+    pub dids: Vec<did::Model>,
+}
+
+/// Passphrase-encrypted wallet key backup.
+///
+/// The content is sealed in a [`SealedEnvelope`]; the creation time is bound as additional
+/// data next to the envelope parameters, so a tampered header fails to open.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyBackup {
+    #[serde(flatten)]
+    pub envelope: SealedEnvelope,
+    pub created_at: DateTime<Utc>,
+}
+
+impl KeyBackup {
+    /// Encrypts `content` under `passphrase`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::FormatError`](crate::errors::Errors::FormatError) when the
+    /// passphrase is too short to protect keys.
+    pub fn seal(content: &KeyBackupContent, passphrase: &str) -> Outcome<Self> {
+        let key = EnvelopeKey::derive(passphrase, WHAT)?;
+        let created_at = Clock::now();
+        let plaintext = serde_json::to_vec(content)?;
+        let context = created_at.timestamp().to_string();
+        Ok(Self {
+            envelope: SealedEnvelope::seal(&plaintext, &key, Some(&context))?,
+            created_at,
+        })
+    }
+
+    /// Decrypts the backup with `passphrase`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::SecurityError`](crate::errors::Errors::SecurityError) when the
+    /// passphrase is wrong or the backup was altered, and an
+    /// [`Errors::FormatError`](crate::errors::Errors::FormatError) for envelopes this build
+    /// cannot read.
+    pub fn open(&self, passphrase: &str) -> Outcome<KeyBackupContent> {
+        let key = self.envelope.key_for(passphrase, WHAT)?;
+        let context = self.created_at.timestamp().to_string();
+        let plaintext = self.envelope.open(&key, Some(&context), WHAT)?;
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+}
+
+/// Outcome of restoring a backup into a wallet.
+///
+/// Entries already present in the wallet are skipped rather than overwritten.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KeyImportSummary {
+    pub imported_keys: Vec<String>,
+    pub skipped_keys: Vec<String>,
+    pub imported_dids: Vec<String>,
+    pub skipped_dids: Vec<String>,
+}
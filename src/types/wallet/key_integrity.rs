@@ -0,0 +1,78 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Result of comparing the signing key held in Vault with the one the wallet and the
+/// published DID document advertise for the active identity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyIntegrityReport {
+    pub did: String,
+    pub verification_method: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vault_thumbprint: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wallet_thumbprint: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_thumbprint: Option<String>,
+    pub drifts: Vec<KeyDrift>,
+}
+
+impl KeyIntegrityReport {
+    pub fn is_healthy(&self) -> bool {
+        self.drifts.is_empty()
+    }
+}
+
+/// A single inconsistency found while checking the active signing key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum KeyDrift {
+    /// The key referenced by the identity cannot be read or parsed from Vault.
+    VaultKeyUnavailable(String),
+    /// The wallet DID document has no usable verification method for the key.
+    MissingFromWallet,
+    /// The wallet DID document advertises a different public key than Vault holds.
+    WalletMismatch,
+    /// The published DID document could not be resolved.
+    PublishedUnresolvable(String),
+    /// The published DID document has no usable verification method for the key.
+    MissingFromPublished,
+    /// The published DID document advertises a different public key than Vault holds.
+    PublishedMismatch,
+}
+
+impl Display for KeyDrift {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyDrift::VaultKeyUnavailable(e) => write!(f, "vault key unavailable: {e}"),
+            KeyDrift::MissingFromWallet => write!(f, "key missing from the wallet DID document"),
+            KeyDrift::WalletMismatch => write!(f, "wallet DID document holds a different key"),
+            KeyDrift::PublishedUnresolvable(e) => {
+                write!(f, "published DID document unresolvable: {e}")
+            }
+            KeyDrift::MissingFromPublished => {
+                write!(f, "key missing from the published DID document")
+            }
+            KeyDrift::PublishedMismatch => {
+                write!(f, "published DID document holds a different key")
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Number of rotation events kept in the rotation state.
+pub const MAX_ROTATION_EVENTS: usize = 100;
+
+/// Bookkeeping of the signing key rotations.
+///
+/// Stored as a single vault secret, so the active and retiring key paths always change
+/// together.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KeyRotationState {
+    /// Vault path of the key signing since the latest rotation.
+    #[serde(default)]
+    pub active: Option<String>,
+    /// Previous keys still published for verification.
+    #[serde(default)]
+    pub retiring: Vec<RetiringKey>,
+    /// Latest rotation events, oldest first.
+    #[serde(default)]
+    pub events: Vec<KeyRotationEvent>,
+}
+
+impl KeyRotationState {
+    /// Appends `event`, dropping the oldest ones past [`MAX_ROTATION_EVENTS`].
+    pub fn record(&mut self, event: KeyRotationEvent) {
+        self.events.push(event);
+        let excess = self.events.len().saturating_sub(MAX_ROTATION_EVENTS);
+        self.events.drain(..excess);
+    }
+
+    /// Returns the retiring keys whose grace period is over at `now`.
+    pub fn expired(&self, now: DateTime<Utc>) -> Vec<RetiringKey> {
+        self.retiring
+            .iter()
+            .filter(|key| key.retire_at <= now)
+            .cloned()
+            .collect()
+    }
+
+    /// Stops tracking the retiring key `key_id`.
+    pub fn forget(&mut self, key_id: &str) {
+        self.retiring.retain(|key| key.key_id != key_id);
+    }
+}
+
+/// Previous signing key kept in the DID document until `retire_at`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetiringKey {
+    pub key_id: String,
+    pub did: String,
+    pub retire_at: DateTime<Utc>,
+}
+
+/// Audit record of a single rotation step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyRotationEvent {
+    /// Key the step acted on.
+    pub key_id: String,
+    pub did: String,
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub step: RotationStep,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "step", rename_all = "snake_case")]
+pub enum RotationStep {
+    /// A new key pair was generated and written to the vault.
+    Generated,
+    /// The new key was added to the DID document, next to the current one.
+    Published,
+    /// The new key became the default signing key.
+    Activated { previous: String },
+    /// The grace period ended and the key was removed from the DID document.
+    Retired,
+    /// The step failed; the keys were left as the previous events describe.
+    Failed { reason: String },
+}
+
+/// Result of a rotation run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyRotationReport {
+    pub did: String,
+    pub previous_key: String,
+    pub active_key: String,
+    /// When the previous key stops being published.
+    pub retire_at: DateTime<Utc>,
+}
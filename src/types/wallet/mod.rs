@@ -17,17 +17,38 @@
 
 use serde::{Deserialize, Serialize};
 
+mod credential_refresh;
+mod credential_selection;
 mod did_search;
 mod identity;
+mod key_backup;
+mod key_integrity;
 mod key_ref;
+mod key_rotation;
 mod oidc_uri;
+mod onboarding;
+mod presentation_consent;
+mod resolved_offer;
+mod vc_search;
 mod wallet_info;
 pub mod waltid;
 
+pub use credential_refresh::{CredentialRefreshEvent, RefreshOutcome};
+pub use credential_selection::{CredentialSelection, SelectableCredential};
 pub use did_search::DidSearch;
 pub use identity::Identity;
+pub use key_backup::{BackedUpKey, KeyBackup, KeyBackupContent, KeyImportSummary};
+pub use key_integrity::{KeyDrift, KeyIntegrityReport};
 pub use key_ref::KeyRef;
+pub use key_rotation::{
+    KeyRotationEvent, KeyRotationReport, KeyRotationState, MAX_ROTATION_EVENTS, RetiringKey,
+    RotationStep,
+};
 pub use oidc_uri::OidcUri;
+pub use onboarding::{OnboardingStatus, OnboardingStep};
+pub use presentation_consent::{ConsentStatus, PresentationCandidate};
+pub use resolved_offer::ResolvedOffer;
+pub use vc_search::VcSearch;
 pub use wallet_info::WalletInfo;
 
 #[derive(Serialize, Deserialize, Default, Clone, Debug)]
@@ -20,4 +20,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct OidcUri {
     pub uri: String,
+    /// Transaction Code delivered out-of-band, for offers that require one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_code: Option<String>,
 }
@@ -0,0 +1,75 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// Step of the wallet onboarding, in the order they run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingStep {
+    /// The agent key is stored in the vault and registered in the wallet.
+    RegisterKey,
+    /// The base DID is built over the agent key and stored.
+    RegisterDid,
+    /// The base DID is the default one of the wallet.
+    SetDefault,
+}
+
+/// Onboarding progress of a wallet.
+///
+/// Every step is persisted by the store it writes to as soon as it succeeds, so the progress
+/// is read back from those stores and an interrupted onboarding resumes where it stopped.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OnboardingStatus {
+    pub key_registered: bool,
+    /// DID the wallet acts as: its default DID, or the base DID while none is set.
+    pub did: Option<String>,
+    pub did_registered: bool,
+    pub default_set: bool,
+    /// Steps the last onboarding run had to perform.
+    pub performed: Vec<OnboardingStep>,
+}
+
+impl OnboardingStatus {
+    /// Status of a wallet that already acts as `did`.
+    pub fn complete(did: impl Into<String>) -> Self {
+        Self {
+            key_registered: true,
+            did: Some(did.into()),
+            did_registered: true,
+            default_set: true,
+            performed: Vec::new(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.default_set
+    }
+
+    /// Lists the steps still to run.
+    pub fn missing(&self) -> Vec<OnboardingStep> {
+        [
+            (self.key_registered, OnboardingStep::RegisterKey),
+            (self.did_registered, OnboardingStep::RegisterDid),
+            (self.default_set, OnboardingStep::SetDefault),
+        ]
+        .into_iter()
+        .filter(|(done, _)| !done)
+        .map(|(_, step)| step)
+        .collect()
+    }
+}
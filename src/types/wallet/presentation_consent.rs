@@ -0,0 +1,54 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Stored credentials able to answer one input descriptor of a presentation request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PresentationCandidate {
+    pub descriptor_id: String,
+    /// Ids of the matching wallet credentials, in the order the wallet would pick them.
+    pub credentials: Vec<String>,
+}
+
+impl PresentationCandidate {
+    /// Picks the first candidate of every descriptor, as an unattended wallet does.
+    pub fn default_selection(candidates: &[PresentationCandidate]) -> Vec<String> {
+        candidates
+            .iter()
+            .filter_map(|c| c.credentials.first().cloned())
+            .collect()
+    }
+}
+
+/// Decision state of a presentation waiting for the holder's consent.
+#[derive(Clone, Debug, Eq, PartialEq, DeriveActiveEnum, EnumIter, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+pub enum ConsentStatus {
+    #[sea_orm(string_value = "Pending")]
+    Pending,
+    /// Approved, with the presentation being delivered to the verifier.
+    #[sea_orm(string_value = "Presenting")]
+    Presenting,
+    #[sea_orm(string_value = "Presented")]
+    Presented,
+    #[sea_orm(string_value = "Rejected")]
+    Rejected,
+    #[sea_orm(string_value = "Failed")]
+    Failed,
+}
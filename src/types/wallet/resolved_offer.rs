@@ -0,0 +1,56 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Errors, Outcome};
+use crate::types::issuance::{IssuerMetadata, VcCredOffer};
+use crate::types::vcs::VcTypeConfig;
+
+/// Credential offer fetched along with the metadata of its issuer, before it is redeemed.
+///
+/// Built by [`WalletTrait::resolve_oid4vci_offer`](crate::services::wallet::WalletTrait),
+/// which already checked the metadata against the offer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolvedOffer {
+    pub offer: VcCredOffer,
+    pub metadata: IssuerMetadata,
+}
+
+impl ResolvedOffer {
+    /// Checks the offer only carries configurations out of `requested`.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::SecurityError`] naming the first configuration nobody asked for.
+    pub fn check_requested(&self, requested: &[VcTypeConfig]) -> Outcome<()> {
+        match self
+            .offer
+            .credential_configuration_ids
+            .iter()
+            .find(|config| !requested.contains(config))
+        {
+            Some(config) => Err(Errors::security(
+                format!(
+                    "{} offered {config}, which was not requested",
+                    self.offer.credential_issuer
+                ),
+                None,
+            )),
+            None => Ok(()),
+        }
+    }
+}
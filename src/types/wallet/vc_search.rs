@@ -0,0 +1,85 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::data::entities::wallet::vc;
+use crate::types::vcs::VcType;
+
+/// Filter applied over the locally indexed wallet credentials.
+///
+/// Every field is optional and all provided fields must match. The expiry window only
+/// considers credentials carrying a `valid_until`; `q` is a case-insensitive free-text
+/// search over the decoded `credentialSubject`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VcSearch {
+    #[serde(default)]
+    pub vc_type: Option<VcType>,
+    #[serde(default)]
+    pub issuer: Option<String>,
+    #[serde(default)]
+    pub expires_after: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub expires_before: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub q: Option<String>,
+}
+
+impl VcSearch {
+    pub fn matches(&self, model: &vc::Model) -> bool {
+        if let Some(vc_type) = &self.vc_type {
+            if &model.vc_type != vc_type {
+                return false;
+            }
+        }
+        if let Some(issuer) = &self.issuer {
+            if &model.issuer_did != issuer {
+                return false;
+            }
+        }
+        if self.expires_after.is_some() || self.expires_before.is_some() {
+            let Some(valid_until) = model.valid_until else {
+                return false;
+            };
+            if self.expires_after.is_some_and(|after| valid_until < after) {
+                return false;
+            }
+            if self
+                .expires_before
+                .is_some_and(|before| valid_until > before)
+            {
+                return false;
+            }
+        }
+        if let Some(q) = &self.q {
+            let doc = &model.parsed_document;
+            let subject = doc
+                .get("credentialSubject")
+                .or_else(|| doc.get("vc").and_then(|vc| vc.get("credentialSubject")))
+                .unwrap_or(doc);
+            if !subject
+                .to_string()
+                .to_lowercase()
+                .contains(&q.to_lowercase())
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
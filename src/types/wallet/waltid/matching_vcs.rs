@@ -15,9 +15,12 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::types::wallet::SelectableCredential;
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct MatchingVCs {
     #[serde(rename = "addedOn")]
@@ -32,6 +35,24 @@ pub struct MatchingVCs {
     pub wallet: String,
 }
 
+impl SelectableCredential for MatchingVCs {
+    fn added_on(&self) -> Option<DateTime<Utc>> {
+        self.added_on.parse().ok()
+    }
+
+    fn valid_until(&self) -> Option<DateTime<Utc>> {
+        // VCDM 2.0 `validUntil`, or the VCDM 1.1 `expirationDate` it replaced.
+        ["validUntil", "expirationDate"]
+            .iter()
+            .find_map(|claim| self.parsed_document.get(*claim)?.as_str()?.parse().ok())
+    }
+
+    fn issuer_did(&self) -> Option<&str> {
+        let issuer = self.parsed_document.get("issuer")?;
+        issuer.as_str().or_else(|| issuer.get("id")?.as_str())
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MatchVCsRequest {
     pub did: String,
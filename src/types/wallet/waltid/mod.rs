@@ -32,7 +32,7 @@ pub use jwt::AuthJwtClaims;
 pub use key_definition::{KeyDefinition, KeyInfo};
 pub use matching_vcs::*;
 pub use other::*;
-pub use wallet_info_response::WalletInfoResponse;
+pub use wallet_info_response::{ModifiedWalletInfo, WalletInfoResponse};
 pub use wallet_login_response::WalletLoginResponse;
 pub use wallet_session::WalletSession;
 pub use wallet_vc::*;
@@ -15,6 +15,7 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -22,3 +23,10 @@ pub struct RedirectResponse {
     #[serde(rename = "redirectUri")]
     pub redirect_uri: String,
 }
+
+/// Entry of the wallet operation history (`/wallet/{id}/history`).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WalletOperationHistory {
+    pub operation: String,
+    pub timestamp: DateTime<Utc>,
+}
@@ -16,6 +16,7 @@
  */
 
 use crate::types::wallet::wallet_info::WalletInfo;
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -25,3 +26,15 @@ pub struct WalletSession {
     pub token_exp: Option<u64>,
     pub wallets: Vec<WalletInfo>,
 }
+
+impl WalletSession {
+    /// Returns the session token unless it is missing or expires within `margin_secs`.
+    pub fn fresh_token(&self, margin_secs: u64) -> Option<&str> {
+        let exp = self.token_exp?;
+        let now = Utc::now().timestamp().max(0) as u64;
+        if exp <= now.saturating_add(margin_secs) {
+            return None;
+        }
+        self.token.as_deref()
+    }
+}
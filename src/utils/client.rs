@@ -16,16 +16,25 @@
  */
 
 use crate::services::client::ClientService;
-use std::sync::LazyLock;
+use std::sync::OnceLock;
 
 // ===== STATIC RUNTIME INSTANCES ==================================================================
 
 /// Centralized thread-safe network pool handling engine egress operations.
-static CLIENT_SERVICE: LazyLock<ClientService> = LazyLock::new(|| ClientService::new(10, 10, 0));
+static CLIENT_SERVICE: OnceLock<ClientService> = OnceLock::new();
 
 // ===== SUBSYSTEM HOOKS ===========================================================================
 
 /// Yields a static reference to the shared global [`ClientService`] management infrastructure.
+///
+/// Falls back to a plain client when none was [installed](install_http_client).
 pub fn http_client() -> &'static ClientService {
-    &CLIENT_SERVICE
+    CLIENT_SERVICE.get_or_init(|| ClientService::new(10, 10, 0))
+}
+
+/// Installs `client`, e.g. one carrying mTLS destinations, as the shared global client.
+///
+/// Must run at startup, before the first outbound call; returns whether it was installed.
+pub fn install_http_client(client: ClientService) -> bool {
+    CLIENT_SERVICE.set(client).is_ok()
 }
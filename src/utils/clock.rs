@@ -0,0 +1,92 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use chrono::{DateTime, Duration, Utc};
+use tracing::warn;
+
+use crate::config::traits::ConnectionConfigTrait;
+use crate::errors::{Errors, Outcome};
+
+// ===== STATIC RUNTIME INSTANCES ==================================================================
+
+/// Simulated offset, in seconds, applied to timestamps stamped on generated tokens.
+static GENERATION_SKEW: AtomicI64 = AtomicI64::new(0);
+
+// ===== CLOCK ABSTRACTION =========================================================================
+
+/// Single source of time for the engine.
+///
+/// Validation paths always read the real wall clock through [`Clock::now`]. Generation paths
+/// (`iat`, `nbf`, `validFrom`, ...) read [`Clock::issued_now`], which can be deliberately skewed
+/// in non-production builds so interop partners can exercise their leeway handling.
+pub struct Clock;
+
+impl Clock {
+    /// Real wall-clock time. Never skewed.
+    pub fn now() -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    /// Time to stamp on generated tokens, shifted by the simulated skew when enabled.
+    pub fn issued_now() -> DateTime<Utc> {
+        Utc::now() + Duration::seconds(Self::skew())
+    }
+
+    /// Currently applied simulated skew in seconds.
+    pub fn skew() -> i64 {
+        GENERATION_SKEW.load(Ordering::Relaxed)
+    }
+
+    /// Applies the simulated skew declared in the connection configuration.
+    ///
+    /// Call it once at startup, right after the configuration is loaded; nothing else in the
+    /// crate installs the skew.
+    ///
+    /// # Errors
+    /// Returns an [`Errors::ModuleNotActiveError`] if a skew is configured in production or the
+    /// crate was built without the `simulated-clock-skew` feature.
+    pub fn init<C: ConnectionConfigTrait>(config: &C) -> Outcome<()> {
+        match config.connection().simulated_clock_skew {
+            None | Some(0) => Ok(()),
+            Some(_) if config.is_prod() => Err(Errors::not_active(
+                "Simulated clock skew cannot be enabled in production",
+                None,
+            )),
+            Some(secs) => Self::set_skew(secs),
+        }
+    }
+
+    /// Overrides the simulated skew at runtime.
+    #[cfg(feature = "simulated-clock-skew")]
+    pub fn set_skew(secs: i64) -> Outcome<()> {
+        warn!("Simulated clock skew of {secs}s applied to generated tokens");
+        GENERATION_SKEW.store(secs, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Overrides the simulated skew at runtime.
+    #[cfg(not(feature = "simulated-clock-skew"))]
+    pub fn set_skew(secs: i64) -> Outcome<()> {
+        warn!("Ignoring simulated clock skew of {secs}s, feature is disabled");
+        Err(Errors::not_active(
+            "Simulated clock skew requires the `simulated-clock-skew` feature",
+            None,
+        ))
+    }
+}
@@ -45,6 +45,51 @@ pub fn trim_4_base(input: &str) -> String {
     input[..cut_index].to_string()
 }
 
+// ===== REVERSE PROXY RESOLUTION ==================================================================
+
+/// Rebuilds the public base URL announced by a reverse proxy.
+///
+/// Prefers the standardized `Forwarded` header (RFC 7239) and falls back to the
+/// de-facto `X-Forwarded-Proto`/`X-Forwarded-Host`/`X-Forwarded-Prefix` trio. Returns `None`
+/// when no forwarded host is present, so callers can fall back to the configured host.
+pub fn forwarded_base_url(headers: &HeaderMap) -> Option<String> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.split(',').next().unwrap_or(v).trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    let (mut proto, mut host) = (None, None);
+    if let Some(forwarded) = header("forwarded") {
+        for pair in forwarded.split(';') {
+            match pair.trim().split_once('=') {
+                Some((k, v)) if k.eq_ignore_ascii_case("proto") => {
+                    proto = Some(v.trim_matches('"').to_string())
+                }
+                Some((k, v)) if k.eq_ignore_ascii_case("host") => {
+                    host = Some(v.trim_matches('"').to_string())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let host = host.or_else(|| header("x-forwarded-host"))?;
+    let proto = proto
+        .or_else(|| header("x-forwarded-proto"))
+        .unwrap_or_else(|| "https".to_string());
+    let prefix = header("x-forwarded-prefix").unwrap_or_default();
+
+    Some(format!(
+        "{}://{}{}",
+        proto,
+        host,
+        prefix.trim_end_matches('/')
+    ))
+}
+
 // ===== HTTP HEADER BUILDERS ======================================================================
 
 /// Allocates an optimized standard HTTP [`HeaderMap`] initialized with standard application/json headers.
@@ -180,6 +225,8 @@ pub fn errors_to_error_code(e: &Errors) -> ErrorCode {
         // Client-side: Valid identities explicitly denied by policies
         Errors::ForbiddenError { .. } => ErrorCode::RequestDenied,
         Errors::MissingActionError { .. } => ErrorCode::RequestDenied,
+        Errors::RateLimitError { .. } => ErrorCode::TooManyAttempts,
+        Errors::ConflictError { .. } => ErrorCode::Other("conflict".to_string()),
 
         // Client-side: Unknown target identifiers
         Errors::MissingResourceError { .. } => ErrorCode::InvalidRequest,
@@ -16,13 +16,17 @@
  */
 
 mod client;
+mod clock;
 mod http;
 mod parse;
 mod present;
+mod qr;
 mod token;
 
-pub use client::http_client;
+pub use client::{http_client, install_http_client};
+pub use clock::Clock;
 pub use http::*;
 pub use parse::*;
 pub use present::*;
+pub use qr::*;
 pub use token::*;
@@ -0,0 +1,77 @@
+/*
+ * Copyright (C) 2026 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::io::Cursor;
+
+use image::{ImageFormat, Luma};
+use qrcode::QrCode;
+use qrcode::render::svg;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Errors, Outcome};
+
+/// Smallest side, in pixels, of rendered QR codes; phone cameras struggle below it.
+const QR_MIN_DIMENSION: u32 = 256;
+
+/// Image format a QR code is rendered to.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum QrFormat {
+    #[default]
+    Svg,
+    Png,
+}
+
+impl QrFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            QrFormat::Svg => "image/svg+xml",
+            QrFormat::Png => "image/png",
+        }
+    }
+}
+
+// ===== QR CODE RENDERING =========================================================================
+
+/// Encodes `data` (typically an `openid4vp://` or `openid-credential-offer://` URI) as a QR code.
+///
+/// # Errors
+/// Returns an [`Errors::ParseError`] when the payload does not fit in a QR code or the image
+/// cannot be encoded.
+pub fn render_qr(data: &str, format: QrFormat) -> Outcome<Vec<u8>> {
+    let code = QrCode::new(data.as_bytes())
+        .map_err(|e| Errors::parse("Unable to encode data as a QR code", Some(Box::new(e))))?;
+
+    match format {
+        QrFormat::Svg => Ok(code
+            .render::<svg::Color>()
+            .min_dimensions(QR_MIN_DIMENSION, QR_MIN_DIMENSION)
+            .build()
+            .into_bytes()),
+        QrFormat::Png => {
+            let image = code
+                .render::<Luma<u8>>()
+                .min_dimensions(QR_MIN_DIMENSION, QR_MIN_DIMENSION)
+                .build();
+            let mut bytes = Vec::new();
+            image
+                .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+                .map_err(|e| Errors::parse("Unable to encode QR code as PNG", Some(Box::new(e))))?;
+            Ok(bytes)
+        }
+    }
+}
@@ -20,8 +20,10 @@ use base64::Engine;
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use chrono::Utc;
 use rand::Rng;
+use std::time::Duration;
 
-const CLOCK_SKEW_LEEWAY: i64 = 30;
+/// Leeway applied to time checks when the caller does not configure one.
+pub const DEFAULT_CLOCK_SKEW_LEEWAY: Duration = Duration::from_secs(30);
 
 // ===== CRYPTOGRAPHIC TOKEN GENERATION ============================================================
 
@@ -43,8 +45,13 @@ pub fn create_opaque_token() -> String {
 /// Returns an [`Errors::ForbiddenError`] if the token context's declared activation milestone sits
 /// inside future temporal horizons.
 pub fn is_active(iat: i64) -> Outcome<()> {
+    is_active_with(iat, DEFAULT_CLOCK_SKEW_LEEWAY)
+}
+
+/// Same as [`is_active`], tolerating up to `leeway` of clock drift with the issuer.
+pub fn is_active_with(iat: i64, leeway: Duration) -> Outcome<()> {
     let now = Utc::now().timestamp();
-    if now + CLOCK_SKEW_LEEWAY >= iat {
+    if now + leeway.as_secs() as i64 >= iat {
         Ok(())
     } else {
         Err(Errors::forbidden("Token is not yet valid", None))
@@ -57,8 +64,13 @@ pub fn is_active(iat: i64) -> Outcome<()> {
 /// Returns an [`Errors::ForbiddenError`] if active network tracking indicates current milestones
 /// have drifted past expiration thresholds.
 pub fn has_expired(exp: i64) -> Outcome<()> {
+    has_expired_with(exp, DEFAULT_CLOCK_SKEW_LEEWAY)
+}
+
+/// Same as [`has_expired`], tolerating up to `leeway` of clock drift with the issuer.
+pub fn has_expired_with(exp: i64, leeway: Duration) -> Outcome<()> {
     let now = Utc::now().timestamp();
-    if now - CLOCK_SKEW_LEEWAY <= exp {
+    if now - leeway.as_secs() as i64 <= exp {
         Ok(())
     } else {
         Err(Errors::forbidden("Token has expired", None))